@@ -0,0 +1,146 @@
+use std::path::PathBuf;
+
+use clap::builder::PossibleValuesParser;
+use clap::builder::RangedI64ValueParser;
+use clap::builder::TypedValueParser;
+use clap::Parser;
+use clap::ValueEnum;
+use strum::IntoEnumIterator;
+
+use triton_vm::table::constraint_circuit::DegreeLoweringStrategy;
+use triton_vm::table::master_table::AIR_TARGET_DEGREE;
+
+/// The directory Triton VM expects the generated files in. Independent of the working directory.
+const DEFAULT_OUT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../triton-vm/src/table");
+
+/// Generate constraint evaluation functions for Triton VM.
+#[derive(Debug, Clone, Eq, PartialEq, Parser)]
+#[command(version, about)]
+pub(crate) struct Args {
+    /// The directory to write the generated files to.
+    #[arg(long, default_value = DEFAULT_OUT_DIR)]
+    pub out_dir: PathBuf,
+
+    /// The backends to emit code for.
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        default_values_t = [Backend::Rust, Backend::Tasm, Backend::Futhark],
+    )]
+    pub backends: Vec<Backend>,
+
+    /// The degree to lower the AIR to by introducing new columns.
+    ///
+    /// Triton VM's prover and verifier assume the default.
+    #[arg(
+        long,
+        default_value_t = AIR_TARGET_DEGREE,
+        value_parser = RangedI64ValueParser::<isize>::new().range(2..),
+    )]
+    pub target_degree: isize,
+
+    /// How to pick the nodes that are substituted by new columns when lowering the AIR's degree.
+    ///
+    /// Triton VM's prover and verifier assume the default.
+    #[arg(
+        long,
+        default_value_t = DegreeLoweringStrategy::default(),
+        value_parser = degree_lowering_strategy_parser(),
+    )]
+    pub degree_lowering_strategy: DegreeLoweringStrategy,
+
+    /// Don't generate any code. Instead, print how many columns every degree lowering strategy
+    /// introduces for every target degree from 2 up to and including `--target-degree`.
+    #[arg(long, conflicts_with = "check")]
+    pub degree_lowering_report: bool,
+
+    /// Don't write any files. Instead, fail if any of the files in the output directory differ
+    /// from the code that would be generated.
+    #[arg(long)]
+    pub check: bool,
+}
+
+fn degree_lowering_strategy_parser() -> impl TypedValueParser<Value = DegreeLoweringStrategy> {
+    let strategies = DegreeLoweringStrategy::iter().map(|strategy| strategy.to_string());
+    PossibleValuesParser::new(strategies)
+        .map(|strategy| strategy.parse::<DegreeLoweringStrategy>().unwrap())
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, ValueEnum)]
+pub(crate) enum Backend {
+    /// Rust code for evaluating the AIR and for filling the degree lowering table.
+    Rust,
+
+    /// Rust code emitting Triton assembly for evaluating the AIR.
+    Tasm,
+
+    /// Futhark code for evaluating the AIR and for filling the degree lowering table.
+    Futhark,
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+
+    use super::*;
+
+    #[test]
+    fn command_line_interface_is_consistent() {
+        Args::command().debug_assert();
+    }
+
+    #[test]
+    fn all_backends_are_emitted_by_default() {
+        let args = Args::parse_from(["constraint-evaluation-generator"]);
+        assert_eq!(Backend::value_variants(), args.backends);
+        assert_eq!(AIR_TARGET_DEGREE, args.target_degree);
+        assert_eq!(
+            DegreeLoweringStrategy::default(),
+            args.degree_lowering_strategy
+        );
+        assert!(!args.check);
+        assert!(!args.degree_lowering_report);
+    }
+
+    #[test]
+    fn backends_can_be_selected() {
+        let cli = [
+            "constraint-evaluation-generator",
+            "--backends",
+            "futhark,rust",
+        ];
+        let args = Args::parse_from(cli);
+        assert_eq!(vec![Backend::Futhark, Backend::Rust], args.backends);
+    }
+
+    #[test]
+    fn target_degree_must_be_greater_than_one() {
+        let cli = ["constraint-evaluation-generator", "--target-degree", "1"];
+        assert!(Args::try_parse_from(cli).is_err());
+    }
+
+    #[test]
+    fn every_degree_lowering_strategy_can_be_selected() {
+        for strategy in DegreeLoweringStrategy::iter() {
+            let strategy_arg = strategy.to_string();
+            let cli = [
+                "constraint-evaluation-generator",
+                "--degree-lowering-strategy",
+                &strategy_arg,
+            ];
+            let args = Args::parse_from(cli);
+            assert_eq!(strategy, args.degree_lowering_strategy);
+        }
+    }
+
+    #[test]
+    fn degree_lowering_report_and_check_are_mutually_exclusive() {
+        let cli = [
+            "constraint-evaluation-generator",
+            "--degree-lowering-report",
+            "--check",
+        ];
+        assert!(Args::try_parse_from(cli).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
@@ -7,6 +8,7 @@ use twenty_first::prelude::XFieldElement;
 
 use crate::constraints::Constraints;
 
+mod futhark;
 mod rust;
 mod tasm;
 
@@ -44,3 +46,17 @@ pub(crate) struct TasmBackend {
     /// See [`TasmBackend::doc_comment`] for details.
     elements_written: usize,
 }
+
+/// Emits [Futhark](https://futhark-lang.org) source code for evaluating the AIR over entire columns
+/// of the master tables, as well as for filling the degree lowering table.
+///
+/// Since Futhark is not Rust, this backend does not implement [`Codegen`], whose output is a
+/// [`TokenStream`].
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub(crate) struct FutharkBackend {
+    /// All [circuit] IDs known to be in scope, mapped to whether they evaluate to a base field
+    /// element.
+    ///
+    /// [circuit]: triton_vm::table::constraint_circuit::ConstraintCircuit
+    scope: HashMap<usize, bool>,
+}
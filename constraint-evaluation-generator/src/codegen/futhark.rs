@@ -0,0 +1,649 @@
+use itertools::Itertools;
+use twenty_first::prelude::BFieldElement;
+use twenty_first::prelude::XFieldElement;
+
+use triton_vm::table;
+use triton_vm::table::constraint_circuit::BinOp;
+use triton_vm::table::constraint_circuit::CircuitExpression;
+use triton_vm::table::constraint_circuit::ConstraintCircuit;
+use triton_vm::table::constraint_circuit::ConstraintCircuitMonad;
+use triton_vm::table::constraint_circuit::DualRowIndicator;
+use triton_vm::table::constraint_circuit::InputIndicator;
+use triton_vm::table::constraint_circuit::SingleRowIndicator;
+use triton_vm::table::degree_lowering_table;
+
+use crate::codegen::FutharkBackend;
+use crate::constraints::Constraints;
+use crate::substitution::AllSubstitutions;
+use crate::substitution::Substitutions;
+
+const HEADER: &str = "\
+-- Evaluation of Triton VM's AIR constraints and of the degree lowering table's columns.
+--
+-- This file has been auto-generated. Any modifications _will_ be lost.
+-- To re-generate, execute:
+-- `cargo run --bin constraint-evaluation-generator`
+--
+-- Base field elements are the raw `u64`s backing `BFieldElement`s, i.e., they are in Montgomery
+-- representation. Extension field elements are arrays of three such coefficients. All tables are
+-- column-major: the outer dimension indexes the columns, the inner dimension indexes the rows.
+";
+
+/// Arithmetic over [`BFieldElement`]s and [`XFieldElement`]s, mirroring `twenty-first`.
+const FIELD_ARITHMETIC: &str = "\
+type bfe = u64
+
+-- Coefficients of an element of the extension field F_p[X] / (X^3 - X + 1).
+type xfe = (bfe, bfe, bfe)
+
+def bfe_add (a: bfe) (b: bfe): bfe =
+  let p_minus_b = 0xffffffff00000001 - b
+  let x = a - p_minus_b
+  in if a < p_minus_b then x - 0xffffffff else x
+
+def bfe_sub (a: bfe) (b: bfe): bfe =
+  let x = a - b
+  in if a < b then x - 0xffffffff else x
+
+def bfe_montyred (lo: u64) (hi: u64): bfe =
+  let a = lo + (lo << 32)
+  let e = if a < lo then 1 else 0
+  let b = a - (a >> 32) - e
+  let r = hi - b
+  in if hi < b then r - 0xffffffff else r
+
+def bfe_mul (a: bfe) (b: bfe): bfe =
+  bfe_montyred (a * b) (u64.mul_hi a b)
+
+def xfe_lift (a: bfe): xfe = (a, 0, 0)
+
+def xfe_from_array (a: [3]u64): xfe = (a[0], a[1], a[2])
+
+def xfe_to_array ((a0, a1, a2): xfe): [3]u64 = [a0, a1, a2]
+
+def xfe_add ((a0, a1, a2): xfe) ((b0, b1, b2): xfe): xfe =
+  (bfe_add a0 b0, bfe_add a1 b1, bfe_add a2 b2)
+
+def xfe_mul ((a0, a1, a2): xfe) ((b0, b1, b2): xfe): xfe =
+  let x3 = bfe_add (bfe_mul a1 b2) (bfe_mul a2 b1)
+  let x4 = bfe_mul a2 b2
+  let c0 = bfe_sub (bfe_mul a0 b0) x3
+  let c1 = bfe_sub (bfe_add (bfe_add (bfe_mul a0 b1) (bfe_mul a1 b0)) x3) x4
+  let c2 = bfe_add (bfe_add (bfe_add (bfe_mul a0 b2) (bfe_mul a1 b1)) (bfe_mul a2 b0)) x4
+  in (c0, c1, c2)
+
+def bx_add (a: bfe) ((b0, b1, b2): xfe): xfe = (bfe_add a b0, b1, b2)
+
+def xb_add (a: xfe) (b: bfe): xfe = bx_add b a
+
+def bx_mul (a: bfe) ((b0, b1, b2): xfe): xfe = (bfe_mul a b0, bfe_mul a b1, bfe_mul a b2)
+
+def xb_mul (a: xfe) (b: bfe): xfe = bx_mul b a
+";
+
+/// The master table a derived column of the degree lowering table belongs to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+enum MasterTable {
+    Base,
+    Ext,
+}
+
+impl MasterTable {
+    fn name(self) -> &'static str {
+        match self {
+            MasterTable::Base => "base_table",
+            MasterTable::Ext => "ext_table",
+        }
+    }
+}
+
+impl FutharkBackend {
+    /// Emits a complete Futhark source file made up of the given fragments, which are as produced
+    /// by [`constraint_evaluation_code`][cons] and [`degree_lowering_table_code`][deg].
+    ///
+    /// [cons]: Self::constraint_evaluation_code
+    /// [deg]: Self::degree_lowering_table_code
+    pub fn source_file(fragments: &[String]) -> String {
+        [HEADER, FIELD_ARITHMETIC]
+            .into_iter()
+            .chain(fragments.iter().map(String::as_str))
+            .join("\n")
+    }
+
+    /// Emits Futhark entry points that evaluate Triton VM's AIR constraints over entire columns.
+    /// For each row, the constraints are evaluated in the same order as by the code the
+    /// [`RustBackend`](crate::codegen::RustBackend) emits.
+    pub fn constraint_evaluation_code(constraints: &Constraints) -> String {
+        let init_constraints = Self::single_row_constraints_code("initial", &constraints.init());
+        let cons_constraints =
+            Self::single_row_constraints_code("consistency", &constraints.cons());
+        let tran_constraints = Self::transition_constraints_code(&constraints.tran());
+        let term_constraints = Self::single_row_constraints_code("terminal", &constraints.term());
+
+        [
+            init_constraints,
+            cons_constraints,
+            tran_constraints,
+            term_constraints,
+        ]
+        .join("\n")
+    }
+
+    /// Emits Futhark entry points that fill the derived columns of the degree lowering table.
+    /// Like the Rust code emitted by [`AllSubstitutions::generate_degree_lowering_table_code`],
+    /// the entry points expect the entire master base and extension tables, respectively.
+    pub fn degree_lowering_table_code(substitutions: &AllSubstitutions) -> String {
+        let fill_base_columns = Self::fill_derived_base_columns_code(&substitutions.base);
+        let fill_ext_columns = Self::fill_derived_ext_columns_code(&substitutions.ext);
+
+        [fill_base_columns, fill_ext_columns].join("\n")
+    }
+
+    fn single_row_constraints_code(
+        name: &str,
+        constraints: &[ConstraintCircuit<SingleRowIndicator>],
+    ) -> String {
+        let num_constraints = constraints.len();
+        let evaluate_constraints = Self::evaluate_constraints(constraints);
+
+        format!(
+            "\
+def num_{name}_constraints: i64 = {num_constraints}
+
+def {name}_constraints [nb][nx][nc][n]
+    (base_table: [nb][n]bfe)
+    (ext_table: [nx][n]xfe)
+    (challenges: [nc]xfe)
+    (row: i64)
+    : [{num_constraints}]xfe =
+{evaluate_constraints}
+
+entry evaluate_{name}_constraints [nb][nx][nc][n]
+    (base_table: [nb][n]u64)
+    (ext_table: [nx][n][3]u64)
+    (challenges: [nc][3]u64)
+    : [{num_constraints}][n][3]u64 =
+  let ext_table = map (map xfe_from_array) ext_table
+  let challenges = map xfe_from_array challenges
+  let rows = map ({name}_constraints base_table ext_table challenges) (iota n)
+  in map (map xfe_to_array) (transpose rows)
+"
+        )
+    }
+
+    /// In contrast to the other constraint types, transition constraints need a notion of the
+    /// next row. On the trace domain, this is the row with index `row + 1`. On larger domains, it
+    /// is `unit_distance` rows away; in both cases, the last rows wrap around.
+    fn transition_constraints_code(constraints: &[ConstraintCircuit<DualRowIndicator>]) -> String {
+        let num_constraints = constraints.len();
+        let evaluate_constraints = Self::evaluate_constraints(constraints);
+
+        format!(
+            "\
+def num_transition_constraints: i64 = {num_constraints}
+
+def transition_constraints [nb][nx][nc][n]
+    (base_table: [nb][n]bfe)
+    (ext_table: [nx][n]xfe)
+    (challenges: [nc]xfe)
+    (row: i64)
+    (next_row: i64)
+    : [{num_constraints}]xfe =
+{evaluate_constraints}
+
+entry evaluate_transition_constraints [nb][nx][nc][n]
+    (base_table: [nb][n]u64)
+    (ext_table: [nx][n][3]u64)
+    (challenges: [nc][3]u64)
+    (unit_distance: i64)
+    : [{num_constraints}][n][3]u64 =
+  let ext_table = map (map xfe_from_array) ext_table
+  let challenges = map xfe_from_array challenges
+  let constraints_on_row row =
+    transition_constraints base_table ext_table challenges row ((row + unit_distance) % n)
+  let rows = map constraints_on_row (iota n)
+  in map (map xfe_to_array) (transpose rows)
+"
+        )
+    }
+
+    /// The body of a function evaluating all given constraints on one row.
+    fn evaluate_constraints<II: InputIndicator>(constraints: &[ConstraintCircuit<II>]) -> String {
+        let mut backend = Self::default();
+        let shared_declarations = backend.declare_shared_nodes(constraints);
+
+        // to match the `RustBackend`, base constraints must be emitted first
+        let (base_constraints, ext_constraints): (Vec<_>, Vec<_>) = constraints
+            .iter()
+            .partition(|constraint| constraint.evaluates_to_base_element());
+        let evaluated_constraints = base_constraints
+            .into_iter()
+            .chain(ext_constraints)
+            .map(|constraint| backend.evaluate_single_node(constraint))
+            .map(|(evaluation, is_base_element)| Self::lift(evaluation, is_base_element))
+            .map(|evaluation| format!("\n    {evaluation}"))
+            .join(",");
+        let evaluated_constraints = match evaluated_constraints.is_empty() {
+            true => "[]".to_string(),
+            false => format!("[{evaluated_constraints}\n  ]"),
+        };
+
+        Self::let_in(&shared_declarations, &evaluated_constraints)
+    }
+
+    fn fill_derived_base_columns_code(substitutions: &Substitutions) -> String {
+        let derived_section_start = table::NUM_BASE_COLUMNS - degree_lowering_table::BASE_WIDTH;
+        let fill_columns =
+            Self::fill_columns_code(MasterTable::Base, derived_section_start, substitutions);
+        let fill_columns = Self::let_in(&fill_columns, "base_table");
+
+        format!(
+            "\
+entry fill_derived_base_columns [nb][n] (base_table: *[nb][n]u64): *[nb][n]u64 =
+{fill_columns}
+"
+        )
+    }
+
+    fn fill_derived_ext_columns_code(substitutions: &Substitutions) -> String {
+        let derived_section_start = table::NUM_EXT_COLUMNS - degree_lowering_table::EXT_WIDTH;
+        let fill_columns =
+            Self::fill_columns_code(MasterTable::Ext, derived_section_start, substitutions);
+        let declarations = [
+            vec![
+                "let ext_table = map (map xfe_from_array) ext_table".to_string(),
+                "let challenges = map xfe_from_array challenges".to_string(),
+            ],
+            fill_columns,
+        ]
+        .concat();
+        let fill_columns = Self::let_in(&declarations, "map (map xfe_to_array) ext_table");
+
+        format!(
+            "\
+entry fill_derived_ext_columns [nb][nx][nc][n]
+    (base_table: [nb][n]u64)
+    (ext_table: [nx][n][3]u64)
+    (challenges: [nc][3]u64)
+    : [nx][n][3]u64 =
+{fill_columns}
+"
+        )
+    }
+
+    /// The substitution rules are applied in order, since later rules may refer to columns
+    /// introduced by earlier ones.
+    fn fill_columns_code(
+        master_table: MasterTable,
+        derived_section_start: usize,
+        substitutions: &Substitutions,
+    ) -> Vec<String> {
+        let derived_section_cons_start = derived_section_start + substitutions.init.len();
+        let derived_section_tran_start = derived_section_cons_start + substitutions.cons.len();
+        let derived_section_term_start = derived_section_tran_start + substitutions.tran.len();
+
+        // For consistency with the `RustBackend`, the last row of single-row substitutions
+        // in the extension table is left untouched.
+        let skip_last_row = master_table == MasterTable::Ext;
+
+        [
+            Self::fill_section_code(
+                master_table,
+                derived_section_start,
+                &substitutions.init,
+                skip_last_row,
+            ),
+            Self::fill_section_code(
+                master_table,
+                derived_section_cons_start,
+                &substitutions.cons,
+                skip_last_row,
+            ),
+            Self::fill_section_code(
+                master_table,
+                derived_section_tran_start,
+                &substitutions.tran,
+                true,
+            ),
+            Self::fill_section_code(
+                master_table,
+                derived_section_term_start,
+                &substitutions.term,
+                skip_last_row,
+            ),
+        ]
+        .concat()
+    }
+
+    fn fill_section_code<II: InputIndicator>(
+        master_table: MasterTable,
+        section_start: usize,
+        substitution_rules: &[ConstraintCircuitMonad<II>],
+        skip_last_row: bool,
+    ) -> Vec<String> {
+        substitution_rules
+            .iter()
+            .enumerate()
+            .flat_map(|(i, rule)| {
+                Self::fill_column_code(master_table, section_start + i, rule, skip_last_row)
+            })
+            .collect()
+    }
+
+    fn fill_column_code<II: InputIndicator>(
+        master_table: MasterTable,
+        column: usize,
+        substitution_rule: &ConstraintCircuitMonad<II>,
+        skip_last_row: bool,
+    ) -> Vec<String> {
+        let substitution_rule = substitution_rule.circuit.borrow().to_owned();
+        let expression = Substitutions::substitution_rule_expression(substitution_rule);
+        let (evaluation, is_base_element) = Self::default().evaluate_single_node(&expression);
+        let evaluation = match master_table {
+            MasterTable::Base => {
+                assert!(
+                    is_base_element,
+                    "Base columns must be filled with base elements."
+                );
+                evaluation
+            }
+            MasterTable::Ext => Self::lift(evaluation, is_base_element),
+        };
+
+        let table = master_table.name();
+        let evaluation = match skip_last_row {
+            true => format!(
+                "let next_row = row + 1 in \
+                if next_row == n then {table}[{column}, row] else {evaluation}"
+            ),
+            false => evaluation,
+        };
+
+        vec![
+            format!("let column_{column} = map (\\row -> {evaluation}) (iota n)"),
+            format!("let {table} = {table} with [{column}] = column_{column}"),
+        ]
+    }
+
+    /// Declare all shared nodes, i.e., those with a ref count greater than 1.
+    /// These declarations must be made starting from the highest ref count.
+    /// Otherwise, the resulting code will refer to bindings that have not yet been made.
+    fn declare_shared_nodes<II: InputIndicator>(
+        &mut self,
+        constraints: &[ConstraintCircuit<II>],
+    ) -> Vec<String> {
+        let constraints_iter = constraints.iter();
+        let all_ref_counts = constraints_iter.flat_map(ConstraintCircuit::all_ref_counters);
+        let relevant_ref_counts = all_ref_counts.unique().filter(|&x| x > 1);
+        let ordered_ref_counts = relevant_ref_counts.sorted().rev();
+
+        ordered_ref_counts
+            .flat_map(|count| self.declare_nodes_with_ref_count(constraints, count))
+            .collect()
+    }
+
+    fn declare_nodes_with_ref_count<II: InputIndicator>(
+        &mut self,
+        circuits: &[ConstraintCircuit<II>],
+        ref_count: usize,
+    ) -> Vec<String> {
+        circuits
+            .iter()
+            .flat_map(|circuit| self.declare_single_node_with_ref_count(circuit, ref_count))
+            .collect()
+    }
+
+    fn declare_single_node_with_ref_count<II: InputIndicator>(
+        &mut self,
+        circuit: &ConstraintCircuit<II>,
+        ref_count: usize,
+    ) -> Vec<String> {
+        if self.scope.contains_key(&circuit.id) {
+            return vec![];
+        }
+
+        // constants can be declared trivially
+        let CircuitExpression::BinaryOperation(_, lhs, rhs) = &circuit.expression else {
+            return vec![];
+        };
+
+        if circuit.ref_count < ref_count {
+            let out_left = self.declare_single_node_with_ref_count(&lhs.borrow(), ref_count);
+            let out_right = self.declare_single_node_with_ref_count(&rhs.borrow(), ref_count);
+            return [out_left, out_right].concat();
+        }
+
+        assert_eq!(circuit.ref_count, ref_count);
+        let binding_name = Self::binding_name(circuit);
+        let (evaluation, is_base_element) = self.evaluate_single_node(circuit);
+        let new_binding = format!("let {binding_name} = {evaluation}");
+
+        let previous_binding = self.scope.insert(circuit.id, is_base_element);
+        assert!(previous_binding.is_none());
+
+        vec![new_binding]
+    }
+
+    /// Recursively construct the code for evaluating a single node. Also returns whether the
+    /// node evaluates to a base field element.
+    fn evaluate_single_node<II: InputIndicator>(
+        &self,
+        circuit: &ConstraintCircuit<II>,
+    ) -> (String, bool) {
+        if let Some(&is_base_element) = self.scope.get(&circuit.id) {
+            return (Self::binding_name(circuit), is_base_element);
+        }
+
+        let CircuitExpression::BinaryOperation(binop, lhs, rhs) = &circuit.expression else {
+            return (
+                Self::binding_name(circuit),
+                circuit.evaluates_to_base_element(),
+            );
+        };
+
+        let (lhs, lhs_is_base_element) = self.evaluate_single_node(&lhs.borrow());
+        let (rhs, rhs_is_base_element) = self.evaluate_single_node(&rhs.borrow());
+        let operation = Self::bin_op(*binop, lhs_is_base_element, rhs_is_base_element);
+        let evaluation = format!("({operation} {lhs} {rhs})");
+
+        (evaluation, lhs_is_base_element && rhs_is_base_element)
+    }
+
+    /// The Futhark function implementing the given binary operation for operands of the given
+    /// fields.
+    fn bin_op(binop: BinOp, lhs_is_base_element: bool, rhs_is_base_element: bool) -> &'static str {
+        match (binop, lhs_is_base_element, rhs_is_base_element) {
+            (BinOp::Add, true, true) => "bfe_add",
+            (BinOp::Add, true, false) => "bx_add",
+            (BinOp::Add, false, true) => "xb_add",
+            (BinOp::Add, false, false) => "xfe_add",
+            (BinOp::Mul, true, true) => "bfe_mul",
+            (BinOp::Mul, true, false) => "bx_mul",
+            (BinOp::Mul, false, true) => "xb_mul",
+            (BinOp::Mul, false, false) => "xfe_mul",
+        }
+    }
+
+    fn binding_name<II: InputIndicator>(circuit: &ConstraintCircuit<II>) -> String {
+        match &circuit.expression {
+            CircuitExpression::BConstant(bfe) => Self::tokenize_bfe(*bfe),
+            CircuitExpression::XConstant(xfe) => Self::tokenize_xfe(*xfe),
+            CircuitExpression::Input(input) => Self::tokenize_input(*input),
+            CircuitExpression::Challenge(challenge) => format!("challenges[{challenge}]"),
+            CircuitExpression::BinaryOperation(_, _, _) => format!("node_{}", circuit.id),
+        }
+    }
+
+    fn tokenize_bfe(bfe: BFieldElement) -> String {
+        format!("{}u64", bfe.raw_u64())
+    }
+
+    fn tokenize_xfe(xfe: XFieldElement) -> String {
+        let [c_0, c_1, c_2] = xfe.coefficients.map(Self::tokenize_bfe);
+        format!("({c_0}, {c_1}, {c_2})")
+    }
+
+    fn tokenize_input<II: InputIndicator>(input: II) -> String {
+        let table = match input.is_base_table_column() {
+            true => MasterTable::Base.name(),
+            false => MasterTable::Ext.name(),
+        };
+        let row = match input.is_current_row() {
+            true => "row",
+            false => "next_row",
+        };
+        format!("{table}[{}, {row}]", input.column())
+    }
+
+    fn lift(evaluation: String, is_base_element: bool) -> String {
+        match is_base_element {
+            true => format!("(xfe_lift {evaluation})"),
+            false => evaluation,
+        }
+    }
+
+    fn let_in(declarations: &[String], body: &str) -> String {
+        if declarations.is_empty() {
+            return format!("  {body}");
+        }
+        let declarations = declarations.iter().map(|d| format!("  {d}\n")).join("");
+        format!("{declarations}  in {body}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::process::Command;
+
+    use ndarray::Array2;
+    use strum::EnumCount;
+    use twenty_first::prelude::*;
+
+    use triton_vm::table::challenges::ChallengeId;
+    use triton_vm::table::constraint_circuit::DegreeLoweringStrategy;
+    use triton_vm::table::master_table::AIR_TARGET_DEGREE;
+
+    use super::*;
+
+    #[test]
+    fn tokenizing_base_field_elements_produces_expected_result() {
+        let bfe = bfe!(42);
+        assert_eq!("180388626390u64", FutharkBackend::tokenize_bfe(bfe));
+    }
+
+    #[test]
+    fn tokenizing_extension_field_elements_produces_expected_result() {
+        let xfe = xfe!([42, 43, 44]);
+        let expected = "(180388626390u64, 184683593685u64, 188978560980u64)";
+        assert_eq!(expected, FutharkBackend::tokenize_xfe(xfe));
+    }
+
+    #[test]
+    fn tokenizing_inputs_refers_to_correct_table_and_row() {
+        let input = SingleRowIndicator::ExtRow(3);
+        assert_eq!("ext_table[3, row]", FutharkBackend::tokenize_input(input));
+
+        let input = DualRowIndicator::NextBaseRow(5);
+        assert_eq!(
+            "base_table[5, next_row]",
+            FutharkBackend::tokenize_input(input)
+        );
+    }
+
+    #[test]
+    fn base_field_operands_are_not_lifted_unnecessarily() {
+        assert_eq!("bfe_mul", FutharkBackend::bin_op(BinOp::Mul, true, true));
+        assert_eq!("bx_mul", FutharkBackend::bin_op(BinOp::Mul, true, false));
+        assert_eq!("xb_add", FutharkBackend::bin_op(BinOp::Add, false, true));
+        assert_eq!("xfe_add", FutharkBackend::bin_op(BinOp::Add, false, false));
+    }
+
+    /// The Futhark source file for the given constraints, generated like the one for Triton VM.
+    fn constraints_as_futhark(mut constraints: Constraints) -> String {
+        let substitutions = constraints.lower_to_target_degree_through_substitutions_with_strategy(
+            DegreeLoweringStrategy::default(),
+            AIR_TARGET_DEGREE,
+        );
+        let degree_lowering_table = FutharkBackend::degree_lowering_table_code(&substitutions);
+        let constraints = constraints.combine_with_substitution_induced_constraints(substitutions);
+        let constraint_evaluation = FutharkBackend::constraint_evaluation_code(&constraints);
+        FutharkBackend::source_file(&[constraint_evaluation, degree_lowering_table])
+    }
+
+    #[test]
+    fn mini_constraints_are_emitted_as_expected() {
+        let code = constraints_as_futhark(Constraints::mini_constraints());
+        assert_eq!(include_str!("mini_constraints.fut"), code);
+    }
+
+    #[test]
+    fn print_test_constraints_as_futhark() {
+        let code = constraints_as_futhark(Constraints::test_constraints());
+        println!("{code}");
+    }
+
+    /// Compares the evaluation of the emitted Futhark code to the evaluation of the constraint
+    /// circuit itself.
+    #[test]
+    #[ignore = "requires the `futhark` compiler"]
+    fn futhark_evaluation_of_mini_constraints_agrees_with_circuit_evaluation() {
+        let file_name = format!("mini_constraints-{}.fut", std::process::id());
+        let source_file = std::env::temp_dir().join(file_name);
+        let code = constraints_as_futhark(Constraints::mini_constraints());
+        fs::write(&source_file, code).unwrap();
+
+        let base_row = bfe_vec![1, 2];
+        let ext_row = vec![xfe!([3, 4, 5]), xfe!([6, 7, 8])];
+        let challenges = (0..ChallengeId::COUNT)
+            .map(|i| xfe!([i as u64, 2 * i as u64 + 1, 3 * i as u64 + 2]))
+            .collect_vec();
+
+        let tokenize_raw = |bfe: BFieldElement| format!("{}u64", bfe.raw_u64());
+        let tokenize_xfe = |xfe: XFieldElement| {
+            let coefficients = xfe.coefficients.map(tokenize_raw).join(", ");
+            format!("[{coefficients}]")
+        };
+        let base_table = base_row
+            .iter()
+            .map(|&bfe| format!("[{}]", tokenize_raw(bfe)))
+            .join(", ");
+        let ext_table = ext_row
+            .iter()
+            .map(|&xfe| format!("[{}]", tokenize_xfe(xfe)))
+            .join(", ");
+        let challenges_arg = challenges.iter().map(|&xfe| tokenize_xfe(xfe)).join(", ");
+        let arguments = format!("[{base_table}] [{ext_table}] [{challenges_arg}]");
+        let expression = format!("evaluate_initial_constraints {arguments}");
+
+        let output = Command::new("futhark")
+            .arg("eval")
+            .arg("-f")
+            .arg(&source_file)
+            .arg(expression)
+            .output()
+            .unwrap();
+        fs::remove_file(source_file).unwrap();
+        assert!(output.status.success(), "{output:?}");
+
+        let output = String::from_utf8(output.stdout).unwrap();
+        let futhark_evaluation = output
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter_map(|token| token.strip_suffix("u64"))
+            .map(|raw| BFieldElement::from_raw_u64(raw.parse().unwrap()))
+            .collect_vec();
+
+        let base_table = Array2::from_shape_vec((1, base_row.len()), base_row).unwrap();
+        let ext_table = Array2::from_shape_vec((1, ext_row.len()), ext_row).unwrap();
+        let circuit_evaluation = Constraints::mini_constraints()
+            .init()
+            .iter()
+            .flat_map(|constraint| {
+                let evaluation =
+                    constraint.evaluate(base_table.view(), ext_table.view(), &challenges);
+                evaluation.coefficients
+            })
+            .collect_vec();
+        assert_eq!(circuit_evaluation, futhark_evaluation);
+    }
+}
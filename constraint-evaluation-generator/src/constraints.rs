@@ -5,6 +5,7 @@ use triton_vm::table::cascade_table::ExtCascadeTable;
 use triton_vm::table::constraint_circuit::ConstraintCircuit;
 use triton_vm::table::constraint_circuit::ConstraintCircuitBuilder;
 use triton_vm::table::constraint_circuit::ConstraintCircuitMonad;
+use triton_vm::table::constraint_circuit::DegreeLoweringStrategy;
 use triton_vm::table::constraint_circuit::DualRowIndicator;
 use triton_vm::table::constraint_circuit::InputIndicator;
 use triton_vm::table::constraint_circuit::SingleRowIndicator;
@@ -13,7 +14,6 @@ use triton_vm::table::degree_lowering_table;
 use triton_vm::table::hash_table::ExtHashTable;
 use triton_vm::table::jump_stack_table::ExtJumpStackTable;
 use triton_vm::table::lookup_table::ExtLookupTable;
-use triton_vm::table::master_table;
 use triton_vm::table::op_stack_table::ExtOpStackTable;
 use triton_vm::table::processor_table::ExtProcessorTable;
 use triton_vm::table::program_table::ExtProgramTable;
@@ -108,15 +108,20 @@ impl Constraints {
         .concat()
     }
 
-    pub fn lower_to_target_degree_through_substitutions(&mut self) -> AllSubstitutions {
+    pub fn lower_to_target_degree_through_substitutions_with_strategy(
+        &mut self,
+        strategy: DegreeLoweringStrategy,
+        target_degree: isize,
+    ) -> AllSubstitutions {
         // Subtract the degree lowering table's width from the total number of columns to guarantee
         // the same number of columns even for repeated runs of the constraint evaluation generator.
         let mut num_base_cols = table::NUM_BASE_COLUMNS - degree_lowering_table::BASE_WIDTH;
         let mut num_ext_cols = table::NUM_EXT_COLUMNS - degree_lowering_table::EXT_WIDTH;
         let (init_base_substitutions, init_ext_substitutions) =
-            ConstraintCircuitMonad::lower_to_degree(
+            ConstraintCircuitMonad::lower_to_degree_with_strategy(
                 &mut self.init,
-                master_table::AIR_TARGET_DEGREE,
+                strategy,
+                target_degree,
                 num_base_cols,
                 num_ext_cols,
             );
@@ -124,9 +129,10 @@ impl Constraints {
         num_ext_cols += init_ext_substitutions.len();
 
         let (cons_base_substitutions, cons_ext_substitutions) =
-            ConstraintCircuitMonad::lower_to_degree(
+            ConstraintCircuitMonad::lower_to_degree_with_strategy(
                 &mut self.cons,
-                master_table::AIR_TARGET_DEGREE,
+                strategy,
+                target_degree,
                 num_base_cols,
                 num_ext_cols,
             );
@@ -134,9 +140,10 @@ impl Constraints {
         num_ext_cols += cons_ext_substitutions.len();
 
         let (tran_base_substitutions, tran_ext_substitutions) =
-            ConstraintCircuitMonad::lower_to_degree(
+            ConstraintCircuitMonad::lower_to_degree_with_strategy(
                 &mut self.tran,
-                master_table::AIR_TARGET_DEGREE,
+                strategy,
+                target_degree,
                 num_base_cols,
                 num_ext_cols,
             );
@@ -144,9 +151,10 @@ impl Constraints {
         num_ext_cols += tran_ext_substitutions.len();
 
         let (term_base_substitutions, term_ext_substitutions) =
-            ConstraintCircuitMonad::lower_to_degree(
+            ConstraintCircuitMonad::lower_to_degree_with_strategy(
                 &mut self.term,
-                master_table::AIR_TARGET_DEGREE,
+                strategy,
+                target_degree,
                 num_base_cols,
                 num_ext_cols,
             );
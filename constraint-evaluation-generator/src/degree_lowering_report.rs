@@ -0,0 +1,139 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+use itertools::Itertools;
+use strum::IntoEnumIterator;
+use twenty_first::math::x_field_element::EXTENSION_DEGREE;
+
+use triton_vm::table::constraint_circuit::DegreeLoweringStrategy;
+
+use crate::constraints::Constraints;
+
+/// Compares the number of columns the different [`DegreeLoweringStrategy`]s introduce when
+/// lowering the AIR to some target degree.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct DegreeLoweringReport {
+    pub entries: Vec<DegreeLoweringReportEntry>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct DegreeLoweringReportEntry {
+    pub target_degree: isize,
+    pub strategy: DegreeLoweringStrategy,
+    pub num_new_base_cols: usize,
+    pub num_new_ext_cols: usize,
+}
+
+impl DegreeLoweringReport {
+    /// Lower all of Triton VM's constraints to each of the given target degrees, using every
+    /// [`DegreeLoweringStrategy`].
+    pub fn new(target_degrees: impl IntoIterator<Item = isize>) -> Self {
+        Self::for_constraints(Constraints::all, target_degrees)
+    }
+
+    /// Like [`new`](Self::new), but for the constraints returned by the given function. Since
+    /// degree lowering modifies the constraints, they are re-built for every lowering.
+    pub fn for_constraints(
+        constraints: impl Fn() -> Constraints,
+        target_degrees: impl IntoIterator<Item = isize>,
+    ) -> Self {
+        let entries = target_degrees
+            .into_iter()
+            .cartesian_product(DegreeLoweringStrategy::iter().collect_vec())
+            .map(|(target_degree, strategy)| {
+                let substitutions = constraints()
+                    .lower_to_target_degree_through_substitutions_with_strategy(
+                        strategy,
+                        target_degree,
+                    );
+                DegreeLoweringReportEntry {
+                    target_degree,
+                    strategy,
+                    num_new_base_cols: substitutions.base.len(),
+                    num_new_ext_cols: substitutions.ext.len(),
+                }
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    /// The entry introducing the fewest new columns for the given target degree, if any. Ties are
+    /// broken in favor of the strategy listed first in [`DegreeLoweringStrategy`].
+    pub fn cheapest(&self, target_degree: isize) -> Option<&DegreeLoweringReportEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.target_degree == target_degree)
+            .min_by_key(|entry| entry.cost())
+    }
+}
+
+impl DegreeLoweringReportEntry {
+    /// The number of new columns, where an extension column counts as [`EXTENSION_DEGREE`] base
+    /// columns.
+    pub fn cost(&self) -> usize {
+        self.num_new_base_cols + EXTENSION_DEGREE * self.num_new_ext_cols
+    }
+}
+
+impl Display for DegreeLoweringReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let strategy_width = DegreeLoweringStrategy::iter()
+            .map(|strategy| strategy.to_string().len())
+            .max()
+            .unwrap_or_default();
+
+        writeln!(
+            f,
+            "| degree | {:<strategy_width$} | base cols | ext cols | cost | cheapest |",
+            "strategy"
+        )?;
+        writeln!(
+            f,
+            "|-------:|:{:-<strategy_width$}-|----------:|---------:|-----:|:--------:|",
+            ""
+        )?;
+        for entry in &self.entries {
+            let is_cheapest = self.cheapest(entry.target_degree) == Some(entry);
+            let cheapest_marker = if is_cheapest { "*" } else { "" };
+            writeln!(
+                f,
+                "| {:>6} | {:<strategy_width$} | {:>9} | {:>8} | {:>4} | {cheapest_marker:^8} |",
+                entry.target_degree,
+                entry.strategy.to_string(),
+                entry.num_new_base_cols,
+                entry.num_new_ext_cols,
+                entry.cost(),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_covers_every_strategy_for_every_target_degree() {
+        let report = DegreeLoweringReport::for_constraints(Constraints::test_constraints, 2..=3);
+        let num_strategies = DegreeLoweringStrategy::iter().count();
+        assert_eq!(2 * num_strategies, report.entries.len());
+        for target_degree in 2..=3 {
+            assert!(report.cheapest(target_degree).is_some());
+        }
+        assert!(report.cheapest(4).is_none());
+    }
+
+    #[test]
+    fn report_can_be_rendered() {
+        let report = DegreeLoweringReport::for_constraints(Constraints::test_constraints, [2]);
+        let rendered = report.to_string();
+        for strategy in DegreeLoweringStrategy::iter() {
+            assert!(rendered.contains(&strategy.to_string()));
+        }
+        assert_eq!(1 + 1 + report.entries.len(), rendered.lines().count());
+    }
+}
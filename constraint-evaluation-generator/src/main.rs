@@ -1,39 +1,156 @@
-use std::fs::write;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
 
+use anyhow::bail;
+use anyhow::Context;
+use anyhow::Result;
+use clap::Parser;
+use itertools::Itertools;
 use proc_macro2::TokenStream;
 
+use triton_vm::table::constraint_circuit::DegreeLoweringStrategy;
+
+use crate::cli::Args;
+use crate::cli::Backend;
 use crate::codegen::Codegen;
+use crate::codegen::FutharkBackend;
 use crate::codegen::RustBackend;
 use crate::codegen::TasmBackend;
 use crate::constraints::Constraints;
+use crate::degree_lowering_report::DegreeLoweringReport;
 
+mod cli;
 mod codegen;
 mod constraints;
+mod degree_lowering_report;
 mod substitution;
 
-fn main() {
+fn main() -> Result<()> {
+    let args = Args::parse();
+    if args.degree_lowering_report {
+        print!("{}", DegreeLoweringReport::new(2..=args.target_degree));
+        return Ok(());
+    }
+
+    let generated_files = generate_files(
+        &args.backends,
+        args.degree_lowering_strategy,
+        args.target_degree,
+    );
+    match args.check {
+        true => check_files(&args.out_dir, &generated_files),
+        false => write_files(&args.out_dir, &generated_files),
+    }
+}
+
+/// A file emitted by one of the [backends](Backend), ready to be written to disk.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct GeneratedFile {
+    file_name: String,
+    code: String,
+}
+
+impl GeneratedFile {
+    fn rust(file_stem: &str, code: TokenStream) -> Self {
+        let syntax_tree = syn::parse2(code).unwrap();
+        let code = prettyplease::unparse(&syntax_tree);
+        let file_name = format!("{file_stem}.rs");
+        Self { file_name, code }
+    }
+
+    fn futhark(file_stem: &str, code: String) -> Self {
+        let file_name = format!("{file_stem}.fut");
+        Self { file_name, code }
+    }
+}
+
+fn generate_files(
+    backends: &[Backend],
+    degree_lowering_strategy: DegreeLoweringStrategy,
+    target_degree: isize,
+) -> Vec<GeneratedFile> {
     let mut constraints = Constraints::all();
-    let substitutions = constraints.lower_to_target_degree_through_substitutions();
+    let substitutions = constraints.lower_to_target_degree_through_substitutions_with_strategy(
+        degree_lowering_strategy,
+        target_degree,
+    );
     let degree_lowering_table_code = substitutions.generate_degree_lowering_table_code();
-
+    let futhark_degree_lowering_table = FutharkBackend::degree_lowering_table_code(&substitutions);
     let constraints = constraints.combine_with_substitution_induced_constraints(substitutions);
-    let rust = RustBackend::constraint_evaluation_code(&constraints);
-    let tasm = TasmBackend::constraint_evaluation_code(&constraints);
 
-    write_code_to_file(degree_lowering_table_code, "degree_lowering_table");
-    write_code_to_file(rust, "constraints");
-    write_code_to_file(tasm, "tasm_air_constraints");
+    let mut generated_files = vec![];
+    for backend in backends.iter().unique() {
+        match backend {
+            Backend::Rust => {
+                let degree_lowering_table = degree_lowering_table_code.clone();
+                let rust = RustBackend::constraint_evaluation_code(&constraints);
+                generated_files.push(GeneratedFile::rust(
+                    "degree_lowering_table",
+                    degree_lowering_table,
+                ));
+                generated_files.push(GeneratedFile::rust("constraints", rust));
+            }
+            Backend::Tasm => {
+                let tasm = TasmBackend::constraint_evaluation_code(&constraints);
+                generated_files.push(GeneratedFile::rust("tasm_air_constraints", tasm));
+            }
+            Backend::Futhark => {
+                let futhark_constraints = FutharkBackend::constraint_evaluation_code(&constraints);
+                let fragments = [futhark_constraints, futhark_degree_lowering_table.clone()];
+                let futhark = FutharkBackend::source_file(&fragments);
+                generated_files.push(GeneratedFile::futhark("air_constraints", futhark));
+            }
+        }
+    }
+
+    generated_files
 }
 
-fn write_code_to_file(code: TokenStream, file_name: &str) {
-    let syntax_tree = syn::parse2(code).unwrap();
-    let code = prettyplease::unparse(&syntax_tree);
-    let path = format!("triton-vm/src/table/{file_name}.rs");
-    write(path, code).unwrap();
+fn write_files(out_dir: &Path, generated_files: &[GeneratedFile]) -> Result<()> {
+    fs::create_dir_all(out_dir)
+        .with_context(|| format!("could not create directory {}", out_dir.display()))?;
+    for file in generated_files {
+        let path = out_dir.join(&file.file_name);
+        fs::write(&path, &file.code)
+            .with_context(|| format!("could not write {}", path.display()))?;
+    }
+
+    Ok(())
+}
+
+/// Fails if any of the generated files is missing from the output directory or differs from the
+/// file found there.
+fn check_files(out_dir: &Path, generated_files: &[GeneratedFile]) -> Result<()> {
+    let mut stale_files = vec![];
+    for file in generated_files {
+        let path = out_dir.join(&file.file_name);
+        let is_stale = match fs::read_to_string(&path) {
+            Ok(code) => code != file.code,
+            Err(error) if error.kind() == ErrorKind::NotFound => true,
+            Err(error) => {
+                return Err(error).with_context(|| format!("could not read {}", path.display()))
+            }
+        };
+        if is_stale {
+            stale_files.push(path);
+        }
+    }
+
+    if stale_files.is_empty() {
+        return Ok(());
+    }
+    let stale_files = stale_files.iter().map(|path| path.display()).join("\n");
+    bail!(
+        "The following generated files are stale:\n{stale_files}\n\
+        To re-generate them, execute `cargo run --bin constraint-evaluation-generator`."
+    )
 }
 
 #[cfg(test)]
 mod tests {
+    use triton_vm::table::master_table::AIR_TARGET_DEGREE;
+
     use super::*;
 
     #[test]
@@ -44,7 +161,10 @@ mod tests {
     #[test]
     fn degree_lowering_tables_code_can_be_generated_for_test_constraints() {
         let mut constraints = Constraints::test_constraints();
-        let substitutions = constraints.lower_to_target_degree_through_substitutions();
+        let substitutions = constraints.lower_to_target_degree_through_substitutions_with_strategy(
+            DegreeLoweringStrategy::default(),
+            AIR_TARGET_DEGREE,
+        );
         let _ = substitutions.generate_degree_lowering_table_code();
     }
 
@@ -56,14 +176,57 @@ mod tests {
     #[test]
     fn degree_lowering_tables_code_can_be_generated_from_all_constraints() {
         let mut constraints = Constraints::all();
-        let substitutions = constraints.lower_to_target_degree_through_substitutions();
+        let substitutions = constraints.lower_to_target_degree_through_substitutions_with_strategy(
+            DegreeLoweringStrategy::default(),
+            AIR_TARGET_DEGREE,
+        );
         let _ = substitutions.generate_degree_lowering_table_code();
     }
 
     #[test]
     fn constraints_and_substitutions_can_be_combined() {
         let mut constraints = Constraints::test_constraints();
-        let substitutions = constraints.lower_to_target_degree_through_substitutions();
+        let substitutions = constraints.lower_to_target_degree_through_substitutions_with_strategy(
+            DegreeLoweringStrategy::default(),
+            AIR_TARGET_DEGREE,
+        );
         let _ = constraints.combine_with_substitution_induced_constraints(substitutions);
     }
+
+    #[test]
+    fn only_files_of_selected_backends_are_generated() {
+        let generated_files = generate_files(
+            &[Backend::Tasm, Backend::Tasm],
+            DegreeLoweringStrategy::default(),
+            AIR_TARGET_DEGREE,
+        );
+        let [generated_file] = generated_files.as_slice() else {
+            panic!(
+                "expected exactly one generated file, got {}",
+                generated_files.len()
+            );
+        };
+        assert_eq!("tasm_air_constraints.rs", generated_file.file_name);
+    }
+
+    #[test]
+    fn stale_and_missing_files_are_detected_by_check() {
+        let out_dir = std::env::temp_dir().join(format!(
+            "constraint-evaluation-generator-{}",
+            std::process::id()
+        ));
+        let generated_files = [GeneratedFile::futhark(
+            "air_constraints",
+            "def x = 0\n".into(),
+        )];
+        assert!(check_files(&out_dir, &generated_files).is_err());
+
+        write_files(&out_dir, &generated_files).unwrap();
+        check_files(&out_dir, &generated_files).unwrap();
+
+        fs::write(out_dir.join("air_constraints.fut"), "def x = 1\n").unwrap();
+        assert!(check_files(&out_dir, &generated_files).is_err());
+
+        fs::remove_dir_all(out_dir).unwrap();
+    }
 }
@@ -107,7 +107,7 @@ impl AllSubstitutions {
 }
 
 impl Substitutions {
-    fn len(&self) -> usize {
+    pub fn len(&self) -> usize {
         self.init.len() + self.cons.len() + self.tran.len() + self.term.len()
     }
 
@@ -223,6 +223,15 @@ impl Substitutions {
     fn substitution_rule_to_code<II: InputIndicator>(
         circuit: ConstraintCircuit<II>,
     ) -> TokenStream {
+        let expr = Self::substitution_rule_expression(circuit);
+        RustBackend::default().evaluate_single_node(&expr)
+    }
+
+    /// Given a substitution rule, i.e., a `ConstraintCircuit` of the form `x - expr`, return
+    /// `expr`.
+    pub(crate) fn substitution_rule_expression<II: InputIndicator>(
+        circuit: ConstraintCircuit<II>,
+    ) -> ConstraintCircuit<II> {
         let CircuitExpression::BinaryOperation(BinOp::Add, new_var, expr) = circuit.expression
         else {
             panic!("Substitution rule must be a subtraction, i.e., addition of `x` and `-expr`.");
@@ -236,8 +245,8 @@ impl Substitutions {
         };
         assert!(neg_one.borrow().is_neg_one());
 
-        let expr = expr.borrow();
-        RustBackend::default().evaluate_single_node(&expr)
+        let expr = expr.borrow().to_owned();
+        expr
     }
 
     fn base_single_row_substitutions(
@@ -0,0 +1,482 @@
+//! The analysis of a single Triton assembly source file, as needed for answering requests.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::ops;
+
+use itertools::Itertools;
+use lsp_types::Diagnostic;
+use lsp_types::DiagnosticSeverity;
+use lsp_types::Hover;
+use lsp_types::HoverContents;
+use lsp_types::InlayHint;
+use lsp_types::InlayHintLabel;
+use lsp_types::InlayHintTooltip;
+use lsp_types::MarkupContent;
+use lsp_types::MarkupKind;
+use lsp_types::Position;
+use lsp_types::Range;
+use nom::error::VerboseErrorKind;
+use triton_vm::analysis::StackAnalysis;
+use triton_vm::instruction::AnInstruction;
+use triton_vm::instruction::LabelledInstruction;
+use triton_vm::parser::parse;
+use triton_vm::parser::pretty_print_error;
+use triton_vm::parser::tokenize;
+use triton_vm::program::Program;
+
+use crate::isa;
+
+/// The contexts of the parse errors that refer to labels rather than to the syntax.
+const LABEL_ERROR_CONTEXTS: [&str; 2] = ["duplicate label", "missing label"];
+
+/// The name of the language server, used as the source of all diagnostics.
+const DIAGNOSTICS_SOURCE: &str = "triton-lsp";
+
+/// A source file, analyzed once whenever its text changes.
+#[derive(Debug, Clone)]
+pub(crate) struct Document {
+    text: String,
+
+    /// The byte offsets at which the lines of the text start.
+    line_starts: Vec<usize>,
+
+    /// All tokens of the text, in order. Empty if the text cannot be tokenized.
+    tokens: Vec<Token>,
+
+    /// The spans of the names of all labels, indexed by the labels. Only the first definition of
+    /// a label is recorded.
+    labels: HashMap<String, ops::Range<usize>>,
+
+    diagnostics: Vec<Diagnostic>,
+
+    /// The op stack depth before the first instruction of every line, if the program is valid.
+    stack_depths: Vec<InlayHint>,
+}
+
+/// A [`LabelledInstruction`] together with the span of source code it stems from. Instructions
+/// originating from the same macro invocation share the invocation's span.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Token {
+    item: LabelledInstruction,
+    span: ops::Range<usize>,
+}
+
+impl Document {
+    pub(crate) fn new(text: String) -> Self {
+        let line_starts = [0]
+            .into_iter()
+            .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+
+        let mut document = Self {
+            text,
+            line_starts,
+            tokens: vec![],
+            labels: HashMap::new(),
+            diagnostics: vec![],
+            stack_depths: vec![],
+        };
+        document.tokens = document.tokens();
+        document.labels = document.labels();
+        document.diagnostics = document.parse_diagnostics();
+        document.stack_depths = document.stack_depth_hints();
+        document
+    }
+
+    pub(crate) fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// The range of the definition of the label called by the instruction at the given position.
+    pub(crate) fn definition(&self, position: Position) -> Option<Range> {
+        let token = self.token_at(position)?;
+        let LabelledInstruction::Instruction(AnInstruction::Call(label)) = &token.item else {
+            return None;
+        };
+        let span = self.labels.get(label)?;
+        Some(self.range(span.clone()))
+    }
+
+    /// The documentation of the instruction at the given position.
+    pub(crate) fn hover(&self, position: Position) -> Option<Hover> {
+        let token = self.token_at(position)?;
+        let LabelledInstruction::Instruction(instruction) = &token.item else {
+            return None;
+        };
+
+        let contents = MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: instruction_documentation(instruction),
+        };
+        let hover = Hover {
+            contents: HoverContents::Markup(contents),
+            range: Some(self.range(token.span.clone())),
+        };
+        Some(hover)
+    }
+
+    /// The op stack depths before the first instruction of every line in the given range.
+    pub(crate) fn inlay_hints(&self, range: Range) -> Vec<InlayHint> {
+        self.stack_depths
+            .iter()
+            .filter(|hint| range.start <= hint.position && hint.position <= range.end)
+            .cloned()
+            .collect()
+    }
+
+    fn tokens(&self) -> Vec<Token> {
+        let Ok((_, tokens)) = tokenize(&self.text) else {
+            return vec![];
+        };
+
+        let starts = tokens
+            .iter()
+            .map(|token| self.offset_of_remainder(token.token_str()))
+            .collect_vec();
+        let distinct_starts = starts.iter().copied().collect::<BTreeSet<_>>();
+
+        tokens
+            .iter()
+            .zip_eq(starts)
+            .map(|(token, start)| {
+                let next_start = distinct_starts.range(start + 1..).next().copied();
+                let span = start..self.span_end(start, next_start);
+                let item = token.to_labelled_instruction();
+                Token { item, span }
+            })
+            .collect()
+    }
+
+    fn labels(&self) -> HashMap<String, ops::Range<usize>> {
+        let mut labels = HashMap::new();
+        for token in &self.tokens {
+            let LabelledInstruction::Label(label) = &token.item else {
+                continue;
+            };
+            let name_span = token.span.start..token.span.start + label.len();
+            labels.entry(label.clone()).or_insert(name_span);
+        }
+        labels
+    }
+
+    fn parse_diagnostics(&self) -> Vec<Diagnostic> {
+        let Err(parse_error) = parse(&self.text) else {
+            return vec![];
+        };
+
+        let errors = &parse_error.errors.errors;
+        let is_label_error = |kind: &VerboseErrorKind| match kind {
+            VerboseErrorKind::Context(context) => LABEL_ERROR_CONTEXTS.contains(context),
+            _ => false,
+        };
+        if errors.iter().all(|(_, kind)| is_label_error(kind)) {
+            return errors
+                .iter()
+                .map(|&(remainder, ref kind)| {
+                    let start = self.offset_of_remainder(remainder);
+                    let span = self.token_span_starting_at(start);
+                    self.error_diagnostic(span, context_message(kind).unwrap_or_default())
+                })
+                .collect();
+        }
+
+        // The innermost error comes first and is the most precise about the location.
+        let Some(&(remainder, _)) = errors.first() else {
+            return vec![];
+        };
+        let message = errors
+            .iter()
+            .find_map(|(_, kind)| context_message(kind))
+            .unwrap_or_else(|| pretty_print_error(&self.text, parse_error.errors.clone()));
+        let span = self.word_span_starting_at(self.offset_of_remainder(remainder));
+        vec![self.error_diagnostic(span, message)]
+    }
+
+    fn error_diagnostic(&self, span: ops::Range<usize>, message: String) -> Diagnostic {
+        Diagnostic {
+            range: self.range(span),
+            severity: Some(DiagnosticSeverity::ERROR),
+            source: Some(DIAGNOSTICS_SOURCE.to_string()),
+            message,
+            ..Diagnostic::default()
+        }
+    }
+
+    fn stack_depth_hints(&self) -> Vec<InlayHint> {
+        let Ok(program) = Program::from_code(&self.text) else {
+            return vec![];
+        };
+        let analysis = StackAnalysis::new(&program);
+
+        // the first instruction of every line, identified by its column, and the depth before it
+        let mut first_instruction_of_line = BTreeMap::new();
+        for address in analysis.control_flow_graph().addresses() {
+            let Some(location) = program.source_location(address) else {
+                continue;
+            };
+            let Some(depth) = analysis.depth_at(address) else {
+                continue;
+            };
+            let first = first_instruction_of_line
+                .entry(location.line)
+                .or_insert((location.column, depth));
+            if location.column < first.0 {
+                *first = (location.column, depth);
+            }
+        }
+
+        first_instruction_of_line
+            .into_iter()
+            .map(|(line, (column, depth))| InlayHint {
+                position: self.position_of_line_and_column(line, column),
+                label: InlayHintLabel::String(format!("[{depth}]")),
+                kind: None,
+                text_edits: None,
+                tooltip: Some(InlayHintTooltip::String("op stack depth".to_string())),
+                padding_left: None,
+                padding_right: Some(true),
+                data: None,
+            })
+            .collect()
+    }
+
+    fn token_at(&self, position: Position) -> Option<&Token> {
+        let offset = self.offset(position)?;
+        self.tokens
+            .iter()
+            .find(|token| token.span.start <= offset && offset <= token.span.end)
+    }
+
+    /// Like all parsed tokens, the remainder must be a suffix of the text.
+    fn offset_of_remainder(&self, remainder: &str) -> usize {
+        self.text.len() - remainder.len()
+    }
+
+    /// A token spans at most until the next token or the end of its line, whichever comes first,
+    /// excluding trailing comments and whitespace.
+    fn span_end(&self, start: usize, next_start: Option<usize>) -> usize {
+        let line_end = self.text[start..]
+            .find('\n')
+            .map_or(self.text.len(), |index| start + index);
+        let end = next_start.map_or(line_end, |next_start| next_start.min(line_end));
+        let span = &self.text[start..end];
+        let code = span.split_once("//").map_or(span, |(code, _)| code);
+        start + code.trim_end().len()
+    }
+
+    fn token_span_starting_at(&self, start: usize) -> ops::Range<usize> {
+        self.tokens
+            .iter()
+            .find(|token| token.span.start == start)
+            .map_or_else(
+                || self.word_span_starting_at(start),
+                |token| token.span.clone(),
+            )
+    }
+
+    fn word_span_starting_at(&self, start: usize) -> ops::Range<usize> {
+        let word_len = self.text[start..]
+            .find(char::is_whitespace)
+            .unwrap_or(self.text.len() - start);
+        start..start + word_len
+    }
+
+    fn range(&self, span: ops::Range<usize>) -> Range {
+        Range::new(self.position(span.start), self.position(span.end))
+    }
+
+    /// The position of the given byte offset. Following the LSP default, columns are counted in
+    /// UTF-16 code units.
+    fn position(&self, offset: usize) -> Position {
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let line_start = self.line_starts[line];
+        let character = self.text[line_start..offset].encode_utf16().count();
+        Position::new(line.try_into().unwrap(), character.try_into().unwrap())
+    }
+
+    /// The byte offset of the given position, if it is within the text.
+    fn offset(&self, position: Position) -> Option<usize> {
+        let line = usize::try_from(position.line).ok()?;
+        let line_start = *self.line_starts.get(line)?;
+        let line_end = self
+            .line_starts
+            .get(line + 1)
+            .map_or(self.text.len(), |next_line_start| next_line_start - 1);
+
+        let mut num_code_units = 0;
+        for (index, char) in self.text[line_start..line_end].char_indices() {
+            if num_code_units >= position.character as usize {
+                return Some(line_start + index);
+            }
+            num_code_units += char.len_utf16();
+        }
+        Some(line_end)
+    }
+
+    /// The position of a 1-based line and a 1-based column counted in characters, as used by
+    /// [`SourceLocation`](triton_vm::parser::SourceLocation).
+    fn position_of_line_and_column(&self, line: usize, column: usize) -> Position {
+        let line_start = self.line_starts[line - 1];
+        let offset = self.text[line_start..]
+            .char_indices()
+            .nth(column - 1)
+            .map_or(self.text.len(), |(index, _)| line_start + index);
+        self.position(offset)
+    }
+}
+
+fn context_message(kind: &VerboseErrorKind) -> Option<String> {
+    match kind {
+        VerboseErrorKind::Context(context) => Some(context.to_string()),
+        _ => None,
+    }
+}
+
+fn instruction_documentation(instruction: &AnInstruction<String>) -> String {
+    let size = match instruction.size() {
+        1 => "1 word".to_string(),
+        size => format!("{size} words"),
+    };
+    let influence = instruction.op_stack_size_influence();
+    let mut documentation = format!(
+        "```tasm\n{instruction}\n```\n\n\
+        Size: {size}. Changes the op stack size by {influence:+}."
+    );
+
+    let instruction = instruction.to_string();
+    let name = instruction.split_whitespace().next().unwrap_or_default();
+    if let Some(isa_documentation) = isa::documentation(name) {
+        documentation.push_str("\n\n---\n\n");
+        documentation.push_str(isa_documentation);
+    }
+    documentation
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::assert;
+    use assert2::let_assert;
+
+    use super::*;
+
+    fn position_of(document: &Document, needle: &str) -> Position {
+        let_assert!(Some(offset) = document.text.find(needle));
+        document.position(offset)
+    }
+
+    #[test]
+    fn valid_code_has_no_diagnostics() {
+        let document = Document::new("push 1 call foo halt\nfoo: return".to_string());
+        assert!(document.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn missing_label_is_reported_at_call() {
+        let document = Document::new("push 1\ncall foo // call it\nhalt".to_string());
+        let_assert!([diagnostic] = document.diagnostics());
+        assert!("missing label" == diagnostic.message);
+        assert!(Some(DiagnosticSeverity::ERROR) == diagnostic.severity);
+        assert!(Range::new(Position::new(1, 0), Position::new(1, 8)) == diagnostic.range);
+    }
+
+    #[test]
+    fn duplicate_labels_are_reported_at_every_definition() {
+        let document = Document::new("foo: halt\nfoo: halt".to_string());
+        let diagnostics = document.diagnostics();
+        assert!(2 == diagnostics.len());
+        for diagnostic in diagnostics {
+            assert!("duplicate label" == diagnostic.message);
+        }
+        let lines = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.range.start.line)
+            .sorted()
+            .collect_vec();
+        assert!(vec![0, 1] == lines);
+    }
+
+    #[test]
+    fn bad_argument_is_reported() {
+        let document = Document::new("push 1\nswap 0\nhalt".to_string());
+        let_assert!([diagnostic] = document.diagnostics());
+        assert!("instruction `swap` cannot take argument `0`" == diagnostic.message);
+        assert!(1 == diagnostic.range.start.line);
+    }
+
+    #[test]
+    fn definition_of_call_target_is_the_label() {
+        let document = Document::new("call foo halt\nfoo:\n    return".to_string());
+        let call = position_of(&document, "foo");
+        let_assert!(Some(definition) = document.definition(call));
+        assert!(Range::new(Position::new(1, 0), Position::new(1, 3)) == definition);
+    }
+
+    #[test]
+    fn labels_and_other_instructions_have_no_definition() {
+        let document = Document::new("call foo halt\nfoo: return".to_string());
+        assert!(document
+            .definition(position_of(&document, "halt"))
+            .is_none());
+        assert!(document
+            .definition(position_of(&document, "foo:"))
+            .is_none());
+    }
+
+    #[test]
+    fn hover_shows_instruction_documentation() {
+        let document = Document::new("push 1 push 2 add halt".to_string());
+        let_assert!(Some(hover) = document.hover(position_of(&document, "add")));
+        let_assert!(HoverContents::Markup(contents) = hover.contents);
+        assert!(contents
+            .value
+            .contains("Size: 1 word. Changes the op stack size by -1."));
+        assert!(contents.value.contains("Opcode"));
+
+        let_assert!(Some(hover) = document.hover(position_of(&document, "push 2")));
+        let_assert!(HoverContents::Markup(contents) = hover.contents);
+        assert!(contents
+            .value
+            .contains("Size: 2 words. Changes the op stack size by +1."));
+        assert!(Some(Range::new(Position::new(0, 7), Position::new(0, 13))) == hover.range);
+    }
+
+    #[test]
+    fn inlay_hints_show_stack_depth_before_first_instruction_of_line() {
+        let document = Document::new("push 1 push 2\nadd\npop 1 halt".to_string());
+        let whole_document = Range::new(Position::new(0, 0), Position::new(3, 0));
+        let hints = document.inlay_hints(whole_document);
+        let hints = hints
+            .into_iter()
+            .map(|hint| {
+                let_assert!(InlayHintLabel::String(label) = hint.label);
+                (hint.position, label)
+            })
+            .collect_vec();
+        let expected_hints = vec![
+            (Position::new(0, 0), "[16]".to_string()),
+            (Position::new(1, 0), "[18]".to_string()),
+            (Position::new(2, 0), "[17]".to_string()),
+        ];
+        assert!(expected_hints == hints);
+    }
+
+    #[test]
+    fn inlay_hints_are_restricted_to_requested_range() {
+        let document = Document::new("push 1 push 2\nadd\npop 1 halt".to_string());
+        let second_line = Range::new(Position::new(1, 0), Position::new(1, 3));
+        let hints = document.inlay_hints(second_line);
+        let_assert!([hint] = hints.as_slice());
+        assert!(Position::new(1, 0) == hint.position);
+    }
+
+    #[test]
+    fn positions_are_counted_in_utf16_code_units() {
+        let document = Document::new("// 𝔽 ∋ x\npush 1 // 𝔽\nhalt".to_string());
+        let_assert!(Some(offset) = document.text.find('x'));
+        let position = document.position(offset);
+        assert!(Position::new(0, 8) == position);
+        assert!(Some(offset) == document.offset(position));
+    }
+}
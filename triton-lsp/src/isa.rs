@@ -0,0 +1,124 @@
+//! Documentation of Triton VM's instructions, extracted from the specification.
+
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use lazy_static::lazy_static;
+
+const INSTRUCTIONS_SPECIFICATION: &str = include_str!("../../specification/src/instructions.md");
+
+lazy_static! {
+    static ref DOCUMENTATION: HashMap<String, String> =
+        documentation_from_specification(INSTRUCTIONS_SPECIFICATION);
+}
+
+/// The documentation of the instruction with the given name, formatted as Markdown.
+pub(crate) fn documentation(instruction_name: &str) -> Option<&'static str> {
+    DOCUMENTATION.get(instruction_name).map(String::as_str)
+}
+
+/// Collect the rows of all tables in the specification that describe instructions. Such tables
+/// have a column “Instruction” followed by columns like “Opcode”, “old op stack”, and
+/// “Description”.
+fn documentation_from_specification(specification: &str) -> HashMap<String, String> {
+    let mut documentation = HashMap::new();
+    let mut header: Option<Vec<&str>> = None;
+    for line in specification.lines() {
+        let Some(cells) = table_cells(line) else {
+            header = None;
+            continue;
+        };
+        let Some(columns) = &header else {
+            header = (cells.first() == Some(&"Instruction")).then_some(cells);
+            continue;
+        };
+        if is_delimiter_row(&cells) {
+            continue;
+        }
+
+        let Some(name) = instruction_name(cells[0]) else {
+            continue;
+        };
+        let entry = documentation_entry(columns, &cells);
+        documentation.insert(name.to_string(), entry);
+    }
+
+    documentation
+}
+
+/// The trimmed cells of a Markdown table row, or `None` if the line is not a table row.
+fn table_cells(line: &str) -> Option<Vec<&str>> {
+    let row = line.trim().strip_prefix('|')?.strip_suffix('|')?;
+    Some(row.split('|').map(str::trim).collect())
+}
+
+fn is_delimiter_row(cells: &[&str]) -> bool {
+    cells
+        .iter()
+        .all(|cell| cell.chars().all(|c| matches!(c, ':' | '-')))
+}
+
+/// The name of the instruction in a cell like “`` `push` + `a` ``”.
+fn instruction_name(cell: &str) -> Option<&str> {
+    let (_, rest) = cell.split_once('`')?;
+    let (name, _) = rest.split_once('`')?;
+    Some(name)
+}
+
+fn documentation_entry(columns: &[&str], cells: &[&str]) -> String {
+    let mut description = "";
+    let mut properties = vec![];
+    for (&column, &cell) in columns.iter().zip(cells).skip(1) {
+        match column {
+            "Description" => description = cell,
+            _ => properties.push(format!("- {column}: {cell}")),
+        }
+    }
+
+    let signature = cells[0];
+    let properties = properties.iter().join("\n");
+    format!("{signature}\n\n{properties}\n\n{description}")
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::assert;
+    use assert2::let_assert;
+    use triton_vm::instruction::ALL_INSTRUCTION_NAMES;
+
+    use super::*;
+
+    #[test]
+    fn every_instruction_is_documented() {
+        for name in ALL_INSTRUCTION_NAMES {
+            assert!(
+                documentation(name).is_some(),
+                "undocumented instruction: {name}"
+            );
+        }
+    }
+
+    #[test]
+    fn documentation_contains_description_and_opcode() {
+        let_assert!(Some(documentation) = documentation("push"));
+        assert!(documentation.starts_with("`push` + `a`"));
+        assert!(documentation.contains("- Opcode: 1"));
+        assert!(documentation.contains("Pushes `a` onto the stack."));
+    }
+
+    #[test]
+    fn tables_not_describing_instructions_are_ignored() {
+        let specification = "\
+| instruction  | old op stack |
+|:-------------|:-------------|
+| `read_mem 1` | `_ p`        |
+
+| Instruction | Opcode | Description |
+|:------------|-------:|:------------|
+| `nop`       |      8 | Do nothing  |
+";
+        let documentation = documentation_from_specification(specification);
+        assert!(1 == documentation.len());
+        assert!("`nop`\n\n- Opcode: 8\n\nDo nothing" == documentation["nop"]);
+    }
+}
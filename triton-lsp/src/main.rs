@@ -0,0 +1,27 @@
+//! A [language server](https://microsoft.github.io/language-server-protocol/) for
+//! [Triton assembly](https://triton-vm.org/spec/instructions.html), communicating over stdio.
+//!
+//! The server provides
+//! - diagnostics for syntax errors, bad instruction arguments, and missing or duplicate labels,
+//! - go-to-definition for the targets of `call`s,
+//! - documentation of instructions on hover, and
+//! - the op stack depth at the start of every line as inlay hints.
+
+use anyhow::Result;
+use lsp_server::Connection;
+
+use crate::server::Server;
+
+mod document;
+mod isa;
+mod server;
+
+fn main() -> Result<()> {
+    let (connection, io_threads) = Connection::stdio();
+    let capabilities = serde_json::to_value(Server::capabilities())?;
+    connection.initialize(capabilities)?;
+
+    Server::new(connection).run()?;
+    io_threads.join()?;
+    Ok(())
+}
@@ -0,0 +1,360 @@
+//! The language server's main loop: receiving messages, keeping track of open documents, and
+//! answering requests.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use lsp_server::Connection;
+use lsp_server::ErrorCode;
+use lsp_server::Message;
+use lsp_server::Notification;
+use lsp_server::Request;
+use lsp_server::Response;
+use lsp_types::notification::DidChangeTextDocument;
+use lsp_types::notification::DidCloseTextDocument;
+use lsp_types::notification::DidOpenTextDocument;
+use lsp_types::notification::Notification as _;
+use lsp_types::notification::PublishDiagnostics;
+use lsp_types::request::GotoDefinition;
+use lsp_types::request::HoverRequest;
+use lsp_types::request::InlayHintRequest;
+use lsp_types::request::Request as _;
+use lsp_types::Diagnostic;
+use lsp_types::GotoDefinitionParams;
+use lsp_types::GotoDefinitionResponse;
+use lsp_types::Hover;
+use lsp_types::HoverParams;
+use lsp_types::HoverProviderCapability;
+use lsp_types::InlayHint;
+use lsp_types::InlayHintParams;
+use lsp_types::Location;
+use lsp_types::OneOf;
+use lsp_types::PublishDiagnosticsParams;
+use lsp_types::ServerCapabilities;
+use lsp_types::TextDocumentSyncCapability;
+use lsp_types::TextDocumentSyncKind;
+use lsp_types::Url;
+
+use crate::document::Document;
+
+/// A language server for Triton assembly, communicating over some [`Connection`].
+pub(crate) struct Server {
+    connection: Connection,
+
+    /// All currently open documents, indexed by their URIs.
+    documents: HashMap<Url, Document>,
+}
+
+impl Server {
+    pub(crate) fn new(connection: Connection) -> Self {
+        Self {
+            connection,
+            documents: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn capabilities() -> ServerCapabilities {
+        ServerCapabilities {
+            text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            definition_provider: Some(OneOf::Left(true)),
+            inlay_hint_provider: Some(OneOf::Left(true)),
+            ..ServerCapabilities::default()
+        }
+    }
+
+    /// Handle messages until the client shuts the server down or closes the connection.
+    pub(crate) fn run(mut self) -> Result<()> {
+        while let Ok(message) = self.connection.receiver.recv() {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    self.handle_request(request)?;
+                }
+                Message::Notification(notification) => self.handle_notification(notification)?,
+                Message::Response(_) => (),
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_request(&self, request: Request) -> Result<()> {
+        let response = match request.method.as_str() {
+            GotoDefinition::METHOD => self.respond::<GotoDefinition>(request, Self::definition),
+            HoverRequest::METHOD => self.respond::<HoverRequest>(request, Self::hover),
+            InlayHintRequest::METHOD => {
+                self.respond::<InlayHintRequest>(request, Self::inlay_hints)
+            }
+            method => {
+                let code = ErrorCode::MethodNotFound as i32;
+                let message = format!("unsupported request: {method}");
+                Response::new_err(request.id, code, message)
+            }
+        };
+        self.connection.sender.send(response.into())?;
+        Ok(())
+    }
+
+    /// Answer the request using the given handler. A request with malformed parameters is answered
+    /// with an error.
+    fn respond<R: lsp_types::request::Request>(
+        &self,
+        request: Request,
+        handler: fn(&Self, R::Params) -> R::Result,
+    ) -> Response {
+        let id = request.id.clone();
+        match request.extract(R::METHOD) {
+            Ok((id, params)) => Response::new_ok(id, handler(self, params)),
+            Err(err) => {
+                let code = ErrorCode::InvalidParams as i32;
+                let message = format!("malformed request: {err:?}");
+                Response::new_err(id, code, message)
+            }
+        }
+    }
+
+    fn handle_notification(&mut self, notification: Notification) -> Result<()> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let Some(params) = Self::params::<DidOpenTextDocument>(notification) else {
+                    return Ok(());
+                };
+                let document = params.text_document;
+                self.update_document(document.uri, document.version, document.text)
+            }
+            DidChangeTextDocument::METHOD => {
+                let Some(mut params) = Self::params::<DidChangeTextDocument>(notification) else {
+                    return Ok(());
+                };
+                let Some(change) = params.content_changes.pop() else {
+                    return Ok(());
+                };
+
+                // With full synchronization, every change contains the entire text.
+                let document = params.text_document;
+                self.update_document(document.uri, document.version, change.text)
+            }
+            DidCloseTextDocument::METHOD => {
+                let Some(params) = Self::params::<DidCloseTextDocument>(notification) else {
+                    return Ok(());
+                };
+                let uri = params.text_document.uri;
+                self.documents.remove(&uri);
+                self.publish_diagnostics(uri, vec![], None)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The parameters of the given notification. Since notifications cannot be answered, malformed
+    /// ones are logged and otherwise ignored.
+    fn params<N: lsp_types::notification::Notification>(
+        notification: Notification,
+    ) -> Option<N::Params> {
+        match notification.extract(N::METHOD) {
+            Ok(params) => Some(params),
+            Err(err) => {
+                eprintln!("ignoring malformed notification: {err:?}");
+                None
+            }
+        }
+    }
+
+    fn update_document(&mut self, uri: Url, version: i32, text: String) -> Result<()> {
+        let document = Document::new(text);
+        let diagnostics = document.diagnostics().to_vec();
+        self.documents.insert(uri.clone(), document);
+        self.publish_diagnostics(uri, diagnostics, Some(version))
+    }
+
+    fn publish_diagnostics(
+        &self,
+        uri: Url,
+        diagnostics: Vec<Diagnostic>,
+        version: Option<i32>,
+    ) -> Result<()> {
+        let params = PublishDiagnosticsParams::new(uri, diagnostics, version);
+        let notification = Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+        self.connection.sender.send(notification.into())?;
+        Ok(())
+    }
+
+    fn definition(&self, params: GotoDefinitionParams) -> Option<GotoDefinitionResponse> {
+        let params = params.text_document_position_params;
+        let uri = params.text_document.uri;
+        let range = self.documents.get(&uri)?.definition(params.position)?;
+        Some(GotoDefinitionResponse::Scalar(Location::new(uri, range)))
+    }
+
+    fn hover(&self, params: HoverParams) -> Option<Hover> {
+        let params = params.text_document_position_params;
+        self.documents
+            .get(&params.text_document.uri)?
+            .hover(params.position)
+    }
+
+    fn inlay_hints(&self, params: InlayHintParams) -> Option<Vec<InlayHint>> {
+        let document = self.documents.get(&params.text_document.uri)?;
+        Some(document.inlay_hints(params.range))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::thread::JoinHandle;
+
+    use assert2::assert;
+    use assert2::let_assert;
+    use lsp_server::RequestId;
+    use lsp_types::notification::Exit;
+    use lsp_types::request::Shutdown;
+    use lsp_types::DidOpenTextDocumentParams;
+    use lsp_types::HoverContents;
+    use lsp_types::Position;
+    use lsp_types::TextDocumentIdentifier;
+    use lsp_types::TextDocumentItem;
+    use lsp_types::TextDocumentPositionParams;
+    use lsp_types::WorkDoneProgressParams;
+
+    use super::*;
+
+    /// A client talking to a [`Server`] running in a separate thread.
+    struct TestClient {
+        connection: Connection,
+        server: JoinHandle<Result<()>>,
+        next_request_id: i32,
+    }
+
+    impl TestClient {
+        fn start() -> Self {
+            let (server_connection, connection) = Connection::memory();
+            let server = thread::spawn(|| Server::new(server_connection).run());
+            Self {
+                connection,
+                server,
+                next_request_id: 0,
+            }
+        }
+
+        fn notify<N: lsp_types::notification::Notification>(&self, params: N::Params) {
+            let notification = Notification::new(N::METHOD.to_string(), params);
+            let_assert!(Ok(()) = self.connection.sender.send(notification.into()));
+        }
+
+        fn request<R: lsp_types::request::Request>(&mut self, params: R::Params) -> Response {
+            let id = RequestId::from(self.next_request_id);
+            self.next_request_id += 1;
+            let request = Request::new(id.clone(), R::METHOD.to_string(), params);
+            let_assert!(Ok(()) = self.connection.sender.send(request.into()));
+
+            let_assert!(Ok(Message::Response(response)) = self.connection.receiver.recv());
+            assert!(id == response.id);
+            response
+        }
+
+        fn open(&self, uri: &Url, text: &str) {
+            let text_document = TextDocumentItem::new(uri.clone(), "tasm".into(), 0, text.into());
+            self.notify::<DidOpenTextDocument>(DidOpenTextDocumentParams { text_document });
+        }
+
+        fn receive_diagnostics(&self) -> PublishDiagnosticsParams {
+            let_assert!(Ok(Message::Notification(notification)) = self.connection.receiver.recv());
+            assert!(PublishDiagnostics::METHOD == notification.method);
+            let_assert!(Ok(params) = serde_json::from_value(notification.params));
+            params
+        }
+
+        fn shut_down(mut self) {
+            let response = self.request::<Shutdown>(());
+            assert!(response.error.is_none());
+            self.notify::<Exit>(());
+            let_assert!(Ok(Ok(())) = self.server.join());
+        }
+    }
+
+    fn uri() -> Url {
+        let_assert!(Ok(uri) = Url::parse("file:///program.tasm"));
+        uri
+    }
+
+    #[test]
+    fn opening_document_publishes_diagnostics() {
+        let client = TestClient::start();
+        client.open(&uri(), "call foo halt");
+
+        let diagnostics = client.receive_diagnostics();
+        assert!(uri() == diagnostics.uri);
+        let_assert!([diagnostic] = diagnostics.diagnostics.as_slice());
+        assert!("missing label" == diagnostic.message);
+
+        client.shut_down();
+    }
+
+    #[test]
+    fn hover_request_is_answered() {
+        let mut client = TestClient::start();
+        client.open(&uri(), "push 1 halt");
+        assert!(client.receive_diagnostics().diagnostics.is_empty());
+
+        let text_document = TextDocumentIdentifier::new(uri());
+        let position = Position::new(0, 8);
+        let text_document_position_params =
+            TextDocumentPositionParams::new(text_document, position);
+        let params = HoverParams {
+            text_document_position_params,
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        };
+        let response = client.request::<HoverRequest>(params);
+
+        let_assert!(Some(result) = response.result);
+        let_assert!(Ok(Some(hover)) = serde_json::from_value::<Option<Hover>>(result));
+        let_assert!(HoverContents::Markup(contents) = hover.contents);
+        assert!(contents.value.contains("halt"));
+
+        client.shut_down();
+    }
+
+    #[test]
+    fn unsupported_request_is_rejected() {
+        let client = TestClient::start();
+        let request = Request::new(RequestId::from(0), "unsupported".to_string(), ());
+        let_assert!(Ok(()) = client.connection.sender.send(request.into()));
+
+        let_assert!(Ok(Message::Response(response)) = client.connection.receiver.recv());
+        let_assert!(Some(error) = response.error);
+        assert!(ErrorCode::MethodNotFound as i32 == error.code);
+
+        client.shut_down();
+    }
+
+    #[test]
+    fn malformed_request_is_rejected_and_server_keeps_running() {
+        let client = TestClient::start();
+        let id = RequestId::from(0);
+        let request = Request::new(id.clone(), HoverRequest::METHOD.to_string(), "garbage");
+        let_assert!(Ok(()) = client.connection.sender.send(request.into()));
+
+        let_assert!(Ok(Message::Response(response)) = client.connection.receiver.recv());
+        assert!(id == response.id);
+        let_assert!(Some(error) = response.error);
+        assert!(ErrorCode::InvalidParams as i32 == error.code);
+
+        client.shut_down();
+    }
+
+    #[test]
+    fn malformed_notification_is_ignored() {
+        let client = TestClient::start();
+        let method = DidOpenTextDocument::METHOD.to_string();
+        let notification = Notification::new(method, "garbage");
+        let_assert!(Ok(()) = client.connection.sender.send(notification.into()));
+
+        client.open(&uri(), "halt");
+        assert!(client.receive_diagnostics().diagnostics.is_empty());
+
+        client.shut_down();
+    }
+}
@@ -0,0 +1,48 @@
+//! # [Triton VM] Example: Estimating the Cost of Proving
+//!
+//! This example demonstrates how to predict the time, memory, and proof size of a proof without
+//! generating it. The prediction is based on a calibration, which is measured once on the current
+//! machine and stored in a file. Pass the path of that file as the first argument.
+//!
+//! [Triton VM]: https://triton-vm.org/
+
+use std::env;
+use std::path::Path;
+
+use triton_vm::estimator::Calibration;
+use triton_vm::example_programs::FIBONACCI_SEQUENCE;
+use triton_vm::prelude::*;
+use triton_vm::profiler::TrackingAllocator;
+
+// Tracking allocations is required for estimating the peak memory.
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator::system();
+
+fn main() {
+    TrackingAllocator::enable();
+    let calibration_file = env::args().nth(1);
+    let calibration_file = calibration_file
+        .as_deref()
+        .unwrap_or("prover_calibration.json");
+    let stark = Stark::default();
+
+    // Timing a few small proofs takes a while. Re-use the calibration if possible.
+    let calibration = if Path::new(calibration_file).exists() {
+        Calibration::load(calibration_file).unwrap()
+    } else {
+        println!("Calibrating – this can take a few minutes…");
+        let calibration = Calibration::measure(stark, 10..=13).unwrap();
+        calibration.save(calibration_file).unwrap();
+        calibration
+    };
+
+    // Profiling is cheap compared to proving.
+    let public_input = PublicInput::from([bfe!(1_000_000)]);
+    let (_, profile) = FIBONACCI_SEQUENCE
+        .profile(public_input, NonDeterminism::default())
+        .unwrap();
+    let estimate = calibration.estimate_profile(stark, &profile).unwrap();
+
+    println!("Estimated cost of proving the 1_000_000th Fibonacci number:");
+    println!("{estimate}");
+}
@@ -154,7 +154,7 @@ impl AlgebraicExecutionTrace {
         height.try_into().unwrap()
     }
 
-    fn padded_program_length(program: &Program) -> usize {
+    pub(crate) fn padded_program_length(program: &Program) -> usize {
         // Padding is at least one 1.
         // Also note that the Program Table's side of the instruction lookup argument requires at
         // least one padding row to account for the processor's “next instruction or argument.”
@@ -164,14 +164,8 @@ impl AlgebraicExecutionTrace {
 
     /// Hash the program and record the entire Sponge's trace for program attestation.
     fn fill_program_hash_trace(&mut self) {
-        let padded_program = Self::hash_input_pad_program(&self.program);
-        let mut program_sponge = Tip5::init();
-        for chunk in padded_program.chunks(Tip5::RATE) {
-            program_sponge.state[..Tip5::RATE]
-                .iter_mut()
-                .zip_eq(chunk)
-                .for_each(|(sponge_state_elem, &absorb_elem)| *sponge_state_elem = absorb_elem);
-            let hash_trace = program_sponge.trace();
+        let program_hash_traces = Self::program_hash_traces(&self.program);
+        for &hash_trace in &program_hash_traces {
             let trace_addendum = HashTable::trace_to_table_rows(hash_trace);
 
             self.increase_lookup_multiplicities(hash_trace);
@@ -185,7 +179,8 @@ impl AlgebraicExecutionTrace {
         instruction_column.fill(Instruction::Hash.opcode_b());
 
         // consistency check
-        let program_digest = program_sponge.state[..tip5::DIGEST_LENGTH]
+        let final_sponge_state = program_hash_traces.last().and_then(|trace| trace.last());
+        let program_digest = final_sponge_state.unwrap()[..tip5::DIGEST_LENGTH]
             .try_into()
             .unwrap();
         let program_digest = Digest::new(program_digest);
@@ -193,6 +188,23 @@ impl AlgebraicExecutionTrace {
         assert_eq!(expected_digest, program_digest);
     }
 
+    /// The traces of the Sponge's permutations when hashing the given program for program
+    /// attestation, one per absorbed chunk.
+    pub(crate) fn program_hash_traces(program: &Program) -> Vec<PermutationTrace> {
+        let padded_program = Self::hash_input_pad_program(program);
+        let mut program_sponge = Tip5::init();
+        let mut program_hash_traces = vec![];
+        for chunk in padded_program.chunks(Tip5::RATE) {
+            program_sponge.state[..Tip5::RATE]
+                .iter_mut()
+                .zip_eq(chunk)
+                .for_each(|(sponge_state_elem, &absorb_elem)| *sponge_state_elem = absorb_elem);
+            program_hash_traces.push(program_sponge.trace());
+        }
+
+        program_hash_traces
+    }
+
     fn hash_input_pad_program(program: &Program) -> Vec<BFieldElement> {
         let padded_program_length = Self::padded_program_length(program);
 
@@ -280,25 +292,7 @@ impl AlgebraicExecutionTrace {
     /// - lookup table was looked up
     /// and increases the multiplicities accordingly
     fn increase_lookup_multiplicities(&mut self, trace: PermutationTrace) {
-        // The last row in the trace is the permutation's result: no lookups are performed for it.
-        let rows_for_which_lookups_are_performed = trace.iter().dropping_back(1);
-        for row in rows_for_which_lookups_are_performed {
-            self.increase_lookup_multiplicities_for_row(row);
-        }
-    }
-
-    /// Given one row of the hash function's permutation trace, increase the multiplicities of the
-    /// relevant entries in the cascade table and/or the lookup table.
-    fn increase_lookup_multiplicities_for_row(&mut self, row: &[BFieldElement; tip5::STATE_SIZE]) {
-        for &state_element in &row[0..tip5::NUM_SPLIT_AND_LOOKUP] {
-            self.increase_lookup_multiplicities_for_state_element(state_element);
-        }
-    }
-
-    /// Given one state element, increase the multiplicities of the corresponding entries in the
-    /// cascade table and/or the lookup table.
-    fn increase_lookup_multiplicities_for_state_element(&mut self, state_element: BFieldElement) {
-        for limb in HashTable::base_field_element_into_16_bit_limbs(state_element) {
+        for limb in Self::cascade_table_lookups(&trace) {
             match self.cascade_table_lookup_multiplicities.entry(limb) {
                 Occupied(mut cascade_table_entry) => *cascade_table_entry.get_mut() += 1,
                 Vacant(cascade_table_entry) => {
@@ -309,6 +303,19 @@ impl AlgebraicExecutionTrace {
         }
     }
 
+    /// The 16-bit limbs that are looked up in the cascade table for the given trace of the hash
+    /// function's permutation, in order and including repetitions.
+    pub(crate) fn cascade_table_lookups(
+        trace: &PermutationTrace,
+    ) -> impl Iterator<Item = u16> + '_ {
+        // The last row in the trace is the permutation's result: no lookups are performed for it.
+        let rows_for_which_lookups_are_performed = trace.iter().dropping_back(1);
+        rows_for_which_lookups_are_performed
+            .flat_map(|row| &row[0..tip5::NUM_SPLIT_AND_LOOKUP])
+            .copied()
+            .flat_map(HashTable::base_field_element_into_16_bit_limbs)
+    }
+
     /// Given one 16-bit limb, increase the multiplicities of the corresponding entries in the
     /// lookup table.
     fn increase_lookup_table_multiplicities_for_limb(&mut self, limb: u16) {
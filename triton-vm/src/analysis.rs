@@ -0,0 +1,594 @@
+//! Static analysis of the operational stack of [Triton assembly][tasm] programs.
+//!
+//! The [`ControlFlowGraph`] of a [`Program`] describes where execution may continue after each
+//! instruction. Based on it, a [`StackAnalysis`] computes the depth of the op stack before each
+//! reachable instruction without running the program, and reports
+//! [issues](StackAnalysisError) like paths that make the op stack too shallow, functions that
+//! return with different stack depths, or [type hints](crate::instruction::TypeHint) referring
+//! to stack elements that do not exist.
+//!
+//! Functions are identified by the address of their first instruction, _i.e._, the destination
+//! of the `call`s invoking them. The analysis is performed per function: within a function, the
+//! op stack depth is tracked relative to the function's start. Absolute depths are derived from
+//! the shallowest op stack with which any `call` can enter the function. Execution starts with
+//! an op stack of [`OpStackElement::COUNT`] elements, the lowest of which hold the program's
+//! digest. Since Triton VM never allows the op stack to become shallower than that, doing so is
+//! reported as an [underflow](StackAnalysisError::OpStackUnderflow).
+//!
+//! The op stack effect of recursive `call`s cannot be determined statically. Loops using
+//! `recurse` are fully supported, as long as each iteration leaves the op stack depth unchanged.
+//!
+//! [tasm]: https://triton-vm.org/spec/instructions.html
+
+use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use strum::EnumCount;
+
+use crate::error::StackAnalysisError;
+use crate::instruction::AnInstruction;
+use crate::instruction::Instruction;
+use crate::op_stack::OpStackElement;
+use crate::program::Program;
+
+/// The address of the program's first instruction, where execution starts.
+const ENTRYPOINT: u64 = 0;
+
+/// How execution can continue after some instruction.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ControlFlow {
+    /// Continue with the instruction at the given address.
+    Continue(u64),
+
+    /// Instruction `skiz`: continue with the next instruction if the top of the stack is non-zero,
+    /// or skip it otherwise.
+    Branch { next: u64, skip: u64 },
+
+    /// Instruction `call`: execute the function at `destination`, then continue at
+    /// `return_address`.
+    Call {
+        destination: u64,
+        return_address: u64,
+    },
+
+    /// Instruction `return`: continue after the most recent `call`.
+    Return,
+
+    /// Instruction `recurse`: continue at the start of the current function.
+    Recurse,
+
+    /// Instruction `halt`.
+    Halt,
+}
+
+/// The [`ControlFlow`] after every instruction of a [`Program`].
+///
+/// # Examples
+///
+/// ```
+/// # use triton_vm::prelude::*;
+/// # use triton_vm::analysis::ControlFlow;
+/// # use triton_vm::analysis::ControlFlowGraph;
+/// let program = triton_program!(push 1 skiz call foo halt foo: return);
+/// let control_flow_graph = ControlFlowGraph::new(&program);
+///
+/// let control_flow = control_flow_graph.control_flow_at(2);
+/// assert_eq!(Some(ControlFlow::Branch { next: 3, skip: 5 }), control_flow);
+/// assert_eq!(vec![0, 6], control_flow_graph.function_entries());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ControlFlowGraph {
+    nodes: BTreeMap<u64, (Instruction, ControlFlow)>,
+}
+
+impl ControlFlowGraph {
+    pub fn new(program: &Program) -> Self {
+        let mut instructions = BTreeMap::new();
+        let mut address = 0;
+        for instruction in program.clone() {
+            instructions.insert(address, instruction);
+            address += instruction.size() as u64;
+        }
+
+        let size_at = |address| {
+            instructions
+                .get(&address)
+                .map_or(1, |i: &Instruction| i.size())
+        };
+        let nodes = instructions
+            .iter()
+            .map(|(&address, &instruction)| {
+                let next = address + instruction.size() as u64;
+                let control_flow = match instruction {
+                    AnInstruction::Skiz => ControlFlow::Branch {
+                        next,
+                        skip: next + size_at(next) as u64,
+                    },
+                    AnInstruction::Call(destination) => ControlFlow::Call {
+                        destination: destination.value(),
+                        return_address: next,
+                    },
+                    AnInstruction::Return => ControlFlow::Return,
+                    AnInstruction::Recurse => ControlFlow::Recurse,
+                    AnInstruction::Halt => ControlFlow::Halt,
+                    _ => ControlFlow::Continue(next),
+                };
+                (address, (instruction, control_flow))
+            })
+            .collect();
+
+        Self { nodes }
+    }
+
+    /// The addresses of all instructions, in ascending order.
+    pub fn addresses(&self) -> impl Iterator<Item = u64> + '_ {
+        self.nodes.keys().copied()
+    }
+
+    pub fn instruction_at(&self, address: u64) -> Option<Instruction> {
+        self.nodes
+            .get(&address)
+            .map(|&(instruction, _)| instruction)
+    }
+
+    pub fn control_flow_at(&self, address: u64) -> Option<ControlFlow> {
+        self.nodes
+            .get(&address)
+            .map(|&(_, control_flow)| control_flow)
+    }
+
+    /// The addresses at which functions start: the program's entrypoint, and the destinations of
+    /// all `call`s, in ascending order.
+    pub fn function_entries(&self) -> Vec<u64> {
+        let call_destinations = self.nodes.values().filter_map(|&(_, control_flow)| {
+            let ControlFlow::Call { destination, .. } = control_flow else {
+                return None;
+            };
+            Some(destination)
+        });
+
+        [ENTRYPOINT]
+            .into_iter()
+            .chain(call_destinations)
+            .sorted()
+            .dedup()
+            .collect()
+    }
+}
+
+/// The op stack depth before every reachable instruction of a [`Program`], together with all
+/// [issues](StackAnalysisError) found while computing them. See the
+/// [module-level documentation](self) for details.
+///
+/// # Examples
+///
+/// ```
+/// # use triton_vm::prelude::*;
+/// # use triton_vm::analysis::StackAnalysis;
+/// let program = triton_program!(push 1 call foo halt foo: pop 2 return);
+/// let analysis = StackAnalysis::new(&program);
+///
+/// assert_eq!(Some(17), analysis.depth_at(2));
+/// assert_eq!(1, analysis.errors().len());
+/// println!("{}", analysis.errors()[0]);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct StackAnalysis {
+    control_flow_graph: ControlFlowGraph,
+
+    /// The minimal op stack depth before each reachable instruction, over all paths reaching it.
+    depths: BTreeMap<u64, i64>,
+
+    errors: Vec<StackAnalysisError>,
+}
+
+impl StackAnalysis {
+    pub fn new(program: &Program) -> Self {
+        let control_flow_graph = ControlFlowGraph::new(program);
+        let mut analyzer = Analyzer {
+            control_flow_graph: &control_flow_graph,
+            functions: HashMap::new(),
+            errors: vec![],
+        };
+        let main = analyzer.summarize(ENTRYPOINT, true);
+        let entry_depths = analyzer.entry_depths(&main);
+
+        let mut contexts = vec![(&main, initial_depth())];
+        for (entry, &entry_depth) in &entry_depths {
+            if let Some(Some(function)) = analyzer.functions.get(entry) {
+                contexts.push((function, entry_depth));
+            }
+        }
+
+        let mut depths = BTreeMap::new();
+        for (function, entry_depth) in contexts {
+            for (&address, &relative_depth) in &function.depths {
+                let depth = entry_depth + relative_depth;
+                let min_depth = depths.entry(address).or_insert(depth);
+                *min_depth = depth.min(*min_depth);
+            }
+        }
+
+        let mut errors = analyzer.errors;
+        for (&address, &depth) in &depths {
+            let instruction = control_flow_graph.instruction_at(address).unwrap();
+            let depth_change = i64::from(instruction.op_stack_size_influence());
+            if depth + depth_change < initial_depth() {
+                let err = StackAnalysisError::OpStackUnderflow {
+                    address,
+                    instruction,
+                    depth,
+                };
+                errors.push(err);
+            }
+
+            for type_hint in program.type_hints_at(address) {
+                let end_of_range = (type_hint.starting_index + type_hint.length) as i64;
+                if end_of_range > depth {
+                    let err = StackAnalysisError::TypeHintOutOfRange {
+                        address,
+                        type_hint,
+                        depth,
+                    };
+                    errors.push(err);
+                }
+            }
+        }
+
+        Self {
+            control_flow_graph,
+            depths,
+            errors,
+        }
+    }
+
+    pub fn control_flow_graph(&self) -> &ControlFlowGraph {
+        &self.control_flow_graph
+    }
+
+    /// The minimal op stack depth before the instruction at the given address, over all paths
+    /// reaching it. `None` if the address is unreachable or not the start of an instruction.
+    pub fn depth_at(&self, address: u64) -> Option<i64> {
+        self.depths.get(&address).copied()
+    }
+
+    pub fn errors(&self) -> &[StackAnalysisError] {
+        &self.errors
+    }
+
+    /// Whether the analysis found no issues.
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// The op stack depth at the start of execution.
+fn initial_depth() -> i64 {
+    OpStackElement::COUNT as i64
+}
+
+/// The op stack depths within one function, relative to the function's start.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+struct FunctionSummary {
+    /// The relative op stack depth before each reachable instruction of the function.
+    depths: HashMap<u64, i64>,
+
+    /// The destinations of all `call`s in the function, and the relative depths at the `call`s.
+    calls: Vec<(u64, i64)>,
+
+    /// The change of the op stack depth between entering and returning from the function. `None`
+    /// if the function never returns.
+    depth_change: Option<i64>,
+}
+
+/// How a `call` changes the op stack depth.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum CallEffect {
+    /// The called function returns, changing the op stack depth by the given amount.
+    Returns(i64),
+
+    /// The called function never returns.
+    NeverReturns,
+
+    /// The called function is still being summarized, _i.e._, the call is recursive.
+    Recursive,
+}
+
+impl CallEffect {
+    fn of(function: &FunctionSummary) -> Self {
+        function
+            .depth_change
+            .map_or(Self::NeverReturns, Self::Returns)
+    }
+}
+
+struct Analyzer<'cfg> {
+    control_flow_graph: &'cfg ControlFlowGraph,
+
+    /// The summary of every function encountered so far. `None` while a function is still being
+    /// summarized.
+    functions: HashMap<u64, Option<FunctionSummary>>,
+
+    errors: Vec<StackAnalysisError>,
+}
+
+impl Analyzer<'_> {
+    /// The effect of calling the function at the given address on the op stack depth,
+    /// summarizing the function if necessary.
+    fn effect_of_call(&mut self, destination: u64) -> CallEffect {
+        match self.functions.get(&destination) {
+            Some(Some(function)) => return CallEffect::of(function),
+            Some(None) => return CallEffect::Recursive,
+            None => (),
+        }
+
+        self.functions.insert(destination, None);
+        let function = self.summarize(destination, false);
+        let effect = CallEffect::of(&function);
+        self.functions.insert(destination, Some(function));
+
+        effect
+    }
+
+    fn summarize(&mut self, entry: u64, is_main: bool) -> FunctionSummary {
+        let mut function = FunctionSummary::default();
+        let mut to_visit = vec![(entry, 0, entry)];
+        while let Some((address, depth, predecessor)) = to_visit.pop() {
+            let Some(control_flow) = self.control_flow_graph.control_flow_at(address) else {
+                self.errors
+                    .push(StackAnalysisError::RunsPastEnd(predecessor));
+                continue;
+            };
+            match function.depths.entry(address) {
+                Entry::Occupied(entry) if *entry.get() != depth => {
+                    let other = *entry.get();
+                    let err = StackAnalysisError::InconsistentDepth {
+                        address,
+                        depth,
+                        other,
+                    };
+                    self.errors.push(err);
+                    continue;
+                }
+                Entry::Occupied(_) => continue,
+                Entry::Vacant(entry) => _ = entry.insert(depth),
+            }
+
+            let instruction = self.control_flow_graph.instruction_at(address).unwrap();
+            let depth = depth + i64::from(instruction.op_stack_size_influence());
+            match control_flow {
+                ControlFlow::Continue(next) => to_visit.push((next, depth, address)),
+                ControlFlow::Branch { next, skip } => {
+                    to_visit.push((skip, depth, address));
+                    to_visit.push((next, depth, address));
+                }
+                ControlFlow::Call {
+                    destination,
+                    return_address,
+                } => {
+                    function.calls.push((destination, depth));
+                    match self.effect_of_call(destination) {
+                        CallEffect::Returns(depth_change) => {
+                            to_visit.push((return_address, depth + depth_change, address));
+                        }
+                        CallEffect::NeverReturns => (),
+                        CallEffect::Recursive => {
+                            self.errors.push(StackAnalysisError::RecursiveCall(address));
+                        }
+                    }
+                }
+                ControlFlow::Return | ControlFlow::Recurse if is_main => {
+                    let err = StackAnalysisError::JumpStackUnderflow {
+                        address,
+                        instruction,
+                    };
+                    self.errors.push(err);
+                }
+                ControlFlow::Return => match function.depth_change {
+                    Some(other) if other != depth => {
+                        let err = StackAnalysisError::InconsistentReturn {
+                            function: entry,
+                            address,
+                            depth_change: depth,
+                            other,
+                        };
+                        self.errors.push(err);
+                    }
+                    Some(_) => (),
+                    None => function.depth_change = Some(depth),
+                },
+                ControlFlow::Recurse if depth != 0 => {
+                    let depth_change = depth;
+                    let err = StackAnalysisError::UnbalancedRecursion {
+                        address,
+                        depth_change,
+                    };
+                    self.errors.push(err);
+                }
+                ControlFlow::Recurse | ControlFlow::Halt => (),
+            }
+        }
+
+        function
+    }
+
+    /// The shallowest op stack with which each function can be entered, starting from the main
+    /// function. Functions that can only be entered with an op stack that is already too shallow
+    /// are omitted; the offending `call`s are reported as underflows regardless.
+    fn entry_depths(&self, main: &FunctionSummary) -> HashMap<u64, i64> {
+        let mut entry_depths = HashMap::new();
+        let mut to_visit = main
+            .calls
+            .iter()
+            .map(|&(destination, depth)| (destination, initial_depth() + depth))
+            .collect_vec();
+        while let Some((entry, entry_depth)) = to_visit.pop() {
+            if entry_depth < initial_depth() {
+                continue;
+            }
+            if entry_depths
+                .get(&entry)
+                .is_some_and(|&depth| depth <= entry_depth)
+            {
+                continue;
+            }
+            entry_depths.insert(entry, entry_depth);
+
+            let Some(Some(function)) = self.functions.get(&entry) else {
+                continue;
+            };
+            let calls = function.calls.iter();
+            to_visit.extend(calls.map(|&(destination, depth)| (destination, entry_depth + depth)));
+        }
+
+        entry_depths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::assert;
+    use assert2::let_assert;
+
+    use crate::prelude::*;
+    use crate::vm::VMState;
+
+    use super::*;
+
+    #[test]
+    fn depths_of_straight_line_program() {
+        let program = triton_program!(push 1 push 2 add pop 1 halt);
+        let analysis = StackAnalysis::new(&program);
+        assert!(analysis.is_ok());
+
+        let depths = [0, 2, 4, 5, 7].map(|address| analysis.depth_at(address));
+        assert!([Some(16), Some(17), Some(18), Some(17), Some(16)] == depths);
+        assert!(analysis.depth_at(1).is_none());
+    }
+
+    #[test]
+    fn control_flow_graph_of_branching_program() {
+        let program = triton_program!(push 0 skiz call foo halt foo: return);
+        let control_flow_graph = ControlFlowGraph::new(&program);
+
+        let_assert!(
+            Some(ControlFlow::Branch { next, skip }) = control_flow_graph.control_flow_at(2)
+        );
+        assert!((3, 5) == (next, skip));
+
+        let_assert!(
+            Some(ControlFlow::Call {
+                destination,
+                return_address
+            }) = control_flow_graph.control_flow_at(3)
+        );
+        assert!((6, 5) == (destination, return_address));
+        assert!(vec![0, 6] == control_flow_graph.function_entries());
+    }
+
+    #[test]
+    fn balanced_loop_has_no_issues_and_depths_agree_with_execution() {
+        let program = triton_program!(
+            push 3 call loop pop 1 halt
+            loop:
+                dup 0 push 0 eq skiz return
+                push -1 add recurse
+        );
+        let analysis = StackAnalysis::new(&program);
+        assert!(analysis.is_ok(), "{:?}", analysis.errors());
+
+        let mut state = VMState::new(&program, PublicInput::default(), NonDeterminism::default());
+        while !state.halting {
+            let address = state.instruction_pointer as u64;
+            let depth = state.op_stack.len() as i64;
+            assert!(Some(depth) == analysis.depth_at(address));
+            state.step().unwrap();
+        }
+    }
+
+    #[test]
+    fn popping_too_much_is_an_underflow() {
+        let program = triton_program!(pop 1 halt);
+        let analysis = StackAnalysis::new(&program);
+        let_assert!([StackAnalysisError::OpStackUnderflow { address: 0, .. }] = analysis.errors());
+    }
+
+    #[test]
+    fn underflow_in_function_depends_on_call_site() {
+        let program = triton_program!(push 1 call foo halt foo: pop 2 return);
+        let analysis = StackAnalysis::new(&program);
+        let_assert!([StackAnalysisError::OpStackUnderflow { address: 5, .. }] = analysis.errors());
+
+        let program = triton_program!(push 1 push 2 call foo halt foo: pop 2 return);
+        assert!(StackAnalysis::new(&program).is_ok());
+    }
+
+    #[test]
+    fn joining_paths_with_different_depths_are_inconsistent() {
+        let program = triton_program!(push 0 skiz push 1 halt);
+        let analysis = StackAnalysis::new(&program);
+        let_assert!([StackAnalysisError::InconsistentDepth { address: 5, .. }] = analysis.errors());
+    }
+
+    #[test]
+    fn returning_with_different_depths_is_inconsistent() {
+        let program = triton_program!(call foo halt foo: push 0 skiz return push 1 return);
+        let analysis = StackAnalysis::new(&program);
+        let_assert!(
+            [StackAnalysisError::InconsistentReturn { function: 3, .. }] = analysis.errors()
+        );
+    }
+
+    #[test]
+    fn recursing_with_changed_depth_is_unbalanced() {
+        let program = triton_program!(call foo halt foo: push 1 recurse);
+        let analysis = StackAnalysis::new(&program);
+        let_assert!(
+            [StackAnalysisError::UnbalancedRecursion {
+                depth_change: 1,
+                ..
+            }] = analysis.errors()
+        );
+    }
+
+    #[test]
+    fn returning_from_main_underflows_jump_stack() {
+        let program = triton_program!(return);
+        let analysis = StackAnalysis::new(&program);
+        let_assert!(
+            [StackAnalysisError::JumpStackUnderflow { address: 0, .. }] = analysis.errors()
+        );
+    }
+
+    #[test]
+    fn running_past_end_of_program_is_detected() {
+        let program = triton_program!(push 1 pop 1);
+        let analysis = StackAnalysis::new(&program);
+        let_assert!([StackAnalysisError::RunsPastEnd(2)] = analysis.errors());
+    }
+
+    #[test]
+    fn recursive_calls_are_reported() {
+        let program = triton_program!(call foo halt foo: call foo return);
+        let analysis = StackAnalysis::new(&program);
+        let_assert!([StackAnalysisError::RecursiveCall(3)] = analysis.errors());
+    }
+
+    #[test]
+    fn type_hints_must_stay_within_op_stack() {
+        let program = triton_program!(push 1 hint x = stack[16..17] pop 1 halt);
+        assert!(StackAnalysis::new(&program).is_ok());
+
+        let program = triton_program!(push 1 hint x = stack[16..18] pop 1 halt);
+        let analysis = StackAnalysis::new(&program);
+        let_assert!(
+            [StackAnalysisError::TypeHintOutOfRange {
+                address: 2,
+                depth: 17,
+                ..
+            }] = analysis.errors()
+        );
+    }
+}
@@ -0,0 +1,436 @@
+//! Instruction coverage of [Triton assembly][tasm] programs.
+//!
+//! The [`AlgebraicExecutionTrace`] records how often each instruction of the executed program
+//! was run. A [`Coverage`] maps these counts back to the [`LabelledInstruction`]s and labels of the
+//! [`Program`] and accumulates them over any number of runs, for example, one run per test input.
+//! The result can be exported as an [lcov] tracefile or rendered as annotated assembly, revealing
+//! the parts of a program that were never executed.
+//!
+//! Line numbers refer to the program as printed by its [`Display`] implementation, _i.e._, one
+//! [`LabelledInstruction`] per line.
+//!
+//! [tasm]: https://triton-vm.org/spec/instructions.html
+//! [lcov]: https://github.com/linux-test-project/lcov
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::fmt::Write;
+
+use crate::aet::AlgebraicExecutionTrace;
+use crate::error::CoverageError;
+use crate::instruction::LabelledInstruction;
+use crate::program::Program;
+
+type Result<T> = std::result::Result<T, CoverageError>;
+
+/// How often each instruction of a [`Program`] was executed, accumulated over any number of runs.
+///
+/// # Examples
+///
+/// ```
+/// # use triton_vm::prelude::*;
+/// # use triton_vm::coverage::Coverage;
+/// let program = triton_program!(read_io 1 skiz call double halt double: push 2 mul return);
+/// let mut coverage = Coverage::new(program.clone());
+/// for input in [0_u64, 1] {
+///     let (aet, _) = program.trace_execution([bfe!(input)].into(), [].into()).unwrap();
+///     coverage.record(&aet).unwrap();
+/// }
+///
+/// assert_eq!(2, coverage.num_runs());
+/// assert!(coverage.is_fully_covered());
+/// println!("{coverage}");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Coverage {
+    program: Program,
+
+    /// The number of times the instruction at each address was executed. Only the first address
+    /// of an instruction with an argument is ever hit.
+    hits: Vec<u64>,
+
+    num_runs: usize,
+}
+
+/// The [coverage](Coverage) of a single [instruction](LabelledInstruction::Instruction).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct InstructionCoverage {
+    pub address: u64,
+
+    /// The line of the instruction in the printed [`Program`], starting at 1.
+    pub line: usize,
+
+    pub instruction: LabelledInstruction,
+
+    /// The number of times the instruction was executed, summed over all recorded runs.
+    pub hits: u64,
+}
+
+/// The [coverage](Coverage) of a [label](LabelledInstruction::Label) and all instructions up to
+/// the next label.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct LabelCoverage {
+    pub label: String,
+    pub address: u64,
+
+    /// The line of the label in the printed [`Program`], starting at 1.
+    pub line: usize,
+
+    /// The number of times the first instruction after the label was executed, summed over all
+    /// recorded runs.
+    pub hits: u64,
+
+    pub num_instructions: usize,
+    pub num_covered_instructions: usize,
+}
+
+impl Coverage {
+    /// Coverage of the given [`Program`] without any recorded runs.
+    pub fn new(program: Program) -> Self {
+        let hits = vec![0; program.len_bwords()];
+        Self {
+            program,
+            hits,
+            num_runs: 0,
+        }
+    }
+
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    /// The number of runs that were recorded or merged into this coverage.
+    pub fn num_runs(&self) -> usize {
+        self.num_runs
+    }
+
+    /// Add the instruction counts of the given [`AlgebraicExecutionTrace`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the trace belongs to a different program.
+    pub fn record(&mut self, aet: &AlgebraicExecutionTrace) -> Result<()> {
+        if aet.program != self.program {
+            return Err(CoverageError::ProgramMismatch);
+        }
+        for (hits, &multiplicity) in self.hits.iter_mut().zip(&aet.instruction_multiplicities) {
+            *hits += u64::from(multiplicity);
+        }
+        self.num_runs += 1;
+        Ok(())
+    }
+
+    /// Add all runs recorded in the other coverage.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the other coverage belongs to a different program.
+    pub fn merge(&mut self, other: &Self) -> Result<()> {
+        if other.program != self.program {
+            return Err(CoverageError::ProgramMismatch);
+        }
+        for (hits, &other_hits) in self.hits.iter_mut().zip(&other.hits) {
+            *hits += other_hits;
+        }
+        self.num_runs += other.num_runs;
+        Ok(())
+    }
+
+    /// The number of times the instruction at the given address was executed. Is 0 for addresses
+    /// outside the program and for addresses of instruction arguments.
+    pub fn hits_at(&self, address: u64) -> u64 {
+        let Ok(address) = usize::try_from(address) else {
+            return 0;
+        };
+        self.hits.get(address).copied().unwrap_or_default()
+    }
+
+    /// The coverage of every instruction, in the order of the program.
+    pub fn instructions(&self) -> Vec<InstructionCoverage> {
+        self.annotated_lines()
+            .into_iter()
+            .filter(|line| matches!(line.instruction, LabelledInstruction::Instruction(_)))
+            .map(|line| InstructionCoverage {
+                address: line.address,
+                line: line.line,
+                hits: self.hits_at(line.address),
+                instruction: line.instruction,
+            })
+            .collect()
+    }
+
+    /// All instructions that were never executed, in the order of the program.
+    pub fn uncovered_instructions(&self) -> Vec<InstructionCoverage> {
+        let mut instructions = self.instructions();
+        instructions.retain(|instruction| instruction.hits == 0);
+        instructions
+    }
+
+    /// The coverage of every label, in the order of the program.
+    pub fn labels(&self) -> Vec<LabelCoverage> {
+        let mut labels: Vec<LabelCoverage> = vec![];
+        for line in self.annotated_lines() {
+            match line.instruction {
+                LabelledInstruction::Label(label) => labels.push(LabelCoverage {
+                    label,
+                    address: line.address,
+                    line: line.line,
+                    hits: self.hits_at(line.address),
+                    num_instructions: 0,
+                    num_covered_instructions: 0,
+                }),
+                LabelledInstruction::Instruction(_) => {
+                    let Some(label) = labels.last_mut() else {
+                        continue;
+                    };
+                    label.num_instructions += 1;
+                    if self.hits_at(line.address) > 0 {
+                        label.num_covered_instructions += 1;
+                    }
+                }
+                _ => (),
+            }
+        }
+        labels
+    }
+
+    pub fn num_instructions(&self) -> usize {
+        self.instructions().len()
+    }
+
+    pub fn num_covered_instructions(&self) -> usize {
+        let instructions = self.instructions();
+        instructions.iter().filter(|i| i.hits > 0).count()
+    }
+
+    /// `true` if and only if every instruction of the program was executed at least once.
+    pub fn is_fully_covered(&self) -> bool {
+        self.uncovered_instructions().is_empty()
+    }
+
+    /// The coverage in the [lcov] tracefile format. Every label is reported as a function. The
+    /// given source file is recorded verbatim and should contain the printed [`Program`] for the
+    /// line numbers to make sense.
+    ///
+    /// [lcov]: https://github.com/linux-test-project/lcov
+    pub fn to_lcov(&self, source_file: &str) -> String {
+        let labels = self.labels();
+        let instructions = self.instructions();
+
+        let mut lcov = String::new();
+        let _ = writeln!(lcov, "TN:");
+        let _ = writeln!(lcov, "SF:{source_file}");
+        for label in &labels {
+            let _ = writeln!(lcov, "FN:{},{}", label.line, label.label);
+        }
+        for label in &labels {
+            let _ = writeln!(lcov, "FNDA:{},{}", label.hits, label.label);
+        }
+        let num_hit_labels = labels.iter().filter(|label| label.hits > 0).count();
+        let _ = writeln!(lcov, "FNF:{}", labels.len());
+        let _ = writeln!(lcov, "FNH:{num_hit_labels}");
+        for instruction in &instructions {
+            let _ = writeln!(lcov, "DA:{},{}", instruction.line, instruction.hits);
+        }
+        let num_hit_instructions = instructions.iter().filter(|i| i.hits > 0).count();
+        let _ = writeln!(lcov, "LF:{}", instructions.len());
+        let _ = writeln!(lcov, "LH:{num_hit_instructions}");
+        let _ = writeln!(lcov, "end_of_record");
+        lcov
+    }
+
+    /// Every line of the printed program alongside its address. The address of anything but an
+    /// instruction is the address of the next instruction.
+    fn annotated_lines(&self) -> Vec<AnnotatedLine> {
+        let mut address = 0;
+        let mut lines = vec![];
+        for (line_idx, instruction) in self.program.labelled_instructions().into_iter().enumerate()
+        {
+            let instruction_size = match &instruction {
+                LabelledInstruction::Instruction(instruction) => instruction.size() as u64,
+                _ => 0,
+            };
+            lines.push(AnnotatedLine {
+                address,
+                line: line_idx + 1,
+                instruction,
+            });
+            address += instruction_size;
+        }
+        lines
+    }
+}
+
+struct AnnotatedLine {
+    address: u64,
+    line: usize,
+    instruction: LabelledInstruction,
+}
+
+impl From<&AlgebraicExecutionTrace> for Coverage {
+    fn from(aet: &AlgebraicExecutionTrace) -> Self {
+        let mut coverage = Self::new(aet.program.clone());
+        coverage
+            .record(aet)
+            .expect("the trace's program must match itself");
+        coverage
+    }
+}
+
+/// Annotated assembly in the style of `gcov`: every instruction is prefixed with the number of
+/// times it was executed, or with `#####` if it was never executed. Lines without instructions are
+/// prefixed with `-`.
+impl Display for Coverage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        const NEVER_EXECUTED: &str = "#####";
+
+        let max_hits = self.hits.iter().max().copied().unwrap_or_default();
+        let width = max_hits.to_string().len().max(NEVER_EXECUTED.len());
+        for line in self.annotated_lines() {
+            let hits = match line.instruction {
+                LabelledInstruction::Instruction(_) => match self.hits_at(line.address) {
+                    0 => NEVER_EXECUTED.to_string(),
+                    hits => hits.to_string(),
+                },
+                _ => "-".to_string(),
+            };
+            writeln!(f, "{hits:>width$}: {}", line.instruction)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::assert;
+    use assert2::let_assert;
+    use itertools::Itertools;
+
+    use crate::example_programs::FIBONACCI_SEQUENCE;
+    use crate::prelude::*;
+
+    use super::*;
+
+    fn coverage_of_runs(program: &Program, inputs: &[u64]) -> Coverage {
+        let mut coverage = Coverage::new(program.clone());
+        for &input in inputs {
+            let (aet, _) = program
+                .trace_execution([bfe!(input)].into(), [].into())
+                .unwrap();
+            coverage.record(&aet).unwrap();
+        }
+        coverage
+    }
+
+    fn branching_program() -> Program {
+        triton_program! {
+            read_io 1 skiz call then halt
+            then: push 1 pop 1 return
+        }
+    }
+
+    #[test]
+    fn total_hits_equal_number_of_executed_instructions() {
+        let program = FIBONACCI_SEQUENCE.clone();
+        let (aet, _) = program
+            .trace_execution(bfe_vec![13].into(), [].into())
+            .unwrap();
+        let coverage = Coverage::from(&aet);
+
+        let total_hits = coverage.instructions().iter().map(|i| i.hits).sum::<u64>();
+        assert!(aet.processor_trace.nrows() as u64 == total_hits);
+    }
+
+    #[test]
+    fn untaken_branch_is_uncovered() {
+        let program = branching_program();
+        let coverage = coverage_of_runs(&program, &[0]);
+        assert!(!coverage.is_fully_covered());
+
+        let uncovered = coverage.uncovered_instructions();
+        let uncovered = uncovered
+            .iter()
+            .map(|i| i.instruction.to_string())
+            .collect_vec();
+        assert!(vec!["call then", "push 1", "pop 1", "return"] == uncovered);
+
+        let_assert!([then] = coverage.labels().as_slice());
+        assert!("then" == then.label);
+        assert!(0 == then.hits);
+        assert!(3 == then.num_instructions);
+        assert!(0 == then.num_covered_instructions);
+    }
+
+    #[test]
+    fn merging_runs_covers_both_branches() {
+        let program = branching_program();
+        let mut coverage = coverage_of_runs(&program, &[0]);
+        coverage
+            .merge(&coverage_of_runs(&program, &[1, 1]))
+            .unwrap();
+
+        assert!(3 == coverage.num_runs());
+        assert!(coverage.is_fully_covered());
+        assert!(3 == coverage.hits_at(0));
+        assert!(2 == coverage.labels()[0].hits);
+    }
+
+    #[test]
+    fn merging_coverage_of_different_programs_fails() {
+        let mut coverage = Coverage::new(triton_program!(halt));
+        let other = Coverage::new(triton_program!(nop halt));
+        let_assert!(Err(CoverageError::ProgramMismatch) = coverage.merge(&other));
+    }
+
+    #[test]
+    fn recording_trace_of_different_program_fails() {
+        let mut coverage = Coverage::new(triton_program!(halt));
+        let (aet, _) = triton_program!(nop halt)
+            .trace_execution([].into(), [].into())
+            .unwrap();
+        let_assert!(Err(CoverageError::ProgramMismatch) = coverage.record(&aet));
+        assert!(0 == coverage.num_runs());
+    }
+
+    #[test]
+    fn line_numbers_refer_to_printed_program() {
+        let program = branching_program();
+        let coverage = coverage_of_runs(&program, &[1]);
+        let printed_program = program.to_string();
+        let printed_lines = printed_program.lines().collect_vec();
+
+        for instruction in coverage.instructions() {
+            let printed_line = printed_lines[instruction.line - 1];
+            assert!(instruction.instruction.to_string() == printed_line);
+        }
+    }
+
+    #[test]
+    fn lcov_report_contains_functions_and_lines() {
+        let program = branching_program();
+        let coverage = coverage_of_runs(&program, &[0]);
+        let lcov = coverage.to_lcov("branching.tasm");
+
+        assert!(lcov.starts_with("TN:\nSF:branching.tasm\n"));
+        assert!(lcov.contains("FN:5,then\n"));
+        assert!(lcov.contains("FNDA:0,then\n"));
+        assert!(lcov.contains("FNF:1\nFNH:0\n"));
+        assert!(lcov.contains("DA:1,1\n"));
+        assert!(lcov.contains("DA:8,0\n"));
+        assert!(lcov.contains("LF:7\nLH:3\n"));
+        assert!(lcov.ends_with("end_of_record\n"));
+    }
+
+    #[test]
+    fn annotated_assembly_marks_unexecuted_instructions() {
+        let program = branching_program();
+        let coverage = coverage_of_runs(&program, &[0]);
+        let annotated = coverage.to_string();
+        let annotated_lines = annotated.lines().collect_vec();
+
+        assert!("    1: read_io 1" == annotated_lines[0]);
+        assert!("#####: call then" == annotated_lines[2]);
+        assert!("    -: then:" == annotated_lines[4]);
+    }
+}
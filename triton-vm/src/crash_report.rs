@@ -0,0 +1,616 @@
+//! Human- and machine-readable reports about crashes of Triton VM.
+//!
+//! A [`VMError`] contains the reason for a crash and the [`VMState`] at the time of the crash.
+//! A [`CrashReport`] additionally resolves the call stack to labels, annotates the op stack with
+//! the [type hints](crate::instruction::TypeHint) of the [`Program`], lists the most recently
+//! executed instructions, and summarizes how much of the input was consumed. It can be rendered as
+//! text through its [`Display`] implementation, or as JSON through [`CrashReport::to_json`].
+//!
+//! Some of this information is only available if execution was observed by a [`CrashReporter`].
+
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+use serde::Serializer;
+use serde_derive::Serialize;
+use twenty_first::prelude::*;
+
+use crate::error::InstructionError;
+use crate::error::VMError;
+use crate::instruction::Instruction;
+use crate::observer::VMObserver;
+use crate::parser::SourceLocation;
+use crate::program::Program;
+use crate::vm::VMState;
+
+/// A report about a crash of Triton VM.
+///
+/// # Examples
+///
+/// ```
+/// # use triton_vm::prelude::*;
+/// # use triton_vm::crash_report::CrashReporter;
+/// let program = triton_program! {
+///     read_io 1 call check_is_one halt
+///     check_is_one:
+///         hint candidate: u32 = stack[0]
+///         assert return
+/// };
+/// let mut reporter = CrashReporter::new(&program);
+/// let result = program.run_with_observer([bfe!(2)].into(), [].into(), &mut reporter);
+/// assert!(result.is_err());
+///
+/// let report = reporter.into_crash_report().unwrap();
+/// assert_eq!("check_is_one", report.call_stack[0].label);
+/// assert_eq!(Some("candidate".to_string()), report.stack[0].variable_name);
+/// assert_eq!(Some(1), report.input.public_input_consumed);
+/// println!("{report}");
+/// println!("{}", report.to_json());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct CrashReport {
+    /// The reason Triton VM crashed.
+    #[serde(serialize_with = "serialize_as_string")]
+    pub error: InstructionError,
+
+    pub cycle_count: u32,
+    pub instruction_pointer: usize,
+
+    /// The instruction that crashed the VM, with call targets resolved to labels. `None` if the
+    /// instruction pointer points outside the program.
+    pub instruction: Option<String>,
+
+    /// The location of the instruction that crashed the VM in the source code. `None` if the
+    /// program has no such [debug information](Program::source_location).
+    pub source_location: Option<SourceLocation>,
+
+    /// The currently active calls, innermost call first.
+    pub call_stack: Vec<CallFrame>,
+
+    /// The most recently executed instructions, oldest instruction first. The last instruction is
+    /// the one that crashed the VM. Empty unless execution was observed by a [`CrashReporter`].
+    pub recent_instructions: Vec<ExecutedInstruction>,
+
+    /// The entire op stack, top of the stack first.
+    pub stack: Vec<StackElement>,
+
+    pub input: InputConsumption,
+}
+
+/// One active call on the jump stack.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
+pub struct CallFrame {
+    /// The label of the called address.
+    pub label: String,
+    pub call_destination: u64,
+
+    /// The address execution continues at once the call [returns](Instruction::Return).
+    pub return_address: u64,
+}
+
+/// An instruction that was executed before the crash.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
+pub struct ExecutedInstruction {
+    pub cycle: u32,
+    pub address: u64,
+
+    /// The instruction, with call targets resolved to labels.
+    pub instruction: String,
+}
+
+/// One element of the op stack, alongside its [type hint](crate::instruction::TypeHint), if any.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
+pub struct StackElement {
+    /// The distance to the top of the stack, _i.e._, `0` for `st0`.
+    pub index: usize,
+    pub value: BFieldElement,
+    pub variable_name: Option<String>,
+    pub type_name: Option<String>,
+}
+
+/// How much of each kind of input was consumed before the crash, and how much remains.
+///
+/// The number of consumed elements is only known if execution was observed by a
+/// [`CrashReporter`].
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Serialize)]
+pub struct InputConsumption {
+    pub public_input_consumed: Option<usize>,
+    pub public_input_remaining: usize,
+    pub secret_individual_tokens_consumed: Option<usize>,
+    pub secret_individual_tokens_remaining: usize,
+    pub secret_digests_consumed: Option<usize>,
+    pub secret_digests_remaining: usize,
+}
+
+/// A [`VMObserver`] that collects everything required for a complete [`CrashReport`]. The
+/// collected information includes the most recently executed instructions and the type hints
+/// that apply to the op stack at the time of the crash.
+#[derive(Debug, Clone)]
+pub struct CrashReporter<'program> {
+    program: &'program Program,
+    max_num_recent_instructions: usize,
+    recent_instructions: VecDeque<ExecutedInstruction>,
+    initial_input_lengths: Option<InputLengths>,
+
+    /// The type hint applying to each element of the op stack. Indexed like the op stack's
+    /// underlying memory, _i.e._, the last element corresponds to the top of the stack.
+    stack_hints: Vec<Option<ElementHint>>,
+    stack_before_instruction: Vec<BFieldElement>,
+
+    crash_report: Option<CrashReport>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct InputLengths {
+    public_input: usize,
+    secret_individual_tokens: usize,
+    secret_digests: usize,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct ElementHint {
+    variable_name: String,
+    type_name: Option<String>,
+}
+
+impl CrashReport {
+    /// A crash report for the given error, which must have occurred while running the given
+    /// program. Since the execution leading up to the crash was not observed, the report lacks
+    /// the [recent instructions](Self::recent_instructions) and the amount of consumed input, and
+    /// only includes type hints declared at the crashing instruction. For a complete report, use
+    /// a [`CrashReporter`].
+    pub fn new(program: &Program, error: &VMError) -> Self {
+        let state = &error.vm_state;
+        let mut stack_hints = vec![None; state.op_stack.len()];
+        apply_type_hints(program, state, &mut stack_hints);
+
+        Self::build(program, error.source, state, &stack_hints)
+    }
+
+    fn build(
+        program: &Program,
+        error: InstructionError,
+        state: &VMState,
+        stack_hints: &[Option<ElementHint>],
+    ) -> Self {
+        let instruction = state
+            .current_instruction()
+            .ok()
+            .map(|instruction| with_labels(program, instruction));
+        let ip = state.instruction_pointer as u64;
+        let source_location = program.source_location(ip).cloned();
+
+        let call_stack = state
+            .jump_stack
+            .iter()
+            .rev()
+            .map(|&(origin, destination)| CallFrame {
+                label: program.label_for_address(destination.value()),
+                call_destination: destination.value(),
+                return_address: origin.value(),
+            })
+            .collect();
+
+        let stack = state
+            .op_stack
+            .stack
+            .iter()
+            .zip(stack_hints)
+            .rev()
+            .enumerate()
+            .map(|(index, (&value, hint))| StackElement {
+                index,
+                value,
+                variable_name: hint.as_ref().map(|hint| hint.variable_name.clone()),
+                type_name: hint.as_ref().and_then(|hint| hint.type_name.clone()),
+            })
+            .collect();
+
+        let input = InputConsumption {
+            public_input_remaining: state.public_input.len(),
+            secret_individual_tokens_remaining: state.secret_individual_tokens.len(),
+            secret_digests_remaining: state.secret_digests.len(),
+            ..InputConsumption::default()
+        };
+
+        Self {
+            error,
+            cycle_count: state.cycle_count,
+            instruction_pointer: state.instruction_pointer,
+            instruction,
+            source_location,
+            call_stack,
+            recent_instructions: vec![],
+            stack,
+            input,
+        }
+    }
+
+    /// The report in JSON format.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("crash report must be serializable")
+    }
+}
+
+impl Display for CrashReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "Triton VM crashed: {}", self.error)?;
+        let instruction = self
+            .instruction
+            .as_deref()
+            .unwrap_or("<outside of program>");
+        let ip = self.instruction_pointer;
+        let clk = self.cycle_count;
+        writeln!(f, "  at address {ip} in cycle {clk}: {instruction}")?;
+        if let Some(location) = &self.source_location {
+            writeln!(f, "  in source {location}")?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Call stack, innermost call first:")?;
+        if self.call_stack.is_empty() {
+            writeln!(f, "  <empty>")?;
+        }
+        for frame in &self.call_stack {
+            let label = &frame.label;
+            let destination = frame.call_destination;
+            let return_address = frame.return_address;
+            writeln!(
+                f,
+                "  {label} (address {destination}, returns to {return_address})"
+            )?;
+        }
+
+        if !self.recent_instructions.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "Recent instructions, oldest first:")?;
+            let max_address = self.recent_instructions.iter().map(|i| i.address).max();
+            let address_width = max_address.unwrap_or_default().to_string().len();
+            for executed in &self.recent_instructions {
+                let cycle = executed.cycle;
+                let address = executed.address;
+                let instruction = &executed.instruction;
+                writeln!(
+                    f,
+                    "  cycle {cycle:>6}  address {address:>address_width$}  {instruction}"
+                )?;
+            }
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Stack, top first:")?;
+        for element in &self.stack {
+            let index = format!("st{}", element.index);
+            let value = element.value.to_string();
+            let hint = match (&element.variable_name, &element.type_name) {
+                (Some(variable), Some(type_name)) => format!("{variable}: {type_name}"),
+                (Some(variable), None) => variable.clone(),
+                _ => String::new(),
+            };
+            writeln!(f, "  {index:>5}  {value:>20}  {hint}")?;
+        }
+
+        let consumed = |consumed: Option<usize>| match consumed {
+            Some(consumed) => consumed.to_string(),
+            None => "unknown".to_string(),
+        };
+        let input = &self.input;
+        writeln!(f)?;
+        writeln!(f, "Input:")?;
+        writeln!(
+            f,
+            "  public input:             {} consumed, {} remaining",
+            consumed(input.public_input_consumed),
+            input.public_input_remaining,
+        )?;
+        writeln!(
+            f,
+            "  secret individual tokens: {} consumed, {} remaining",
+            consumed(input.secret_individual_tokens_consumed),
+            input.secret_individual_tokens_remaining,
+        )?;
+        writeln!(
+            f,
+            "  secret digests:           {} consumed, {} remaining",
+            consumed(input.secret_digests_consumed),
+            input.secret_digests_remaining,
+        )
+    }
+}
+
+impl<'program> CrashReporter<'program> {
+    /// The default number of [recent instructions](CrashReport::recent_instructions) to report.
+    pub const DEFAULT_NUM_RECENT_INSTRUCTIONS: usize = 20;
+
+    /// A reporter for crashes of the given program.
+    pub fn new(program: &'program Program) -> Self {
+        Self {
+            program,
+            max_num_recent_instructions: Self::DEFAULT_NUM_RECENT_INSTRUCTIONS,
+            recent_instructions: VecDeque::new(),
+            initial_input_lengths: None,
+            stack_hints: vec![],
+            stack_before_instruction: vec![],
+            crash_report: None,
+        }
+    }
+
+    /// Set the maximum number of [recent instructions](CrashReport::recent_instructions) to
+    /// report.
+    #[must_use]
+    pub fn with_num_recent_instructions(mut self, num_recent_instructions: usize) -> Self {
+        self.max_num_recent_instructions = num_recent_instructions;
+        self
+    }
+
+    /// The report about the observed crash, or `None` if the VM has not crashed.
+    pub fn crash_report(&self) -> Option<&CrashReport> {
+        self.crash_report.as_ref()
+    }
+
+    /// The report about the observed crash, or `None` if the VM has not crashed.
+    pub fn into_crash_report(self) -> Option<CrashReport> {
+        self.crash_report
+    }
+
+    fn record_initial_input_lengths(&mut self, state: &VMState) {
+        if self.initial_input_lengths.is_some() {
+            return;
+        }
+        self.initial_input_lengths = Some(InputLengths {
+            public_input: state.public_input.len(),
+            secret_individual_tokens: state.secret_individual_tokens.len(),
+            secret_digests: state.secret_digests.len(),
+        });
+    }
+
+    fn record_recent_instruction(&mut self, state: &VMState, instruction: Instruction) {
+        if self.max_num_recent_instructions == 0 {
+            return;
+        }
+        if self.recent_instructions.len() == self.max_num_recent_instructions {
+            self.recent_instructions.pop_front();
+        }
+        self.recent_instructions.push_back(ExecutedInstruction {
+            cycle: state.cycle_count,
+            address: state.instruction_pointer as u64,
+            instruction: with_labels(self.program, instruction),
+        });
+    }
+
+    /// Keep the type hints in sync with the op stack. Hints of elements that are moved by
+    /// instructions `dup` or `swap` move along with them. Hints of elements that are otherwise
+    /// modified are dropped.
+    fn update_stack_hints(&mut self, state: &VMState, instruction: Instruction) {
+        let top_of_stack = self.stack_hints.len().saturating_sub(1);
+        match instruction {
+            Instruction::Dup(st) => {
+                let hint = self.stack_hints[top_of_stack - usize::from(st)].clone();
+                self.stack_hints.push(hint);
+            }
+            Instruction::Swap(st) => {
+                let other = top_of_stack - usize::from(st);
+                self.stack_hints.swap(top_of_stack, other);
+            }
+            _ => {
+                self.stack_hints.resize(state.op_stack.len(), None);
+                let stack = state.op_stack.stack.iter();
+                let stack_before_instruction = self.stack_before_instruction.iter();
+                let hints = self.stack_hints.iter_mut();
+                for ((new, old), hint) in stack.zip(stack_before_instruction).zip(hints) {
+                    if new != old {
+                        *hint = None;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl VMObserver for CrashReporter<'_> {
+    fn before_instruction(&mut self, state: &VMState, instruction: Instruction) {
+        self.record_initial_input_lengths(state);
+        self.record_recent_instruction(state, instruction);
+        self.stack_hints.resize(state.op_stack.len(), None);
+        apply_type_hints(self.program, state, &mut self.stack_hints);
+        self.stack_before_instruction
+            .clone_from(&state.op_stack.stack);
+    }
+
+    fn after_instruction(&mut self, state: &VMState, instruction: Instruction) {
+        self.update_stack_hints(state, instruction);
+    }
+
+    fn error(&mut self, state: &VMState, error: InstructionError) {
+        self.record_initial_input_lengths(state);
+        self.stack_hints.resize(state.op_stack.len(), None);
+
+        let mut report = CrashReport::build(self.program, error, state, &self.stack_hints);
+        report.recent_instructions = self.recent_instructions.iter().cloned().collect();
+        if let Some(initial_lengths) = self.initial_input_lengths {
+            let input = &mut report.input;
+            input.public_input_consumed =
+                Some(initial_lengths.public_input - input.public_input_remaining);
+            input.secret_individual_tokens_consumed = Some(
+                initial_lengths.secret_individual_tokens - input.secret_individual_tokens_remaining,
+            );
+            input.secret_digests_consumed =
+                Some(initial_lengths.secret_digests - input.secret_digests_remaining);
+        }
+        self.crash_report = Some(report);
+    }
+}
+
+/// Apply the type hints declared at the current instruction pointer of the given state.
+fn apply_type_hints(program: &Program, state: &VMState, stack_hints: &mut [Option<ElementHint>]) {
+    let top_of_stack = stack_hints.len().saturating_sub(1);
+    let type_hints = program.type_hints_at(state.instruction_pointer as u64);
+    for type_hint in type_hints {
+        let hinted_range = type_hint.starting_index..type_hint.starting_index + type_hint.length;
+        for index in hinted_range.filter(|&index| index <= top_of_stack) {
+            stack_hints[top_of_stack - index] = Some(ElementHint {
+                variable_name: type_hint.variable_name.clone(),
+                type_name: type_hint.type_name.clone(),
+            });
+        }
+    }
+}
+
+fn with_labels(program: &Program, instruction: Instruction) -> String {
+    let instruction =
+        instruction.map_call_address(|address| program.label_for_address(address.value()));
+    instruction.to_string()
+}
+
+fn serialize_as_string<T: Display, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::assert;
+    use assert2::let_assert;
+
+    use crate::prelude::*;
+
+    use super::*;
+
+    fn crash_report_of(
+        program: &Program,
+        public_input: PublicInput,
+        non_determinism: NonDeterminism,
+    ) -> CrashReport {
+        let mut reporter = CrashReporter::new(program);
+        let result = program.run_with_observer(public_input, non_determinism, &mut reporter);
+        assert!(result.is_err());
+        let_assert!(Some(report) = reporter.into_crash_report());
+        report
+    }
+
+    #[test]
+    fn call_stack_is_resolved_to_labels() {
+        let program = triton_program! {
+            call outer halt
+            outer: call inner return
+            inner: push 0 assert return
+        };
+        let report = crash_report_of(&program, [].into(), [].into());
+
+        assert!(InstructionError::AssertionFailed == report.error);
+        assert!(Some("assert".to_string()) == report.instruction);
+        let labels = report.call_stack.iter().map(|frame| frame.label.as_str());
+        assert!(vec!["inner", "outer"] == labels.collect::<Vec<_>>());
+        assert!(2 == report.call_stack[1].return_address);
+    }
+
+    #[test]
+    fn only_most_recent_instructions_are_reported() {
+        let program = triton_program!(nop nop nop nop push 0 assert halt);
+        let mut reporter = CrashReporter::new(&program).with_num_recent_instructions(3);
+        let _ = program.run_with_observer([].into(), [].into(), &mut reporter);
+        let_assert!(Some(report) = reporter.into_crash_report());
+
+        let recent = report.recent_instructions.iter();
+        let recent = recent.map(|executed| executed.instruction.as_str());
+        assert!(vec!["nop", "push 0", "assert"] == recent.collect::<Vec<_>>());
+        assert!(5 == report.recent_instructions[2].cycle);
+    }
+
+    #[test]
+    fn type_hints_follow_moved_stack_elements() {
+        let program = triton_program! {
+            push 42
+            hint answer: u32 = stack[0]
+            push 7
+            hint lucky = stack[0]
+            swap 1 dup 1 push 1 add
+            push 0 assert
+        };
+        let report = crash_report_of(&program, [].into(), [].into());
+
+        // stack, top first: 0, 8, 42, 7
+        let hint = |index: usize| report.stack[index].variable_name.as_deref();
+        assert!(None == hint(0));
+        assert!(None == hint(1));
+        assert!(Some("answer") == hint(2));
+        assert!(Some("lucky") == hint(3));
+        assert!(Some("u32") == report.stack[2].type_name.as_deref());
+        assert!(bfe!(8) == report.stack[1].value);
+    }
+
+    #[test]
+    fn consumed_input_is_reported() {
+        let program = triton_program!(read_io 2 divine 1 divine 1 push 0 assert halt);
+        let public_input = bfe_vec![1, 2, 3].into();
+        let non_determinism = NonDeterminism::new(bfe_vec![4, 5, 6, 7]);
+        let report = crash_report_of(&program, public_input, non_determinism);
+
+        let input = report.input;
+        assert!(Some(2) == input.public_input_consumed);
+        assert!(1 == input.public_input_remaining);
+        assert!(Some(2) == input.secret_individual_tokens_consumed);
+        assert!(2 == input.secret_individual_tokens_remaining);
+        assert!(Some(0) == input.secret_digests_consumed);
+    }
+
+    #[test]
+    fn crash_report_without_observation_lacks_history() {
+        let program = triton_program!(read_io 1 push 0 assert halt);
+        let_assert!(Err(err) = program.run(bfe_vec![1].into(), [].into()));
+        let report = CrashReport::new(&program, &err);
+
+        assert!(report.recent_instructions.is_empty());
+        assert!(None == report.input.public_input_consumed);
+        assert!(0 == report.input.public_input_remaining);
+        assert!(err.vm_state.op_stack.len() == report.stack.len());
+    }
+
+    #[test]
+    fn instruction_pointer_overflow_can_be_reported() {
+        let program = triton_program!(nop);
+        let report = crash_report_of(&program, [].into(), [].into());
+        assert!(InstructionError::InstructionPointerOverflow == report.error);
+        assert!(None == report.instruction);
+        assert!(report.to_string().contains("<outside of program>"));
+    }
+
+    #[test]
+    fn crashing_instruction_is_located_in_source() {
+        let program = Program::from_code("push 1\npush 0\n  assert\nhalt").unwrap();
+        let report = crash_report_of(&program, [].into(), [].into());
+
+        let_assert!(Some(location) = &report.source_location);
+        assert!((3, 3) == (location.line, location.column));
+        assert!(report.to_string().contains("in source 3:3"));
+    }
+
+    #[test]
+    fn text_report_contains_all_sections() {
+        let program = triton_program!(call crash halt crash: push 0 assert return);
+        let report = crash_report_of(&program, [].into(), [].into());
+        let text = report.to_string();
+
+        assert!(text.starts_with("Triton VM crashed: assertion failed"));
+        assert!(text.contains("crash (address 3, returns to 2)"));
+        assert!(text.contains("Recent instructions"));
+        assert!(text.contains("Stack, top first:"));
+        assert!(text.contains("public input:"));
+    }
+
+    #[test]
+    fn json_report_is_valid_json() {
+        let program = triton_program!(call crash halt crash: push 0 assert return);
+        let report = crash_report_of(&program, [].into(), [].into());
+        let json = report.to_json();
+
+        let_assert!(Ok(value) = serde_json::from_str::<serde_json::Value>(&json));
+        assert!(report.error.to_string() == value["error"]);
+        assert!("crash" == value["call_stack"][0]["label"]);
+        assert!(report.stack.len() == value["stack"].as_array().unwrap().len());
+    }
+}
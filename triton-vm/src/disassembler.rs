@@ -0,0 +1,422 @@
+//! Turn the raw words of a program back into [Triton assembly][tasm].
+//!
+//! The [encoding](BFieldCodec::encode) of a [`Program`], and the words it is
+//! [hashed](Program::hash) from, contain no debug information. A [`Disassembly`] recovers the
+//! [`LabelledInstruction`]s from these words. Labels, type hints, and breakpoints are taken from
+//! an optional [`SymbolTable`], which can be extracted from any program that still has its debug
+//! information and stored alongside the program as a JSON “symbol file.” Call targets without a
+//! known label receive synthetic labels.
+//!
+//! Additionally, the disassembly points out the likely boundaries of functions as well as code
+//! that is unreachable from the program's entrypoint.
+//!
+//! [tasm]: https://triton-vm.org/spec/instructions.html
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::ops::Range;
+
+use itertools::Itertools;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use twenty_first::prelude::*;
+
+use crate::analysis::ControlFlow;
+use crate::analysis::ControlFlowGraph;
+use crate::error::DisassemblyError;
+use crate::instruction::LabelledInstruction;
+use crate::instruction::TypeHint;
+use crate::program::Program;
+
+type Result<T> = std::result::Result<T, DisassemblyError>;
+
+/// The debug information of a [`Program`], indexed by address.
+///
+/// # Examples
+///
+/// ```
+/// # use triton_vm::prelude::*;
+/// # use triton_vm::disassembler::SymbolTable;
+/// let program = triton_program!(push 1 call foo halt foo: hint x = stack[0] return);
+/// let symbol_file = SymbolTable::from(&program).to_json();
+///
+/// let symbols = SymbolTable::from_json(&symbol_file).unwrap();
+/// assert_eq!(Some("foo"), symbols.labels.get(&5).map(String::as_str));
+/// assert_eq!(1, symbols.type_hints[&5].len());
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SymbolTable {
+    pub labels: BTreeMap<u64, String>,
+    pub type_hints: BTreeMap<u64, Vec<TypeHint>>,
+    pub breakpoints: BTreeSet<u64>,
+}
+
+impl From<&Program> for SymbolTable {
+    fn from(program: &Program) -> Self {
+        let labels = program
+            .labels()
+            .iter()
+            .map(|(&address, label)| (address, label.clone()))
+            .collect();
+
+        let end_of_program = program.len_bwords() as u64;
+        let type_hints = (0..=end_of_program)
+            .map(|address| (address, program.type_hints_at(address)))
+            .filter(|(_, type_hints)| !type_hints.is_empty())
+            .collect();
+        let breakpoints = (0..end_of_program)
+            .filter(|&address| program.is_breakpoint(address))
+            .collect();
+
+        Self {
+            labels,
+            type_hints,
+            breakpoints,
+        }
+    }
+}
+
+impl SymbolTable {
+    /// The symbol table in JSON format, suitable for storing as a symbol file.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("symbol table must be serializable")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json)
+            .map_err(|err| DisassemblyError::MalformedSymbolFile(err.to_string()))
+    }
+}
+
+/// A [`Program`] recovered from its raw words. See the [module-level documentation](self) for
+/// details.
+///
+/// The [`Display`] implementation prints the disassembly as Triton assembly that can be parsed
+/// again. Function boundaries are separated by empty lines, and unreachable code is marked with
+/// comments.
+///
+/// # Examples
+///
+/// ```
+/// # use triton_vm::prelude::*;
+/// # use triton_vm::disassembler::Disassembly;
+/// # use triton_vm::disassembler::SymbolTable;
+/// let program = triton_program!(call foo halt unused: return foo: return);
+/// let disassembly = Disassembly::new(&program.to_bwords(), &SymbolTable::default()).unwrap();
+///
+/// assert_eq!(vec![0, 4], disassembly.function_entries());
+/// assert_eq!(vec![3..4], disassembly.unreachable_code());
+/// assert_eq!(program.hash::<Tip5>(), disassembly.program().hash::<Tip5>());
+/// println!("{disassembly}");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Disassembly {
+    /// The recovered labelled instructions, each with the address it refers to.
+    listing: Vec<(u64, LabelledInstruction)>,
+
+    function_entries: Vec<u64>,
+    unreachable_code: Vec<Range<u64>>,
+}
+
+impl Disassembly {
+    /// Disassemble the given words, which must be in the format of [`Program::to_bwords`].
+    pub fn new(bwords: &[BFieldElement], symbols: &SymbolTable) -> Result<Self> {
+        let encoding = [vec![bfe!(bwords.len() as u64)], bwords.to_vec()].concat();
+        let program = *Program::decode(&encoding)?;
+        let end_of_program = program.len_bwords() as u64;
+        let control_flow_graph = ControlFlowGraph::new(&program);
+        Self::check_alignment(&control_flow_graph, end_of_program, symbols)?;
+
+        let mut labels = symbols.labels.clone();
+        for address in control_flow_graph.addresses() {
+            let Some(ControlFlow::Call { destination, .. }) =
+                control_flow_graph.control_flow_at(address)
+            else {
+                continue;
+            };
+            let synthetic_label = || format!("address_{destination}");
+            labels.entry(destination).or_insert_with(synthetic_label);
+        }
+        if let Some(label) = labels.values().duplicates().next() {
+            return Err(DisassemblyError::DuplicateLabel(label.clone()));
+        }
+
+        let type_hints_at = |address: u64| symbols.type_hints.get(&address).into_iter().flatten();
+        let mut listing = vec![];
+        let mut address = 0;
+        for instruction in program {
+            if let Some(label) = labels.get(&address) {
+                listing.push((address, LabelledInstruction::Label(label.clone())));
+            }
+            for type_hint in type_hints_at(address) {
+                listing.push((address, LabelledInstruction::TypeHint(type_hint.clone())));
+            }
+            if symbols.breakpoints.contains(&address) {
+                listing.push((address, LabelledInstruction::Breakpoint));
+            }
+            let instruction_with_label =
+                instruction.map_call_address(|destination| labels[&destination.value()].clone());
+            let instruction_with_label = LabelledInstruction::Instruction(instruction_with_label);
+            listing.push((address, instruction_with_label));
+            address += instruction.size() as u64;
+        }
+        for type_hint in type_hints_at(end_of_program) {
+            listing.push((address, LabelledInstruction::TypeHint(type_hint.clone())));
+        }
+        for (&address, label) in labels.range(end_of_program..) {
+            listing.push((address, LabelledInstruction::Label(label.clone())));
+        }
+
+        let function_entries = control_flow_graph.function_entries();
+        let unreachable_code = Self::unreachable_code_in(&control_flow_graph);
+
+        Ok(Self {
+            listing,
+            function_entries,
+            unreachable_code,
+        })
+    }
+
+    /// Make sure all symbols and call targets refer to the start of some instruction. Labels
+    /// and call targets may also refer to addresses past the end of the program, type hints
+    /// and breakpoints only to the end of the program itself.
+    fn check_alignment(
+        control_flow_graph: &ControlFlowGraph,
+        end_of_program: u64,
+        symbols: &SymbolTable,
+    ) -> Result<()> {
+        let is_instruction = |address| control_flow_graph.instruction_at(address).is_some();
+
+        let labels = symbols.labels.keys();
+        let debug_symbols = symbols.type_hints.keys().chain(&symbols.breakpoints);
+        let labels_within_program = labels.filter(|&&address| address < end_of_program);
+        let debug_symbols = debug_symbols.filter(|&&address| address != end_of_program);
+        let misaligned_symbol = labels_within_program
+            .chain(debug_symbols)
+            .find(|&&address| !is_instruction(address));
+        if let Some(&address) = misaligned_symbol {
+            return Err(DisassemblyError::MisalignedSymbol(address));
+        }
+
+        for address in control_flow_graph.addresses() {
+            let Some(ControlFlow::Call { destination, .. }) =
+                control_flow_graph.control_flow_at(address)
+            else {
+                continue;
+            };
+            if destination < end_of_program && !is_instruction(destination) {
+                return Err(DisassemblyError::MisalignedCallTarget {
+                    address,
+                    destination,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn unreachable_code_in(control_flow_graph: &ControlFlowGraph) -> Vec<Range<u64>> {
+        let mut reachable = HashSet::new();
+        let mut to_visit = vec![0];
+        while let Some(address) = to_visit.pop() {
+            if !reachable.insert(address) {
+                continue;
+            }
+            match control_flow_graph.control_flow_at(address) {
+                Some(ControlFlow::Continue(next)) => to_visit.push(next),
+                Some(ControlFlow::Branch { next, skip }) => to_visit.extend([next, skip]),
+                Some(ControlFlow::Call {
+                    destination,
+                    return_address,
+                }) => to_visit.extend([destination, return_address]),
+                Some(ControlFlow::Return | ControlFlow::Recurse | ControlFlow::Halt) | None => (),
+            }
+        }
+
+        let mut unreachable_code: Vec<Range<u64>> = vec![];
+        let unreachable_addresses = control_flow_graph
+            .addresses()
+            .filter(|address| !reachable.contains(address));
+        for address in unreachable_addresses {
+            let instruction = control_flow_graph.instruction_at(address).unwrap();
+            let end = address + instruction.size() as u64;
+            match unreachable_code.last_mut() {
+                Some(range) if range.end == address => range.end = end,
+                _ => unreachable_code.push(address..end),
+            }
+        }
+
+        unreachable_code
+    }
+
+    /// The recovered program, including all debug information from the symbol table.
+    pub fn program(&self) -> Program {
+        Program::new(&self.labelled_instructions())
+    }
+
+    pub fn labelled_instructions(&self) -> Vec<LabelledInstruction> {
+        self.listing
+            .iter()
+            .map(|(_, labelled_instruction)| labelled_instruction.clone())
+            .collect()
+    }
+
+    /// The addresses at which functions likely start: the program's entrypoint, and the
+    /// destinations of all `call`s, in ascending order.
+    pub fn function_entries(&self) -> Vec<u64> {
+        self.function_entries.clone()
+    }
+
+    /// The address ranges of all instructions that can never be executed, in ascending order.
+    /// Unreachable code might be a function that is never called, or a remnant of an
+    /// incomplete rewrite.
+    pub fn unreachable_code(&self) -> Vec<Range<u64>> {
+        self.unreachable_code.clone()
+    }
+}
+
+impl Display for Disassembly {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut previous_address = None;
+        for (address, labelled_instruction) in &self.listing {
+            if previous_address != Some(address) {
+                let is_function_entry = self.function_entries.contains(address);
+                if previous_address.is_some() && is_function_entry {
+                    writeln!(f)?;
+                }
+                let mut unreachable_code = self.unreachable_code.iter();
+                if let Some(range) = unreachable_code.find(|range| range.start == *address) {
+                    let Range { start, end } = range;
+                    writeln!(f, "// unreachable: addresses {start}..{end}")?;
+                }
+            }
+            writeln!(f, "{labelled_instruction}")?;
+            previous_address = Some(address);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::assert;
+    use assert2::let_assert;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use crate::error::ProgramDecodingError;
+    use crate::prelude::*;
+
+    use super::*;
+
+    #[proptest]
+    fn disassembling_with_symbols_recovers_debug_information(#[strategy(arb())] program: Program) {
+        let symbols = SymbolTable::from(&program);
+        let disassembly = Disassembly::new(&program.to_bwords(), &symbols)?;
+        let recovered_program = disassembly.program();
+
+        prop_assert_eq!(&program, &recovered_program);
+        prop_assert_eq!(
+            program.labelled_instructions(),
+            recovered_program.labelled_instructions()
+        );
+        prop_assert_eq!(symbols, SymbolTable::from(&recovered_program));
+    }
+
+    #[proptest]
+    fn disassembling_without_symbols_recovers_program(#[strategy(arb())] program: Program) {
+        let disassembly = Disassembly::new(&program.to_bwords(), &SymbolTable::default())?;
+        prop_assert_eq!(program.to_bwords(), disassembly.program().to_bwords());
+    }
+
+    #[proptest]
+    fn printed_disassembly_can_be_parsed(#[strategy(arb())] program: Program) {
+        let symbols = SymbolTable::from(&program);
+        let disassembly = Disassembly::new(&program.to_bwords(), &symbols)?;
+        let code = disassembly.to_string();
+        let parsed_program = Program::from_code(&code)?;
+        prop_assert_eq!(program, parsed_program);
+    }
+
+    #[test]
+    fn symbol_file_to_symbol_table_is_identity() {
+        let program = triton_program!(
+            push 1 call foo halt
+            foo: hint x: u32 = stack[0] break pop 1 return
+        );
+        let symbols = SymbolTable::from(&program);
+        assert!(Ok(symbols.clone()) == SymbolTable::from_json(&symbols.to_json()));
+        assert!(BTreeSet::from([5]) == symbols.breakpoints);
+    }
+
+    #[test]
+    fn malformed_symbol_file_is_reported() {
+        let_assert!(Err(err) = SymbolTable::from_json("{ \"labels\": 42 }"));
+        let_assert!(DisassemblyError::MalformedSymbolFile(_) = err);
+    }
+
+    #[test]
+    fn function_entries_and_unreachable_code_are_found() {
+        let program = triton_program!(
+            push 1 skiz call foo halt
+            dead: push 2 pop 1 return
+            foo: call bar return
+            bar: return
+            also_dead: nop nop
+        );
+        let disassembly = Disassembly::new(&program.to_bwords(), &SymbolTable::default()).unwrap();
+        assert!(vec![0, 11, 14] == disassembly.function_entries());
+        assert!(vec![6..11, 15..17] == disassembly.unreachable_code());
+
+        let listing = disassembly.to_string();
+        assert!(listing.contains("// unreachable: addresses 6..11"));
+        assert!(listing.contains("call address_11"));
+        assert!(listing.contains("\naddress_14:"));
+    }
+
+    #[test]
+    fn invalid_words_cannot_be_disassembled() {
+        let_assert!(Err(err) = Disassembly::new(&[bfe!(1)], &SymbolTable::default()));
+        let_assert!(DisassemblyError::DecodingError(err) = err);
+        let_assert!(ProgramDecodingError::MissingArgument(0, _) = err);
+    }
+
+    #[test]
+    fn misaligned_symbols_are_rejected() {
+        let program = triton_program!(push 1 pop 1 halt);
+        let mut symbols = SymbolTable::default();
+        symbols.breakpoints.insert(1);
+
+        let_assert!(Err(err) = Disassembly::new(&program.to_bwords(), &symbols));
+        assert!(DisassemblyError::MisalignedSymbol(1) == err);
+    }
+
+    #[test]
+    fn misaligned_call_targets_are_rejected() {
+        let bwords = triton_program!(push 1 call foo halt foo: return).to_bwords();
+        let bwords = [&bwords[..3], &[bfe!(1)], &bwords[4..]].concat();
+
+        let_assert!(Err(err) = Disassembly::new(&bwords, &SymbolTable::default()));
+        let_assert!(
+            DisassemblyError::MisalignedCallTarget {
+                address: 2,
+                destination: 1
+            } = err
+        );
+    }
+
+    #[test]
+    fn duplicate_labels_are_rejected() {
+        let program = triton_program!(call foo halt foo: return);
+        let mut symbols = SymbolTable::default();
+        symbols.labels.insert(2, "address_3".to_string());
+
+        let_assert!(Err(err) = Disassembly::new(&program.to_bwords(), &symbols));
+        assert!(DisassemblyError::DuplicateLabel("address_3".to_string()) == err);
+    }
+}
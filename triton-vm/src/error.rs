@@ -8,6 +8,8 @@ use twenty_first::error::MerkleTreeError;
 use twenty_first::prelude::*;
 
 use crate::instruction::Instruction;
+use crate::instruction::TypeHint;
+use crate::parser::SourceLocation;
 use crate::proof_item::ProofItem;
 use crate::proof_item::ProofItemVariant;
 use crate::proof_stream::ProofStream;
@@ -87,6 +89,9 @@ pub enum InstructionError {
     #[error("public input buffer is empty after {0} reads")]
     EmptyPublicInput(usize),
 
+    #[error("public output sink is closed")]
+    PublicOutputSinkClosed,
+
     #[error("secret input buffer is empty after {0} reads")]
     EmptySecretInput(usize),
 
@@ -186,6 +191,181 @@ pub enum FriValidationError {
     ArithmeticDomainError(#[from] ArithmeticDomainError),
 }
 
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Error)]
+pub enum SnapshotError {
+    #[error("snapshot format version {0} is not supported")]
+    UnsupportedFormatVersion(u32),
+
+    #[error("snapshot contains co-processor calls of an unfinished instruction")]
+    PendingCoProcessorCalls,
+
+    #[error("operational stack of snapshot is too shallow")]
+    OpStackTooShallow,
+}
+
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Error)]
+pub enum CoverageError {
+    #[error("coverage can only be combined for identical programs")]
+    ProgramMismatch,
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum LinkError {
+    #[error("module `{0}` is defined more than once")]
+    DuplicateModule(String),
+
+    #[error("{location}: parse error\n{message}")]
+    ParseError {
+        location: SourceLocation,
+        message: String,
+    },
+
+    #[error("{0}: unknown module `{1}`")]
+    UnknownModule(SourceLocation, String),
+
+    #[error("{0}: module `{1}` is not imported")]
+    ModuleNotImported(SourceLocation, String),
+
+    #[error("{0}: label definition `{1}` must not be qualified")]
+    QualifiedLabelDefinition(SourceLocation, String),
+
+    #[error("{0}: duplicate label `{1}`")]
+    DuplicateLabel(SourceLocation, String),
+
+    #[error("{0}: missing label `{1}`")]
+    MissingLabel(SourceLocation, String),
+}
+
+/// An issue found by the [static analysis](crate::analysis::StackAnalysis) of a program's op
+/// stack. Addresses refer to the instructions of the analyzed program; functions are identified
+/// by the address of their first instruction.
+#[non_exhaustive]
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum StackAnalysisError {
+    #[error(
+        "instruction `{instruction}` at address {address} might make the op stack too shallow \
+        (depth {depth} before the instruction)"
+    )]
+    OpStackUnderflow {
+        address: u64,
+        instruction: Instruction,
+        depth: i64,
+    },
+
+    #[error("address {address} is reachable with different op stack depths {depth} and {other}")]
+    InconsistentDepth {
+        address: u64,
+        depth: i64,
+        other: i64,
+    },
+
+    #[error(
+        "function {function} returns with different op stack depth changes {depth_change} and \
+        {other} (at address {address})"
+    )]
+    InconsistentReturn {
+        function: u64,
+        address: u64,
+        depth_change: i64,
+        other: i64,
+    },
+
+    #[error("`recurse` at address {address} changes the op stack depth by {depth_change}")]
+    UnbalancedRecursion { address: u64, depth_change: i64 },
+
+    #[error("instruction `{instruction}` at address {address} is reachable outside any function")]
+    JumpStackUnderflow {
+        address: u64,
+        instruction: Instruction,
+    },
+
+    #[error("execution might run past the end of the program after address {0}")]
+    RunsPastEnd(u64),
+
+    #[error("the op stack depth after the recursive call at address {0} cannot be determined")]
+    RecursiveCall(u64),
+
+    #[error("type hint `{type_hint}` at address {address} exceeds the op stack depth {depth}")]
+    TypeHintOutOfRange {
+        address: u64,
+        type_hint: TypeHint,
+        depth: i64,
+    },
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum DisassemblyError {
+    #[error(transparent)]
+    DecodingError(#[from] ProgramDecodingError),
+
+    #[error("symbol file is malformed: {0}")]
+    MalformedSymbolFile(String),
+
+    #[error("symbol at address {0} does not refer to the start of an instruction")]
+    MisalignedSymbol(u64),
+
+    #[error("call at address {address} targets the middle of an instruction: {destination}")]
+    MisalignedCallTarget { address: u64, destination: u64 },
+
+    #[error("label \"{0}\" is defined more than once")]
+    DuplicateLabel(String),
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum FormatError {
+    #[error("cannot parse the code to format:\n{0}")]
+    ParseError(String),
+
+    #[error("cannot parse the formatted code:\n{0}")]
+    InvalidFormattedCode(String),
+
+    #[error("formatting changed the program's digest from {original} to {formatted}")]
+    DigestMismatch { original: Digest, formatted: Digest },
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum PeepholeRuleError {
+    #[error("rule \"{rule}\" does not apply to its own example \"{example}\"")]
+    NotApplicable { rule: String, example: String },
+
+    #[error("rule \"{rule}\" does not shorten its example \"{example}\"")]
+    NotShortening { rule: String, example: String },
+
+    #[error("rule \"{rule}\" changes the semantics of \"{example}\"")]
+    SemanticsChanged { rule: String, example: String },
+}
+
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum CalibrationError {
+    #[error("calibration requires samples of at least 4 different padded heights, got {0}")]
+    TooFewPaddedHeights(usize),
+
+    #[error("the calibration samples do not determine the cost model")]
+    DegenerateSamples,
+
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+
+    #[error(transparent)]
+    JsonError(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    FriSetupError(#[from] FriSetupError),
+
+    #[error(transparent)]
+    ProvingError(#[from] ProvingError),
+
+    #[error(transparent)]
+    VMError(#[from] VMError),
+}
+
 #[non_exhaustive]
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Error)]
 pub enum ProgramDecodingError {
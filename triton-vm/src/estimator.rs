@@ -0,0 +1,481 @@
+//! Estimate the cost of proving a program's execution without running the prover.
+//!
+//! The cost of proving is determined almost entirely by the [padded height][padded_height] of the
+//! execution trace, which can be obtained cheaply by [profiling](Program::profile) the program.
+//! Together with the [`Stark`] parameters, the padded height determines the length of the FRI
+//! domain, and with it, the size of the low-degree extended tables the prover has to compute,
+//! commit to, and keep in memory.
+//!
+//! How long that takes depends on the machine. A [`Calibration`] captures this by timing a few
+//! small proofs. The calibration can be [saved](Calibration::save) and
+//! [loaded](Calibration::load) to avoid repeating the measurements.
+//!
+//! [padded_height]: crate::aet::AlgebraicExecutionTrace::padded_height
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::time::Duration;
+use std::time::Instant;
+
+use itertools::Itertools;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use twenty_first::prelude::*;
+
+use crate::error::CalibrationError;
+use crate::error::FriSetupError;
+use crate::profiler::display_bytes;
+use crate::profiler::TrackingAllocator;
+use crate::profiler::TritonProfiler;
+use crate::program::Program;
+use crate::program::PublicInput;
+use crate::program::VMProfilingReport;
+use crate::proof::Claim;
+use crate::stark::Stark;
+use crate::table::NUM_BASE_COLUMNS;
+use crate::table::NUM_EXT_COLUMNS;
+use crate::triton_program;
+
+type Result<T> = std::result::Result<T, CalibrationError>;
+
+/// The number of different padded heights needed to [calibrate](Calibration::from_samples) the
+/// estimation of all costs. It exceeds the number of coefficients of every cost model, such that
+/// no model can fit the samples merely by having enough degrees of freedom.
+pub const MIN_NUM_CALIBRATION_HEIGHTS: usize = 4;
+
+/// The measured cost of proving one execution, used to [calibrate](Calibration) the estimates.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct CalibrationSample {
+    pub padded_height: usize,
+    pub fri_domain_length: usize,
+    pub prover_time: Duration,
+
+    /// The peak number of allocated bytes during proving. `None` if memory was not being tracked,
+    /// see [`TrackingAllocator`].
+    pub peak_memory: Option<usize>,
+
+    /// The size of the proof in bytes.
+    pub proof_size: usize,
+}
+
+/// The costs of proving on the current machine, measured for a few small proofs. Allows
+/// [estimating](Self::estimate) the costs of larger proofs.
+///
+/// The prover's time is modeled as growing with `n·log(n)`, where `n` is the number of field
+/// elements in the low-degree extended tables. The prover's peak memory is modeled as growing
+/// linearly in `n`. The size of a proof is modeled as growing quadratically in the logarithm of
+/// the FRI domain length: every FRI round adds authentication paths of logarithmic length.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Calibration {
+    samples: Vec<CalibrationSample>,
+    prover_time: LinearModel,
+    peak_memory: Option<LinearModel>,
+    proof_size: LinearModel,
+}
+
+/// The estimated cost of generating a proof, as well as the parameters the estimate is based on.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ProverCostEstimate {
+    pub padded_height: usize,
+    pub fri_domain_length: usize,
+    pub num_base_columns: usize,
+    pub num_ext_columns: usize,
+    pub prover_time: Duration,
+
+    /// The estimated peak number of allocated bytes. `None` if the calibration did not track
+    /// memory, see [`TrackingAllocator`].
+    pub peak_memory: Option<usize>,
+
+    /// The estimated size of the proof in bytes.
+    pub proof_size: usize,
+}
+
+/// A model `y = Σ cᵢ·xᵢ`, where the `xᵢ` are the features of some input, and the coefficients
+/// `cᵢ` are fitted using least squares.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct LinearModel {
+    coefficients: Vec<f64>,
+}
+
+impl Calibration {
+    /// Calibrate by proving executions of the given padded heights, each given as the logarithm
+    /// to base 2. Requires at least [`MIN_NUM_CALIBRATION_HEIGHTS`] different heights. Heights
+    /// below 2^8 are not possible, as the lookup table always has 2^8 rows.
+    ///
+    /// To calibrate the estimation of the peak memory, install the [`TrackingAllocator`] as the
+    /// global allocator and [enable](TrackingAllocator::enable) it.
+    pub fn measure(stark: Stark, log2_padded_heights: RangeInclusive<u32>) -> Result<Self> {
+        let samples = log2_padded_heights
+            .map(|log2_padded_height| Self::measure_sample(stark, log2_padded_height))
+            .try_collect()?;
+        Self::from_samples(samples)
+    }
+
+    fn measure_sample(stark: Stark, log2_padded_height: u32) -> Result<CalibrationSample> {
+        let (program, public_input) = Self::program_of_padded_height(log2_padded_height);
+        let (aet, output) = program.trace_execution(public_input.clone(), [].into())?;
+        let claim = Claim::about_program(&program)
+            .with_input(public_input.individual_tokens)
+            .with_output(output);
+
+        let padded_height = aet.padded_height();
+        let fri_domain_length = stark.derive_fri(padded_height)?.domain.length;
+
+        let mut maybe_profiler = TrackingAllocator::allocated_bytes()
+            .map(|_| TritonProfiler::new(format!("calibration for 2^{log2_padded_height}")));
+        let start = Instant::now();
+        let proof = stark.prove(&claim, &aet, &mut maybe_profiler)?;
+        let prover_time = start.elapsed();
+        let peak_memory = maybe_profiler.and_then(|mut profiler| profiler.report().peak_memory());
+
+        Ok(CalibrationSample {
+            padded_height,
+            fri_domain_length,
+            prover_time,
+            peak_memory,
+            proof_size: proof.0.len() * std::mem::size_of::<BFieldElement>(),
+        })
+    }
+
+    /// A program looping often enough to result in the given padded height, and its input.
+    fn program_of_padded_height(log2_padded_height: u32) -> (Program, PublicInput) {
+        let program = triton_program! {
+            read_io 1 call loop halt
+            loop: dup 0 push 0 eq skiz return push -1 add recurse
+        };
+
+        // Every iteration takes 7 clock cycles. The remaining instructions take at most 8.
+        let num_iterations = (1_u64 << log2_padded_height) / 8;
+        (program, PublicInput::from([bfe!(num_iterations)]))
+    }
+
+    /// Calibrate using previously measured samples. Requires samples of at least
+    /// [`MIN_NUM_CALIBRATION_HEIGHTS`] different padded heights.
+    ///
+    /// The peak memory is only calibrated if all samples have a peak memory.
+    pub fn from_samples(samples: Vec<CalibrationSample>) -> Result<Self> {
+        let num_heights = samples.iter().map(|s| s.padded_height).unique().count();
+        if num_heights < MIN_NUM_CALIBRATION_HEIGHTS {
+            return Err(CalibrationError::TooFewPaddedHeights(num_heights));
+        }
+
+        let fit = |features: fn(usize) -> Vec<f64>, measurement: fn(&CalibrationSample) -> f64| {
+            let observations = samples
+                .iter()
+                .map(|sample| (features(sample.fri_domain_length), measurement(sample)))
+                .collect_vec();
+            LinearModel::fit(&observations).ok_or(CalibrationError::DegenerateSamples)
+        };
+
+        let prover_time = fit(Self::prover_time_features, |s| s.prover_time.as_secs_f64())?;
+        let proof_size = fit(Self::proof_size_features, |s| s.proof_size as f64)?;
+        let peak_memory = match samples.iter().all(|s| s.peak_memory.is_some()) {
+            true => Some(fit(Self::peak_memory_features, |s| {
+                s.peak_memory.unwrap_or_default() as f64
+            })?),
+            false => None,
+        };
+
+        Ok(Self {
+            samples,
+            prover_time,
+            peak_memory,
+            proof_size,
+        })
+    }
+
+    /// The samples this calibration is based on.
+    pub fn samples(&self) -> &[CalibrationSample] {
+        &self.samples
+    }
+
+    /// Load the samples of a calibration from a JSON file, as written by [`Self::save`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let samples = fs::read_to_string(path)?;
+        let samples = serde_json::from_str(&samples)?;
+        Self::from_samples(samples)
+    }
+
+    /// Save the samples of this calibration to a JSON file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let samples = serde_json::to_string_pretty(&self.samples)?;
+        fs::write(path, samples)?;
+        Ok(())
+    }
+
+    /// Estimate the cost of proving an execution of the given padded height.
+    ///
+    /// The estimated proof size is only meaningful if the given [`Stark`] is the one used for
+    /// calibrating.
+    pub fn estimate(
+        &self,
+        stark: Stark,
+        padded_height: usize,
+    ) -> std::result::Result<ProverCostEstimate, FriSetupError> {
+        let padded_height = padded_height.next_power_of_two();
+        let fri_domain_length = stark.derive_fri(padded_height)?.domain.length;
+
+        let predict = |model: &LinearModel, features: Vec<f64>| model.predict(&features).max(0.0);
+        let prover_time = predict(
+            &self.prover_time,
+            Self::prover_time_features(fri_domain_length),
+        );
+        let peak_memory = self.peak_memory.as_ref().map(|model| {
+            let peak_memory = predict(model, Self::peak_memory_features(fri_domain_length));
+            peak_memory as usize
+        });
+        let proof_size = predict(
+            &self.proof_size,
+            Self::proof_size_features(fri_domain_length),
+        );
+
+        Ok(ProverCostEstimate {
+            padded_height,
+            fri_domain_length,
+            num_base_columns: NUM_BASE_COLUMNS,
+            num_ext_columns: NUM_EXT_COLUMNS,
+            prover_time: Duration::from_secs_f64(prover_time),
+            peak_memory,
+            proof_size: proof_size as usize,
+        })
+    }
+
+    /// Estimate the cost of proving the profiled execution. See also [`Self::estimate`].
+    pub fn estimate_profile(
+        &self,
+        stark: Stark,
+        profile: &VMProfilingReport,
+    ) -> std::result::Result<ProverCostEstimate, FriSetupError> {
+        let padded_height = usize::try_from(profile.padded_height()).unwrap();
+        self.estimate(stark, padded_height)
+    }
+
+    /// The number of base field elements in the low-degree extended tables.
+    fn num_fri_domain_elements(fri_domain_length: usize) -> f64 {
+        let row_width = NUM_BASE_COLUMNS + x_field_element::EXTENSION_DEGREE * NUM_EXT_COLUMNS;
+        (fri_domain_length * row_width) as f64
+    }
+
+    fn prover_time_features(fri_domain_length: usize) -> Vec<f64> {
+        let num_elements = Self::num_fri_domain_elements(fri_domain_length);
+        vec![1.0, num_elements * num_elements.log2()]
+    }
+
+    fn peak_memory_features(fri_domain_length: usize) -> Vec<f64> {
+        vec![1.0, Self::num_fri_domain_elements(fri_domain_length)]
+    }
+
+    fn proof_size_features(fri_domain_length: usize) -> Vec<f64> {
+        let log2_fri_domain_length = (fri_domain_length as f64).log2();
+        let squared = log2_fri_domain_length * log2_fri_domain_length;
+        vec![1.0, log2_fri_domain_length, squared]
+    }
+}
+
+impl Display for ProverCostEstimate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let log2 = |n: usize| n.checked_ilog2().unwrap_or_default();
+        let padded_height = log2(self.padded_height);
+        let fri_domain_length = log2(self.fri_domain_length);
+        let num_base_columns = self.num_base_columns;
+        let num_ext_columns = self.num_ext_columns;
+        let prover_time = self.prover_time;
+        let peak_memory = self
+            .peak_memory
+            .map_or_else(|| "unknown".to_string(), display_bytes);
+        let proof_size = display_bytes(self.proof_size);
+
+        writeln!(f, "padded height:     2^{padded_height}")?;
+        writeln!(f, "FRI domain length: 2^{fri_domain_length}")?;
+        writeln!(
+            f,
+            "columns:           {num_base_columns} base, {num_ext_columns} extension"
+        )?;
+        writeln!(f, "prover time:       {prover_time:.2?}")?;
+        writeln!(f, "peak memory:       {peak_memory}")?;
+        write!(f, "proof size:        {proof_size}")
+    }
+}
+
+impl LinearModel {
+    /// Fit the model to the given observations, each consisting of features and a measurement,
+    /// by solving the normal equations. `None` if the observations don't determine the model,
+    /// for example, if there are fewer observations than features.
+    fn fit(observations: &[(Vec<f64>, f64)]) -> Option<Self> {
+        let num_features = observations.first()?.0.len();
+
+        // the augmented matrix (XᵀX | Xᵀy)
+        let mut matrix = vec![vec![0.0; num_features + 1]; num_features];
+        for (features, measurement) in observations {
+            for (row, &feature) in matrix.iter_mut().zip(features) {
+                for (entry, &other_feature) in row.iter_mut().zip(features) {
+                    *entry += feature * other_feature;
+                }
+                row[num_features] += feature * measurement;
+            }
+        }
+
+        // Gaussian elimination with partial pivoting
+        for column in 0..num_features {
+            let pivot_row = (column..num_features)
+                .max_by(|&i, &j| matrix[i][column].abs().total_cmp(&matrix[j][column].abs()))?;
+            if matrix[pivot_row][column].abs() < f64::EPSILON {
+                return None;
+            }
+            matrix.swap(column, pivot_row);
+            let pivot = matrix[column].clone();
+            for (row_index, row) in matrix.iter_mut().enumerate() {
+                if row_index == column {
+                    continue;
+                }
+                let factor = row[column] / pivot[column];
+                for (entry, &pivot_entry) in row.iter_mut().zip(&pivot).skip(column) {
+                    *entry -= factor * pivot_entry;
+                }
+            }
+        }
+
+        let coefficients = matrix
+            .iter()
+            .enumerate()
+            .map(|(i, row)| row[num_features] / row[i])
+            .collect();
+        Some(Self { coefficients })
+    }
+
+    fn predict(&self, features: &[f64]) -> f64 {
+        self.coefficients
+            .iter()
+            .zip_eq(features)
+            .map(|(coefficient, feature)| coefficient * feature)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::assert;
+    use assert2::let_assert;
+
+    use super::*;
+
+    fn synthetic_sample(log2_padded_height: u32) -> CalibrationSample {
+        let stark = Stark::default();
+        let padded_height = 1 << log2_padded_height;
+        let fri_domain_length = stark.derive_fri(padded_height).unwrap().domain.length;
+        let time = Calibration::prover_time_features(fri_domain_length)[1] * 1e-9;
+        let memory = 100 + 8 * Calibration::num_fri_domain_elements(fri_domain_length) as usize;
+        let log2_fri_domain_length = fri_domain_length.ilog2() as usize;
+
+        CalibrationSample {
+            padded_height,
+            fri_domain_length,
+            prover_time: Duration::from_secs_f64(time),
+            peak_memory: Some(memory),
+            proof_size: 1000 + 50 * log2_fri_domain_length * log2_fri_domain_length,
+        }
+    }
+
+    #[test]
+    fn linear_model_recovers_exact_relationship() {
+        let observations = (0..5)
+            .map(f64::from)
+            .map(|x| (vec![1.0, x, x * x], 3.0 - 2.0 * x + 0.5 * x * x))
+            .collect_vec();
+        let_assert!(Some(model) = LinearModel::fit(&observations));
+        for (&coefficient, expected) in model.coefficients.iter().zip([3.0, -2.0, 0.5]) {
+            assert!((coefficient - expected).abs() < 1e-9);
+        }
+        assert!((model.predict(&[1.0, 10.0, 100.0]) - 33.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn linear_model_cannot_be_fitted_to_too_few_observations() {
+        let observations = [(vec![1.0, 2.0], 3.0)];
+        assert!(LinearModel::fit(&observations).is_none());
+    }
+
+    #[test]
+    fn calibration_requires_enough_different_padded_heights() {
+        let samples = vec![
+            synthetic_sample(8),
+            synthetic_sample(9),
+            synthetic_sample(10),
+            synthetic_sample(10),
+        ];
+        let_assert!(Err(err) = Calibration::from_samples(samples));
+        let_assert!(CalibrationError::TooFewPaddedHeights(3) = err);
+    }
+
+    #[test]
+    fn calibration_extrapolates_synthetic_costs() {
+        let samples = (8..=11).map(synthetic_sample).collect();
+        let_assert!(Ok(calibration) = Calibration::from_samples(samples));
+
+        let expected = synthetic_sample(20);
+        let_assert!(Ok(estimate) = calibration.estimate(Stark::default(), 1 << 20));
+        assert!(expected.padded_height == estimate.padded_height);
+        assert!(expected.fri_domain_length == estimate.fri_domain_length);
+
+        let relative_error = |actual: f64, expected: f64| (actual - expected).abs() / expected;
+        let time = estimate.prover_time.as_secs_f64();
+        assert!(relative_error(time, expected.prover_time.as_secs_f64()) < 1e-3);
+        let_assert!(Some(peak_memory) = estimate.peak_memory);
+        let_assert!(Some(expected_peak_memory) = expected.peak_memory);
+        assert!(relative_error(peak_memory as f64, expected_peak_memory as f64) < 1e-3);
+        assert!(relative_error(estimate.proof_size as f64, expected.proof_size as f64) < 1e-3);
+
+        println!("{estimate}");
+    }
+
+    #[test]
+    fn estimate_explains_its_inputs() {
+        let samples = (8..=11).map(synthetic_sample).collect();
+        let_assert!(Ok(calibration) = Calibration::from_samples(samples));
+        let_assert!(Ok(estimate) = calibration.estimate(Stark::default(), 1000));
+        assert!(1024 == estimate.padded_height);
+
+        let estimate = estimate.to_string();
+        assert!(estimate.contains("padded height:     2^10"));
+        assert!(estimate.contains(&format!("{NUM_BASE_COLUMNS} base")));
+        assert!(estimate.contains(&format!("{NUM_EXT_COLUMNS} extension")));
+    }
+
+    #[test]
+    fn calibration_survives_saving_and_loading() {
+        let samples = (8..=11).map(synthetic_sample).collect();
+        let_assert!(Ok(calibration) = Calibration::from_samples(samples));
+
+        let file_name = format!("triton_vm_calibration_test_{}.json", std::process::id());
+        let path = std::env::temp_dir().join(file_name);
+        let_assert!(Ok(()) = calibration.save(&path));
+        let_assert!(Ok(loaded) = Calibration::load(&path));
+        assert!(calibration.samples() == loaded.samples());
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn calibration_programs_have_requested_padded_heights() {
+        for log2_padded_height in 8..=12 {
+            let (program, input) = Calibration::program_of_padded_height(log2_padded_height);
+            let_assert!(Ok((aet, _)) = program.trace_execution(input, [].into()));
+            assert!(1 << log2_padded_height == aet.padded_height());
+        }
+    }
+
+    #[test]
+    fn calibration_can_be_measured() {
+        let_assert!(Ok(calibration) = Calibration::measure(Stark::default(), 8..=11));
+        assert!(4 == calibration.samples().len());
+
+        let (program, input) = Calibration::program_of_padded_height(12);
+        let_assert!(Ok((_, profile)) = program.profile(input, [].into()));
+        let_assert!(Ok(estimate) = calibration.estimate_profile(Stark::default(), &profile));
+        assert!(1 << 12 == estimate.padded_height);
+        println!("{estimate}");
+    }
+}
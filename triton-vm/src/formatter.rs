@@ -0,0 +1,575 @@
+//! A canonical formatter for [Triton assembly][tasm].
+//!
+//! [Formatting](format) puts every label, instruction, breakpoint, type hint, and directive on a
+//! line of its own. Labels and directives – constant declarations, macro definitions, and static
+//! data – start at the beginning of the line, everything else is indented. Arguments are
+//! separated from instructions by exactly one space.
+//!
+//! Arguments of instructions, constant declarations, static data, and macro invocations are
+//! [constant expressions](crate::parser) and are formatted canonically: binary operators are
+//! surrounded by exactly one space, while unary negation, parentheses, and the commas separating
+//! multiple arguments are not preceded by any space. Named constants are kept, not replaced by their
+//! values.
+//!
+//! Comments are kept. A comment following some code on the same line, like a comment describing
+//! the stack, stays on that line. The trailing comments of consecutive lines are aligned to the
+//! same column. Comments on lines of their own are indented like the code following them. Runs of
+//! empty lines are collapsed into a single empty line.
+//!
+//! Of a macro definition, only the signature `macro <name>(<PARAMETERS>)` is formatted. The body
+//! can only be parsed once the macro is invoked, and is kept verbatim, except that whitespace is
+//! removed from the end of every line. The same holds for a signature containing comments.
+//!
+//! Formatting is idempotent, and does not change the formatted program: the [`Program`]s before
+//! and after formatting have the same [hash](Program::hash).
+//!
+//! [tasm]: https://triton-vm.org/spec/instructions.html
+
+use itertools::Itertools;
+use nom::Finish;
+use twenty_first::prelude::*;
+
+use crate::error::FormatError;
+use crate::instruction::AnInstruction;
+use crate::instruction::LabelledInstruction;
+use crate::parser;
+use crate::parser::InstructionToken;
+use crate::parser::SourceItem;
+use crate::program::Program;
+
+type Result<T> = std::result::Result<T, FormatError>;
+
+/// The indentation of instructions, breakpoints, type hints, and macro invocations.
+const INDENTATION: &str = "    ";
+
+/// Format the given Triton assembly. See the [module-level documentation](self) for details.
+///
+/// # Errors
+///
+/// Returns an error if the code cannot be parsed, or – indicating a bug in the formatter – if the
+/// formatted code corresponds to a different program.
+///
+/// # Examples
+///
+/// ```
+/// # use triton_vm::formatter::format;
+/// let code = "push 1 push 2 // _ 1 2\n  add\n  foo :   hint   x=stack[0] // _ 3\n break halt";
+/// let formatted_code = format(code).unwrap();
+///
+/// let expected = "    push 1
+///     push 2 // _ 1 2
+///     add
+/// foo:
+///     hint x = stack[0] // _ 3
+///     break
+///     halt
+/// ";
+/// assert_eq!(expected, formatted_code);
+/// assert_eq!(formatted_code, format(&formatted_code).unwrap());
+/// ```
+pub fn format(code: &str) -> Result<String> {
+    let (_, items) = parser::source_items(code)
+        .finish()
+        .map_err(|errors| FormatError::ParseError(parser::pretty_print_error(code, errors)))?;
+    let tokens = items
+        .iter()
+        .flat_map(|item| item.tokens())
+        .cloned()
+        .collect_vec();
+    parser::ensure_no_missing_or_duplicate_labels(code, &tokens)
+        .map_err(|err| FormatError::ParseError(err.to_string()))?;
+    let program = Program::new(&parser::to_labelled_instructions(&tokens));
+
+    let mut lines = vec![];
+    let length_of_items = items.iter().map(|item| item.span().len()).sum::<usize>();
+    let leading_trivia = &code[..code.len() - length_of_items];
+    let leading_trivia = leading_trivia.split('\n').collect_vec();
+    lines.extend(Trivia::of(&leading_trivia).into_lines());
+    for item in items {
+        lines.extend(format_item(&item));
+    }
+
+    let formatted_code = render(&lines);
+    let formatted_program = Program::from_code(&formatted_code)
+        .map_err(|err| FormatError::InvalidFormattedCode(err.to_string()))?;
+    let original = program.hash::<Tip5>();
+    let formatted = formatted_program.hash::<Tip5>();
+    if original != formatted {
+        return Err(FormatError::DigestMismatch {
+            original,
+            formatted,
+        });
+    }
+
+    Ok(formatted_code)
+}
+
+/// One line of formatted code.
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Line {
+    Empty,
+    Comment(String),
+    Code {
+        is_indented: bool,
+        code: String,
+        trailing_comment: Option<String>,
+    },
+}
+
+impl Line {
+    fn is_code(&self) -> bool {
+        matches!(self, Line::Code { .. })
+    }
+
+    fn starts_block(&self) -> bool {
+        match self {
+            Line::Code { is_indented, .. } => !is_indented,
+            _ => true,
+        }
+    }
+
+    fn has_trailing_comment(&self) -> bool {
+        matches!(
+            self,
+            Line::Code {
+                trailing_comment: Some(_),
+                ..
+            }
+        )
+    }
+
+    fn indentation(&self) -> &'static str {
+        match self {
+            Line::Code {
+                is_indented: true, ..
+            } => INDENTATION,
+            _ => "",
+        }
+    }
+
+    /// The width of the line's last line of code, including indentation.
+    fn width(&self) -> usize {
+        let Line::Code { code, .. } = self else {
+            return 0;
+        };
+        let last_line_of_code = code.lines().last().unwrap_or_default();
+        self.indentation().len() + last_line_of_code.chars().count()
+    }
+}
+
+/// The comments and empty lines surrounding some code.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+struct Trivia {
+    /// The comments interrupting the code, like in `push // comment ⏎ 1`.
+    interrupting_comments: Vec<String>,
+
+    /// The comment following the code on the same line.
+    trailing_comment: Option<String>,
+
+    /// The lines following the code.
+    following_lines: Vec<Line>,
+}
+
+impl Trivia {
+    /// The trivia of the given lines, all of which must consist of comments or whitespace only.
+    /// Unless it contains a comment, the last line is the one containing the next code, and is
+    /// ignored.
+    fn of(lines: &[&str]) -> Self {
+        let mut following_lines = vec![];
+        for (index, line) in lines.iter().enumerate() {
+            let line = line.trim();
+            let is_last_line = index + 1 == lines.len();
+            if line.is_empty() && is_last_line {
+                break;
+            }
+            let next_line = if line.is_empty() {
+                Line::Empty
+            } else {
+                Line::Comment(line.to_string())
+            };
+            if next_line != Line::Empty || following_lines.last() != Some(&Line::Empty) {
+                following_lines.push(next_line);
+            }
+        }
+
+        Self {
+            following_lines,
+            ..Self::default()
+        }
+    }
+
+    fn into_lines(self) -> Vec<Line> {
+        self.following_lines
+    }
+}
+
+/// Split the source code of an item into its code and its trivia. The code of all but macro
+/// definitions is joined into a single line.
+fn split_span(span: &str, is_macro_definition: bool) -> (String, Trivia) {
+    let lines = span.split('\n').collect_vec();
+    let split_off_comment = |line: &str| match line.find("//") {
+        Some(index) => (
+            line[..index].to_string(),
+            Some(line[index..].trim().to_string()),
+        ),
+        None => (line.to_string(), None),
+    };
+    let lines_with_comments = lines
+        .iter()
+        .map(|line| split_off_comment(line))
+        .collect_vec();
+    let last_line_with_code = lines_with_comments
+        .iter()
+        .rposition(|(code, _)| !code.trim().is_empty())
+        .expect("every item contains code");
+
+    let mut code_lines = vec![];
+    let mut interrupting_comments = vec![];
+    for (line, (code, comment)) in lines.iter().zip(&lines_with_comments) {
+        if code_lines.len() == last_line_with_code {
+            code_lines.push(code.trim_end());
+            break;
+        }
+        if is_macro_definition {
+            code_lines.push(line.trim_end());
+        } else {
+            code_lines.push(code.trim_end());
+            interrupting_comments.extend(comment.clone());
+        }
+    }
+    let code = if is_macro_definition {
+        code_lines.join("\n").trim().to_string()
+    } else {
+        code_lines
+            .iter()
+            .flat_map(|line| line.split_whitespace())
+            .join(" ")
+    };
+
+    let trailing_comment = lines_with_comments[last_line_with_code].1.clone();
+    let trivia = Trivia {
+        interrupting_comments,
+        trailing_comment,
+        ..Trivia::of(&lines[last_line_with_code + 1..])
+    };
+
+    (code, trivia)
+}
+
+fn format_item(item: &SourceItem) -> Vec<Line> {
+    let is_macro_definition = matches!(item, SourceItem::MacroDefinition(_));
+    let (code, trivia) = split_span(item.span(), is_macro_definition);
+
+    let (is_indented, code) = match item {
+        SourceItem::ConstantDeclaration(_) => (false, format_constant_declaration(&code)),
+        SourceItem::MacroDefinition(_) => (false, format_macro_definition(&code)),
+        SourceItem::MacroInvocation(..) => (true, format_macro_invocation(&code)),
+        SourceItem::Token(InstructionToken::Label(label, _), _) => {
+            (false, LabelledInstruction::Label(label.clone()).to_string())
+        }
+        SourceItem::Token(InstructionToken::Instruction(AnInstruction::Call(_), _), _) => {
+            (true, code)
+        }
+        SourceItem::Token(InstructionToken::Instruction(..), _) => {
+            (true, format_instruction(&code))
+        }
+        SourceItem::Token(InstructionToken::Breakpoint(_), _) => {
+            (true, LabelledInstruction::Breakpoint.to_string())
+        }
+        SourceItem::Token(InstructionToken::TypeHint(type_hint, _), _) => {
+            (true, type_hint.to_string())
+        }
+        SourceItem::Token(InstructionToken::Data(..), _) => (false, format_static_data(&code)),
+    };
+
+    let interrupting_comments = trivia.interrupting_comments.into_iter();
+    let mut lines = interrupting_comments.map(Line::Comment).collect_vec();
+    lines.push(Line::Code {
+        is_indented,
+        code,
+        trailing_comment: trivia.trailing_comment,
+    });
+    lines.extend(trivia.following_lines);
+    lines
+}
+
+/// Format a constant declaration, like `const NAME = <expression>`.
+fn format_constant_declaration(code: &str) -> String {
+    let (name, expression) = code
+        .trim_start_matches("const")
+        .split_once('=')
+        .expect("constant declaration contains `=`");
+    let expression = format_expression(expression);
+    format!("const {} = {expression}", name.trim())
+}
+
+/// Format an instruction with an optional argument, like `push <expression>`. Since labels can
+/// contain `-`, the argument must not be a label.
+fn format_instruction(code: &str) -> String {
+    match code.split_once(' ') {
+        Some((mnemonic, argument)) => format!("{mnemonic} {}", format_expression(argument)),
+        None => code.to_string(),
+    }
+}
+
+/// Format the signature of a macro definition, like `macro name(<PARAMETERS>)`, keeping the body.
+fn format_macro_definition(code: &str) -> String {
+    let (signature, body) = code.split_once(')').expect("macro definition contains `)`");
+    if signature.contains("//") {
+        return code.to_string();
+    }
+    let (name, parameters) = signature
+        .trim_start_matches("macro")
+        .split_once('(')
+        .expect("macro definition contains `(`");
+    let parameters = parameters
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .collect_vec();
+    format!("macro {}({}){body}", name.trim(), parameters.join(", "))
+}
+
+/// Format a macro invocation, like `name(<expression>, ...)`.
+fn format_macro_invocation(code: &str) -> String {
+    let (name, arguments) = code.split_once('(').expect("macro invocation contains `(`");
+    let arguments = arguments.trim_end().strip_suffix(')').unwrap_or(arguments);
+    let arguments = arguments
+        .split(',')
+        .map(format_expression)
+        .filter(|a| !a.is_empty())
+        .collect_vec();
+    format!("{}({})", name.trim(), arguments.join(", "))
+}
+
+/// Format static data, like `.data <expression>: <expression>, ...`.
+fn format_static_data(code: &str) -> String {
+    let (address, values) = code
+        .trim_start_matches(".data")
+        .split_once(':')
+        .expect("static data contains `:`");
+    let address = format_expression(address);
+    let values = values.split(',').map(format_expression).collect_vec();
+    format!(".data {address}: {}", values.join(", "))
+}
+
+/// Format a [constant expression](crate::parser), like `-(N + 1) * 2 ^ 32`. Binary
+/// operators are surrounded by exactly one space. Unary negation and parentheses are not
+/// separated from their operands.
+fn format_expression(expression: &str) -> String {
+    let is_operator = |c: char| "+-*/^".contains(c);
+    let is_operand_char = |c: char| !c.is_whitespace() && !is_operator(c) && !"()".contains(c);
+
+    let mut formatted = String::new();
+    let mut follows_operand = false;
+    let mut chars = expression.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            _ if c.is_whitespace() => continue,
+            '(' => formatted.push('('),
+            ')' => formatted.push(')'),
+            _ if is_operator(c) && follows_operand => formatted.push_str(&format!(" {c} ")),
+            _ if is_operator(c) => formatted.push(c),
+            _ => {
+                if follows_operand {
+                    formatted.push(' ');
+                }
+                formatted.push(c);
+                while let Some(c) = chars.next_if(|&c| is_operand_char(c)) {
+                    formatted.push(c);
+                }
+            }
+        }
+        follows_operand = c == ')' || is_operand_char(c);
+    }
+
+    formatted
+}
+
+fn render(lines: &[Line]) -> String {
+    let lines = lines
+        .iter()
+        .skip_while(|&line| line == &Line::Empty)
+        .collect_vec();
+    let num_trailing_empty_lines = lines
+        .iter()
+        .rev()
+        .take_while(|&&line| line == &Line::Empty)
+        .count();
+    let lines = &lines[..lines.len() - num_trailing_empty_lines];
+    let comment_columns = trailing_comment_columns(lines);
+
+    let mut formatted_code = String::new();
+    for (index, &line) in lines.iter().enumerate() {
+        match line {
+            Line::Empty => (),
+            Line::Comment(comment) => {
+                let next_code = lines[index..].iter().find(|line| line.is_code());
+                let indentation = next_code.map_or("", |line| line.indentation());
+                formatted_code.push_str(indentation);
+                formatted_code.push_str(comment);
+            }
+            Line::Code {
+                code,
+                trailing_comment,
+                ..
+            } => {
+                formatted_code.push_str(line.indentation());
+                formatted_code.push_str(code);
+                if let Some(comment) = trailing_comment {
+                    let padding = comment_columns[index] - line.width();
+                    formatted_code.push_str(&format!("{:padding$} {comment}", ""));
+                }
+            }
+        }
+        formatted_code.push('\n');
+    }
+
+    formatted_code
+}
+
+/// The column at which the trailing comment of each line starts. Trailing comments are aligned
+/// within blocks of consecutive lines of code. Every label and directive starts a new block.
+fn trailing_comment_columns(lines: &[&Line]) -> Vec<usize> {
+    let mut comment_columns = vec![0; lines.len()];
+    let mut block_start = 0;
+    for index in 0..=lines.len() {
+        let is_end_of_block = index == lines.len() || lines[index].starts_block();
+        if !is_end_of_block {
+            continue;
+        }
+
+        let block = block_start..index;
+        let lines_with_comments = block.clone().filter(|&i| lines[i].has_trailing_comment());
+        let column = lines_with_comments.map(|i| lines[i].width()).max();
+        for i in block {
+            comment_columns[i] = column.unwrap_or_default();
+        }
+
+        let is_code = lines.get(index).is_some_and(|line| line.is_code());
+        block_start = if is_code { index } else { index + 1 };
+    }
+
+    comment_columns
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::assert;
+    use assert2::let_assert;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use super::*;
+
+    const MESSY_CODE: &str = "// leading comment
+
+
+const  N=  3
+macro   double(X)
+  push X   // the value
+  push 2 mul
+endmacro
+.data 0 : 1,2 ,3
+push N push 1 add // _ 4
+   double( N+1 ) // _ 4 8
+pop   2
+// end of main
+halt
+foo:   return   // done
+";
+
+    #[test]
+    fn messy_code_is_formatted_canonically() {
+        let expected = "// leading comment
+
+const N = 3
+macro double(X)
+  push X   // the value
+  push 2 mul
+endmacro
+.data 0: 1, 2, 3
+    push N
+    push 1
+    add           // _ 4
+    double(N + 1) // _ 4 8
+    pop 2
+    // end of main
+    halt
+foo:
+    return // done
+";
+        let_assert!(Ok(formatted_code) = format(MESSY_CODE));
+        assert!(expected == formatted_code);
+    }
+
+    #[test]
+    fn formatting_is_idempotent() {
+        let formatted_code = format(MESSY_CODE).unwrap();
+        assert!(Ok(formatted_code.clone()) == format(&formatted_code));
+    }
+
+    #[proptest]
+    fn formatting_keeps_program_and_is_idempotent(#[strategy(arb())] program: Program) {
+        let formatted_code = format(&program.to_string())?;
+        prop_assert_eq!(program, Program::from_code(&formatted_code)?);
+        prop_assert_eq!(&formatted_code, &format(&formatted_code)?);
+    }
+
+    #[test]
+    fn comments_interrupting_code_are_moved_before_the_code() {
+        let code = "push // the answer\n 42 halt";
+        let_assert!(Ok(formatted_code) = format(code));
+        assert!("    // the answer\n    push 42\n    halt\n" == formatted_code);
+    }
+
+    #[test]
+    fn comments_at_end_of_file_are_kept() {
+        let_assert!(Ok(formatted_code) = format("halt\n\n\n// the end"));
+        assert!("    halt\n\n// the end\n" == formatted_code);
+    }
+
+    #[test]
+    fn code_without_instructions_can_be_formatted() {
+        assert!(Ok(String::new()) == format(""));
+        assert!(Ok("// nothing\n".to_string()) == format("  // nothing  \n\n"));
+    }
+
+    #[test]
+    fn unparsable_code_cannot_be_formatted() {
+        let_assert!(Err(FormatError::ParseError(_)) = format("push"));
+        let_assert!(Err(FormatError::ParseError(_)) = format("call foo halt"));
+    }
+
+    #[test]
+    fn equivalent_arguments_are_formatted_identically() {
+        let code_0 = "const N=1+2 .data N:-N,( N+1 )*2 push N+ -1 push 2^32-1 push -(N) halt";
+        let code_1 = "const N = 1 + 2\n.data N : - N , (N + 1) * 2\npush N + -1\npush 2 ^ 32 - 1\n\
+            push - ( N )\nhalt";
+        let expected = "const N = 1 + 2
+.data N: -N, (N + 1) * 2
+    push N + -1
+    push 2 ^ 32 - 1
+    push -(N)
+    halt
+";
+        let_assert!(Ok(formatted_code) = format(code_0));
+        assert!(expected == formatted_code);
+        assert!(Ok(formatted_code) == format(code_1));
+    }
+
+    #[test]
+    fn labels_in_arguments_are_kept() {
+        let_assert!(Ok(formatted_code) = format("call  foo-1  foo-1: return"));
+        assert!("    call foo-1\nfoo-1:\n    return\n" == formatted_code);
+    }
+
+    #[test]
+    fn signatures_of_macro_definitions_are_formatted_but_bodies_are_kept() {
+        let code = "macro  twice (A ,B)   push A  push B   endmacro twice(1,2) halt";
+        let expected = "macro twice(A, B)   push A  push B   endmacro\n    twice(1, 2)\n    halt\n";
+        let_assert!(Ok(formatted_code) = format(code));
+        assert!(expected == formatted_code);
+    }
+}
@@ -43,6 +43,10 @@ lazy_static! {
 }
 
 /// A `LabelledInstruction` has `call` addresses encoded as label names.
+///
+/// New kinds of assembler directives might be added in the future; hence, this enum is
+/// non-exhaustive.
+#[non_exhaustive]
 #[derive(Debug, Clone, Eq, PartialEq, Hash, EnumCount)]
 pub enum LabelledInstruction {
     /// An instructions from the [instruction set architecture][isa].
@@ -56,6 +60,9 @@ pub enum LabelledInstruction {
     Breakpoint,
 
     TypeHint(TypeHint),
+
+    /// Data that is written to RAM before the program proper starts. See [`StaticData`].
+    Data(StaticData),
 }
 
 /// A hint about a range of stack elements. Helps debugging programs written for Triton VM.
@@ -132,10 +139,69 @@ impl Display for LabelledInstruction {
             LabelledInstruction::Label(label) => write!(f, "{label}:"),
             LabelledInstruction::Breakpoint => write!(f, "break"),
             LabelledInstruction::TypeHint(type_hint) => write!(f, "{type_hint}"),
+            LabelledInstruction::Data(static_data) => write!(f, "{static_data}"),
         }
     }
 }
 
+/// Values that are written to consecutive RAM addresses before the rest of the program runs.
+///
+/// Usually constructed by parsing a `.data` directive in the assembly code. For example,
+/// ```tasm
+/// .data 42: 1, 2, 3
+/// ```
+/// writes the values 1, 2, and 3 to the RAM addresses 42, 43, and 44, respectively.
+///
+/// When [creating a program](crate::program::Program::new), all static data is turned into a
+/// prologue of regular [instructions](Self::instructions) that runs before any other
+/// instruction. As a result, the static data is part of the program: it is covered by the
+/// program's [hash](crate::program::Program::hash) and, thereby, attested to by any proof of the
+/// program's execution. If several directives declare the same address, the last one wins. Data
+/// initialized through [`NonDeterminism`](crate::NonDeterminism) is overwritten.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, GetSize)]
+pub struct StaticData {
+    /// The RAM address of the first value.
+    pub address: BFieldElement,
+    pub values: Vec<BFieldElement>,
+}
+
+impl StaticData {
+    /// The instructions writing the data to RAM. They leave the stack unchanged.
+    pub fn instructions(&self) -> Vec<LabelledInstruction> {
+        let mut instructions = vec![];
+        let mut address = self.address;
+        for chunk in self.values.chunks(N5.num_words()) {
+            let num_words = NumberOfWords::try_from(chunk.len()).expect("chunk size is legal");
+            instructions.extend(chunk.iter().rev().map(|&value| Push(value)));
+            instructions.extend([Push(address), WriteMem(num_words), Pop(N1)]);
+            address += bfe!(chunk.len() as u64);
+        }
+
+        instructions
+            .into_iter()
+            .map(LabelledInstruction::Instruction)
+            .collect()
+    }
+}
+
+impl Display for StaticData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let values = self.values.iter().join(", ");
+        write!(f, ".data {}: {values}", self.address)
+    }
+}
+
+impl<'a> Arbitrary<'a> for StaticData {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let address = u.arbitrary()?;
+        let first_value = u.arbitrary()?;
+        let other_values: Vec<_> = u.arbitrary()?;
+        let values = [vec![first_value], other_values].concat();
+
+        Ok(Self { address, values })
+    }
+}
+
 /// A Triton VM instruction. See the
 /// [Instruction Set Architecture](https://triton-vm.org/spec/isa.html)
 /// for more details.
@@ -647,6 +713,7 @@ impl<'a> Arbitrary<'a> for LabelledInstruction {
             1 => return Ok(Self::Label(u.arbitrary::<InstructionLabel>()?.into())),
             2 => return Ok(Self::Breakpoint),
             3 => return Ok(Self::TypeHint(u.arbitrary()?)),
+            4 => return Ok(Self::Data(u.arbitrary()?)),
             _ => unreachable!(),
         };
         let legal_label = String::from(u.arbitrary::<InstructionLabel>()?);
@@ -147,12 +147,21 @@ use crate::error::ProvingError;
 use crate::prelude::*;
 
 pub mod aet;
+pub mod analysis;
 pub mod arithmetic_domain;
+pub mod coverage;
+pub mod crash_report;
+pub mod disassembler;
 pub mod error;
+pub mod estimator;
 pub mod example_programs;
+pub mod formatter;
 pub mod fri;
 pub mod instruction;
+pub mod linker;
+pub mod observer;
 pub mod op_stack;
+pub mod optimizer;
 pub mod parser;
 pub mod prelude;
 pub mod profiler;
@@ -160,6 +169,7 @@ pub mod program;
 pub mod proof;
 pub mod proof_item;
 pub mod proof_stream;
+pub mod public_io;
 pub mod stark;
 pub mod table;
 pub mod vm;
@@ -216,6 +226,17 @@ mod shared_tests;
 /// );
 /// ```
 ///
+/// Named constants can be declared and used in instruction arguments:
+///
+/// ```
+/// # use triton_vm::prelude::*;
+/// let program = triton_program!(
+///     const DIGEST_LEN = 5
+///     read_mem DIGEST_LEN pop 1
+///     push 2 ^ 32 - 1 halt
+/// );
+/// ```
+///
 /// # Panics
 ///
 /// **Panics** if the program cannot be parsed.
@@ -374,6 +395,12 @@ macro_rules! triton_asm {
     (@fmt $fmt:expr, $($args:expr,)*; {$expression:expr} $($tail:tt)*) => {
         $crate::triton_asm!(@fmt concat!($fmt, "{} "), $($args,)* $expression,; $($tail)*)
     };
+    (@fmt $fmt:expr, $($args:expr,)*; .data $($tail:tt)*) => {
+        $crate::triton_asm!(@fmt concat!($fmt, " .data "), $($args,)*; $($tail)*)
+    };
+    (@fmt $fmt:expr, $($args:expr,)*; $token:tt $($tail:tt)*) => {
+        $crate::triton_asm!(@fmt concat!($fmt, " ", stringify!($token), " "), $($args,)*; $($tail)*)
+    };
 
     // repeated instructions
     [pop $arg:literal; $num:expr] => { vec![ $crate::triton_instr!(pop $arg); $num ] };
@@ -607,6 +634,14 @@ mod tests {
         implements_auto_traits::<error::FriSetupError>();
         implements_auto_traits::<error::FriProvingError>();
         implements_auto_traits::<error::FriValidationError>();
+        implements_auto_traits::<error::SnapshotError>();
+        implements_auto_traits::<error::CoverageError>();
+        implements_auto_traits::<error::LinkError>();
+        implements_auto_traits::<error::StackAnalysisError>();
+        implements_auto_traits::<error::DisassemblyError>();
+        implements_auto_traits::<error::FormatError>();
+        implements_auto_traits::<error::PeepholeRuleError>();
+        implements_auto_traits::<error::CalibrationError>();
         implements_auto_traits::<error::ProgramDecodingError>();
         implements_auto_traits::<error::ProvingError>();
         implements_auto_traits::<error::VerificationError>();
@@ -675,27 +710,59 @@ mod tests {
         // other
         implements_auto_traits::<aet::AlgebraicExecutionTrace>();
         implements_auto_traits::<aet::TableHeight>();
+        implements_auto_traits::<analysis::ControlFlow>();
+        implements_auto_traits::<analysis::ControlFlowGraph>();
+        implements_auto_traits::<analysis::StackAnalysis>();
         implements_auto_traits::<arithmetic_domain::ArithmeticDomain>();
+        implements_auto_traits::<coverage::Coverage>();
+        implements_auto_traits::<coverage::InstructionCoverage>();
+        implements_auto_traits::<coverage::LabelCoverage>();
+        implements_auto_traits::<crash_report::CrashReport>();
+        implements_auto_traits::<crash_report::CrashReporter<'static>>();
+        implements_auto_traits::<crash_report::CallFrame>();
+        implements_auto_traits::<crash_report::ExecutedInstruction>();
+        implements_auto_traits::<crash_report::StackElement>();
+        implements_auto_traits::<crash_report::InputConsumption>();
+        implements_auto_traits::<disassembler::Disassembly>();
+        implements_auto_traits::<disassembler::SymbolTable>();
+        implements_auto_traits::<estimator::Calibration>();
+        implements_auto_traits::<estimator::CalibrationSample>();
+        implements_auto_traits::<estimator::ProverCostEstimate>();
         implements_auto_traits::<fri::Fri<Tip5>>();
         implements_auto_traits::<TypeHint>();
         implements_auto_traits::<instruction::AnInstruction<usize>>();
         implements_auto_traits::<instruction::InstructionBit>();
+        implements_auto_traits::<instruction::StaticData>();
+        implements_auto_traits::<linker::Linker>();
+        implements_auto_traits::<linker::Module>();
         implements_auto_traits::<op_stack::OpStack>();
         implements_auto_traits::<op_stack::UnderflowIO>();
         implements_auto_traits::<op_stack::OpStackElement>();
         implements_auto_traits::<op_stack::NumberOfWords>();
+        implements_auto_traits::<optimizer::PeepholeOptimizer>();
+        implements_auto_traits::<optimizer::Rewrite>();
+        implements_auto_traits::<optimizer::RuleReport>();
         implements_auto_traits::<parser::ParseError>();
         implements_auto_traits::<parser::InstructionToken>();
+        implements_auto_traits::<parser::SourceLocation>();
         implements_auto_traits::<profiler::TritonProfiler>();
         implements_auto_traits::<profiler::Report>();
+        implements_auto_traits::<profiler::ReportComparison>();
+        implements_auto_traits::<profiler::TimeComparison>();
+        implements_auto_traits::<profiler::ProfilerHandle>();
+        implements_auto_traits::<profiler::SpanGuard>();
+        implements_auto_traits::<profiler::TrackingAllocator>();
         implements_auto_traits::<program::InstructionIter>();
         implements_auto_traits::<program::ProfileLine>();
         implements_auto_traits::<program::VMProfilingReport>();
+        implements_auto_traits::<program::VMTable>();
         implements_auto_traits::<program::VMTableHeights>();
         implements_auto_traits::<proof_item::FriResponse>();
         implements_auto_traits::<proof_item::ProofItem>();
         implements_auto_traits::<proof_stream::ProofStream>();
+        implements_auto_traits::<public_io::PublicIoTranscript>();
         implements_auto_traits::<vm::CoProcessorCall>();
+        implements_auto_traits::<vm::VMSnapshot>();
     }
 
     #[proptest]
@@ -0,0 +1,615 @@
+//! Linking of [Triton assembly][tasm] programs spanning multiple source files.
+//!
+//! Each source file is a [`Module`] with a unique name. A module can make the labels of other
+//! modules available using `import` directives, which must precede all instructions:
+//!
+//! ```tasm
+//! import std::hashing
+//!
+//! call std::hashing::absorb_all
+//! ```
+//!
+//! Labels are namespaced by their module: within module `std::hashing`, label `absorb_all` can be
+//! called as `absorb_all`; from anywhere else, it must be called as `std::hashing::absorb_all`.
+//! In the linked [`Program`], all labels are qualified by their module's name.
+//!
+//! The [`Linker`] concatenates all modules, starting with the main module, whose first
+//! instruction is the entrypoint of the linked program. By default, all code that can never be
+//! executed is removed. Code is considered reachable if it is the entrypoint, is the target of a
+//! reachable call, or directly follows reachable code that might not [`return`], [`recurse`], or
+//! [`halt`].
+//!
+//! [tasm]: https://triton-vm.org/spec/instructions.html
+//! [`return`]: crate::instruction::AnInstruction::Return
+//! [`recurse`]: crate::instruction::AnInstruction::Recurse
+//! [`halt`]: crate::instruction::AnInstruction::Halt
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use nom::error::VerboseError;
+use nom::Finish;
+
+use crate::error::LinkError;
+use crate::instruction::AnInstruction;
+use crate::instruction::LabelledInstruction;
+use crate::parser;
+use crate::parser::SourceLocation;
+use crate::parser::SourceLocator;
+use crate::program::Program;
+
+type Result<T> = std::result::Result<T, LinkError>;
+
+/// One source file of Triton assembly.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Module {
+    name: String,
+    file: String,
+    source: String,
+}
+
+/// Links several [`Module`]s into one [`Program`].
+///
+/// # Examples
+///
+/// ```
+/// # use triton_vm::prelude::*;
+/// # use triton_vm::linker::Linker;
+/// # use triton_vm::linker::Module;
+/// let main = Module::new("main", "import math read_io 1 call math::double write_io 1 halt");
+/// let math = Module::new("math", "double: push 2 mul return triple: push 3 mul return");
+/// let program = Linker::new(main).with_module(math).link_program().unwrap();
+///
+/// let output = program.run([bfe!(21)].into(), [].into()).unwrap();
+/// assert_eq!(bfe_vec![42], output);
+/// assert!(!program.to_string().contains("triple"));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Linker {
+    modules: Vec<Module>,
+    eliminate_dead_code: bool,
+}
+
+/// A parsed [`Module`], with all labels qualified by the name of their module.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct LinkUnit<'module> {
+    module: &'module Module,
+    imports: Vec<(String, SourceLocation)>,
+    instructions: Vec<(LabelledInstruction, SourceLocation)>,
+}
+
+/// A label and all instructions up to the next label. The first block of a module might not
+/// start with a label.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+struct Block {
+    instructions: Vec<(LabelledInstruction, SourceLocation)>,
+}
+
+impl Module {
+    /// A module with the given name and source code. The name also serves as the file name in
+    /// error messages, unless [changed](Self::with_file_name).
+    pub fn new(name: impl Into<String>, source: impl Into<String>) -> Self {
+        let name = name.into();
+        Self {
+            file: name.clone(),
+            name,
+            source: source.into(),
+        }
+    }
+
+    /// Set the file name used in error messages.
+    #[must_use]
+    pub fn with_file_name(mut self, file: impl Into<String>) -> Self {
+        self.file = file.into();
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn file_name(&self) -> &str {
+        &self.file
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    fn qualify(&self, label: &str) -> String {
+        format!("{}::{label}", self.name)
+    }
+}
+
+impl Linker {
+    /// A linker for the given main module, which contains the program's entrypoint.
+    pub fn new(main_module: Module) -> Self {
+        Self {
+            modules: vec![main_module],
+            eliminate_dead_code: true,
+        }
+    }
+
+    /// Add a module that can be imported by other modules.
+    #[must_use]
+    pub fn with_module(mut self, module: Module) -> Self {
+        self.modules.push(module);
+        self
+    }
+
+    /// Whether to remove code that can never be executed. Enabled by default.
+    /// [Static data](crate::instruction::StaticData) is never removed.
+    #[must_use]
+    pub fn with_dead_code_elimination(mut self, eliminate_dead_code: bool) -> Self {
+        self.eliminate_dead_code = eliminate_dead_code;
+        self
+    }
+
+    /// Link all modules into one [`Program`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any module cannot be parsed, or if any module refers to a label or
+    /// module that does not exist or is not imported.
+    pub fn link_program(&self) -> Result<Program> {
+        let instructions = self.link_with_source_locations()?;
+        Ok(Program::new_with_source_locations(&instructions))
+    }
+
+    /// Link all modules into one list of [`LabelledInstruction`]s.
+    ///
+    /// # Errors
+    ///
+    /// See [`link_program`](Self::link_program).
+    pub fn link(&self) -> Result<Vec<LabelledInstruction>> {
+        let instructions = self.link_with_source_locations()?;
+        let instructions = instructions.into_iter().map(|(instruction, _)| instruction);
+        Ok(instructions.collect())
+    }
+
+    fn link_with_source_locations(&self) -> Result<Vec<(LabelledInstruction, SourceLocation)>> {
+        self.ensure_unique_module_names()?;
+        let mut units = self
+            .modules
+            .iter()
+            .map(LinkUnit::parse)
+            .collect::<Result<Vec<_>>>()?;
+
+        let labels = Self::labels_of_all_units(&units)?;
+        for unit in &mut units {
+            unit.qualify_labels(&labels)?;
+        }
+
+        let blocks = units.into_iter().map(LinkUnit::into_blocks).collect_vec();
+        let reachable = if self.eliminate_dead_code {
+            Self::reachable_blocks(&blocks)
+        } else {
+            Self::all_blocks(&blocks)
+        };
+
+        let instructions = blocks
+            .into_iter()
+            .enumerate()
+            .flat_map(|(module_idx, blocks)| {
+                blocks
+                    .into_iter()
+                    .enumerate()
+                    .map(move |(block_idx, block)| ((module_idx, block_idx), block))
+            })
+            .flat_map(|(block_id, block)| {
+                // static data is loaded no matter where it is declared
+                let is_reachable = reachable.contains(&block_id);
+                block
+                    .instructions
+                    .into_iter()
+                    .filter(move |(instruction, _)| {
+                        is_reachable || matches!(instruction, LabelledInstruction::Data(_))
+                    })
+            })
+            .collect();
+        Ok(instructions)
+    }
+
+    fn ensure_unique_module_names(&self) -> Result<()> {
+        let mut duplicate_names = self.modules.iter().map(|module| &module.name).duplicates();
+        match duplicate_names.next() {
+            Some(name) => Err(LinkError::DuplicateModule(name.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// The unqualified labels defined in each module.
+    fn labels_of_all_units<'a>(
+        units: &[LinkUnit<'a>],
+    ) -> Result<HashMap<&'a str, HashSet<String>>> {
+        let mut labels = HashMap::new();
+        for unit in units {
+            labels.insert(unit.module.name.as_str(), unit.labels()?);
+        }
+        Ok(labels)
+    }
+
+    fn all_blocks(blocks: &[Vec<Block>]) -> HashSet<(usize, usize)> {
+        blocks
+            .iter()
+            .enumerate()
+            .flat_map(|(module_idx, blocks)| (0..blocks.len()).map(move |idx| (module_idx, idx)))
+            .collect()
+    }
+
+    /// All blocks reachable from the first block of the main module.
+    fn reachable_blocks(blocks: &[Vec<Block>]) -> HashSet<(usize, usize)> {
+        let mut label_to_block = HashMap::new();
+        for (module_idx, blocks) in blocks.iter().enumerate() {
+            for (block_idx, block) in blocks.iter().enumerate() {
+                if let Some(label) = block.label() {
+                    label_to_block.insert(label, (module_idx, block_idx));
+                }
+            }
+        }
+
+        let entrypoint = (0, 0);
+        let mut reachable = HashSet::from([entrypoint]);
+        let mut to_visit = vec![entrypoint];
+        while let Some((module_idx, block_idx)) = to_visit.pop() {
+            let block = &blocks[module_idx][block_idx];
+            let mut successors = block
+                .call_targets()
+                .map(|label| label_to_block[label])
+                .collect_vec();
+            if block.may_fall_through() && block_idx + 1 < blocks[module_idx].len() {
+                successors.push((module_idx, block_idx + 1));
+            }
+            for successor in successors {
+                if reachable.insert(successor) {
+                    to_visit.push(successor);
+                }
+            }
+        }
+        reachable
+    }
+}
+
+impl<'module> LinkUnit<'module> {
+    fn parse(module: &'module Module) -> Result<Self> {
+        let source = module.source.as_str();
+        let locator = SourceLocator::new(&module.file, source);
+        let location = |remainder: &str| locator.locate(remainder);
+        let parse_error = |errors: VerboseError<&str>| {
+            let remainder = errors
+                .errors
+                .first()
+                .map_or("", |&(remainder, _)| remainder);
+            LinkError::ParseError {
+                location: location(remainder),
+                message: parser::pretty_print_error(source, errors),
+            }
+        };
+
+        let (source_without_imports, imports) =
+            parser::imports(source).finish().map_err(parse_error)?;
+        let (_, tokens) = parser::tokenize(source_without_imports)
+            .finish()
+            .map_err(parse_error)?;
+
+        let imports = imports
+            .into_iter()
+            .map(|(name, remainder)| (name, location(remainder)))
+            .collect();
+        let instructions = tokens
+            .iter()
+            .map(|token| (token.to_labelled_instruction(), location(token.token_str())))
+            .collect();
+
+        Ok(Self {
+            module,
+            imports,
+            instructions,
+        })
+    }
+
+    /// The unqualified labels defined in this unit.
+    fn labels(&self) -> Result<HashSet<String>> {
+        let mut labels = HashSet::new();
+        for (instruction, location) in &self.instructions {
+            let LabelledInstruction::Label(label) = instruction else {
+                continue;
+            };
+            if label.contains("::") {
+                let err = LinkError::QualifiedLabelDefinition(location.clone(), label.clone());
+                return Err(err);
+            }
+            if !labels.insert(label.clone()) {
+                return Err(LinkError::DuplicateLabel(location.clone(), label.clone()));
+            }
+        }
+        Ok(labels)
+    }
+
+    /// Qualify all label definitions and call targets with the name of the defining module.
+    fn qualify_labels(&mut self, labels: &HashMap<&str, HashSet<String>>) -> Result<()> {
+        let own_name = self.module.name.as_str();
+        let mut visible_modules = HashSet::from([own_name]);
+        for (import, location) in &self.imports {
+            if !labels.contains_key(import.as_str()) {
+                return Err(LinkError::UnknownModule(location.clone(), import.clone()));
+            }
+            visible_modules.insert(import.as_str());
+        }
+
+        for (instruction, location) in &mut self.instructions {
+            let qualified_instruction = match instruction {
+                LabelledInstruction::Label(label) => {
+                    LabelledInstruction::Label(self.module.qualify(label))
+                }
+                LabelledInstruction::Instruction(AnInstruction::Call(target)) => {
+                    let (module, label) = match target.rsplit_once("::") {
+                        Some((module, label)) => (module, label),
+                        None => (own_name, target.as_str()),
+                    };
+                    if !visible_modules.contains(module) {
+                        let module = module.to_string();
+                        return Err(LinkError::ModuleNotImported(location.clone(), module));
+                    }
+                    if !labels[module].contains(label) {
+                        let err = LinkError::MissingLabel(location.clone(), target.clone());
+                        return Err(err);
+                    }
+                    let qualified_target = format!("{module}::{label}");
+                    LabelledInstruction::Instruction(AnInstruction::Call(qualified_target))
+                }
+                _ => continue,
+            };
+            *instruction = qualified_instruction;
+        }
+        Ok(())
+    }
+
+    fn into_blocks(self) -> Vec<Block> {
+        let mut blocks = vec![Block::default()];
+        for (instruction, location) in self.instructions {
+            if let LabelledInstruction::Label(_) = instruction {
+                blocks.push(Block::default());
+            }
+            let current_block = blocks.last_mut().expect("there is always a block");
+            current_block.instructions.push((instruction, location));
+        }
+        blocks
+    }
+}
+
+impl Block {
+    fn label(&self) -> Option<&str> {
+        match self.instructions.first() {
+            Some((LabelledInstruction::Label(label), _)) => Some(label),
+            _ => None,
+        }
+    }
+
+    fn call_targets(&self) -> impl Iterator<Item = &str> {
+        self.instructions.iter().filter_map(|(instruction, _)| {
+            let LabelledInstruction::Instruction(AnInstruction::Call(target)) = instruction else {
+                return None;
+            };
+            Some(target.as_str())
+        })
+    }
+
+    /// Whether execution might continue with the instruction following this block. This is the
+    /// case unless the block's last instruction unconditionally transfers control elsewhere.
+    fn may_fall_through(&self) -> bool {
+        let mut instructions = self
+            .instructions
+            .iter()
+            .filter_map(|(instruction, _)| match instruction {
+                LabelledInstruction::Instruction(instruction) => Some(instruction),
+                _ => None,
+            })
+            .rev();
+
+        let Some(last_instruction) = instructions.next() else {
+            return true;
+        };
+        let exits_block = matches!(
+            last_instruction,
+            AnInstruction::Return | AnInstruction::Recurse | AnInstruction::Halt
+        );
+        let exit_is_conditional = matches!(instructions.next(), Some(AnInstruction::Skiz));
+
+        !exits_block || exit_is_conditional
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::assert;
+    use assert2::let_assert;
+
+    use crate::prelude::*;
+
+    use super::*;
+
+    fn math_module() -> Module {
+        let source = "
+            // multiply st0 by a constant
+            double: push 2 mul return
+            triple: push 3 mul return
+            sextuple: call double call triple return
+        ";
+        Module::new("math", source).with_file_name("lib/math.tasm")
+    }
+
+    fn label_names(program: &Program) -> Vec<String> {
+        program
+            .labelled_instructions()
+            .into_iter()
+            .filter_map(|instruction| match instruction {
+                LabelledInstruction::Label(label) => Some(label),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn linked_program_calls_functions_of_imported_module() {
+        let source = "import math read_io 1 call math::sextuple write_io 1 halt";
+        let main = Module::new("main", source);
+        let program = Linker::new(main)
+            .with_module(math_module())
+            .link_program()
+            .unwrap();
+
+        let output = program.run(bfe_vec![7].into(), [].into()).unwrap();
+        assert!(bfe_vec![42] == output);
+    }
+
+    #[test]
+    fn labels_of_different_modules_do_not_clash() {
+        let source = "import math call double call math::double halt double: return";
+        let main = Module::new("main", source);
+        let program = Linker::new(main)
+            .with_module(math_module())
+            .link_program()
+            .unwrap();
+
+        let labels = label_names(&program);
+        assert!(labels.contains(&"main::double".to_string()));
+        assert!(labels.contains(&"math::double".to_string()));
+    }
+
+    #[test]
+    fn unused_functions_are_eliminated() {
+        let main = Module::new("main", "import math push 1 call math::double halt");
+        let linker = Linker::new(main).with_module(math_module());
+
+        let program = linker.link_program().unwrap();
+        assert!(vec!["math::double"] == label_names(&program));
+
+        let program = linker
+            .with_dead_code_elimination(false)
+            .link_program()
+            .unwrap();
+        let expected_labels = ["math::double", "math::triple", "math::sextuple"];
+        assert!(expected_labels.to_vec() == label_names(&program));
+    }
+
+    #[test]
+    fn static_data_of_all_modules_is_kept() {
+        let main = Module::new("main", "push 0 read_mem 1 pop 1 write_io 1 halt");
+        let table = Module::new("table", ".data 0: 42 unused: return");
+        let program = Linker::new(main).with_module(table).link_program().unwrap();
+        assert!(label_names(&program).is_empty());
+
+        let output = program.run([].into(), [].into()).unwrap();
+        assert!(bfe_vec![42] == output);
+    }
+
+    #[test]
+    fn linked_program_knows_source_locations() {
+        let main = Module::new("main", "import math push 1 call math::sextuple halt");
+        let program = Linker::new(main)
+            .with_module(math_module())
+            .link_program()
+            .unwrap();
+
+        let_assert!(Some(location) = program.source_location(0));
+        assert!(("main", 1, 13) == (location.file.as_str(), location.line, location.column));
+        let_assert!(Some(location) = program.source_location(5));
+        assert!(
+            ("lib/math.tasm", 3, 21) == (location.file.as_str(), location.line, location.column)
+        );
+        assert!(vec![5, 7, 8] == program.addresses_at_line("lib/math.tasm", 3));
+    }
+
+    #[test]
+    fn code_that_might_be_fallen_through_to_is_kept() {
+        let source = "call foo halt foo: push 1 skiz return bar: return baz: return";
+        let main = Module::new("main", source);
+        let program = Linker::new(main).link_program().unwrap();
+        assert!(vec!["main::foo", "main::bar"] == label_names(&program));
+    }
+
+    #[test]
+    fn linked_program_can_be_printed_and_parsed() {
+        let main = Module::new("main", "import math push 1 call math::sextuple halt");
+        let program = Linker::new(main)
+            .with_module(math_module())
+            .link_program()
+            .unwrap();
+
+        let_assert!(Ok(reparsed_program) = Program::from_code(&program.to_string()));
+        assert!(program == reparsed_program);
+    }
+
+    #[test]
+    fn missing_label_is_reported_with_file_and_line() {
+        let main = Module::new("main", "push 1\npush 2\n  call nowhere\nhalt");
+        let_assert!(Err(err) = Linker::new(main).link());
+
+        let_assert!(LinkError::MissingLabel(location, label) = err);
+        assert!("nowhere" == label);
+        assert!("main" == location.file);
+        assert!(3 == location.line);
+        assert!(3 == location.column);
+    }
+
+    #[test]
+    fn calling_label_of_module_that_is_not_imported_fails() {
+        let main = Module::new("main", "push 1 call math::double halt").with_file_name("main.tasm");
+        let_assert!(Err(err) = Linker::new(main).with_module(math_module()).link());
+        let_assert!(LinkError::ModuleNotImported(location, module) = err);
+        assert!("math" == module);
+        assert!("main.tasm:1:8" == location.to_string());
+    }
+
+    #[test]
+    fn importing_unknown_module_fails() {
+        let main = Module::new("main", "import math\nimport physics\nhalt");
+        let_assert!(Err(err) = Linker::new(main).with_module(math_module()).link());
+        let_assert!(LinkError::UnknownModule(location, module) = err);
+        assert!("physics" == module);
+        assert!(2 == location.line);
+    }
+
+    #[test]
+    fn calling_missing_label_of_imported_module_fails() {
+        let main = Module::new("main", "import math call math::quadruple halt");
+        let_assert!(Err(err) = Linker::new(main).with_module(math_module()).link());
+        let_assert!(LinkError::MissingLabel(_, label) = err);
+        assert!("math::quadruple" == label);
+    }
+
+    #[test]
+    fn duplicate_labels_are_reported_with_file_and_line() {
+        let library = Module::new("lib", "foo: return\nfoo: return").with_file_name("lib.tasm");
+        let linker = Linker::new(Module::new("main", "halt")).with_module(library);
+        let_assert!(Err(err) = linker.link());
+        let_assert!(LinkError::DuplicateLabel(location, label) = err);
+        assert!("foo" == label);
+        assert!("lib.tasm:2:1" == location.to_string());
+    }
+
+    #[test]
+    fn qualified_label_definitions_are_rejected() {
+        let main = Module::new("main", "call main::foo halt main::foo: return");
+        let_assert!(Err(LinkError::QualifiedLabelDefinition(_, _)) = Linker::new(main).link());
+    }
+
+    #[test]
+    fn duplicate_module_names_are_rejected() {
+        let linker = Linker::new(math_module()).with_module(math_module());
+        let_assert!(Err(LinkError::DuplicateModule(name)) = linker.link());
+        assert!("math" == name);
+    }
+
+    #[test]
+    fn parse_errors_are_reported_with_file_and_line() {
+        let library = Module::new("lib", "foo:\n  push 1\n  pus 2\n  return");
+        let linker = Linker::new(Module::new("main", "halt")).with_module(library);
+        let_assert!(Err(err) = linker.link());
+        let_assert!(LinkError::ParseError { location, .. } = err);
+        assert!("lib" == location.file);
+        assert!(3 == location.line);
+    }
+}
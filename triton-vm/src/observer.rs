@@ -0,0 +1,293 @@
+//! Hooks into the execution of Triton VM.
+//!
+//! A [`VMObserver`] is notified about every step Triton VM takes, allowing tools like coverage
+//! trackers, loggers, or custom profilers to be built without re-implementing the execution loop.
+//! Observers can be passed to
+//! [`Program::run_with_observer`](crate::program::Program::run_with_observer),
+//! [`Program::trace_execution_with_observer`](crate::program::Program::trace_execution_with_observer),
+//! [`Program::profile_with_observer`](crate::program::Program::profile_with_observer), and
+//! [`VMState::run_with_observer`].
+
+use twenty_first::prelude::*;
+
+use crate::error::InstructionError;
+use crate::instruction::Instruction;
+use crate::table::ram_table::RamTableCall;
+use crate::vm::CoProcessorCall;
+use crate::vm::VMState;
+
+/// Callbacks for the execution of Triton VM. All callbacks default to doing nothing, so only the
+/// relevant ones need to be implemented.
+///
+/// For each successfully executed instruction, the callbacks are invoked in the following order:
+/// 1. [`before_instruction`](Self::before_instruction),
+/// 1. [`public_input_read`](Self::public_input_read), if instruction `read_io` was executed,
+/// 1. [`co_processor_call`](Self::co_processor_call) and [`ram_access`](Self::ram_access) for
+///     every call to a co-processor, in the order of the calls,
+/// 1. [`public_output_written`](Self::public_output_written), if instruction `write_io` was
+///     executed,
+/// 1. [`after_instruction`](Self::after_instruction).
+///
+/// If executing an instruction crashes the VM, only [`error`](Self::error) is invoked after
+/// [`before_instruction`](Self::before_instruction). If the instruction pointer points outside
+/// the program, only [`error`](Self::error) is invoked.
+///
+/// The unit type `()` is the observer that ignores everything. A pair of observers notifies both
+/// of its members, the first one first.
+///
+/// # Examples
+///
+/// ```
+/// # use triton_vm::prelude::*;
+/// # use triton_vm::instruction::Instruction;
+/// # use triton_vm::observer::VMObserver;
+/// #[derive(Default)]
+/// struct HashCounter {
+///     num_hashes: usize,
+/// }
+///
+/// impl VMObserver for HashCounter {
+///     fn before_instruction(&mut self, _: &VMState, instruction: Instruction) {
+///         if instruction == Instruction::Hash {
+///             self.num_hashes += 1;
+///         }
+///     }
+/// }
+///
+/// let program = triton_program!(hash hash push 0 skiz hash halt);
+/// let mut counter = HashCounter::default();
+/// program.run_with_observer([].into(), [].into(), &mut counter).unwrap();
+/// assert_eq!(2, counter.num_hashes);
+/// ```
+pub trait VMObserver {
+    /// Called right before the given instruction is executed on the given state.
+    fn before_instruction(&mut self, _state: &VMState, _instruction: Instruction) {}
+
+    /// Called right after the given instruction was executed successfully, resulting in the given
+    /// state.
+    fn after_instruction(&mut self, _state: &VMState, _instruction: Instruction) {}
+
+    /// Called for every call from the processor to one of the co-processors.
+    fn co_processor_call(&mut self, _call: &CoProcessorCall) {}
+
+    /// Called for every read from or write to RAM.
+    fn ram_access(&mut self, _call: &RamTableCall) {}
+
+    /// Called with the elements read by instruction `read_io`, in the order they were read.
+    fn public_input_read(&mut self, _elements: &[BFieldElement]) {}
+
+    /// Called with the elements written by instruction `write_io`, in the order they were written.
+    fn public_output_written(&mut self, _elements: &[BFieldElement]) {}
+
+    /// Called if the VM crashes. The given state is the state at the time of the crash.
+    fn error(&mut self, _state: &VMState, _error: InstructionError) {}
+}
+
+impl VMObserver for () {}
+
+impl<O: VMObserver + ?Sized> VMObserver for &mut O {
+    fn before_instruction(&mut self, state: &VMState, instruction: Instruction) {
+        (**self).before_instruction(state, instruction);
+    }
+
+    fn after_instruction(&mut self, state: &VMState, instruction: Instruction) {
+        (**self).after_instruction(state, instruction);
+    }
+
+    fn co_processor_call(&mut self, call: &CoProcessorCall) {
+        (**self).co_processor_call(call);
+    }
+
+    fn ram_access(&mut self, call: &RamTableCall) {
+        (**self).ram_access(call);
+    }
+
+    fn public_input_read(&mut self, elements: &[BFieldElement]) {
+        (**self).public_input_read(elements);
+    }
+
+    fn public_output_written(&mut self, elements: &[BFieldElement]) {
+        (**self).public_output_written(elements);
+    }
+
+    fn error(&mut self, state: &VMState, error: InstructionError) {
+        (**self).error(state, error);
+    }
+}
+
+impl<A: VMObserver, B: VMObserver> VMObserver for (A, B) {
+    fn before_instruction(&mut self, state: &VMState, instruction: Instruction) {
+        self.0.before_instruction(state, instruction);
+        self.1.before_instruction(state, instruction);
+    }
+
+    fn after_instruction(&mut self, state: &VMState, instruction: Instruction) {
+        self.0.after_instruction(state, instruction);
+        self.1.after_instruction(state, instruction);
+    }
+
+    fn co_processor_call(&mut self, call: &CoProcessorCall) {
+        self.0.co_processor_call(call);
+        self.1.co_processor_call(call);
+    }
+
+    fn ram_access(&mut self, call: &RamTableCall) {
+        self.0.ram_access(call);
+        self.1.ram_access(call);
+    }
+
+    fn public_input_read(&mut self, elements: &[BFieldElement]) {
+        self.0.public_input_read(elements);
+        self.1.public_input_read(elements);
+    }
+
+    fn public_output_written(&mut self, elements: &[BFieldElement]) {
+        self.0.public_output_written(elements);
+        self.1.public_output_written(elements);
+    }
+
+    fn error(&mut self, state: &VMState, error: InstructionError) {
+        self.0.error(state, error);
+        self.1.error(state, error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::assert;
+    use assert2::let_assert;
+
+    use crate::example_programs::FIBONACCI_SEQUENCE;
+    use crate::prelude::*;
+    use crate::table::master_table::TableId;
+
+    use super::*;
+
+    /// Records every callback as a short string.
+    #[derive(Debug, Default, Clone, Eq, PartialEq)]
+    struct EventLog {
+        events: Vec<String>,
+    }
+
+    impl VMObserver for EventLog {
+        fn before_instruction(&mut self, _: &VMState, instruction: Instruction) {
+            self.events.push(format!("before {instruction}"));
+        }
+
+        fn after_instruction(&mut self, _: &VMState, instruction: Instruction) {
+            self.events.push(format!("after {instruction}"));
+        }
+
+        fn ram_access(&mut self, call: &RamTableCall) {
+            let kind = if call.is_write { "write" } else { "read" };
+            self.events.push(format!("ram {kind} {}", call.ram_pointer));
+        }
+
+        fn public_input_read(&mut self, elements: &[BFieldElement]) {
+            self.events.push(format!("input {}", elements.len()));
+        }
+
+        fn public_output_written(&mut self, elements: &[BFieldElement]) {
+            self.events.push(format!("output {}", elements.len()));
+        }
+
+        fn error(&mut self, _: &VMState, error: InstructionError) {
+            self.events.push(format!("error {error}"));
+        }
+    }
+
+    #[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+    struct InstructionCounter {
+        before: u32,
+        after: u32,
+    }
+
+    impl VMObserver for InstructionCounter {
+        fn before_instruction(&mut self, _: &VMState, _: Instruction) {
+            self.before += 1;
+        }
+
+        fn after_instruction(&mut self, _: &VMState, _: Instruction) {
+            self.after += 1;
+        }
+    }
+
+    #[test]
+    fn callbacks_are_invoked_in_documented_order() {
+        let program = triton_program!(read_io 2 push 5 write_mem 1 pop 1 write_io 1 halt);
+        let mut log = EventLog::default();
+        program
+            .run_with_observer(bfe_vec![3, 4].into(), [].into(), &mut log)
+            .unwrap();
+
+        let expected_events = [
+            "before read_io 2",
+            "input 2",
+            "after read_io 2",
+            "before push 5",
+            "after push 5",
+            "before write_mem 1",
+            "ram write 5",
+            "after write_mem 1",
+            "before pop 1",
+            "after pop 1",
+            "before write_io 1",
+            "output 1",
+            "after write_io 1",
+            "before halt",
+            "after halt",
+        ];
+        assert!(expected_events.to_vec() == log.events);
+    }
+
+    #[test]
+    fn crashing_vm_notifies_observer_of_error() {
+        let program = triton_program!(push 2 assert halt);
+        let mut log = EventLog::default();
+        let_assert!(Err(err) = program.run_with_observer([].into(), [].into(), &mut log));
+
+        let expected_error_event = format!("error {}", InstructionError::AssertionFailed);
+        let_assert!(Some(last_event) = log.events.last());
+        assert!(&expected_error_event == last_event);
+        assert!(InstructionError::AssertionFailed == err.source);
+    }
+
+    #[test]
+    fn instruction_pointer_overflow_notifies_observer_of_error() {
+        let program = triton_program!(nop);
+        let mut log = EventLog::default();
+        let _ = program.run_with_observer([].into(), [].into(), &mut log);
+
+        let overflow = InstructionError::InstructionPointerOverflow;
+        let expected_events = ["before nop".to_string(), "after nop".to_string()]
+            .into_iter()
+            .chain([format!("error {overflow}")])
+            .collect::<Vec<_>>();
+        assert!(expected_events == log.events);
+    }
+
+    #[test]
+    fn observer_sees_every_cycle_of_traced_execution() {
+        let program = FIBONACCI_SEQUENCE.clone();
+        let mut counter = InstructionCounter::default();
+        let (aet, _) = program
+            .trace_execution_with_observer(bfe_vec![11].into(), [].into(), &mut counter)
+            .unwrap();
+
+        let processor_table_height = aet.height_of_table(TableId::Processor);
+        assert!(processor_table_height == counter.before as usize);
+        assert!(processor_table_height == counter.after as usize);
+    }
+
+    #[test]
+    fn pair_of_observers_notifies_both() {
+        let program = FIBONACCI_SEQUENCE.clone();
+        let mut observers = (InstructionCounter::default(), InstructionCounter::default());
+        let (_, profile) = program
+            .profile_with_observer(bfe_vec![7].into(), [].into(), &mut observers)
+            .unwrap();
+
+        assert!(observers.0 == observers.1);
+        assert!(profile.total.processor == observers.0.after);
+    }
+}
@@ -87,6 +87,10 @@ impl OpStack {
         self.underflow_io_sequence.drain(..).collect()
     }
 
+    pub(crate) fn has_recorded_underflow_io(&self) -> bool {
+        !self.underflow_io_sequence.is_empty()
+    }
+
     pub(crate) fn push_extension_field_element(&mut self, element: XFieldElement) {
         for coefficient in element.coefficients.into_iter().rev() {
             self.push(coefficient);
@@ -0,0 +1,757 @@
+//! Remove obvious waste from [Triton assembly][tasm].
+//!
+//! Generated code frequently contains short instruction sequences that have no effect, like
+//! `push 0 add` or `swap 1 swap 1`, or sequences that can be computed ahead of time, like
+//! `push 2 push 3 add`. The [`PeepholeOptimizer`] rewrites such sequences according to a set of
+//! [`PeepholeRule`]s, which can be extended with custom rules.
+//!
+//! Every rule provides examples of the sequences it rewrites. These examples are used to
+//! [check](PeepholeOptimizer::check_rules) that the rule preserves the semantics of the code by
+//! executing both the original and the rewritten example on random stacks. The check also reports
+//! how many cycles and table rows the rule saves.
+//!
+//! [tasm]: https://triton-vm.org/spec/instructions.html
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+use itertools::Itertools;
+use num_traits::One;
+use num_traits::Zero;
+use strum::IntoEnumIterator;
+use twenty_first::math::other::random_elements;
+use twenty_first::prelude::*;
+
+use crate::error::PeepholeRuleError;
+use crate::instruction::AnInstruction;
+use crate::instruction::AnInstruction::*;
+use crate::instruction::LabelledInstruction;
+use crate::op_stack::NumberOfWords;
+use crate::op_stack::OpStackElement;
+use crate::op_stack::NUM_OP_STACK_REGISTERS;
+use crate::program::NonDeterminism;
+use crate::program::Program;
+use crate::program::PublicInput;
+use crate::program::VMProfiler;
+use crate::program::VMTableHeights;
+use crate::vm::VMState;
+
+type Result<T> = std::result::Result<T, PeepholeRuleError>;
+
+/// A local rewrite of [Triton assembly][tasm] that does not change the semantics of the code.
+///
+/// Rules only ever see instructions without labels or other non-instruction items in between.
+/// A rule must shorten the code it rewrites, measured in [`BFieldElement`]s; rewrites that do not
+/// are ignored by the [`PeepholeOptimizer`].
+///
+/// [tasm]: https://triton-vm.org/spec/instructions.html
+pub trait PeepholeRule: Debug + Send + Sync {
+    /// A short name identifying the rule, used in [reports](RuleReport) and errors.
+    fn name(&self) -> &str;
+
+    /// The rewrite of the instructions at the very beginning of the given ones, if the rule
+    /// applies.
+    fn apply(&self, instructions: &[AnInstruction<String>]) -> Option<Rewrite>;
+
+    /// Instruction sequences the rule [applies](Self::apply) to. They are used to
+    /// [check](PeepholeOptimizer::check_rules) the rule.
+    fn examples(&self) -> Vec<Vec<AnInstruction<String>>>;
+}
+
+/// The replacement for the first few instructions of some sequence, as produced by a
+/// [`PeepholeRule`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Rewrite {
+    /// The number of instructions that are replaced.
+    pub num_replaced: usize,
+
+    /// The instructions replacing the first [`num_replaced`](Self::num_replaced) instructions.
+    pub replacement: Vec<AnInstruction<String>>,
+}
+
+/// Rewrites [Triton assembly][tasm] according to a set of [`PeepholeRule`]s.
+///
+/// # Examples
+///
+/// ```
+/// # use triton_vm::prelude::*;
+/// # use triton_vm::optimizer::PeepholeOptimizer;
+/// let code = triton_asm!(push 2 push 3 add swap 1 swap 1 push 0 add halt);
+/// let optimized = PeepholeOptimizer::new().optimize(&code);
+/// assert_eq!(triton_asm!(push 5 halt), optimized);
+/// ```
+///
+/// [tasm]: https://triton-vm.org/spec/instructions.html
+#[derive(Debug)]
+pub struct PeepholeOptimizer {
+    rules: Vec<Box<dyn PeepholeRule>>,
+}
+
+/// The outcome of [checking](PeepholeOptimizer::check_rules) a [`PeepholeRule`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RuleReport {
+    /// The [name](PeepholeRule::name) of the checked rule.
+    pub rule: String,
+
+    /// The number of [examples](PeepholeRule::examples) of the rule.
+    pub num_examples: usize,
+
+    /// The number of random stacks each example was executed on.
+    pub num_stacks: usize,
+
+    /// The accumulated table heights of executing all examples on all stacks.
+    pub table_heights_before: VMTableHeights,
+
+    /// The accumulated table heights of executing all rewritten examples on all stacks.
+    pub table_heights_after: VMTableHeights,
+}
+
+/// The observable effect of executing some instructions.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Outcome {
+    op_stack: Vec<BFieldElement>,
+    ram: HashMap<BFieldElement, BFieldElement>,
+    public_output: Vec<BFieldElement>,
+}
+
+impl Rewrite {
+    pub fn new(num_replaced: usize, replacement: Vec<AnInstruction<String>>) -> Self {
+        Self {
+            num_replaced,
+            replacement,
+        }
+    }
+
+    /// Remove the first `num_replaced` instructions without replacement.
+    pub fn removal(num_replaced: usize) -> Self {
+        Self::new(num_replaced, vec![])
+    }
+
+    /// Whether the rewrite applies to the given instructions and reduces their
+    /// [size](AnInstruction::size).
+    fn is_shortening_for(&self, instructions: &[AnInstruction<String>]) -> bool {
+        self.num_replaced <= instructions.len()
+            && size_of(&self.replacement) < size_of(&instructions[..self.num_replaced])
+    }
+}
+
+impl Default for PeepholeOptimizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PeepholeOptimizer {
+    /// An optimizer using all built-in rules.
+    pub fn new() -> Self {
+        let rules: Vec<Box<dyn PeepholeRule>> = vec![
+            Box::new(RemoveNop),
+            Box::new(AddZero),
+            Box::new(MulOne),
+            Box::new(DoubleSwap),
+            Box::new(DupPop),
+            Box::new(PushPop),
+            Box::new(MergePops),
+            Box::new(ConstantFolding),
+        ];
+        Self { rules }
+    }
+
+    /// An optimizer without any rules. Rules can be added using [`with_rule`](Self::with_rule).
+    pub fn without_rules() -> Self {
+        Self { rules: vec![] }
+    }
+
+    /// Additionally use the given rule. Rules added earlier take precedence.
+    #[must_use]
+    pub fn with_rule(mut self, rule: impl PeepholeRule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    pub fn rules(&self) -> &[Box<dyn PeepholeRule>] {
+        &self.rules
+    }
+
+    /// Apply the rules until none of them applies anymore.
+    ///
+    /// Labels, type hints, breakpoints, and other non-instruction items are kept and never
+    /// rewritten across. The instruction immediately following a `skiz` is never the start of a
+    /// rewrite, since `skiz` might skip it.
+    pub fn optimize(&self, instructions: &[LabelledInstruction]) -> Vec<LabelledInstruction> {
+        let is_instruction =
+            |item: &LabelledInstruction| matches!(item, LabelledInstruction::Instruction(_));
+
+        let mut optimized = Vec::with_capacity(instructions.len());
+        let mut follows_skiz = false;
+        for chunk in instructions.chunk_by(|a, b| is_instruction(a) && is_instruction(b)) {
+            let run = chunk
+                .iter()
+                .filter_map(|item| match item {
+                    LabelledInstruction::Instruction(instruction) => Some(instruction.clone()),
+                    _ => None,
+                })
+                .collect_vec();
+            if run.is_empty() {
+                optimized.extend_from_slice(chunk);
+                continue;
+            }
+
+            let run = self.optimize_run(run, follows_skiz);
+            if let Some(last) = run.last() {
+                follows_skiz = *last == Skiz;
+            }
+            optimized.extend(run.into_iter().map(LabelledInstruction::Instruction));
+        }
+
+        optimized
+    }
+
+    fn optimize_run(
+        &self,
+        mut run: Vec<AnInstruction<String>>,
+        follows_skiz: bool,
+    ) -> Vec<AnInstruction<String>> {
+        loop {
+            let rewritten = self.rewrite_run(&run, follows_skiz);
+            if size_of(&rewritten) == size_of(&run) {
+                return run;
+            }
+            run = rewritten;
+        }
+    }
+
+    fn rewrite_run(
+        &self,
+        run: &[AnInstruction<String>],
+        mut follows_skiz: bool,
+    ) -> Vec<AnInstruction<String>> {
+        let mut rewritten = Vec::with_capacity(run.len());
+        let mut index = 0;
+        while index < run.len() {
+            let rewrite = if follows_skiz {
+                None
+            } else {
+                self.rewrite(&run[index..])
+            };
+
+            let Some(rewrite) = rewrite else {
+                follows_skiz = run[index] == Skiz;
+                rewritten.push(run[index].clone());
+                index += 1;
+                continue;
+            };
+
+            if let Some(last) = rewrite.replacement.last() {
+                follows_skiz = *last == Skiz;
+            }
+            rewritten.extend(rewrite.replacement);
+            index += rewrite.num_replaced;
+        }
+
+        rewritten
+    }
+
+    fn rewrite(&self, instructions: &[AnInstruction<String>]) -> Option<Rewrite> {
+        self.rules
+            .iter()
+            .filter_map(|rule| rule.apply(instructions))
+            .find(|rewrite| rewrite.is_shortening_for(instructions))
+    }
+
+    /// [Check](check_rule) all rules of this optimizer.
+    pub fn check_rules(&self, num_stacks: usize) -> Result<Vec<RuleReport>> {
+        self.rules
+            .iter()
+            .map(|rule| check_rule(rule.as_ref(), num_stacks))
+            .collect()
+    }
+}
+
+/// Check that the given rule preserves semantics by executing each of its
+/// [examples](PeepholeRule::examples), as well as their rewrites, on `num_stacks` random stacks.
+///
+/// Both executions must have the same outcome: the same op stack, RAM, and public output, or
+/// failure in both cases. Each rewrite must also be shorter than its example.
+pub fn check_rule(rule: &dyn PeepholeRule, num_stacks: usize) -> Result<RuleReport> {
+    let mut report = RuleReport {
+        rule: rule.name().to_string(),
+        num_examples: 0,
+        num_stacks,
+        table_heights_before: VMTableHeights::default(),
+        table_heights_after: VMTableHeights::default(),
+    };
+
+    for example in rule.examples() {
+        let error_context = || (rule.name().to_string(), example.iter().join(" "));
+        let Some(rewrite) = rule.apply(&example) else {
+            let (rule, example) = error_context();
+            return Err(PeepholeRuleError::NotApplicable { rule, example });
+        };
+        if !rewrite.is_shortening_for(&example) {
+            let (rule, example) = error_context();
+            return Err(PeepholeRuleError::NotShortening { rule, example });
+        }
+
+        let remainder = &example[rewrite.num_replaced..];
+        let rewritten = [rewrite.replacement, remainder.to_vec()].concat();
+        for _ in 0..num_stacks {
+            let stack: Vec<BFieldElement> = random_elements(NUM_OP_STACK_REGISTERS);
+            let original = execute_on_stack(&stack, &example);
+            let optimized = execute_on_stack(&stack, &rewritten);
+            if original.as_ref().map(|(outcome, _)| outcome)
+                != optimized.as_ref().map(|(outcome, _)| outcome)
+            {
+                let (rule, example) = error_context();
+                return Err(PeepholeRuleError::SemanticsChanged { rule, example });
+            }
+            if let (Some((_, before)), Some((_, after))) = (original, optimized) {
+                report.table_heights_before += before;
+                report.table_heights_after += after;
+            }
+        }
+        report.num_examples += 1;
+    }
+
+    Ok(report)
+}
+
+/// Execute the given instructions after pushing the given elements to the stack.
+fn execute_on_stack(
+    stack: &[BFieldElement],
+    instructions: &[AnInstruction<String>],
+) -> Option<(Outcome, VMTableHeights)> {
+    let code = stack
+        .iter()
+        .map(|&element| Push(element))
+        .chain(instructions.iter().cloned())
+        .chain([Halt])
+        .map(LabelledInstruction::Instruction)
+        .collect_vec();
+    let program = Program::new(&code);
+
+    let mut profiler = VMProfiler::new(&program);
+    let mut state = VMState::new(&program, PublicInput::default(), NonDeterminism::default());
+    state.run_with_observer(&mut profiler).ok()?;
+
+    let outcome = Outcome {
+        op_stack: state.op_stack.stack,
+        ram: state.ram,
+        public_output: state.public_output,
+    };
+    Some((outcome, profiler.report().total))
+}
+
+impl RuleReport {
+    /// The table heights saved by the rule, accumulated over all examples and stacks.
+    pub fn savings(&self) -> VMTableHeights {
+        self.table_heights_before - self.table_heights_after
+    }
+
+    /// The clock cycles saved by the rule, accumulated over all examples and stacks.
+    pub fn cycles_saved(&self) -> u32 {
+        self.savings().processor
+    }
+}
+
+impl Display for RuleReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let savings = self.savings();
+        let num_examples = self.num_examples;
+        let num_stacks = self.num_stacks;
+        writeln!(
+            f,
+            "{}: {num_examples} examples on {num_stacks} stacks each",
+            self.rule
+        )?;
+        writeln!(f, "  processor rows saved: {:>8}", savings.processor)?;
+        writeln!(f, "  op stack rows saved:  {:>8}", savings.op_stack)?;
+        writeln!(f, "  ram rows saved:       {:>8}", savings.ram)?;
+        writeln!(f, "  hash rows saved:      {:>8}", savings.hash)?;
+        writeln!(f, "  cascade rows saved:   {:>8}", savings.cascade)?;
+        write!(f, "  u32 rows saved:       {:>8}", savings.u32)
+    }
+}
+
+/// The total size of the given instructions in [`BFieldElement`]s.
+fn size_of(instructions: &[AnInstruction<String>]) -> usize {
+    instructions.iter().map(AnInstruction::size).sum()
+}
+
+/// Pop `n - 1` elements, the result of removing the last element pushed before a `pop n`.
+fn pop_one_less(n: NumberOfWords) -> Vec<AnInstruction<String>> {
+    let remaining = n.num_words() - 1;
+    NumberOfWords::try_from(remaining).map_or(vec![], |n| vec![Pop(n)])
+}
+
+/// Removes `nop`.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct RemoveNop;
+
+/// Removes `push 0 add`.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct AddZero;
+
+/// Removes `push 1 mul`.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct MulOne;
+
+/// Removes `swap n swap n`.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct DoubleSwap;
+
+/// Rewrites `dup m pop n` to `pop (n-1)`, removing it entirely if `n` is 1.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct DupPop;
+
+/// Rewrites `push a pop n` to `pop (n-1)`, removing it entirely if `n` is 1.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct PushPop;
+
+/// Rewrites `pop m pop n` to `pop (m+n)` if `m+n` does not exceed 5.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct MergePops;
+
+/// Rewrites `push a push b add` to `push (a+b)`, and `push a push b mul` to `push (a·b)`.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ConstantFolding;
+
+impl PeepholeRule for RemoveNop {
+    fn name(&self) -> &str {
+        "remove nop"
+    }
+
+    fn apply(&self, instructions: &[AnInstruction<String>]) -> Option<Rewrite> {
+        match instructions {
+            [Nop, ..] => Some(Rewrite::removal(1)),
+            _ => None,
+        }
+    }
+
+    fn examples(&self) -> Vec<Vec<AnInstruction<String>>> {
+        vec![vec![Nop]]
+    }
+}
+
+impl PeepholeRule for AddZero {
+    fn name(&self) -> &str {
+        "add zero"
+    }
+
+    fn apply(&self, instructions: &[AnInstruction<String>]) -> Option<Rewrite> {
+        match instructions {
+            [Push(zero), Add, ..] if zero.is_zero() => Some(Rewrite::removal(2)),
+            _ => None,
+        }
+    }
+
+    fn examples(&self) -> Vec<Vec<AnInstruction<String>>> {
+        vec![vec![Push(bfe!(0)), Add]]
+    }
+}
+
+impl PeepholeRule for MulOne {
+    fn name(&self) -> &str {
+        "multiply by one"
+    }
+
+    fn apply(&self, instructions: &[AnInstruction<String>]) -> Option<Rewrite> {
+        match instructions {
+            [Push(one), Mul, ..] if one.is_one() => Some(Rewrite::removal(2)),
+            _ => None,
+        }
+    }
+
+    fn examples(&self) -> Vec<Vec<AnInstruction<String>>> {
+        vec![vec![Push(bfe!(1)), Mul]]
+    }
+}
+
+impl PeepholeRule for DoubleSwap {
+    fn name(&self) -> &str {
+        "double swap"
+    }
+
+    fn apply(&self, instructions: &[AnInstruction<String>]) -> Option<Rewrite> {
+        match instructions {
+            [Swap(a), Swap(b), ..] if a == b => Some(Rewrite::removal(2)),
+            _ => None,
+        }
+    }
+
+    fn examples(&self) -> Vec<Vec<AnInstruction<String>>> {
+        OpStackElement::iter()
+            .skip(1)
+            .map(|st| vec![Swap(st), Swap(st)])
+            .collect()
+    }
+}
+
+impl PeepholeRule for DupPop {
+    fn name(&self) -> &str {
+        "dup then pop"
+    }
+
+    fn apply(&self, instructions: &[AnInstruction<String>]) -> Option<Rewrite> {
+        match instructions {
+            [Dup(_), Pop(n), ..] => Some(Rewrite::new(2, pop_one_less(*n))),
+            _ => None,
+        }
+    }
+
+    fn examples(&self) -> Vec<Vec<AnInstruction<String>>> {
+        OpStackElement::iter()
+            .cartesian_product(NumberOfWords::iter())
+            .map(|(st, n)| vec![Dup(st), Pop(n)])
+            .collect()
+    }
+}
+
+impl PeepholeRule for PushPop {
+    fn name(&self) -> &str {
+        "push then pop"
+    }
+
+    fn apply(&self, instructions: &[AnInstruction<String>]) -> Option<Rewrite> {
+        match instructions {
+            [Push(_), Pop(n), ..] => Some(Rewrite::new(2, pop_one_less(*n))),
+            _ => None,
+        }
+    }
+
+    fn examples(&self) -> Vec<Vec<AnInstruction<String>>> {
+        NumberOfWords::iter()
+            .map(|n| vec![Push(bfe!(42)), Pop(n)])
+            .collect()
+    }
+}
+
+impl PeepholeRule for MergePops {
+    fn name(&self) -> &str {
+        "merge pops"
+    }
+
+    fn apply(&self, instructions: &[AnInstruction<String>]) -> Option<Rewrite> {
+        let [Pop(m), Pop(n), ..] = instructions else {
+            return None;
+        };
+        let merged = NumberOfWords::try_from(m.num_words() + n.num_words()).ok()?;
+        Some(Rewrite::new(2, vec![Pop(merged)]))
+    }
+
+    fn examples(&self) -> Vec<Vec<AnInstruction<String>>> {
+        NumberOfWords::iter()
+            .cartesian_product(NumberOfWords::iter())
+            .filter(|(m, n)| m.num_words() + n.num_words() <= NumberOfWords::N5.num_words())
+            .map(|(m, n)| vec![Pop(m), Pop(n)])
+            .collect()
+    }
+}
+
+impl PeepholeRule for ConstantFolding {
+    fn name(&self) -> &str {
+        "constant folding"
+    }
+
+    fn apply(&self, instructions: &[AnInstruction<String>]) -> Option<Rewrite> {
+        match instructions {
+            [Push(a), Push(b), Add, ..] => Some(Rewrite::new(3, vec![Push(*a + *b)])),
+            [Push(a), Push(b), Mul, ..] => Some(Rewrite::new(3, vec![Push(*a * *b)])),
+            _ => None,
+        }
+    }
+
+    fn examples(&self) -> Vec<Vec<AnInstruction<String>>> {
+        let minus_one = -bfe!(1);
+        let constants = [bfe!(0), bfe!(1), bfe!(2), bfe!(1_u64 << 40), minus_one];
+        constants
+            .into_iter()
+            .cartesian_product(constants)
+            .cartesian_product([Add, Mul])
+            .map(|((a, b), operation)| vec![Push(a), Push(b), operation])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert2::assert;
+    use assert2::let_assert;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+    use test_strategy::proptest;
+
+    use crate::prelude::*;
+
+    use super::*;
+
+    /// Not a valid rule: `push 1 add` increments the top of the stack.
+    #[derive(Debug)]
+    struct RemovePushOneAdd;
+
+    impl PeepholeRule for RemovePushOneAdd {
+        fn name(&self) -> &str {
+            "remove push one add"
+        }
+
+        fn apply(&self, instructions: &[AnInstruction<String>]) -> Option<Rewrite> {
+            match instructions {
+                [Push(one), Add, ..] if one.is_one() => Some(Rewrite::removal(2)),
+                _ => None,
+            }
+        }
+
+        fn examples(&self) -> Vec<Vec<AnInstruction<String>>> {
+            vec![vec![Push(bfe!(1)), Add]]
+        }
+    }
+
+    /// Not a valid rule: it replaces `swap 1` by an equivalent but longer sequence.
+    #[derive(Debug)]
+    struct ExpandSwap;
+
+    impl PeepholeRule for ExpandSwap {
+        fn name(&self) -> &str {
+            "expand swap"
+        }
+
+        fn apply(&self, instructions: &[AnInstruction<String>]) -> Option<Rewrite> {
+            match instructions {
+                [Swap(OpStackElement::ST1), ..] => {
+                    let st1 = OpStackElement::ST1;
+                    Some(Rewrite::new(1, vec![Swap(st1), Swap(st1), Swap(st1)]))
+                }
+                _ => None,
+            }
+        }
+
+        fn examples(&self) -> Vec<Vec<AnInstruction<String>>> {
+            vec![vec![Swap(OpStackElement::ST1)]]
+        }
+    }
+
+    #[test]
+    fn built_in_rules_preserve_semantics_and_save_cycles() {
+        let_assert!(Ok(reports) = PeepholeOptimizer::new().check_rules(10));
+        for report in reports {
+            assert!(report.num_examples > 0);
+            assert!(report.cycles_saved() > 0);
+            assert!(report.table_heights_after.op_stack <= report.table_heights_before.op_stack);
+
+            let report = report.to_string();
+            assert!(report.contains("processor rows saved"));
+        }
+    }
+
+    #[test]
+    fn rule_changing_semantics_is_detected() {
+        let_assert!(Err(err) = check_rule(&RemovePushOneAdd, 10));
+        let_assert!(PeepholeRuleError::SemanticsChanged { rule, .. } = err);
+        assert!(RemovePushOneAdd.name() == rule);
+    }
+
+    #[test]
+    fn rule_not_shortening_code_is_detected() {
+        let_assert!(Err(err) = check_rule(&ExpandSwap, 10));
+        let_assert!(PeepholeRuleError::NotShortening { .. } = err);
+    }
+
+    #[test]
+    fn rewrites_not_shortening_code_are_ignored() {
+        let code = triton_asm!(swap 1 halt);
+        let optimizer = PeepholeOptimizer::without_rules().with_rule(ExpandSwap);
+        assert!(code == optimizer.optimize(&code));
+    }
+
+    #[test]
+    fn obvious_waste_is_removed() {
+        let code = triton_asm!(
+            push 0 add
+            swap 1 swap 1
+            dup 0 pop 1
+            nop
+            push 2 push 3 add
+            halt
+        );
+        let optimized = PeepholeOptimizer::new().optimize(&code);
+        assert!(triton_asm!(push 5 halt) == optimized);
+    }
+
+    #[test]
+    fn rewrites_are_applied_until_fixpoint() {
+        let code = triton_asm!(push 1 push 2 push 3 add mul pop 1 pop 1 pop 1 halt);
+        let optimized = PeepholeOptimizer::new().optimize(&code);
+        assert!(triton_asm!(pop 2 halt) == optimized);
+    }
+
+    #[test]
+    fn optimization_is_idempotent() {
+        let code = triton_asm!(push 7 dup 0 pop 2 push 0 push 1 mul add swap 2 swap 2 halt);
+        let optimizer = PeepholeOptimizer::new();
+        let optimized = optimizer.optimize(&code);
+        assert!(optimized == optimizer.optimize(&optimized));
+    }
+
+    #[test]
+    fn instruction_following_skiz_is_not_rewritten() {
+        let code = triton_asm!(skiz nop halt);
+        assert!(code == PeepholeOptimizer::new().optimize(&code));
+
+        let code = triton_asm!(skiz push 0 pop 1 halt);
+        assert!(code == PeepholeOptimizer::new().optimize(&code));
+    }
+
+    #[test]
+    fn instruction_following_skiz_is_not_rewritten_across_labels() {
+        let code = triton_asm!(skiz label: push 0 add halt);
+        assert!(code == PeepholeOptimizer::new().optimize(&code));
+    }
+
+    #[test]
+    fn rewrite_after_instruction_following_skiz_is_possible() {
+        let code = triton_asm!(skiz push 0 push 0 add halt);
+        let optimized = PeepholeOptimizer::new().optimize(&code);
+        assert!(triton_asm!(skiz push 0 halt) == optimized);
+    }
+
+    #[test]
+    fn labels_delimit_rewrites() {
+        let code = triton_asm!(push 0 label: add halt);
+        assert!(code == PeepholeOptimizer::new().optimize(&code));
+    }
+
+    #[test]
+    fn rules_added_earlier_take_precedence() {
+        let code = triton_asm!(push 1 add halt);
+        let optimizer = PeepholeOptimizer::without_rules()
+            .with_rule(RemovePushOneAdd)
+            .with_rule(ConstantFolding);
+        assert!(triton_asm!(halt) == optimizer.optimize(&code));
+    }
+
+    #[proptest]
+    fn optimized_program_computes_same_output(
+        #[strategy(arb())] a: BFieldElement,
+        #[strategy(arb())] b: BFieldElement,
+        #[strategy(arb())] c: BFieldElement,
+    ) {
+        let code = triton_asm!(
+            push {a} push {b} push {c}
+            push 0 add swap 1 swap 1
+            push 3 push 4 mul mul
+            dup 2 pop 1 nop
+            add push 1 mul add
+            write_io 1 halt
+        );
+        let optimized = PeepholeOptimizer::new().optimize(&code);
+        let code_len = Program::new(&code).len_bwords();
+        prop_assert!(Program::new(&optimized).len_bwords() < code_len);
+
+        let_assert!(Ok(output) = Program::new(&code).run([].into(), [].into()));
+        let_assert!(Ok(optimized_output) = Program::new(&optimized).run([].into(), [].into()));
+        prop_assert_eq!(output, optimized_output);
+    }
+}
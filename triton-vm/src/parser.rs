@@ -5,14 +5,21 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
 
+use get_size::GetSize;
+use itertools::Itertools;
 use nom::branch::alt;
 use nom::bytes::complete::*;
 use nom::character::complete::digit1;
 use nom::combinator::*;
 use nom::error::*;
 use nom::multi::*;
+use nom::sequence::preceded;
+use nom::sequence::terminated;
 use nom::Finish;
 use nom::IResult;
+use num_traits::Zero;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
 use twenty_first::prelude::BFieldElement;
 
 use crate::instruction::AnInstruction::*;
@@ -20,9 +27,7 @@ use crate::instruction::LabelledInstruction;
 use crate::instruction::ALL_INSTRUCTION_NAMES;
 use crate::instruction::*;
 use crate::op_stack::NumberOfWords;
-use crate::op_stack::NumberOfWords::*;
 use crate::op_stack::OpStackElement;
-use crate::op_stack::OpStackElement::*;
 
 #[derive(Debug, PartialEq)]
 pub struct ParseError<'a> {
@@ -38,6 +43,79 @@ pub enum InstructionToken<'a> {
     Label(String, &'a str),
     Breakpoint(&'a str),
     TypeHint(TypeHint, &'a str),
+    Data(StaticData, &'a str),
+}
+
+/// A syntactic unit of Triton assembly, alongside the source code it spans. The span includes
+/// all whitespace and comments up to the next unit.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) enum SourceItem<'a> {
+    ConstantDeclaration(&'a str),
+    MacroDefinition(&'a str),
+    MacroInvocation(Vec<InstructionToken<'a>>, &'a str),
+    Token(InstructionToken<'a>, &'a str),
+}
+
+/// A position in a source file, as used for reporting errors in multi-file programs and for
+/// mapping a [`Program`](crate::program::Program)'s addresses back to the source code.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, GetSize)]
+pub struct SourceLocation {
+    /// The name of the source file. Empty if the source code did not come from a named file.
+    pub file: String,
+
+    /// The line in the file, starting at 1.
+    pub line: usize,
+
+    /// The column in the line, counted in characters and starting at 1.
+    pub column: usize,
+}
+
+/// Locates remainders of some source code without re-scanning the source code for every
+/// remainder. The start of every line is computed once, making locating a remainder logarithmic in
+/// the number of lines and linear in the length of the remainder's line.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct SourceLocator<'src> {
+    file: &'src str,
+    source: &'src str,
+    line_starts: Vec<usize>,
+}
+
+impl<'src> SourceLocator<'src> {
+    pub fn new(file: &'src str, source: &'src str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+
+        Self {
+            file,
+            source,
+            line_starts,
+        }
+    }
+
+    /// The location of the given remainder of the source code. Like all parsed tokens, the
+    /// remainder must be a suffix of the source code.
+    pub fn locate(&self, remainder: &str) -> SourceLocation {
+        let offset = self.source.len() - remainder.len();
+        let line_index = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let line_start = self.line_starts[line_index];
+        let column = self.source[line_start..offset].chars().count() + 1;
+
+        SourceLocation {
+            file: self.file.to_string(),
+            line: line_index + 1,
+            column,
+        }
+    }
+}
+
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if !self.file.is_empty() {
+            write!(f, "{}:", self.file)?;
+        }
+        write!(f, "{}:{}", self.line, self.column)
+    }
 }
 
 impl<'a> Display for ParseError<'a> {
@@ -55,6 +133,7 @@ impl<'a> InstructionToken<'a> {
             InstructionToken::Label(_, token_str) => token_str,
             InstructionToken::Breakpoint(token_str) => token_str,
             InstructionToken::TypeHint(_, token_str) => token_str,
+            InstructionToken::Data(_, token_str) => token_str,
         }
     }
 
@@ -65,6 +144,36 @@ impl<'a> InstructionToken<'a> {
             Label(label, _) => LabelledInstruction::Label(label.to_owned()),
             Breakpoint(_) => LabelledInstruction::Breakpoint,
             TypeHint(type_hint, _) => LabelledInstruction::TypeHint(type_hint.to_owned()),
+            Data(static_data, _) => LabelledInstruction::Data(static_data.to_owned()),
+        }
+    }
+}
+
+impl<'a> SourceItem<'a> {
+    pub(crate) fn span(&self) -> &'a str {
+        match self {
+            SourceItem::ConstantDeclaration(span) => span,
+            SourceItem::MacroDefinition(span) => span,
+            SourceItem::MacroInvocation(_, span) => span,
+            SourceItem::Token(_, span) => span,
+        }
+    }
+
+    /// The tokens this item stands for. Constant declarations and macro definitions have none,
+    /// a macro invocation has the tokens of its expansion.
+    pub(crate) fn tokens(&self) -> &[InstructionToken<'a>] {
+        match self {
+            SourceItem::ConstantDeclaration(_) | SourceItem::MacroDefinition(_) => &[],
+            SourceItem::MacroInvocation(tokens, _) => tokens,
+            SourceItem::Token(token, _) => std::slice::from_ref(token),
+        }
+    }
+
+    fn into_tokens(self) -> Vec<InstructionToken<'a>> {
+        match self {
+            SourceItem::ConstantDeclaration(_) | SourceItem::MacroDefinition(_) => vec![],
+            SourceItem::MacroInvocation(tokens, _) => tokens,
+            SourceItem::Token(token, _) => vec![token],
         }
     }
 }
@@ -108,7 +217,7 @@ pub fn parse(input: &str) -> Result<Vec<InstructionToken>, ParseError> {
     Ok(instructions)
 }
 
-fn ensure_no_missing_or_duplicate_labels<'a>(
+pub(crate) fn ensure_no_missing_or_duplicate_labels<'a>(
     input: &'a str,
     instructions: &[InstructionToken<'a>],
 ) -> Result<(), ParseError<'a>> {
@@ -181,16 +290,126 @@ fn errors_for_labels_with_context(
 /// error type, but we want `nom::error::VerboseError` as it allows `context()`.
 type ParseResult<'input, Out> = IResult<&'input str, Out, VerboseError<&'input str>>;
 
+/// The values of the named constants declared so far, indexed by their names.
+type Constants = HashMap<String, BFieldElement>;
+
+/// The named constants and macros visible at some point in the source code.
+#[derive(Debug, Default, Clone)]
+struct Scope<'a> {
+    constants: Constants,
+    macros: HashMap<String, Macro<'a>>,
+}
+
+/// A macro, as defined by `macro <name>(<PARAMETERS>) <body> endmacro`.
+#[derive(Debug, Clone)]
+struct Macro<'a> {
+    parameters: Vec<String>,
+
+    /// The unparsed source code between the macro's signature and `endmacro`.
+    body: &'a str,
+
+    /// The scope at the macro's definition. The body is expanded in this scope, extended by the
+    /// parameters, which makes expansion independent of the invocation site.
+    scope: Scope<'a>,
+}
+
 pub fn tokenize(s: &str) -> ParseResult<Vec<InstructionToken>> {
-    let (s, _) = comment_or_whitespace0(s)?;
-    let (s, instructions) = many0(alt((label, labelled_instruction, breakpoint, type_hint)))(s)?;
+    tokenize_in_scope(s, Scope::default())
+}
+
+fn tokenize_in_scope<'a>(
+    s: &'a str,
+    scope: Scope<'a>,
+) -> ParseResult<'a, Vec<InstructionToken<'a>>> {
+    let (s, items) = source_items_in_scope(s, scope)?;
+    let tokens = items
+        .into_iter()
+        .flat_map(SourceItem::into_tokens)
+        .collect();
+    Ok((s, tokens))
+}
+
+/// Like [`tokenize`], but keeps constant declarations and macro definitions, and does not
+/// break up macro invocations. Every [`SourceItem`] knows the source code it spans.
+pub(crate) fn source_items(s: &str) -> ParseResult<Vec<SourceItem>> {
+    source_items_in_scope(s, Scope::default())
+}
+
+fn source_items_in_scope<'a>(
+    s: &'a str,
+    mut scope: Scope<'a>,
+) -> ParseResult<'a, Vec<SourceItem<'a>>> {
+    let (mut s, _) = comment_or_whitespace0(s)?;
+    let span = |s: &'a str, s_after: &str| &s[..s.len() - s_after.len()];
+
+    // Constants and macros must be declared before their first use. Since every declaration
+    // changes how the remaining input is parsed, the scope is threaded through the parsers by hand.
+    let mut items = vec![];
+    let mut num_expansions = 0;
+    loop {
+        let (s_after_declaration, declaration) = opt(constant_declaration(&scope.constants))(s)?;
+        if let Some((name, value)) = declaration {
+            scope.constants.insert(name, value);
+            items.push(SourceItem::ConstantDeclaration(span(
+                s,
+                s_after_declaration,
+            )));
+            s = s_after_declaration;
+            continue;
+        }
+
+        let (s_after_definition, definition) = opt(|s| macro_definition(s, &scope))(s)?;
+        if let Some((name, macro_)) = definition {
+            scope.macros.insert(name, macro_);
+            items.push(SourceItem::MacroDefinition(span(s, s_after_definition)));
+            s = s_after_definition;
+            continue;
+        }
+
+        let invocation = |s| macro_invocation(s, &scope, num_expansions);
+        let (s_after_invocation, expansion) = opt(invocation)(s)?;
+        if let Some(expansion) = expansion {
+            let invocation_span = span(s, s_after_invocation);
+            items.push(SourceItem::MacroInvocation(expansion, invocation_span));
+            num_expansions += 1;
+            s = s_after_invocation;
+            continue;
+        }
+
+        let instruction = |s| labelled_instruction(s, &scope.constants);
+        let data = |s| static_data(s, &scope.constants);
+        let any_token = alt((label, instruction, breakpoint, type_hint, data));
+        let (s_after_token, Some(token)) = opt(any_token)(s)? else {
+            break;
+        };
+        items.push(SourceItem::Token(token, span(s, s_after_token)));
+        s = s_after_token;
+    }
     let (s, _) = context("expecting label, instruction or eof", eof)(s)?;
 
-    Ok((s, instructions))
+    Ok((s, items))
+}
+
+/// Parse the `import` directives at the beginning of a [module](crate::linker::Module). Returns
+/// the names of the imported modules alongside the input starting at the respective directive.
+pub(crate) fn imports(s: &str) -> ParseResult<Vec<(String, &str)>> {
+    let (s, _) = comment_or_whitespace0(s)?;
+    many0(import)(s)
 }
 
-fn labelled_instruction(s_instr: &str) -> ParseResult<InstructionToken> {
-    let (s, instr) = an_instruction(s_instr)?;
+fn import(s_import: &str) -> ParseResult<(String, &str)> {
+    let (s, _) = token1("import")(s_import)?;
+    let (s, module_name) = label_addr(s)?;
+    let (s, _) = comment_or_whitespace1(s)?;
+
+    Ok((s, (module_name, s_import)))
+}
+
+fn labelled_instruction<'a>(
+    s_instr: &'a str,
+    constants: &Constants,
+) -> ParseResult<'a, InstructionToken<'a>> {
+    let (s, instr) = an_instruction(s_instr, constants)?;
     Ok((s, InstructionToken::Instruction(instr, s_instr)))
 }
 
@@ -214,13 +433,13 @@ fn breakpoint(breakpoint_s: &str) -> ParseResult<InstructionToken> {
     Ok((s, InstructionToken::Breakpoint(breakpoint_s)))
 }
 
-fn an_instruction(s: &str) -> ParseResult<AnInstruction<String>> {
+fn an_instruction<'a>(s: &'a str, constants: &Constants) -> ParseResult<'a, AnInstruction<String>> {
     // OpStack manipulation
-    let pop = pop_instruction();
-    let push = push_instruction();
-    let divine = divine_instruction();
-    let dup = dup_instruction();
-    let swap = swap_instruction();
+    let pop = pop_instruction(constants);
+    let push = push_instruction(constants);
+    let divine = divine_instruction(constants);
+    let dup = dup_instruction(constants);
+    let swap = swap_instruction(constants);
 
     let opstack_manipulation = alt((pop, push, dup, swap));
 
@@ -236,8 +455,8 @@ fn an_instruction(s: &str) -> ParseResult<AnInstruction<String>> {
     let control_flow = alt((nop, skiz, call, return_, recurse, halt));
 
     // Memory access
-    let read_mem = read_mem_instruction();
-    let write_mem = write_mem_instruction();
+    let read_mem = read_mem_instruction(constants);
+    let write_mem = write_mem_instruction(constants);
 
     let memory_access = alt((read_mem, write_mem));
 
@@ -280,8 +499,8 @@ fn an_instruction(s: &str) -> ParseResult<AnInstruction<String>> {
     ));
 
     // Read/write
-    let read_io = read_io_instruction();
-    let write_io = write_io_instruction();
+    let read_io = read_io_instruction(constants);
+    let write_io = write_io_instruction(constants);
 
     let read_write = alt((read_io, write_io));
 
@@ -324,55 +543,66 @@ fn instruction<'a>(
     }
 }
 
-fn pop_instruction() -> impl Fn(&str) -> ParseResult<AnInstruction<String>> {
+fn pop_instruction(
+    constants: &Constants,
+) -> impl Fn(&str) -> ParseResult<AnInstruction<String>> + '_ {
     move |s: &str| {
         let (s, _) = token1("pop")(s)?; // require space after instruction name
-        let (s, arg) = number_of_words(s)?;
+        let (s, arg) = number_of_words(s, constants)?;
         let (s, _) = comment_or_whitespace1(s)?; // require space after field element
 
         Ok((s, Pop(arg)))
     }
 }
 
-fn push_instruction() -> impl Fn(&str) -> ParseResult<AnInstruction<String>> {
+fn push_instruction(
+    constants: &Constants,
+) -> impl Fn(&str) -> ParseResult<AnInstruction<String>> + '_ {
     move |s: &str| {
         let (s, _) = token1("push")(s)?; // require space after instruction name
-        let (s, elem) = field_element(s)?;
+        let (s, elem) = field_element(s, constants)?;
         let (s, _) = comment_or_whitespace1(s)?; // require space after field element
 
         Ok((s, Push(elem)))
     }
 }
 
-fn divine_instruction() -> impl Fn(&str) -> ParseResult<AnInstruction<String>> {
+fn divine_instruction(
+    constants: &Constants,
+) -> impl Fn(&str) -> ParseResult<AnInstruction<String>> + '_ {
     move |s: &str| {
         let (s, _) = token1("divine")(s)?; // require space after instruction name
-        let (s, arg) = number_of_words(s)?;
+        let (s, arg) = number_of_words(s, constants)?;
         let (s, _) = comment_or_whitespace1(s)?;
 
         Ok((s, Divine(arg)))
     }
 }
 
-fn dup_instruction() -> impl Fn(&str) -> ParseResult<AnInstruction<String>> {
+fn dup_instruction(
+    constants: &Constants,
+) -> impl Fn(&str) -> ParseResult<AnInstruction<String>> + '_ {
     move |s: &str| {
         let (s, _) = token1("dup")(s)?; // require space before argument
-        let (s, stack_register) = stack_register(s)?;
+        let (s, stack_register) = stack_register(s, constants)?;
         let (s, _) = comment_or_whitespace1(s)?;
 
         Ok((s, Dup(stack_register)))
     }
 }
 
-fn swap_instruction() -> impl Fn(&str) -> ParseResult<AnInstruction<String>> {
+fn swap_instruction(
+    constants: &Constants,
+) -> impl Fn(&str) -> ParseResult<AnInstruction<String>> + '_ {
     move |s: &str| {
-        let (s, _) = token1("swap")(s)?; // require space before argument
-        let (s, stack_register) = stack_register(s)?;
+        let (s_argument, _) = token1("swap")(s)?; // require space before argument
+        let (s, stack_register) = stack_register(s_argument, constants)?;
         let (s, _) = comment_or_whitespace1(s)?;
 
         let instruction = Swap(stack_register);
         if instruction.has_illegal_argument() {
-            return cut(context("instruction `swap` cannot take argument `0`", fail))(s);
+            let error = context("instruction `swap` cannot take argument `0`", fail);
+            return cut(error)(s_argument);
         }
 
         Ok((s, instruction))
@@ -410,108 +640,442 @@ fn call_instruction<'a>() -> impl Fn(&'a str) -> ParseResult<AnInstruction<Strin
     }
 }
 
-fn read_mem_instruction() -> impl Fn(&str) -> ParseResult<AnInstruction<String>> {
+fn read_mem_instruction(
+    constants: &Constants,
+) -> impl Fn(&str) -> ParseResult<AnInstruction<String>> + '_ {
     move |s: &str| {
         let (s, _) = token1("read_mem")(s)?; // require space after instruction name
-        let (s, arg) = number_of_words(s)?;
+        let (s, arg) = number_of_words(s, constants)?;
         let (s, _) = comment_or_whitespace1(s)?;
 
         Ok((s, ReadMem(arg)))
     }
 }
 
-fn write_mem_instruction() -> impl Fn(&str) -> ParseResult<AnInstruction<String>> {
+fn write_mem_instruction(
+    constants: &Constants,
+) -> impl Fn(&str) -> ParseResult<AnInstruction<String>> + '_ {
     move |s: &str| {
         let (s, _) = token1("write_mem")(s)?; // require space after instruction name
-        let (s, arg) = number_of_words(s)?;
+        let (s, arg) = number_of_words(s, constants)?;
         let (s, _) = comment_or_whitespace1(s)?;
 
         Ok((s, WriteMem(arg)))
     }
 }
 
-fn read_io_instruction() -> impl Fn(&str) -> ParseResult<AnInstruction<String>> {
+fn read_io_instruction(
+    constants: &Constants,
+) -> impl Fn(&str) -> ParseResult<AnInstruction<String>> + '_ {
     move |s: &str| {
         let (s, _) = token1("read_io")(s)?; // require space after instruction name
-        let (s, arg) = number_of_words(s)?;
+        let (s, arg) = number_of_words(s, constants)?;
         let (s, _) = comment_or_whitespace1(s)?;
 
         Ok((s, ReadIo(arg)))
     }
 }
 
-fn write_io_instruction() -> impl Fn(&str) -> ParseResult<AnInstruction<String>> {
+fn write_io_instruction(
+    constants: &Constants,
+) -> impl Fn(&str) -> ParseResult<AnInstruction<String>> + '_ {
     move |s: &str| {
         let (s, _) = token1("write_io")(s)?; // require space after instruction name
-        let (s, arg) = number_of_words(s)?;
+        let (s, arg) = number_of_words(s, constants)?;
         let (s, _) = comment_or_whitespace1(s)?;
 
         Ok((s, WriteIo(arg)))
     }
 }
 
-fn field_element(s_orig: &str) -> ParseResult<BFieldElement> {
-    let (s, negative) = opt(token0("-"))(s_orig)?;
-    let (s, n) = digit1(s)?;
+fn field_element<'a>(s: &'a str, constants: &Constants) -> ParseResult<'a, BFieldElement> {
+    constant_expression(s, constants)
+}
 
-    let Ok(mut n): Result<i128, _> = n.parse() else {
-        return context("out-of-bounds constant", fail)(s);
+fn stack_register<'a>(s: &'a str, constants: &Constants) -> ParseResult<'a, OpStackElement> {
+    let (s, n) = constant_expression(s, constants)?;
+    let Ok(stack_register) = OpStackElement::try_from(n) else {
+        return context("using an out-of-bounds stack register (0-15 exist)", fail)(s);
     };
 
-    let quotient = i128::from(BFieldElement::P);
-    if n >= quotient {
-        return context("out-of-bounds constant", fail)(s_orig);
+    Ok((s, stack_register))
+}
+
+fn number_of_words<'a>(s: &'a str, constants: &Constants) -> ParseResult<'a, NumberOfWords> {
+    let (s, n) = constant_expression(s, constants)?;
+    let Ok(arg) = NumberOfWords::try_from(n) else {
+        return context("using an out-of-bounds argument (1-5 allowed)", fail)(s);
+    };
+
+    Ok((s, arg))
+}
+
+/// Parse one declaration of a named constant.
+///
+/// Constant declarations look like this:
+///
+/// ```text
+/// const <NAME> = <expression>
+/// ```
+///
+/// Names start with an uppercase letter, followed by uppercase letters, digits, or underscores.
+/// See [`constant_expression`] for the syntax of expressions. Once declared, a constant can be
+/// used in the arguments of all subsequent instructions, and in subsequent declarations.
+fn constant_declaration(
+    constants: &Constants,
+) -> impl Fn(&str) -> ParseResult<(String, BFieldElement)> + '_ {
+    move |s: &str| {
+        let (s_name, _) = token1("const")(s)?;
+        let (s, name) = cut(context("expecting constant name", constant_name))(s_name)?;
+        if constants.contains_key(&name) {
+            return cut(context("constant is already declared", fail))(s_name);
+        }
+
+        let (s, _) = comment_or_whitespace0(s)?;
+        let (s, _) = cut(context("expecting `=`", token0("=")))(s)?;
+        let (s, value) = cut(|s| constant_expression(s, constants))(s)?;
+        let (s, _) = cut(context(
+            "expecting end of declaration",
+            comment_or_whitespace1,
+        ))(s)?;
+
+        Ok((s, (name, value)))
+    }
+}
+
+fn constant_name(s_name: &str) -> ParseResult<String> {
+    let (s, name) = take_while1(is_constant_name_character)(s_name)?;
+    if !name.starts_with(|c: char| c.is_ascii_uppercase()) {
+        return context("constant name must start with an uppercase letter", fail)(s_name);
+    }
+
+    Ok((s, name.to_string()))
+}
+
+fn is_constant_name_character(c: char) -> bool {
+    c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_'
+}
+
+/// Parse one directive declaring [static data](StaticData).
+///
+/// Static data directives look like this:
+///
+/// ```text
+/// .data <address>: <value>, ...
+/// ```
+///
+/// The address and the values are [constant expressions](constant_expression).
+fn static_data<'a>(
+    s_data: &'a str,
+    constants: &Constants,
+) -> ParseResult<'a, InstructionToken<'a>> {
+    let (s, _) = token1(".data")(s_data)?;
+    let expression = |s| constant_expression(s, constants);
+    let (s, address) = cut(context("expecting address", expression))(s)?;
+    let (s, _) = comment_or_whitespace0(s)?;
+    let (s, _) = cut(context("expecting `:`", token0(":")))(s)?;
+
+    let separator = preceded(comment_or_whitespace0, token0(","));
+    let (s, values) = cut(context(
+        "expecting value",
+        separated_list1(separator, expression),
+    ))(s)?;
+    let (s, _) = cut(context("expecting whitespace", comment_or_whitespace1))(s)?;
+
+    let static_data = StaticData { address, values };
+    Ok((s, InstructionToken::Data(static_data, s_data)))
+}
+
+/// Parse one macro definition.
+///
+/// Macro definitions look like this:
+///
+/// ```text
+/// macro <name>(<PARAMETER>, ...)
+///     <body>
+/// endmacro
+/// ```
+///
+/// Macro names follow the rules for labels, but cannot be namespaced. Parameters follow the rules
+/// for [constants](constant_declaration). Macro definitions cannot be nested.
+fn macro_definition<'a>(s: &'a str, scope: &Scope<'a>) -> ParseResult<'a, (String, Macro<'a>)> {
+    let (s_name, _) = token1("macro")(s)?;
+    let (s, name) = cut(context("expecting macro name", label_segment))(s_name)?;
+    if is_instruction_name(&name) {
+        return cut(context("macro cannot be named after instruction", fail))(s_name);
+    }
+    if scope.macros.contains_key(&name) {
+        return cut(context("macro is already defined", fail))(s_name);
     }
 
-    if negative.is_some() {
-        n *= -1;
-        n += quotient;
-    }
-
-    Ok((s, BFieldElement::new(n as u64)))
-}
-
-fn stack_register(s: &str) -> ParseResult<OpStackElement> {
-    let (s, n) = digit1(s)?;
-    let stack_register = match n {
-        "0" => ST0,
-        "1" => ST1,
-        "2" => ST2,
-        "3" => ST3,
-        "4" => ST4,
-        "5" => ST5,
-        "6" => ST6,
-        "7" => ST7,
-        "8" => ST8,
-        "9" => ST9,
-        "10" => ST10,
-        "11" => ST11,
-        "12" => ST12,
-        "13" => ST13,
-        "14" => ST14,
-        "15" => ST15,
-        _ => return context("using an out-of-bounds stack register (0-15 exist)", fail)(s),
+    let (s, _) = whitespace0(s)?;
+    let (s_parameters, _) = cut(context("expecting `(`", token0("(")))(s)?;
+    let parameter = terminated(constant_name, comment_or_whitespace0);
+    let (s, parameters) = separated_list0(token0(","), parameter)(s_parameters)?;
+    let (s, _) = cut(context("expecting `)`", token0(")")))(s)?;
+    if !parameters.iter().all_unique() {
+        return cut(context("macro parameters must be distinct", fail))(s_parameters);
+    }
+
+    let (s, body) = cut(context("expecting `endmacro`", macro_body))(s)?;
+    let macro_ = Macro {
+        parameters,
+        body,
+        scope: scope.clone(),
     };
 
-    Ok((s, stack_register))
+    Ok((s, (name, macro_)))
 }
 
-fn number_of_words(s: &str) -> ParseResult<NumberOfWords> {
-    let (s, n) = digit1(s)?;
-    let arg = match n {
-        "1" => N1,
-        "2" => N2,
-        "3" => N3,
-        "4" => N4,
-        "5" => N5,
-        _ => return context("using an out-of-bounds argument (1-5 allowed)", fail)(s),
+/// Parse the body of a macro, including the closing `endmacro`. Returns the body's source code.
+fn macro_body(s_body: &str) -> ParseResult<&str> {
+    let mut s = s_body;
+    loop {
+        if let Ok((s_rest, _)) = token1("endmacro")(s) {
+            let body = &s_body[..s_body.len() - s.len()];
+            return Ok((s_rest, body));
+        }
+
+        // A `/` not followed by another `/` is division in a constant expression.
+        let word = alt((
+            take_till1(|c: char| c.is_whitespace() || c == '/'),
+            tag("/"),
+        ));
+        (s, _) = alt((comment1, whitespace1, map(word, |_| ())))(s)?;
+    }
+}
+
+/// Parse one macro invocation, like `<name>(<argument>, ...)`, and return its expansion.
+/// Whitespace between the name and the opening parenthesis is allowed.
+///
+/// The arguments are [constant expressions](constant_expression), evaluated at the invocation
+/// site. Labels defined in the macro's body are local to each expansion: they are prefixed with
+/// the macro's name and the index of the expansion, separated by an `@`. Since `@` cannot appear
+/// in labels written by the user, local labels never collide with them. All tokens of the
+/// expansion refer to the invocation as their source.
+fn macro_invocation<'a>(
+    s_invocation: &'a str,
+    scope: &Scope<'a>,
+    expansion_index: usize,
+) -> ParseResult<'a, Vec<InstructionToken<'a>>> {
+    let (s, name) = label_segment(s_invocation)?;
+    if is_instruction_name(&name) {
+        return fail(s_invocation);
+    }
+    let (s, _) = whitespace0(s)?;
+    let (s, _) = token0("(")(s)?;
+    let Some(macro_) = scope.macros.get(&name) else {
+        return cut(context("unknown macro", fail))(s_invocation);
     };
 
-    Ok((s, arg))
+    let argument = |s| constant_expression(s, &scope.constants);
+    let argument = terminated(argument, comment_or_whitespace0);
+    let (s, arguments) = separated_list0(token0(","), argument)(s)?;
+    let (s, _) = cut(context("expecting `)`", tag(")")))(s)?;
+    let (s, _) = cut(context("expecting whitespace", comment_or_whitespace1))(s)?;
+    if arguments.len() != macro_.parameters.len() {
+        return cut(context("wrong number of macro arguments", fail))(s_invocation);
+    }
+
+    let mut body_scope = macro_.scope.clone();
+    let parameters = macro_.parameters.iter().cloned();
+    body_scope.constants.extend(parameters.zip(arguments));
+    let body = tokenize_in_scope(macro_.body, body_scope).map_err(|err| {
+        err.map(|mut err| {
+            let expansion_context = VerboseErrorKind::Context("in expansion of macro");
+            err.errors.push((s_invocation, expansion_context));
+            err
+        })
+    });
+    let body = match body {
+        Ok((_, body)) => body,
+        Err(nom::Err::Error(err)) => return Err(nom::Err::Failure(err)),
+        Err(err) => return Err(err),
+    };
+
+    let local_labels = body
+        .iter()
+        .filter_map(|token| match token {
+            InstructionToken::Label(label, _) => Some(label.as_str()),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+    let hygienic = |label: &String| {
+        if local_labels.contains(label.as_str()) {
+            format!("{name}@{expansion_index}-{label}")
+        } else {
+            label.clone()
+        }
+    };
+
+    let expansion = body
+        .iter()
+        .map(|token| match token {
+            InstructionToken::Instruction(instruction, _) => {
+                let instruction = instruction.map_call_address(hygienic);
+                InstructionToken::Instruction(instruction, s_invocation)
+            }
+            InstructionToken::Label(label, _) => {
+                InstructionToken::Label(hygienic(label), s_invocation)
+            }
+            InstructionToken::Breakpoint(_) => InstructionToken::Breakpoint(s_invocation),
+            InstructionToken::TypeHint(type_hint, _) => {
+                InstructionToken::TypeHint(type_hint.clone(), s_invocation)
+            }
+            InstructionToken::Data(static_data, _) => {
+                InstructionToken::Data(static_data.clone(), s_invocation)
+            }
+        })
+        .collect();
+
+    Ok((s, expansion))
+}
+
+/// Parse and evaluate an expression over the field, using the given named constants.
+///
+/// Expressions are built from number literals, named constants, and parentheses using the
+/// binary operators `+`, `-`, `*`, `/` (multiplication by the inverse), and `^`
+/// (exponentiation), as well as unary negation. The usual precedences apply: `^` binds tightest
+/// and is right-associative, followed by negation, then `*` and `/`, then `+` and `-`. For
+/// example, `-2^32 + 1` evaluates to `P - 2^32 + 1`.
+///
+/// Exponents are integers, not field elements: they are number literals up to [`u64::MAX`],
+/// named constants (using their canonical representative), or, again, exponentiations of
+/// those. For example, `2^3^2` evaluates to `2^9`.
+///
+/// Any whitespace is consumed only between the parts of the expression, never after it.
+fn constant_expression<'a>(s: &'a str, constants: &Constants) -> ParseResult<'a, BFieldElement> {
+    let (mut s, mut value) = constant_term(s, constants)?;
+    loop {
+        let operator = preceded(comment_or_whitespace0, alt((tag("+"), tag("-"))));
+        let (s_operand, Some(operator)) = opt(operator)(s)? else {
+            return Ok((s, value));
+        };
+        let (s_operand, _) = comment_or_whitespace0(s_operand)?;
+        let (s_rest, operand) = cut(|s| constant_term(s, constants))(s_operand)?;
+        value = match operator {
+            "+" => value + operand,
+            _ => value - operand,
+        };
+        s = s_rest;
+    }
+}
+
+fn constant_term<'a>(s: &'a str, constants: &Constants) -> ParseResult<'a, BFieldElement> {
+    let (mut s, mut value) = constant_unary(s, constants)?;
+    loop {
+        // A `/` followed by another `/` starts a comment.
+        let division = terminated(tag("/"), not(tag("/")));
+        let operator = preceded(comment_or_whitespace0, alt((tag("*"), division)));
+        let (s_operand, Some(operator)) = opt(operator)(s)? else {
+            return Ok((s, value));
+        };
+        let (s_operand, _) = comment_or_whitespace0(s_operand)?;
+        let (s_rest, operand) = cut(|s| constant_unary(s, constants))(s_operand)?;
+        value = match operator {
+            "*" => value * operand,
+            _ if operand.is_zero() => {
+                return cut(context("division by zero in constant expression", fail))(s_operand)
+            }
+            _ => value / operand,
+        };
+        s = s_rest;
+    }
+}
+
+fn constant_unary<'a>(s: &'a str, constants: &Constants) -> ParseResult<'a, BFieldElement> {
+    let Ok((s, _)) = token0("-")(s) else {
+        return constant_power(s, constants);
+    };
+    let (s, value) = cut(|s| constant_unary(s, constants))(s)?;
+
+    Ok((s, -value))
+}
+
+fn constant_power<'a>(s: &'a str, constants: &Constants) -> ParseResult<'a, BFieldElement> {
+    let (s, base) = constant_atom(s, constants)?;
+
+    let exponentiation = preceded(comment_or_whitespace0, tag("^"));
+    let (s_exponent, Some(_)) = opt(exponentiation)(s)? else {
+        return Ok((s, base));
+    };
+    let (s_exponent, _) = comment_or_whitespace0(s_exponent)?;
+    let (s, exponent) = cut(|s| constant_exponent(s, constants))(s_exponent)?;
+
+    Ok((s, base.mod_pow(exponent)))
+}
+
+fn constant_exponent<'a>(s_orig: &'a str, constants: &Constants) -> ParseResult<'a, u64> {
+    let (s, base) = if let Ok((s_rest, name)) = constant_name(s_orig) {
+        let Some(&value) = constants.get(&name) else {
+            return cut(context("unknown constant", fail))(s_orig);
+        };
+        (s_rest, value.value())
+    } else {
+        let (s, n) = context("expecting exponent", digit1)(s_orig)?;
+        let Ok(n) = n.parse::<u64>() else {
+            return context("out-of-bounds exponent", fail)(s_orig);
+        };
+        (s, n)
+    };
+
+    let exponentiation = preceded(comment_or_whitespace0, tag("^"));
+    let (s_exponent, Some(_)) = opt(exponentiation)(s)? else {
+        return Ok((s, base));
+    };
+    let (s_exponent, _) = comment_or_whitespace0(s_exponent)?;
+    let (s, exponent) = cut(|s| constant_exponent(s, constants))(s_exponent)?;
+    let exponent = u32::try_from(exponent).ok();
+    let Some(power) = exponent.and_then(|exponent| base.checked_pow(exponent)) else {
+        return cut(context("out-of-bounds exponent", fail))(s_orig);
+    };
+
+    Ok((s, power))
+}
+
+fn constant_atom<'a>(s: &'a str, constants: &Constants) -> ParseResult<'a, BFieldElement> {
+    if let Ok((s, _)) = token0("(")(s) {
+        let (s, value) = cut(|s| constant_expression(s, constants))(s)?;
+        let (s, _) = comment_or_whitespace0(s)?;
+        let (s, _) = cut(context("expecting `)`", tag(")")))(s)?;
+        return Ok((s, value));
+    }
+
+    if let Ok((s_rest, name)) = constant_name(s) {
+        let Some(&value) = constants.get(&name) else {
+            return cut(context("unknown constant", fail))(s);
+        };
+        return Ok((s_rest, value));
+    }
+
+    number_literal(s)
+}
+
+fn number_literal(s_orig: &str) -> ParseResult<BFieldElement> {
+    let (s, n) = digit1(s_orig)?;
+    let Ok(n) = n.parse::<u64>() else {
+        return context("out-of-bounds constant", fail)(s_orig);
+    };
+    if n >= BFieldElement::P {
+        return context("out-of-bounds constant", fail)(s_orig);
+    }
+
+    Ok((s, BFieldElement::new(n)))
 }
 
 /// Parse a label address. This is used in "`<label>:`" and in "`call <label>`".
+///
+/// Labels can be namespaced using `::`, as in `module::label`. The [linker](crate::linker) uses
+/// this to qualify the labels of different modules.
 fn label_addr(s_orig: &str) -> ParseResult<String> {
+    let (s, first_segment) = label_segment(s_orig)?;
+    let (s, other_segments) = many0(preceded(tag("::"), label_segment))(s)?;
+
+    let segments = [vec![first_segment], other_segments].concat();
+    Ok((s, segments.join("::")))
+}
+
+fn label_segment(s_orig: &str) -> ParseResult<String> {
     let (s, addr_part_0) = take_while1(is_label_start_char)(s_orig)?;
     if addr_part_0.is_empty() {
         // todo: this error is never shown to the user, since the `label` parser is wrapped in an
@@ -705,12 +1269,15 @@ pub(crate) mod tests {
     use test_strategy::proptest;
     use test_strategy::Arbitrary;
     use twenty_first::bfe;
+    use twenty_first::bfe_vec;
     use twenty_first::prelude::tip5;
 
     use LabelledInstruction::Breakpoint;
     use LabelledInstruction::Instruction;
     use LabelledInstruction::Label;
 
+    use crate::op_stack::NumberOfWords::*;
+    use crate::op_stack::OpStackElement::*;
     use crate::program::Program;
     use crate::triton_asm;
     use crate::triton_instr;
@@ -969,6 +1536,42 @@ pub(crate) mod tests {
         });
     }
 
+    #[test]
+    fn parse_program_namespaced_label() {
+        parse_program_prop(TestCase {
+            input: "std::hashing::absorb: call std::hashing::absorb",
+            expected: Program::new(&[
+                Label("std::hashing::absorb".to_string()),
+                Instruction(Call("std::hashing::absorb".to_string())),
+            ]),
+            message: "labels can be namespaced",
+        });
+
+        parse_program_neg_prop(NegativeTestCase {
+            input: "foo::: call foo",
+            expected_error: "expecting label, instruction or eof",
+            expected_error_count: 1,
+            message: "namespace separator must be followed by a label segment",
+        });
+    }
+
+    #[test]
+    fn parse_imports() {
+        let input = "// header\nimport std::io import hashing\npush 1 halt";
+        let_assert!(Ok((rest, imports)) = imports(input));
+        let module_names = imports.iter().map(|(name, _)| name.as_str()).collect_vec();
+        assert!(vec!["std::io", "hashing"] == module_names);
+        assert!("push 1 halt" == rest);
+    }
+
+    #[test]
+    fn label_named_import_is_not_an_import() {
+        let input = "import: call import";
+        let_assert!(Ok((rest, imports)) = imports(input));
+        assert!(imports.is_empty());
+        assert!(input == rest);
+    }
+
     #[test]
     fn parse_program_nonexistent_instructions() {
         parse_program_neg_prop(NegativeTestCase {
@@ -999,6 +1602,281 @@ pub(crate) mod tests {
         });
     }
 
+    #[test]
+    fn parse_program_with_constants() {
+        parse_program_prop(TestCase {
+            input: "const N = 3 const TWO_TO_THE_32 = 2^32 \
+                    push TWO_TO_THE_32 dup N pop N - 1 swap 2 * N read_mem N / 3 halt",
+            expected: triton_program!(push 4294967296 dup 3 pop 2 swap 6 read_mem 1 halt),
+            message: "constants can be used wherever instructions take arguments",
+        });
+
+        parse_program_prop(TestCase {
+            input: "const N = 4 // four\nconst M = N + 1\npush -(M * N) // twenty\nhalt",
+            expected: triton_program!(push -20 halt),
+            message: "constants can be declared in terms of other constants",
+        });
+
+        parse_program_prop(TestCase {
+            input: "push 4 / 2 // halving\npush -2^3 push 2^3^2 halt",
+            expected: triton_program!(push 2 push -8 push 512 halt),
+            message: "division is not confused with comments; `^` is right-associative",
+        });
+    }
+
+    #[proptest]
+    fn constant_expressions_follow_field_arithmetic(
+        #[strategy(arb())] a: BFieldElement,
+        #[strategy(arb())] b: BFieldElement,
+        #[strategy(arb())]
+        #[filter(!#c.is_zero())]
+        c: BFieldElement,
+        #[strategy(0_u64..64)] k: u64,
+    ) {
+        let source = format!(
+            "const A = {a} const B = {b} const C = {c} \
+            push A + B * C push (A - B) / C push -A push 2^{k} + A halt"
+        );
+        let_assert!(Ok(tokens) = parse(&source));
+        let program = Program::new(&to_labelled_instructions(&tokens));
+
+        let expected = triton_program!(
+            push {a + b * c} push {(a - b) / c} push {-a} push {bfe!(1_u64 << k) + a} halt
+        );
+        prop_assert_eq!(expected, program);
+    }
+
+    #[test]
+    fn parse_program_with_erroneous_constants() {
+        parse_program_neg_prop(NegativeTestCase {
+            input: "push N const N = 1",
+            expected_error: "unknown constant",
+            expected_error_count: 1,
+            message: "constants must be declared before they are used",
+        });
+
+        parse_program_neg_prop(NegativeTestCase {
+            input: "const N = 1 const N = 2",
+            expected_error: "constant is already declared",
+            expected_error_count: 1,
+            message: "constants cannot be redeclared",
+        });
+
+        parse_program_neg_prop(NegativeTestCase {
+            input: "const N = 1 push 1 / (N - 1)",
+            expected_error: "division by zero in constant expression",
+            expected_error_count: 1,
+            message: "division by zero is an error",
+        });
+
+        parse_program_neg_prop(NegativeTestCase {
+            input: "const n = 1",
+            expected_error: "expecting constant name",
+            expected_error_count: 1,
+            message: "constant names must start with an uppercase letter",
+        });
+
+        parse_program_neg_prop(NegativeTestCase {
+            input: "const N = 16 dup N",
+            expected_error: "expecting label, instruction or eof",
+            expected_error_count: 1,
+            message: "there is no dup 16 instruction, even if 16 is a constant",
+        });
+
+        parse_program_neg_prop(NegativeTestCase {
+            input: "push (1 + 2 halt",
+            expected_error: "expecting `)`",
+            expected_error_count: 1,
+            message: "parentheses must be closed",
+        });
+
+        parse_program_neg_prop(NegativeTestCase {
+            input: "push 2^-1",
+            expected_error: "expecting exponent",
+            expected_error_count: 1,
+            message: "exponents are integers, not field elements",
+        });
+
+        parse_program_neg_prop(NegativeTestCase {
+            input: "push 2^2^64",
+            expected_error: "out-of-bounds exponent",
+            expected_error_count: 1,
+            message: "exponents must fit into a u64",
+        });
+    }
+
+    #[test]
+    fn exponents_are_not_reduced_modulo_p() {
+        let_assert!(Ok(tokens) = parse("push 3^18446744073709551615 halt"));
+        let program = Program::new(&to_labelled_instructions(&tokens));
+        let expected = triton_program!(push {bfe!(3).mod_pow(u64::MAX)} halt);
+        assert!(expected == program);
+    }
+
+    #[test]
+    fn out_of_bounds_constant_is_reported_at_its_start() {
+        for source in ["18446744069414584321 halt", "99999999999999999999999 halt"] {
+            let_assert!(Err(nom::Err::Error(error)) = number_literal(source));
+            let_assert!(Some(&(location, _)) = error.errors.first());
+            assert!(source == location);
+        }
+    }
+
+    #[test]
+    fn triton_asm_macro_supports_constants() {
+        let instructions = triton_asm!(const N = 2 ^ 5 push N dup N - 30);
+        let expected_instructions = vec![Instruction(Push(bfe!(32))), Instruction(Dup(ST2))];
+        assert!(expected_instructions == instructions);
+    }
+
+    #[test]
+    fn parse_program_with_macros() {
+        parse_program_prop(TestCase {
+            input: "macro u32_check(N)\n    dup N split pop 1 push 0 eq assert\nendmacro\n\
+                    push 5 u32_check(0) push 7 u32_check(1) halt",
+            expected: triton_program!(
+                push 5 dup 0 split pop 1 push 0 eq assert
+                push 7 dup 1 split pop 1 push 0 eq assert
+                halt
+            ),
+            message: "macros are expanded with their arguments",
+        });
+
+        parse_program_prop(TestCase {
+            input: "const N = 2 macro double() push N mul endmacro \
+                    macro quadruple() double() double() endmacro \
+                    push 1 quadruple() halt",
+            expected: triton_program!(push 1 push 2 mul push 2 mul halt),
+            message: "macros can use constants and previously defined macros",
+        });
+
+        parse_program_prop(TestCase {
+            input: "macro m(A, B) push A / B // endmacro\nendmacro m(6, 3) halt",
+            expected: triton_program!(push 2 halt),
+            message: "a commented-out `endmacro` does not end the macro's body",
+        });
+    }
+
+    #[test]
+    fn labels_in_macros_are_hygienic() {
+        let source = "macro spin() here: nop call here endmacro \
+                      here: spin() spin() call here";
+        let_assert!(Ok(tokens) = parse(source));
+        let instructions = to_labelled_instructions(&tokens);
+
+        let label = |name: &str| Label(name.to_string());
+        let call = |name: &str| Instruction(AnInstruction::Call(name.to_string()));
+        let nop = Instruction(AnInstruction::Nop);
+        let expected_instructions = vec![
+            label("here"),
+            label("spin@0-here"),
+            nop.clone(),
+            call("spin@0-here"),
+            label("spin@1-here"),
+            nop,
+            call("spin@1-here"),
+            call("here"),
+        ];
+        assert!(expected_instructions == instructions);
+
+        let program = Program::new(&instructions);
+        let round_tripped = Program::new(&program.labelled_instructions());
+        assert!(program == round_tripped);
+        assert!(program.labelled_instructions() == round_tripped.labelled_instructions());
+    }
+
+    #[test]
+    fn local_labels_of_macros_do_not_collide_with_user_labels() {
+        let source = "macro spin() here: nop call here endmacro \
+                      spin-0-here: spin() call spin-0-here";
+        let_assert!(Ok(tokens) = parse(source));
+        let instructions = to_labelled_instructions(&tokens);
+        assert!(Label("spin-0-here".to_string()) == instructions[0]);
+        let call = Instruction(AnInstruction::Call("spin-0-here".to_string()));
+        assert!(Some(&call) == instructions.last());
+
+        assert!(parse("spin@0-here: halt").is_err());
+    }
+
+    #[test]
+    fn tokens_of_macro_expansions_refer_to_invocation() {
+        let source = "macro m() push 1 push 2 endmacro halt m()";
+        let_assert!(Ok(tokens) = parse(source));
+        let expansion_token_strs = tokens[1..].iter().map(|t| t.token_str()).collect_vec();
+        assert!(vec!["m()", "m()"] == expansion_token_strs);
+    }
+
+    #[test]
+    fn program_with_macros_runs() {
+        let program = triton_program! {
+            macro add_const(C)
+                push C add
+            endmacro
+            read_io 1 add_const(2) add_const(3 * 4) write_io 1 halt
+        };
+        let_assert!(Ok(output) = program.run(bfe_vec![1].into(), [].into()));
+        assert!(bfe_vec![15] == output);
+    }
+
+    #[test]
+    fn parse_program_with_erroneous_macros() {
+        parse_program_neg_prop(NegativeTestCase {
+            input: "m() macro m() nop endmacro",
+            expected_error: "unknown macro",
+            expected_error_count: 1,
+            message: "macros must be defined before they are invoked",
+        });
+
+        parse_program_neg_prop(NegativeTestCase {
+            input: "macro m(A) push A endmacro m(1, 2)",
+            expected_error: "wrong number of macro arguments",
+            expected_error_count: 1,
+            message: "macro invocations must match the macro's signature",
+        });
+
+        parse_program_neg_prop(NegativeTestCase {
+            input: "macro m() push 1",
+            expected_error: "expecting `endmacro`",
+            expected_error_count: 1,
+            message: "macros must be terminated",
+        });
+
+        parse_program_neg_prop(NegativeTestCase {
+            input: "macro m() nop endmacro macro m() halt endmacro",
+            expected_error: "macro is already defined",
+            expected_error_count: 1,
+            message: "macros cannot be redefined",
+        });
+
+        parse_program_neg_prop(NegativeTestCase {
+            input: "macro pop() nop endmacro",
+            expected_error: "macro cannot be named after instruction",
+            expected_error_count: 1,
+            message: "macro names are like label names",
+        });
+
+        parse_program_neg_prop(NegativeTestCase {
+            input: "macro m(A, A) push A endmacro",
+            expected_error: "macro parameters must be distinct",
+            expected_error_count: 1,
+            message: "macro parameters must be distinct",
+        });
+
+        parse_program_neg_prop(NegativeTestCase {
+            input: "macro m() push N endmacro m()",
+            expected_error: "in expansion of macro",
+            expected_error_count: 1,
+            message: "errors in a macro's body are reported at the invocation",
+        });
+
+        parse_program_neg_prop(NegativeTestCase {
+            input: "macro m() foo: nop endmacro m() call foo",
+            expected_error: "missing label",
+            expected_error_count: 1,
+            message: "labels defined in macros are not visible outside the expansion",
+        });
+    }
+
     #[test]
     fn parse_program_bracket_syntax() {
         parse_program_prop(TestCase {
@@ -1237,6 +2115,57 @@ pub(crate) mod tests {
         prop_assert_eq!(type_hint, parsed_type_hint);
     }
 
+    #[proptest]
+    fn static_data_to_string_to_static_data_is_identity(
+        #[strategy(arb())] static_data: StaticData,
+    ) {
+        let static_data_string = static_data.to_string();
+        let instruction_tokens = parse(&static_data_string)
+            .map_err(|err| TestCaseError::Fail(format!("{err}").into()))?;
+        let labelled_instructions = to_labelled_instructions(&instruction_tokens);
+        prop_assert_eq!(1, labelled_instructions.len());
+        let first_labelled_instruction = labelled_instructions[0].clone();
+        let_assert!(LabelledInstruction::Data(parsed_static_data) = first_labelled_instruction);
+        prop_assert_eq!(static_data, parsed_static_data);
+    }
+
+    #[test]
+    fn parse_program_with_static_data() {
+        parse_program_prop(TestCase {
+            input: ".data 42: 1, 2 push 0 halt",
+            expected: triton_program!(push 2 push 1 push 42 write_mem 2 pop 1 push 0 halt),
+            message: "static data is written to RAM by a prologue",
+        });
+
+        parse_program_prop(TestCase {
+            input: "const BASE = 2^32\n\
+                    halt\n\
+                    .data BASE: 1, 2, 3, 4, 5, 6 // more than fits into one `write_mem`\n\
+                    .data BASE + 6: 7",
+            expected: triton_program!(
+                push 5 push 4 push 3 push 2 push 1 push 4294967296 write_mem 5 pop 1
+                push 6 push 4294967301 write_mem 1 pop 1
+                push 7 push 4294967302 write_mem 1 pop 1
+                halt
+            ),
+            message: "static data can be declared anywhere, using constant expressions",
+        });
+
+        parse_program_neg_prop(NegativeTestCase {
+            input: ".data 42 1",
+            expected_error: "expecting `:`",
+            expected_error_count: 1,
+            message: "address and values are separated by a colon",
+        });
+
+        parse_program_neg_prop(NegativeTestCase {
+            input: ".data 42: halt",
+            expected_error: "expecting value",
+            expected_error_count: 1,
+            message: "static data cannot be empty",
+        });
+    }
+
     #[test]
     fn triton_asm_macro() {
         let instructions = triton_asm!(write_io 3 push 17 call huh lt swap 3);
@@ -1390,4 +2319,23 @@ pub(crate) mod tests {
         let printed_program = format!("{program}");
         assert_eq!(source_code, &printed_program);
     }
+
+    #[test]
+    fn source_locator_counts_lines_and_characters() {
+        let source = "push 1\n\n  pop 1 // ä comment\nhalt";
+        let locator = SourceLocator::new("main.tasm", source);
+        let location = |line, column| SourceLocation {
+            file: "main.tasm".to_string(),
+            line,
+            column,
+        };
+
+        assert!(location(1, 1) == locator.locate(source));
+        assert!(location(1, 6) == locator.locate(&source[5..]));
+        assert!(location(2, 1) == locator.locate(&source[7..]));
+        assert!(location(3, 3) == locator.locate(&source[10..]));
+        assert!(location(3, 13) == locator.locate(" comment\nhalt"));
+        assert!(location(4, 1) == locator.locate("halt"));
+        assert!(location(4, 5) == locator.locate(""));
+    }
 }
@@ -1,11 +1,25 @@
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
 use std::cmp::max;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::env::var as env_var;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::iter::once;
 use std::ops::AddAssign;
 use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::thread;
 use std::time::Duration;
 use std::time::Instant;
 use std::vec;
@@ -16,21 +30,68 @@ use colored::ColoredString;
 use colored::Colorize;
 use criterion::profiler::Profiler;
 use itertools::Itertools;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
 use unicode_width::UnicodeWidthStr;
 
 const ENV_VAR_PROFILER_LIVE_UPDATE: &str = "TVM_PROFILER_LIVE_UPDATE";
 
+/// The default relative slowdown above which a [`ReportComparison`] considers a task or
+/// category to have regressed significantly.
+pub const DEFAULT_REGRESSION_THRESHOLD: f64 = 0.05;
+
+/// The counters of every [`TrackingAllocator`] created through its public constructors.
+static GLOBAL_ALLOCATION_COUNTERS: AllocationCounters = AllocationCounters::new();
+
+/// The bytes allocated through a [`TrackingAllocator`].
+#[derive(Debug)]
+struct AllocationCounters {
+    /// The number of bytes currently allocated.
+    allocated: AtomicUsize,
+
+    /// The maximum of `allocated` since the last reset. See [`TritonProfiler::plain_start`] and
+    /// [`TritonProfiler::plain_stop`].
+    peak: AtomicUsize,
+
+    /// Whether the counters are reported to [`TritonProfiler`]s. See
+    /// [`TrackingAllocator::enable`].
+    is_enabled: AtomicBool,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 struct Task {
     name: String,
     parent_index: Option<usize>,
     depth: usize,
+
+    /// The time the task started, relative to the creation of the profiler.
+    start: Duration,
+
+    /// The time the task took. Zero while the task is running.
     time: Duration,
     task_type: TaskType,
 
     /// The type of work the task is doing. Helps to track time across specific tasks. For
     /// example, if the task is building a Merkle tree, then the category could be "hash".
     category: Option<String>,
+
+    /// The task's memory usage. `None` if memory is not being tracked, see
+    /// [`TrackingAllocator`].
+    memory: Option<MemoryUsage>,
+}
+
+/// The memory allocated during a [`Task`], in bytes.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+struct MemoryUsage {
+    /// The memory allocated when the task started.
+    start: usize,
+
+    /// The memory allocated when the task stopped. Equals `start` while the task is running.
+    end: usize,
+
+    /// The maximum memory allocated at any point while the task was running. Equals `start`
+    /// while the task is running.
+    peak: usize,
 }
 
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Arbitrary)]
@@ -39,15 +100,106 @@ enum TaskType {
     Generic,
     IterationZero,
     AnyOtherIteration,
+
+    /// Time spent concurrently to the task's parent, for example, on another thread or on an
+    /// accelerator. Recorded through a [`ProfilerHandle`].
+    Concurrent,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone)]
 pub struct TritonProfiler {
     name: String,
     timer: Instant,
     stack: Vec<(usize, String)>,
     profile: Vec<Task>,
     total_time: Option<Duration>,
+
+    /// The spans recorded through all [`ProfilerHandle`]s of this profiler.
+    spans: Arc<Mutex<Vec<Span>>>,
+
+    /// For every task on the stack, the peak memory usage before the task started, if memory is
+    /// being tracked.
+    outer_peaks: Vec<Option<usize>>,
+
+    /// Where to read the memory usage of tasks from.
+    allocation_counters: &'static AllocationCounters,
+}
+
+/// A [global allocator](GlobalAlloc) keeping track of the number of allocated bytes. If it is
+/// installed and [enabled](Self::enable), every [`TritonProfiler`] records the memory usage of its
+/// tasks: the bytes allocated when the task started and stopped, as well as the peak in between.
+/// Among other things, this includes the memory of the master tables, their low-degree
+/// extensions, and Merkle trees.
+///
+/// Tracking memory is opt-in, as it makes every allocation a little more expensive.
+///
+/// ```
+/// # use triton_vm::profiler::TrackingAllocator;
+/// #[global_allocator]
+/// static ALLOCATOR: TrackingAllocator = TrackingAllocator::system();
+///
+/// fn main() {
+///     TrackingAllocator::enable();
+///     // …
+/// }
+/// ```
+///
+/// The memory usage is process-wide. Allocations happening on other threads, for example, of
+/// concurrent profilers, are attributed to whichever tasks are running at the time.
+#[derive(Debug, Copy, Clone)]
+pub struct TrackingAllocator<A = System> {
+    inner: A,
+    counters: &'static AllocationCounters,
+}
+
+/// A handle to a [`TritonProfiler`] that can be cloned into worker threads, for example, into
+/// the closures of [rayon](https://docs.rs/rayon). Each thread records its own
+/// [spans](Self::span). Timings measured on an accelerator can be
+/// [recorded](Self::record_device_span) as well.
+///
+/// All spans recorded through a handle belong to the profiler's task that was running when the
+/// handle was [created](TritonProfiler::handle). In the profiler's [report](TritonProfiler::report),
+/// they are merged into the task tree: below the task, every thread and device gets its own
+/// subtree, in which spans of the same name are combined. The
+/// [Chrome trace](TritonProfiler::chrome_trace) contains every span individually, with one track
+/// per thread and device.
+#[derive(Debug, Clone)]
+pub struct ProfilerHandle {
+    timer: Instant,
+    parent_index: Option<usize>,
+    spans: Arc<Mutex<Vec<Span>>>,
+}
+
+/// Records a span of a [`ProfilerHandle`] when dropped.
+#[derive(Debug)]
+#[must_use = "the span ends when the guard is dropped"]
+pub struct SpanGuard {
+    handle: ProfilerHandle,
+    name: String,
+    category: Option<String>,
+    start: Duration,
+}
+
+/// A span of time recorded through a [`ProfilerHandle`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Span {
+    name: String,
+    category: Option<String>,
+
+    /// The index of the profiler's task the span belongs to.
+    parent_index: Option<usize>,
+    track: Track,
+
+    /// The time the span started, relative to the creation of the profiler.
+    start: Duration,
+    time: Duration,
+}
+
+/// Where the time of a [`Span`] was spent.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+enum Track {
+    Thread(String),
+    Device(String),
 }
 
 impl TritonProfiler {
@@ -58,25 +210,184 @@ impl TritonProfiler {
             stack: vec![],
             profile: vec![],
             total_time: None,
+            spans: Arc::default(),
+            outer_peaks: vec![],
+            allocation_counters: &GLOBAL_ALLOCATION_COUNTERS,
+        }
+    }
+
+    /// A handle for recording spans on other threads or devices. The spans belong to the task
+    /// that is currently running. See [`ProfilerHandle`] for details.
+    pub fn handle(&self) -> ProfilerHandle {
+        ProfilerHandle {
+            timer: self.timer,
+            parent_index: self.stack.last().map(|&(index, _)| index),
+            spans: Arc::clone(&self.spans),
         }
     }
 
+    fn spans(&self) -> Vec<Span> {
+        let spans = self.spans.lock().unwrap_or_else(PoisonError::into_inner);
+        spans.clone()
+    }
+
     fn ignoring(&self) -> bool {
         self.stack
             .last()
             .is_some_and(|&(idx, _)| self.profile[idx].task_type == TaskType::AnyOtherIteration)
     }
 
-    fn younger_sibling_indices(&self, index: usize) -> Vec<usize> {
-        self.profile
+    fn younger_sibling_indices(profile: &[Task], index: usize) -> Vec<usize> {
+        profile
             .iter()
             .enumerate()
             .filter(|&(idx, _)| idx > index)
-            .filter(|&(_, task)| task.parent_index == self.profile[index].parent_index)
+            .filter(|&(_, task)| task.parent_index == profile[index].parent_index)
             .map(|(idx, _)| idx)
             .collect()
     }
 
+    /// All tasks, including the ones summarizing the spans recorded through
+    /// [handles](ProfilerHandle), in depth-first order.
+    fn merged_profile(&self) -> Vec<Task> {
+        let spans = self.spans();
+        let concurrent_tasks: HashMap<_, _> = spans
+            .iter()
+            .into_group_map_by(|span| span.parent_index)
+            .into_iter()
+            .map(|(parent_index, spans)| (parent_index, Self::summarize_spans(&spans)))
+            .collect();
+
+        let mut merged_profile = vec![];
+        let root_indices = self.child_indices(None);
+        for root_index in root_indices {
+            self.push_subtree(root_index, None, &concurrent_tasks, &mut merged_profile);
+        }
+        if let Some(concurrent_tasks) = concurrent_tasks.get(&None) {
+            Self::push_concurrent_tasks(concurrent_tasks, None, &mut merged_profile);
+        }
+
+        merged_profile
+    }
+
+    fn child_indices(&self, parent_index: Option<usize>) -> Vec<usize> {
+        self.profile
+            .iter()
+            .positions(|task| task.parent_index == parent_index)
+            .collect()
+    }
+
+    fn push_subtree(
+        &self,
+        task_index: usize,
+        parent_index: Option<usize>,
+        concurrent_tasks: &HashMap<Option<usize>, Vec<(Task, Vec<Task>)>>,
+        merged_profile: &mut Vec<Task>,
+    ) {
+        let merged_index = merged_profile.len();
+        let task = Task {
+            parent_index,
+            ..self.profile[task_index].clone()
+        };
+        merged_profile.push(task);
+
+        for child_index in self.child_indices(Some(task_index)) {
+            self.push_subtree(
+                child_index,
+                Some(merged_index),
+                concurrent_tasks,
+                merged_profile,
+            );
+        }
+        if let Some(concurrent_tasks) = concurrent_tasks.get(&Some(task_index)) {
+            Self::push_concurrent_tasks(concurrent_tasks, Some(merged_index), merged_profile);
+        }
+    }
+
+    fn push_concurrent_tasks(
+        concurrent_tasks: &[(Task, Vec<Task>)],
+        parent_index: Option<usize>,
+        merged_profile: &mut Vec<Task>,
+    ) {
+        let depth = parent_index.map_or(1, |index| merged_profile[index].depth + 1);
+        for (track_task, span_tasks) in concurrent_tasks {
+            let track_index = merged_profile.len();
+            merged_profile.push(Task {
+                parent_index,
+                depth,
+                ..track_task.clone()
+            });
+            for span_task in span_tasks {
+                merged_profile.push(Task {
+                    parent_index: Some(track_index),
+                    depth: depth + 1,
+                    ..span_task.clone()
+                });
+            }
+        }
+    }
+
+    /// One task per track, each with one child task per span name. Tracks and span names appear
+    /// in order of their first occurrence. The parent and depth of the tasks are not yet set.
+    fn summarize_spans(spans: &[&Span]) -> Vec<(Task, Vec<Task>)> {
+        let concurrent_task = |name, category, start, time| Task {
+            name,
+            parent_index: None,
+            depth: 0,
+            start,
+            time,
+            task_type: TaskType::Concurrent,
+            category,
+            memory: None,
+        };
+
+        let mut summaries = vec![];
+        let tracks = spans.iter().map(|span| &span.track).unique();
+        for track in tracks {
+            let track_spans = spans
+                .iter()
+                .filter(|span| &span.track == track)
+                .collect_vec();
+            let start = track_spans.iter().map(|span| span.start).min();
+            let time = track_spans.iter().map(|span| span.time).sum();
+            let track_task = concurrent_task(track.to_string(), None, start.unwrap(), time);
+
+            let mut span_tasks = vec![];
+            let names = track_spans.iter().map(|span| &span.name).unique();
+            for name in names {
+                let named_spans = track_spans.iter().filter(|span| &span.name == name);
+                let named_spans = named_spans.collect_vec();
+                let num_spans = named_spans.len();
+                let name = match num_spans {
+                    1 => name.clone(),
+                    _ => format!("{name} ({num_spans}×)"),
+                };
+                let category = named_spans[0].category.clone();
+                let start = named_spans.iter().map(|span| span.start).min().unwrap();
+                let time = named_spans.iter().map(|span| span.time).sum();
+                span_tasks.push(concurrent_task(name, category, start, time));
+            }
+            summaries.push((track_task, span_tasks));
+        }
+
+        summaries
+    }
+
+    /// The time of the closest ancestor of the given concurrent task that is not concurrent
+    /// itself, or `None` if there is no such ancestor. Since concurrent tasks run in parallel to
+    /// that ancestor, their time is relative to it, not to the total time.
+    fn concurrency_reference_time(profile: &[Task], task_index: usize) -> Option<Duration> {
+        let mut ancestor_index = profile[task_index].parent_index;
+        while let Some(index) = ancestor_index {
+            let ancestor = &profile[index];
+            if ancestor.task_type != TaskType::Concurrent {
+                return Some(ancestor.time);
+            }
+            ancestor_index = ancestor.parent_index;
+        }
+        None
+    }
+
     pub fn finish(&mut self) {
         let open_task_positions = self.stack.iter().map(|&(i, _)| i);
         for open_task_position in open_task_positions {
@@ -98,12 +409,17 @@ impl TritonProfiler {
             self.finish();
         }
         let total_time = self.total_time.expect("finishing should set a total time");
+        let profile = self.merged_profile();
 
         let mut report: Vec<TaskReport> = vec![];
 
         // todo: this can count the same category multiple times if it's nested
+        // Concurrent tasks overlap with their parent, possibly many times over, and are ignored.
         let mut category_times = HashMap::new();
-        for task in &self.profile {
+        for task in &profile {
+            if task.task_type == TaskType::Concurrent {
+                continue;
+            }
             if let Some(ref category) = task.category {
                 category_times
                     .entry(category.to_string())
@@ -112,11 +428,16 @@ impl TritonProfiler {
             }
         }
 
-        for (task_index, task) in self.profile.iter().enumerate() {
-            let relative_time = task.time.as_secs_f64() / total_time.as_secs_f64();
+        for (task_index, task) in profile.iter().enumerate() {
+            let reference_time = match task.task_type {
+                TaskType::Concurrent => Self::concurrency_reference_time(&profile, task_index),
+                _ => None,
+            };
+            let reference_time = reference_time.unwrap_or(total_time);
+            let relative_time = task.time.as_secs_f64() / reference_time.as_secs_f64();
             let weight = match task.task_type {
                 TaskType::AnyOtherIteration => Weight::LikeNothing,
-                _ => Weight::weigh(task.time.as_secs_f64() / total_time.as_secs_f64()),
+                _ => Weight::weigh(relative_time),
             };
 
             let mut ancestors = vec![];
@@ -130,8 +451,9 @@ impl TritonProfiler {
             let relative_category_time = task
                 .category
                 .as_ref()
+                .filter(|_| task.task_type != TaskType::Concurrent)
                 .map(|category| task.time.as_secs_f64() / category_times[category].as_secs_f64());
-            let is_last_sibling = self.younger_sibling_indices(task_index).is_empty();
+            let is_last_sibling = Self::younger_sibling_indices(&profile, task_index).is_empty();
 
             report.push(TaskReport {
                 name: task.name.clone(),
@@ -145,20 +467,21 @@ impl TritonProfiler {
                 ancestors,
                 weight,
                 younger_max_weight: Weight::LikeNothing,
+                memory: task.memory,
             });
         }
 
         for task_index in 0..report.len() {
-            report[task_index].younger_max_weight = self
-                .younger_sibling_indices(task_index)
-                .into_iter()
-                .map(|sibling_idx| report[sibling_idx].weight)
-                .max()
-                .unwrap_or(Weight::LikeNothing);
+            report[task_index].younger_max_weight =
+                Self::younger_sibling_indices(&profile, task_index)
+                    .into_iter()
+                    .map(|sibling_idx| report[sibling_idx].weight)
+                    .max()
+                    .unwrap_or(Weight::LikeNothing);
         }
 
         // “Other iterations” are not currently tracked
-        let all_tasks = self.profile.iter();
+        let all_tasks = profile.iter();
         let other_iterations = all_tasks.filter(|t| t.task_type == TaskType::AnyOtherIteration);
         let untracked_time = other_iterations.map(|t| t.time).sum();
 
@@ -174,6 +497,44 @@ impl TritonProfiler {
         }
     }
 
+    /// [Finishes](TritonProfiler::finish) the profiling and exports all tasks as JSON in the
+    /// [Chrome Trace Event Format][format]. The trace can be inspected with tools like
+    /// [Perfetto](https://ui.perfetto.dev) or `chrome://tracing`.
+    ///
+    /// Every task becomes a “complete event” with the task's start time and duration, both in
+    /// microseconds since the creation of the profiler. The task's category, if any, becomes the
+    /// event's category. Tasks are nested like in the [report](Self::report).
+    ///
+    /// Spans recorded through a [`ProfilerHandle`] are not combined. Instead, every thread and
+    /// device gets its own, named track.
+    ///
+    /// [format]: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+    pub fn chrome_trace(&mut self) -> String {
+        if self.total_time.is_none() {
+            self.finish();
+        }
+
+        let process_name = TraceEvent::process_name(&self.name);
+        let task_events = self.profile.iter().map(TraceEvent::from);
+        let mut trace_events = once(process_name).chain(task_events).collect_vec();
+
+        let spans = self.spans();
+        let tracks = spans.iter().map(|span| &span.track).unique().collect_vec();
+        for (track_index, &track) in tracks.iter().enumerate() {
+            let tid = u32::try_from(track_index + 1).expect("number of tracks should be small");
+            trace_events.push(TraceEvent::thread_name(tid, track));
+            let track_spans = spans.iter().filter(|span| &span.track == track);
+            let span_events = track_spans.map(|span| TraceEvent::from(span).on_thread(tid));
+            trace_events.extend(span_events);
+        }
+
+        let trace = ChromeTrace {
+            trace_events,
+            display_time_unit: "ms",
+        };
+        serde_json::to_string(&trace).expect("trace events should be serializable")
+    }
+
     pub fn start(&mut self, name: &str, category: Option<String>) {
         if !self.ignoring() {
             self.plain_start(name, TaskType::Generic, category);
@@ -194,13 +555,27 @@ impl TritonProfiler {
             println!("start: {name}");
         }
 
+        // Reset the peak memory usage to measure the peak of the new task. Restored once the task
+        // stops, see `plain_stop`.
+        let counters = self.allocation_counters;
+        let allocated_bytes = counters.allocated_bytes();
+        let outer_peak = allocated_bytes.map(|bytes| counters.peak.swap(bytes, Ordering::Relaxed));
+        self.outer_peaks.push(outer_peak);
+        let memory = allocated_bytes.map(|bytes| MemoryUsage {
+            start: bytes,
+            end: bytes,
+            peak: bytes,
+        });
+
         self.profile.push(Task {
             name,
             parent_index,
             depth: self.stack.len(),
-            time: self.timer.elapsed(),
+            start: self.timer.elapsed(),
+            time: Duration::ZERO,
             task_type,
             category,
+            memory,
         });
     }
 
@@ -258,9 +633,22 @@ impl TritonProfiler {
             return;
         };
         let now = self.timer.elapsed();
-        let duration = now - self.profile[index].time;
+        let duration = now.saturating_sub(self.profile[index].start);
         self.profile[index].time = duration;
 
+        let counters = self.allocation_counters;
+        let outer_peak = self.outer_peaks.pop().flatten();
+        let allocated_bytes = counters.allocated_bytes();
+        if let (Some(memory), Some(allocated_bytes)) =
+            (&mut self.profile[index].memory, allocated_bytes)
+        {
+            memory.end = allocated_bytes;
+            memory.peak = max(memory.start, counters.peak.load(Ordering::Relaxed));
+        }
+        if let Some(outer_peak) = outer_peak {
+            counters.peak.fetch_max(outer_peak, Ordering::Relaxed);
+        }
+
         if env_var(ENV_VAR_PROFILER_LIVE_UPDATE).is_ok() {
             println!("stop:  {name} – took {duration:.2?}");
         }
@@ -296,6 +684,36 @@ impl TritonProfiler {
     }
 }
 
+impl Eq for TritonProfiler {}
+
+impl PartialEq for TritonProfiler {
+    /// Profilers are equal if their tasks and the spans recorded through their
+    /// [handles](ProfilerHandle) are equal.
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.timer == other.timer
+            && self.stack == other.stack
+            && self.profile == other.profile
+            && self.total_time == other.total_time
+            && self.outer_peaks == other.outer_peaks
+            && std::ptr::eq(self.allocation_counters, other.allocation_counters)
+            && self.spans() == other.spans()
+    }
+}
+
+impl Hash for TritonProfiler {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.timer.hash(state);
+        self.stack.hash(state);
+        self.profile.hash(state);
+        self.total_time.hash(state);
+        self.outer_peaks.hash(state);
+        std::ptr::hash(self.allocation_counters, state);
+        self.spans().hash(state);
+    }
+}
+
 impl Profiler for TritonProfiler {
     fn start_profiling(&mut self, benchmark_id: &str, benchmark_dir: &Path) {
         let dir = benchmark_dir
@@ -315,7 +733,325 @@ impl Profiler for TritonProfiler {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+impl ProfilerHandle {
+    /// Start a span on the current thread. The span ends when the returned guard is dropped.
+    pub fn span(&self, name: impl Into<String>, category: Option<String>) -> SpanGuard {
+        SpanGuard {
+            handle: self.clone(),
+            name: name.into(),
+            category,
+            start: self.timer.elapsed(),
+        }
+    }
+
+    /// Record a span measured on some device, for example, a GPU. The `start` is the moment the
+    /// work on the device began, as observed from the host. The `duration` should be reported by
+    /// the device itself, for example, through the kernel timings of an accelerator's context.
+    /// If the device does not report timings, timing a blocking call on the host is the next best
+    /// thing: the span still shows up on the device's own track.
+    pub fn record_device_span(
+        &self,
+        device: &str,
+        name: impl Into<String>,
+        category: Option<String>,
+        start: Instant,
+        duration: Duration,
+    ) {
+        let start = start.saturating_duration_since(self.timer);
+        let track = Track::Device(device.to_string());
+        self.record(name.into(), category, track, start, duration);
+    }
+
+    fn record(
+        &self,
+        name: String,
+        category: Option<String>,
+        track: Track,
+        start: Duration,
+        time: Duration,
+    ) {
+        let span = Span {
+            name,
+            category,
+            parent_index: self.parent_index,
+            track,
+            start,
+            time,
+        };
+        let mut spans = self.spans.lock().unwrap_or_else(PoisonError::into_inner);
+        spans.push(span);
+    }
+}
+
+impl Drop for SpanGuard {
+    fn drop(&mut self) {
+        let time = self.handle.timer.elapsed().saturating_sub(self.start);
+        let name = std::mem::take(&mut self.name);
+        let category = self.category.take();
+        let track = Track::current_thread();
+        self.handle.record(name, category, track, self.start, time);
+    }
+}
+
+impl Track {
+    fn current_thread() -> Self {
+        let current_thread = thread::current();
+        let name = match current_thread.name() {
+            Some(name) => name.to_string(),
+            None => format!("{:?}", current_thread.id()),
+        };
+        Self::Thread(name)
+    }
+}
+
+impl Display for Track {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Thread(name) => write!(f, "thread {name}"),
+            Self::Device(name) => write!(f, "device {name}"),
+        }
+    }
+}
+
+impl TrackingAllocator {
+    /// Track the allocations of the [system allocator](System).
+    pub const fn system() -> Self {
+        Self::new(System)
+    }
+
+    /// Report the memory usage of tasks to all [`TritonProfiler`]s from now on. Only call this if
+    /// the [`TrackingAllocator`] is the [global allocator](GlobalAlloc); otherwise, the reported
+    /// numbers are meaningless.
+    pub fn enable() {
+        GLOBAL_ALLOCATION_COUNTERS
+            .is_enabled
+            .store(true, Ordering::Relaxed);
+    }
+
+    /// The number of bytes currently allocated, or `None` if the [`TrackingAllocator`] is not
+    /// [enabled](Self::enable).
+    pub fn allocated_bytes() -> Option<usize> {
+        GLOBAL_ALLOCATION_COUNTERS.allocated_bytes()
+    }
+}
+
+impl<A> TrackingAllocator<A> {
+    /// Track the allocations of the given allocator.
+    pub const fn new(inner: A) -> Self {
+        Self::with_counters(inner, &GLOBAL_ALLOCATION_COUNTERS)
+    }
+
+    const fn with_counters(inner: A, counters: &'static AllocationCounters) -> Self {
+        Self { inner, counters }
+    }
+}
+
+impl<A: Default> Default for TrackingAllocator<A> {
+    fn default() -> Self {
+        Self::new(A::default())
+    }
+}
+
+impl AllocationCounters {
+    const fn new() -> Self {
+        Self {
+            allocated: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+            is_enabled: AtomicBool::new(false),
+        }
+    }
+
+    /// The number of bytes currently allocated, or `None` if the counters are not enabled.
+    fn allocated_bytes(&self) -> Option<usize> {
+        let is_enabled = self.is_enabled.load(Ordering::Relaxed);
+        is_enabled.then(|| self.allocated.load(Ordering::Relaxed))
+    }
+
+    fn record_allocation(&self, num_bytes: usize) {
+        let allocated_bytes = self.allocated.fetch_add(num_bytes, Ordering::Relaxed) + num_bytes;
+        self.peak.fetch_max(allocated_bytes, Ordering::Relaxed);
+    }
+
+    fn record_deallocation(&self, num_bytes: usize) {
+        self.allocated.fetch_sub(num_bytes, Ordering::Relaxed);
+    }
+}
+
+// SAFETY: All allocation work is delegated to the inner allocator. Only counters are updated.
+unsafe impl<A: GlobalAlloc> GlobalAlloc for TrackingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc(layout);
+        if !ptr.is_null() {
+            self.counters.record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.inner.dealloc(ptr, layout);
+        self.counters.record_deallocation(layout.size());
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.counters.record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = self.inner.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            self.counters.record_deallocation(layout.size());
+            self.counters.record_allocation(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// The given number of bytes in a human-readable form, using binary units like KiB and MiB.
+pub(crate) fn display_bytes(num_bytes: usize) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut size = num_bytes as f64;
+    let mut unit_index = 0;
+    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit_index += 1;
+    }
+    let unit = UNITS[unit_index];
+    match unit_index {
+        0 => format!("{num_bytes} {unit}"),
+        _ => format!("{size:.2} {unit}"),
+    }
+}
+
+impl MemoryUsage {
+    /// The change in allocated memory over the course of the task, formatted with a sign.
+    fn display_change(self) -> String {
+        if self.end < self.start {
+            format!("-{}", display_bytes(self.start - self.end))
+        } else {
+            format!("+{}", display_bytes(self.end - self.start))
+        }
+    }
+}
+
+/// A trace in the [Chrome Trace Event Format][format], as produced by
+/// [`TritonProfiler::chrome_trace`].
+///
+/// [format]: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChromeTrace {
+    trace_events: Vec<TraceEvent>,
+    display_time_unit: &'static str,
+}
+
+/// A single event of a [`ChromeTrace`]. All events of a [`TritonProfiler`] belong to the same
+/// process. The profiler's tasks all belong to the same thread, which makes trace viewers nest
+/// them according to their timestamps. Every thread or device of a [`ProfilerHandle`] gets a
+/// thread of its own.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct TraceEvent {
+    name: String,
+
+    #[serde(rename = "cat", skip_serializing_if = "Option::is_none")]
+    category: Option<String>,
+
+    #[serde(rename = "ph")]
+    phase: &'static str,
+
+    /// The start of the event in microseconds.
+    #[serde(rename = "ts", skip_serializing_if = "Option::is_none")]
+    timestamp: Option<f64>,
+
+    /// The duration of the event in microseconds.
+    #[serde(rename = "dur", skip_serializing_if = "Option::is_none")]
+    duration: Option<f64>,
+
+    pid: u32,
+    tid: u32,
+
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    args: BTreeMap<&'static str, String>,
+}
+
+impl TraceEvent {
+    const PHASE_COMPLETE: &'static str = "X";
+    const PHASE_METADATA: &'static str = "M";
+
+    /// A metadata event naming the process all other events belong to.
+    fn process_name(name: &str) -> Self {
+        Self {
+            name: "process_name".to_string(),
+            category: None,
+            phase: Self::PHASE_METADATA,
+            timestamp: None,
+            duration: None,
+            pid: 0,
+            tid: 0,
+            args: BTreeMap::from([("name", name.to_string())]),
+        }
+    }
+
+    /// A metadata event naming the thread with the given id.
+    fn thread_name(tid: u32, track: &Track) -> Self {
+        Self {
+            name: "thread_name".to_string(),
+            tid,
+            args: BTreeMap::from([("name", track.to_string())]),
+            ..Self::process_name("")
+        }
+    }
+
+    fn on_thread(self, tid: u32) -> Self {
+        Self { tid, ..self }
+    }
+
+    fn complete(
+        name: String,
+        category: Option<String>,
+        start: Duration,
+        duration: Duration,
+    ) -> Self {
+        let micros = |duration: Duration| duration.as_secs_f64() * 1_000_000.0;
+        Self {
+            name,
+            category,
+            phase: Self::PHASE_COMPLETE,
+            timestamp: Some(micros(start)),
+            duration: Some(micros(duration)),
+            pid: 0,
+            tid: 0,
+            args: BTreeMap::new(),
+        }
+    }
+}
+
+impl From<&Task> for TraceEvent {
+    fn from(task: &Task) -> Self {
+        let name = task.name.clone();
+        let mut event = Self::complete(name, task.category.clone(), task.start, task.time);
+        if let Some(memory) = task.memory {
+            event.args = BTreeMap::from([
+                ("allocated bytes at start", memory.start.to_string()),
+                ("allocated bytes at end", memory.end.to_string()),
+                ("peak allocated bytes", memory.peak.to_string()),
+            ]);
+        }
+        event
+    }
+}
+
+impl From<&Span> for TraceEvent {
+    fn from(span: &Span) -> Self {
+        let name = span.name.clone();
+        Self::complete(name, span.category.clone(), span.start, span.time)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
 enum Weight {
     LikeNothing,
     VeryLittle,
@@ -355,7 +1091,7 @@ impl Weight {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct TaskReport {
     name: String,
     parent_index: Option<usize>,
@@ -368,9 +1104,17 @@ struct TaskReport {
     ancestors: Vec<usize>,
     weight: Weight,
     younger_max_weight: Weight,
+
+    #[serde(default)]
+    memory: Option<MemoryUsage>,
 }
 
-#[derive(Debug)]
+/// The outcome of profiling with a [`TritonProfiler`].
+///
+/// Offers a human-readable [`Display`] implementation. Reports can be
+/// [serialized](serde::Serialize), for example to compare them with the reports of later runs
+/// through a [`ReportComparison`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Report {
     name: String,
     tasks: Vec<TaskReport>,
@@ -398,6 +1142,49 @@ impl Report {
         self
     }
 
+    /// Compare this report, the baseline, with the report of another run, the candidate. See
+    /// [`ReportComparison`] for details.
+    pub fn compare(&self, candidate: &Report) -> ReportComparison {
+        ReportComparison::new(self, candidate)
+    }
+
+    /// The names of the task's ancestors and the task's own name, separated by slashes. For
+    /// example, `"base tables/LDE/LDE-GPU-3"`.
+    fn task_path(&self, task_index: usize) -> String {
+        let task = &self.tasks[task_index];
+        task.ancestors
+            .iter()
+            .map(|&ancestor_index| self.tasks[ancestor_index].name.as_str())
+            .chain(once(task.name.as_str()))
+            .join("/")
+    }
+
+    /// The time spent in each task, indexed by the [task's path](Self::task_path) and in order of
+    /// first occurrence. Tasks with the same path are combined.
+    fn time_per_task_path(&self) -> Vec<(String, Duration)> {
+        let mut times: Vec<(String, Duration)> = vec![];
+        let mut index_of_path: HashMap<String, usize> = HashMap::new();
+        for (task_index, task) in self.tasks.iter().enumerate() {
+            let path = self.task_path(task_index);
+            if let Some(&index) = index_of_path.get(&path) {
+                times[index].1 += task.time;
+                continue;
+            }
+            index_of_path.insert(path.clone(), times.len());
+            times.push((path, task.time));
+        }
+        times
+    }
+
+    /// The time spent in each category, sorted by the category's name.
+    fn time_per_category(&self) -> Vec<(String, Duration)> {
+        self.category_times
+            .iter()
+            .map(|(category, &time)| (category.clone(), time))
+            .sorted()
+            .collect()
+    }
+
     fn display_time_aligned(time: Duration) -> String {
         let unaligned_time = format!("{time:.2?}");
         let time_components: Vec<_> = unaligned_time.split('.').collect();
@@ -407,6 +1194,16 @@ impl Report {
 
         format!("{:>3}.{:<4}", time_components[0], time_components[1])
     }
+
+    /// The maximum memory allocated during any of the tasks, or `None` if memory was not being
+    /// tracked.
+    pub fn peak_memory(&self) -> Option<usize> {
+        self.tasks
+            .iter()
+            .filter_map(|task| task.memory)
+            .map(|memory| memory.peak)
+            .max()
+    }
 }
 
 impl Display for Report {
@@ -440,9 +1237,14 @@ impl Display for Report {
         } else {
             "Category".bold()
         };
+        let tracks_memory = self.peak_memory().is_some();
+        let memory_title = match tracks_memory {
+            true => format!("{:>11} {:>12} ", "Peak", "Change").bold(),
+            false => ColoredString::default(),
+        };
         writeln!(
             f,
-            "{title}   {total_time}   {share_title}  {category_title}"
+            "{title}   {total_time}   {share_title}  {memory_title}{category_title}"
         )?;
 
         for task in &self.tasks {
@@ -482,10 +1284,20 @@ impl Display for Report {
             let category_and_relative_time_colored =
                 category_and_relative_time.color(relative_category_color);
 
+            let memory = match (tracks_memory, task.memory) {
+                (true, Some(memory)) => {
+                    let peak = display_bytes(memory.peak);
+                    let change = memory.display_change();
+                    format!("{peak:>11} {change:>12} ")
+                }
+                (true, None) => format!("{:>25}", ""),
+                (false, _) => String::new(),
+            };
+
             f.write_fmt(format_args!(
                 "{task_name_colored}   \
                  {task_time_colored}{relative_time_string_colored} \
-                 {category_and_relative_time_colored}\n"
+                 {memory}{category_and_relative_time_colored}\n"
             ))?;
         }
 
@@ -516,10 +1328,16 @@ impl Display for Report {
         if self.cycle_count.is_some()
             || self.padded_height.is_some()
             || self.fri_domain_len.is_some()
+            || tracks_memory
         {
             writeln!(f)?;
         }
 
+        if let Some(peak_memory) = self.peak_memory() {
+            let peak_memory = display_bytes(peak_memory);
+            writeln!(f, "Peak memory usage is {peak_memory}")?;
+        }
+
         let total_time = self.total_time.as_millis() as usize;
         if let Some(cycle_count) = self.cycle_count {
             if total_time != 0 {
@@ -553,6 +1371,251 @@ impl Display for Report {
     }
 }
 
+/// A comparison of two profiling [`Report`]s, typically of two runs of the same program using
+/// different implementations. Offers a human-readable [`Display`] implementation.
+///
+/// Tasks are matched by their path, which consists of the names of all their ancestors and their
+/// own name, separated by slashes, _e.g._, `"base tables/LDE/LDE-GPU-3"`. Tasks with the same path
+/// are combined. Categories are matched by name.
+///
+/// A task or category has regressed significantly if it got slower by more than the
+/// [threshold](Self::with_threshold), relative to the baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportComparison {
+    baseline_name: String,
+    candidate_name: String,
+    total: TimeComparison,
+    tasks: Vec<TimeComparison>,
+    categories: Vec<TimeComparison>,
+    threshold: f64,
+}
+
+/// The time spent on the same task or category in two profiling runs.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TimeComparison {
+    /// The path of the task, or the name of the category.
+    pub name: String,
+
+    /// The time spent in the baseline run. `None` if the baseline run has no such task or
+    /// category.
+    pub baseline: Option<Duration>,
+
+    /// The time spent in the candidate run. `None` if the candidate run has no such task or
+    /// category.
+    pub candidate: Option<Duration>,
+}
+
+impl ReportComparison {
+    pub fn new(baseline: &Report, candidate: &Report) -> Self {
+        let total = TimeComparison {
+            name: "total".to_string(),
+            baseline: Some(baseline.total_time),
+            candidate: Some(candidate.total_time),
+        };
+        let tasks = TimeComparison::match_by_name(
+            baseline.time_per_task_path(),
+            candidate.time_per_task_path(),
+        );
+        let categories = TimeComparison::match_by_name(
+            baseline.time_per_category(),
+            candidate.time_per_category(),
+        );
+
+        Self {
+            baseline_name: baseline.name.clone(),
+            candidate_name: candidate.name.clone(),
+            total,
+            tasks,
+            categories,
+            threshold: DEFAULT_REGRESSION_THRESHOLD,
+        }
+    }
+
+    /// Set the relative slowdown above which a task or category is considered to have regressed
+    /// significantly. For example, `0.1` flags everything that got more than 10% slower. Defaults
+    /// to [`DEFAULT_REGRESSION_THRESHOLD`].
+    #[must_use]
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// The comparison of the total profiling time.
+    pub fn total(&self) -> &TimeComparison {
+        &self.total
+    }
+
+    /// The comparisons of all tasks, in order of their first occurrence in the baseline, followed
+    /// by the tasks only the candidate has.
+    pub fn tasks(&self) -> &[TimeComparison] {
+        &self.tasks
+    }
+
+    /// The comparisons of all categories, sorted by name, followed by the categories only the
+    /// candidate has.
+    pub fn categories(&self) -> &[TimeComparison] {
+        &self.categories
+    }
+
+    /// All tasks and categories, as well as the total time, that regressed beyond the
+    /// [threshold](Self::with_threshold).
+    pub fn regressions(&self) -> Vec<&TimeComparison> {
+        once(&self.total)
+            .chain(&self.tasks)
+            .chain(&self.categories)
+            .filter(|comparison| comparison.is_regression(self.threshold))
+            .collect()
+    }
+
+    fn write_table(
+        &self,
+        f: &mut Formatter<'_>,
+        title: &str,
+        comparisons: &[&TimeComparison],
+    ) -> FmtResult {
+        let name_width = comparisons
+            .iter()
+            .map(|comparison| comparison.name.width())
+            .chain(once(title.width()))
+            .max()
+            .unwrap_or_default();
+
+        let title = format!("{title:<name_width$}").bold();
+        let baseline = format!("{:>10}", "Baseline").bold();
+        let candidate = format!("{:>10}", "Candidate").bold();
+        let delta = format!("{:>11}", "Delta").bold();
+        let relative = format!("{:>9}", "Relative").bold();
+        writeln!(
+            f,
+            "{title}   {baseline}   {candidate}   {delta}   {relative}"
+        )?;
+
+        for comparison in comparisons {
+            let name = format!("{:<name_width$}", comparison.name);
+            let baseline = format!("{:>10}", Self::display_time(comparison.baseline));
+            let candidate = format!("{:>10}", Self::display_time(comparison.candidate));
+            let delta = comparison
+                .delta_secs()
+                .map_or_else(|| "–".to_string(), Self::display_delta);
+            let delta = format!("{delta:>11}");
+            let relative = comparison
+                .relative_delta()
+                .map_or_else(|| "–".to_string(), |r| format!("{:+.2}%", 100.0 * r));
+            let relative = format!("{relative:>9}");
+
+            let line = format!("{name}   {baseline}   {candidate}   {delta}   {relative}");
+            let is_improvement = comparison
+                .relative_delta()
+                .is_some_and(|r| r < -self.threshold);
+            if comparison.is_regression(self.threshold) {
+                writeln!(f, "{}", format!("{line}   regression").color(Color::Red))?;
+            } else if is_improvement {
+                writeln!(f, "{}", line.color(Color::Green))?;
+            } else {
+                writeln!(f, "{line}")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn display_time(time: Option<Duration>) -> String {
+        time.map_or_else(|| "–".to_string(), |time| format!("{time:.2?}"))
+    }
+
+    fn display_delta(delta_secs: f64) -> String {
+        let sign = if delta_secs < 0.0 { '-' } else { '+' };
+        let magnitude = Duration::from_secs_f64(delta_secs.abs());
+        format!("{sign}{magnitude:.2?}")
+    }
+}
+
+impl Display for ReportComparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let title = format!("### {} → {}", self.baseline_name, self.candidate_name).bold();
+        writeln!(f, "{title}")?;
+
+        let tasks = once(&self.total).chain(&self.tasks).collect_vec();
+        self.write_table(f, "Task", &tasks)?;
+
+        if !self.categories.is_empty() {
+            writeln!(f)?;
+            let categories = self.categories.iter().collect_vec();
+            self.write_table(f, "Category", &categories)?;
+        }
+
+        writeln!(f)?;
+        let num_regressions = self.regressions().len();
+        let threshold = 100.0 * self.threshold;
+        write!(
+            f,
+            "{num_regressions} significant regression(s) with a threshold of {threshold:.2}%"
+        )
+    }
+}
+
+impl TimeComparison {
+    /// Match the times of two runs by name. Times of the baseline come first, in order, followed
+    /// by the times only present in the candidate, in order.
+    fn match_by_name(
+        baseline: Vec<(String, Duration)>,
+        candidate: Vec<(String, Duration)>,
+    ) -> Vec<Self> {
+        let mut candidate_times: HashMap<_, _> = candidate.iter().cloned().collect();
+        let mut comparisons = baseline
+            .into_iter()
+            .map(|(name, time)| {
+                let candidate = candidate_times.remove(&name);
+                Self {
+                    name,
+                    baseline: Some(time),
+                    candidate,
+                }
+            })
+            .collect_vec();
+
+        let candidate_only = candidate
+            .into_iter()
+            .filter(|(name, _)| candidate_times.contains_key(name))
+            .map(|(name, time)| Self {
+                name,
+                baseline: None,
+                candidate: Some(time),
+            });
+        comparisons.extend(candidate_only);
+        comparisons
+    }
+
+    /// The time the candidate run took longer than the baseline run, in seconds. Negative if the
+    /// candidate run was faster. `None` if either run is missing.
+    pub fn delta_secs(&self) -> Option<f64> {
+        let baseline = self.baseline?.as_secs_f64();
+        let candidate = self.candidate?.as_secs_f64();
+        Some(candidate - baseline)
+    }
+
+    /// The [delta](Self::delta_secs) relative to the baseline. For example, `0.5` if the candidate
+    /// run took 50% longer than the baseline run. `None` if either run is missing or the
+    /// baseline took no time at all.
+    pub fn relative_delta(&self) -> Option<f64> {
+        let baseline = self.baseline?.as_secs_f64();
+        if baseline == 0.0 {
+            return None;
+        }
+        Some(self.delta_secs()? / baseline)
+    }
+
+    /// Whether the candidate run took longer than the baseline run by more than the given
+    /// relative threshold.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.relative_delta().is_some_and(|delta| delta > threshold)
+    }
+}
+
 /// Start a profiling task.
 /// Requires an `Option<Profiler>` as first argument. Does nothing if this is `None`.
 /// The second argument is the name of the task.
@@ -763,6 +1826,151 @@ mod tests {
         println!("{report}");
     }
 
+    #[test]
+    fn chrome_trace_contains_all_tasks_with_categories() {
+        let mut profiler = TritonProfiler::new("Chrome Trace Test");
+        profiler.start("parent", Some("hash".to_string()));
+        profiler.start("child", None);
+        sleep(Duration::from_millis(1));
+        profiler.stop("child");
+        profiler.stop("parent");
+
+        let trace = profiler.chrome_trace();
+        let trace: serde_json::Value = serde_json::from_str(&trace).unwrap();
+        let events = trace["traceEvents"].as_array().unwrap();
+        assert_eq!(3, events.len());
+
+        let process_name = &events[0];
+        assert_eq!("M", process_name["ph"]);
+        assert_eq!("Chrome Trace Test", process_name["args"]["name"]);
+
+        let [parent, child] = [&events[1], &events[2]];
+        assert_eq!("parent", parent["name"]);
+        assert_eq!("hash", parent["cat"]);
+        assert_eq!("child", child["name"]);
+        assert!(child.get("cat").is_none());
+        for event in [parent, child] {
+            assert_eq!("X", event["ph"]);
+        }
+
+        let start = |event: &serde_json::Value| event["ts"].as_f64().unwrap();
+        let end = |event: &serde_json::Value| start(event) + event["dur"].as_f64().unwrap();
+        assert!(start(parent) <= start(child));
+        assert!(end(child) <= end(parent));
+        assert!(1_000.0 <= end(child) - start(child));
+    }
+
+    #[test]
+    fn chrome_trace_includes_unfinished_tasks() {
+        let mut profiler = TritonProfiler::new("Unfinished Chrome Trace Test");
+        profiler.start("unfinished task", None);
+
+        let trace = profiler.chrome_trace();
+        let trace: serde_json::Value = serde_json::from_str(&trace).unwrap();
+        let events = trace["traceEvents"].as_array().unwrap();
+        assert_eq!(2, events.len());
+        assert_eq!("unfinished task (unfinished)", events[1]["name"]);
+    }
+
+    /// A report of a task “outer” with category “hash”, containing the given tasks. The times of
+    /// the tasks are the given number of milliseconds, independent of the time profiling took.
+    fn report_with_tasks(name: &str, tasks: &[(&str, u64)]) -> Report {
+        let mut profiler = TritonProfiler::new(name);
+        profiler.start("outer", Some("hash".to_string()));
+        for &(task, _) in tasks {
+            profiler.start(task, None);
+            profiler.stop(task);
+        }
+        profiler.stop("outer");
+        let mut report = profiler.report();
+
+        let task_times = tasks
+            .iter()
+            .map(|&(_, millis)| Duration::from_millis(millis));
+        let outer_time: Duration = task_times.clone().sum();
+        report.tasks[0].time = outer_time;
+        for (task, time) in report.tasks[1..].iter_mut().zip(task_times) {
+            task.time = time;
+        }
+        report.total_time = outer_time;
+        report.category_times = HashMap::from([("hash".to_string(), outer_time)]);
+        report
+    }
+
+    #[test]
+    fn report_survives_serialization_round_trip() {
+        let report = report_with_tasks("Serialization Test", &[("task", 1)])
+            .with_cycle_count(10)
+            .with_padded_height(16);
+        let serialized = serde_json::to_string(&report).unwrap();
+        let deserialized: Report = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(report.to_string(), deserialized.to_string());
+    }
+
+    #[test]
+    fn report_compared_with_itself_has_no_regressions() {
+        let report = report_with_tasks("Self Comparison Test", &[("a", 1), ("b", 1)]);
+        let comparison = report.compare(&report);
+        println!("{comparison}");
+
+        assert!(comparison.regressions().is_empty());
+        for task in comparison.tasks() {
+            assert_eq!(Some(0.0), task.delta_secs());
+        }
+        let paths = comparison
+            .tasks()
+            .iter()
+            .map(|t| t.name.as_str())
+            .collect_vec();
+        assert_eq!(vec!["outer", "outer/a", "outer/b"], paths);
+        assert_eq!(1, comparison.categories().len());
+    }
+
+    #[test]
+    fn comparison_matches_tasks_by_path_and_flags_regressions() {
+        let baseline = report_with_tasks("Baseline", &[("fast", 1), ("slow", 1), ("gone", 1)]);
+        let candidate = report_with_tasks("Candidate", &[("fast", 1), ("slow", 30), ("new", 1)]);
+        let comparison = baseline.compare(&candidate).with_threshold(1.0);
+        println!("{comparison}");
+
+        let task = |path: &str| {
+            let mut tasks = comparison.tasks().iter();
+            tasks.find(|task| task.name == path).unwrap()
+        };
+        assert!(task("outer/slow").is_regression(comparison.threshold()));
+        assert!(task("outer/gone").candidate.is_none());
+        assert!(task("outer/new").baseline.is_none());
+        assert_eq!("outer/new", comparison.tasks().last().unwrap().name);
+
+        let regressions = comparison.regressions();
+        let regressed_names = regressions.iter().map(|r| r.name.as_str()).collect_vec();
+        assert!(regressed_names.contains(&"outer/slow"));
+        assert!(!regressed_names.contains(&"outer/fast"));
+    }
+
+    #[test]
+    fn regression_threshold_is_configurable() {
+        let baseline = report_with_tasks("Baseline", &[("task", 1)]);
+        let candidate = report_with_tasks("Candidate", &[("task", 20)]);
+        let comparison = baseline.compare(&candidate);
+        assert!(!comparison.regressions().is_empty());
+
+        let comparison = comparison.with_threshold(f64::INFINITY);
+        assert!(comparison.regressions().is_empty());
+    }
+
+    #[test]
+    fn tasks_with_identical_paths_are_combined() {
+        let report = report_with_tasks("Repeated Task Test", &[("task", 1), ("task", 1)]);
+        let comparison = report.compare(&report);
+        let paths = comparison
+            .tasks()
+            .iter()
+            .map(|t| t.name.as_str())
+            .collect_vec();
+        assert_eq!(vec!["outer", "outer/task"], paths);
+    }
+
     #[test]
     fn profiler_can_generate_multiple_reports() {
         let mut profiler = TritonProfiler::new("Multiple Reports Test");
@@ -774,4 +1982,203 @@ mod tests {
         let report = profiler.report();
         println!("{report}");
     }
+
+    fn task_names(report: &Report) -> Vec<&str> {
+        report.tasks.iter().map(|t| t.name.as_str()).collect()
+    }
+
+    #[test]
+    fn spans_from_worker_threads_are_merged_into_report() {
+        let mut profiler = TritonProfiler::new("Worker Threads Test");
+        profiler.start("parent", Some("hash".to_string()));
+        let handle = profiler.handle();
+        for worker in 0..2 {
+            let handle = handle.clone();
+            let worker = thread::Builder::new()
+                .name(format!("worker {worker}"))
+                .spawn(move || {
+                    for _ in 0..2 {
+                        let _span = handle.span("work", Some("hash".to_string()));
+                        sleep(Duration::from_millis(1));
+                    }
+                })
+                .unwrap();
+            worker.join().unwrap();
+        }
+        profiler.stop("parent");
+
+        let report = profiler.report();
+        println!("{report}");
+        let expected_names = [
+            "parent",
+            "thread worker 0",
+            "work (2×)",
+            "thread worker 1",
+            "work (2×)",
+        ];
+        assert_eq!(expected_names.to_vec(), task_names(&report));
+
+        let [_, thread_0, work_0, thread_1, _] = &report.tasks[..] else {
+            panic!("report should contain exactly 5 tasks");
+        };
+        assert_eq!(Some(0), thread_0.parent_index);
+        assert_eq!(Some(1), work_0.parent_index);
+        assert_eq!(Some(0), thread_1.parent_index);
+        assert_eq!(3, work_0.depth);
+        assert!(Duration::from_millis(2) <= work_0.time);
+
+        // concurrent time is relative to the parent and does not inflate the category
+        let parent = &report.tasks[0];
+        assert_eq!(parent.time, report.category_times["hash"]);
+        let relative_to_parent = work_0.time.as_secs_f64() / parent.time.as_secs_f64();
+        assert_eq!(relative_to_parent, work_0.relative_time);
+        assert_eq!(None, work_0.relative_category_time);
+    }
+
+    #[test]
+    fn profilers_with_different_spans_are_different() {
+        let mut profiler = TritonProfiler::new("Equality Test");
+        profiler.start("task", None);
+        let clone = profiler.clone();
+        let independent_profiler = TritonProfiler {
+            spans: Arc::default(),
+            ..profiler.clone()
+        };
+        assert_eq!(profiler, independent_profiler);
+
+        drop(profiler.handle().span("span", None));
+        assert_eq!(profiler, clone);
+        assert_ne!(profiler, independent_profiler);
+    }
+
+    #[test]
+    fn device_spans_appear_below_their_task() {
+        let mut profiler = TritonProfiler::new("Device Span Test");
+        profiler.start("outer", None);
+        profiler.start("lde", None);
+        let duration = Duration::from_millis(5);
+        let category = Some("LDE".to_string());
+        profiler
+            .handle()
+            .record_device_span("GPU", "kernel", category, Instant::now(), duration);
+        profiler.stop("lde");
+        profiler.start("other", None);
+        profiler.stop("other");
+        profiler.stop("outer");
+
+        let report = profiler.report();
+        println!("{report}");
+        let expected_names = ["outer", "lde", "device GPU", "kernel", "other"];
+        assert_eq!(expected_names.to_vec(), task_names(&report));
+        assert_eq!(duration, report.tasks[3].time);
+        assert_eq!(Some(0), report.tasks[4].parent_index);
+    }
+
+    #[test]
+    fn spans_recorded_without_running_task_appear_at_top_level() {
+        let mut profiler = TritonProfiler::new("Top Level Span Test");
+        let handle = profiler.handle();
+        profiler.start("task", None);
+        profiler.stop("task");
+        drop(handle.span("span", None));
+
+        let report = profiler.report();
+        println!("{report}");
+        let thread_name = Track::current_thread().to_string();
+        let expected_names = ["task", thread_name.as_str(), "span"];
+        assert_eq!(expected_names.to_vec(), task_names(&report));
+        assert_eq!(None, report.tasks[1].parent_index);
+    }
+
+    #[test]
+    fn tasks_record_memory_allocated_through_tracking_allocator() {
+        // Own counters keep the global ones, and thus all other tests, unaffected.
+        static COUNTERS: AllocationCounters = AllocationCounters::new();
+        COUNTERS.is_enabled.store(true, Ordering::Relaxed);
+        let allocator = TrackingAllocator::with_counters(System, &COUNTERS);
+        let layout = Layout::from_size_align(1 << 20, 8).unwrap();
+
+        let mut profiler = TritonProfiler {
+            allocation_counters: &COUNTERS,
+            ..TritonProfiler::new("Memory Test")
+        };
+        profiler.start("outer", None);
+        profiler.start("allocate", None);
+        let ptr = unsafe { allocator.alloc(layout) };
+        assert!(!ptr.is_null());
+        profiler.stop("allocate");
+        profiler.start("deallocate", None);
+        unsafe { allocator.dealloc(ptr, layout) };
+        profiler.stop("deallocate");
+        profiler.stop("outer");
+
+        let report = profiler.report();
+        println!("{report}");
+        let [outer, allocate, deallocate] = &report.tasks[..] else {
+            panic!("report should contain exactly 3 tasks");
+        };
+        assert!(outer.memory.is_some());
+        let [allocate, deallocate] = [allocate, deallocate].map(|task| task.memory.unwrap());
+        assert_eq!(1 << 20, allocate.end - allocate.start);
+        assert_eq!(1 << 20, deallocate.start - deallocate.end);
+        assert!(allocate.end <= allocate.peak);
+        assert!(Some(allocate.peak) <= report.peak_memory());
+        assert!(TrackingAllocator::allocated_bytes().is_none());
+
+        let trace = profiler.chrome_trace();
+        let trace: serde_json::Value = serde_json::from_str(&trace).unwrap();
+        let allocate_event = &trace["traceEvents"][2];
+        assert_eq!("allocate", allocate_event["name"]);
+        let peak = allocate.peak.to_string();
+        assert_eq!(peak, allocate_event["args"]["peak allocated bytes"]);
+    }
+
+    #[test]
+    fn bytes_are_displayed_in_binary_units() {
+        assert_eq!("0 B", display_bytes(0));
+        assert_eq!("1023 B", display_bytes(1023));
+        assert_eq!("1.00 KiB", display_bytes(1024));
+        assert_eq!("1.50 MiB", display_bytes(3 << 19));
+        assert_eq!("2.00 GiB", display_bytes(2 << 30));
+
+        let shrinking = MemoryUsage {
+            start: 2048,
+            end: 1024,
+            peak: 2048,
+        };
+        assert_eq!("-1.00 KiB", shrinking.display_change());
+    }
+
+    #[test]
+    fn chrome_trace_puts_every_thread_and_device_on_its_own_track() {
+        let mut profiler = TritonProfiler::new("Chrome Trace Tracks Test");
+        profiler.start("task", None);
+        let handle = profiler.handle();
+        drop(handle.span("span", None));
+        handle.record_device_span("GPU", "kernel", None, Instant::now(), Duration::ZERO);
+        profiler.stop("task");
+
+        let trace = profiler.chrome_trace();
+        let trace: serde_json::Value = serde_json::from_str(&trace).unwrap();
+        let events = trace["traceEvents"].as_array().unwrap();
+        let names_and_tids = events
+            .iter()
+            .map(|event| {
+                (
+                    event["name"].as_str().unwrap(),
+                    event["tid"].as_u64().unwrap(),
+                )
+            })
+            .collect_vec();
+        let expected = vec![
+            ("process_name", 0),
+            ("task", 0),
+            ("thread_name", 1),
+            ("span", 1),
+            ("thread_name", 2),
+            ("kernel", 2),
+        ];
+        assert_eq!(expected, names_and_tids);
+        assert_eq!("device GPU", events[4]["args"]["name"]);
+    }
 }
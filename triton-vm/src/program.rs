@@ -1,4 +1,6 @@
+use std::cmp::Reverse;
 use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fmt::Display;
@@ -6,6 +8,7 @@ use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
 use std::hash::Hash;
 use std::io::Cursor;
+use std::iter::once;
 use std::ops::Add;
 use std::ops::AddAssign;
 use std::ops::Sub;
@@ -15,6 +18,8 @@ use get_size::GetSize;
 use itertools::Itertools;
 use serde_derive::Deserialize;
 use serde_derive::Serialize;
+use strum::EnumIter;
+use strum::IntoEnumIterator;
 use twenty_first::prelude::*;
 
 use crate::aet::AlgebraicExecutionTrace;
@@ -24,9 +29,16 @@ use crate::instruction::AnInstruction;
 use crate::instruction::Instruction;
 use crate::instruction::LabelledInstruction;
 use crate::instruction::TypeHint;
+use crate::observer::VMObserver;
 use crate::parser::parse;
 use crate::parser::to_labelled_instructions;
 use crate::parser::ParseError;
+use crate::parser::SourceLocation;
+use crate::parser::SourceLocator;
+use crate::public_io::PublicInputSource;
+use crate::public_io::PublicIoTranscript;
+use crate::public_io::PublicOutputSink;
+use crate::public_io::StreamingIo;
 use crate::table::hash_table::PERMUTATION_TRACE_LENGTH;
 use crate::table::u32_table::U32TableEntry;
 use crate::vm::CoProcessorCall;
@@ -43,20 +55,27 @@ type Result<T> = std::result::Result<T, VMError>;
 /// [`Hashing`](Program::hash) a program under [`Tip5`] yields a [`Digest`] that can be used
 /// in a [`Claim`](crate::Claim), _i.e._, is consistent with Triton VM's [program attestation].
 ///
-/// A program may contain debug information, such as label names and breakpoints.
-/// Access this information through methods [`label_for_address()`][label_for_address] and
-/// [`is_breakpoint()`][is_breakpoint]. Some operations, most notably
-/// [BField-encoding](BFieldCodec::encode), discard this debug information.
+/// A program may contain debug information, such as label names, breakpoints, and the
+/// [location](SourceLocation) in the source code of each instruction. Access this information
+/// through methods like [`label_for_address()`][label_for_address],
+/// [`is_breakpoint()`][is_breakpoint], and [`source_location()`][source_location]. Some
+/// operations, most notably [BField-encoding](BFieldCodec::encode), discard this debug
+/// information.
+///
+/// A program may also declare [static data](crate::instruction::StaticData), which becomes part
+/// of the program's instructions.
 ///
 /// [program attestation]: https://triton-vm.org/spec/program-attestation.html
 /// [label_for_address]: Program::label_for_address
 /// [is_breakpoint]: Program::is_breakpoint
+/// [source_location]: Program::source_location
 #[derive(Debug, Clone, Eq, Serialize, Deserialize, GetSize)]
 pub struct Program {
     pub instructions: Vec<Instruction>,
     address_to_label: HashMap<u64, String>,
     breakpoints: Vec<bool>,
     type_hints: HashMap<u64, Vec<TypeHint>>,
+    source_locations: HashMap<u64, SourceLocation>,
 }
 
 impl Display for Program {
@@ -124,6 +143,7 @@ impl BFieldCodec for Program {
             address_to_label: HashMap::default(),
             breakpoints: vec![],
             type_hints: HashMap::default(),
+            source_locations: HashMap::default(),
         }))
     }
 
@@ -216,6 +236,7 @@ impl IntoIterator for Program {
 
 impl Program {
     pub fn new(labelled_instructions: &[LabelledInstruction]) -> Self {
+        let labelled_instructions = &Self::with_static_data_prologue(labelled_instructions);
         let label_to_address = Self::build_label_to_address_map(labelled_instructions);
         let instructions =
             Self::turn_labels_into_addresses(labelled_instructions, &label_to_address);
@@ -228,9 +249,74 @@ impl Program {
             address_to_label,
             breakpoints,
             type_hints,
+            source_locations: HashMap::default(),
         }
     }
 
+    /// Like [`new`](Self::new), but additionally records the location in the source code of
+    /// every instruction. Instructions stemming from [static data](LabelledInstruction::Data)
+    /// are attributed to the location of the static data's declaration.
+    pub fn new_with_source_locations(
+        labelled_instructions: &[(LabelledInstruction, SourceLocation)],
+    ) -> Self {
+        let instructions = labelled_instructions
+            .iter()
+            .map(|(instruction, _)| instruction.clone())
+            .collect_vec();
+        let mut program = Self::new(&instructions);
+        program.source_locations = Self::locate_instructions(labelled_instructions);
+        program
+    }
+
+    /// The location of every instruction's address, taking the
+    /// [static data prologue](Self::with_static_data_prologue) into account.
+    fn locate_instructions(
+        labelled_instructions: &[(LabelledInstruction, SourceLocation)],
+    ) -> HashMap<u64, SourceLocation> {
+        let mut prologue = vec![];
+        let mut program = vec![];
+        for (labelled_instruction, location) in labelled_instructions {
+            match labelled_instruction {
+                LabelledInstruction::Data(static_data) => {
+                    let instructions = static_data.instructions().into_iter();
+                    prologue.extend(instructions.map(|instruction| (instruction, location)));
+                }
+                _ => program.push((labelled_instruction.clone(), location)),
+            }
+        }
+
+        let mut source_locations = HashMap::new();
+        let mut address = 0;
+        for (instruction, location) in [prologue, program].concat() {
+            let LabelledInstruction::Instruction(instruction) = instruction else {
+                continue;
+            };
+            source_locations.insert(address, location.clone());
+            address += instruction.size() as u64;
+        }
+
+        source_locations
+    }
+
+    /// Replace all [static data](LabelledInstruction::Data) with a prologue of instructions that
+    /// write the data to RAM, and that runs before any other instruction.
+    fn with_static_data_prologue(
+        labelled_instructions: &[LabelledInstruction],
+    ) -> Vec<LabelledInstruction> {
+        let mut prologue = vec![];
+        let mut program = vec![];
+        for labelled_instruction in labelled_instructions {
+            match labelled_instruction {
+                LabelledInstruction::Data(static_data) => {
+                    prologue.extend(static_data.instructions());
+                }
+                _ => program.push(labelled_instruction.clone()),
+            }
+        }
+
+        [prologue, program].concat()
+    }
+
     fn build_label_to_address_map(program: &[LabelledInstruction]) -> HashMap<String, u64> {
         let mut label_map = HashMap::new();
         let mut instruction_pointer = 0;
@@ -301,7 +387,7 @@ impl Program {
                     break_before_next_instruction = false;
                     address += instruction.size() as u64;
                 }
-                LabelledInstruction::Label(_) => (),
+                LabelledInstruction::Label(_) | LabelledInstruction::Data(_) => (),
                 LabelledInstruction::Breakpoint => break_before_next_instruction = true,
                 LabelledInstruction::TypeHint(type_hint) => match type_hints.entry(address) {
                     Entry::Occupied(mut entry) => entry.get_mut().push(type_hint.clone()),
@@ -313,11 +399,20 @@ impl Program {
         (breakpoints, type_hints)
     }
 
-    /// Create a `Program` by parsing source code.
+    /// Create a `Program` by parsing source code. The resulting program records the
+    /// [location](Self::source_location) of each instruction in the source code, with an empty
+    /// file name.
     pub fn from_code(code: &str) -> std::result::Result<Self, ParseError> {
-        parse(code)
-            .map(|tokens| to_labelled_instructions(&tokens))
-            .map(|instructions| Program::new(&instructions))
+        let tokens = parse(code)?;
+        let labelled_instructions = to_labelled_instructions(&tokens);
+        let locator = SourceLocator::new("", code);
+        let source_locations = tokens.iter().map(|token| locator.locate(token.token_str()));
+        let labelled_instructions = labelled_instructions
+            .into_iter()
+            .zip(source_locations)
+            .collect_vec();
+
+        Ok(Program::new_with_source_locations(&labelled_instructions))
     }
 
     pub fn labelled_instructions(&self) -> Vec<LabelledInstruction> {
@@ -381,6 +476,24 @@ impl Program {
         self.type_hints.get(&address).cloned().unwrap_or_default()
     }
 
+    /// The location in the source code of the instruction at the given address. `None` if the
+    /// program has no such debug information, or if the address does not refer to the start of
+    /// an instruction.
+    pub fn source_location(&self, address: u64) -> Option<&SourceLocation> {
+        self.source_locations.get(&address)
+    }
+
+    /// The addresses of all instructions originating from the given line of the given file, in
+    /// ascending order. Helpful for setting breakpoints by line.
+    pub fn addresses_at_line(&self, file: &str, line: usize) -> Vec<u64> {
+        self.source_locations
+            .iter()
+            .filter(|(_, location)| location.file == file && location.line == line)
+            .map(|(&address, _)| address)
+            .sorted()
+            .collect()
+    }
+
     /// Turn the program into a sequence of `BFieldElement`s. Each instruction is encoded as its
     /// opcode, followed by its argument (if any).
     ///
@@ -428,9 +541,20 @@ impl Program {
         &self,
         public_input: PublicInput,
         non_determinism: NonDeterminism,
+    ) -> Result<Vec<BFieldElement>> {
+        self.run_with_observer(public_input, non_determinism, &mut ())
+    }
+
+    /// [Run](Self::run) Triton VM on the [`Program`], notifying the given [`VMObserver`] about
+    /// every step.
+    pub fn run_with_observer<O: VMObserver>(
+        &self,
+        public_input: PublicInput,
+        non_determinism: NonDeterminism,
+        observer: &mut O,
     ) -> Result<Vec<BFieldElement>> {
         let mut state = VMState::new(self, public_input, non_determinism);
-        if let Err(err) = state.run() {
+        if let Err(err) = state.run_with_observer(observer) {
             return Err(VMError::new(err, state));
         }
         Ok(state.public_output)
@@ -450,9 +574,20 @@ impl Program {
         &self,
         public_input: PublicInput,
         non_determinism: NonDeterminism,
+    ) -> Result<(AlgebraicExecutionTrace, Vec<BFieldElement>)> {
+        self.trace_execution_with_observer(public_input, non_determinism, &mut ())
+    }
+
+    /// [Trace the execution](Self::trace_execution) of a [`Program`], notifying the given
+    /// [`VMObserver`] about every step.
+    pub fn trace_execution_with_observer<O: VMObserver>(
+        &self,
+        public_input: PublicInput,
+        non_determinism: NonDeterminism,
+        observer: &mut O,
     ) -> Result<(AlgebraicExecutionTrace, Vec<BFieldElement>)> {
         let state = VMState::new(self, public_input, non_determinism);
-        let (aet, terminal_state) = self.trace_execution_of_state(state)?;
+        let (aet, terminal_state) = self.trace_execution_of_state_with_observer(state, observer)?;
         Ok((aet, terminal_state.public_output))
     }
 
@@ -460,9 +595,24 @@ impl Program {
     /// [`trace_execution`][Self::trace_execution], unless you know this is what you want.
     ///
     /// Returns the [`AlgebraicExecutionTrace`] and the terminal [`VMState`] if execution succeeds.
+    ///
+    /// Execution can be resumed from a state [restored](VMState::restore) from a
+    /// [snapshot](crate::vm::VMSnapshot). The traces of the resulting [`AlgebraicExecutionTrace`]
+    /// are then identical to the corresponding suffixes of the traces of an uninterrupted
+    /// execution.
     pub fn trace_execution_of_state(
+        &self,
+        state: VMState,
+    ) -> Result<(AlgebraicExecutionTrace, VMState)> {
+        self.trace_execution_of_state_with_observer(state, &mut ())
+    }
+
+    /// [Trace the execution](Self::trace_execution_of_state) of a [`Program`] from a given
+    /// [`VMState`], notifying the given [`VMObserver`] about every step.
+    pub fn trace_execution_of_state_with_observer<O: VMObserver>(
         &self,
         mut state: VMState,
+        observer: &mut O,
     ) -> Result<(AlgebraicExecutionTrace, VMState)> {
         let mut aet = AlgebraicExecutionTrace::new(self.clone());
         assert_eq!(self.instructions, state.program);
@@ -470,9 +620,10 @@ impl Program {
 
         while !state.halting {
             if let Err(err) = aet.record_state(&state) {
+                observer.error(&state, err);
                 return Err(VMError::new(err, state));
             };
-            let co_processor_calls = match state.step() {
+            let co_processor_calls = match state.step_with_observer(observer) {
                 Ok(calls) => calls,
                 Err(err) => return Err(VMError::new(err, state)),
             };
@@ -484,6 +635,84 @@ impl Program {
         Ok((aet, state))
     }
 
+    /// Run Triton VM on the [`Program`], reading public input from the given `source` and writing
+    /// public output to the given `sink` while running. If an error is encountered, the returned
+    /// [`VMError`] contains the [`VMState`] at the point of execution failure.
+    ///
+    /// Returns the [transcript](PublicIoTranscript) of all public input and output, which is needed
+    /// to construct a [`Claim`](crate::Claim).
+    ///
+    /// See also [`run`][run] and [`trace_execution_streaming`][trace_execution_streaming].
+    ///
+    /// [run]: Self::run
+    /// [trace_execution_streaming]: Self::trace_execution_streaming
+    pub fn run_streaming<I, O>(
+        &self,
+        source: I,
+        non_determinism: NonDeterminism,
+        sink: O,
+    ) -> Result<PublicIoTranscript>
+    where
+        I: PublicInputSource,
+        O: PublicOutputSink,
+    {
+        let mut state = VMState::new(self, PublicInput::default(), non_determinism);
+        let mut io = StreamingIo::new(source, sink);
+        while !state.halting {
+            io.prefetch_public_input(&mut state);
+            if let Err(err) = state.step() {
+                return Err(VMError::new(err, state));
+            }
+            if let Err(err) = io.flush_public_output(&state) {
+                return Err(VMError::new(err, state));
+            }
+        }
+
+        Ok(io.into_transcript(&state))
+    }
+
+    /// Trace the execution of a [`Program`] like [`trace_execution`][trace_execution] does, but
+    /// read public input from the given `source` and write public output to the given `sink`
+    /// while running.
+    ///
+    /// Returns the [`AlgebraicExecutionTrace`] and the [transcript](PublicIoTranscript) of all
+    /// public input and output if execution succeeds.
+    ///
+    /// [trace_execution]: Self::trace_execution
+    pub fn trace_execution_streaming<I, O>(
+        &self,
+        source: I,
+        non_determinism: NonDeterminism,
+        sink: O,
+    ) -> Result<(AlgebraicExecutionTrace, PublicIoTranscript)>
+    where
+        I: PublicInputSource,
+        O: PublicOutputSink,
+    {
+        let mut state = VMState::new(self, PublicInput::default(), non_determinism);
+        let mut io = StreamingIo::new(source, sink);
+        let mut aet = AlgebraicExecutionTrace::new(self.clone());
+
+        while !state.halting {
+            io.prefetch_public_input(&mut state);
+            if let Err(err) = aet.record_state(&state) {
+                return Err(VMError::new(err, state));
+            };
+            let co_processor_calls = match state.step() {
+                Ok(calls) => calls,
+                Err(err) => return Err(VMError::new(err, state)),
+            };
+            for call in co_processor_calls {
+                aet.record_co_processor_call(call);
+            }
+            if let Err(err) = io.flush_public_output(&state) {
+                return Err(VMError::new(err, state));
+            }
+        }
+
+        Ok((aet, io.into_transcript(&state)))
+    }
+
     /// Run Triton VM with the given public and secret input, but record the number of cycles spent
     /// in each callable block of instructions. This function returns a Result wrapping a program
     /// profiler report, which is a Vec of [`ProfileLine`]s.
@@ -497,20 +726,21 @@ impl Program {
         public_input: PublicInput,
         non_determinism: NonDeterminism,
     ) -> Result<(Vec<BFieldElement>, VMProfilingReport)> {
-        let mut profiler = VMProfiler::new(self.instructions.len());
+        self.profile_with_observer(public_input, non_determinism, &mut ())
+    }
+
+    /// [Profile](Self::profile) the [`Program`], notifying the given [`VMObserver`] about every
+    /// step.
+    pub fn profile_with_observer<O: VMObserver>(
+        &self,
+        public_input: PublicInput,
+        non_determinism: NonDeterminism,
+        observer: &mut O,
+    ) -> Result<(Vec<BFieldElement>, VMProfilingReport)> {
+        let mut profiler = VMProfiler::new(self);
         let mut state = VMState::new(self, public_input, non_determinism);
-        while !state.halting {
-            if let Ok(Instruction::Call(address)) = state.current_instruction() {
-                let label = self.label_for_address(address.value());
-                profiler.enter_span(label);
-            }
-            if let Ok(Instruction::Return) = state.current_instruction() {
-                profiler.exit_span();
-            }
-            match state.step() {
-                Ok(calls) => profiler.handle_co_processor_calls(calls),
-                Err(err) => return Err(VMError::new(err, state)),
-            };
+        if let Err(err) = state.run_with_observer(&mut (&mut profiler, observer)) {
+            return Err(VMError::new(err, state));
         }
 
         Ok((state.public_output, profiler.report()))
@@ -525,14 +755,24 @@ impl Program {
             .cloned()
             .unwrap_or_else(|| format!("address_{address}"))
     }
+
+    /// All labels of the program, _i.e._, the labels that are not synthesized by
+    /// [`label_for_address()`](Self::label_for_address).
+    pub(crate) fn labels(&self) -> &HashMap<u64, String> {
+        &self.address_to_label
+    }
 }
 
-#[derive(Debug, Default, Clone, Eq, PartialEq, Arbitrary)]
-struct VMProfiler {
+/// Records the table heights of every called function. Resolves call targets to labels through
+/// the profiled program.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct VMProfiler<'program> {
+    program: &'program Program,
     call_stack: Vec<usize>,
     profile: Vec<ProfileLine>,
     table_heights: VMTableHeights,
     u32_table_entries: HashSet<U32TableEntry>,
+    cascade_table_entries: HashSet<u16>,
 }
 
 /// A single line in a [profile report](VMProfilingReport) for profiling [Triton](crate) programs.
@@ -562,20 +802,56 @@ pub struct VMProfilingReport {
 #[non_exhaustive]
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Arbitrary)]
 pub struct VMTableHeights {
+    pub program: u32,
     pub processor: u32,
     pub op_stack: u32,
     pub ram: u32,
     pub hash: u32,
+    pub cascade: u32,
+    pub lookup: u32,
     pub u32: u32,
 }
 
-impl VMProfiler {
-    fn new(num_instructions: usize) -> Self {
+/// One of the dimensions of [`VMTableHeights`]. Used, for example, to weigh the
+/// [folded stacks](VMProfilingReport::folded_stacks) of a profile.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, EnumIter, Arbitrary)]
+pub enum VMTable {
+    Program,
+    Processor,
+    OpStack,
+    Ram,
+    Hash,
+    Cascade,
+    Lookup,
+    U32,
+}
+
+impl<'program> VMProfiler<'program> {
+    pub(crate) fn new(program: &'program Program) -> Self {
+        let program_hash_traces = AlgebraicExecutionTrace::program_hash_traces(program);
+        let cascade_table_entries: HashSet<_> = program_hash_traces
+            .iter()
+            .flat_map(AlgebraicExecutionTrace::cascade_table_lookups)
+            .collect();
+
+        let program_len = AlgebraicExecutionTrace::padded_program_length(program);
+        let program_hash_len = program_hash_traces.len() * PERMUTATION_TRACE_LENGTH;
+        let lookup_len = AlgebraicExecutionTrace::LOOKUP_TABLE_HEIGHT;
+        let table_heights = VMTableHeights {
+            program: program_len.try_into().unwrap(),
+            hash: program_hash_len.try_into().unwrap(),
+            cascade: cascade_table_entries.len().try_into().unwrap(),
+            lookup: lookup_len.try_into().unwrap(),
+            ..Default::default()
+        };
+
         Self {
+            program,
             call_stack: vec![],
             profile: vec![],
-            table_heights: VMTableHeights::new(num_instructions),
+            table_heights,
             u32_table_entries: HashSet::default(),
+            cascade_table_entries,
         }
     }
 
@@ -600,26 +876,7 @@ impl VMProfiler {
         };
     }
 
-    fn handle_co_processor_calls(&mut self, calls: Vec<CoProcessorCall>) {
-        self.table_heights.processor += 1;
-        for call in calls {
-            match call {
-                CoProcessorCall::SpongeStateReset => self.table_heights.hash += 1,
-                CoProcessorCall::Tip5Trace(_, trace) => {
-                    self.table_heights.hash += u32::try_from(trace.len()).unwrap();
-                }
-                CoProcessorCall::U32Call(c) => {
-                    self.u32_table_entries.insert(c);
-                    let contribution = U32TableEntry::table_height_contribution;
-                    self.table_heights.u32 = self.u32_table_entries.iter().map(contribution).sum();
-                }
-                CoProcessorCall::OpStackCall(_) => self.table_heights.op_stack += 1,
-                CoProcessorCall::RamCall(_) => self.table_heights.ram += 1,
-            }
-        }
-    }
-
-    fn report(mut self) -> VMProfilingReport {
+    pub(crate) fn report(mut self) -> VMProfilingReport {
         for &line_number in &self.call_stack {
             self.profile[line_number].table_heights_stop = self.table_heights;
         }
@@ -631,15 +888,55 @@ impl VMProfiler {
     }
 }
 
-impl VMTableHeights {
-    fn new(num_instructions: usize) -> Self {
-        let padded_program_len = (num_instructions + 1).next_multiple_of(Tip5::RATE);
-        let num_absorbs = padded_program_len / Tip5::RATE;
-        let initial_hash_table_len = num_absorbs * PERMUTATION_TRACE_LENGTH;
+impl VMObserver for VMProfiler<'_> {
+    fn before_instruction(&mut self, _: &VMState, instruction: Instruction) {
+        match instruction {
+            Instruction::Call(address) => {
+                let label = self.program.label_for_address(address.value());
+                self.enter_span(label);
+            }
+            Instruction::Return => self.exit_span(),
+            _ => (),
+        }
+    }
 
-        Self {
-            hash: initial_hash_table_len.try_into().unwrap(),
-            ..Default::default()
+    fn after_instruction(&mut self, _: &VMState, _: Instruction) {
+        self.table_heights.processor += 1;
+    }
+
+    fn co_processor_call(&mut self, call: &CoProcessorCall) {
+        match call {
+            CoProcessorCall::SpongeStateReset => self.table_heights.hash += 1,
+            CoProcessorCall::Tip5Trace(_, trace) => {
+                self.table_heights.hash += u32::try_from(trace.len()).unwrap();
+                let lookups = AlgebraicExecutionTrace::cascade_table_lookups(trace);
+                self.cascade_table_entries.extend(lookups);
+                let num_cascade_table_entries = self.cascade_table_entries.len();
+                self.table_heights.cascade = num_cascade_table_entries.try_into().unwrap();
+            }
+            CoProcessorCall::U32Call(c) => {
+                self.u32_table_entries.insert(*c);
+                let contribution = U32TableEntry::table_height_contribution;
+                self.table_heights.u32 = self.u32_table_entries.iter().map(contribution).sum();
+            }
+            CoProcessorCall::OpStackCall(_) => self.table_heights.op_stack += 1,
+            CoProcessorCall::RamCall(_) => self.table_heights.ram += 1,
+        }
+    }
+}
+
+impl VMTableHeights {
+    /// The height of the given table.
+    pub fn height_of(&self, table: VMTable) -> u32 {
+        match table {
+            VMTable::Program => self.program,
+            VMTable::Processor => self.processor,
+            VMTable::OpStack => self.op_stack,
+            VMTable::Ram => self.ram,
+            VMTable::Hash => self.hash,
+            VMTable::Cascade => self.cascade,
+            VMTable::Lookup => self.lookup,
+            VMTable::U32 => self.u32,
         }
     }
 }
@@ -649,10 +946,13 @@ impl Sub<Self> for VMTableHeights {
 
     fn sub(self, rhs: Self) -> Self::Output {
         Self {
+            program: self.program.saturating_sub(rhs.program),
             processor: self.processor.saturating_sub(rhs.processor),
             op_stack: self.op_stack.saturating_sub(rhs.op_stack),
             ram: self.ram.saturating_sub(rhs.ram),
             hash: self.hash.saturating_sub(rhs.hash),
+            cascade: self.cascade.saturating_sub(rhs.cascade),
+            lookup: self.lookup.saturating_sub(rhs.lookup),
             u32: self.u32.saturating_sub(rhs.u32),
         }
     }
@@ -663,10 +963,13 @@ impl Add<Self> for VMTableHeights {
 
     fn add(self, rhs: Self) -> Self::Output {
         Self {
+            program: self.program + rhs.program,
             processor: self.processor + rhs.processor,
             op_stack: self.op_stack + rhs.op_stack,
             ram: self.ram + rhs.ram,
             hash: self.hash + rhs.hash,
+            cascade: self.cascade + rhs.cascade,
+            lookup: self.lookup + rhs.lookup,
             u32: self.u32 + rhs.u32,
         }
     }
@@ -693,6 +996,126 @@ impl Display for ProfileLine {
     }
 }
 
+impl VMProfilingReport {
+    /// The name of the stack frame at the bottom of every [folded stack](Self::folded_stacks),
+    /// representing the code outside of any function call.
+    pub const ROOT_FRAME: &'static str = "main";
+
+    /// The profile in the “folded stacks” format, _e.g._, as produced by `stackcollapse` scripts
+    /// and consumed by [`flamegraph.pl`](https://github.com/brendangregg/FlameGraph) or
+    /// [`inferno`](https://github.com/jonhoo/inferno). Every line consists of a call stack,
+    /// starting with the [root frame](Self::ROOT_FRAME) and separated by semicolons, followed by
+    /// the number of rows the innermost function added to the given table, excluding the rows
+    /// added by the functions it called. For example, with [`VMTable::Processor`], the lines
+    /// look like `main;foo;bar 42`, meaning that 42 clock cycles were spent in `bar` while it
+    /// was called from `foo`.
+    ///
+    /// Identical call stacks are combined. Call stacks not contributing to the given table are
+    /// omitted. The lines are sorted by call stack.
+    pub fn folded_stacks(&self, table: VMTable) -> String {
+        let (root_contribution, exclusive_contributions) = self.exclusive_contributions(table);
+
+        let mut call_stack: Vec<&str> = vec![];
+        let mut paths = vec![];
+        for line in &self.profile {
+            call_stack.truncate(line.call_depth);
+            call_stack.push(&line.label);
+            let path = once(Self::ROOT_FRAME)
+                .chain(call_stack.iter().copied())
+                .join(";");
+            paths.push(path);
+        }
+
+        let mut folded_stacks = BTreeMap::<String, u64>::new();
+        folded_stacks.insert(Self::ROOT_FRAME.to_string(), root_contribution.into());
+        for (path, contribution) in paths.into_iter().zip(exclusive_contributions) {
+            *folded_stacks.entry(path).or_default() += u64::from(contribution);
+        }
+
+        folded_stacks
+            .into_iter()
+            .filter(|&(_, contribution)| contribution > 0)
+            .map(|(path, contribution)| format!("{path} {contribution}\n"))
+            .collect()
+    }
+
+    /// The number of rows the code outside any function call added to the given table, and the
+    /// number of rows each [`ProfileLine`] added, excluding the rows added by called functions.
+    fn exclusive_contributions(&self, table: VMTable) -> (u32, Vec<u32>) {
+        let contribution = |line: &ProfileLine| line.table_height_contributions().height_of(table);
+        let mut exclusive_contributions = self.profile.iter().map(contribution).collect_vec();
+        let mut root_contribution = self.total.height_of(table);
+
+        let mut call_stack: Vec<usize> = vec![];
+        for (line_number, line) in self.profile.iter().enumerate() {
+            call_stack.truncate(line.call_depth);
+            let own_contribution = contribution(line);
+            match call_stack.last() {
+                Some(&caller) => {
+                    let caller_contribution = &mut exclusive_contributions[caller];
+                    *caller_contribution = caller_contribution.saturating_sub(own_contribution);
+                }
+                None => root_contribution = root_contribution.saturating_sub(own_contribution),
+            }
+            call_stack.push(line_number);
+        }
+
+        (root_contribution, exclusive_contributions)
+    }
+
+    /// The table with the most rows. Its height determines the padded height, and with it, the
+    /// cost of proving the execution. If multiple tables have the same height, the last one in
+    /// the order of [`VMTable`] is returned.
+    ///
+    /// The lookup table always has 2^8 rows. For short executions, it is often the dominating
+    /// table.
+    pub fn dominating_table(&self) -> VMTable {
+        VMTable::iter()
+            .max_by_key(|&table| self.total.height_of(table))
+            .expect("there should be at least one table")
+    }
+
+    /// The height of the [dominating table](Self::dominating_table), rounded up to the next power
+    /// of two. Corresponds to [`AlgebraicExecutionTrace::padded_height`].
+    pub fn padded_height(&self) -> u32 {
+        let height = self.total.height_of(self.dominating_table());
+        height.next_power_of_two()
+    }
+
+    /// The number of rows that can be added to the [dominating table](Self::dominating_table)
+    /// before the [padded height](Self::padded_height) doubles.
+    pub fn rows_until_next_doubling(&self) -> u32 {
+        let height = self.total.height_of(self.dominating_table());
+        self.padded_height() - height
+    }
+
+    /// The number of rows every function added to the given table, excluding the rows added by
+    /// the functions it called, summed over all calls. The code outside any function call is
+    /// attributed to the [root frame](Self::ROOT_FRAME). Sorted by contribution, largest first;
+    /// functions not contributing to the table are omitted.
+    ///
+    /// Most useful with the [dominating table](Self::dominating_table): reducing its height
+    /// below the next lower power of two reduces the [padded height](Self::padded_height).
+    pub fn function_contributions(&self, table: VMTable) -> Vec<(String, u32)> {
+        let (root_contribution, exclusive_contributions) = self.exclusive_contributions(table);
+
+        let mut contributions = vec![(Self::ROOT_FRAME.to_string(), root_contribution)];
+        for (line, contribution) in self.profile.iter().zip(exclusive_contributions) {
+            match contributions
+                .iter_mut()
+                .find(|(label, _)| label == &line.label)
+            {
+                Some((_, total_contribution)) => *total_contribution += contribution,
+                None => contributions.push((line.label.clone(), contribution)),
+            }
+        }
+
+        contributions.retain(|&(_, contribution)| contribution > 0);
+        contributions.sort_by_key(|&(_, contribution)| Reverse(contribution));
+        contributions
+    }
+}
+
 impl Display for VMProfilingReport {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         struct AggregateLine {
@@ -730,24 +1153,36 @@ impl Display for VMProfilingReport {
         let max_label_len = aggregated.iter().map(label_len).max();
         let max_label_len = max_label_len.unwrap_or_default().max(COL_WIDTH);
 
-        let [soubroutine, processor, op_stack, ram, hash, u32_title] =
-            ["Subroutine", "Processor", "Op Stack", "RAM", "Hash", "U32"];
+        let title = |table| match table {
+            VMTable::Program => "Program",
+            VMTable::Processor => "Processor",
+            VMTable::OpStack => "Op Stack",
+            VMTable::Ram => "RAM",
+            VMTable::Hash => "Hash",
+            VMTable::Cascade => "Cascade",
+            VMTable::Lookup => "Lookup",
+            VMTable::U32 => "U32",
+        };
+
+        // The heights of the program and lookup tables are the same for every execution of the
+        // program. No subroutine ever contributes to them.
+        let is_execution_dependent =
+            |table: &VMTable| !matches!(table, VMTable::Program | VMTable::Lookup);
+        let tables = VMTable::iter().filter(is_execution_dependent).collect_vec();
 
+        let soubroutine = "Subroutine";
         write!(f, "| {soubroutine:<max_label_len$} ")?;
-        write!(f, "| {processor:>COL_WIDTH$} ")?;
-        write!(f, "| {op_stack:>COL_WIDTH$} ")?;
-        write!(f, "| {ram:>COL_WIDTH$} ")?;
-        write!(f, "| {hash:>COL_WIDTH$} ")?;
-        write!(f, "| {u32_title:>COL_WIDTH$} ")?;
+        for &table in &tables {
+            let title = title(table);
+            write!(f, "| {title:>COL_WIDTH$} ")?;
+        }
         writeln!(f, "|")?;
 
         let dash = "-";
         write!(f, "|:{dash:-<max_label_len$}-")?;
-        write!(f, "|-{dash:->COL_WIDTH$}:")?;
-        write!(f, "|-{dash:->COL_WIDTH$}:")?;
-        write!(f, "|-{dash:->COL_WIDTH$}:")?;
-        write!(f, "|-{dash:->COL_WIDTH$}:")?;
-        write!(f, "|-{dash:->COL_WIDTH$}:")?;
+        for _ in &tables {
+            write!(f, "|-{dash:->COL_WIDTH$}:")?;
+        }
         writeln!(f, "|")?;
 
         for line in &aggregated {
@@ -756,31 +1191,44 @@ impl Display for VMProfilingReport {
             let abs_width = COL_WIDTH - rel_width - 4; // ' (' and '%)'
 
             let label = label(line);
-            let proc_abs = line.table_heights.processor;
-            let proc_rel = 100.0 * f64::from(proc_abs) / f64::from(self.total.processor);
-            let proc_rel = format!("{proc_rel:.rel_precision$}");
-            let stack_abs = line.table_heights.op_stack;
-            let stack_rel = 100.0 * f64::from(stack_abs) / f64::from(self.total.op_stack);
-            let stack_rel = format!("{stack_rel:.rel_precision$}");
-            let ram_abs = line.table_heights.ram;
-            let ram_rel = 100.0 * f64::from(ram_abs) / f64::from(self.total.ram);
-            let ram_rel = format!("{ram_rel:.rel_precision$}");
-            let hash_abs = line.table_heights.hash;
-            let hash_rel = 100.0 * f64::from(hash_abs) / f64::from(self.total.hash);
-            let hash_rel = format!("{hash_rel:.rel_precision$}");
-            let u32_abs = line.table_heights.u32;
-            let u32_rel = 100.0 * f64::from(u32_abs) / f64::from(self.total.u32);
-            let u32_rel = format!("{u32_rel:.rel_precision$}");
-
             write!(f, "| {label:<max_label_len$} ")?;
-            write!(f, "| {proc_abs:>abs_width$} ({proc_rel:>rel_width$}%) ")?;
-            write!(f, "| {stack_abs:>abs_width$} ({stack_rel:>rel_width$}%) ")?;
-            write!(f, "| {ram_abs:>abs_width$} ({ram_rel:>rel_width$}%) ")?;
-            write!(f, "| {hash_abs:>abs_width$} ({hash_rel:>rel_width$}%) ")?;
-            write!(f, "| {u32_abs:>abs_width$} ({u32_rel:>rel_width$}%) ")?;
+            for &table in &tables {
+                let abs = line.table_heights.height_of(table);
+                let rel = 100.0 * f64::from(abs) / f64::from(self.total.height_of(table));
+                let rel = format!("{rel:.rel_precision$}");
+                write!(f, "| {abs:>abs_width$} ({rel:>rel_width$}%) ")?;
+            }
             writeln!(f, "|")?;
         }
 
+        let dominating_table = self.dominating_table();
+        let height = self.total.height_of(dominating_table);
+        let padded_height = self.padded_height();
+        let rows_left = self.rows_until_next_doubling();
+        writeln!(f)?;
+        writeln!(
+            f,
+            "Dominating table: {dominating_table:?} with {height} rows. \
+            Padded height is {padded_height}; {rows_left} rows left until it doubles."
+        )?;
+
+        let contributions = self.function_contributions(dominating_table);
+        let max_label_len = contributions.iter().map(|(label, _)| label.len()).max();
+        let max_label_len = max_label_len.unwrap_or_default().max(COL_WIDTH);
+        let function = "Function";
+        let rows = format!("{dominating_table:?} rows (self)");
+        writeln!(f)?;
+        writeln!(f, "| {function:<max_label_len$} | {rows:>COL_WIDTH$} |")?;
+        writeln!(f, "|:{dash:-<max_label_len$}-|-{dash:->COL_WIDTH$}:|")?;
+        for (label, contribution) in contributions {
+            let rel_contribution = 100.0 * f64::from(contribution) / f64::from(height);
+            let contribution = format!("{contribution} ({rel_contribution:>5.1}%)");
+            writeln!(
+                f,
+                "| {label:<max_label_len$} | {contribution:>COL_WIDTH$} |"
+            )?;
+        }
+
         Ok(())
     }
 }
@@ -894,6 +1342,18 @@ mod tests {
 
     use super::*;
 
+    /// Arbitrary [`LabelledInstruction`]s include [static data](LabelledInstruction::Data), but
+    /// arbitrary programs only ever contain its prologue. All property tests on programs keep
+    /// testing regular instructions only.
+    #[proptest]
+    fn arbitrary_programs_contain_static_data_only_as_instructions(
+        #[strategy(arb())] program: Program,
+    ) {
+        let is_data =
+            |instruction: &LabelledInstruction| matches!(instruction, LabelledInstruction::Data(_));
+        prop_assert!(!program.labelled_instructions().iter().any(is_data));
+    }
+
     #[proptest]
     fn random_program_encode_decode_equivalence(#[strategy(arb())] program: Program) {
         let encoding = program.encode();
@@ -1046,9 +1506,112 @@ mod tests {
         let u32_height = u32::try_from(aet.height_of_table(TableId::U32)).unwrap();
         assert!(u32_height == profile.total.u32);
 
+        let program_height = u32::try_from(aet.height_of_table(TableId::Program)).unwrap();
+        assert!(program_height == profile.total.program);
+
+        let cascade_height = u32::try_from(aet.height_of_table(TableId::Cascade)).unwrap();
+        assert!(cascade_height == profile.total.cascade);
+
+        let lookup_height = u32::try_from(aet.height_of_table(TableId::Lookup)).unwrap();
+        assert!(lookup_height == profile.total.lookup);
+
         println!("{profile}");
     }
 
+    #[test]
+    fn profiling_report_agrees_with_aet_on_padded_height() {
+        let short_program = triton_program!(halt);
+        let hashing_program = triton_program! {
+            sponge_init
+            push 20 call squeeze pop 1 halt
+            squeeze:
+                dup 0 push 0 eq skiz return
+                sponge_squeeze pop 5 pop 5
+                push -1 add recurse
+        };
+        let long_program = CALCULATE_NEW_MMR_PEAKS_FROM_APPEND_WITH_SAFE_LISTS.clone();
+
+        for program in [short_program, hashing_program, long_program] {
+            let_assert!(Ok((aet, _)) = program.trace_execution([].into(), [].into()));
+            let_assert!(Ok((_, profile)) = program.profile([].into(), [].into()));
+            let padded_height = usize::try_from(profile.padded_height()).unwrap();
+            assert!(aet.padded_height() == padded_height);
+
+            let height = profile.total.height_of(profile.dominating_table());
+            let height = usize::try_from(height).unwrap();
+            assert!(aet.height().height == height);
+        }
+    }
+
+    #[test]
+    fn folded_stacks_attribute_cycles_to_innermost_function() {
+        let program = triton_program! {
+            call foo halt
+            foo: call bar call bar return
+            bar: push 1 pop 1 return
+        };
+        let_assert!(Ok((_, profile)) = program.profile([].into(), [].into()));
+        let folded_stacks = profile.folded_stacks(VMTable::Processor);
+        assert!("main 1\nmain;foo 2\nmain;foo;bar 8\n" == folded_stacks);
+    }
+
+    #[test]
+    fn folded_stacks_account_for_entire_table_heights() {
+        let program = CALCULATE_NEW_MMR_PEAKS_FROM_APPEND_WITH_SAFE_LISTS.clone();
+        let_assert!(Ok((_, profile)) = program.profile([].into(), [].into()));
+        for table in VMTable::iter() {
+            let folded_stacks = profile.folded_stacks(table);
+            let weights = folded_stacks.lines().map(|line| {
+                let_assert!(Some((_, weight)) = line.rsplit_once(' '));
+                let_assert!(Ok(weight) = weight.parse::<u64>());
+                weight
+            });
+            let total = u64::from(profile.total.height_of(table));
+            assert!(total == weights.sum::<u64>(), "table: {table:?}");
+        }
+    }
+
+    #[test]
+    fn profiling_report_attributes_rows_of_dominating_table_to_functions() {
+        let hash_heights = |hash| VMTableHeights {
+            hash,
+            ..VMTableHeights::default()
+        };
+        let profile_line = |label: &str, call_depth, start, stop| ProfileLine {
+            label: label.to_string(),
+            call_depth,
+            table_heights_start: hash_heights(start),
+            table_heights_stop: hash_heights(stop),
+        };
+        let report = VMProfilingReport {
+            total: VMTableHeights {
+                processor: 10,
+                op_stack: 5,
+                u32: 3,
+                ..hash_heights(40)
+            },
+            profile: vec![
+                profile_line("foo", 0, 6, 30),
+                profile_line("bar", 1, 10, 20),
+                profile_line("bar", 0, 30, 38),
+            ],
+        };
+
+        assert!(VMTable::Hash == report.dominating_table());
+        assert!(64 == report.padded_height());
+        assert!(24 == report.rows_until_next_doubling());
+
+        let contributions = report.function_contributions(VMTable::Hash);
+        let expected = [("bar", 18), ("foo", 14), (VMProfilingReport::ROOT_FRAME, 8)];
+        let expected = expected.map(|(label, rows)| (label.to_string(), rows));
+        assert!(expected.to_vec() == contributions);
+        assert!(report.function_contributions(VMTable::Ram).is_empty());
+
+        let report = report.to_string();
+        assert!(report.contains("Dominating table: Hash with 40 rows."));
+        assert!(report.contains("24 rows left"));
+    }
+
     #[test]
     fn program_with_too_many_returns_crashes_vm_but_not_profiler() {
         let program = triton_program! {
@@ -1097,4 +1660,72 @@ mod tests {
         let program = Program::decode(&encoding).unwrap();
         println!("{program}");
     }
+
+    #[test]
+    fn static_data_is_in_ram_when_program_starts() {
+        let program = triton_program! {
+            .data 100: 7, 8, 9, 10, 11, 12
+            push 105 read_mem 1 pop 1 write_io 1
+            push 100 read_mem 1 pop 1 write_io 1
+            halt
+        };
+        let_assert!(Ok(output) = program.run([].into(), [].into()));
+        assert!(bfe_vec![12, 7] == output);
+    }
+
+    #[test]
+    fn static_data_overwrites_initial_ram() {
+        let program = triton_program!(.data 0: 42 push 0 read_mem 1 pop 1 write_io 1 halt);
+        let ram = HashMap::from([(bfe!(0), bfe!(1))]);
+        let non_determinism = NonDeterminism::default().with_ram(ram);
+        let_assert!(Ok(output) = program.run([].into(), non_determinism));
+        assert!(bfe_vec![42] == output);
+    }
+
+    #[test]
+    fn static_data_is_covered_by_program_hash() {
+        let program = triton_program!(.data 0: 42 halt);
+        let other_program = triton_program!(.data 0: 43 halt);
+        assert!(program.hash::<Tip5>() != other_program.hash::<Tip5>());
+    }
+
+    #[test]
+    fn program_with_static_data_can_be_printed_and_parsed() {
+        let program = triton_program!(.data 5: 1, 2 call foo halt foo: return);
+        let_assert!(Ok(reparsed_program) = Program::from_code(&program.to_string()));
+        assert!(program == reparsed_program);
+    }
+
+    #[test]
+    fn parsed_program_knows_source_locations() {
+        let program = Program::from_code("push 1\n  push 2 add\n\nhalt").unwrap();
+        let line_and_column = |address| {
+            let location = program.source_location(address)?;
+            Some((location.line, location.column))
+        };
+
+        assert!(Some((1, 1)) == line_and_column(0));
+        assert!(None == line_and_column(1));
+        assert!(Some((2, 3)) == line_and_column(2));
+        assert!(Some((2, 10)) == line_and_column(4));
+        assert!(Some((4, 1)) == line_and_column(5));
+        assert!(vec![2, 4] == program.addresses_at_line("", 2));
+        assert!(program.addresses_at_line("", 3).is_empty());
+    }
+
+    #[test]
+    fn static_data_prologue_is_located_at_static_data_declaration() {
+        let program = Program::from_code("push 1 pop 1\n.data 0: 7\nhalt").unwrap();
+        assert!(vec![0, 2, 4, 6] == program.addresses_at_line("", 2));
+        assert!(vec![8, 10] == program.addresses_at_line("", 1));
+        assert!(vec![12] == program.addresses_at_line("", 3));
+    }
+
+    #[test]
+    fn source_locations_are_not_encoded() {
+        let program = Program::from_code("push 1 halt").unwrap();
+        let decoded_program = *Program::decode(&program.encode()).unwrap();
+        assert!(program.source_location(0).is_some());
+        assert!(decoded_program.source_location(0).is_none());
+    }
 }
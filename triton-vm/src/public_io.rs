@@ -0,0 +1,268 @@
+//! Adapters for streaming public input into, and public output out of, a running Triton VM.
+//!
+//! By default, all public input is handed to the VM up front as a [`PublicInput`], and all public
+//! output becomes available only once the VM has halted. Long-running programs can instead
+//! consume their input from a [`PublicInputSource`] and emit their output to a
+//! [`PublicOutputSink`] while running, see [`Program::run_streaming`] and
+//! [`Program::trace_execution_streaming`].
+//!
+//! Reading from a source and writing to a sink may block. For example, a bounded
+//! [`sync_channel`](std::sync::mpsc::sync_channel) used as a sink stalls the VM until the
+//! receiving end catches up, providing back-pressure.
+//!
+//! Irrespective of streaming, the full transcript of public input and output is kept, since it is
+//! required to construct a [`Claim`](crate::proof::Claim).
+//!
+//! [`PublicInput`]: crate::program::PublicInput
+//! [`Program::run_streaming`]: crate::program::Program::run_streaming
+//! [`Program::trace_execution_streaming`]: crate::program::Program::trace_execution_streaming
+
+use std::sync::mpsc::Sender;
+use std::sync::mpsc::SyncSender;
+
+use twenty_first::prelude::*;
+
+use crate::error::InstructionError;
+use crate::error::InstructionError::PublicOutputSinkClosed;
+use crate::instruction::Instruction;
+use crate::vm::VMState;
+
+type Result<T> = std::result::Result<T, InstructionError>;
+
+/// A source of public input that Triton VM can read from using instruction `read_io`.
+///
+/// Elements are requested lazily, _i.e._, only when instruction `read_io` is about to be executed
+/// and the VM's public input buffer does not hold enough elements. Requesting an element may block.
+///
+/// Any [`Iterator`] over [`BFieldElement`]s is a source. In particular, this includes
+/// [`Receiver::into_iter()`](std::sync::mpsc::Receiver::into_iter), which blocks until the next
+/// element arrives and ends once all senders have been dropped.
+pub trait PublicInputSource {
+    /// The next element of public input, or `None` if the source is exhausted.
+    fn next_element(&mut self) -> Option<BFieldElement>;
+}
+
+/// A sink for public output that Triton VM writes to using instruction `write_io`.
+///
+/// Writing an element may block, which stalls the VM until the sink accepts the element.
+pub trait PublicOutputSink {
+    /// Accept one element of public output. Returns an error if the sink can no longer accept any
+    /// elements, which crashes the VM.
+    fn write_element(&mut self, element: BFieldElement) -> Result<()>;
+}
+
+impl<I: Iterator<Item = BFieldElement>> PublicInputSource for I {
+    fn next_element(&mut self) -> Option<BFieldElement> {
+        self.next()
+    }
+}
+
+impl PublicOutputSink for Vec<BFieldElement> {
+    fn write_element(&mut self, element: BFieldElement) -> Result<()> {
+        self.push(element);
+        Ok(())
+    }
+}
+
+impl PublicOutputSink for Sender<BFieldElement> {
+    fn write_element(&mut self, element: BFieldElement) -> Result<()> {
+        self.send(element).map_err(|_| PublicOutputSinkClosed)
+    }
+}
+
+/// Blocks if the channel's buffer is full.
+impl PublicOutputSink for SyncSender<BFieldElement> {
+    fn write_element(&mut self, element: BFieldElement) -> Result<()> {
+        self.send(element).map_err(|_| PublicOutputSinkClosed)
+    }
+}
+
+impl<S: PublicOutputSink + ?Sized> PublicOutputSink for &mut S {
+    fn write_element(&mut self, element: BFieldElement) -> Result<()> {
+        (**self).write_element(element)
+    }
+}
+
+/// The complete public input and output of one execution of a program. Can be used to construct
+/// the corresponding [`Claim`](crate::proof::Claim), for example:
+///
+/// ```
+/// # use triton_vm::prelude::*;
+/// let program = triton_program!(read_io 1 push 2 mul write_io 1 halt);
+/// let input = [bfe!(21)].into_iter();
+/// let transcript = program.run_streaming(input, [].into(), vec![]).unwrap();
+///
+/// let claim = Claim::about_program(&program)
+///     .with_input(transcript.input)
+///     .with_output(transcript.output);
+/// assert_eq!(bfe_vec![42], claim.output);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct PublicIoTranscript {
+    /// All public input that was read by the program, in order.
+    pub input: Vec<BFieldElement>,
+
+    /// All public output that was written by the program, in order.
+    pub output: Vec<BFieldElement>,
+}
+
+/// Connects a [`VMState`] to a [`PublicInputSource`] and a [`PublicOutputSink`].
+#[derive(Debug, Clone)]
+pub(crate) struct StreamingIo<I, O> {
+    source: I,
+    sink: O,
+    input_transcript: Vec<BFieldElement>,
+    num_forwarded_output_elements: usize,
+}
+
+impl<I: PublicInputSource, O: PublicOutputSink> StreamingIo<I, O> {
+    pub(crate) fn new(source: I, sink: O) -> Self {
+        Self {
+            source,
+            sink,
+            input_transcript: vec![],
+            num_forwarded_output_elements: 0,
+        }
+    }
+
+    /// Make sure the public input buffer of the given [`VMState`] holds enough elements for the
+    /// current instruction, pulling as many elements from the source as necessary.
+    ///
+    /// If the source is exhausted, the buffer is left too short, and executing the current
+    /// instruction crashes the VM in the usual manner.
+    pub(crate) fn prefetch_public_input(&mut self, state: &mut VMState) {
+        let Ok(Instruction::ReadIo(n)) = state.current_instruction() else {
+            return;
+        };
+        while state.public_input.len() < n.num_words() {
+            let Some(element) = self.source.next_element() else {
+                return;
+            };
+            self.input_transcript.push(element);
+            state.public_input.push_back(element);
+        }
+    }
+
+    /// Forward all public output of the given [`VMState`] that has not yet been forwarded to the
+    /// sink.
+    pub(crate) fn flush_public_output(&mut self, state: &VMState) -> Result<()> {
+        let unforwarded_output = &state.public_output[self.num_forwarded_output_elements..];
+        for &element in unforwarded_output {
+            self.sink.write_element(element)?;
+            self.num_forwarded_output_elements += 1;
+        }
+        Ok(())
+    }
+
+    /// The transcript of the execution, consisting of all public input pulled from the source and
+    /// all public output of the given [`VMState`].
+    pub(crate) fn into_transcript(self, state: &VMState) -> PublicIoTranscript {
+        PublicIoTranscript {
+            input: self.input_transcript,
+            output: state.public_output.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::channel;
+    use std::sync::mpsc::sync_channel;
+    use std::thread;
+
+    use assert2::assert;
+    use assert2::let_assert;
+
+    use crate::example_programs::FIBONACCI_SEQUENCE;
+    use crate::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn streaming_run_agrees_with_regular_run() {
+        let program = FIBONACCI_SEQUENCE.clone();
+        let input = bfe_vec![17];
+        let expected_output = program.run(input.clone().into(), [].into()).unwrap();
+
+        let transcript = program
+            .run_streaming(input.clone().into_iter(), [].into(), vec![])
+            .unwrap();
+        assert!(input == transcript.input);
+        assert!(expected_output == transcript.output);
+    }
+
+    #[test]
+    fn streaming_trace_execution_agrees_with_regular_trace_execution() {
+        let program = FIBONACCI_SEQUENCE.clone();
+        let input = bfe_vec![23];
+        let (expected_aet, expected_output) = program
+            .trace_execution(input.clone().into(), [].into())
+            .unwrap();
+
+        let mut sink = vec![];
+        let (aet, transcript) = program
+            .trace_execution_streaming(input.clone().into_iter(), [].into(), &mut sink)
+            .unwrap();
+        assert!(expected_aet.processor_trace == aet.processor_trace);
+        assert!(input == transcript.input);
+        assert!(expected_output == transcript.output);
+        assert!(expected_output == sink);
+    }
+
+    #[test]
+    fn program_can_interact_with_channels_while_running() {
+        let program = triton_program! {
+            call echo_doubled
+            echo_doubled:
+                read_io 1
+                dup 0 push 0 eq skiz halt
+                push 2 mul write_io 1
+                recurse
+        };
+
+        let (input_sender, input_receiver) = channel();
+        let (output_sender, output_receiver) = sync_channel(0);
+        let vm_thread = thread::spawn(move || {
+            program.run_streaming(input_receiver.into_iter(), [].into(), output_sender)
+        });
+
+        for i in 1..=10_u64 {
+            input_sender.send(bfe!(i)).unwrap();
+            let_assert!(Ok(output) = output_receiver.recv());
+            assert!(bfe!(2 * i) == output);
+        }
+        input_sender.send(bfe!(0)).unwrap();
+
+        let_assert!(Ok(Ok(transcript)) = vm_thread.join());
+        assert!(11 == transcript.input.len());
+        assert!(10 == transcript.output.len());
+    }
+
+    #[test]
+    fn exhausted_source_crashes_vm_like_empty_public_input() {
+        let program = triton_program!(read_io 2 halt);
+        let input = bfe_vec![1].into_iter();
+        let_assert!(Err(err) = program.run_streaming(input, [].into(), vec![]));
+        let_assert!(InstructionError::EmptyPublicInput(1) = err.source);
+    }
+
+    #[test]
+    fn closed_sink_crashes_vm() {
+        let program = triton_program!(push 1 write_io 1 halt);
+        let (sender, receiver) = channel();
+        drop(receiver);
+        let_assert!(Err(err) = program.run_streaming(std::iter::empty(), [].into(), sender));
+        let_assert!(InstructionError::PublicOutputSinkClosed = err.source);
+    }
+
+    #[test]
+    fn source_is_only_read_from_when_necessary() {
+        let program = triton_program!(read_io 1 write_io 1 halt);
+        let mut input = bfe_vec![1, 2, 3].into_iter();
+        let transcript = program
+            .run_streaming(&mut input, [].into(), vec![])
+            .unwrap();
+        assert!(bfe_vec![1] == transcript.input);
+        assert!(bfe_vec![2, 3] == input.collect::<Vec<_>>());
+    }
+}
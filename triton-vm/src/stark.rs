@@ -118,8 +118,7 @@ impl Stark {
 
         prof_start!(maybe_profiler, "derive additional parameters");
         let padded_height = aet.padded_height();
-        // let max_degree = self.derive_max_degree(padded_height);
-        let max_degree = 2 << 20; // TODO: DELETE!!
+        let max_degree = self.derive_max_degree(padded_height);
         let fri = self.derive_fri(padded_height)?;
         let quotient_domain = Self::quotient_domain(fri.domain, max_degree)?;
         proof_stream.enqueue(ProofItem::Log2PaddedHeight(padded_height.ilog2()));
@@ -143,11 +142,6 @@ impl Stark {
         master_base_table.low_degree_extend_all_columns(maybe_profiler);
         prof_stop!(maybe_profiler, "LDE");
 
-        let report = maybe_profiler.clone().as_mut().unwrap().report();
-        println!("{report}");
-
-        panic!("Oh my God. Just please crash.");
-
         prof_start!(maybe_profiler, "Merkle tree", "hash");
         let base_merkle_tree = master_base_table.merkle_tree(maybe_profiler);
         prof_stop!(maybe_profiler, "Merkle tree");
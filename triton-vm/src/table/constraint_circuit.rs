@@ -31,6 +31,9 @@ use num_traits::One;
 use num_traits::Zero;
 use quote::quote;
 use quote::ToTokens;
+use strum::EnumIter;
+use strum::EnumString;
+use twenty_first::math::x_field_element::EXTENSION_DEGREE;
 use twenty_first::prelude::*;
 
 use CircuitExpression::*;
@@ -71,6 +74,39 @@ impl BinOp {
     }
 }
 
+/// How [`ConstraintCircuitMonad::lower_to_degree_with_strategy`] picks the next node to
+/// substitute with a new variable. Every new variable corresponds to a new column in the
+/// [degree lowering table](crate::table::degree_lowering_table), making the strategy directly
+/// influence the prover's cost.
+#[derive(
+    Debug, Default, Copy, Clone, Eq, PartialEq, Hash, strum::Display, EnumString, EnumIter,
+)]
+#[strum(serialize_all = "kebab-case")]
+pub enum DegreeLoweringStrategy {
+    /// Of all nodes that can be substituted, pick one that occurs most often. Break ties by
+    /// picking one of highest degree. This is the strategy Triton VM's AIR is lowered with.
+    #[default]
+    MostSharedFirst,
+
+    /// Of all nodes that can be substituted, pick one of highest degree. Break ties by picking one
+    /// that occurs most often.
+    HighestDegreeFirst,
+
+    /// Search all sets of nodes for one whose substitution lowers the degree sufficiently while
+    /// introducing the fewest new columns. An extension column counts as [`EXTENSION_DEGREE`] base
+    /// columns. Since the search is exponential in the number of nodes, it falls back to
+    /// [`MostSharedFirst`](Self::MostSharedFirst) while more than
+    /// [`MAX_NUM_EXHAUSTIVE_SEARCH_CANDIDATES`](Self::MAX_NUM_EXHAUSTIVE_SEARCH_CANDIDATES) nodes
+    /// can be substituted.
+    Exhaustive,
+}
+
+impl DegreeLoweringStrategy {
+    /// The maximum number of nodes for which strategy [`Exhaustive`](Self::Exhaustive) actually
+    /// performs an exhaustive search.
+    pub const MAX_NUM_EXHAUSTIVE_SEARCH_CANDIDATES: usize = 12;
+}
+
 /// Describes the position of a variable in a constraint polynomial in the row layout applicable
 /// for a certain kind of constraint polynomial.
 ///
@@ -774,11 +810,31 @@ impl<II: InputIndicator> ConstraintCircuitMonad<II> {
     /// provided. The uniqueness of the new columns' indices depends on these provided values.
     /// Note that these indices are generally not equal to the number of used columns, especially
     /// when a tables' constraints are built using the master table's column indices.
+    ///
+    /// Uses the [default](DegreeLoweringStrategy::default) [`DegreeLoweringStrategy`].
     pub fn lower_to_degree(
         multicircuit: &mut [Self],
         target_degree: isize,
         num_base_cols: usize,
         num_ext_cols: usize,
+    ) -> (Vec<Self>, Vec<Self>) {
+        Self::lower_to_degree_with_strategy(
+            multicircuit,
+            DegreeLoweringStrategy::default(),
+            target_degree,
+            num_base_cols,
+            num_ext_cols,
+        )
+    }
+
+    /// Like [`lower_to_degree`](Self::lower_to_degree), but picks the nodes to substitute
+    /// according to the given [`DegreeLoweringStrategy`].
+    pub fn lower_to_degree_with_strategy(
+        multicircuit: &mut [Self],
+        strategy: DegreeLoweringStrategy,
+        target_degree: isize,
+        num_base_cols: usize,
+        num_ext_cols: usize,
     ) -> (Vec<Self>, Vec<Self>) {
         assert!(
             target_degree > 1,
@@ -795,7 +851,8 @@ impl<II: InputIndicator> ConstraintCircuitMonad<II> {
         let builder = multicircuit[0].builder.clone();
 
         while Self::multicircuit_degree(multicircuit) > target_degree {
-            let chosen_node_id = Self::pick_node_to_substitute(multicircuit, target_degree);
+            let chosen_node_id =
+                Self::pick_node_to_substitute(multicircuit, strategy, target_degree);
 
             // Create a new variable.
             let chosen_node = builder.get_node_by_id(chosen_node_id).unwrap();
@@ -831,10 +888,11 @@ impl<II: InputIndicator> ConstraintCircuitMonad<II> {
         (base_constraints, ext_constraints)
     }
 
-    /// Heuristically pick a node from the given multicircuit that is to be substituted with a new
-    /// variable. The ID of the chosen node is returned.
+    /// Pick a node from the given multicircuit that is to be substituted with a new variable,
+    /// according to the given strategy. The ID of the chosen node is returned.
     fn pick_node_to_substitute(
         multicircuit: &[ConstraintCircuitMonad<II>],
+        strategy: DegreeLoweringStrategy,
         target_degree: isize,
     ) -> usize {
         assert!(!multicircuit.is_empty());
@@ -863,24 +921,205 @@ impl<II: InputIndicator> ConstraintCircuitMonad<II> {
         // If the resulting list is empty, there is no way forward. Stop – panic time!
         assert!(!low_degree_nodes.is_empty(), "Cannot lower degree.");
 
-        // Of the remaining nodes, keep the ones occurring the most often.
-        let mut nodes_and_occurrences = HashMap::new();
-        for node in &low_degree_nodes {
-            *nodes_and_occurrences.entry(node).or_insert(0) += 1;
-        }
-        let max_occurrences = nodes_and_occurrences.iter().map(|(_, &c)| c).max().unwrap();
-        nodes_and_occurrences.retain(|_, &mut count| count == max_occurrences);
-        let mut candidate_nodes = nodes_and_occurrences.keys().copied().collect_vec();
-
-        // If there are still multiple nodes, pick the one with the highest degree.
-        let max_degree = candidate_nodes.iter().map(|n| n.degree()).max().unwrap();
-        candidate_nodes.retain(|node| node.degree() == max_degree);
+        let mut candidate_nodes = match strategy {
+            DegreeLoweringStrategy::MostSharedFirst => {
+                let most_shared_nodes = Self::most_occurring_nodes(low_degree_nodes);
+                Self::highest_degree_nodes(most_shared_nodes)
+            }
+            DegreeLoweringStrategy::HighestDegreeFirst => {
+                let highest_degree_nodes = Self::highest_degree_nodes(low_degree_nodes);
+                Self::most_occurring_nodes(highest_degree_nodes)
+            }
+            DegreeLoweringStrategy::Exhaustive => {
+                let high_degree_roots = multicircuit
+                    .into_iter()
+                    .filter(|root| root.degree() > target_degree)
+                    .collect_vec();
+                let search_candidates = Self::exhaustive_search_candidates(&high_degree_roots);
+                if search_candidates.len()
+                    > DegreeLoweringStrategy::MAX_NUM_EXHAUSTIVE_SEARCH_CANDIDATES
+                {
+                    let most_shared_nodes = Self::most_occurring_nodes(low_degree_nodes);
+                    Self::highest_degree_nodes(most_shared_nodes)
+                } else {
+                    Self::cheapest_substitution(
+                        &high_degree_roots,
+                        search_candidates,
+                        target_degree,
+                    )
+                    .into_iter()
+                    .filter(|node| node.degree() <= target_degree)
+                    .collect()
+                }
+            }
+        };
 
         // If there are still multiple nodes, pick any one – but deterministically so.
         candidate_nodes.sort_by_key(|node| node.id);
         candidate_nodes[0].id
     }
 
+    /// Of the given nodes, keep the ones occurring the most often, removing duplicates.
+    fn most_occurring_nodes(nodes: Vec<ConstraintCircuit<II>>) -> Vec<ConstraintCircuit<II>> {
+        let mut nodes_and_occurrences = nodes.into_iter().counts();
+        let max_occurrences = nodes_and_occurrences.values().copied().max().unwrap();
+        nodes_and_occurrences.retain(|_, &mut count| count == max_occurrences);
+        nodes_and_occurrences.into_keys().collect()
+    }
+
+    /// Of the given nodes, keep the ones with the highest degree.
+    fn highest_degree_nodes(mut nodes: Vec<ConstraintCircuit<II>>) -> Vec<ConstraintCircuit<II>> {
+        let max_degree = nodes.iter().map(|n| n.degree()).max().unwrap();
+        nodes.retain(|node| node.degree() == max_degree);
+        nodes
+    }
+
+    /// All nodes that might be worth substituting when lowering the degree of the given roots,
+    /// without duplicates and sorted by ID.
+    fn exhaustive_search_candidates(
+        high_degree_roots: &[ConstraintCircuit<II>],
+    ) -> Vec<ConstraintCircuit<II>> {
+        Self::all_nodes_in_multicircuit(high_degree_roots)
+            .into_iter()
+            .filter(|node| node.degree() > 1)
+            .unique_by(|node| node.id)
+            .sorted_by_key(|node| node.id)
+            .collect()
+    }
+
+    /// Find the set of candidate nodes whose substitution lowers the degree of all given roots to
+    /// the target degree and requires the fewest new columns. Panics if there is no such set.
+    fn cheapest_substitution(
+        high_degree_roots: &[ConstraintCircuit<II>],
+        candidates: Vec<ConstraintCircuit<II>>,
+        target_degree: isize,
+    ) -> Vec<ConstraintCircuit<II>> {
+        let num_candidates = candidates.len();
+        let column_cost = |node: &ConstraintCircuit<II>| match node.evaluates_to_base_element() {
+            true => 1,
+            false => EXTENSION_DEGREE,
+        };
+        let costs = candidates.iter().map(column_cost).collect_vec();
+        let is_chosen = |selection: u32, i: usize| (selection >> i) & 1 == 1;
+
+        let mut cheapest_selection: Option<(usize, u32)> = None;
+        for selection in 0..(1_u32 << num_candidates) {
+            let cost: usize = (0..num_candidates)
+                .filter(|&i| is_chosen(selection, i))
+                .map(|i| costs[i])
+                .sum();
+            if cheapest_selection.is_some_and(|(cheapest_cost, _)| cheapest_cost <= cost) {
+                continue;
+            }
+
+            let substituted_nodes = (0..num_candidates)
+                .filter(|&i| is_chosen(selection, i))
+                .map(|i| &candidates[i])
+                .collect_vec();
+            if Self::substitution_lowers_degree(
+                high_degree_roots,
+                &substituted_nodes,
+                target_degree,
+            ) {
+                cheapest_selection = Some((cost, selection));
+            }
+        }
+
+        let Some((_, cheapest_selection)) = cheapest_selection else {
+            panic!("Cannot lower degree.");
+        };
+        candidates
+            .into_iter()
+            .enumerate()
+            .filter(|&(i, _)| is_chosen(cheapest_selection, i))
+            .map(|(_, node)| node)
+            .collect()
+    }
+
+    /// Whether substituting the given nodes with new variables lowers the degree of both the
+    /// given roots and the substitution rules of all substituted nodes to the target degree.
+    fn substitution_lowers_degree(
+        roots: &[ConstraintCircuit<II>],
+        substituted_nodes: &[&ConstraintCircuit<II>],
+        target_degree: isize,
+    ) -> bool {
+        let substituted_ids: HashSet<_> = substituted_nodes.iter().map(|node| node.id).collect();
+        let mut expression_degrees = HashMap::new();
+        let mut degree_of_root =
+            |root| Self::degree_after_substitution(root, &substituted_ids, &mut expression_degrees);
+        if roots
+            .iter()
+            .any(|root| degree_of_root(root) > target_degree)
+        {
+            return false;
+        }
+
+        substituted_nodes.iter().all(|node| {
+            let degree = Self::expression_degree_after_substitution(
+                node,
+                &substituted_ids,
+                &mut expression_degrees,
+            );
+            degree <= target_degree
+        })
+    }
+
+    /// The degree of the given node once all nodes with the given IDs are substituted by new
+    /// variables.
+    fn degree_after_substitution(
+        node: &ConstraintCircuit<II>,
+        substituted_ids: &HashSet<usize>,
+        expression_degrees: &mut HashMap<usize, isize>,
+    ) -> isize {
+        if substituted_ids.contains(&node.id) {
+            return 1;
+        }
+        Self::expression_degree_after_substitution(node, substituted_ids, expression_degrees)
+    }
+
+    /// Like [`degree_after_substitution`](Self::degree_after_substitution), but disregarding
+    /// whether the given node itself is substituted. Mirrors [`ConstraintCircuit::degree`].
+    fn expression_degree_after_substitution(
+        node: &ConstraintCircuit<II>,
+        substituted_ids: &HashSet<usize>,
+        expression_degrees: &mut HashMap<usize, isize>,
+    ) -> isize {
+        if let Some(&degree) = expression_degrees.get(&node.id) {
+            return degree;
+        }
+        if node.is_zero() {
+            return -1;
+        }
+
+        let degree = match &node.expression {
+            BinaryOperation(binop, lhs, rhs) => {
+                let degree_lhs = Self::degree_after_substitution(
+                    &lhs.borrow(),
+                    substituted_ids,
+                    expression_degrees,
+                );
+                let degree_rhs = Self::degree_after_substitution(
+                    &rhs.borrow(),
+                    substituted_ids,
+                    expression_degrees,
+                );
+                let degree_additive = cmp::max(degree_lhs, degree_rhs);
+                let degree_multiplicative = match degree_lhs == -1 || degree_rhs == -1 {
+                    true => -1,
+                    false => degree_lhs + degree_rhs,
+                };
+                match binop {
+                    BinOp::Add => degree_additive,
+                    BinOp::Mul => degree_multiplicative,
+                }
+            }
+            Input(_) => 1,
+            BConstant(_) | XConstant(_) | Challenge(_) => 0,
+        };
+        expression_degrees.insert(node.id, degree);
+        degree
+    }
+
     /// Returns all nodes used in the multicircuit.
     /// This is distinct from `ConstraintCircuitBuilder::all_nodes` because it
     /// 1. only considers nodes used in the given multicircuit, not all nodes in the builder,
@@ -1130,6 +1369,7 @@ mod tests {
     use rand::rngs::StdRng;
     use rand::Rng;
     use rand::SeedableRng;
+    use strum::IntoEnumIterator;
     use test_strategy::proptest;
 
     use crate::table::cascade_table::ExtCascadeTable;
@@ -1539,6 +1779,46 @@ mod tests {
         assert!(new_ext_constraints.is_empty());
     }
 
+    #[test]
+    fn degree_lowering_with_any_strategy_has_expected_properties() {
+        for strategy in DegreeLoweringStrategy::iter() {
+            let builder = ConstraintCircuitBuilder::new();
+            let x = |i| builder.input(BaseRow(i));
+            let constraint_0 = (x(0) * x(1) * x(2)) * (x(3) * x(4)) * x(5);
+            let constraint_1 = (x(6) * x(7)) * (x(3) * x(4)) * x(8);
+            let mut multicircuit = [constraint_0, constraint_1];
+            lower_degree_with_strategy_and_assert_properties(&mut multicircuit, strategy, 3, 9, 0);
+        }
+    }
+
+    #[test]
+    fn exhaustive_degree_lowering_prefers_base_columns_over_shared_extension_column() {
+        let lower_degree = |strategy| {
+            let builder = ConstraintCircuitBuilder::new();
+            let x = |i| builder.input(BaseRow(i));
+            let y = |i| builder.input(ExtRow(i));
+            let constraint_0 = (x(0) * x(1)) * (x(2) * y(0));
+            let constraint_1 = (x(2) * y(0)) * (x(3) * x(4));
+            let mut multicircuit = [constraint_0, constraint_1];
+            let (new_base_constraints, new_ext_constraints) =
+                lower_degree_with_strategy_and_assert_properties(
+                    &mut multicircuit,
+                    strategy,
+                    3,
+                    5,
+                    1,
+                );
+            (new_base_constraints.len(), new_ext_constraints.len())
+        };
+        let cost = |(num_base_cols, num_ext_cols)| num_base_cols + EXTENSION_DEGREE * num_ext_cols;
+
+        let exhaustive = lower_degree(DegreeLoweringStrategy::Exhaustive);
+        assert_eq!((2, 0), exhaustive);
+
+        let most_shared_first = lower_degree(DegreeLoweringStrategy::MostSharedFirst);
+        assert!(cost(exhaustive) < cost(most_shared_first));
+    }
+
     #[test]
     fn program_table_initial_constraints_degree_lowering() {
         lower_degree_and_assert_properties(
@@ -1914,6 +2194,26 @@ mod tests {
     ) -> (
         Vec<ConstraintCircuitMonad<II>>,
         Vec<ConstraintCircuitMonad<II>>,
+    ) {
+        lower_degree_with_strategy_and_assert_properties(
+            multicircuit,
+            DegreeLoweringStrategy::default(),
+            target_deg,
+            num_base_cols,
+            num_ext_cols,
+        )
+    }
+
+    /// Like [`lower_degree_and_assert_properties`], but using the given strategy.
+    fn lower_degree_with_strategy_and_assert_properties<II: InputIndicator>(
+        multicircuit: &mut [ConstraintCircuitMonad<II>],
+        strategy: DegreeLoweringStrategy,
+        target_deg: isize,
+        num_base_cols: usize,
+        num_ext_cols: usize,
+    ) -> (
+        Vec<ConstraintCircuitMonad<II>>,
+        Vec<ConstraintCircuitMonad<II>>,
     ) {
         let seed = random();
         let mut rng = StdRng::seed_from_u64(seed);
@@ -1925,12 +2225,14 @@ mod tests {
             println!("  {circuit}");
         }
 
-        let (new_base_constraints, new_ext_constraints) = ConstraintCircuitMonad::lower_to_degree(
-            multicircuit,
-            target_deg,
-            num_base_cols,
-            num_ext_cols,
-        );
+        let (new_base_constraints, new_ext_constraints) =
+            ConstraintCircuitMonad::lower_to_degree_with_strategy(
+                multicircuit,
+                strategy,
+                target_deg,
+                num_base_cols,
+                num_ext_cols,
+            );
 
         assert_eq!(num_constraints, multicircuit.len());
         assert!(ConstraintCircuitMonad::multicircuit_degree(multicircuit) <= target_deg);
@@ -1,112 +1,12325 @@
-//! This file is a placeholder for auto-generated code
-//! Run `cargo run --bin constraint-evaluation-generator`
-//! to fill in this file with optimized constraints.
-
-use crate::table::challenges::Challenges;
 use ndarray::ArrayView1;
 use twenty_first::prelude::BFieldElement;
 use twenty_first::prelude::XFieldElement;
-
+use crate::table::challenges::Challenges;
 use crate::table::extension_table::Evaluable;
 use crate::table::extension_table::Quotientable;
 use crate::table::master_table::MasterExtTable;
-
-pub(crate) const ERROR_MESSAGE_GENERATE_CONSTRAINTS: &str =
-    "Constraints must be in place. Run: `cargo run --bin constraint-evaluation-generator`";
-const ERROR_MESSAGE_GENERATE_DEGREE_BOUNDS: &str =
-    "Degree bounds must be in place. Run: `cargo run --bin constraint-evaluation-generator`";
-
 impl Evaluable<BFieldElement> for MasterExtTable {
+    #[allow(unused_variables)]
     fn evaluate_initial_constraints(
-        _: ArrayView1<BFieldElement>,
-        _: ArrayView1<XFieldElement>,
-        _: &Challenges,
+        base_row: ArrayView1<BFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        panic!("{ERROR_MESSAGE_GENERATE_CONSTRAINTS}")
+        let node_468 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[129usize]));
+        let node_474 = ((challenges[52usize]) * (base_row[131usize]))
+            + ((challenges[53usize]) * (base_row[133usize]));
+        let node_477 = ((challenges[52usize]) * (base_row[130usize]))
+            + ((challenges[53usize]) * (base_row[132usize]));
+        let base_constraints = [
+            base_row[0usize],
+            base_row[3usize],
+            base_row[5usize],
+            base_row[7usize],
+            base_row[9usize],
+            base_row[19usize],
+            base_row[20usize],
+            base_row[21usize],
+            base_row[22usize],
+            base_row[23usize],
+            base_row[24usize],
+            base_row[25usize],
+            base_row[26usize],
+            base_row[27usize],
+            base_row[28usize],
+            base_row[29usize],
+            base_row[30usize],
+            base_row[31usize],
+            base_row[32usize],
+            (base_row[38usize]) + (BFieldElement::from_raw_u64(18446744000695107601u64)),
+            (base_row[48usize]) + (BFieldElement::from_raw_u64(18446744000695107601u64)),
+            base_row[55usize],
+            base_row[57usize],
+            base_row[59usize],
+            base_row[60usize],
+            base_row[61usize],
+            (base_row[62usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            base_row[64usize],
+            base_row[136usize],
+            (base_row[149usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((((base_row[12usize])
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+                        * (base_row[13usize]))
+                        * ((base_row[14usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                        * ((base_row[15usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))),
+            (base_row[150usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((base_row[149usize]) * (base_row[16usize]))
+                        * ((base_row[17usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                        * ((base_row[18usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))),
+        ];
+        let ext_constraints = [
+            ext_row[0usize],
+            ((ext_row[1usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (challenges[29usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (base_row[1usize])),
+            (ext_row[2usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((((((((((challenges[0usize]) + (base_row[33usize])) * (challenges[0usize]))
+                + (base_row[34usize])) * (challenges[0usize])) + (base_row[35usize]))
+                * (challenges[0usize])) + (base_row[36usize])) * (challenges[0usize]))
+                + (base_row[37usize]))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (challenges[62usize])),
+            (ext_row[3usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[5usize])
+                * ((challenges[3usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[14usize]) * (base_row[10usize]))
+                            + ((challenges[15usize]) * (base_row[11usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            (ext_row[4usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            (ext_row[6usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            (ext_row[7usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            (ext_row[8usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((challenges[9usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((challenges[25usize]) * (base_row[10usize]))))),
+            ((ext_row[13usize]) * (challenges[11usize]))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (base_row[45usize])),
+            (((base_row[10usize])
+                + (BFieldElement::from_raw_u64(18446743992105173011u64)))
+                * ((ext_row[9usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((base_row[150usize])
+                    * ((ext_row[9usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (challenges[4usize])))),
+            (ext_row[10usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            (ext_row[11usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ext_row[12usize],
+            (((ext_row[14usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((challenges[7usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((((challenges[16usize]) * (base_row[46usize]))
+                                + ((challenges[17usize]) * (base_row[47usize])))
+                                + ((challenges[18usize])
+                                    * (BFieldElement::from_raw_u64(68719476720u64))))
+                                + ((challenges[19usize]) * (base_row[49usize])))))))
+                * ((base_row[47usize])
+                    + (BFieldElement::from_raw_u64(18446744060824649731u64))))
+                + (((ext_row[14usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+                    * ((base_row[47usize])
+                        * ((base_row[47usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))),
+            ext_row[15usize],
+            ext_row[18usize],
+            (ext_row[19usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (base_row[56usize])),
+            ((ext_row[16usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (challenges[12usize]))) + (base_row[52usize]),
+            (ext_row[17usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((((ext_row[20usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (challenges[8usize])))
+                + (((((base_row[50usize]) * (challenges[20usize]))
+                    + ((base_row[51usize]) * (challenges[23usize])))
+                    + ((base_row[52usize]) * (challenges[21usize])))
+                    + ((base_row[53usize]) * (challenges[22usize]))))
+                * ((base_row[51usize])
+                    + (BFieldElement::from_raw_u64(18446744060824649731u64))))
+                + (((ext_row[20usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+                    * (((base_row[51usize])
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+                        * (base_row[51usize]))),
+            ext_row[21usize],
+            (ext_row[22usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((challenges[9usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((challenges[25usize]) * (base_row[58usize]))))),
+            ext_row[23usize],
+            (ext_row[25usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            (ext_row[26usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            (ext_row[27usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[24usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (challenges[30usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((((((((((((((((((challenges[29usize])
+                        + ((((((base_row[65usize])
+                            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+                            + ((base_row[66usize])
+                                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+                            + ((base_row[67usize])
+                                * (BFieldElement::from_raw_u64(281474976645120u64))))
+                            + (base_row[68usize]))
+                            * (BFieldElement::from_raw_u64(1u64))))
+                        * (challenges[29usize]))
+                        + ((((((base_row[69usize])
+                            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+                            + ((base_row[70usize])
+                                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+                            + ((base_row[71usize])
+                                * (BFieldElement::from_raw_u64(281474976645120u64))))
+                            + (base_row[72usize]))
+                            * (BFieldElement::from_raw_u64(1u64))))
+                        * (challenges[29usize]))
+                        + ((((((base_row[73usize])
+                            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+                            + ((base_row[74usize])
+                                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+                            + ((base_row[75usize])
+                                * (BFieldElement::from_raw_u64(281474976645120u64))))
+                            + (base_row[76usize]))
+                            * (BFieldElement::from_raw_u64(1u64))))
+                        * (challenges[29usize]))
+                        + ((((((base_row[77usize])
+                            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+                            + ((base_row[78usize])
+                                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+                            + ((base_row[79usize])
+                                * (BFieldElement::from_raw_u64(281474976645120u64))))
+                            + (base_row[80usize]))
+                            * (BFieldElement::from_raw_u64(1u64))))
+                        * (challenges[29usize])) + (base_row[97usize]))
+                        * (challenges[29usize])) + (base_row[98usize]))
+                        * (challenges[29usize])) + (base_row[99usize]))
+                        * (challenges[29usize])) + (base_row[100usize]))
+                        * (challenges[29usize])) + (base_row[101usize]))
+                        * (challenges[29usize])) + (base_row[102usize]))),
+            ((ext_row[28usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[65usize]))
+                            + ((challenges[50usize]) * (base_row[81usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[29usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[66usize]))
+                            + ((challenges[50usize]) * (base_row[82usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[30usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[67usize]))
+                            + ((challenges[50usize]) * (base_row[83usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[31usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[68usize]))
+                            + ((challenges[50usize]) * (base_row[84usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[32usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[69usize]))
+                            + ((challenges[50usize]) * (base_row[85usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[33usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[70usize]))
+                            + ((challenges[50usize]) * (base_row[86usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[34usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[71usize]))
+                            + ((challenges[50usize]) * (base_row[87usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[35usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[72usize]))
+                            + ((challenges[50usize]) * (base_row[88usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[36usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[73usize]))
+                            + ((challenges[50usize]) * (base_row[89usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[37usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[74usize]))
+                            + ((challenges[50usize]) * (base_row[90usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[38usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[75usize]))
+                            + ((challenges[50usize]) * (base_row[91usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[39usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[76usize]))
+                            + ((challenges[50usize]) * (base_row[92usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[40usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[77usize]))
+                            + ((challenges[50usize]) * (base_row[93usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[41usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[78usize]))
+                            + ((challenges[50usize]) * (base_row[94usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[42usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[79usize]))
+                            + ((challenges[50usize]) * (base_row[95usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[43usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[80usize]))
+                            + ((challenges[50usize]) * (base_row[96usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((node_468)
+                * (((ext_row[44usize])
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize])
+                                * (((BFieldElement::from_raw_u64(1099511627520u64))
+                                    * (base_row[130usize])) + (base_row[131usize])))
+                                + ((challenges[50usize])
+                                    * (((BFieldElement::from_raw_u64(1099511627520u64))
+                                        * (base_row[132usize])) + (base_row[133usize])))))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (base_row[134usize]))))
+                + ((base_row[129usize]) * (ext_row[44usize])),
+            ((node_468)
+                * ((((((ext_row[45usize])
+                    * ((challenges[51usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_474))))
+                    * ((challenges[51usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_477))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((BFieldElement::from_raw_u64(8589934590u64))
+                            * (challenges[51usize])))) + (node_474)) + (node_477)))
+                + ((base_row[129usize]) * (ext_row[45usize])),
+            ((ext_row[46usize])
+                * ((challenges[51usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((base_row[137usize]) * (challenges[53usize])))))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (base_row[138usize])),
+            ((ext_row[47usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (challenges[54usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (base_row[137usize])),
+            (((base_row[139usize])
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+                * (ext_row[48usize]))
+                + ((base_row[139usize])
+                    * (((ext_row[48usize])
+                        * ((challenges[10usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((((challenges[55usize]) * (base_row[143usize]))
+                                    + ((challenges[56usize]) * (base_row[145usize])))
+                                    + ((challenges[57usize]) * (base_row[142usize])))
+                                    + ((challenges[58usize]) * (base_row[147usize]))))))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (base_row[148usize])))),
+        ];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints)
+            .collect()
     }
-
+    #[allow(unused_variables)]
     fn evaluate_consistency_constraints(
-        _: ArrayView1<BFieldElement>,
-        _: ArrayView1<XFieldElement>,
-        _: &Challenges,
+        base_row: ArrayView1<BFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        panic!("{ERROR_MESSAGE_GENERATE_CONSTRAINTS}")
+        let node_102 = (base_row[152usize])
+            * ((base_row[64usize])
+                + (BFieldElement::from_raw_u64(18446744047939747846u64)));
+        let node_221 = (base_row[153usize])
+            * ((base_row[64usize])
+                + (BFieldElement::from_raw_u64(18446744047939747846u64)));
+        let node_238 = ((base_row[154usize])
+            * ((base_row[64usize])
+                + (BFieldElement::from_raw_u64(18446744052234715141u64))))
+            * ((base_row[64usize])
+                + (BFieldElement::from_raw_u64(18446744047939747846u64)));
+        let node_245 = ((base_row[154usize])
+            * ((base_row[64usize])
+                + (BFieldElement::from_raw_u64(18446744056529682436u64))))
+            * ((base_row[64usize])
+                + (BFieldElement::from_raw_u64(18446744047939747846u64)));
+        let node_655 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[157usize]));
+        let node_114 = (((base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743992105173011u64)))
+            * ((base_row[63usize])
+                + (BFieldElement::from_raw_u64(18446743923385696291u64))))
+            * ((base_row[63usize])
+                + (BFieldElement::from_raw_u64(18446743863256154161u64)));
+        let node_116 = (node_102) * (base_row[161usize]);
+        let node_660 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[160usize]));
+        let node_101 = (base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744047939747846u64));
+        let node_678 = (base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446743949155500061u64));
+        let node_674 = (base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446743940565565471u64));
+        let node_94 = (base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744056529682436u64));
+        let node_97 = (base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744052234715141u64));
+        let node_153 = ((((BFieldElement::from_raw_u64(18446744065119617025u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((base_row[65usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[66usize]))) * (base_row[109usize]))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_155 = ((((BFieldElement::from_raw_u64(18446744065119617025u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((base_row[69usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[70usize]))) * (base_row[110usize]))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_157 = ((((BFieldElement::from_raw_u64(18446744065119617025u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((base_row[73usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[74usize]))) * (base_row[111usize]))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_159 = ((((BFieldElement::from_raw_u64(18446744065119617025u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((base_row[77usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[78usize]))) * (base_row[112usize]))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_680 = (base_row[139usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_90 = (base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744060824649731u64));
+        let node_670 = (base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446744017874976781u64));
+        let node_11 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (((BFieldElement::from_raw_u64(38654705655u64))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (base_row[3usize]))) * (base_row[4usize])));
+        let node_8 = (BFieldElement::from_raw_u64(38654705655u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[3usize]));
+        let node_104 = (((base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+            * ((base_row[62usize])
+                + (BFieldElement::from_raw_u64(18446744060824649731u64))))
+            * ((base_row[62usize])
+                + (BFieldElement::from_raw_u64(18446744056529682436u64)));
+        let node_85 = (base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744060824649731u64));
+        let node_73 = (base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743992105173011u64));
+        let node_79 = (base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743923385696291u64));
+        let node_82 = (base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743863256154161u64));
+        let node_126 = ((BFieldElement::from_raw_u64(18446744065119617025u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((base_row[65usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[66usize]));
+        let node_133 = ((BFieldElement::from_raw_u64(18446744065119617025u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((base_row[69usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[70usize]));
+        let node_140 = ((BFieldElement::from_raw_u64(18446744065119617025u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((base_row[73usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[74usize]));
+        let node_147 = ((BFieldElement::from_raw_u64(18446744065119617025u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((base_row[77usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[78usize]));
+        let node_89 = (base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_663 = (base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446744052234715141u64));
+        let node_666 = (base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446744009285042191u64));
+        let node_86 = ((base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64))) * (node_85);
+        let node_83 = (base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_103 = (base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744056529682436u64));
+        let base_constraints = [
+            (node_11) * (base_row[4usize]),
+            (node_11) * (node_8),
+            (base_row[5usize])
+                * ((base_row[5usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[6usize])
+                * ((base_row[6usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[12usize])
+                * ((base_row[12usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[13usize])
+                * ((base_row[13usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[14usize])
+                * ((base_row[14usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[15usize])
+                * ((base_row[15usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[16usize])
+                * ((base_row[16usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[17usize])
+                * ((base_row[17usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[18usize])
+                * ((base_row[18usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[8usize])
+                * ((base_row[8usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[10usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((((((base_row[12usize])
+                        + ((BFieldElement::from_raw_u64(8589934590u64))
+                            * (base_row[13usize])))
+                        + ((BFieldElement::from_raw_u64(17179869180u64))
+                            * (base_row[14usize])))
+                        + ((BFieldElement::from_raw_u64(34359738360u64))
+                            * (base_row[15usize])))
+                        + ((BFieldElement::from_raw_u64(68719476720u64))
+                            * (base_row[16usize])))
+                        + ((BFieldElement::from_raw_u64(137438953440u64))
+                            * (base_row[17usize])))
+                        + ((BFieldElement::from_raw_u64(274877906880u64))
+                            * (base_row[18usize])))),
+            ((base_row[8usize])
+                * ((base_row[7usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                * (base_row[45usize]),
+            (node_104) * (base_row[62usize]),
+            (node_85) * (node_73),
+            ((base_row[165usize]) * (node_79)) * (node_82),
+            (node_104) * (base_row[64usize]),
+            (node_114) * (base_row[64usize]),
+            (node_153) * (base_row[109usize]),
+            (node_155) * (base_row[110usize]),
+            (node_157) * (base_row[111usize]),
+            (node_159) * (base_row[112usize]),
+            (node_153) * (node_126),
+            (node_155) * (node_133),
+            (node_157) * (node_140),
+            (node_159) * (node_147),
+            (node_153)
+                * (((base_row[67usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))
+                    + (base_row[68usize])),
+            (node_155)
+                * (((base_row[71usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))
+                    + (base_row[72usize])),
+            (node_157)
+                * (((base_row[75usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))
+                    + (base_row[76usize])),
+            (node_159)
+                * (((base_row[79usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))
+                    + (base_row[80usize])),
+            (node_114) * (base_row[103usize]),
+            (node_114) * (base_row[104usize]),
+            (node_114) * (base_row[105usize]),
+            (node_114) * (base_row[106usize]),
+            (node_114) * (base_row[107usize]),
+            (node_114) * (base_row[108usize]),
+            (node_116)
+                * ((base_row[103usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (node_116)
+                * ((base_row[104usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (node_116)
+                * ((base_row[105usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (node_116)
+                * ((base_row[106usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (node_116)
+                * ((base_row[107usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (node_116)
+                * ((base_row[108usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (((((node_102)
+                * ((base_row[113usize])
+                    + (BFieldElement::from_raw_u64(11408918724931329738u64))))
+                + ((node_221)
+                    * ((base_row[113usize])
+                        + (BFieldElement::from_raw_u64(16073625066478178581u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[113usize])
+                        + (BFieldElement::from_raw_u64(12231462398569191607u64)))))
+                + ((node_238)
+                    * ((base_row[113usize])
+                        + (BFieldElement::from_raw_u64(9408518518620565480u64)))))
+                + ((node_245)
+                    * ((base_row[113usize])
+                        + (BFieldElement::from_raw_u64(11492978409391175103u64)))),
+            (((((node_102)
+                * ((base_row[114usize])
+                    + (BFieldElement::from_raw_u64(2786462832312611053u64))))
+                + ((node_221)
+                    * ((base_row[114usize])
+                        + (BFieldElement::from_raw_u64(11837051899140380443u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[114usize])
+                        + (BFieldElement::from_raw_u64(11546487907579866869u64)))))
+                + ((node_238)
+                    * ((base_row[114usize])
+                        + (BFieldElement::from_raw_u64(1785884128667671832u64)))))
+                + ((node_245)
+                    * ((base_row[114usize])
+                        + (BFieldElement::from_raw_u64(17615222217495663839u64)))),
+            (((((node_102)
+                * ((base_row[115usize])
+                    + (BFieldElement::from_raw_u64(6782977121958050999u64))))
+                + ((node_221)
+                    * ((base_row[115usize])
+                        + (BFieldElement::from_raw_u64(15625104599191418968u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[115usize])
+                        + (BFieldElement::from_raw_u64(14006427992450931468u64)))))
+                + ((node_238)
+                    * ((base_row[115usize])
+                        + (BFieldElement::from_raw_u64(1188899344229954938u64)))))
+                + ((node_245)
+                    * ((base_row[115usize])
+                        + (BFieldElement::from_raw_u64(5864349944556149748u64)))),
+            (((((node_102)
+                * ((base_row[116usize])
+                    + (BFieldElement::from_raw_u64(8688421733879975670u64))))
+                + ((node_221)
+                    * ((base_row[116usize])
+                        + (BFieldElement::from_raw_u64(12819157612210448391u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[116usize])
+                        + (BFieldElement::from_raw_u64(11770003398407723041u64)))))
+                + ((node_238)
+                    * ((base_row[116usize])
+                        + (BFieldElement::from_raw_u64(14740727267735052728u64)))))
+                + ((node_245)
+                    * ((base_row[116usize])
+                        + (BFieldElement::from_raw_u64(2745609811140253793u64)))),
+            (((((node_102)
+                * ((base_row[117usize])
+                    + (BFieldElement::from_raw_u64(8602724563769480463u64))))
+                + ((node_221)
+                    * ((base_row[117usize])
+                        + (BFieldElement::from_raw_u64(6235256903503367222u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[117usize])
+                        + (BFieldElement::from_raw_u64(15124190001489436038u64)))))
+                + ((node_238)
+                    * ((base_row[117usize])
+                        + (BFieldElement::from_raw_u64(880257844992994007u64)))))
+                + ((node_245)
+                    * ((base_row[117usize])
+                        + (BFieldElement::from_raw_u64(15189664869386394185u64)))),
+            (((((node_102)
+                * ((base_row[118usize])
+                    + (BFieldElement::from_raw_u64(13589155570211330507u64))))
+                + ((node_221)
+                    * ((base_row[118usize])
+                        + (BFieldElement::from_raw_u64(11242082964257948320u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[118usize])
+                        + (BFieldElement::from_raw_u64(14834674155811570980u64)))))
+                + ((node_238)
+                    * ((base_row[118usize])
+                        + (BFieldElement::from_raw_u64(10737952517017171197u64)))))
+                + ((node_245)
+                    * ((base_row[118usize])
+                        + (BFieldElement::from_raw_u64(5192963426821415349u64)))),
+            (((((node_102)
+                * ((base_row[119usize])
+                    + (BFieldElement::from_raw_u64(10263462378312899510u64))))
+                + ((node_221)
+                    * ((base_row[119usize])
+                        + (BFieldElement::from_raw_u64(5820425254787221108u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[119usize])
+                        + (BFieldElement::from_raw_u64(13004675752386552573u64)))))
+                + ((node_238)
+                    * ((base_row[119usize])
+                        + (BFieldElement::from_raw_u64(15757222735741919824u64)))))
+                + ((node_245)
+                    * ((base_row[119usize])
+                        + (BFieldElement::from_raw_u64(11971160388083607515u64)))),
+            (((((node_102)
+                * ((base_row[120usize])
+                    + (BFieldElement::from_raw_u64(3264875873073042616u64))))
+                + ((node_221)
+                    * ((base_row[120usize])
+                        + (BFieldElement::from_raw_u64(12019227591549292608u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[120usize])
+                        + (BFieldElement::from_raw_u64(1475232519215872482u64)))))
+                + ((node_238)
+                    * ((base_row[120usize])
+                        + (BFieldElement::from_raw_u64(14382578632612566479u64)))))
+                + ((node_245)
+                    * ((base_row[120usize])
+                        + (BFieldElement::from_raw_u64(11608544217838050708u64)))),
+            (((((node_102)
+                * ((base_row[121usize])
+                    + (BFieldElement::from_raw_u64(3133435276616064683u64))))
+                + ((node_221)
+                    * ((base_row[121usize])
+                        + (BFieldElement::from_raw_u64(4625353063880731092u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[121usize])
+                        + (BFieldElement::from_raw_u64(4883869161905122316u64)))))
+                + ((node_238)
+                    * ((base_row[121usize])
+                        + (BFieldElement::from_raw_u64(3305272539067787726u64)))))
+                + ((node_245)
+                    * ((base_row[121usize])
+                        + (BFieldElement::from_raw_u64(674972795234232729u64)))),
+            (((((node_102)
+                * ((base_row[122usize])
+                    + (BFieldElement::from_raw_u64(13508500531157332153u64))))
+                + ((node_221)
+                    * ((base_row[122usize])
+                        + (BFieldElement::from_raw_u64(3723900760706330287u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[122usize])
+                        + (BFieldElement::from_raw_u64(12579737103870920763u64)))))
+                + ((node_238)
+                    * ((base_row[122usize])
+                        + (BFieldElement::from_raw_u64(17082569335437832789u64)))))
+                + ((node_245)
+                    * ((base_row[122usize])
+                        + (BFieldElement::from_raw_u64(14165256104883557753u64)))),
+            (((((node_102)
+                * ((base_row[123usize])
+                    + (BFieldElement::from_raw_u64(6968886508437513677u64))))
+                + ((node_221)
+                    * ((base_row[123usize])
+                        + (BFieldElement::from_raw_u64(615596267195055952u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[123usize])
+                        + (BFieldElement::from_raw_u64(10119826060478909841u64)))))
+                + ((node_238)
+                    * ((base_row[123usize])
+                        + (BFieldElement::from_raw_u64(229051680548583225u64)))))
+                + ((node_245)
+                    * ((base_row[123usize])
+                        + (BFieldElement::from_raw_u64(15283356519694111298u64)))),
+            (((((node_102)
+                * ((base_row[124usize])
+                    + (BFieldElement::from_raw_u64(9713264609690967820u64))))
+                + ((node_221)
+                    * ((base_row[124usize])
+                        + (BFieldElement::from_raw_u64(18227830850447556704u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[124usize])
+                        + (BFieldElement::from_raw_u64(1528714547662620921u64)))))
+                + ((node_238)
+                    * ((base_row[124usize])
+                        + (BFieldElement::from_raw_u64(2943254981416254648u64)))))
+                + ((node_245)
+                    * ((base_row[124usize])
+                        + (BFieldElement::from_raw_u64(2306049938060341466u64)))),
+            (((((node_102)
+                * ((base_row[125usize])
+                    + (BFieldElement::from_raw_u64(12482374976099749513u64))))
+                + ((node_221)
+                    * ((base_row[125usize])
+                        + (BFieldElement::from_raw_u64(15609691041895848348u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[125usize])
+                        + (BFieldElement::from_raw_u64(12972275929555275935u64)))))
+                + ((node_238)
+                    * ((base_row[125usize])
+                        + (BFieldElement::from_raw_u64(5767629304344025219u64)))))
+                + ((node_245)
+                    * ((base_row[125usize])
+                        + (BFieldElement::from_raw_u64(11578793764462375094u64)))),
+            (((((node_102)
+                * ((base_row[126usize])
+                    + (BFieldElement::from_raw_u64(13209711277645656680u64))))
+                + ((node_221)
+                    * ((base_row[126usize])
+                        + (BFieldElement::from_raw_u64(15235800289984546486u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[126usize])
+                        + (BFieldElement::from_raw_u64(15992731669612695172u64)))))
+                + ((node_238)
+                    * ((base_row[126usize])
+                        + (BFieldElement::from_raw_u64(16721422493821450473u64)))))
+                + ((node_245)
+                    * ((base_row[126usize])
+                        + (BFieldElement::from_raw_u64(7511767364422267184u64)))),
+            (((((node_102)
+                * ((base_row[127usize])
+                    + (BFieldElement::from_raw_u64(87705059284758253u64))))
+                + ((node_221)
+                    * ((base_row[127usize])
+                        + (BFieldElement::from_raw_u64(11392407538241985753u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[127usize])
+                        + (BFieldElement::from_raw_u64(17877154195438905917u64)))))
+                + ((node_238)
+                    * ((base_row[127usize])
+                        + (BFieldElement::from_raw_u64(5753720429376839714u64)))))
+                + ((node_245)
+                    * ((base_row[127usize])
+                        + (BFieldElement::from_raw_u64(16999805755930336630u64)))),
+            (((((node_102)
+                * ((base_row[128usize])
+                    + (BFieldElement::from_raw_u64(330155256278907084u64))))
+                + ((node_221)
+                    * ((base_row[128usize])
+                        + (BFieldElement::from_raw_u64(11776128816341368822u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[128usize])
+                        + (BFieldElement::from_raw_u64(939319986782105612u64)))))
+                + ((node_238)
+                    * ((base_row[128usize])
+                        + (BFieldElement::from_raw_u64(2063756830275051942u64)))))
+                + ((node_245)
+                    * ((base_row[128usize])
+                        + (BFieldElement::from_raw_u64(940614108343834936u64)))),
+            (base_row[129usize])
+                * ((BFieldElement::from_raw_u64(4294967295u64))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (base_row[129usize]))),
+            (base_row[135usize])
+                * ((BFieldElement::from_raw_u64(4294967295u64))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (base_row[135usize]))),
+            (base_row[139usize])
+                * ((BFieldElement::from_raw_u64(4294967295u64))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (base_row[139usize]))),
+            (base_row[139usize]) * (base_row[140usize]),
+            (BFieldElement::from_raw_u64(4294967295u64))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((base_row[141usize])
+                        * ((base_row[140usize])
+                            + (BFieldElement::from_raw_u64(18446743927680663586u64))))),
+            (base_row[144usize]) * (node_655),
+            (base_row[143usize]) * (node_655),
+            (base_row[146usize]) * (node_660),
+            (base_row[145usize]) * (node_660),
+            (base_row[167usize])
+                * ((base_row[147usize])
+                    + (BFieldElement::from_raw_u64(18446744060824649731u64))),
+            (base_row[168usize]) * (base_row[147usize]),
+            (((base_row[163usize]) * (node_655)) * (node_660)) * (base_row[147usize]),
+            (((base_row[166usize]) * (node_678)) * (node_660))
+                * ((base_row[147usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (((base_row[164usize]) * (node_680)) * (node_655))
+                * ((base_row[147usize]) + (BFieldElement::from_raw_u64(4294967295u64))),
+            (((base_row[166usize]) * (node_674)) * (node_655)) * (base_row[147usize]),
+            ((base_row[164usize]) * (base_row[139usize])) * (node_655),
+            (node_680) * (base_row[148usize]),
+            (base_row[151usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((base_row[64usize]) * (node_89))),
+            (base_row[152usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((node_89) * (node_90)) * (node_94)) * (node_97))),
+            (base_row[153usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((base_row[64usize]) * (node_90)) * (node_94)) * (node_97))),
+            (base_row[154usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((base_row[151usize]) * (node_90))),
+            (base_row[155usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((base_row[151usize]) * (node_94)) * (node_97)) * (node_101))),
+            (base_row[156usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_663)
+                        * ((base_row[142usize])
+                            + (BFieldElement::from_raw_u64(18446744043644780551u64))))),
+            (base_row[157usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((base_row[143usize]) * (base_row[144usize]))),
+            (base_row[158usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((node_663) * (node_666)) * (node_670)) * (node_674))),
+            (base_row[159usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((base_row[156usize]) * (node_666))),
+            (base_row[160usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((base_row[145usize]) * (base_row[146usize]))),
+            (base_row[161usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_86) * (base_row[62usize]))),
+            (base_row[162usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((base_row[158usize]) * (node_678))),
+            (base_row[163usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((base_row[156usize]) * (node_670)) * (node_674)) * (node_678))),
+            (base_row[164usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((base_row[159usize]) * (node_674)) * (node_678))),
+            (base_row[165usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((node_83) * (node_103)) * (base_row[62usize]))
+                        * ((base_row[63usize])
+                            + (BFieldElement::from_raw_u64(18446743897615892521u64))))),
+            (base_row[166usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((base_row[159usize]) * (node_670))),
+            (base_row[167usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((base_row[162usize]) * (node_680)) * (node_655)) * (node_660))),
+            (base_row[168usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((base_row[162usize]) * (base_row[139usize])) * (node_655))
+                        * (node_660))),
+        ];
+        let ext_constraints = [];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints)
+            .collect()
     }
-
+    #[allow(unused_variables)]
     fn evaluate_transition_constraints(
-        _: ArrayView1<BFieldElement>,
-        _: ArrayView1<XFieldElement>,
-        _: ArrayView1<BFieldElement>,
-        _: ArrayView1<XFieldElement>,
-        _: &Challenges,
+        current_base_row: ArrayView1<BFieldElement>,
+        current_ext_row: ArrayView1<XFieldElement>,
+        next_base_row: ArrayView1<BFieldElement>,
+        next_ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        panic!("{ERROR_MESSAGE_GENERATE_CONSTRAINTS}")
+        let node_3765 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (next_base_row[8usize]));
+        let node_120 = (next_base_row[19usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[19usize]));
+        let node_517 = (next_ext_row[3usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[3usize]));
+        let node_521 = (next_ext_row[4usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[4usize]));
+        let node_124 = (next_base_row[20usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[20usize]));
+        let node_128 = (next_base_row[21usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[21usize]));
+        let node_513 = (next_ext_row[7usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[7usize]));
+        let node_1503 = (current_base_row[18usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_893 = ((next_base_row[9usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[9usize])))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_158 = (next_base_row[38usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[38usize]));
+        let node_1501 = (current_base_row[17usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_785 = (next_base_row[22usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[22usize]));
+        let node_167 = ((challenges[16usize]) * (current_base_row[7usize]))
+            + ((challenges[17usize]) * (current_base_row[13usize]));
+        let node_525 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[22usize]);
+        let node_5416 = (current_base_row[280usize])
+            * ((next_base_row[64usize])
+                + (BFieldElement::from_raw_u64(18446744052234715141u64)));
+        let node_833 = (next_base_row[24usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[24usize]));
+        let node_837 = (next_base_row[25usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[25usize]));
+        let node_841 = (next_base_row[26usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[26usize]));
+        let node_5493 = (((next_base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743992105173011u64)))
+            * ((next_base_row[63usize])
+                + (BFieldElement::from_raw_u64(18446743923385696291u64))))
+            * ((next_base_row[63usize])
+                + (BFieldElement::from_raw_u64(18446743863256154161u64)));
+        let node_4464 = (((((current_base_row[81usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((current_base_row[82usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((current_base_row[83usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (current_base_row[84usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_4475 = (((((current_base_row[85usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((current_base_row[86usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((current_base_row[87usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (current_base_row[88usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_4486 = (((((current_base_row[89usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((current_base_row[90usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((current_base_row[91usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (current_base_row[92usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_4497 = (((((current_base_row[93usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((current_base_row[94usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((current_base_row[95usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (current_base_row[96usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_845 = (next_base_row[27usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[27usize]));
+        let node_849 = (next_base_row[28usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[28usize]));
+        let node_853 = (next_base_row[29usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[29usize]));
+        let node_857 = (next_base_row[30usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[30usize]));
+        let node_861 = (next_base_row[31usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[31usize]));
+        let node_865 = (next_base_row[32usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[32usize]));
+        let node_869 = (next_base_row[33usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[33usize]));
+        let node_873 = (next_base_row[34usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[34usize]));
+        let node_877 = (next_base_row[35usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[35usize]));
+        let node_881 = (next_base_row[36usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[36usize]));
+        let node_885 = (next_base_row[37usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[37usize]));
+        let node_888 = (next_ext_row[6usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[6usize]));
+        let node_200 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[27usize]);
+        let node_829 = (next_base_row[23usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[23usize]));
+        let node_196 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[26usize]);
+        let node_295 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[39usize]));
+        let node_192 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[25usize]);
+        let node_204 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[28usize]);
+        let node_220 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[32usize]);
+        let node_208 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[29usize]);
+        let node_224 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[33usize]);
+        let node_212 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[30usize]);
+        let node_216 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[31usize]);
+        let node_1499 = (current_base_row[16usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_197 = (next_base_row[25usize]) + (node_196);
+        let node_201 = (next_base_row[26usize]) + (node_200);
+        let node_205 = (next_base_row[27usize]) + (node_204);
+        let node_228 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[34usize]);
+        let node_209 = (next_base_row[28usize]) + (node_208);
+        let node_213 = (next_base_row[29usize]) + (node_212);
+        let node_217 = (next_base_row[30usize]) + (node_216);
+        let node_159 = (node_158) + (BFieldElement::from_raw_u64(4294967295u64));
+        let node_221 = (next_base_row[31usize]) + (node_220);
+        let node_181 = (next_ext_row[6usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[71usize]));
+        let node_225 = (next_base_row[32usize]) + (node_224);
+        let node_229 = (next_base_row[33usize]) + (node_228);
+        let node_233 = (next_base_row[34usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[35usize]));
+        let node_237 = (next_base_row[35usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[36usize]));
+        let node_241 = (next_base_row[36usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[37usize]));
+        let node_5882 = (next_base_row[139usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_957 = ((current_base_row[7usize]) * (challenges[20usize]))
+            + (challenges[23usize]);
+        let node_184 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[23usize]);
+        let node_188 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[24usize]);
+        let node_116 = ((next_base_row[9usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[9usize])))
+            + (BFieldElement::from_raw_u64(18446744060824649731u64));
+        let node_189 = (next_base_row[23usize]) + (node_188);
+        let node_193 = (next_base_row[24usize]) + (node_192);
+        let node_232 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[35usize]);
+        let node_4233 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (((next_base_row[52usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[52usize]))) * (current_base_row[54usize])));
+        let node_131 = (current_base_row[39usize])
+            * ((current_base_row[39usize])
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)));
+        let node_236 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[36usize]);
+        let node_154 = ((((current_base_row[11usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((BFieldElement::from_raw_u64(34359738360u64))
+                    * (current_base_row[42usize]))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((BFieldElement::from_raw_u64(17179869180u64))
+                    * (current_base_row[41usize]))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((BFieldElement::from_raw_u64(8589934590u64))
+                    * (current_base_row[40usize]))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[39usize]));
+        let node_240 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[37usize]);
+        let node_4230 = (next_base_row[52usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[52usize]));
+        let node_5259 = (next_base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744056529682436u64));
+        let node_5878 = (current_base_row[145usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((BFieldElement::from_raw_u64(8589934590u64))
+                    * (next_base_row[145usize])));
+        let node_4108 = (next_ext_row[12usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[12usize]));
+        let node_5875 = (current_base_row[143usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((BFieldElement::from_raw_u64(8589934590u64))
+                    * (next_base_row[143usize])));
+        let node_1497 = (current_base_row[15usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_955 = (current_base_row[7usize]) * (challenges[20usize]);
+        let node_1323 = (challenges[1usize]) * (current_ext_row[3usize]);
+        let node_5263 = (next_base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743897615892521u64));
+        let node_522 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[11usize]);
+        let node_895 = (current_base_row[274usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_113 = (next_base_row[9usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[9usize]));
+        let node_527 = (next_base_row[24usize]) + (node_184);
+        let node_528 = (next_base_row[25usize]) + (node_188);
+        let node_529 = (next_base_row[26usize]) + (node_192);
+        let node_530 = (next_base_row[27usize]) + (node_196);
+        let node_531 = (next_base_row[28usize]) + (node_200);
+        let node_532 = (next_base_row[29usize]) + (node_204);
+        let node_533 = (next_base_row[30usize]) + (node_208);
+        let node_534 = (next_base_row[31usize]) + (node_212);
+        let node_541 = (node_158)
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_535 = (next_base_row[32usize]) + (node_216);
+        let node_536 = (next_base_row[33usize]) + (node_220);
+        let node_537 = (next_base_row[34usize]) + (node_224);
+        let node_538 = (next_base_row[35usize]) + (node_228);
+        let node_539 = (next_base_row[36usize]) + (node_232);
+        let node_540 = (next_base_row[37usize]) + (node_236);
+        let node_550 = (next_ext_row[6usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((current_ext_row[6usize])
+                    * ((challenges[7usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((node_167)
+                                + ((challenges[18usize]) * (current_base_row[38usize])))
+                                + ((challenges[19usize])
+                                    * (current_base_row[37usize])))))));
+        let node_4337 = ((next_base_row[59usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[59usize])))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_5124 = (((((next_base_row[65usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((next_base_row[66usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((next_base_row[67usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (next_base_row[68usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_5135 = (((((next_base_row[69usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((next_base_row[70usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((next_base_row[71usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (next_base_row[72usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_5146 = (((((next_base_row[73usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((next_base_row[74usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((next_base_row[75usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (next_base_row[76usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_5157 = (((((next_base_row[77usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((next_base_row[78usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((next_base_row[79usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (next_base_row[80usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_5254 = (next_base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_5822 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (next_base_row[135usize]));
+        let node_5909 = (next_base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446743940565565471u64));
+        let node_1495 = (current_base_row[14usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_248 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[40usize]));
+        let node_5913 = (next_base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446743949155500061u64));
+        let node_34 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((current_base_row[4usize])
+                    * ((BFieldElement::from_raw_u64(38654705655u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[3usize])))));
+        let node_1246 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[350usize]));
+        let node_526 = (next_base_row[23usize]) + (node_525);
+        let node_253 = (current_base_row[185usize])
+            * ((next_base_row[22usize]) + (node_184));
+        let node_299 = (current_base_row[186usize])
+            * ((next_base_row[22usize]) + (node_188));
+        let node_342 = (current_base_row[187usize])
+            * ((next_base_row[22usize]) + (node_192));
+        let node_329 = (next_base_row[25usize]) + (node_204);
+        let node_384 = (current_base_row[188usize])
+            * ((next_base_row[22usize]) + (node_196));
+        let node_414 = (next_base_row[27usize]) + (node_220);
+        let node_330 = (next_base_row[26usize]) + (node_208);
+        let node_185 = (next_base_row[22usize]) + (node_184);
+        let node_415 = (next_base_row[28usize]) + (node_224);
+        let node_331 = (next_base_row[27usize]) + (node_212);
+        let node_416 = (next_base_row[29usize]) + (node_228);
+        let node_332 = (next_base_row[28usize]) + (node_216);
+        let node_417 = (next_base_row[30usize]) + (node_232);
+        let node_333 = (next_base_row[29usize]) + (node_220);
+        let node_418 = (next_base_row[31usize]) + (node_236);
+        let node_334 = (next_base_row[30usize]) + (node_224);
+        let node_437 = (((((current_base_row[185usize]) * (node_159))
+            + ((current_base_row[186usize])
+                * ((node_158) + (BFieldElement::from_raw_u64(8589934590u64)))))
+            + ((current_base_row[187usize])
+                * ((node_158) + (BFieldElement::from_raw_u64(12884901885u64)))))
+            + ((current_base_row[188usize])
+                * ((node_158) + (BFieldElement::from_raw_u64(17179869180u64)))))
+            + ((current_base_row[189usize])
+                * ((node_158) + (BFieldElement::from_raw_u64(21474836475u64))));
+        let node_730 = (((((current_base_row[185usize]) * (node_541))
+            + ((current_base_row[186usize])
+                * ((node_158) + (BFieldElement::from_raw_u64(18446744060824649731u64)))))
+            + ((current_base_row[187usize])
+                * ((node_158) + (BFieldElement::from_raw_u64(18446744056529682436u64)))))
+            + ((current_base_row[188usize])
+                * ((node_158) + (BFieldElement::from_raw_u64(18446744052234715141u64)))))
+            + ((current_base_row[189usize])
+                * ((node_158) + (BFieldElement::from_raw_u64(18446744047939747846u64))));
+        let node_419 = (next_base_row[32usize]) + (node_240);
+        let node_397 = (node_158) + (BFieldElement::from_raw_u64(21474836475u64));
+        let node_335 = (next_base_row[31usize]) + (node_228);
+        let node_441 = (((((current_base_row[185usize]) * (node_181))
+            + (current_ext_row[76usize]))
+            + ((current_base_row[187usize])
+                * ((next_ext_row[6usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_ext_row[72usize]))))) + (current_ext_row[77usize]))
+            + ((current_base_row[189usize])
+                * ((next_ext_row[6usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_ext_row[59usize]))));
+        let node_734 = ((((current_ext_row[78usize]) + (current_ext_row[79usize]))
+            + (current_ext_row[80usize])) + (current_ext_row[81usize]))
+            + ((current_base_row[189usize])
+                * ((next_ext_row[6usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_ext_row[74usize]))));
+        let node_408 = (next_ext_row[6usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[59usize]));
+        let node_336 = (next_base_row[32usize]) + (node_232);
+        let node_337 = (next_base_row[33usize]) + (node_236);
+        let node_449 = ((((current_base_row[290usize]) + (current_base_row[291usize]))
+            + (current_base_row[292usize])) + (current_base_row[293usize]))
+            + (current_base_row[294usize]);
+        let node_742 = ((((current_base_row[285usize]) + (current_base_row[286usize]))
+            + (current_base_row[287usize])) + (current_base_row[288usize]))
+            + (current_base_row[289usize]);
+        let node_338 = (next_base_row[34usize]) + (node_240);
+        let node_453 = ((((current_base_row[332usize]) + (current_base_row[334usize]))
+            + (current_base_row[336usize])) + (current_base_row[338usize]))
+            + (current_base_row[340usize]);
+        let node_746 = (((((current_base_row[185usize]) * (node_528))
+            + ((current_base_row[186usize]) * ((next_base_row[26usize]) + (node_188))))
+            + ((current_base_row[187usize]) * ((next_base_row[27usize]) + (node_188))))
+            + ((current_base_row[188usize]) * ((next_base_row[28usize]) + (node_188))))
+            + ((current_base_row[189usize]) * ((next_base_row[29usize]) + (node_188)));
+        let node_314 = (node_158) + (BFieldElement::from_raw_u64(12884901885u64));
+        let node_457 = ((((current_base_row[333usize]) + (current_base_row[335usize]))
+            + (current_base_row[337usize])) + (current_base_row[339usize]))
+            + (current_base_row[341usize]);
+        let node_750 = (((((current_base_row[185usize]) * (node_529))
+            + ((current_base_row[186usize]) * ((next_base_row[27usize]) + (node_192))))
+            + ((current_base_row[187usize]) * ((next_base_row[28usize]) + (node_192))))
+            + ((current_base_row[188usize]) * ((next_base_row[29usize]) + (node_192))))
+            + ((current_base_row[189usize]) * ((next_base_row[30usize]) + (node_192)));
+        let node_325 = (next_ext_row[6usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[72usize]));
+        let node_461 = (((((current_base_row[185usize]) * (node_201))
+            + ((current_base_row[186usize]) * ((next_base_row[26usize]) + (node_204))))
+            + ((current_base_row[187usize]) * (node_330)))
+            + ((current_base_row[188usize]) * ((next_base_row[26usize]) + (node_212))))
+            + ((current_base_row[189usize]) * ((next_base_row[26usize]) + (node_216)));
+        let node_754 = (((((current_base_row[185usize]) * (node_530))
+            + ((current_base_row[186usize]) * ((next_base_row[28usize]) + (node_196))))
+            + ((current_base_row[187usize]) * ((next_base_row[29usize]) + (node_196))))
+            + ((current_base_row[188usize]) * ((next_base_row[30usize]) + (node_196))))
+            + ((current_base_row[189usize]) * ((next_base_row[31usize]) + (node_196)));
+        let node_465 = (((((current_base_row[185usize]) * (node_205))
+            + ((current_base_row[186usize]) * ((next_base_row[27usize]) + (node_208))))
+            + ((current_base_row[187usize]) * (node_331)))
+            + ((current_base_row[188usize]) * ((next_base_row[27usize]) + (node_216))))
+            + ((current_base_row[189usize]) * (node_414));
+        let node_758 = (((((current_base_row[185usize]) * (node_531))
+            + ((current_base_row[186usize]) * ((next_base_row[29usize]) + (node_200))))
+            + ((current_base_row[187usize]) * ((next_base_row[30usize]) + (node_200))))
+            + ((current_base_row[188usize]) * ((next_base_row[31usize]) + (node_200))))
+            + ((current_base_row[189usize]) * ((next_base_row[32usize]) + (node_200)));
+        let node_469 = (((((current_base_row[185usize]) * (node_209))
+            + ((current_base_row[186usize]) * ((next_base_row[28usize]) + (node_212))))
+            + ((current_base_row[187usize]) * (node_332)))
+            + ((current_base_row[188usize]) * ((next_base_row[28usize]) + (node_220))))
+            + ((current_base_row[189usize]) * (node_415));
+        let node_762 = (((((current_base_row[185usize]) * (node_532))
+            + ((current_base_row[186usize]) * ((next_base_row[30usize]) + (node_204))))
+            + ((current_base_row[187usize]) * ((next_base_row[31usize]) + (node_204))))
+            + ((current_base_row[188usize]) * ((next_base_row[32usize]) + (node_204))))
+            + ((current_base_row[189usize]) * ((next_base_row[33usize]) + (node_204)));
+        let node_473 = (((((current_base_row[185usize]) * (node_213))
+            + ((current_base_row[186usize]) * ((next_base_row[29usize]) + (node_216))))
+            + ((current_base_row[187usize]) * (node_333)))
+            + ((current_base_row[188usize]) * ((next_base_row[29usize]) + (node_224))))
+            + ((current_base_row[189usize]) * (node_416));
+        let node_766 = (((((current_base_row[185usize]) * (node_533))
+            + ((current_base_row[186usize]) * ((next_base_row[31usize]) + (node_208))))
+            + ((current_base_row[187usize]) * ((next_base_row[32usize]) + (node_208))))
+            + ((current_base_row[188usize]) * ((next_base_row[33usize]) + (node_208))))
+            + ((current_base_row[189usize]) * ((next_base_row[34usize]) + (node_208)));
+        let node_477 = (((((current_base_row[185usize]) * (node_217))
+            + ((current_base_row[186usize]) * ((next_base_row[30usize]) + (node_220))))
+            + ((current_base_row[187usize]) * (node_334)))
+            + ((current_base_row[188usize]) * ((next_base_row[30usize]) + (node_228))))
+            + ((current_base_row[189usize]) * (node_417));
+        let node_770 = (((((current_base_row[185usize]) * (node_534))
+            + ((current_base_row[186usize]) * ((next_base_row[32usize]) + (node_212))))
+            + ((current_base_row[187usize]) * ((next_base_row[33usize]) + (node_212))))
+            + ((current_base_row[188usize]) * ((next_base_row[34usize]) + (node_212))))
+            + ((current_base_row[189usize]) * ((next_base_row[35usize]) + (node_212)));
+        let node_481 = (((((current_base_row[185usize]) * (node_221))
+            + ((current_base_row[186usize]) * ((next_base_row[31usize]) + (node_224))))
+            + ((current_base_row[187usize]) * (node_335)))
+            + ((current_base_row[188usize]) * ((next_base_row[31usize]) + (node_232))))
+            + ((current_base_row[189usize]) * (node_418));
+        let node_774 = (((((current_base_row[185usize]) * (node_535))
+            + ((current_base_row[186usize]) * ((next_base_row[33usize]) + (node_216))))
+            + ((current_base_row[187usize]) * ((next_base_row[34usize]) + (node_216))))
+            + ((current_base_row[188usize]) * ((next_base_row[35usize]) + (node_216))))
+            + ((current_base_row[189usize]) * ((next_base_row[36usize]) + (node_216)));
+        let node_485 = (((((current_base_row[185usize]) * (node_225))
+            + ((current_base_row[186usize]) * ((next_base_row[32usize]) + (node_228))))
+            + ((current_base_row[187usize]) * (node_336)))
+            + ((current_base_row[188usize]) * ((next_base_row[32usize]) + (node_236))))
+            + ((current_base_row[189usize]) * (node_419));
+        let node_778 = (((((current_base_row[185usize]) * (node_536))
+            + ((current_base_row[186usize]) * ((next_base_row[34usize]) + (node_220))))
+            + ((current_base_row[187usize]) * ((next_base_row[35usize]) + (node_220))))
+            + ((current_base_row[188usize]) * ((next_base_row[36usize]) + (node_220))))
+            + ((current_base_row[189usize]) * ((next_base_row[37usize]) + (node_220)));
+        let node_488 = (((current_base_row[320usize]) + (current_base_row[321usize]))
+            + (current_base_row[322usize])) + (current_base_row[323usize]);
+        let node_781 = (((current_base_row[325usize]) + (current_base_row[326usize]))
+            + (current_base_row[327usize])) + (current_base_row[328usize]);
+        let node_490 = (((current_base_row[185usize]) * (node_233))
+            + ((current_base_row[186usize]) * ((next_base_row[34usize]) + (node_236))))
+            + ((current_base_row[187usize]) * (node_338));
+        let node_783 = (((current_base_row[185usize]) * (node_538))
+            + ((current_base_row[186usize]) * ((next_base_row[36usize]) + (node_228))))
+            + ((current_base_row[187usize]) * ((next_base_row[37usize]) + (node_228)));
+        let node_491 = ((current_base_row[185usize]) * (node_237))
+            + ((current_base_row[186usize]) * ((next_base_row[35usize]) + (node_240)));
+        let node_784 = ((current_base_row[185usize]) * (node_539))
+            + ((current_base_row[186usize]) * ((next_base_row[37usize]) + (node_232)));
+        let node_267 = (current_base_row[185usize]) * (node_241);
+        let node_567 = (current_base_row[185usize]) * (node_540);
+        let node_4045 = ((next_ext_row[11usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((challenges[6usize]) * (current_ext_row[11usize]))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((challenges[31usize]) * (current_base_row[10usize])));
+        let node_4072 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * ((challenges[57usize]) * (current_base_row[10usize]));
+        let node_4112 = ((node_4108)
+            * (((((challenges[10usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((challenges[55usize]) * (current_base_row[22usize]))))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((challenges[56usize]) * (current_base_row[23usize]))))
+                + (node_4072))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((challenges[58usize]) * (next_base_row[22usize])))))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_4076 = (challenges[10usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((challenges[55usize]) * (current_base_row[22usize])));
+        let node_4156 = ((next_base_row[48usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[48usize])))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_4155 = (next_base_row[48usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[48usize]));
+        let node_4162 = (next_base_row[47usize])
+            + (BFieldElement::from_raw_u64(18446744060824649731u64));
+        let node_4188 = (next_ext_row[15usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[15usize]));
+        let node_4225 = (next_base_row[51usize])
+            + (BFieldElement::from_raw_u64(18446744060824649731u64));
+        let node_4308 = (next_ext_row[21usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[21usize]));
+        let node_4336 = (next_base_row[59usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[59usize]));
+        let node_5251 = (current_base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744056529682436u64));
+        let node_5315 = (next_base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744047939747846u64));
+        let node_5366 = (next_base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743992105173011u64));
+        let node_5368 = (next_base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743923385696291u64));
+        let node_5485 = (next_ext_row[28usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[28usize]));
+        let node_5506 = (next_ext_row[29usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[29usize]));
+        let node_5523 = (next_ext_row[30usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[30usize]));
+        let node_5540 = (next_ext_row[31usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[31usize]));
+        let node_5557 = (next_ext_row[32usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[32usize]));
+        let node_5574 = (next_ext_row[33usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[33usize]));
+        let node_5591 = (next_ext_row[34usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[34usize]));
+        let node_5608 = (next_ext_row[35usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[35usize]));
+        let node_5625 = (next_ext_row[36usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[36usize]));
+        let node_5642 = (next_ext_row[37usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[37usize]));
+        let node_5659 = (next_ext_row[38usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[38usize]));
+        let node_5676 = (next_ext_row[39usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[39usize]));
+        let node_5693 = (next_ext_row[40usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[40usize]));
+        let node_5710 = (next_ext_row[41usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[41usize]));
+        let node_5727 = (next_ext_row[42usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[42usize]));
+        let node_5744 = (next_ext_row[43usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[43usize]));
+        let node_5770 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (next_base_row[129usize]));
+        let node_5872 = (current_base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446743940565565471u64));
+        let node_5899 = (node_5878)
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_5907 = (next_base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446744017874976781u64));
+        let node_5275 = (next_base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744060824649731u64));
+        let node_30 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[3usize]);
+        let node_47 = (next_base_row[6usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_51 = (next_ext_row[0usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[0usize]));
+        let node_31 = (BFieldElement::from_raw_u64(38654705655u64)) + (node_30);
+        let node_74 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (next_base_row[1usize]);
+        let node_90 = (BFieldElement::from_raw_u64(38654705655u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (next_base_row[3usize]));
+        let node_88 = (next_ext_row[2usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[2usize]));
+        let node_892 = (next_base_row[10usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[10usize]));
+        let node_127 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[21usize]);
+        let node_1240 = (next_base_row[22usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[348usize]));
+        let node_1262 = (current_base_row[23usize]) * (next_base_row[23usize]);
+        let node_1265 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (next_base_row[22usize]);
+        let node_1270 = (current_base_row[22usize]) * (current_base_row[25usize]);
+        let node_1271 = (current_base_row[24usize]) * (current_base_row[26usize]);
+        let node_1274 = (current_base_row[23usize]) * (current_base_row[27usize]);
+        let node_1297 = (current_base_row[24usize]) * (next_base_row[23usize]);
+        let node_1300 = (current_base_row[23usize]) * (next_base_row[24usize]);
+        let node_1695 = (current_base_row[281usize]) * (node_120);
+        let node_1697 = (current_base_row[283usize]) * (node_120);
+        let node_553 = (current_base_row[185usize]) * (node_526);
+        let node_112 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[9usize]);
+        let node_123 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[20usize]);
+        let node_1243 = (current_base_row[23usize]) + (node_525);
+        let node_1282 = (current_base_row[24usize]) * (current_base_row[27usize]);
+        let node_1307 = (current_base_row[24usize]) * (next_base_row[24usize]);
+        let node_1774 = (current_base_row[281usize]) * (node_124);
+        let node_1776 = (current_base_row[283usize]) * (node_124);
+        let node_596 = (current_base_row[186usize])
+            * ((next_base_row[24usize]) + (node_525));
+        let node_247 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[40usize]);
+        let node_144 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * ((BFieldElement::from_raw_u64(34359738360u64))
+                * (current_base_row[42usize]));
+        let node_703 = (next_base_row[27usize]) + (node_525);
+        let node_1853 = (current_base_row[281usize]) * (node_128);
+        let node_1855 = (current_base_row[283usize]) * (node_128);
+        let node_638 = (current_base_row[187usize])
+            * ((next_base_row[25usize]) + (node_525));
+        let node_704 = (next_base_row[28usize]) + (node_184);
+        let node_678 = (current_base_row[188usize])
+            * ((next_base_row[26usize]) + (node_525));
+        let node_705 = (next_base_row[29usize]) + (node_188);
+        let node_706 = (next_base_row[30usize]) + (node_192);
+        let node_788 = (current_base_row[317usize])
+            * ((next_base_row[22usize]) + (node_204));
+        let node_707 = (next_base_row[31usize]) + (node_196);
+        let node_795 = (next_base_row[22usize]) + (node_220);
+        let node_815 = (next_base_row[32usize]) + (node_525);
+        let node_709 = (next_base_row[33usize]) + (node_204);
+        let node_792 = (current_base_row[273usize])
+            * ((next_base_row[22usize]) + (node_212));
+        let node_710 = (next_base_row[34usize]) + (node_208);
+        let node_794 = (current_base_row[319usize])
+            * ((next_base_row[22usize]) + (node_216));
+        let node_711 = (next_base_row[35usize]) + (node_212);
+        let node_796 = (current_base_row[330usize]) * (node_795);
+        let node_1036 = (node_785)
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_1062 = (node_785)
+            + (BFieldElement::from_raw_u64(18446744056529682436u64));
+        let node_712 = (next_base_row[36usize]) + (node_216);
+        let node_445 = ((((node_253) + (node_299)) + (node_342)) + (node_384))
+            + (current_base_row[297usize]);
+        let node_738 = ((((node_553) + (node_596)) + (node_638)) + (node_678))
+            + (current_base_row[298usize]);
+        let node_798 = (current_base_row[331usize])
+            * ((next_base_row[22usize]) + (node_224));
+        let node_713 = (next_base_row[37usize]) + (node_220);
+        let node_409 = (next_base_row[22usize]) + (node_200);
+        let node_800 = (current_base_row[277usize])
+            * ((next_base_row[22usize]) + (node_228));
+        let node_691 = (node_158)
+            + (BFieldElement::from_raw_u64(18446744047939747846u64));
+        let node_410 = (next_base_row[23usize]) + (node_204);
+        let node_802 = (current_base_row[342usize])
+            * ((next_base_row[22usize]) + (node_232));
+        let node_702 = (next_ext_row[6usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[74usize]));
+        let node_411 = (next_base_row[24usize]) + (node_208);
+        let node_804 = (current_base_row[343usize])
+            * ((next_base_row[22usize]) + (node_236));
+        let node_412 = (next_base_row[25usize]) + (node_212);
+        let node_806 = (current_base_row[344usize])
+            * ((next_base_row[22usize]) + (node_240));
+        let node_413 = (next_base_row[26usize]) + (node_216);
+        let node_1385 = ((challenges[2usize])
+            * (((challenges[2usize])
+                * (((challenges[2usize])
+                    * (((challenges[2usize]) * (current_ext_row[4usize]))
+                        + (current_base_row[22usize]))) + (current_base_row[23usize])))
+                + (current_base_row[24usize]))) + (current_base_row[25usize]);
+        let node_1439 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (next_ext_row[7usize]);
+        let node_1441 = (current_base_row[39usize]) * (current_base_row[42usize]);
+        let node_1451 = ((current_base_row[41usize]) * (current_base_row[43usize]))
+            + ((current_base_row[40usize]) * (current_base_row[44usize]));
+        let node_1380 = ((challenges[2usize])
+            * (((challenges[2usize])
+                * (((challenges[2usize]) * (current_ext_row[4usize]))
+                    + (current_base_row[22usize]))) + (current_base_row[23usize])))
+            + (current_base_row[24usize]);
+        let node_1452 = (current_base_row[41usize]) * (current_base_row[44usize]);
+        let node_1375 = ((challenges[2usize])
+            * (((challenges[2usize]) * (current_ext_row[4usize]))
+                + (current_base_row[22usize]))) + (current_base_row[23usize]);
+        let node_1370 = ((challenges[2usize]) * (current_ext_row[4usize]))
+            + (current_base_row[22usize]);
+        let node_1465 = (node_829)
+            + (BFieldElement::from_raw_u64(18446744056529682436u64));
+        let node_3899 = (next_ext_row[5usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[5usize]));
+        let node_3985 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (((((((((((challenges[32usize]) * (next_base_row[22usize]))
+                + ((challenges[33usize]) * (next_base_row[23usize])))
+                + ((challenges[34usize]) * (next_base_row[24usize])))
+                + ((challenges[35usize]) * (next_base_row[25usize])))
+                + ((challenges[36usize]) * (next_base_row[26usize])))
+                + ((challenges[37usize]) * (next_base_row[27usize])))
+                + ((challenges[38usize]) * (next_base_row[28usize])))
+                + ((challenges[39usize]) * (next_base_row[29usize])))
+                + ((challenges[40usize]) * (next_base_row[30usize])))
+                + ((challenges[41usize]) * (next_base_row[31usize])));
+        let node_3968 = (((((challenges[32usize]) * (next_base_row[22usize]))
+            + ((challenges[33usize]) * (next_base_row[23usize])))
+            + ((challenges[34usize]) * (next_base_row[24usize])))
+            + ((challenges[35usize]) * (next_base_row[25usize])))
+            + ((challenges[36usize]) * (next_base_row[26usize]));
+        let node_4065 = (challenges[10usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((challenges[55usize]) * (next_base_row[22usize])));
+        let node_4068 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * ((challenges[56usize]) * (next_base_row[23usize]));
+        let node_4079 = (node_4076)
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((challenges[56usize]) * (current_base_row[23usize])));
+        let node_4116 = ((node_4108)
+            * (((node_4076) + (node_4072))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((challenges[58usize]) * (next_base_row[22usize])))))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_4102 = (((node_4065)
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((challenges[56usize]) * (current_base_row[23usize]))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((challenges[57usize])
+                    * (BFieldElement::from_raw_u64(25769803770u64)))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (challenges[58usize]));
+        let node_4106 = ((node_4076) + (node_4068))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((challenges[57usize])
+                    * (BFieldElement::from_raw_u64(17179869180u64))));
+        let node_4179 = (next_base_row[47usize])
+            * ((next_base_row[47usize])
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)));
+        let node_4248 = (challenges[12usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (next_base_row[52usize]));
+        let node_4253 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_ext_row[16usize]);
+        let node_4299 = ((next_base_row[51usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+            * (next_base_row[51usize]);
+        let node_4341 = (node_4337)
+            * ((current_base_row[58usize])
+                + (BFieldElement::from_raw_u64(18446744000695107601u64)));
+        let node_4349 = (next_base_row[57usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[57usize]));
+        let node_4340 = (current_base_row[58usize])
+            + (BFieldElement::from_raw_u64(18446744000695107601u64));
+        let node_4371 = (next_ext_row[23usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[23usize]));
+        let node_5233 = (current_base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743897615892521u64));
+        let node_5235 = (current_base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744047939747846u64));
+        let node_5466 = (next_ext_row[24usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[24usize]));
+        let node_4408 = (((((current_base_row[65usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((current_base_row[66usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((current_base_row[67usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (current_base_row[68usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_4419 = (((((current_base_row[69usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((current_base_row[70usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((current_base_row[71usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (current_base_row[72usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_4430 = (((((current_base_row[73usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((current_base_row[74usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((current_base_row[75usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (current_base_row[76usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_4441 = (((((current_base_row[77usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((current_base_row[78usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((current_base_row[79usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (current_base_row[80usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_5265 = (node_5235) * (node_5233);
+        let node_5279 = ((current_base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+            * ((current_base_row[62usize])
+                + (BFieldElement::from_raw_u64(18446744060824649731u64)));
+        let node_5297 = (challenges[42usize])
+            * ((next_base_row[103usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (current_base_row[103usize])));
+        let node_5298 = (challenges[43usize])
+            * ((next_base_row[104usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (current_base_row[104usize])));
+        let node_5300 = (challenges[44usize])
+            * ((next_base_row[105usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (current_base_row[105usize])));
+        let node_5302 = (challenges[45usize])
+            * ((next_base_row[106usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (current_base_row[106usize])));
+        let node_5304 = (challenges[46usize])
+            * ((next_base_row[107usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (current_base_row[107usize])));
+        let node_5306 = (challenges[47usize])
+            * ((next_base_row[108usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (current_base_row[108usize])));
+        let node_5396 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (((((((((((challenges[32usize]) * (node_5124))
+                + ((challenges[33usize]) * (node_5135)))
+                + ((challenges[34usize]) * (node_5146)))
+                + ((challenges[35usize]) * (node_5157)))
+                + ((challenges[36usize]) * (next_base_row[97usize])))
+                + ((challenges[37usize]) * (next_base_row[98usize])))
+                + ((challenges[38usize]) * (next_base_row[99usize])))
+                + ((challenges[39usize]) * (next_base_row[100usize])))
+                + ((challenges[40usize]) * (next_base_row[101usize])))
+                + ((challenges[41usize]) * (next_base_row[102usize])));
+        let node_5373 = (next_ext_row[25usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[25usize]));
+        let node_5382 = (((((challenges[32usize]) * (node_5124))
+            + ((challenges[33usize]) * (node_5135)))
+            + ((challenges[34usize]) * (node_5146)))
+            + ((challenges[35usize]) * (node_5157)))
+            + ((challenges[36usize]) * (next_base_row[97usize]));
+        let node_5407 = (next_ext_row[26usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[26usize]));
+        let node_5433 = (next_ext_row[27usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[27usize]));
+        let node_5436 = (next_base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743863256154161u64));
+        let node_5780 = (next_ext_row[44usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[44usize]));
+        let node_5796 = (next_ext_row[45usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[45usize]));
+        let node_5791 = ((challenges[52usize]) * (next_base_row[131usize]))
+            + ((challenges[53usize]) * (next_base_row[133usize]));
+        let node_5794 = ((challenges[52usize]) * (next_base_row[130usize]))
+            + ((challenges[53usize]) * (next_base_row[132usize]));
+        let node_5834 = (next_ext_row[46usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[46usize]));
+        let node_5890 = ((next_base_row[140usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[140usize])))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_5896 = (node_5875)
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_5916 = (next_base_row[147usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_5918 = (next_base_row[147usize])
+            + (BFieldElement::from_raw_u64(18446744060824649731u64));
+        let node_5921 = (current_base_row[314usize]) * (next_base_row[147usize]);
+        let node_5923 = (current_base_row[147usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_5888 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[140usize]);
+        let node_5982 = (next_base_row[147usize]) * (next_base_row[147usize]);
+        let node_5932 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (node_5875);
+        let node_5998 = (next_ext_row[48usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[48usize]));
+        let node_243 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[42usize]));
+        let node_245 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[41usize]));
+        let node_1522 = (current_base_row[12usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_1505 = (current_base_row[13usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_133 = (current_base_row[40usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_5308 = (next_base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_5309 = (next_base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744060824649731u64));
+        let node_5311 = (next_base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744056529682436u64));
+        let node_5901 = (next_base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446744052234715141u64));
+        let node_5903 = (next_base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446744009285042191u64));
+        let node_5313 = (next_base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744052234715141u64));
+        let node_5250 = (current_base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744060824649731u64));
+        let node_5272 = (current_base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_176 = (challenges[7usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (((node_167) + ((challenges[18usize]) * (next_base_row[38usize])))
+                    + ((challenges[19usize]) * (next_base_row[37usize]))));
+        let node_547 = (challenges[7usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (((node_167) + ((challenges[18usize]) * (current_base_row[38usize])))
+                    + ((challenges[19usize]) * (current_base_row[37usize]))));
+        let node_966 = (challenges[8usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (((node_957)
+                    + (((next_base_row[22usize])
+                        + (BFieldElement::from_raw_u64(4294967295u64)))
+                        * (challenges[21usize])))
+                    + ((next_base_row[23usize]) * (challenges[22usize]))));
+        let node_1042 = (challenges[8usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (((node_955) + ((current_base_row[22usize]) * (challenges[21usize])))
+                    + ((current_base_row[23usize]) * (challenges[22usize]))));
+        let node_1050 = ((current_base_row[22usize])
+            + (BFieldElement::from_raw_u64(4294967295u64))) * (challenges[21usize]);
+        let node_1064 = ((current_base_row[22usize])
+            + (BFieldElement::from_raw_u64(8589934590u64))) * (challenges[21usize]);
+        let node_1404 = (challenges[8usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (((node_957) + ((current_base_row[22usize]) * (challenges[21usize])))
+                    + ((current_base_row[39usize]) * (challenges[22usize]))));
+        let node_1406 = (current_base_row[40usize]) * (challenges[22usize]);
+        let node_1412 = (current_base_row[41usize]) * (challenges[22usize]);
+        let node_1418 = (node_957)
+            + ((current_base_row[23usize]) * (challenges[21usize]));
+        let node_1419 = (current_base_row[42usize]) * (challenges[22usize]);
+        let node_1425 = (node_957)
+            + (((current_base_row[23usize])
+                + (BFieldElement::from_raw_u64(4294967295u64))) * (challenges[21usize]));
+        let node_1432 = (node_957)
+            + (((current_base_row[23usize])
+                + (BFieldElement::from_raw_u64(8589934590u64))) * (challenges[21usize]));
+        let base_constraints = [
+            (next_base_row[0usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[0usize])
+                        + (BFieldElement::from_raw_u64(4294967295u64)))),
+            (current_base_row[6usize])
+                * ((next_base_row[6usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[6usize]))),
+            ((node_34) * (next_base_row[3usize]))
+                + ((current_base_row[4usize])
+                    * (((next_base_row[3usize]) + (node_30))
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))),
+            (current_base_row[5usize])
+                * ((next_base_row[5usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (((current_base_row[5usize])
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+                * (next_base_row[5usize]))
+                * ((next_base_row[1usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (current_base_row[5usize]) * (next_base_row[1usize]),
+            ((current_base_row[5usize]) * (node_34)) * (node_47),
+            ((next_base_row[7usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (current_base_row[7usize])))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            (current_base_row[8usize])
+                * ((next_base_row[8usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[8usize]))),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_120))
+                + ((current_base_row[213usize])
+                    * ((next_base_row[22usize]) + (node_522))))
+                + ((current_base_row[206usize]) * (node_120)))
+                + ((current_base_row[210usize])
+                    * ((current_base_row[324usize]) * (node_785))))
+                + ((current_base_row[208usize]) * (current_base_row[324usize])))
+                + ((current_base_row[216usize]) * (node_892)))
+                + ((current_base_row[221usize]) * (node_120)))
+                + ((current_base_row[214usize])
+                    * ((node_895) * (current_base_row[39usize]))))
+                + ((current_base_row[224usize])
+                    * ((node_120)
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))))
+                + ((current_base_row[228usize])
+                    * ((next_base_row[19usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((current_base_row[19usize])
+                                + (BFieldElement::from_raw_u64(
+                                    18446744065119617026u64,
+                                )))))))
+                + ((current_base_row[225usize])
+                    * ((next_base_row[9usize]) + (node_127))))
+                + ((current_base_row[226usize])
+                    * ((current_base_row[22usize])
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))))
+                + ((current_base_row[215usize]) * (node_120)))
+                + ((current_base_row[223usize]) * (node_120)))
+                + ((current_base_row[243usize]) * (node_120)))
+                + ((current_base_row[233usize]) * (node_131)))
+                + ((current_base_row[230usize])
+                    * ((current_base_row[27usize]) + (node_525))))
+                + ((current_base_row[232usize]) * (node_120)))
+                + ((current_base_row[246usize]) * (node_120)))
+                + ((current_base_row[249usize]) * (node_120)))
+                + ((current_base_row[235usize]) * ((node_785) + (node_184))))
+                + ((current_base_row[236usize]) * (node_1240)))
+                + ((current_base_row[237usize])
+                    * ((current_base_row[349usize])
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))))
+                + ((current_base_row[239usize])
+                    * ((current_base_row[39usize]) * (node_1246))))
+                + ((current_base_row[238usize])
+                    * ((current_base_row[22usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((BFieldElement::from_raw_u64(18446744069414584320u64))
+                                * (next_base_row[23usize])) + (next_base_row[22usize]))))))
+                + ((current_base_row[240usize]) * (node_120)))
+                + ((current_base_row[241usize]) * (node_120)))
+                + ((current_base_row[242usize]) * (node_120)))
+                + ((current_base_row[244usize]) * (node_120)))
+                + ((current_base_row[245usize]) * (node_120)))
+                + ((current_base_row[247usize])
+                    * (((current_base_row[22usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_1262))) + (node_1265))))
+                + ((current_base_row[250usize]) * (node_120)))
+                + ((current_base_row[275usize]) * ((node_785) + (node_192))))
+                + ((current_base_row[278usize])
+                    * ((next_base_row[22usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((node_1270)
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * (node_1271)))
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * (node_1274)))))))
+                + ((current_base_row[276usize])
+                    * ((((current_base_row[349usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_1297)))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_1300)))
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))))
+                + ((current_base_row[279usize]) * (node_1240)))
+                + ((current_base_row[254usize]) * (node_120)))
+                + ((current_base_row[259usize]) * (node_120))) + (node_1695))
+                + (node_1697)) * (node_3765)) + ((node_113) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_124)) + ((current_base_row[213usize]) * (node_526)))
+                + ((current_base_row[206usize]) * (node_124)))
+                + ((current_base_row[210usize]) * (node_253)))
+                + ((current_base_row[208usize]) * (node_553)))
+                + ((current_base_row[216usize]) * (node_120)))
+                + ((current_base_row[221usize]) * (node_124)))
+                + ((current_base_row[214usize])
+                    * ((node_895) * (current_base_row[22usize]))))
+                + ((current_base_row[224usize])
+                    * (((next_base_row[20usize]) + (node_112))
+                        + (BFieldElement::from_raw_u64(18446744060824649731u64)))))
+                + ((current_base_row[228usize])
+                    * ((next_base_row[9usize]) + (node_123))))
+                + ((current_base_row[225usize]) * (node_120)))
+                + ((current_base_row[226usize]) * (node_120)))
+                + ((current_base_row[215usize]) * (node_124)))
+                + ((current_base_row[223usize]) * (node_124)))
+                + ((current_base_row[243usize]) * (node_124)))
+                + ((current_base_row[233usize])
+                    * ((((next_base_row[32usize])
+                        * (BFieldElement::from_raw_u64(8589934590u64)))
+                        + (current_base_row[39usize])) + (node_200))))
+                + ((current_base_row[230usize])
+                    * ((current_base_row[28usize]) + (node_184))))
+                + ((current_base_row[232usize]) * (node_124)))
+                + ((current_base_row[246usize]) * (node_124)))
+                + ((current_base_row[249usize]) * (node_124)))
+                + ((current_base_row[235usize]) * (node_120)))
+                + ((current_base_row[236usize]) * (node_120)))
+                + ((current_base_row[237usize]) * (node_120)))
+                + ((current_base_row[239usize]) * ((node_1243) * (node_1246))))
+                + (current_base_row[353usize]))
+                + ((current_base_row[240usize]) * (node_124)))
+                + ((current_base_row[241usize]) * (node_124)))
+                + ((current_base_row[242usize]) * (node_124)))
+                + ((current_base_row[244usize]) * (node_124)))
+                + ((current_base_row[245usize]) * (node_124)))
+                + ((current_base_row[247usize]) * (node_120)))
+                + ((current_base_row[250usize]) * (node_124)))
+                + ((current_base_row[275usize]) * ((node_829) + (node_196))))
+                + ((current_base_row[278usize])
+                    * ((next_base_row[23usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((((((current_base_row[23usize])
+                                * (current_base_row[25usize]))
+                                + ((current_base_row[22usize])
+                                    * (current_base_row[26usize])))
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * (node_1282))) + (node_1271)) + (node_1274))))))
+                + ((current_base_row[276usize])
+                    * ((((((current_base_row[23usize]) * (next_base_row[22usize]))
+                        + ((current_base_row[22usize]) * (next_base_row[23usize])))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_1307))) + (node_1297)) + (node_1300))))
+                + ((current_base_row[279usize])
+                    * ((next_base_row[23usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((current_base_row[22usize])
+                                * (current_base_row[24usize]))))))
+                + ((current_base_row[254usize]) * (node_124)))
+                + ((current_base_row[259usize]) * (node_124))) + (node_1774))
+                + (node_1776)) * (node_3765)) + ((node_892) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_128)) + ((current_base_row[213usize]) * (node_527)))
+                + ((current_base_row[206usize]) * (node_128)))
+                + ((current_base_row[210usize]) * (node_299)))
+                + ((current_base_row[208usize]) * (node_596)))
+                + ((current_base_row[216usize]) * (node_124)))
+                + ((current_base_row[221usize]) * (node_128)))
+                + ((current_base_row[214usize])
+                    * ((((((current_base_row[11usize]) + (node_247))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((BFieldElement::from_raw_u64(8589934590u64))
+                                * (current_base_row[41usize])))) + (node_144))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((BFieldElement::from_raw_u64(137438953440u64))
+                                * (current_base_row[43usize]))))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((BFieldElement::from_raw_u64(549755813760u64))
+                                * (current_base_row[44usize]))))))
+                + ((current_base_row[224usize])
+                    * ((next_base_row[21usize]) + (node_522))))
+                + ((current_base_row[228usize]) * (node_785)))
+                + ((current_base_row[225usize]) * (node_124)))
+                + ((current_base_row[226usize]) * (node_124)))
+                + ((current_base_row[215usize]) * (node_128)))
+                + ((current_base_row[223usize]) * (node_128)))
+                + ((current_base_row[243usize]) * (node_128)))
+                + ((current_base_row[233usize])
+                    * (((node_295) * (node_785))
+                        + ((current_base_row[39usize]) * (node_703)))))
+                + ((current_base_row[230usize])
+                    * ((current_base_row[29usize]) + (node_188))))
+                + ((current_base_row[232usize]) * (node_128)))
+                + ((current_base_row[246usize]) * (node_128)))
+                + ((current_base_row[249usize]) * (node_128)))
+                + ((current_base_row[235usize]) * (node_124)))
+                + ((current_base_row[236usize]) * (node_124)))
+                + ((current_base_row[237usize]) * (node_124)))
+                + ((current_base_row[239usize])
+                    * ((next_base_row[22usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_1246)))))
+                + ((current_base_row[238usize]) * (node_527)))
+                + ((current_base_row[240usize]) * (node_128)))
+                + ((current_base_row[241usize]) * (node_128)))
+                + ((current_base_row[242usize]) * (node_128)))
+                + ((current_base_row[244usize]) * (node_128)))
+                + ((current_base_row[245usize]) * (node_128)))
+                + ((current_base_row[247usize]) * (node_124)))
+                + ((current_base_row[250usize]) * (node_128)))
+                + ((current_base_row[275usize]) * ((node_833) + (node_200))))
+                + ((current_base_row[278usize])
+                    * ((next_base_row[24usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((((current_base_row[24usize])
+                                * (current_base_row[25usize]))
+                                + ((current_base_row[23usize])
+                                    * (current_base_row[26usize])))
+                                + ((current_base_row[22usize])
+                                    * (current_base_row[27usize]))) + (node_1282))))))
+                + ((current_base_row[276usize])
+                    * (((((current_base_row[24usize]) * (next_base_row[22usize]))
+                        + (node_1262))
+                        + ((current_base_row[22usize]) * (next_base_row[24usize])))
+                        + (node_1307))))
+                + ((current_base_row[279usize])
+                    * ((next_base_row[24usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_1270)))))
+                + ((current_base_row[254usize]) * (node_128)))
+                + ((current_base_row[259usize]) * (node_128))) + (node_1853))
+                + (node_1855)) * (node_3765))
+                + (((next_base_row[11usize]) + (node_522)) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_116)) + ((current_base_row[213usize]) * (node_528)))
+                + ((current_base_row[206usize]) * (node_116)))
+                + ((current_base_row[210usize]) * (node_342)))
+                + ((current_base_row[208usize]) * (node_638)))
+                + ((current_base_row[216usize]) * (node_128)))
+                + ((current_base_row[221usize]) * (node_893)))
+                + (current_base_row[354usize]))
+                + ((current_base_row[224usize])
+                    * ((next_base_row[9usize]) + (node_522))))
+                + ((current_base_row[228usize]) * (node_829)))
+                + ((current_base_row[225usize]) * (node_128)))
+                + ((current_base_row[226usize]) * (node_128)))
+                + ((current_base_row[215usize]) * (node_116)))
+                + ((current_base_row[223usize]) * (node_116)))
+                + ((current_base_row[243usize]) * (node_893)))
+                + ((current_base_row[233usize])
+                    * (((node_295) * (node_829))
+                        + ((current_base_row[39usize]) * (node_704)))))
+                + ((current_base_row[230usize])
+                    * ((current_base_row[30usize]) + (node_192))))
+                + ((current_base_row[232usize]) * (node_893)))
+                + ((current_base_row[246usize]) * (node_893)))
+                + ((current_base_row[249usize]) * (node_893)))
+                + ((current_base_row[235usize]) * (node_128)))
+                + ((current_base_row[236usize]) * (node_128)))
+                + ((current_base_row[237usize]) * (node_128)))
+                + ((current_base_row[239usize]) * (node_120)))
+                + ((current_base_row[238usize]) * (node_528)))
+                + ((current_base_row[240usize]) * (node_893)))
+                + ((current_base_row[241usize]) * (node_893)))
+                + ((current_base_row[242usize]) * (node_893)))
+                + ((current_base_row[244usize]) * (node_893)))
+                + ((current_base_row[245usize]) * (node_893)))
+                + ((current_base_row[247usize]) * (node_128)))
+                + ((current_base_row[250usize]) * (node_893)))
+                + ((current_base_row[275usize]) * (node_329)))
+                + ((current_base_row[278usize]) * (node_329)))
+                + ((current_base_row[276usize]) * (node_837)))
+                + ((current_base_row[279usize]) * (node_197)))
+                + ((current_base_row[254usize]) * (node_116)))
+                + ((current_base_row[259usize]) * (node_116)))
+                + ((current_base_row[281usize]) * (node_893)))
+                + ((current_base_row[283usize]) * (node_893))) * (node_3765))
+                + ((node_120) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (current_base_row[263usize]))
+                + ((current_base_row[213usize]) * (node_531)))
+                + ((current_base_row[206usize]) * (current_base_row[263usize])))
+                + ((current_base_row[210usize]) * (node_788)))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[317usize])
+                        * ((next_base_row[28usize]) + (node_525)))))
+                + ((current_base_row[216usize]) * (node_829)))
+                + ((current_base_row[221usize]) * (node_833)))
+                + (current_base_row[356usize]))
+                + ((current_base_row[224usize]) * (node_833)))
+                + ((current_base_row[228usize]) * (node_841)))
+                + ((current_base_row[225usize]) * (node_833)))
+                + ((current_base_row[226usize]) * (node_189)))
+                + ((current_base_row[215usize]) * (current_base_row[263usize])))
+                + ((current_base_row[223usize]) * (current_base_row[263usize])))
+                + ((current_base_row[243usize]) * (node_416)))
+                + ((current_base_row[233usize])
+                    * (((node_295) * (node_841))
+                        + ((current_base_row[39usize]) * (node_707)))))
+                + ((current_base_row[230usize]) * (node_124)))
+                + ((current_base_row[232usize]) * (node_833)))
+                + ((current_base_row[246usize]) * (node_795)))
+                + ((current_base_row[249usize]) * (node_815)))
+                + ((current_base_row[235usize]) * (node_193)))
+                + ((current_base_row[236usize]) * (node_193)))
+                + ((current_base_row[237usize]) * (node_833)))
+                + ((current_base_row[239usize]) * (node_893)))
+                + ((current_base_row[238usize]) * (node_531)))
+                + ((current_base_row[240usize]) * (node_197)))
+                + ((current_base_row[241usize]) * (node_197)))
+                + ((current_base_row[242usize]) * (node_197)))
+                + ((current_base_row[244usize]) * (node_837)))
+                + ((current_base_row[245usize]) * (node_197)))
+                + ((current_base_row[247usize]) * (node_837)))
+                + ((current_base_row[250usize]) * (node_837)))
+                + ((current_base_row[275usize]) * (node_332)))
+                + ((current_base_row[278usize]) * (node_332)))
+                + ((current_base_row[276usize]) * (node_849)))
+                + ((current_base_row[279usize]) * (node_209)))
+                + ((current_base_row[254usize]) * (current_base_row[263usize])))
+                + ((current_base_row[259usize]) * (current_base_row[263usize])))
+                + ((current_base_row[281usize]) * (node_845)))
+                + ((current_base_row[283usize]) * (node_845))) * (node_3765))
+                + ((node_785) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (current_base_row[264usize]))
+                + ((current_base_row[213usize]) * (node_532)))
+                + ((current_base_row[206usize]) * (current_base_row[264usize])))
+                + ((current_base_row[210usize]) * (current_base_row[284usize])))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[272usize])
+                        * ((next_base_row[29usize]) + (node_525)))))
+                + ((current_base_row[216usize]) * (node_833)))
+                + ((current_base_row[221usize]) * (node_837)))
+                + ((current_base_row[214usize]) * (current_base_row[295usize])))
+                + ((current_base_row[224usize]) * (node_837)))
+                + ((current_base_row[228usize]) * (node_845)))
+                + ((current_base_row[225usize]) * (node_837)))
+                + ((current_base_row[226usize]) * (node_193)))
+                + ((current_base_row[215usize]) * (current_base_row[264usize])))
+                + ((current_base_row[223usize]) * (current_base_row[264usize])))
+                + ((current_base_row[243usize]) * (node_417)))
+                + ((current_base_row[233usize]) * (node_709)))
+                + ((current_base_row[230usize]) * (node_128)))
+                + ((current_base_row[232usize]) * (node_837)))
+                + ((current_base_row[246usize])
+                    * ((next_base_row[23usize]) + (node_224))))
+                + ((current_base_row[249usize])
+                    * ((next_base_row[33usize]) + (node_184))))
+                + ((current_base_row[235usize]) * (node_197)))
+                + ((current_base_row[236usize]) * (node_197)))
+                + ((current_base_row[237usize]) * (node_837)))
+                + ((current_base_row[239usize]) * (node_189)))
+                + ((current_base_row[238usize]) * (node_532)))
+                + ((current_base_row[240usize]) * (node_201)))
+                + ((current_base_row[241usize]) * (node_201)))
+                + ((current_base_row[242usize]) * (node_201)))
+                + ((current_base_row[244usize]) * (node_841)))
+                + ((current_base_row[245usize]) * (node_201)))
+                + ((current_base_row[247usize]) * (node_841)))
+                + ((current_base_row[250usize]) * (node_841)))
+                + ((current_base_row[275usize]) * (node_333)))
+                + ((current_base_row[278usize]) * (node_333)))
+                + ((current_base_row[276usize]) * (node_853)))
+                + ((current_base_row[279usize]) * (node_213)))
+                + ((current_base_row[254usize]) * (current_base_row[264usize])))
+                + ((current_base_row[259usize]) * (current_base_row[264usize])))
+                + ((current_base_row[281usize]) * (node_849)))
+                + ((current_base_row[283usize]) * (node_849))) * (node_3765))
+                + ((node_829) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_154)) + ((current_base_row[213usize]) * (node_533)))
+                + ((current_base_row[206usize]) * (node_154)))
+                + ((current_base_row[210usize]) * (node_792)))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[273usize])
+                        * ((next_base_row[30usize]) + (node_525)))))
+                + ((current_base_row[216usize]) * (node_837)))
+                + ((current_base_row[221usize]) * (node_841)))
+                + ((current_base_row[214usize]) * (current_base_row[296usize])))
+                + ((current_base_row[224usize]) * (node_841)))
+                + ((current_base_row[228usize]) * (node_849)))
+                + ((current_base_row[225usize]) * (node_841)))
+                + ((current_base_row[226usize]) * (node_197)))
+                + ((current_base_row[215usize]) * (node_154)))
+                + ((current_base_row[223usize]) * (node_154)))
+                + ((current_base_row[243usize]) * (node_418)))
+                + ((current_base_row[233usize]) * (node_710)))
+                + ((current_base_row[230usize]) * (node_893)))
+                + ((current_base_row[232usize]) * (node_841)))
+                + ((current_base_row[246usize])
+                    * ((next_base_row[24usize]) + (node_228))))
+                + ((current_base_row[249usize])
+                    * ((next_base_row[34usize]) + (node_188))))
+                + ((current_base_row[235usize]) * (node_201)))
+                + ((current_base_row[236usize]) * (node_201)))
+                + ((current_base_row[237usize]) * (node_841)))
+                + ((current_base_row[239usize]) * (node_193)))
+                + ((current_base_row[238usize]) * (node_533)))
+                + ((current_base_row[240usize]) * (node_205)))
+                + ((current_base_row[241usize]) * (node_205)))
+                + ((current_base_row[242usize]) * (node_205)))
+                + ((current_base_row[244usize]) * (node_845)))
+                + ((current_base_row[245usize]) * (node_205)))
+                + ((current_base_row[247usize]) * (node_845)))
+                + ((current_base_row[250usize]) * (node_845)))
+                + ((current_base_row[275usize]) * (node_334)))
+                + ((current_base_row[278usize]) * (node_334)))
+                + ((current_base_row[276usize]) * (node_857)))
+                + ((current_base_row[279usize]) * (node_217)))
+                + ((current_base_row[254usize]) * (node_154)))
+                + ((current_base_row[259usize]) * (node_154)))
+                + ((current_base_row[281usize]) * (node_853)))
+                + ((current_base_row[283usize]) * (node_853))) * (node_3765))
+                + ((node_833) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_437)) + ((current_base_row[213usize]) * (node_534)))
+                + ((current_base_row[206usize]) * (node_730)))
+                + ((current_base_row[210usize]) * (node_794)))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[319usize])
+                        * ((next_base_row[31usize]) + (node_525)))))
+                + ((current_base_row[216usize]) * (node_841)))
+                + ((current_base_row[221usize]) * (node_845)))
+                + ((current_base_row[214usize]) * (node_120)))
+                + ((current_base_row[224usize]) * (node_845)))
+                + ((current_base_row[228usize]) * (node_853)))
+                + ((current_base_row[225usize]) * (node_845)))
+                + ((current_base_row[226usize]) * (node_201)))
+                + ((current_base_row[215usize]) * (node_730)))
+                + ((current_base_row[223usize]) * (node_437)))
+                + ((current_base_row[243usize]) * (node_419)))
+                + ((current_base_row[233usize]) * (node_711)))
+                + ((current_base_row[230usize]) * (node_397)))
+                + ((current_base_row[232usize]) * (node_845)))
+                + ((current_base_row[246usize])
+                    * ((next_base_row[25usize]) + (node_232))))
+                + ((current_base_row[249usize])
+                    * ((next_base_row[35usize]) + (node_192))))
+                + ((current_base_row[235usize]) * (node_205)))
+                + ((current_base_row[236usize]) * (node_205)))
+                + ((current_base_row[237usize]) * (node_845)))
+                + ((current_base_row[239usize]) * (node_197)))
+                + ((current_base_row[238usize]) * (node_534)))
+                + ((current_base_row[240usize]) * (node_209)))
+                + ((current_base_row[241usize]) * (node_209)))
+                + ((current_base_row[242usize]) * (node_209)))
+                + ((current_base_row[244usize]) * (node_849)))
+                + ((current_base_row[245usize]) * (node_209)))
+                + ((current_base_row[247usize]) * (node_849)))
+                + ((current_base_row[250usize]) * (node_849)))
+                + ((current_base_row[275usize]) * (node_335)))
+                + ((current_base_row[278usize]) * (node_335)))
+                + ((current_base_row[276usize]) * (node_861)))
+                + ((current_base_row[279usize]) * (node_221)))
+                + ((current_base_row[254usize]) * (node_730)))
+                + ((current_base_row[259usize]) * (node_437)))
+                + ((current_base_row[281usize]) * (node_857)))
+                + ((current_base_row[283usize]) * (node_857))) * (node_3765))
+                + ((node_837) * (next_base_row[8usize])),
+            (((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_461)) + ((current_base_row[213usize]) * (node_540)))
+                + ((current_base_row[206usize]) * (node_754)))
+                + ((current_base_row[210usize]) * (node_806)))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[344usize])
+                        * ((next_base_row[37usize]) + (node_525)))))
+                + ((current_base_row[216usize]) * (node_865)))
+                + ((current_base_row[221usize]) * (node_869)))
+                + ((current_base_row[214usize]) * (node_197)))
+                + ((current_base_row[224usize]) * (node_869)))
+                + ((current_base_row[228usize]) * (node_877)))
+                + ((current_base_row[225usize]) * (node_869)))
+                + ((current_base_row[226usize]) * (node_225)))
+                + ((current_base_row[215usize]) * (node_750)))
+                + ((current_base_row[223usize]) * (node_457)))
+                + ((current_base_row[233usize]) * (node_124)))
+                + ((current_base_row[230usize]) * (node_413)))
+                + ((current_base_row[232usize]) * (node_869)))
+                + ((current_base_row[235usize]) * (node_229)))
+                + ((current_base_row[236usize]) * (node_229)))
+                + ((current_base_row[237usize]) * (node_869)))
+                + ((current_base_row[239usize]) * (node_221)))
+                + ((current_base_row[238usize]) * (node_540)))
+                + ((current_base_row[240usize]) * (node_233)))
+                + ((current_base_row[241usize]) * (node_233)))
+                + ((current_base_row[242usize]) * (node_233)))
+                + ((current_base_row[244usize]) * (node_873)))
+                + ((current_base_row[245usize]) * (node_233)))
+                + ((current_base_row[247usize]) * (node_873)))
+                + ((current_base_row[250usize]) * (node_873)))
+                + ((current_base_row[275usize]) * (node_120)))
+                + ((current_base_row[278usize]) * (node_120)))
+                + ((current_base_row[276usize]) * (node_885)))
+                + ((current_base_row[279usize]) * (node_159)))
+                + ((current_base_row[254usize]) * (node_754)))
+                + ((current_base_row[259usize]) * (node_461)))
+                + ((current_base_row[281usize]) * (node_881)))
+                + ((current_base_row[283usize]) * (node_881))) * (node_3765))
+                + ((node_861) * (next_base_row[8usize])),
+            ((((((((((((current_base_row[204usize]) * (current_base_row[317usize]))
+                + ((current_base_row[206usize]) * (current_base_row[317usize])))
+                + ((current_base_row[210usize]) * (node_528)))
+                + ((current_base_row[208usize]) * (node_800)))
+                + ((current_base_row[214usize]) * (node_159)))
+                + ((current_base_row[215usize]) * (current_base_row[324usize])))
+                + ((current_base_row[223usize]) * (current_base_row[324usize])))
+                + ((current_base_row[254usize]) * (current_base_row[324usize])))
+                + ((current_base_row[259usize]) * (current_base_row[324usize])))
+                + ((current_base_row[281usize]) * (node_1465)))
+                + ((current_base_row[283usize]) * (node_1465))) * (node_3765),
+            (((((((((current_base_row[204usize]) * (current_base_row[331usize]))
+                + ((current_base_row[206usize]) * (current_base_row[331usize])))
+                + ((current_base_row[210usize]) * (node_533)))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[186usize]))) * (node_833))))
+                + ((current_base_row[215usize]) * (current_base_row[330usize])))
+                + ((current_base_row[223usize]) * (current_base_row[330usize])))
+                + ((current_base_row[254usize]) * (current_base_row[330usize])))
+                + ((current_base_row[259usize]) * (current_base_row[330usize])))
+                * (node_3765),
+            (((((((((current_base_row[204usize]) * (current_base_row[277usize]))
+                + ((current_base_row[206usize]) * (current_base_row[277usize])))
+                + ((current_base_row[210usize]) * (node_534)))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[187usize]))) * (node_837))))
+                + ((current_base_row[215usize]) * (current_base_row[331usize])))
+                + ((current_base_row[223usize]) * (current_base_row[331usize])))
+                + ((current_base_row[254usize]) * (current_base_row[331usize])))
+                + ((current_base_row[259usize]) * (current_base_row[331usize])))
+                * (node_3765),
+            (((((((((current_base_row[204usize]) * (current_base_row[342usize]))
+                + ((current_base_row[206usize]) * (current_base_row[342usize])))
+                + ((current_base_row[210usize]) * (node_535)))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[188usize]))) * (node_841))))
+                + ((current_base_row[215usize]) * (current_base_row[277usize])))
+                + ((current_base_row[223usize]) * (current_base_row[277usize])))
+                + ((current_base_row[254usize]) * (current_base_row[277usize])))
+                + ((current_base_row[259usize]) * (current_base_row[277usize])))
+                * (node_3765),
+            (((((((((current_base_row[204usize]) * (current_base_row[343usize]))
+                + ((current_base_row[206usize]) * (current_base_row[343usize])))
+                + ((current_base_row[210usize]) * (node_536)))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[189usize]))) * (node_845))))
+                + ((current_base_row[215usize]) * (current_base_row[342usize])))
+                + ((current_base_row[223usize]) * (current_base_row[342usize])))
+                + ((current_base_row[254usize]) * (current_base_row[342usize])))
+                + ((current_base_row[259usize]) * (current_base_row[342usize])))
+                * (node_3765),
+            (((((((((current_base_row[204usize]) * (current_base_row[344usize]))
+                + ((current_base_row[206usize]) * (current_base_row[344usize])))
+                + ((current_base_row[210usize]) * (node_537)))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[317usize]))) * (node_849))))
+                + ((current_base_row[215usize]) * (current_base_row[343usize])))
+                + ((current_base_row[223usize]) * (current_base_row[343usize])))
+                + ((current_base_row[254usize]) * (current_base_row[343usize])))
+                + ((current_base_row[259usize]) * (current_base_row[343usize])))
+                * (node_3765),
+            (((current_base_row[210usize]) * (node_541))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[330usize]))) * (node_865))))
+                * (node_3765),
+            ((current_base_row[208usize])
+                * (((BFieldElement::from_raw_u64(4294967295u64))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[344usize]))) * (node_885))) * (node_3765),
+            ((current_base_row[208usize]) * (node_131)) * (node_3765),
+            ((current_base_row[208usize]) * (current_base_row[269usize])) * (node_3765),
+            ((current_base_row[208usize]) * (current_base_row[263usize])) * (node_3765),
+            ((current_base_row[208usize]) * (current_base_row[264usize])) * (node_3765),
+            ((current_base_row[208usize]) * (node_154)) * (node_3765),
+            ((current_base_row[208usize]) * (node_120)) * (node_3765),
+            ((current_base_row[208usize]) * (node_124)) * (node_3765),
+            ((current_base_row[208usize]) * (node_128)) * (node_3765),
+            ((current_base_row[208usize]) * (node_116)) * (node_3765),
+            ((current_base_row[208usize]) * (node_158)) * (node_3765),
+            (node_4156) * (node_4155),
+            ((node_4156)
+                * ((next_base_row[49usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[49usize])))) * (next_base_row[47usize]),
+            ((current_base_row[47usize])
+                * ((current_base_row[47usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                * (node_4162),
+            (((current_base_row[51usize])
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+                * (current_base_row[51usize])) * (node_4225),
+            (current_base_row[54usize]) * (node_4233),
+            (node_4230) * (node_4233),
+            ((node_4233)
+                * ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (next_base_row[51usize])))
+                * ((next_base_row[53usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[53usize]))),
+            (node_4233)
+                * ((next_base_row[55usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[55usize]))),
+            (node_4233)
+                * ((next_base_row[56usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[56usize]))),
+            (node_4337) * (node_4336),
+            (node_4341)
+                * ((next_base_row[60usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[60usize]))),
+            (node_4341)
+                * ((next_base_row[61usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[61usize]))),
+            (((node_4337)
+                * ((node_4349) + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                * ((current_base_row[58usize])
+                    + (BFieldElement::from_raw_u64(18446743927680663586u64))))
+                * (node_4340),
+            ((current_base_row[358usize])
+                * ((current_base_row[64usize])
+                    + (BFieldElement::from_raw_u64(18446744052234715141u64))))
+                * (next_base_row[64usize]),
+            (((next_base_row[62usize]) * (node_5233)) * (node_5235))
+                * (((next_base_row[64usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[64usize])))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (current_base_row[363usize]) * (node_5263),
+            (node_5265)
+                * ((next_base_row[63usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[63usize]))),
+            (node_5265)
+                * ((next_base_row[62usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[62usize]))),
+            ((current_base_row[359usize]) * (node_5259)) * (next_base_row[62usize]),
+            (current_base_row[364usize]) * (next_base_row[62usize]),
+            ((node_5279) * (node_5251)) * (next_base_row[62usize]),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(263719581847590u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[313usize]))) + (current_base_row[113usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (node_5124))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(4758823762860u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[313usize]))) + (current_base_row[114usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (node_5135))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(123480309731250u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[313usize]))) + (current_base_row[115usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (node_5146))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(145268678818785u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[313usize]))) + (current_base_row[116usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (node_5157))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(32014686216930u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[313usize]))) + (current_base_row[117usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[97usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(185731565704980u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[313usize]))) + (current_base_row[118usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[98usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(231348413345175u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[313usize]))) + (current_base_row[119usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[99usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(51685636428030u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[313usize]))) + (current_base_row[120usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[100usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(244602682417545u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[313usize]))) + (current_base_row[121usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[101usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(118201794925695u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[313usize]))) + (current_base_row[122usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[102usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(177601192615545u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[313usize]))) + (current_base_row[123usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[103usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(175668457332795u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[313usize]))) + (current_base_row[124usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[104usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(51629801853195u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[313usize]))) + (current_base_row[125usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[105usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(256362302871255u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[313usize]))) + (current_base_row[126usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[106usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(115096533571410u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[313usize]))) + (current_base_row[127usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[107usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(76643691379275u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[313usize]))) + (current_base_row[128usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[108usize]))),
+            (current_base_row[129usize]) * (node_5770),
+            (current_base_row[135usize]) * (node_5822),
+            ((next_base_row[135usize]) * (next_base_row[136usize]))
+                + ((node_5822)
+                    * (((next_base_row[136usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[136usize])))
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))),
+            ((next_base_row[139usize]) * (current_base_row[143usize])) * (node_5872),
+            (next_base_row[139usize]) * (current_base_row[145usize]),
+            (node_5882)
+                * ((next_base_row[142usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[142usize]))),
+            (((node_5882) * (current_base_row[143usize])) * (node_5872)) * (node_5890),
+            ((node_5882) * (current_base_row[145usize])) * (node_5890),
+            (((node_5882) * (node_5872)) * (node_5875)) * (node_5896),
+            ((node_5882) * (node_5878)) * (node_5899),
+            (((current_base_row[314usize]) * (node_5916)) * (node_5918))
+                * (current_base_row[147usize]),
+            ((node_5921) * (node_5918)) * (node_5923),
+            (((current_base_row[318usize]) * (node_5896)) * (node_5878)) * (node_5923),
+            (((current_base_row[318usize]) * (node_5875)) * (node_5899))
+                * (current_base_row[147usize]),
+            ((current_base_row[361usize])
+                * ((current_base_row[139usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                * ((current_base_row[147usize])
+                    + (BFieldElement::from_raw_u64(18446744060824649731u64))),
+            ((current_base_row[361usize]) * (current_base_row[139usize]))
+                * (current_base_row[147usize]),
+            ((node_5882) * (current_base_row[360usize]))
+                * (((current_base_row[147usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((BFieldElement::from_raw_u64(8589934590u64))
+                            * (next_base_row[147usize]))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((node_5875) * (node_5878)))),
+            (current_base_row[367usize]) * ((current_base_row[147usize]) + (node_5888)),
+            ((current_base_row[347usize]) * (next_base_row[143usize]))
+                * ((next_base_row[147usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[147usize]))),
+            (current_base_row[362usize])
+                * ((next_base_row[143usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[143usize]))),
+            ((current_base_row[362usize]) * (node_5899))
+                * ((current_base_row[147usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (node_5982))),
+            ((current_base_row[362usize]) * (node_5878))
+                * ((current_base_row[147usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[368usize]))),
+            ((node_5882) * ((current_base_row[345usize]) * (node_5909)))
+                * (((current_base_row[147usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[147usize]))) + (node_5932)),
+            (current_base_row[169usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_243) * (node_245))),
+            (current_base_row[170usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_1522) * (node_1505))),
+            (current_base_row[171usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_1522) * (current_base_row[13usize]))),
+            (current_base_row[172usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[12usize]) * (node_1505)) * (node_1495))),
+            (current_base_row[173usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[170usize]) * (node_1495))),
+            (current_base_row[174usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[171usize]) * (node_1495))),
+            (current_base_row[175usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[169usize]) * (current_base_row[40usize]))),
+            (current_base_row[176usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_243) * (current_base_row[41usize]))),
+            (current_base_row[177usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[176usize]) * (node_248))),
+            (current_base_row[178usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[172usize]) * (node_1497))),
+            (current_base_row[179usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[172usize]) * (current_base_row[15usize]))),
+            (current_base_row[180usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[173usize]) * (node_1497))),
+            (current_base_row[181usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[173usize]) * (current_base_row[15usize]))),
+            (current_base_row[182usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[174usize]) * (node_1497))),
+            (current_base_row[183usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[169usize]) * (node_248))),
+            (current_base_row[184usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[174usize]) * (current_base_row[15usize]))),
+            (current_base_row[185usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[183usize]) * (current_base_row[39usize]))),
+            (current_base_row[186usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[175usize]) * (node_295))),
+            (current_base_row[187usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[175usize]) * (current_base_row[39usize]))),
+            (current_base_row[188usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[177usize]) * (node_295))),
+            (current_base_row[189usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[177usize]) * (current_base_row[39usize]))),
+            (current_base_row[190usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[12usize]) * (current_base_row[13usize]))
+                        * (node_1495))),
+            (current_base_row[191usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[179usize]) * (node_1499))),
+            (current_base_row[192usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[178usize]) * (node_1499))),
+            (current_base_row[193usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[180usize]) * (node_1499))),
+            (current_base_row[194usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[181usize]) * (node_1499))),
+            (current_base_row[195usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[171usize]) * (current_base_row[14usize]))),
+            (current_base_row[196usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[182usize]) * (node_1499))),
+            (current_base_row[197usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[184usize]) * (node_1499))),
+            (current_base_row[198usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[170usize]) * (current_base_row[14usize]))),
+            (current_base_row[199usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[190usize]) * (node_1497))),
+            (current_base_row[200usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[178usize]) * (current_base_row[16usize]))),
+            (current_base_row[201usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[181usize]) * (current_base_row[16usize]))),
+            (current_base_row[202usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[180usize]) * (current_base_row[16usize]))),
+            (current_base_row[203usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[182usize]) * (current_base_row[16usize]))),
+            (current_base_row[204usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[199usize]) * (node_1499)) * (node_1501))
+                        * (node_1503))),
+            (current_base_row[205usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[184usize]) * (current_base_row[16usize]))),
+            (current_base_row[206usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[191usize]) * (node_1501)) * (node_1503))),
+            (current_base_row[207usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[196usize]) * (node_1501))),
+            (current_base_row[208usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[179usize]) * (current_base_row[16usize]))
+                        * (node_1501)) * (node_1503))),
+            (current_base_row[209usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[193usize]) * (node_1501))),
+            (current_base_row[210usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[200usize]) * (node_1501)) * (node_1503))),
+            (current_base_row[211usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[194usize]) * (node_1501))),
+            (current_base_row[212usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[197usize]) * (node_1501))),
+            (current_base_row[213usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[192usize]) * (node_1501)) * (node_1503))),
+            (current_base_row[214usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[207usize]) * (node_1503))),
+            (current_base_row[215usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[191usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[216usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[209usize]) * (node_1503))),
+            (current_base_row[217usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[195usize]) * (node_1497))),
+            (current_base_row[218usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[202usize]) * (node_1501))),
+            (current_base_row[219usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[190usize]) * (current_base_row[15usize]))
+                        * (node_1499)) * (node_1501))),
+            (current_base_row[220usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[198usize]) * (node_1497))),
+            (current_base_row[221usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[211usize]) * (node_1503))),
+            (current_base_row[222usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[195usize]) * (current_base_row[15usize]))),
+            (current_base_row[223usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[219usize]) * (node_1503))),
+            (current_base_row[224usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[192usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[225usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[201usize]) * (node_1501)) * (node_1503))),
+            (current_base_row[226usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[212usize]) * (node_1503))),
+            (current_base_row[227usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[198usize]) * (current_base_row[15usize]))),
+            (current_base_row[228usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[218usize]) * (node_1503))),
+            (current_base_row[229usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[203usize]) * (node_1501))),
+            (current_base_row[230usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[205usize]) * (node_1501)) * (node_1503))),
+            (current_base_row[231usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[42usize]) * (node_245))),
+            (current_base_row[232usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[194usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[233usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[193usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[234usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[42usize]) * (current_base_row[41usize]))),
+            (current_base_row[235usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[197usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[236usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[203usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[237usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[201usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[238usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[220usize]) * (node_1499)) * (node_1501))
+                        * (node_1503))),
+            (current_base_row[239usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[205usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[240usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[217usize]) * (node_1499)) * (node_1501))
+                        * (node_1503))),
+            (current_base_row[241usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[222usize]) * (node_1499)) * (node_1501))
+                        * (node_1503))),
+            (current_base_row[242usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[217usize]) * (current_base_row[16usize]))
+                        * (node_1501)) * (node_1503))),
+            (current_base_row[243usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[229usize]) * (node_1503))),
+            (current_base_row[244usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[227usize]) * (node_1499)) * (node_1501))
+                        * (node_1503))),
+            (current_base_row[245usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[222usize]) * (current_base_row[16usize]))
+                        * (node_1501)) * (node_1503))),
+            (current_base_row[246usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[196usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[247usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[220usize]) * (current_base_row[16usize]))
+                        * (node_1501)) * (node_1503))),
+            (current_base_row[248usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[176usize]) * (current_base_row[40usize]))),
+            (current_base_row[249usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[202usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[250usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[227usize]) * (current_base_row[16usize]))
+                        * (node_1501)) * (node_1503))),
+            (current_base_row[251usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[231usize]) * (node_248))),
+            (current_base_row[252usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[97usize]) * (current_base_row[97usize]))
+                        * (current_base_row[97usize])) * (current_base_row[97usize]))),
+            (current_base_row[253usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[98usize]) * (current_base_row[98usize]))
+                        * (current_base_row[98usize])) * (current_base_row[98usize]))),
+            (current_base_row[254usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[200usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[255usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[99usize]) * (current_base_row[99usize]))
+                        * (current_base_row[99usize])) * (current_base_row[99usize]))),
+            (current_base_row[256usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[231usize]) * (current_base_row[40usize]))),
+            (current_base_row[257usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[100usize]) * (current_base_row[100usize]))
+                        * (current_base_row[100usize])) * (current_base_row[100usize]))),
+            (current_base_row[258usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[234usize]) * (node_248))),
+            (current_base_row[259usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[199usize]) * (current_base_row[16usize]))
+                        * (node_1501)) * (node_1503))),
+            (current_base_row[260usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[101usize]) * (current_base_row[101usize]))
+                        * (current_base_row[101usize])) * (current_base_row[101usize]))),
+            (current_base_row[261usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[234usize]) * (current_base_row[40usize]))),
+            (current_base_row[262usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[102usize]) * (current_base_row[102usize]))
+                        * (current_base_row[102usize])) * (current_base_row[102usize]))),
+            (current_base_row[263usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[41usize])
+                        * ((current_base_row[41usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))),
+            (current_base_row[264usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[42usize])
+                        * ((current_base_row[42usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))),
+            (current_base_row[265usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[103usize]) * (current_base_row[103usize]))
+                        * (current_base_row[103usize])) * (current_base_row[103usize]))),
+            (current_base_row[266usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[104usize]) * (current_base_row[104usize]))
+                        * (current_base_row[104usize])) * (current_base_row[104usize]))),
+            (current_base_row[267usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[105usize]) * (current_base_row[105usize]))
+                        * (current_base_row[105usize])) * (current_base_row[105usize]))),
+            (current_base_row[268usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[106usize]) * (current_base_row[106usize]))
+                        * (current_base_row[106usize])) * (current_base_row[106usize]))),
+            (current_base_row[269usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[40usize]) * (node_133))),
+            (current_base_row[270usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[107usize]) * (current_base_row[107usize]))
+                        * (current_base_row[107usize])) * (current_base_row[107usize]))),
+            (current_base_row[271usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[108usize]) * (current_base_row[108usize]))
+                        * (current_base_row[108usize])) * (current_base_row[108usize]))),
+            (current_base_row[272usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[248usize]) * (current_base_row[39usize]))),
+            (current_base_row[273usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[251usize]) * (node_295))),
+            (current_base_row[274usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[39usize]) * (current_base_row[22usize]))),
+            (current_base_row[275usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[207usize]) * (current_base_row[18usize]))),
+            (current_base_row[276usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[209usize]) * (current_base_row[18usize]))),
+            (current_base_row[277usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[258usize]) * (node_295))),
+            (current_base_row[278usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[212usize]) * (current_base_row[18usize]))),
+            (current_base_row[279usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[229usize]) * (current_base_row[18usize]))),
+            (current_base_row[280usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((next_base_row[64usize]) * (node_5308)) * (node_5309))
+                        * (node_5311))),
+            (current_base_row[281usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[211usize]) * (current_base_row[18usize]))),
+            (current_base_row[282usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((next_base_row[62usize]) * (node_5315)) * (node_5263))),
+            (current_base_row[283usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[218usize]) * (current_base_row[18usize]))),
+            (current_base_row[284usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[272usize])
+                        * ((next_base_row[22usize]) + (node_208)))),
+            (current_base_row[285usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[185usize]) * (node_527))),
+            (current_base_row[286usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[186usize])
+                        * ((next_base_row[25usize]) + (node_184)))),
+            (current_base_row[287usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[187usize])
+                        * ((next_base_row[26usize]) + (node_184)))),
+            (current_base_row[288usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[188usize])
+                        * ((next_base_row[27usize]) + (node_184)))),
+            (current_base_row[289usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[189usize]) * (node_704))),
+            (current_base_row[290usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[185usize]) * (node_189))),
+            (current_base_row[291usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[186usize])
+                        * ((next_base_row[23usize]) + (node_192)))),
+            (current_base_row[292usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[187usize])
+                        * ((next_base_row[23usize]) + (node_196)))),
+            (current_base_row[293usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[188usize])
+                        * ((next_base_row[23usize]) + (node_200)))),
+            (current_base_row[294usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[189usize]) * (node_410))),
+            (current_base_row[295usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[43usize])
+                        * ((current_base_row[43usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                        * ((current_base_row[43usize])
+                            + (BFieldElement::from_raw_u64(18446744060824649731u64))))
+                        * ((current_base_row[43usize])
+                            + (BFieldElement::from_raw_u64(18446744056529682436u64))))),
+            (current_base_row[296usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[44usize])
+                        * ((current_base_row[44usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                        * ((current_base_row[44usize])
+                            + (BFieldElement::from_raw_u64(18446744060824649731u64))))
+                        * ((current_base_row[44usize])
+                            + (BFieldElement::from_raw_u64(18446744056529682436u64))))),
+            (current_base_row[297usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[189usize]) * (node_409))),
+            (current_base_row[298usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[189usize]) * (node_703))),
+            (current_base_row[299usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((node_5901) * (node_5903)) * (node_5907)) * (node_5909))),
+            (current_base_row[300usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_5901)
+                        * ((next_base_row[142usize])
+                            + (BFieldElement::from_raw_u64(18446744043644780551u64))))),
+            (current_base_row[301usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((node_5308) * (node_5309)) * (node_5311)) * (node_5313))),
+            (current_base_row[302usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[252usize]) * (current_base_row[97usize]))
+                        * (current_base_row[97usize])) * (current_base_row[97usize]))),
+            (current_base_row[303usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[253usize]) * (current_base_row[98usize]))
+                        * (current_base_row[98usize])) * (current_base_row[98usize]))),
+            (current_base_row[304usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[255usize]) * (current_base_row[99usize]))
+                        * (current_base_row[99usize])) * (current_base_row[99usize]))),
+            (current_base_row[305usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[257usize]) * (current_base_row[100usize]))
+                        * (current_base_row[100usize])) * (current_base_row[100usize]))),
+            (current_base_row[306usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[260usize]) * (current_base_row[101usize]))
+                        * (current_base_row[101usize])) * (current_base_row[101usize]))),
+            (current_base_row[307usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[262usize]) * (current_base_row[102usize]))
+                        * (current_base_row[102usize])) * (current_base_row[102usize]))),
+            (current_base_row[308usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[265usize]) * (current_base_row[103usize]))
+                        * (current_base_row[103usize])) * (current_base_row[103usize]))),
+            (current_base_row[309usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[266usize]) * (current_base_row[104usize]))
+                        * (current_base_row[104usize])) * (current_base_row[104usize]))),
+            (current_base_row[310usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[267usize]) * (current_base_row[105usize]))
+                        * (current_base_row[105usize])) * (current_base_row[105usize]))),
+            (current_base_row[311usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[268usize]) * (current_base_row[106usize]))
+                        * (current_base_row[106usize])) * (current_base_row[106usize]))),
+            (current_base_row[312usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[270usize]) * (current_base_row[107usize]))
+                        * (current_base_row[107usize])) * (current_base_row[107usize]))),
+            (current_base_row[313usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[271usize]) * (current_base_row[108usize]))
+                        * (current_base_row[108usize])) * (current_base_row[108usize]))),
+            (current_base_row[314usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_5882) * ((current_base_row[299usize]) * (node_5913)))),
+            (current_base_row[315usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[300usize]) * (node_5903))),
+            (current_base_row[316usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[301usize]) * (node_5315))),
+            (current_base_row[317usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[248usize]) * (node_295))),
+            (current_base_row[318usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_5921) * (node_5916))),
+            (current_base_row[319usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[251usize]) * (current_base_row[39usize]))),
+            (current_base_row[320usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[185usize]) * (node_229))),
+            (current_base_row[321usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[186usize])
+                        * ((next_base_row[33usize]) + (node_232)))),
+            (current_base_row[322usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[187usize]) * (node_337))),
+            (current_base_row[323usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[188usize])
+                        * ((next_base_row[33usize]) + (node_240)))),
+            (current_base_row[324usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[183usize]) * (node_295))),
+            (current_base_row[325usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[185usize]) * (node_537))),
+            (current_base_row[326usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[186usize])
+                        * ((next_base_row[35usize]) + (node_224)))),
+            (current_base_row[327usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[187usize])
+                        * ((next_base_row[36usize]) + (node_224)))),
+            (current_base_row[328usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[188usize])
+                        * ((next_base_row[37usize]) + (node_224)))),
+            (current_base_row[329usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((node_5254) * (node_5275)) * (next_base_row[62usize]))),
+            (current_base_row[330usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[256usize]) * (node_295))),
+            (current_base_row[331usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[256usize]) * (current_base_row[39usize]))),
+            (current_base_row[332usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[185usize]) * (node_193))),
+            (current_base_row[333usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[185usize]) * (node_197))),
+            (current_base_row[334usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[186usize])
+                        * ((next_base_row[24usize]) + (node_196)))),
+            (current_base_row[335usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[186usize])
+                        * ((next_base_row[25usize]) + (node_200)))),
+            (current_base_row[336usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[187usize])
+                        * ((next_base_row[24usize]) + (node_200)))),
+            (current_base_row[337usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[187usize]) * (node_329))),
+            (current_base_row[338usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[188usize])
+                        * ((next_base_row[24usize]) + (node_204)))),
+            (current_base_row[339usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[188usize])
+                        * ((next_base_row[25usize]) + (node_208)))),
+            (current_base_row[340usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[189usize]) * (node_411))),
+            (current_base_row[341usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[189usize]) * (node_412))),
+            (current_base_row[342usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[258usize]) * (current_base_row[39usize]))),
+            (current_base_row[343usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[261usize]) * (node_295))),
+            (current_base_row[344usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[261usize]) * (current_base_row[39usize]))),
+            (current_base_row[345usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[315usize]) * (node_5907))),
+            (current_base_row[346usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((((next_base_row[12usize])
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+                        * (next_base_row[13usize]))
+                        * ((next_base_row[14usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                        * ((next_base_row[15usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))),
+            (current_base_row[347usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_5882)
+                        * (((current_base_row[315usize]) * (node_5909)) * (node_5913)))),
+            (current_base_row[348usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[22usize]) * (current_base_row[23usize]))),
+            (current_base_row[349usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((next_base_row[22usize]) * (current_base_row[22usize]))),
+            (current_base_row[350usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[39usize]) * (node_1243))),
+            (current_base_row[351usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[316usize])
+                        * (((node_5275) * (node_5259)) * (next_base_row[62usize])))),
+            (current_base_row[352usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((node_5250) * (node_5251)) * (current_base_row[62usize]))),
+            (current_base_row[353usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[238usize])
+                        * ((next_base_row[22usize])
+                            * (((current_base_row[39usize])
+                                * ((next_base_row[23usize])
+                                    + (BFieldElement::from_raw_u64(4294967296u64))))
+                                + (BFieldElement::from_raw_u64(
+                                    18446744065119617026u64,
+                                )))))),
+            (current_base_row[354usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[214usize])
+                        * ((((node_893) * (current_base_row[22usize]))
+                            + (((node_116) * (node_895)) * (node_133)))
+                            + ((((node_113)
+                                + (BFieldElement::from_raw_u64(18446744056529682436u64)))
+                                * (node_895)) * (current_base_row[40usize]))))),
+            (current_base_row[355usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[214usize])
+                        * (((current_base_row[263usize])
+                            * ((current_base_row[41usize])
+                                + (BFieldElement::from_raw_u64(18446744060824649731u64))))
+                            * ((current_base_row[41usize])
+                                + (BFieldElement::from_raw_u64(
+                                    18446744056529682436u64,
+                                )))))),
+            (current_base_row[356usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[214usize])
+                        * (((current_base_row[264usize])
+                            * ((current_base_row[42usize])
+                                + (BFieldElement::from_raw_u64(18446744060824649731u64))))
+                            * ((current_base_row[42usize])
+                                + (BFieldElement::from_raw_u64(
+                                    18446744056529682436u64,
+                                )))))),
+            (current_base_row[357usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[346usize]) * (next_base_row[16usize]))
+                        * ((next_base_row[17usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                        * ((next_base_row[18usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))),
+            (current_base_row[358usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[64usize])
+                        * ((current_base_row[64usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                        * ((current_base_row[64usize])
+                            + (BFieldElement::from_raw_u64(18446744060824649731u64))))
+                        * ((current_base_row[64usize])
+                            + (BFieldElement::from_raw_u64(18446744056529682436u64))))),
+            (current_base_row[359usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((node_5272) * (node_5251)) * (current_base_row[62usize]))
+                        * (node_5275))),
+            (current_base_row[360usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[300usize]) * (node_5907)) * (node_5909))
+                        * (node_5913))),
+            (current_base_row[361usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[318usize])
+                        * ((((BFieldElement::from_raw_u64(4294967295u64)) + (node_5932))
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (node_5878)))
+                            + (((BFieldElement::from_raw_u64(8589934590u64))
+                                * (node_5875)) * (node_5878))))),
+            (current_base_row[362usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_5882) * ((current_base_row[345usize]) * (node_5913)))),
+            (current_base_row[363usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[352usize])
+                        * (((node_5254) * (node_5259)) * (next_base_row[62usize])))),
+            (current_base_row[364usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((node_5279) * (current_base_row[62usize])) * (node_5259))),
+            (current_base_row[365usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[316usize]) * (node_5259))
+                        * (next_base_row[62usize])) * (node_5263))),
+            (current_base_row[366usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[316usize])
+                        * (((node_5366) * (node_5263)) * (node_5368)))),
+            (current_base_row[367usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[347usize])
+                        * ((BFieldElement::from_raw_u64(4294967295u64))
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((next_base_row[143usize]) * (next_base_row[144usize])))))
+                        * (current_base_row[143usize]))),
+            (current_base_row[368usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_5982) * (current_base_row[143usize]))),
+        ];
+        let ext_constraints = [
+            (((BFieldElement::from_raw_u64(4294967295u64))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (current_base_row[5usize])))
+                * (((node_51)
+                    * ((challenges[3usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((((challenges[13usize]) * (current_base_row[0usize]))
+                                + ((challenges[14usize]) * (current_base_row[1usize])))
+                                + ((challenges[15usize]) * (next_base_row[1usize]))))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[2usize]))))
+                + ((current_base_row[5usize]) * (node_51)),
+            ((node_31)
+                * (((next_ext_row[1usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((challenges[29usize]) * (current_ext_row[1usize]))))
+                    + (node_74)))
+                + ((node_34)
+                    * (((next_ext_row[1usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (challenges[29usize]))) + (node_74))),
+            ((((((next_ext_row[2usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((challenges[30usize]) * (current_ext_row[2usize]))))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (next_ext_row[1usize]))) * (node_47))
+                * ((BFieldElement::from_raw_u64(4294967295u64))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((next_base_row[4usize]) * (node_90)))))
+                + ((node_88) * (next_base_row[6usize]))) + ((node_88) * (node_90)),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_131)) + ((current_base_row[213usize]) * (node_529)))
+                + ((current_base_row[206usize]) * (node_131)))
+                + ((current_base_row[210usize]) * (node_384)))
+                + ((current_base_row[208usize]) * (node_678)))
+                + ((current_base_row[216usize]) * (node_893)))
+                + ((current_base_row[221usize]) * (node_785)))
+                + ((current_base_row[214usize]) * (current_base_row[269usize])))
+                + ((current_base_row[224usize]) * (node_785)))
+                + ((current_base_row[228usize]) * (node_833)))
+                + ((current_base_row[225usize]) * (node_785)))
+                + ((current_base_row[226usize]) * (node_893)))
+                + ((current_base_row[215usize]) * (node_131)))
+                + ((current_base_row[223usize]) * (node_131)))
+                + ((current_base_row[243usize]) * (node_414)))
+                + ((current_base_row[233usize])
+                    * (((node_295) * (node_833))
+                        + ((current_base_row[39usize]) * (node_705)))))
+                + ((current_base_row[230usize])
+                    * ((current_base_row[31usize]) + (node_196))))
+                + ((current_base_row[232usize]) * (node_785)))
+                + ((current_base_row[246usize])
+                    * ((node_158) + (BFieldElement::from_raw_u64(42949672950u64)))))
+                + ((current_base_row[249usize])
+                    * ((node_158)
+                        + (BFieldElement::from_raw_u64(18446744026464911371u64)))))
+                + ((current_base_row[235usize]) * (node_893)))
+                + ((current_base_row[236usize]) * (node_893)))
+                + ((current_base_row[237usize]) * (node_893)))
+                + ((current_base_row[239usize]) * (node_124)))
+                + ((current_base_row[238usize]) * (node_529)))
+                + ((current_base_row[240usize]) * (node_189)))
+                + ((current_base_row[241usize]) * (node_189)))
+                + ((current_base_row[242usize]) * (node_189)))
+                + ((current_base_row[244usize]) * (node_829)))
+                + ((current_base_row[245usize]) * (node_189)))
+                + ((current_base_row[247usize]) * (node_893)))
+                + ((current_base_row[250usize]) * (node_829)))
+                + ((current_base_row[275usize]) * (node_330)))
+                + ((current_base_row[278usize]) * (node_330)))
+                + ((current_base_row[276usize]) * (node_841)))
+                + ((current_base_row[279usize]) * (node_201)))
+                + ((current_base_row[254usize]) * (node_131)))
+                + ((current_base_row[259usize]) * (node_131)))
+                + ((current_base_row[281usize]) * (node_517)))
+                + ((current_base_row[283usize]) * (node_517))) * (node_3765))
+                + ((node_124) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (current_base_row[269usize]))
+                + ((current_base_row[213usize]) * (node_530)))
+                + ((current_base_row[206usize]) * (current_base_row[269usize])))
+                + ((current_base_row[210usize]) * (current_base_row[297usize])))
+                + ((current_base_row[208usize]) * (current_base_row[298usize])))
+                + ((current_base_row[216usize]) * (node_785)))
+                + ((current_base_row[221usize]) * (node_829)))
+                + (current_base_row[355usize]))
+                + ((current_base_row[224usize]) * (node_829)))
+                + ((current_base_row[228usize]) * (node_837)))
+                + ((current_base_row[225usize]) * (node_829)))
+                + ((current_base_row[226usize]) * (node_185)))
+                + ((current_base_row[215usize]) * (current_base_row[269usize])))
+                + ((current_base_row[223usize]) * (current_base_row[269usize])))
+                + ((current_base_row[243usize]) * (node_415)))
+                + ((current_base_row[233usize])
+                    * (((node_295) * (node_837))
+                        + ((current_base_row[39usize]) * (node_706)))))
+                + ((current_base_row[230usize]) * (node_120)))
+                + ((current_base_row[232usize]) * (node_829)))
+                + ((current_base_row[246usize])
+                    * ((next_ext_row[6usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_ext_row[69usize])))))
+                + ((current_base_row[249usize])
+                    * ((next_ext_row[6usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_ext_row[70usize])))))
+                + ((current_base_row[235usize]) * (node_189)))
+                + ((current_base_row[236usize]) * (node_189)))
+                + ((current_base_row[237usize]) * (node_829)))
+                + ((current_base_row[239usize]) * (node_128)))
+                + ((current_base_row[238usize]) * (node_530)))
+                + ((current_base_row[240usize]) * (node_193)))
+                + ((current_base_row[241usize]) * (node_193)))
+                + ((current_base_row[242usize]) * (node_193)))
+                + ((current_base_row[244usize]) * (node_833)))
+                + ((current_base_row[245usize]) * (node_193)))
+                + ((current_base_row[247usize]) * (node_833)))
+                + ((current_base_row[250usize]) * (node_833)))
+                + ((current_base_row[275usize]) * (node_331)))
+                + ((current_base_row[278usize]) * (node_331)))
+                + ((current_base_row[276usize]) * (node_845)))
+                + ((current_base_row[279usize]) * (node_205)))
+                + ((current_base_row[254usize]) * (current_base_row[269usize])))
+                + ((current_base_row[259usize]) * (current_base_row[269usize])))
+                + ((current_base_row[281usize]) * (node_521)))
+                + ((current_base_row[283usize]) * (node_521))) * (node_3765))
+                + ((node_128) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_441)) + ((current_base_row[213usize]) * (node_535)))
+                + ((current_base_row[206usize]) * (node_734)))
+                + ((current_base_row[210usize]) * (node_796)))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[330usize]) * (node_815))))
+                + ((current_base_row[216usize]) * (node_845)))
+                + ((current_base_row[221usize]) * (node_849)))
+                + ((current_base_row[214usize]) * (node_124)))
+                + ((current_base_row[224usize]) * (node_849)))
+                + ((current_base_row[228usize]) * (node_857)))
+                + ((current_base_row[225usize]) * (node_849)))
+                + ((current_base_row[226usize]) * (node_205)))
+                + ((current_base_row[215usize])
+                    * ((((((current_base_row[185usize])
+                        * ((node_785) + (BFieldElement::from_raw_u64(4294967295u64))))
+                        + ((current_base_row[186usize])
+                            * ((node_785)
+                                + (BFieldElement::from_raw_u64(8589934590u64)))))
+                        + ((current_base_row[187usize])
+                            * ((node_785)
+                                + (BFieldElement::from_raw_u64(12884901885u64)))))
+                        + ((current_base_row[188usize])
+                            * ((node_785)
+                                + (BFieldElement::from_raw_u64(17179869180u64)))))
+                        + ((current_base_row[189usize])
+                            * ((node_785)
+                                + (BFieldElement::from_raw_u64(21474836475u64)))))))
+                + ((current_base_row[223usize])
+                    * ((((((current_base_row[185usize]) * (node_1036))
+                        + ((current_base_row[186usize])
+                            * ((node_785)
+                                + (BFieldElement::from_raw_u64(18446744060824649731u64)))))
+                        + ((current_base_row[187usize]) * (node_1062)))
+                        + ((current_base_row[188usize])
+                            * ((node_785)
+                                + (BFieldElement::from_raw_u64(18446744052234715141u64)))))
+                        + ((current_base_row[189usize])
+                            * ((node_785)
+                                + (BFieldElement::from_raw_u64(
+                                    18446744047939747846u64,
+                                ))))))) + ((current_base_row[243usize]) * (node_397)))
+                + ((current_base_row[233usize]) * (node_712)))
+                + ((current_base_row[230usize]) * (node_408)))
+                + ((current_base_row[232usize]) * (node_849)))
+                + ((current_base_row[246usize])
+                    * ((next_base_row[26usize]) + (node_236))))
+                + ((current_base_row[249usize])
+                    * ((next_base_row[36usize]) + (node_196))))
+                + ((current_base_row[235usize]) * (node_209)))
+                + ((current_base_row[236usize]) * (node_209)))
+                + ((current_base_row[237usize]) * (node_849)))
+                + ((current_base_row[239usize]) * (node_201)))
+                + ((current_base_row[238usize]) * (node_535)))
+                + ((current_base_row[240usize]) * (node_213)))
+                + ((current_base_row[241usize]) * (node_213)))
+                + ((current_base_row[242usize]) * (node_213)))
+                + ((current_base_row[244usize]) * (node_853)))
+                + ((current_base_row[245usize]) * (node_213)))
+                + ((current_base_row[247usize]) * (node_853)))
+                + ((current_base_row[250usize]) * (node_853)))
+                + ((current_base_row[275usize]) * (node_336)))
+                + ((current_base_row[278usize]) * (node_336)))
+                + ((current_base_row[276usize]) * (node_865)))
+                + ((current_base_row[279usize]) * (node_225)))
+                + ((current_base_row[254usize]) * (node_734)))
+                + ((current_base_row[259usize]) * (node_441)))
+                + ((current_base_row[281usize]) * (node_861)))
+                + ((current_base_row[283usize]) * (node_861))) * (node_3765))
+                + ((node_841) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_445)) + ((current_base_row[213usize]) * (node_536)))
+                + ((current_base_row[206usize]) * (node_738)))
+                + ((current_base_row[210usize]) * (node_798)))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[331usize])
+                        * ((next_base_row[33usize]) + (node_525)))))
+                + ((current_base_row[216usize]) * (node_849)))
+                + ((current_base_row[221usize]) * (node_853)))
+                + ((current_base_row[214usize]) * (node_128)))
+                + ((current_base_row[224usize]) * (node_853)))
+                + ((current_base_row[228usize]) * (node_861)))
+                + ((current_base_row[225usize]) * (node_853)))
+                + ((current_base_row[226usize]) * (node_209)))
+                + ((current_base_row[215usize]) * (node_734)))
+                + ((current_base_row[223usize]) * (node_441)))
+                + ((current_base_row[243usize]) * (node_408)))
+                + ((current_base_row[233usize]) * (node_713)))
+                + ((current_base_row[230usize]) * (node_409)))
+                + ((current_base_row[232usize]) * (node_853)))
+                + ((current_base_row[246usize])
+                    * ((next_base_row[27usize]) + (node_240))))
+                + ((current_base_row[249usize])
+                    * ((next_base_row[37usize]) + (node_200))))
+                + ((current_base_row[235usize]) * (node_213)))
+                + ((current_base_row[236usize]) * (node_213)))
+                + ((current_base_row[237usize]) * (node_853)))
+                + ((current_base_row[239usize]) * (node_205)))
+                + ((current_base_row[238usize]) * (node_536)))
+                + ((current_base_row[240usize]) * (node_217)))
+                + ((current_base_row[241usize]) * (node_217)))
+                + ((current_base_row[242usize]) * (node_217)))
+                + ((current_base_row[244usize]) * (node_857)))
+                + ((current_base_row[245usize]) * (node_217)))
+                + ((current_base_row[247usize]) * (node_857)))
+                + ((current_base_row[250usize]) * (node_857)))
+                + ((current_base_row[275usize]) * (node_337)))
+                + ((current_base_row[278usize]) * (node_337)))
+                + ((current_base_row[276usize]) * (node_869)))
+                + ((current_base_row[279usize]) * (node_229)))
+                + ((current_base_row[254usize]) * (node_738)))
+                + ((current_base_row[259usize]) * (node_445)))
+                + ((current_base_row[281usize]) * (node_865)))
+                + ((current_base_row[283usize]) * (node_865))) * (node_3765))
+                + ((node_845) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_449)) + ((current_base_row[213usize]) * (node_537)))
+                + ((current_base_row[206usize]) * (node_742)))
+                + ((current_base_row[210usize]) * (node_800)))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[277usize])
+                        * ((next_base_row[34usize]) + (node_525)))))
+                + ((current_base_row[216usize]) * (node_853)))
+                + ((current_base_row[221usize]) * (node_857)))
+                + ((current_base_row[214usize]) * (node_185)))
+                + ((current_base_row[224usize]) * (node_857)))
+                + ((current_base_row[228usize]) * (node_865)))
+                + ((current_base_row[225usize]) * (node_857)))
+                + ((current_base_row[226usize]) * (node_213)))
+                + (current_ext_row[84usize])) + (current_ext_row[85usize]))
+                + ((current_base_row[243usize]) * (node_513)))
+                + ((current_base_row[233usize]) * (node_691)))
+                + ((current_base_row[230usize]) * (node_410)))
+                + ((current_base_row[232usize]) * (node_857)))
+                + ((current_base_row[246usize]) * (node_513)))
+                + ((current_base_row[249usize]) * (node_513)))
+                + ((current_base_row[235usize]) * (node_217)))
+                + ((current_base_row[236usize]) * (node_217)))
+                + ((current_base_row[237usize]) * (node_857)))
+                + ((current_base_row[239usize]) * (node_209)))
+                + ((current_base_row[238usize]) * (node_537)))
+                + ((current_base_row[240usize]) * (node_221)))
+                + ((current_base_row[241usize]) * (node_221)))
+                + ((current_base_row[242usize]) * (node_221)))
+                + ((current_base_row[244usize]) * (node_861)))
+                + ((current_base_row[245usize]) * (node_221)))
+                + ((current_base_row[247usize]) * (node_861)))
+                + ((current_base_row[250usize]) * (node_861)))
+                + ((current_base_row[275usize]) * (node_338)))
+                + ((current_base_row[278usize]) * (node_338)))
+                + ((current_base_row[276usize]) * (node_873)))
+                + ((current_base_row[279usize]) * (node_233)))
+                + ((current_base_row[254usize]) * (node_742)))
+                + ((current_base_row[259usize]) * (node_449)))
+                + ((current_base_row[281usize]) * (node_869)))
+                + ((current_base_row[283usize]) * (node_869))) * (node_3765))
+                + ((node_849) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_453)) + ((current_base_row[213usize]) * (node_538)))
+                + ((current_base_row[206usize]) * (node_746)))
+                + ((current_base_row[210usize]) * (node_802)))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[342usize])
+                        * ((next_base_row[35usize]) + (node_525)))))
+                + ((current_base_row[216usize]) * (node_857)))
+                + ((current_base_row[221usize]) * (node_861)))
+                + ((current_base_row[214usize]) * (node_189)))
+                + ((current_base_row[224usize]) * (node_861)))
+                + ((current_base_row[228usize]) * (node_869)))
+                + ((current_base_row[225usize]) * (node_861)))
+                + ((current_base_row[226usize]) * (node_217)))
+                + ((current_base_row[215usize]) * (node_742)))
+                + ((current_base_row[223usize]) * (node_449)))
+                + ((current_base_row[243usize]) * (node_517)))
+                + ((current_base_row[233usize]) * (node_702)))
+                + ((current_base_row[230usize]) * (node_411)))
+                + ((current_base_row[232usize]) * (node_861)))
+                + ((current_base_row[246usize]) * (node_517)))
+                + ((current_base_row[249usize]) * (node_517)))
+                + ((current_base_row[235usize]) * (node_221)))
+                + ((current_base_row[236usize]) * (node_221)))
+                + ((current_base_row[237usize]) * (node_861)))
+                + ((current_base_row[239usize]) * (node_213)))
+                + ((current_base_row[238usize]) * (node_538)))
+                + ((current_base_row[240usize]) * (node_225)))
+                + ((current_base_row[241usize]) * (node_225)))
+                + ((current_base_row[242usize]) * (node_225)))
+                + ((current_base_row[244usize]) * (node_865)))
+                + ((current_base_row[245usize]) * (node_225)))
+                + ((current_base_row[247usize]) * (node_865)))
+                + ((current_base_row[250usize]) * (node_865)))
+                + ((current_base_row[275usize]) * (node_314)))
+                + ((current_base_row[278usize]) * (node_314)))
+                + ((current_base_row[276usize]) * (node_877)))
+                + ((current_base_row[279usize]) * (node_237)))
+                + ((current_base_row[254usize]) * (node_746)))
+                + ((current_base_row[259usize]) * (node_453)))
+                + ((current_base_row[281usize]) * (node_873)))
+                + ((current_base_row[283usize]) * (node_873))) * (node_3765))
+                + ((node_853) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_457)) + ((current_base_row[213usize]) * (node_539)))
+                + ((current_base_row[206usize]) * (node_750)))
+                + ((current_base_row[210usize]) * (node_804)))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[343usize])
+                        * ((next_base_row[36usize]) + (node_525)))))
+                + ((current_base_row[216usize]) * (node_861)))
+                + ((current_base_row[221usize]) * (node_865)))
+                + ((current_base_row[214usize]) * (node_193)))
+                + ((current_base_row[224usize]) * (node_865)))
+                + ((current_base_row[228usize]) * (node_873)))
+                + ((current_base_row[225usize]) * (node_865)))
+                + ((current_base_row[226usize]) * (node_221)))
+                + ((current_base_row[215usize]) * (node_746)))
+                + ((current_base_row[223usize]) * (node_453)))
+                + ((current_base_row[243usize]) * (node_521)))
+                + ((current_base_row[233usize]) * (node_120)))
+                + ((current_base_row[230usize]) * (node_412)))
+                + ((current_base_row[232usize]) * (node_865)))
+                + ((current_base_row[246usize]) * (node_521)))
+                + ((current_base_row[249usize]) * (node_521)))
+                + ((current_base_row[235usize]) * (node_225)))
+                + ((current_base_row[236usize]) * (node_225)))
+                + ((current_base_row[237usize]) * (node_865)))
+                + ((current_base_row[239usize]) * (node_217)))
+                + ((current_base_row[238usize]) * (node_539)))
+                + ((current_base_row[240usize]) * (node_229)))
+                + ((current_base_row[241usize]) * (node_229)))
+                + ((current_base_row[242usize]) * (node_229)))
+                + ((current_base_row[244usize]) * (node_869)))
+                + ((current_base_row[245usize]) * (node_229)))
+                + ((current_base_row[247usize]) * (node_869)))
+                + ((current_base_row[250usize]) * (node_869)))
+                + ((current_base_row[275usize]) * (node_325)))
+                + ((current_base_row[278usize]) * (node_325)))
+                + ((current_base_row[276usize]) * (node_881)))
+                + ((current_base_row[279usize]) * (node_241)))
+                + ((current_base_row[254usize]) * (node_750)))
+                + ((current_base_row[259usize]) * (node_457)))
+                + ((current_base_row[281usize]) * (node_877)))
+                + ((current_base_row[283usize]) * (node_877))) * (node_3765))
+                + ((node_857) * (next_base_row[8usize])),
+            (((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_465)) + ((current_base_row[213usize]) * (node_541)))
+                + ((current_base_row[206usize]) * (node_758)))
+                + ((current_base_row[210usize]) * (node_131)))
+                + ((current_base_row[208usize]) * (node_253)))
+                + ((current_base_row[216usize]) * (node_869)))
+                + ((current_base_row[221usize]) * (node_873)))
+                + ((current_base_row[214usize]) * (node_201)))
+                + ((current_base_row[224usize]) * (node_873)))
+                + ((current_base_row[228usize]) * (node_881)))
+                + ((current_base_row[225usize]) * (node_873)))
+                + ((current_base_row[226usize]) * (node_229)))
+                + ((current_base_row[215usize]) * (node_754)))
+                + ((current_base_row[223usize]) * (node_461)))
+                + ((current_base_row[233usize]) * (node_128)))
+                + ((current_base_row[230usize]) * (node_414)))
+                + ((current_base_row[232usize]) * (node_873)))
+                + ((current_base_row[235usize]) * (node_233)))
+                + ((current_base_row[236usize]) * (node_233)))
+                + ((current_base_row[237usize]) * (node_873)))
+                + ((current_base_row[239usize]) * (node_225)))
+                + ((current_base_row[238usize]) * (node_541)))
+                + ((current_base_row[240usize]) * (node_237)))
+                + ((current_base_row[241usize]) * (node_237)))
+                + ((current_base_row[242usize]) * (node_237)))
+                + ((current_base_row[244usize]) * (node_877)))
+                + ((current_base_row[245usize]) * (node_237)))
+                + ((current_base_row[247usize]) * (node_877)))
+                + ((current_base_row[250usize]) * (node_877)))
+                + ((current_base_row[275usize]) * (node_124)))
+                + ((current_base_row[278usize]) * (node_124)))
+                + ((current_base_row[276usize]) * (node_888)))
+                + ((current_base_row[279usize]) * (node_181)))
+                + ((current_base_row[254usize]) * (node_758)))
+                + ((current_base_row[259usize]) * (node_465)))
+                + ((current_base_row[281usize]) * (node_885)))
+                + ((current_base_row[283usize]) * (node_885))) * (node_3765))
+                + ((node_865) * (next_base_row[8usize])),
+            (((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_469)) + ((current_base_row[213usize]) * (node_550)))
+                + ((current_base_row[206usize]) * (node_762)))
+                + ((current_base_row[210usize]) * (current_base_row[269usize])))
+                + ((current_base_row[208usize]) * (node_299)))
+                + ((current_base_row[216usize]) * (node_873)))
+                + ((current_base_row[221usize]) * (node_877)))
+                + ((current_base_row[214usize]) * (node_205)))
+                + ((current_base_row[224usize]) * (node_877)))
+                + ((current_base_row[228usize]) * (node_885)))
+                + ((current_base_row[225usize]) * (node_877)))
+                + ((current_base_row[226usize]) * (node_233)))
+                + ((current_base_row[215usize]) * (node_758)))
+                + ((current_base_row[223usize]) * (node_465)))
+                + ((current_base_row[233usize]) * (node_893)))
+                + ((current_base_row[230usize]) * (node_415)))
+                + ((current_base_row[232usize]) * (node_877)))
+                + ((current_base_row[235usize]) * (node_237)))
+                + ((current_base_row[236usize]) * (node_237)))
+                + ((current_base_row[237usize]) * (node_877)))
+                + ((current_base_row[239usize]) * (node_229)))
+                + ((current_base_row[238usize]) * (node_550)))
+                + ((current_base_row[240usize]) * (node_241)))
+                + ((current_base_row[241usize]) * (node_241)))
+                + ((current_base_row[242usize]) * (node_241)))
+                + ((current_base_row[244usize]) * (node_881)))
+                + ((current_base_row[245usize]) * (node_241)))
+                + ((current_base_row[247usize]) * (node_881)))
+                + ((current_base_row[250usize]) * (node_881)))
+                + ((current_base_row[275usize]) * (node_128)))
+                + ((current_base_row[278usize]) * (node_128)))
+                + ((current_base_row[276usize]) * (node_158)))
+                + ((current_base_row[279usize]) * (node_120)))
+                + ((current_base_row[254usize]) * (node_762)))
+                + ((current_base_row[259usize]) * (node_469)))
+                + ((current_base_row[281usize]) * (node_888)))
+                + ((current_base_row[283usize]) * (node_888))) * (node_3765))
+                + ((node_869) * (next_base_row[8usize])),
+            (((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_473)) + ((current_base_row[213usize]) * (node_120)))
+                + ((current_base_row[206usize]) * (node_766)))
+                + ((current_base_row[210usize]) * (current_base_row[263usize])))
+                + ((current_base_row[208usize]) * (node_342)))
+                + ((current_base_row[216usize]) * (node_877)))
+                + ((current_base_row[221usize]) * (node_881)))
+                + ((current_base_row[214usize]) * (node_209)))
+                + ((current_base_row[224usize]) * (node_881)))
+                + ((current_base_row[228usize]) * (node_888)))
+                + ((current_base_row[225usize]) * (node_881)))
+                + ((current_base_row[226usize]) * (node_237)))
+                + ((current_base_row[215usize]) * (node_762)))
+                + ((current_base_row[223usize]) * (node_469)))
+                + ((current_base_row[233usize]) * (node_513)))
+                + ((current_base_row[230usize]) * (node_416)))
+                + ((current_base_row[232usize]) * (node_881)))
+                + ((current_base_row[235usize]) * (node_241)))
+                + ((current_base_row[236usize]) * (node_241)))
+                + ((current_base_row[237usize]) * (node_881)))
+                + ((current_base_row[239usize]) * (node_233)))
+                + ((current_base_row[238usize]) * (node_120)))
+                + ((current_base_row[240usize]) * (node_159)))
+                + ((current_base_row[241usize]) * (node_159)))
+                + ((current_base_row[242usize]) * (node_159)))
+                + ((current_base_row[244usize]) * (node_885)))
+                + ((current_base_row[245usize]) * (node_159)))
+                + ((current_base_row[247usize]) * (node_885)))
+                + ((current_base_row[250usize]) * (node_885)))
+                + ((current_base_row[275usize]) * (node_893)))
+                + ((current_base_row[278usize]) * (node_893)))
+                + ((current_base_row[276usize]) * (node_120)))
+                + ((current_base_row[279usize]) * (node_124)))
+                + ((current_base_row[254usize]) * (node_766)))
+                + ((current_base_row[259usize]) * (node_473)))
+                + ((current_base_row[281usize]) * (node_158)))
+                + ((current_base_row[283usize]) * (node_158))) * (node_3765))
+                + ((node_873) * (next_base_row[8usize])),
+            (((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_477)) + ((current_base_row[213usize]) * (node_124)))
+                + ((current_base_row[206usize]) * (node_770)))
+                + ((current_base_row[210usize]) * (current_base_row[264usize])))
+                + ((current_base_row[208usize]) * (node_384)))
+                + ((current_base_row[216usize]) * (node_881)))
+                + ((current_base_row[221usize]) * (node_885)))
+                + ((current_base_row[214usize]) * (node_213)))
+                + ((current_base_row[224usize]) * (node_885)))
+                + ((current_base_row[228usize]) * (node_158)))
+                + ((current_base_row[225usize]) * (node_885)))
+                + ((current_base_row[226usize]) * (node_241)))
+                + ((current_base_row[215usize]) * (node_766)))
+                + ((current_base_row[223usize]) * (node_473)))
+                + ((current_base_row[233usize]) * (node_517)))
+                + ((current_base_row[230usize]) * (node_417)))
+                + ((current_base_row[232usize]) * (node_885)))
+                + ((current_base_row[235usize]) * (node_159)))
+                + ((current_base_row[236usize]) * (node_159)))
+                + ((current_base_row[237usize]) * (node_885)))
+                + ((current_base_row[239usize]) * (node_237)))
+                + ((current_base_row[238usize]) * (node_124)))
+                + ((current_base_row[240usize]) * (node_181)))
+                + ((current_base_row[241usize]) * (node_181)))
+                + ((current_base_row[242usize]) * (node_181)))
+                + ((current_base_row[244usize]) * (node_888)))
+                + ((current_base_row[245usize]) * (node_181)))
+                + ((current_base_row[247usize]) * (node_888)))
+                + ((current_base_row[250usize]) * (node_888)))
+                + ((current_base_row[275usize]) * (node_513)))
+                + ((current_base_row[278usize]) * (node_513)))
+                + ((current_base_row[276usize]) * (node_124)))
+                + ((current_base_row[279usize]) * (node_128)))
+                + ((current_base_row[254usize]) * (node_770)))
+                + ((current_base_row[259usize]) * (node_477))) + (node_1695))
+                + (node_1697)) * (node_3765)) + ((node_877) * (next_base_row[8usize])),
+            (((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_481)) + ((current_base_row[213usize]) * (node_128)))
+                + ((current_base_row[206usize]) * (node_774)))
+                + ((current_base_row[210usize]) * (node_154)))
+                + ((current_base_row[208usize]) * (current_base_row[297usize])))
+                + ((current_base_row[216usize]) * (node_885)))
+                + ((current_base_row[221usize]) * (node_888)))
+                + ((current_base_row[214usize]) * (node_217)))
+                + ((current_base_row[224usize]) * (node_888)))
+                + ((current_base_row[228usize]) * (node_513)))
+                + ((current_base_row[225usize]) * (node_888)))
+                + ((current_base_row[226usize]) * (node_159)))
+                + ((current_base_row[215usize]) * (node_770)))
+                + ((current_base_row[223usize]) * (node_477)))
+                + ((current_base_row[233usize]) * (node_521)))
+                + ((current_base_row[230usize]) * (node_418)))
+                + ((current_base_row[232usize]) * (node_888)))
+                + ((current_base_row[235usize]) * (node_181)))
+                + ((current_base_row[236usize]) * (node_181)))
+                + ((current_base_row[237usize]) * (node_888)))
+                + ((current_base_row[239usize]) * (node_241)))
+                + ((current_base_row[238usize]) * (node_128)))
+                + ((current_base_row[240usize]) * (node_513)))
+                + ((current_base_row[241usize]) * (node_513)))
+                + ((current_base_row[242usize]) * (node_513)))
+                + ((current_base_row[244usize]) * (node_158)))
+                + ((current_base_row[245usize]) * (node_513)))
+                + ((current_base_row[247usize]) * (node_158)))
+                + ((current_base_row[250usize]) * (node_158)))
+                + ((current_base_row[275usize]) * (node_517)))
+                + ((current_base_row[278usize]) * (node_517)))
+                + ((current_base_row[276usize]) * (node_128)))
+                + ((current_base_row[279usize]) * (node_893)))
+                + ((current_base_row[254usize]) * (node_774)))
+                + ((current_base_row[259usize]) * (node_481))) + (node_1774))
+                + (node_1776)) * (node_3765)) + ((node_881) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_485)) + ((current_base_row[213usize]) * (node_116)))
+                + ((current_base_row[206usize]) * (node_778)))
+                + ((current_base_row[210usize]) * (node_120)))
+                + ((current_base_row[208usize]) * (node_788)))
+                + ((current_base_row[216usize]) * (node_888)))
+                + ((current_base_row[221usize]) * (node_158)))
+                + ((current_base_row[214usize]) * (node_221)))
+                + ((current_base_row[224usize]) * (node_158)))
+                + ((current_base_row[228usize]) * (node_517)))
+                + ((current_base_row[225usize]) * (node_158)))
+                + ((current_base_row[226usize]) * (node_181)))
+                + ((current_base_row[215usize]) * (node_774)))
+                + ((current_base_row[223usize]) * (node_481)))
+                + ((current_base_row[230usize]) * (node_419)))
+                + ((current_base_row[232usize]) * (node_158)))
+                + ((current_base_row[235usize]) * (node_513)))
+                + ((current_base_row[236usize]) * (node_513)))
+                + ((current_base_row[237usize]) * (node_158)))
+                + ((current_base_row[239usize]) * (node_159)))
+                + ((current_base_row[238usize]) * (node_893)))
+                + ((current_base_row[240usize]) * (node_517)))
+                + ((current_base_row[241usize]) * (node_517)))
+                + ((current_base_row[242usize]) * (node_517)))
+                + ((current_base_row[244usize]) * (node_513)))
+                + ((current_base_row[245usize]) * (node_517)))
+                + ((current_base_row[247usize]) * (node_513)))
+                + ((current_base_row[250usize]) * (node_513)))
+                + ((current_base_row[275usize]) * (node_521)))
+                + ((current_base_row[278usize]) * (node_521)))
+                + ((current_base_row[276usize]) * (node_893)))
+                + ((current_base_row[279usize]) * (node_513)))
+                + ((current_base_row[254usize]) * (node_778)))
+                + ((current_base_row[259usize]) * (node_485))) + (node_1853))
+                + (node_1855)) * (node_3765)) + ((node_885) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((current_base_row[204usize]) * (node_488))
+                + ((current_base_row[213usize]) * (node_513)))
+                + ((current_base_row[206usize]) * (node_781)))
+                + ((current_base_row[210usize]) * (node_124)))
+                + ((current_base_row[208usize]) * (current_base_row[284usize])))
+                + ((current_base_row[216usize]) * (node_158)))
+                + ((current_base_row[221usize]) * (node_513)))
+                + ((current_base_row[214usize]) * (node_225)))
+                + ((current_base_row[224usize]) * (node_513)))
+                + ((current_base_row[228usize]) * (node_521)))
+                + ((current_base_row[225usize]) * (node_513)))
+                + ((current_base_row[226usize]) * (node_513)))
+                + ((current_base_row[215usize]) * (node_778)))
+                + ((current_base_row[223usize]) * (node_485)))
+                + ((current_base_row[230usize]) * (node_513)))
+                + ((current_base_row[232usize]) * (node_513)))
+                + ((current_base_row[235usize]) * (node_517)))
+                + ((current_base_row[236usize]) * (node_517)))
+                + ((current_base_row[237usize]) * (node_513)))
+                + ((current_base_row[239usize]) * (node_181)))
+                + ((current_base_row[238usize]) * (node_513)))
+                + ((current_base_row[240usize]) * (node_521)))
+                + ((current_base_row[241usize]) * (node_521)))
+                + ((current_base_row[242usize]) * (node_521)))
+                + ((current_base_row[244usize]) * (node_517)))
+                + ((current_base_row[245usize]) * (node_521)))
+                + ((current_base_row[247usize]) * (node_517)))
+                + ((current_base_row[250usize]) * (node_517)))
+                + ((current_base_row[276usize]) * (node_513)))
+                + ((current_base_row[279usize]) * (node_517)))
+                + ((current_base_row[254usize])
+                    * ((node_781)
+                        + (((next_ext_row[3usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((challenges[1usize])
+                                    * (((challenges[1usize])
+                                        * (((challenges[1usize])
+                                            * (((challenges[1usize])
+                                                * ((node_1323) + (next_base_row[26usize])))
+                                                + (next_base_row[25usize]))) + (next_base_row[24usize])))
+                                        + (next_base_row[23usize]))) + (next_base_row[22usize]))))
+                            * (current_base_row[189usize])))))
+                + ((current_base_row[259usize])
+                    * ((node_488)
+                        + (((next_ext_row[4usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((challenges[2usize]) * (node_1385))
+                                    + (current_base_row[26usize]))))
+                            * (current_base_row[189usize])))))
+                + ((current_base_row[281usize])
+                    * ((current_ext_row[82usize]) + (node_1439))))
+                + ((current_base_row[283usize])
+                    * (((current_ext_row[7usize]) * (current_ext_row[75usize]))
+                        + (node_1439)))) * (node_3765))
+                + ((node_888) * (next_base_row[8usize])),
+            (((((((((((((((((((((((((((((((current_base_row[204usize]) * (node_490))
+                + ((current_base_row[213usize]) * (node_517)))
+                + ((current_base_row[206usize]) * (node_783)))
+                + ((current_base_row[210usize]) * (node_128)))
+                + ((current_base_row[208usize]) * (node_792)))
+                + ((current_base_row[216usize]) * (node_513)))
+                + ((current_base_row[221usize]) * (node_517)))
+                + ((current_base_row[214usize]) * (node_229)))
+                + ((current_base_row[224usize]) * (node_517)))
+                + ((current_base_row[225usize]) * (node_517)))
+                + ((current_base_row[226usize]) * (node_517)))
+                + ((current_base_row[215usize]) * (node_781)))
+                + ((current_base_row[223usize]) * (node_488)))
+                + ((current_base_row[230usize]) * (node_517)))
+                + ((current_base_row[232usize]) * (node_517)))
+                + ((current_base_row[235usize]) * (node_521)))
+                + ((current_base_row[236usize]) * (node_521)))
+                + ((current_base_row[237usize]) * (node_517)))
+                + ((current_base_row[239usize]) * (node_513)))
+                + ((current_base_row[238usize]) * (node_517)))
+                + ((current_base_row[244usize]) * (node_521)))
+                + ((current_base_row[247usize]) * (node_521)))
+                + ((current_base_row[250usize]) * (node_521)))
+                + ((current_base_row[276usize]) * (node_517)))
+                + ((current_base_row[279usize]) * (node_521)))
+                + ((current_base_row[254usize])
+                    * ((node_783)
+                        + (((next_ext_row[3usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((challenges[1usize])
+                                    * (((challenges[1usize])
+                                        * (((challenges[1usize])
+                                            * ((node_1323) + (next_base_row[25usize])))
+                                            + (next_base_row[24usize]))) + (next_base_row[23usize])))
+                                    + (next_base_row[22usize]))))
+                            * (current_base_row[188usize])))))
+                + ((current_base_row[259usize])
+                    * ((node_490)
+                        + (((next_ext_row[4usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (node_1385))) * (current_base_row[188usize])))))
+                + ((current_base_row[281usize])
+                    * ((node_833)
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((node_1441)
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * (node_1451)))))))
+                + ((current_base_row[283usize])
+                    * ((node_833)
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((current_base_row[40usize])
+                                * (current_base_row[39usize])))))) * (node_3765))
+                + ((node_158) * (next_base_row[8usize])),
+            (((((((((((((((((((((((((current_base_row[204usize]) * (node_491))
+                + ((current_base_row[213usize]) * (node_521)))
+                + ((current_base_row[206usize]) * (node_784)))
+                + ((current_base_row[210usize]) * (node_116)))
+                + ((current_base_row[208usize]) * (node_794)))
+                + ((current_base_row[216usize]) * (node_517)))
+                + ((current_base_row[221usize]) * (node_521)))
+                + ((current_base_row[214usize]) * (node_233)))
+                + ((current_base_row[224usize]) * (node_521)))
+                + ((current_base_row[225usize]) * (node_521)))
+                + ((current_base_row[226usize]) * (node_521)))
+                + ((current_base_row[215usize]) * (node_783)))
+                + ((current_base_row[223usize]) * (node_490)))
+                + ((current_base_row[230usize]) * (node_521)))
+                + ((current_base_row[232usize]) * (node_521)))
+                + ((current_base_row[237usize]) * (node_521)))
+                + ((current_base_row[239usize]) * (node_517)))
+                + ((current_base_row[238usize]) * (node_521)))
+                + ((current_base_row[276usize]) * (node_521)))
+                + ((current_base_row[254usize])
+                    * ((node_784)
+                        + (((next_ext_row[3usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((challenges[1usize])
+                                    * (((challenges[1usize])
+                                        * ((node_1323) + (next_base_row[24usize])))
+                                        + (next_base_row[23usize]))) + (next_base_row[22usize]))))
+                            * (current_base_row[187usize])))))
+                + ((current_base_row[259usize])
+                    * ((node_491)
+                        + (((next_ext_row[4usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (node_1380))) * (current_base_row[187usize])))))
+                + ((current_base_row[281usize])
+                    * ((node_837)
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((((current_base_row[40usize])
+                                * (current_base_row[42usize]))
+                                + ((current_base_row[39usize])
+                                    * (current_base_row[43usize])))
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * (node_1452))) + (node_1451))))))
+                + ((current_base_row[283usize])
+                    * ((node_837)
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((current_base_row[41usize])
+                                * (current_base_row[39usize])))))) * (node_3765))
+                + ((node_513) * (next_base_row[8usize])),
+            (((((((((((((((current_base_row[204usize]) * (node_267))
+                + ((current_base_row[206usize]) * (node_567)))
+                + ((current_base_row[210usize]) * (node_526)))
+                + ((current_base_row[208usize]) * (node_796)))
+                + ((current_base_row[216usize]) * (node_521)))
+                + ((current_base_row[214usize]) * (node_237)))
+                + ((current_base_row[215usize]) * (node_784)))
+                + ((current_base_row[223usize]) * (node_491)))
+                + ((current_base_row[239usize]) * (node_521)))
+                + ((current_base_row[254usize])
+                    * ((node_567)
+                        + (((next_ext_row[3usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((challenges[1usize])
+                                    * ((node_1323) + (next_base_row[23usize])))
+                                    + (next_base_row[22usize]))))
+                            * (current_base_row[186usize])))))
+                + ((current_base_row[259usize])
+                    * ((node_267)
+                        + (((next_ext_row[4usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (node_1375))) * (current_base_row[186usize])))))
+                + ((current_base_row[281usize])
+                    * ((node_841)
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((((current_base_row[234usize])
+                                + ((current_base_row[40usize])
+                                    * (current_base_row[43usize])))
+                                + ((current_base_row[39usize])
+                                    * (current_base_row[44usize]))) + (node_1452))))))
+                + ((current_base_row[283usize])
+                    * ((node_841)
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_1441))))) * (node_3765))
+                + ((node_517) * (next_base_row[8usize])),
+            (((((((((((((current_base_row[204usize]) * (current_base_row[324usize]))
+                + ((current_base_row[206usize]) * (current_base_row[324usize])))
+                + ((current_base_row[210usize]) * (node_527)))
+                + ((current_base_row[208usize]) * (node_798)))
+                + ((current_base_row[214usize]) * (node_241)))
+                + ((current_base_row[215usize]) * (node_567)))
+                + ((current_base_row[223usize]) * (node_267)))
+                + ((current_base_row[254usize])
+                    * (((next_ext_row[3usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((node_1323) + (next_base_row[22usize]))))
+                        * (current_base_row[185usize]))))
+                + ((current_base_row[259usize])
+                    * (((next_ext_row[4usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_1370))) * (current_base_row[185usize]))))
+                + ((current_base_row[281usize]) * (node_1062)))
+                + ((current_base_row[283usize]) * (node_1036))) * (node_3765))
+                + ((node_521) * (next_base_row[8usize])),
+            ((((((((((current_base_row[204usize]) * (current_base_row[272usize]))
+                + ((current_base_row[206usize]) * (current_base_row[272usize])))
+                + ((current_base_row[210usize]) * (node_529)))
+                + ((current_base_row[208usize]) * (node_802)))
+                + ((current_base_row[214usize]) * (node_181)))
+                + ((current_base_row[215usize]) * (current_base_row[317usize])))
+                + ((current_base_row[223usize]) * (current_base_row[317usize])))
+                + ((current_base_row[254usize]) * (current_base_row[317usize])))
+                + ((current_base_row[259usize]) * (current_base_row[317usize])))
+                * (node_3765),
+            ((((((((((current_base_row[204usize]) * (current_base_row[273usize]))
+                + ((current_base_row[206usize]) * (current_base_row[273usize])))
+                + ((current_base_row[210usize]) * (node_530)))
+                + ((current_base_row[208usize]) * (node_804)))
+                + ((current_base_row[214usize]) * (node_513)))
+                + ((current_base_row[215usize]) * (current_base_row[272usize])))
+                + ((current_base_row[223usize]) * (current_base_row[272usize])))
+                + ((current_base_row[254usize]) * (current_base_row[272usize])))
+                + ((current_base_row[259usize]) * (current_base_row[272usize])))
+                * (node_3765),
+            ((((((((((current_base_row[204usize]) * (current_base_row[319usize]))
+                + ((current_base_row[206usize]) * (current_base_row[319usize])))
+                + ((current_base_row[210usize]) * (node_531)))
+                + ((current_base_row[208usize]) * (node_806)))
+                + ((current_base_row[214usize]) * (node_517)))
+                + ((current_base_row[215usize]) * (current_base_row[273usize])))
+                + ((current_base_row[223usize]) * (current_base_row[273usize])))
+                + ((current_base_row[254usize]) * (current_base_row[273usize])))
+                + ((current_base_row[259usize]) * (current_base_row[273usize])))
+                * (node_3765),
+            ((((((((((current_base_row[204usize]) * (current_base_row[330usize]))
+                + ((current_base_row[206usize]) * (current_base_row[330usize])))
+                + ((current_base_row[210usize]) * (node_532)))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[185usize]))) * (node_829))))
+                + ((current_base_row[214usize]) * (node_521)))
+                + ((current_base_row[215usize]) * (current_base_row[319usize])))
+                + ((current_base_row[223usize]) * (current_base_row[319usize])))
+                + ((current_base_row[254usize]) * (current_base_row[319usize])))
+                + ((current_base_row[259usize]) * (current_base_row[319usize])))
+                * (node_3765),
+            (((((((((current_base_row[204usize]) * (node_513))
+                + ((current_base_row[206usize]) * (node_513)))
+                + ((current_base_row[210usize]) * (node_538)))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[272usize]))) * (node_853))))
+                + ((current_base_row[215usize]) * (current_base_row[344usize])))
+                + ((current_base_row[223usize]) * (current_base_row[344usize])))
+                + ((current_base_row[254usize]) * (current_base_row[344usize])))
+                + ((current_base_row[259usize]) * (current_base_row[344usize])))
+                * (node_3765),
+            (((((((((current_base_row[204usize]) * (node_517))
+                + ((current_base_row[206usize]) * (node_517)))
+                + ((current_base_row[210usize]) * (node_539)))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[273usize]))) * (node_857))))
+                + ((current_base_row[215usize]) * (node_517)))
+                + ((current_base_row[223usize]) * (node_517)))
+                + ((current_base_row[254usize]) * (node_513)))
+                + ((current_base_row[259usize]) * (node_513))) * (node_3765),
+            (((((((((current_base_row[204usize]) * (node_521))
+                + ((current_base_row[206usize]) * (node_521)))
+                + ((current_base_row[210usize]) * (node_540)))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[319usize]))) * (node_861))))
+                + ((current_base_row[215usize]) * (node_521)))
+                + ((current_base_row[223usize]) * (node_521)))
+                + ((current_base_row[254usize]) * (node_521)))
+                + ((current_base_row[259usize]) * (node_517))) * (node_3765),
+            (((current_base_row[210usize]) * (node_550))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[331usize]))) * (node_869))))
+                * (node_3765),
+            (((current_base_row[210usize]) * (node_513))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[277usize]))) * (node_873))))
+                * (node_3765),
+            (((current_base_row[210usize]) * (node_517))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[342usize]))) * (node_877))))
+                * (node_3765),
+            (((current_base_row[210usize]) * (node_521))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[343usize]))) * (node_881))))
+                * (node_3765),
+            ((current_base_row[208usize]) * (node_513)) * (node_3765),
+            ((current_base_row[208usize]) * (node_517)) * (node_3765),
+            ((current_base_row[208usize]) * (node_521)) * (node_3765),
+            ((current_base_row[208usize]) * (node_888)) * (node_3765),
+            (((next_ext_row[13usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (current_ext_row[13usize])))
+                * ((challenges[11usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[7usize]))))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (next_base_row[45usize])),
+            ((node_3765)
+                * (((node_3899)
+                    * ((challenges[3usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((((challenges[13usize]) * (next_base_row[9usize]))
+                                + ((challenges[14usize]) * (next_base_row[10usize])))
+                                + ((challenges[15usize]) * (next_base_row[11usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((next_base_row[8usize]) * (node_3899)),
+            (next_ext_row[8usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[8usize])
+                        * ((challenges[9usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((((((challenges[24usize]) * (next_base_row[7usize]))
+                                    + ((challenges[25usize]) * (next_base_row[10usize])))
+                                    + ((challenges[26usize]) * (next_base_row[19usize])))
+                                    + ((challenges[27usize]) * (next_base_row[20usize])))
+                                    + ((challenges[28usize]) * (next_base_row[21usize]))))))),
+            (((next_base_row[10usize])
+                + (BFieldElement::from_raw_u64(18446743992105173011u64)))
+                * ((next_ext_row[9usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_ext_row[9usize]))))
+                + ((current_base_row[357usize])
+                    * (((next_ext_row[9usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((challenges[4usize]) * (current_ext_row[9usize]))))
+                        + (node_3985))),
+            (((current_base_row[10usize])
+                + (BFieldElement::from_raw_u64(18446743992105173011u64)))
+                * ((next_ext_row[10usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_ext_row[10usize]))))
+                + ((current_base_row[243usize])
+                    * (((next_ext_row[10usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((challenges[5usize]) * (current_ext_row[10usize]))))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_3968)))),
+            (((((((current_base_row[10usize])
+                + (BFieldElement::from_raw_u64(18446743897615892521u64)))
+                * ((current_base_row[10usize])
+                    + (BFieldElement::from_raw_u64(18446743923385696291u64))))
+                * ((current_base_row[10usize])
+                    + (BFieldElement::from_raw_u64(18446743863256154161u64))))
+                * ((next_ext_row[11usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_ext_row[11usize]))))
+                + ((current_base_row[232usize]) * (node_4045)))
+                + ((current_base_row[246usize])
+                    * ((node_4045)
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((((((((((challenges[32usize])
+                                * (current_base_row[22usize]))
+                                + ((challenges[33usize]) * (current_base_row[23usize])))
+                                + ((challenges[34usize]) * (current_base_row[24usize])))
+                                + ((challenges[35usize]) * (current_base_row[25usize])))
+                                + ((challenges[36usize]) * (current_base_row[26usize])))
+                                + ((challenges[37usize]) * (current_base_row[27usize])))
+                                + ((challenges[38usize]) * (current_base_row[28usize])))
+                                + ((challenges[39usize]) * (current_base_row[29usize])))
+                                + ((challenges[40usize]) * (current_base_row[30usize])))
+                                + ((challenges[41usize]) * (current_base_row[31usize])))))))
+                + ((current_base_row[249usize]) * ((node_4045) + (node_3985))),
+            (((((((((current_base_row[238usize])
+                * (((node_4108) * (((node_4065) + (node_4068)) + (node_4072)))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((current_base_row[240usize]) * (node_4112)))
+                + ((current_base_row[241usize]) * (node_4112)))
+                + ((current_base_row[242usize])
+                    * (((node_4108)
+                        * (((node_4079)
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((challenges[57usize])
+                                    * (BFieldElement::from_raw_u64(60129542130u64)))))
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((challenges[58usize])
+                                    * (((current_base_row[22usize])
+                                        + (current_base_row[23usize])) + (node_1265)))
+                                    * (BFieldElement::from_raw_u64(9223372036854775808u64))))))
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))))
+                + ((current_base_row[245usize]) * (node_4112)))
+                + ((current_base_row[244usize]) * (node_4116)))
+                + ((current_base_row[247usize])
+                    * (((((node_4108) * (node_4102)) * (node_4106))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_4102)))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_4106)))))
+                + ((current_base_row[250usize]) * (node_4116)))
+                + (((BFieldElement::from_raw_u64(4294967295u64))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[14usize]))) * (node_4108)),
+            (((next_ext_row[14usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[14usize])
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((((challenges[16usize]) * (next_base_row[46usize]))
+                                    + ((challenges[17usize]) * (next_base_row[47usize])))
+                                    + ((challenges[18usize]) * (next_base_row[48usize])))
+                                    + ((challenges[19usize]) * (next_base_row[49usize]))))))))
+                * (node_4162))
+                + (((next_ext_row[14usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_ext_row[14usize]))) * (node_4179)),
+            ((((((node_4188)
+                * ((challenges[11usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((next_base_row[46usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (current_base_row[46usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64))) * (node_4156))
+                * (node_4162)) + ((node_4188) * (node_4155)))
+                + ((node_4188) * (node_4179)),
+            ((node_4230)
+                * ((next_ext_row[16usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((current_ext_row[16usize]) * (node_4248)))))
+                + ((node_4233) * ((next_ext_row[16usize]) + (node_4253))),
+            ((node_4230)
+                * (((next_ext_row[17usize]) + (node_4253))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((node_4248) * (current_ext_row[17usize])))))
+                + ((node_4233)
+                    * ((next_ext_row[17usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_ext_row[17usize])))),
+            ((node_4230)
+                * (((next_ext_row[18usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((challenges[12usize]) * (current_ext_row[18usize]))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[55usize]))))
+                + ((node_4233)
+                    * ((next_ext_row[18usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_ext_row[18usize])))),
+            ((node_4230)
+                * (((next_ext_row[19usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((challenges[12usize]) * (current_ext_row[19usize]))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[56usize]))))
+                + ((node_4233)
+                    * ((next_ext_row[19usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_ext_row[19usize])))),
+            (((next_ext_row[20usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[20usize])
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((((next_base_row[50usize]) * (challenges[20usize]))
+                                    + ((next_base_row[52usize]) * (challenges[21usize])))
+                                    + ((next_base_row[53usize]) * (challenges[22usize])))
+                                    + ((next_base_row[51usize]) * (challenges[23usize]))))))))
+                * (node_4225))
+                + (((next_ext_row[20usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_ext_row[20usize]))) * (node_4299)),
+            (((current_ext_row[83usize]) * (node_4225)) + ((node_4308) * (node_4230)))
+                + ((node_4308) * (node_4299)),
+            (next_ext_row[22usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[22usize])
+                        * ((challenges[9usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((((((challenges[24usize]) * (next_base_row[57usize]))
+                                    + ((challenges[25usize]) * (next_base_row[58usize])))
+                                    + ((challenges[26usize]) * (next_base_row[59usize])))
+                                    + ((challenges[27usize]) * (next_base_row[60usize])))
+                                    + ((challenges[28usize]) * (next_base_row[61usize]))))))),
+            ((node_4337)
+                * (((node_4371)
+                    * ((challenges[11usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_4349))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_4336) * (node_4371)),
+            (((current_base_row[351usize])
+                * (((next_ext_row[24usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((challenges[30usize]) * (current_ext_row[24usize]))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((((((((((((((((((((challenges[29usize]) + (node_5124))
+                            * (challenges[29usize])) + (node_5135))
+                            * (challenges[29usize])) + (node_5146))
+                            * (challenges[29usize])) + (node_5157))
+                            * (challenges[29usize])) + (next_base_row[97usize]))
+                            * (challenges[29usize])) + (next_base_row[98usize]))
+                            * (challenges[29usize])) + (next_base_row[99usize]))
+                            * (challenges[29usize])) + (next_base_row[100usize]))
+                            * (challenges[29usize])) + (next_base_row[101usize]))
+                            * (challenges[29usize])) + (next_base_row[102usize])))))
+                + ((next_base_row[64usize]) * (node_5466)))
+                + ((node_5254) * (node_5466)),
+            ((current_base_row[352usize]) * (node_5254))
+                * (((((((((((challenges[0usize]) + (node_4408)) * (challenges[0usize]))
+                    + (node_4419)) * (challenges[0usize])) + (node_4430))
+                    * (challenges[0usize])) + (node_4441)) * (challenges[0usize]))
+                    + (current_base_row[97usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (challenges[62usize]))),
+            (current_base_row[365usize])
+                * ((((((node_5297) + (node_5298)) + (node_5300)) + (node_5302))
+                    + (node_5304)) + (node_5306)),
+            (current_base_row[366usize])
+                * (((((((((((((((((challenges[32usize])
+                    * ((node_5124)
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_4408))))
+                    + ((challenges[33usize])
+                        * ((node_5135)
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (node_4419)))))
+                    + ((challenges[34usize])
+                        * ((node_5146)
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (node_4430)))))
+                    + ((challenges[35usize])
+                        * ((node_5157)
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (node_4441)))))
+                    + ((challenges[36usize])
+                        * ((next_base_row[97usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (current_base_row[97usize])))))
+                    + ((challenges[37usize])
+                        * ((next_base_row[98usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (current_base_row[98usize])))))
+                    + ((challenges[38usize])
+                        * ((next_base_row[99usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (current_base_row[99usize])))))
+                    + ((challenges[39usize])
+                        * ((next_base_row[100usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (current_base_row[100usize])))))
+                    + ((challenges[40usize])
+                        * ((next_base_row[101usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (current_base_row[101usize])))))
+                    + ((challenges[41usize])
+                        * ((next_base_row[102usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (current_base_row[102usize]))))) + (node_5297))
+                    + (node_5298)) + (node_5300)) + (node_5302)) + (node_5304))
+                    + (node_5306)),
+            ((((current_base_row[316usize]) * (current_base_row[329usize]))
+                * (((next_ext_row[25usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((challenges[4usize]) * (current_ext_row[25usize]))))
+                    + (node_5396))) + ((next_base_row[64usize]) * (node_5373)))
+                + ((node_5259) * (node_5373)),
+            ((((node_5416) * (current_base_row[329usize]))
+                * (((next_ext_row[26usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((challenges[5usize]) * (current_ext_row[26usize]))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (node_5382)))) + ((node_5315) * (node_5407)))
+                + ((node_5259) * (node_5407)),
+            ((((current_base_row[316usize]) * (node_5366))
+                * ((((next_ext_row[27usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((challenges[6usize]) * (current_ext_row[27usize]))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((challenges[31usize]) * (next_base_row[63usize]))))
+                    + (node_5396))) + ((next_base_row[64usize]) * (node_5433)))
+                + ((((node_5263) * (node_5368)) * (node_5436)) * (node_5433)),
+            (((current_base_row[282usize])
+                * (((node_5485)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[65usize]))
+                                + ((challenges[50usize]) * (next_base_row[81usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5485))) + ((node_5493) * (node_5485)),
+            (((current_base_row[282usize])
+                * (((node_5506)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[66usize]))
+                                + ((challenges[50usize]) * (next_base_row[82usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5506))) + ((node_5493) * (node_5506)),
+            (((current_base_row[282usize])
+                * (((node_5523)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[67usize]))
+                                + ((challenges[50usize]) * (next_base_row[83usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5523))) + ((node_5493) * (node_5523)),
+            (((current_base_row[282usize])
+                * (((node_5540)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[68usize]))
+                                + ((challenges[50usize]) * (next_base_row[84usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5540))) + ((node_5493) * (node_5540)),
+            (((current_base_row[282usize])
+                * (((node_5557)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[69usize]))
+                                + ((challenges[50usize]) * (next_base_row[85usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5557))) + ((node_5493) * (node_5557)),
+            (((current_base_row[282usize])
+                * (((node_5574)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[70usize]))
+                                + ((challenges[50usize]) * (next_base_row[86usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5574))) + ((node_5493) * (node_5574)),
+            (((current_base_row[282usize])
+                * (((node_5591)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[71usize]))
+                                + ((challenges[50usize]) * (next_base_row[87usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5591))) + ((node_5493) * (node_5591)),
+            (((current_base_row[282usize])
+                * (((node_5608)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[72usize]))
+                                + ((challenges[50usize]) * (next_base_row[88usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5608))) + ((node_5493) * (node_5608)),
+            (((current_base_row[282usize])
+                * (((node_5625)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[73usize]))
+                                + ((challenges[50usize]) * (next_base_row[89usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5625))) + ((node_5493) * (node_5625)),
+            (((current_base_row[282usize])
+                * (((node_5642)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[74usize]))
+                                + ((challenges[50usize]) * (next_base_row[90usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5642))) + ((node_5493) * (node_5642)),
+            (((current_base_row[282usize])
+                * (((node_5659)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[75usize]))
+                                + ((challenges[50usize]) * (next_base_row[91usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5659))) + ((node_5493) * (node_5659)),
+            (((current_base_row[282usize])
+                * (((node_5676)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[76usize]))
+                                + ((challenges[50usize]) * (next_base_row[92usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5676))) + ((node_5493) * (node_5676)),
+            (((current_base_row[282usize])
+                * (((node_5693)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[77usize]))
+                                + ((challenges[50usize]) * (next_base_row[93usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5693))) + ((node_5493) * (node_5693)),
+            (((current_base_row[282usize])
+                * (((node_5710)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[78usize]))
+                                + ((challenges[50usize]) * (next_base_row[94usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5710))) + ((node_5493) * (node_5710)),
+            (((current_base_row[282usize])
+                * (((node_5727)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[79usize]))
+                                + ((challenges[50usize]) * (next_base_row[95usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5727))) + ((node_5493) * (node_5727)),
+            (((current_base_row[282usize])
+                * (((node_5744)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[80usize]))
+                                + ((challenges[50usize]) * (next_base_row[96usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5744))) + ((node_5493) * (node_5744)),
+            ((node_5770)
+                * (((node_5780)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize])
+                                * (((BFieldElement::from_raw_u64(1099511627520u64))
+                                    * (next_base_row[130usize])) + (next_base_row[131usize])))
+                                + ((challenges[50usize])
+                                    * (((BFieldElement::from_raw_u64(1099511627520u64))
+                                        * (next_base_row[132usize]))
+                                        + (next_base_row[133usize])))))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[134usize]))))
+                + ((next_base_row[129usize]) * (node_5780)),
+            ((node_5770)
+                * ((((((node_5796)
+                    * ((challenges[51usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_5791))))
+                    * ((challenges[51usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_5794))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((BFieldElement::from_raw_u64(8589934590u64))
+                            * (challenges[51usize])))) + (node_5791)) + (node_5794)))
+                + ((next_base_row[129usize]) * (node_5796)),
+            ((node_5822)
+                * (((node_5834)
+                    * ((challenges[51usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((next_base_row[136usize]) * (challenges[52usize]))
+                                + ((next_base_row[137usize]) * (challenges[53usize]))))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[138usize]))))
+                + ((next_base_row[135usize]) * (node_5834)),
+            ((node_5822)
+                * (((next_ext_row[47usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((current_ext_row[47usize]) * (challenges[54usize]))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[137usize]))))
+                + ((next_base_row[135usize])
+                    * ((next_ext_row[47usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_ext_row[47usize])))),
+            (node_5882) * (node_5998),
+            (next_base_row[139usize])
+                * (((node_5998)
+                    * ((challenges[10usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((((challenges[57usize]) * (next_base_row[142usize]))
+                                + ((challenges[55usize]) * (next_base_row[143usize])))
+                                + ((challenges[56usize]) * (next_base_row[145usize])))
+                                + ((challenges[58usize]) * (next_base_row[147usize]))))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[148usize]))),
+            (current_ext_row[50usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_176)
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((next_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(4294967295u64)))))
+                                    + ((challenges[19usize]) * (next_base_row[36usize]))))))),
+            (current_ext_row[51usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_547)
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((current_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(4294967295u64)))))
+                                    + ((challenges[19usize])
+                                        * (current_base_row[36usize]))))))),
+            (current_ext_row[52usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[50usize])
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((next_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(8589934590u64)))))
+                                    + ((challenges[19usize]) * (next_base_row[35usize]))))))),
+            (current_ext_row[53usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[51usize])
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((current_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(8589934590u64)))))
+                                    + ((challenges[19usize])
+                                        * (current_base_row[35usize]))))))),
+            (current_ext_row[54usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[52usize])
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((next_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(12884901885u64)))))
+                                    + ((challenges[19usize]) * (next_base_row[34usize]))))))),
+            (current_ext_row[55usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[53usize])
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((current_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(12884901885u64)))))
+                                    + ((challenges[19usize])
+                                        * (current_base_row[34usize]))))))),
+            (current_ext_row[56usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[54usize])
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((next_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(17179869180u64)))))
+                                    + ((challenges[19usize]) * (next_base_row[33usize]))))))),
+            (current_ext_row[57usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_966)
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_957)
+                                    + (((next_base_row[22usize])
+                                        + (BFieldElement::from_raw_u64(8589934590u64)))
+                                        * (challenges[21usize])))
+                                    + ((next_base_row[24usize]) * (challenges[22usize]))))))),
+            (current_ext_row[58usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_1042)
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_955) + (node_1050))
+                                    + ((current_base_row[24usize])
+                                        * (challenges[22usize]))))))),
+            (current_ext_row[59usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[6usize]) * (current_ext_row[56usize]))),
+            (current_ext_row[60usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[55usize])
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((current_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(17179869180u64)))))
+                                    + ((challenges[19usize])
+                                        * (current_base_row[33usize]))))))),
+            (current_ext_row[61usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[57usize])
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_957)
+                                    + (((next_base_row[22usize])
+                                        + (BFieldElement::from_raw_u64(12884901885u64)))
+                                        * (challenges[21usize])))
+                                    + ((next_base_row[25usize]) * (challenges[22usize]))))))),
+            (current_ext_row[62usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[58usize])
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_955) + (node_1064))
+                                    + ((current_base_row[25usize])
+                                        * (challenges[22usize]))))))),
+            (current_ext_row[63usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[61usize])
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_957)
+                                    + (((next_base_row[22usize])
+                                        + (BFieldElement::from_raw_u64(17179869180u64)))
+                                        * (challenges[21usize])))
+                                    + ((next_base_row[26usize]) * (challenges[22usize]))))))),
+            (current_ext_row[64usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[62usize])
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_955)
+                                    + (((current_base_row[22usize])
+                                        + (BFieldElement::from_raw_u64(12884901885u64)))
+                                        * (challenges[21usize])))
+                                    + ((current_base_row[26usize])
+                                        * (challenges[22usize]))))))),
+            (current_ext_row[65usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[189usize])
+                        * ((next_ext_row[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((current_ext_row[7usize])
+                                    * ((current_ext_row[63usize])
+                                        * ((challenges[8usize])
+                                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                                * (((node_957)
+                                                    + (((next_base_row[22usize])
+                                                        + (BFieldElement::from_raw_u64(21474836475u64)))
+                                                        * (challenges[21usize])))
+                                                    + ((next_base_row[27usize])
+                                                        * (challenges[22usize]))))))))))),
+            (current_ext_row[66usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[189usize])
+                        * ((next_ext_row[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((current_ext_row[7usize])
+                                    * ((current_ext_row[64usize])
+                                        * ((challenges[8usize])
+                                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                                * (((node_955)
+                                                    + (((current_base_row[22usize])
+                                                        + (BFieldElement::from_raw_u64(17179869180u64)))
+                                                        * (challenges[21usize])))
+                                                    + ((current_base_row[27usize])
+                                                        * (challenges[22usize]))))))))))),
+            (current_ext_row[67usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_ext_row[56usize])
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((next_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(21474836475u64)))))
+                                    + ((challenges[19usize]) * (next_base_row[32usize]))))))
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((next_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(25769803770u64)))))
+                                    + ((challenges[19usize]) * (next_base_row[31usize]))))))
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((next_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(30064771065u64)))))
+                                    + ((challenges[19usize]) * (next_base_row[30usize]))))))),
+            (current_ext_row[68usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_ext_row[60usize])
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((current_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(21474836475u64)))))
+                                    + ((challenges[19usize]) * (current_base_row[32usize]))))))
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((current_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(25769803770u64)))))
+                                    + ((challenges[19usize]) * (current_base_row[31usize]))))))
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((current_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(30064771065u64)))))
+                                    + ((challenges[19usize])
+                                        * (current_base_row[30usize]))))))),
+            (current_ext_row[69usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[6usize])
+                        * (((current_ext_row[67usize])
+                            * ((challenges[7usize])
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * (((node_167)
+                                        + ((challenges[18usize])
+                                            * ((next_base_row[38usize])
+                                                + (BFieldElement::from_raw_u64(34359738360u64)))))
+                                        + ((challenges[19usize]) * (next_base_row[29usize]))))))
+                            * ((challenges[7usize])
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * (((node_167)
+                                        + ((challenges[18usize])
+                                            * ((next_base_row[38usize])
+                                                + (BFieldElement::from_raw_u64(38654705655u64)))))
+                                        + ((challenges[19usize]) * (next_base_row[28usize])))))))),
+            (current_ext_row[70usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[6usize])
+                        * (((current_ext_row[68usize])
+                            * ((challenges[7usize])
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * (((node_167)
+                                        + ((challenges[18usize])
+                                            * ((current_base_row[38usize])
+                                                + (BFieldElement::from_raw_u64(34359738360u64)))))
+                                        + ((challenges[19usize]) * (current_base_row[29usize]))))))
+                            * ((challenges[7usize])
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * (((node_167)
+                                        + ((challenges[18usize])
+                                            * ((current_base_row[38usize])
+                                                + (BFieldElement::from_raw_u64(38654705655u64)))))
+                                        + ((challenges[19usize])
+                                            * (current_base_row[28usize])))))))),
+            (current_ext_row[71usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[6usize]) * (node_176))),
+            (current_ext_row[72usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[6usize]) * (current_ext_row[52usize]))),
+            (current_ext_row[73usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((node_1404)
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_957) + (node_1050)) + (node_1406)))))
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_957) + (node_1064)) + (node_1412)))))
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((node_1418) + (node_1419)))))),
+            (current_ext_row[74usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[6usize]) * (current_ext_row[60usize]))),
+            (current_ext_row[75usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((node_1404)
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((node_1418) + (node_1406)))))
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((node_1425) + (node_1412)))))
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((node_1432) + (node_1419)))))),
+            (current_ext_row[76usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[186usize])
+                        * ((next_ext_row[6usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((current_ext_row[6usize])
+                                    * (current_ext_row[50usize])))))),
+            (current_ext_row[77usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[188usize])
+                        * ((next_ext_row[6usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((current_ext_row[6usize])
+                                    * (current_ext_row[54usize])))))),
+            (current_ext_row[78usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[185usize]) * (node_550))),
+            (current_ext_row[79usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[186usize])
+                        * ((next_ext_row[6usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((current_ext_row[6usize])
+                                    * (current_ext_row[51usize])))))),
+            (current_ext_row[80usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[187usize])
+                        * ((next_ext_row[6usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((current_ext_row[6usize])
+                                    * (current_ext_row[53usize])))))),
+            (current_ext_row[81usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[188usize])
+                        * ((next_ext_row[6usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((current_ext_row[6usize])
+                                    * (current_ext_row[55usize])))))),
+            (current_ext_row[82usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[7usize])
+                        * (((current_ext_row[73usize])
+                            * ((challenges[8usize])
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * ((node_1425)
+                                        + ((current_base_row[43usize]) * (challenges[22usize]))))))
+                            * ((challenges[8usize])
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * ((node_1432)
+                                        + ((current_base_row[44usize])
+                                            * (challenges[22usize])))))))),
+            (current_ext_row[83usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((node_4308)
+                        * ((challenges[11usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((next_base_row[50usize])
+                                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                        * (current_base_row[50usize]))))))
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+                        * (node_4233))),
+            (current_ext_row[84usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[215usize])
+                        * ((((((current_base_row[185usize])
+                            * ((next_ext_row[7usize])
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * ((current_ext_row[7usize]) * (node_966)))))
+                            + ((current_base_row[186usize])
+                                * ((next_ext_row[7usize])
+                                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                        * ((current_ext_row[7usize])
+                                            * (current_ext_row[57usize]))))))
+                            + ((current_base_row[187usize])
+                                * ((next_ext_row[7usize])
+                                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                        * ((current_ext_row[7usize])
+                                            * (current_ext_row[61usize]))))))
+                            + ((current_base_row[188usize])
+                                * ((next_ext_row[7usize])
+                                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                        * ((current_ext_row[7usize])
+                                            * (current_ext_row[63usize]))))))
+                            + (current_ext_row[65usize])))),
+            (current_ext_row[85usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[223usize])
+                        * ((((((current_base_row[185usize])
+                            * ((next_ext_row[7usize])
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * ((current_ext_row[7usize]) * (node_1042)))))
+                            + ((current_base_row[186usize])
+                                * ((next_ext_row[7usize])
+                                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                        * ((current_ext_row[7usize])
+                                            * (current_ext_row[58usize]))))))
+                            + ((current_base_row[187usize])
+                                * ((next_ext_row[7usize])
+                                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                        * ((current_ext_row[7usize])
+                                            * (current_ext_row[62usize]))))))
+                            + ((current_base_row[188usize])
+                                * ((next_ext_row[7usize])
+                                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                        * ((current_ext_row[7usize])
+                                            * (current_ext_row[64usize]))))))
+                            + (current_ext_row[66usize])))),
+        ];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints)
+            .collect()
     }
-
+    #[allow(unused_variables)]
     fn evaluate_terminal_constraints(
-        _: ArrayView1<BFieldElement>,
-        _: ArrayView1<XFieldElement>,
-        _: &Challenges,
+        base_row: ArrayView1<BFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        panic!("{ERROR_MESSAGE_GENERATE_CONSTRAINTS}")
+        let base_constraints = [
+            (base_row[5usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((base_row[3usize]) + (BFieldElement::from_raw_u64(18446744030759878666u64)))
+                * ((base_row[6usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            base_row[10usize],
+            ((base_row[62usize])
+                * ((base_row[63usize])
+                    + (BFieldElement::from_raw_u64(18446743897615892521u64))))
+                * ((base_row[64usize])
+                    + (BFieldElement::from_raw_u64(18446744047939747846u64))),
+            (base_row[143usize])
+                * ((base_row[142usize])
+                    + (BFieldElement::from_raw_u64(18446743940565565471u64))),
+            base_row[145usize],
+        ];
+        let ext_constraints = [
+            (((ext_row[18usize]) * (ext_row[16usize]))
+                + ((ext_row[19usize]) * (ext_row[17usize])))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((((base_row[62usize])
+                + (BFieldElement::from_raw_u64(18446744060824649731u64)))
+                * ((base_row[62usize])
+                    + (BFieldElement::from_raw_u64(18446744056529682436u64))))
+                * (base_row[62usize]))
+                * (((((((((((challenges[0usize])
+                    + ((((((base_row[65usize])
+                        * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+                        + ((base_row[66usize])
+                            * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+                        + ((base_row[67usize])
+                            * (BFieldElement::from_raw_u64(281474976645120u64))))
+                        + (base_row[68usize])) * (BFieldElement::from_raw_u64(1u64))))
+                    * (challenges[0usize]))
+                    + ((((((base_row[69usize])
+                        * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+                        + ((base_row[70usize])
+                            * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+                        + ((base_row[71usize])
+                            * (BFieldElement::from_raw_u64(281474976645120u64))))
+                        + (base_row[72usize])) * (BFieldElement::from_raw_u64(1u64))))
+                    * (challenges[0usize]))
+                    + ((((((base_row[73usize])
+                        * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+                        + ((base_row[74usize])
+                            * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+                        + ((base_row[75usize])
+                            * (BFieldElement::from_raw_u64(281474976645120u64))))
+                        + (base_row[76usize])) * (BFieldElement::from_raw_u64(1u64))))
+                    * (challenges[0usize]))
+                    + ((((((base_row[77usize])
+                        * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+                        + ((base_row[78usize])
+                            * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+                        + ((base_row[79usize])
+                            * (BFieldElement::from_raw_u64(281474976645120u64))))
+                        + (base_row[80usize])) * (BFieldElement::from_raw_u64(1u64))))
+                    * (challenges[0usize])) + (base_row[97usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (challenges[62usize]))),
+            (ext_row[47usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (challenges[61usize])),
+            (ext_row[2usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[24usize])),
+            (challenges[59usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[3usize])),
+            (ext_row[4usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (challenges[60usize])),
+            (ext_row[5usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[0usize])),
+            (ext_row[6usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[14usize])),
+            (ext_row[7usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[20usize])),
+            (ext_row[8usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[22usize])),
+            (ext_row[9usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[25usize])),
+            (ext_row[26usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[10usize])),
+            (ext_row[11usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[27usize])),
+            ((((((((((((((((ext_row[44usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[28usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[29usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[30usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[31usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[32usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[33usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[34usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[35usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[36usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[37usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[38usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[39usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[40usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[41usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[42usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[43usize])),
+            (ext_row[45usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[46usize])),
+            (ext_row[12usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[48usize])),
+            (((ext_row[13usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[15usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[21usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[23usize])),
+        ];
+        base_constraints
+            .into_iter()
+            .map(|bfe| bfe.lift())
+            .chain(ext_constraints)
+            .collect()
     }
 }
-
 impl Evaluable<XFieldElement> for MasterExtTable {
+    #[allow(unused_variables)]
     fn evaluate_initial_constraints(
-        _: ArrayView1<XFieldElement>,
-        _: ArrayView1<XFieldElement>,
-        _: &Challenges,
+        base_row: ArrayView1<XFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        panic!("{ERROR_MESSAGE_GENERATE_CONSTRAINTS}")
+        let node_468 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[129usize]));
+        let node_474 = ((challenges[52usize]) * (base_row[131usize]))
+            + ((challenges[53usize]) * (base_row[133usize]));
+        let node_477 = ((challenges[52usize]) * (base_row[130usize]))
+            + ((challenges[53usize]) * (base_row[132usize]));
+        let base_constraints = [
+            base_row[0usize],
+            base_row[3usize],
+            base_row[5usize],
+            base_row[7usize],
+            base_row[9usize],
+            base_row[19usize],
+            base_row[20usize],
+            base_row[21usize],
+            base_row[22usize],
+            base_row[23usize],
+            base_row[24usize],
+            base_row[25usize],
+            base_row[26usize],
+            base_row[27usize],
+            base_row[28usize],
+            base_row[29usize],
+            base_row[30usize],
+            base_row[31usize],
+            base_row[32usize],
+            (base_row[38usize]) + (BFieldElement::from_raw_u64(18446744000695107601u64)),
+            (base_row[48usize]) + (BFieldElement::from_raw_u64(18446744000695107601u64)),
+            base_row[55usize],
+            base_row[57usize],
+            base_row[59usize],
+            base_row[60usize],
+            base_row[61usize],
+            (base_row[62usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            base_row[64usize],
+            base_row[136usize],
+            (base_row[149usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((((base_row[12usize])
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+                        * (base_row[13usize]))
+                        * ((base_row[14usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                        * ((base_row[15usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))),
+            (base_row[150usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((base_row[149usize]) * (base_row[16usize]))
+                        * ((base_row[17usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                        * ((base_row[18usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))),
+        ];
+        let ext_constraints = [
+            ext_row[0usize],
+            ((ext_row[1usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (challenges[29usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (base_row[1usize])),
+            (ext_row[2usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((((((((((challenges[0usize]) + (base_row[33usize])) * (challenges[0usize]))
+                + (base_row[34usize])) * (challenges[0usize])) + (base_row[35usize]))
+                * (challenges[0usize])) + (base_row[36usize])) * (challenges[0usize]))
+                + (base_row[37usize]))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (challenges[62usize])),
+            (ext_row[3usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[5usize])
+                * ((challenges[3usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[14usize]) * (base_row[10usize]))
+                            + ((challenges[15usize]) * (base_row[11usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            (ext_row[4usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            (ext_row[6usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            (ext_row[7usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            (ext_row[8usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((challenges[9usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((challenges[25usize]) * (base_row[10usize]))))),
+            ((ext_row[13usize]) * (challenges[11usize]))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (base_row[45usize])),
+            (((base_row[10usize])
+                + (BFieldElement::from_raw_u64(18446743992105173011u64)))
+                * ((ext_row[9usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((base_row[150usize])
+                    * ((ext_row[9usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (challenges[4usize])))),
+            (ext_row[10usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            (ext_row[11usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ext_row[12usize],
+            (((ext_row[14usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((challenges[7usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((((challenges[16usize]) * (base_row[46usize]))
+                                + ((challenges[17usize]) * (base_row[47usize])))
+                                + ((challenges[18usize])
+                                    * (BFieldElement::from_raw_u64(68719476720u64))))
+                                + ((challenges[19usize]) * (base_row[49usize])))))))
+                * ((base_row[47usize])
+                    + (BFieldElement::from_raw_u64(18446744060824649731u64))))
+                + (((ext_row[14usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+                    * ((base_row[47usize])
+                        * ((base_row[47usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))),
+            ext_row[15usize],
+            ext_row[18usize],
+            (ext_row[19usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (base_row[56usize])),
+            ((ext_row[16usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (challenges[12usize]))) + (base_row[52usize]),
+            (ext_row[17usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((((ext_row[20usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (challenges[8usize])))
+                + (((((base_row[50usize]) * (challenges[20usize]))
+                    + ((base_row[51usize]) * (challenges[23usize])))
+                    + ((base_row[52usize]) * (challenges[21usize])))
+                    + ((base_row[53usize]) * (challenges[22usize]))))
+                * ((base_row[51usize])
+                    + (BFieldElement::from_raw_u64(18446744060824649731u64))))
+                + (((ext_row[20usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+                    * (((base_row[51usize])
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+                        * (base_row[51usize]))),
+            ext_row[21usize],
+            (ext_row[22usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((challenges[9usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((challenges[25usize]) * (base_row[58usize]))))),
+            ext_row[23usize],
+            (ext_row[25usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            (ext_row[26usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            (ext_row[27usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[24usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (challenges[30usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((((((((((((((((((challenges[29usize])
+                        + ((((((base_row[65usize])
+                            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+                            + ((base_row[66usize])
+                                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+                            + ((base_row[67usize])
+                                * (BFieldElement::from_raw_u64(281474976645120u64))))
+                            + (base_row[68usize]))
+                            * (BFieldElement::from_raw_u64(1u64))))
+                        * (challenges[29usize]))
+                        + ((((((base_row[69usize])
+                            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+                            + ((base_row[70usize])
+                                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+                            + ((base_row[71usize])
+                                * (BFieldElement::from_raw_u64(281474976645120u64))))
+                            + (base_row[72usize]))
+                            * (BFieldElement::from_raw_u64(1u64))))
+                        * (challenges[29usize]))
+                        + ((((((base_row[73usize])
+                            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+                            + ((base_row[74usize])
+                                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+                            + ((base_row[75usize])
+                                * (BFieldElement::from_raw_u64(281474976645120u64))))
+                            + (base_row[76usize]))
+                            * (BFieldElement::from_raw_u64(1u64))))
+                        * (challenges[29usize]))
+                        + ((((((base_row[77usize])
+                            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+                            + ((base_row[78usize])
+                                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+                            + ((base_row[79usize])
+                                * (BFieldElement::from_raw_u64(281474976645120u64))))
+                            + (base_row[80usize]))
+                            * (BFieldElement::from_raw_u64(1u64))))
+                        * (challenges[29usize])) + (base_row[97usize]))
+                        * (challenges[29usize])) + (base_row[98usize]))
+                        * (challenges[29usize])) + (base_row[99usize]))
+                        * (challenges[29usize])) + (base_row[100usize]))
+                        * (challenges[29usize])) + (base_row[101usize]))
+                        * (challenges[29usize])) + (base_row[102usize]))),
+            ((ext_row[28usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[65usize]))
+                            + ((challenges[50usize]) * (base_row[81usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[29usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[66usize]))
+                            + ((challenges[50usize]) * (base_row[82usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[30usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[67usize]))
+                            + ((challenges[50usize]) * (base_row[83usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[31usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[68usize]))
+                            + ((challenges[50usize]) * (base_row[84usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[32usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[69usize]))
+                            + ((challenges[50usize]) * (base_row[85usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[33usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[70usize]))
+                            + ((challenges[50usize]) * (base_row[86usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[34usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[71usize]))
+                            + ((challenges[50usize]) * (base_row[87usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[35usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[72usize]))
+                            + ((challenges[50usize]) * (base_row[88usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[36usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[73usize]))
+                            + ((challenges[50usize]) * (base_row[89usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[37usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[74usize]))
+                            + ((challenges[50usize]) * (base_row[90usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[38usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[75usize]))
+                            + ((challenges[50usize]) * (base_row[91usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[39usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[76usize]))
+                            + ((challenges[50usize]) * (base_row[92usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[40usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[77usize]))
+                            + ((challenges[50usize]) * (base_row[93usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[41usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[78usize]))
+                            + ((challenges[50usize]) * (base_row[94usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[42usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[79usize]))
+                            + ((challenges[50usize]) * (base_row[95usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((ext_row[43usize])
+                * ((challenges[48usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (((challenges[49usize]) * (base_row[80usize]))
+                            + ((challenges[50usize]) * (base_row[96usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((node_468)
+                * (((ext_row[44usize])
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize])
+                                * (((BFieldElement::from_raw_u64(1099511627520u64))
+                                    * (base_row[130usize])) + (base_row[131usize])))
+                                + ((challenges[50usize])
+                                    * (((BFieldElement::from_raw_u64(1099511627520u64))
+                                        * (base_row[132usize])) + (base_row[133usize])))))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (base_row[134usize]))))
+                + ((base_row[129usize]) * (ext_row[44usize])),
+            ((node_468)
+                * ((((((ext_row[45usize])
+                    * ((challenges[51usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_474))))
+                    * ((challenges[51usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_477))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((BFieldElement::from_raw_u64(8589934590u64))
+                            * (challenges[51usize])))) + (node_474)) + (node_477)))
+                + ((base_row[129usize]) * (ext_row[45usize])),
+            ((ext_row[46usize])
+                * ((challenges[51usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((base_row[137usize]) * (challenges[53usize])))))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (base_row[138usize])),
+            ((ext_row[47usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (challenges[54usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (base_row[137usize])),
+            (((base_row[139usize])
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+                * (ext_row[48usize]))
+                + ((base_row[139usize])
+                    * (((ext_row[48usize])
+                        * ((challenges[10usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((((challenges[55usize]) * (base_row[143usize]))
+                                    + ((challenges[56usize]) * (base_row[145usize])))
+                                    + ((challenges[57usize]) * (base_row[142usize])))
+                                    + ((challenges[58usize]) * (base_row[147usize]))))))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (base_row[148usize])))),
+        ];
+        base_constraints.into_iter().chain(ext_constraints).collect()
     }
-
+    #[allow(unused_variables)]
     fn evaluate_consistency_constraints(
-        _: ArrayView1<XFieldElement>,
-        _: ArrayView1<XFieldElement>,
-        _: &Challenges,
+        base_row: ArrayView1<XFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        panic!("{ERROR_MESSAGE_GENERATE_CONSTRAINTS}")
+        let node_102 = (base_row[152usize])
+            * ((base_row[64usize])
+                + (BFieldElement::from_raw_u64(18446744047939747846u64)));
+        let node_221 = (base_row[153usize])
+            * ((base_row[64usize])
+                + (BFieldElement::from_raw_u64(18446744047939747846u64)));
+        let node_238 = ((base_row[154usize])
+            * ((base_row[64usize])
+                + (BFieldElement::from_raw_u64(18446744052234715141u64))))
+            * ((base_row[64usize])
+                + (BFieldElement::from_raw_u64(18446744047939747846u64)));
+        let node_245 = ((base_row[154usize])
+            * ((base_row[64usize])
+                + (BFieldElement::from_raw_u64(18446744056529682436u64))))
+            * ((base_row[64usize])
+                + (BFieldElement::from_raw_u64(18446744047939747846u64)));
+        let node_655 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[157usize]));
+        let node_114 = (((base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743992105173011u64)))
+            * ((base_row[63usize])
+                + (BFieldElement::from_raw_u64(18446743923385696291u64))))
+            * ((base_row[63usize])
+                + (BFieldElement::from_raw_u64(18446743863256154161u64)));
+        let node_116 = (node_102) * (base_row[161usize]);
+        let node_660 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[160usize]));
+        let node_101 = (base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744047939747846u64));
+        let node_678 = (base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446743949155500061u64));
+        let node_674 = (base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446743940565565471u64));
+        let node_94 = (base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744056529682436u64));
+        let node_97 = (base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744052234715141u64));
+        let node_153 = ((((BFieldElement::from_raw_u64(18446744065119617025u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((base_row[65usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[66usize]))) * (base_row[109usize]))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_155 = ((((BFieldElement::from_raw_u64(18446744065119617025u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((base_row[69usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[70usize]))) * (base_row[110usize]))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_157 = ((((BFieldElement::from_raw_u64(18446744065119617025u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((base_row[73usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[74usize]))) * (base_row[111usize]))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_159 = ((((BFieldElement::from_raw_u64(18446744065119617025u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((base_row[77usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[78usize]))) * (base_row[112usize]))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_680 = (base_row[139usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_90 = (base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744060824649731u64));
+        let node_670 = (base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446744017874976781u64));
+        let node_11 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (((BFieldElement::from_raw_u64(38654705655u64))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (base_row[3usize]))) * (base_row[4usize])));
+        let node_8 = (BFieldElement::from_raw_u64(38654705655u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[3usize]));
+        let node_104 = (((base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+            * ((base_row[62usize])
+                + (BFieldElement::from_raw_u64(18446744060824649731u64))))
+            * ((base_row[62usize])
+                + (BFieldElement::from_raw_u64(18446744056529682436u64)));
+        let node_85 = (base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744060824649731u64));
+        let node_73 = (base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743992105173011u64));
+        let node_79 = (base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743923385696291u64));
+        let node_82 = (base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743863256154161u64));
+        let node_126 = ((BFieldElement::from_raw_u64(18446744065119617025u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((base_row[65usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[66usize]));
+        let node_133 = ((BFieldElement::from_raw_u64(18446744065119617025u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((base_row[69usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[70usize]));
+        let node_140 = ((BFieldElement::from_raw_u64(18446744065119617025u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((base_row[73usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[74usize]));
+        let node_147 = ((BFieldElement::from_raw_u64(18446744065119617025u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((base_row[77usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (base_row[78usize]));
+        let node_89 = (base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_663 = (base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446744052234715141u64));
+        let node_666 = (base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446744009285042191u64));
+        let node_86 = ((base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64))) * (node_85);
+        let node_83 = (base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_103 = (base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744056529682436u64));
+        let base_constraints = [
+            (node_11) * (base_row[4usize]),
+            (node_11) * (node_8),
+            (base_row[5usize])
+                * ((base_row[5usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[6usize])
+                * ((base_row[6usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[12usize])
+                * ((base_row[12usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[13usize])
+                * ((base_row[13usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[14usize])
+                * ((base_row[14usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[15usize])
+                * ((base_row[15usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[16usize])
+                * ((base_row[16usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[17usize])
+                * ((base_row[17usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[18usize])
+                * ((base_row[18usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[8usize])
+                * ((base_row[8usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (base_row[10usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((((((base_row[12usize])
+                        + ((BFieldElement::from_raw_u64(8589934590u64))
+                            * (base_row[13usize])))
+                        + ((BFieldElement::from_raw_u64(17179869180u64))
+                            * (base_row[14usize])))
+                        + ((BFieldElement::from_raw_u64(34359738360u64))
+                            * (base_row[15usize])))
+                        + ((BFieldElement::from_raw_u64(68719476720u64))
+                            * (base_row[16usize])))
+                        + ((BFieldElement::from_raw_u64(137438953440u64))
+                            * (base_row[17usize])))
+                        + ((BFieldElement::from_raw_u64(274877906880u64))
+                            * (base_row[18usize])))),
+            ((base_row[8usize])
+                * ((base_row[7usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                * (base_row[45usize]),
+            (node_104) * (base_row[62usize]),
+            (node_85) * (node_73),
+            ((base_row[165usize]) * (node_79)) * (node_82),
+            (node_104) * (base_row[64usize]),
+            (node_114) * (base_row[64usize]),
+            (node_153) * (base_row[109usize]),
+            (node_155) * (base_row[110usize]),
+            (node_157) * (base_row[111usize]),
+            (node_159) * (base_row[112usize]),
+            (node_153) * (node_126),
+            (node_155) * (node_133),
+            (node_157) * (node_140),
+            (node_159) * (node_147),
+            (node_153)
+                * (((base_row[67usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))
+                    + (base_row[68usize])),
+            (node_155)
+                * (((base_row[71usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))
+                    + (base_row[72usize])),
+            (node_157)
+                * (((base_row[75usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))
+                    + (base_row[76usize])),
+            (node_159)
+                * (((base_row[79usize])
+                    * (BFieldElement::from_raw_u64(281474976645120u64)))
+                    + (base_row[80usize])),
+            (node_114) * (base_row[103usize]),
+            (node_114) * (base_row[104usize]),
+            (node_114) * (base_row[105usize]),
+            (node_114) * (base_row[106usize]),
+            (node_114) * (base_row[107usize]),
+            (node_114) * (base_row[108usize]),
+            (node_116)
+                * ((base_row[103usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (node_116)
+                * ((base_row[104usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (node_116)
+                * ((base_row[105usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (node_116)
+                * ((base_row[106usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (node_116)
+                * ((base_row[107usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (node_116)
+                * ((base_row[108usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (((((node_102)
+                * ((base_row[113usize])
+                    + (BFieldElement::from_raw_u64(11408918724931329738u64))))
+                + ((node_221)
+                    * ((base_row[113usize])
+                        + (BFieldElement::from_raw_u64(16073625066478178581u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[113usize])
+                        + (BFieldElement::from_raw_u64(12231462398569191607u64)))))
+                + ((node_238)
+                    * ((base_row[113usize])
+                        + (BFieldElement::from_raw_u64(9408518518620565480u64)))))
+                + ((node_245)
+                    * ((base_row[113usize])
+                        + (BFieldElement::from_raw_u64(11492978409391175103u64)))),
+            (((((node_102)
+                * ((base_row[114usize])
+                    + (BFieldElement::from_raw_u64(2786462832312611053u64))))
+                + ((node_221)
+                    * ((base_row[114usize])
+                        + (BFieldElement::from_raw_u64(11837051899140380443u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[114usize])
+                        + (BFieldElement::from_raw_u64(11546487907579866869u64)))))
+                + ((node_238)
+                    * ((base_row[114usize])
+                        + (BFieldElement::from_raw_u64(1785884128667671832u64)))))
+                + ((node_245)
+                    * ((base_row[114usize])
+                        + (BFieldElement::from_raw_u64(17615222217495663839u64)))),
+            (((((node_102)
+                * ((base_row[115usize])
+                    + (BFieldElement::from_raw_u64(6782977121958050999u64))))
+                + ((node_221)
+                    * ((base_row[115usize])
+                        + (BFieldElement::from_raw_u64(15625104599191418968u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[115usize])
+                        + (BFieldElement::from_raw_u64(14006427992450931468u64)))))
+                + ((node_238)
+                    * ((base_row[115usize])
+                        + (BFieldElement::from_raw_u64(1188899344229954938u64)))))
+                + ((node_245)
+                    * ((base_row[115usize])
+                        + (BFieldElement::from_raw_u64(5864349944556149748u64)))),
+            (((((node_102)
+                * ((base_row[116usize])
+                    + (BFieldElement::from_raw_u64(8688421733879975670u64))))
+                + ((node_221)
+                    * ((base_row[116usize])
+                        + (BFieldElement::from_raw_u64(12819157612210448391u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[116usize])
+                        + (BFieldElement::from_raw_u64(11770003398407723041u64)))))
+                + ((node_238)
+                    * ((base_row[116usize])
+                        + (BFieldElement::from_raw_u64(14740727267735052728u64)))))
+                + ((node_245)
+                    * ((base_row[116usize])
+                        + (BFieldElement::from_raw_u64(2745609811140253793u64)))),
+            (((((node_102)
+                * ((base_row[117usize])
+                    + (BFieldElement::from_raw_u64(8602724563769480463u64))))
+                + ((node_221)
+                    * ((base_row[117usize])
+                        + (BFieldElement::from_raw_u64(6235256903503367222u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[117usize])
+                        + (BFieldElement::from_raw_u64(15124190001489436038u64)))))
+                + ((node_238)
+                    * ((base_row[117usize])
+                        + (BFieldElement::from_raw_u64(880257844992994007u64)))))
+                + ((node_245)
+                    * ((base_row[117usize])
+                        + (BFieldElement::from_raw_u64(15189664869386394185u64)))),
+            (((((node_102)
+                * ((base_row[118usize])
+                    + (BFieldElement::from_raw_u64(13589155570211330507u64))))
+                + ((node_221)
+                    * ((base_row[118usize])
+                        + (BFieldElement::from_raw_u64(11242082964257948320u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[118usize])
+                        + (BFieldElement::from_raw_u64(14834674155811570980u64)))))
+                + ((node_238)
+                    * ((base_row[118usize])
+                        + (BFieldElement::from_raw_u64(10737952517017171197u64)))))
+                + ((node_245)
+                    * ((base_row[118usize])
+                        + (BFieldElement::from_raw_u64(5192963426821415349u64)))),
+            (((((node_102)
+                * ((base_row[119usize])
+                    + (BFieldElement::from_raw_u64(10263462378312899510u64))))
+                + ((node_221)
+                    * ((base_row[119usize])
+                        + (BFieldElement::from_raw_u64(5820425254787221108u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[119usize])
+                        + (BFieldElement::from_raw_u64(13004675752386552573u64)))))
+                + ((node_238)
+                    * ((base_row[119usize])
+                        + (BFieldElement::from_raw_u64(15757222735741919824u64)))))
+                + ((node_245)
+                    * ((base_row[119usize])
+                        + (BFieldElement::from_raw_u64(11971160388083607515u64)))),
+            (((((node_102)
+                * ((base_row[120usize])
+                    + (BFieldElement::from_raw_u64(3264875873073042616u64))))
+                + ((node_221)
+                    * ((base_row[120usize])
+                        + (BFieldElement::from_raw_u64(12019227591549292608u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[120usize])
+                        + (BFieldElement::from_raw_u64(1475232519215872482u64)))))
+                + ((node_238)
+                    * ((base_row[120usize])
+                        + (BFieldElement::from_raw_u64(14382578632612566479u64)))))
+                + ((node_245)
+                    * ((base_row[120usize])
+                        + (BFieldElement::from_raw_u64(11608544217838050708u64)))),
+            (((((node_102)
+                * ((base_row[121usize])
+                    + (BFieldElement::from_raw_u64(3133435276616064683u64))))
+                + ((node_221)
+                    * ((base_row[121usize])
+                        + (BFieldElement::from_raw_u64(4625353063880731092u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[121usize])
+                        + (BFieldElement::from_raw_u64(4883869161905122316u64)))))
+                + ((node_238)
+                    * ((base_row[121usize])
+                        + (BFieldElement::from_raw_u64(3305272539067787726u64)))))
+                + ((node_245)
+                    * ((base_row[121usize])
+                        + (BFieldElement::from_raw_u64(674972795234232729u64)))),
+            (((((node_102)
+                * ((base_row[122usize])
+                    + (BFieldElement::from_raw_u64(13508500531157332153u64))))
+                + ((node_221)
+                    * ((base_row[122usize])
+                        + (BFieldElement::from_raw_u64(3723900760706330287u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[122usize])
+                        + (BFieldElement::from_raw_u64(12579737103870920763u64)))))
+                + ((node_238)
+                    * ((base_row[122usize])
+                        + (BFieldElement::from_raw_u64(17082569335437832789u64)))))
+                + ((node_245)
+                    * ((base_row[122usize])
+                        + (BFieldElement::from_raw_u64(14165256104883557753u64)))),
+            (((((node_102)
+                * ((base_row[123usize])
+                    + (BFieldElement::from_raw_u64(6968886508437513677u64))))
+                + ((node_221)
+                    * ((base_row[123usize])
+                        + (BFieldElement::from_raw_u64(615596267195055952u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[123usize])
+                        + (BFieldElement::from_raw_u64(10119826060478909841u64)))))
+                + ((node_238)
+                    * ((base_row[123usize])
+                        + (BFieldElement::from_raw_u64(229051680548583225u64)))))
+                + ((node_245)
+                    * ((base_row[123usize])
+                        + (BFieldElement::from_raw_u64(15283356519694111298u64)))),
+            (((((node_102)
+                * ((base_row[124usize])
+                    + (BFieldElement::from_raw_u64(9713264609690967820u64))))
+                + ((node_221)
+                    * ((base_row[124usize])
+                        + (BFieldElement::from_raw_u64(18227830850447556704u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[124usize])
+                        + (BFieldElement::from_raw_u64(1528714547662620921u64)))))
+                + ((node_238)
+                    * ((base_row[124usize])
+                        + (BFieldElement::from_raw_u64(2943254981416254648u64)))))
+                + ((node_245)
+                    * ((base_row[124usize])
+                        + (BFieldElement::from_raw_u64(2306049938060341466u64)))),
+            (((((node_102)
+                * ((base_row[125usize])
+                    + (BFieldElement::from_raw_u64(12482374976099749513u64))))
+                + ((node_221)
+                    * ((base_row[125usize])
+                        + (BFieldElement::from_raw_u64(15609691041895848348u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[125usize])
+                        + (BFieldElement::from_raw_u64(12972275929555275935u64)))))
+                + ((node_238)
+                    * ((base_row[125usize])
+                        + (BFieldElement::from_raw_u64(5767629304344025219u64)))))
+                + ((node_245)
+                    * ((base_row[125usize])
+                        + (BFieldElement::from_raw_u64(11578793764462375094u64)))),
+            (((((node_102)
+                * ((base_row[126usize])
+                    + (BFieldElement::from_raw_u64(13209711277645656680u64))))
+                + ((node_221)
+                    * ((base_row[126usize])
+                        + (BFieldElement::from_raw_u64(15235800289984546486u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[126usize])
+                        + (BFieldElement::from_raw_u64(15992731669612695172u64)))))
+                + ((node_238)
+                    * ((base_row[126usize])
+                        + (BFieldElement::from_raw_u64(16721422493821450473u64)))))
+                + ((node_245)
+                    * ((base_row[126usize])
+                        + (BFieldElement::from_raw_u64(7511767364422267184u64)))),
+            (((((node_102)
+                * ((base_row[127usize])
+                    + (BFieldElement::from_raw_u64(87705059284758253u64))))
+                + ((node_221)
+                    * ((base_row[127usize])
+                        + (BFieldElement::from_raw_u64(11392407538241985753u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[127usize])
+                        + (BFieldElement::from_raw_u64(17877154195438905917u64)))))
+                + ((node_238)
+                    * ((base_row[127usize])
+                        + (BFieldElement::from_raw_u64(5753720429376839714u64)))))
+                + ((node_245)
+                    * ((base_row[127usize])
+                        + (BFieldElement::from_raw_u64(16999805755930336630u64)))),
+            (((((node_102)
+                * ((base_row[128usize])
+                    + (BFieldElement::from_raw_u64(330155256278907084u64))))
+                + ((node_221)
+                    * ((base_row[128usize])
+                        + (BFieldElement::from_raw_u64(11776128816341368822u64)))))
+                + ((base_row[155usize])
+                    * ((base_row[128usize])
+                        + (BFieldElement::from_raw_u64(939319986782105612u64)))))
+                + ((node_238)
+                    * ((base_row[128usize])
+                        + (BFieldElement::from_raw_u64(2063756830275051942u64)))))
+                + ((node_245)
+                    * ((base_row[128usize])
+                        + (BFieldElement::from_raw_u64(940614108343834936u64)))),
+            (base_row[129usize])
+                * ((BFieldElement::from_raw_u64(4294967295u64))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (base_row[129usize]))),
+            (base_row[135usize])
+                * ((BFieldElement::from_raw_u64(4294967295u64))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (base_row[135usize]))),
+            (base_row[139usize])
+                * ((BFieldElement::from_raw_u64(4294967295u64))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (base_row[139usize]))),
+            (base_row[139usize]) * (base_row[140usize]),
+            (BFieldElement::from_raw_u64(4294967295u64))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((base_row[141usize])
+                        * ((base_row[140usize])
+                            + (BFieldElement::from_raw_u64(18446743927680663586u64))))),
+            (base_row[144usize]) * (node_655),
+            (base_row[143usize]) * (node_655),
+            (base_row[146usize]) * (node_660),
+            (base_row[145usize]) * (node_660),
+            (base_row[167usize])
+                * ((base_row[147usize])
+                    + (BFieldElement::from_raw_u64(18446744060824649731u64))),
+            (base_row[168usize]) * (base_row[147usize]),
+            (((base_row[163usize]) * (node_655)) * (node_660)) * (base_row[147usize]),
+            (((base_row[166usize]) * (node_678)) * (node_660))
+                * ((base_row[147usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (((base_row[164usize]) * (node_680)) * (node_655))
+                * ((base_row[147usize]) + (BFieldElement::from_raw_u64(4294967295u64))),
+            (((base_row[166usize]) * (node_674)) * (node_655)) * (base_row[147usize]),
+            ((base_row[164usize]) * (base_row[139usize])) * (node_655),
+            (node_680) * (base_row[148usize]),
+            (base_row[151usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((base_row[64usize]) * (node_89))),
+            (base_row[152usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((node_89) * (node_90)) * (node_94)) * (node_97))),
+            (base_row[153usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((base_row[64usize]) * (node_90)) * (node_94)) * (node_97))),
+            (base_row[154usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((base_row[151usize]) * (node_90))),
+            (base_row[155usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((base_row[151usize]) * (node_94)) * (node_97)) * (node_101))),
+            (base_row[156usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_663)
+                        * ((base_row[142usize])
+                            + (BFieldElement::from_raw_u64(18446744043644780551u64))))),
+            (base_row[157usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((base_row[143usize]) * (base_row[144usize]))),
+            (base_row[158usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((node_663) * (node_666)) * (node_670)) * (node_674))),
+            (base_row[159usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((base_row[156usize]) * (node_666))),
+            (base_row[160usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((base_row[145usize]) * (base_row[146usize]))),
+            (base_row[161usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_86) * (base_row[62usize]))),
+            (base_row[162usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((base_row[158usize]) * (node_678))),
+            (base_row[163usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((base_row[156usize]) * (node_670)) * (node_674)) * (node_678))),
+            (base_row[164usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((base_row[159usize]) * (node_674)) * (node_678))),
+            (base_row[165usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((node_83) * (node_103)) * (base_row[62usize]))
+                        * ((base_row[63usize])
+                            + (BFieldElement::from_raw_u64(18446743897615892521u64))))),
+            (base_row[166usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((base_row[159usize]) * (node_670))),
+            (base_row[167usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((base_row[162usize]) * (node_680)) * (node_655)) * (node_660))),
+            (base_row[168usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((base_row[162usize]) * (base_row[139usize])) * (node_655))
+                        * (node_660))),
+        ];
+        let ext_constraints = [];
+        base_constraints.into_iter().chain(ext_constraints).collect()
     }
-
+    #[allow(unused_variables)]
     fn evaluate_transition_constraints(
-        _: ArrayView1<XFieldElement>,
-        _: ArrayView1<XFieldElement>,
-        _: ArrayView1<XFieldElement>,
-        _: ArrayView1<XFieldElement>,
-        _: &Challenges,
+        current_base_row: ArrayView1<XFieldElement>,
+        current_ext_row: ArrayView1<XFieldElement>,
+        next_base_row: ArrayView1<XFieldElement>,
+        next_ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        panic!("{ERROR_MESSAGE_GENERATE_CONSTRAINTS}")
+        let node_3765 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (next_base_row[8usize]));
+        let node_120 = (next_base_row[19usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[19usize]));
+        let node_517 = (next_ext_row[3usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[3usize]));
+        let node_521 = (next_ext_row[4usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[4usize]));
+        let node_124 = (next_base_row[20usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[20usize]));
+        let node_128 = (next_base_row[21usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[21usize]));
+        let node_513 = (next_ext_row[7usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[7usize]));
+        let node_1503 = (current_base_row[18usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_893 = ((next_base_row[9usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[9usize])))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_158 = (next_base_row[38usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[38usize]));
+        let node_1501 = (current_base_row[17usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_785 = (next_base_row[22usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[22usize]));
+        let node_167 = ((challenges[16usize]) * (current_base_row[7usize]))
+            + ((challenges[17usize]) * (current_base_row[13usize]));
+        let node_525 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[22usize]);
+        let node_5416 = (current_base_row[280usize])
+            * ((next_base_row[64usize])
+                + (BFieldElement::from_raw_u64(18446744052234715141u64)));
+        let node_833 = (next_base_row[24usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[24usize]));
+        let node_837 = (next_base_row[25usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[25usize]));
+        let node_841 = (next_base_row[26usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[26usize]));
+        let node_5493 = (((next_base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743992105173011u64)))
+            * ((next_base_row[63usize])
+                + (BFieldElement::from_raw_u64(18446743923385696291u64))))
+            * ((next_base_row[63usize])
+                + (BFieldElement::from_raw_u64(18446743863256154161u64)));
+        let node_4464 = (((((current_base_row[81usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((current_base_row[82usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((current_base_row[83usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (current_base_row[84usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_4475 = (((((current_base_row[85usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((current_base_row[86usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((current_base_row[87usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (current_base_row[88usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_4486 = (((((current_base_row[89usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((current_base_row[90usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((current_base_row[91usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (current_base_row[92usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_4497 = (((((current_base_row[93usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((current_base_row[94usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((current_base_row[95usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (current_base_row[96usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_845 = (next_base_row[27usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[27usize]));
+        let node_849 = (next_base_row[28usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[28usize]));
+        let node_853 = (next_base_row[29usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[29usize]));
+        let node_857 = (next_base_row[30usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[30usize]));
+        let node_861 = (next_base_row[31usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[31usize]));
+        let node_865 = (next_base_row[32usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[32usize]));
+        let node_869 = (next_base_row[33usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[33usize]));
+        let node_873 = (next_base_row[34usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[34usize]));
+        let node_877 = (next_base_row[35usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[35usize]));
+        let node_881 = (next_base_row[36usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[36usize]));
+        let node_885 = (next_base_row[37usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[37usize]));
+        let node_888 = (next_ext_row[6usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[6usize]));
+        let node_200 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[27usize]);
+        let node_829 = (next_base_row[23usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[23usize]));
+        let node_196 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[26usize]);
+        let node_295 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[39usize]));
+        let node_192 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[25usize]);
+        let node_204 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[28usize]);
+        let node_220 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[32usize]);
+        let node_208 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[29usize]);
+        let node_224 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[33usize]);
+        let node_212 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[30usize]);
+        let node_216 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[31usize]);
+        let node_1499 = (current_base_row[16usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_197 = (next_base_row[25usize]) + (node_196);
+        let node_201 = (next_base_row[26usize]) + (node_200);
+        let node_205 = (next_base_row[27usize]) + (node_204);
+        let node_228 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[34usize]);
+        let node_209 = (next_base_row[28usize]) + (node_208);
+        let node_213 = (next_base_row[29usize]) + (node_212);
+        let node_217 = (next_base_row[30usize]) + (node_216);
+        let node_159 = (node_158) + (BFieldElement::from_raw_u64(4294967295u64));
+        let node_221 = (next_base_row[31usize]) + (node_220);
+        let node_181 = (next_ext_row[6usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[71usize]));
+        let node_225 = (next_base_row[32usize]) + (node_224);
+        let node_229 = (next_base_row[33usize]) + (node_228);
+        let node_233 = (next_base_row[34usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[35usize]));
+        let node_237 = (next_base_row[35usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[36usize]));
+        let node_241 = (next_base_row[36usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[37usize]));
+        let node_5882 = (next_base_row[139usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_957 = ((current_base_row[7usize]) * (challenges[20usize]))
+            + (challenges[23usize]);
+        let node_184 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[23usize]);
+        let node_188 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[24usize]);
+        let node_116 = ((next_base_row[9usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[9usize])))
+            + (BFieldElement::from_raw_u64(18446744060824649731u64));
+        let node_189 = (next_base_row[23usize]) + (node_188);
+        let node_193 = (next_base_row[24usize]) + (node_192);
+        let node_232 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[35usize]);
+        let node_4233 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (((next_base_row[52usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[52usize]))) * (current_base_row[54usize])));
+        let node_131 = (current_base_row[39usize])
+            * ((current_base_row[39usize])
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)));
+        let node_236 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[36usize]);
+        let node_154 = ((((current_base_row[11usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((BFieldElement::from_raw_u64(34359738360u64))
+                    * (current_base_row[42usize]))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((BFieldElement::from_raw_u64(17179869180u64))
+                    * (current_base_row[41usize]))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((BFieldElement::from_raw_u64(8589934590u64))
+                    * (current_base_row[40usize]))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[39usize]));
+        let node_240 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[37usize]);
+        let node_4230 = (next_base_row[52usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[52usize]));
+        let node_5259 = (next_base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744056529682436u64));
+        let node_5878 = (current_base_row[145usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((BFieldElement::from_raw_u64(8589934590u64))
+                    * (next_base_row[145usize])));
+        let node_4108 = (next_ext_row[12usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[12usize]));
+        let node_5875 = (current_base_row[143usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((BFieldElement::from_raw_u64(8589934590u64))
+                    * (next_base_row[143usize])));
+        let node_1497 = (current_base_row[15usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_955 = (current_base_row[7usize]) * (challenges[20usize]);
+        let node_1323 = (challenges[1usize]) * (current_ext_row[3usize]);
+        let node_5263 = (next_base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743897615892521u64));
+        let node_522 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[11usize]);
+        let node_895 = (current_base_row[274usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_113 = (next_base_row[9usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[9usize]));
+        let node_527 = (next_base_row[24usize]) + (node_184);
+        let node_528 = (next_base_row[25usize]) + (node_188);
+        let node_529 = (next_base_row[26usize]) + (node_192);
+        let node_530 = (next_base_row[27usize]) + (node_196);
+        let node_531 = (next_base_row[28usize]) + (node_200);
+        let node_532 = (next_base_row[29usize]) + (node_204);
+        let node_533 = (next_base_row[30usize]) + (node_208);
+        let node_534 = (next_base_row[31usize]) + (node_212);
+        let node_541 = (node_158)
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_535 = (next_base_row[32usize]) + (node_216);
+        let node_536 = (next_base_row[33usize]) + (node_220);
+        let node_537 = (next_base_row[34usize]) + (node_224);
+        let node_538 = (next_base_row[35usize]) + (node_228);
+        let node_539 = (next_base_row[36usize]) + (node_232);
+        let node_540 = (next_base_row[37usize]) + (node_236);
+        let node_550 = (next_ext_row[6usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((current_ext_row[6usize])
+                    * ((challenges[7usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((node_167)
+                                + ((challenges[18usize]) * (current_base_row[38usize])))
+                                + ((challenges[19usize])
+                                    * (current_base_row[37usize])))))));
+        let node_4337 = ((next_base_row[59usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[59usize])))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_5124 = (((((next_base_row[65usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((next_base_row[66usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((next_base_row[67usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (next_base_row[68usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_5135 = (((((next_base_row[69usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((next_base_row[70usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((next_base_row[71usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (next_base_row[72usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_5146 = (((((next_base_row[73usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((next_base_row[74usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((next_base_row[75usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (next_base_row[76usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_5157 = (((((next_base_row[77usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((next_base_row[78usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((next_base_row[79usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (next_base_row[80usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_5254 = (next_base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_5822 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (next_base_row[135usize]));
+        let node_5909 = (next_base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446743940565565471u64));
+        let node_1495 = (current_base_row[14usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_248 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[40usize]));
+        let node_5913 = (next_base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446743949155500061u64));
+        let node_34 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((current_base_row[4usize])
+                    * ((BFieldElement::from_raw_u64(38654705655u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[3usize])))));
+        let node_1246 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[350usize]));
+        let node_526 = (next_base_row[23usize]) + (node_525);
+        let node_253 = (current_base_row[185usize])
+            * ((next_base_row[22usize]) + (node_184));
+        let node_299 = (current_base_row[186usize])
+            * ((next_base_row[22usize]) + (node_188));
+        let node_342 = (current_base_row[187usize])
+            * ((next_base_row[22usize]) + (node_192));
+        let node_329 = (next_base_row[25usize]) + (node_204);
+        let node_384 = (current_base_row[188usize])
+            * ((next_base_row[22usize]) + (node_196));
+        let node_414 = (next_base_row[27usize]) + (node_220);
+        let node_330 = (next_base_row[26usize]) + (node_208);
+        let node_185 = (next_base_row[22usize]) + (node_184);
+        let node_415 = (next_base_row[28usize]) + (node_224);
+        let node_331 = (next_base_row[27usize]) + (node_212);
+        let node_416 = (next_base_row[29usize]) + (node_228);
+        let node_332 = (next_base_row[28usize]) + (node_216);
+        let node_417 = (next_base_row[30usize]) + (node_232);
+        let node_333 = (next_base_row[29usize]) + (node_220);
+        let node_418 = (next_base_row[31usize]) + (node_236);
+        let node_334 = (next_base_row[30usize]) + (node_224);
+        let node_437 = (((((current_base_row[185usize]) * (node_159))
+            + ((current_base_row[186usize])
+                * ((node_158) + (BFieldElement::from_raw_u64(8589934590u64)))))
+            + ((current_base_row[187usize])
+                * ((node_158) + (BFieldElement::from_raw_u64(12884901885u64)))))
+            + ((current_base_row[188usize])
+                * ((node_158) + (BFieldElement::from_raw_u64(17179869180u64)))))
+            + ((current_base_row[189usize])
+                * ((node_158) + (BFieldElement::from_raw_u64(21474836475u64))));
+        let node_730 = (((((current_base_row[185usize]) * (node_541))
+            + ((current_base_row[186usize])
+                * ((node_158) + (BFieldElement::from_raw_u64(18446744060824649731u64)))))
+            + ((current_base_row[187usize])
+                * ((node_158) + (BFieldElement::from_raw_u64(18446744056529682436u64)))))
+            + ((current_base_row[188usize])
+                * ((node_158) + (BFieldElement::from_raw_u64(18446744052234715141u64)))))
+            + ((current_base_row[189usize])
+                * ((node_158) + (BFieldElement::from_raw_u64(18446744047939747846u64))));
+        let node_419 = (next_base_row[32usize]) + (node_240);
+        let node_397 = (node_158) + (BFieldElement::from_raw_u64(21474836475u64));
+        let node_335 = (next_base_row[31usize]) + (node_228);
+        let node_441 = (((((current_base_row[185usize]) * (node_181))
+            + (current_ext_row[76usize]))
+            + ((current_base_row[187usize])
+                * ((next_ext_row[6usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_ext_row[72usize]))))) + (current_ext_row[77usize]))
+            + ((current_base_row[189usize])
+                * ((next_ext_row[6usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_ext_row[59usize]))));
+        let node_734 = ((((current_ext_row[78usize]) + (current_ext_row[79usize]))
+            + (current_ext_row[80usize])) + (current_ext_row[81usize]))
+            + ((current_base_row[189usize])
+                * ((next_ext_row[6usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_ext_row[74usize]))));
+        let node_408 = (next_ext_row[6usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[59usize]));
+        let node_336 = (next_base_row[32usize]) + (node_232);
+        let node_337 = (next_base_row[33usize]) + (node_236);
+        let node_449 = ((((current_base_row[290usize]) + (current_base_row[291usize]))
+            + (current_base_row[292usize])) + (current_base_row[293usize]))
+            + (current_base_row[294usize]);
+        let node_742 = ((((current_base_row[285usize]) + (current_base_row[286usize]))
+            + (current_base_row[287usize])) + (current_base_row[288usize]))
+            + (current_base_row[289usize]);
+        let node_338 = (next_base_row[34usize]) + (node_240);
+        let node_453 = ((((current_base_row[332usize]) + (current_base_row[334usize]))
+            + (current_base_row[336usize])) + (current_base_row[338usize]))
+            + (current_base_row[340usize]);
+        let node_746 = (((((current_base_row[185usize]) * (node_528))
+            + ((current_base_row[186usize]) * ((next_base_row[26usize]) + (node_188))))
+            + ((current_base_row[187usize]) * ((next_base_row[27usize]) + (node_188))))
+            + ((current_base_row[188usize]) * ((next_base_row[28usize]) + (node_188))))
+            + ((current_base_row[189usize]) * ((next_base_row[29usize]) + (node_188)));
+        let node_314 = (node_158) + (BFieldElement::from_raw_u64(12884901885u64));
+        let node_457 = ((((current_base_row[333usize]) + (current_base_row[335usize]))
+            + (current_base_row[337usize])) + (current_base_row[339usize]))
+            + (current_base_row[341usize]);
+        let node_750 = (((((current_base_row[185usize]) * (node_529))
+            + ((current_base_row[186usize]) * ((next_base_row[27usize]) + (node_192))))
+            + ((current_base_row[187usize]) * ((next_base_row[28usize]) + (node_192))))
+            + ((current_base_row[188usize]) * ((next_base_row[29usize]) + (node_192))))
+            + ((current_base_row[189usize]) * ((next_base_row[30usize]) + (node_192)));
+        let node_325 = (next_ext_row[6usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[72usize]));
+        let node_461 = (((((current_base_row[185usize]) * (node_201))
+            + ((current_base_row[186usize]) * ((next_base_row[26usize]) + (node_204))))
+            + ((current_base_row[187usize]) * (node_330)))
+            + ((current_base_row[188usize]) * ((next_base_row[26usize]) + (node_212))))
+            + ((current_base_row[189usize]) * ((next_base_row[26usize]) + (node_216)));
+        let node_754 = (((((current_base_row[185usize]) * (node_530))
+            + ((current_base_row[186usize]) * ((next_base_row[28usize]) + (node_196))))
+            + ((current_base_row[187usize]) * ((next_base_row[29usize]) + (node_196))))
+            + ((current_base_row[188usize]) * ((next_base_row[30usize]) + (node_196))))
+            + ((current_base_row[189usize]) * ((next_base_row[31usize]) + (node_196)));
+        let node_465 = (((((current_base_row[185usize]) * (node_205))
+            + ((current_base_row[186usize]) * ((next_base_row[27usize]) + (node_208))))
+            + ((current_base_row[187usize]) * (node_331)))
+            + ((current_base_row[188usize]) * ((next_base_row[27usize]) + (node_216))))
+            + ((current_base_row[189usize]) * (node_414));
+        let node_758 = (((((current_base_row[185usize]) * (node_531))
+            + ((current_base_row[186usize]) * ((next_base_row[29usize]) + (node_200))))
+            + ((current_base_row[187usize]) * ((next_base_row[30usize]) + (node_200))))
+            + ((current_base_row[188usize]) * ((next_base_row[31usize]) + (node_200))))
+            + ((current_base_row[189usize]) * ((next_base_row[32usize]) + (node_200)));
+        let node_469 = (((((current_base_row[185usize]) * (node_209))
+            + ((current_base_row[186usize]) * ((next_base_row[28usize]) + (node_212))))
+            + ((current_base_row[187usize]) * (node_332)))
+            + ((current_base_row[188usize]) * ((next_base_row[28usize]) + (node_220))))
+            + ((current_base_row[189usize]) * (node_415));
+        let node_762 = (((((current_base_row[185usize]) * (node_532))
+            + ((current_base_row[186usize]) * ((next_base_row[30usize]) + (node_204))))
+            + ((current_base_row[187usize]) * ((next_base_row[31usize]) + (node_204))))
+            + ((current_base_row[188usize]) * ((next_base_row[32usize]) + (node_204))))
+            + ((current_base_row[189usize]) * ((next_base_row[33usize]) + (node_204)));
+        let node_473 = (((((current_base_row[185usize]) * (node_213))
+            + ((current_base_row[186usize]) * ((next_base_row[29usize]) + (node_216))))
+            + ((current_base_row[187usize]) * (node_333)))
+            + ((current_base_row[188usize]) * ((next_base_row[29usize]) + (node_224))))
+            + ((current_base_row[189usize]) * (node_416));
+        let node_766 = (((((current_base_row[185usize]) * (node_533))
+            + ((current_base_row[186usize]) * ((next_base_row[31usize]) + (node_208))))
+            + ((current_base_row[187usize]) * ((next_base_row[32usize]) + (node_208))))
+            + ((current_base_row[188usize]) * ((next_base_row[33usize]) + (node_208))))
+            + ((current_base_row[189usize]) * ((next_base_row[34usize]) + (node_208)));
+        let node_477 = (((((current_base_row[185usize]) * (node_217))
+            + ((current_base_row[186usize]) * ((next_base_row[30usize]) + (node_220))))
+            + ((current_base_row[187usize]) * (node_334)))
+            + ((current_base_row[188usize]) * ((next_base_row[30usize]) + (node_228))))
+            + ((current_base_row[189usize]) * (node_417));
+        let node_770 = (((((current_base_row[185usize]) * (node_534))
+            + ((current_base_row[186usize]) * ((next_base_row[32usize]) + (node_212))))
+            + ((current_base_row[187usize]) * ((next_base_row[33usize]) + (node_212))))
+            + ((current_base_row[188usize]) * ((next_base_row[34usize]) + (node_212))))
+            + ((current_base_row[189usize]) * ((next_base_row[35usize]) + (node_212)));
+        let node_481 = (((((current_base_row[185usize]) * (node_221))
+            + ((current_base_row[186usize]) * ((next_base_row[31usize]) + (node_224))))
+            + ((current_base_row[187usize]) * (node_335)))
+            + ((current_base_row[188usize]) * ((next_base_row[31usize]) + (node_232))))
+            + ((current_base_row[189usize]) * (node_418));
+        let node_774 = (((((current_base_row[185usize]) * (node_535))
+            + ((current_base_row[186usize]) * ((next_base_row[33usize]) + (node_216))))
+            + ((current_base_row[187usize]) * ((next_base_row[34usize]) + (node_216))))
+            + ((current_base_row[188usize]) * ((next_base_row[35usize]) + (node_216))))
+            + ((current_base_row[189usize]) * ((next_base_row[36usize]) + (node_216)));
+        let node_485 = (((((current_base_row[185usize]) * (node_225))
+            + ((current_base_row[186usize]) * ((next_base_row[32usize]) + (node_228))))
+            + ((current_base_row[187usize]) * (node_336)))
+            + ((current_base_row[188usize]) * ((next_base_row[32usize]) + (node_236))))
+            + ((current_base_row[189usize]) * (node_419));
+        let node_778 = (((((current_base_row[185usize]) * (node_536))
+            + ((current_base_row[186usize]) * ((next_base_row[34usize]) + (node_220))))
+            + ((current_base_row[187usize]) * ((next_base_row[35usize]) + (node_220))))
+            + ((current_base_row[188usize]) * ((next_base_row[36usize]) + (node_220))))
+            + ((current_base_row[189usize]) * ((next_base_row[37usize]) + (node_220)));
+        let node_488 = (((current_base_row[320usize]) + (current_base_row[321usize]))
+            + (current_base_row[322usize])) + (current_base_row[323usize]);
+        let node_781 = (((current_base_row[325usize]) + (current_base_row[326usize]))
+            + (current_base_row[327usize])) + (current_base_row[328usize]);
+        let node_490 = (((current_base_row[185usize]) * (node_233))
+            + ((current_base_row[186usize]) * ((next_base_row[34usize]) + (node_236))))
+            + ((current_base_row[187usize]) * (node_338));
+        let node_783 = (((current_base_row[185usize]) * (node_538))
+            + ((current_base_row[186usize]) * ((next_base_row[36usize]) + (node_228))))
+            + ((current_base_row[187usize]) * ((next_base_row[37usize]) + (node_228)));
+        let node_491 = ((current_base_row[185usize]) * (node_237))
+            + ((current_base_row[186usize]) * ((next_base_row[35usize]) + (node_240)));
+        let node_784 = ((current_base_row[185usize]) * (node_539))
+            + ((current_base_row[186usize]) * ((next_base_row[37usize]) + (node_232)));
+        let node_267 = (current_base_row[185usize]) * (node_241);
+        let node_567 = (current_base_row[185usize]) * (node_540);
+        let node_4045 = ((next_ext_row[11usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((challenges[6usize]) * (current_ext_row[11usize]))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((challenges[31usize]) * (current_base_row[10usize])));
+        let node_4072 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * ((challenges[57usize]) * (current_base_row[10usize]));
+        let node_4112 = ((node_4108)
+            * (((((challenges[10usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((challenges[55usize]) * (current_base_row[22usize]))))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((challenges[56usize]) * (current_base_row[23usize]))))
+                + (node_4072))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((challenges[58usize]) * (next_base_row[22usize])))))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_4076 = (challenges[10usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((challenges[55usize]) * (current_base_row[22usize])));
+        let node_4156 = ((next_base_row[48usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[48usize])))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_4155 = (next_base_row[48usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[48usize]));
+        let node_4162 = (next_base_row[47usize])
+            + (BFieldElement::from_raw_u64(18446744060824649731u64));
+        let node_4188 = (next_ext_row[15usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[15usize]));
+        let node_4225 = (next_base_row[51usize])
+            + (BFieldElement::from_raw_u64(18446744060824649731u64));
+        let node_4308 = (next_ext_row[21usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[21usize]));
+        let node_4336 = (next_base_row[59usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[59usize]));
+        let node_5251 = (current_base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744056529682436u64));
+        let node_5315 = (next_base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744047939747846u64));
+        let node_5366 = (next_base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743992105173011u64));
+        let node_5368 = (next_base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743923385696291u64));
+        let node_5485 = (next_ext_row[28usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[28usize]));
+        let node_5506 = (next_ext_row[29usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[29usize]));
+        let node_5523 = (next_ext_row[30usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[30usize]));
+        let node_5540 = (next_ext_row[31usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[31usize]));
+        let node_5557 = (next_ext_row[32usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[32usize]));
+        let node_5574 = (next_ext_row[33usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[33usize]));
+        let node_5591 = (next_ext_row[34usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[34usize]));
+        let node_5608 = (next_ext_row[35usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[35usize]));
+        let node_5625 = (next_ext_row[36usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[36usize]));
+        let node_5642 = (next_ext_row[37usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[37usize]));
+        let node_5659 = (next_ext_row[38usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[38usize]));
+        let node_5676 = (next_ext_row[39usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[39usize]));
+        let node_5693 = (next_ext_row[40usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[40usize]));
+        let node_5710 = (next_ext_row[41usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[41usize]));
+        let node_5727 = (next_ext_row[42usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[42usize]));
+        let node_5744 = (next_ext_row[43usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[43usize]));
+        let node_5770 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (next_base_row[129usize]));
+        let node_5872 = (current_base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446743940565565471u64));
+        let node_5899 = (node_5878)
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_5907 = (next_base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446744017874976781u64));
+        let node_5275 = (next_base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744060824649731u64));
+        let node_30 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[3usize]);
+        let node_47 = (next_base_row[6usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_51 = (next_ext_row[0usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[0usize]));
+        let node_31 = (BFieldElement::from_raw_u64(38654705655u64)) + (node_30);
+        let node_74 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (next_base_row[1usize]);
+        let node_90 = (BFieldElement::from_raw_u64(38654705655u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (next_base_row[3usize]));
+        let node_88 = (next_ext_row[2usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[2usize]));
+        let node_892 = (next_base_row[10usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[10usize]));
+        let node_127 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[21usize]);
+        let node_1240 = (next_base_row[22usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[348usize]));
+        let node_1262 = (current_base_row[23usize]) * (next_base_row[23usize]);
+        let node_1265 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (next_base_row[22usize]);
+        let node_1270 = (current_base_row[22usize]) * (current_base_row[25usize]);
+        let node_1271 = (current_base_row[24usize]) * (current_base_row[26usize]);
+        let node_1274 = (current_base_row[23usize]) * (current_base_row[27usize]);
+        let node_1297 = (current_base_row[24usize]) * (next_base_row[23usize]);
+        let node_1300 = (current_base_row[23usize]) * (next_base_row[24usize]);
+        let node_1695 = (current_base_row[281usize]) * (node_120);
+        let node_1697 = (current_base_row[283usize]) * (node_120);
+        let node_553 = (current_base_row[185usize]) * (node_526);
+        let node_112 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[9usize]);
+        let node_123 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[20usize]);
+        let node_1243 = (current_base_row[23usize]) + (node_525);
+        let node_1282 = (current_base_row[24usize]) * (current_base_row[27usize]);
+        let node_1307 = (current_base_row[24usize]) * (next_base_row[24usize]);
+        let node_1774 = (current_base_row[281usize]) * (node_124);
+        let node_1776 = (current_base_row[283usize]) * (node_124);
+        let node_596 = (current_base_row[186usize])
+            * ((next_base_row[24usize]) + (node_525));
+        let node_247 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[40usize]);
+        let node_144 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * ((BFieldElement::from_raw_u64(34359738360u64))
+                * (current_base_row[42usize]));
+        let node_703 = (next_base_row[27usize]) + (node_525);
+        let node_1853 = (current_base_row[281usize]) * (node_128);
+        let node_1855 = (current_base_row[283usize]) * (node_128);
+        let node_638 = (current_base_row[187usize])
+            * ((next_base_row[25usize]) + (node_525));
+        let node_704 = (next_base_row[28usize]) + (node_184);
+        let node_678 = (current_base_row[188usize])
+            * ((next_base_row[26usize]) + (node_525));
+        let node_705 = (next_base_row[29usize]) + (node_188);
+        let node_706 = (next_base_row[30usize]) + (node_192);
+        let node_788 = (current_base_row[317usize])
+            * ((next_base_row[22usize]) + (node_204));
+        let node_707 = (next_base_row[31usize]) + (node_196);
+        let node_795 = (next_base_row[22usize]) + (node_220);
+        let node_815 = (next_base_row[32usize]) + (node_525);
+        let node_709 = (next_base_row[33usize]) + (node_204);
+        let node_792 = (current_base_row[273usize])
+            * ((next_base_row[22usize]) + (node_212));
+        let node_710 = (next_base_row[34usize]) + (node_208);
+        let node_794 = (current_base_row[319usize])
+            * ((next_base_row[22usize]) + (node_216));
+        let node_711 = (next_base_row[35usize]) + (node_212);
+        let node_796 = (current_base_row[330usize]) * (node_795);
+        let node_1036 = (node_785)
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_1062 = (node_785)
+            + (BFieldElement::from_raw_u64(18446744056529682436u64));
+        let node_712 = (next_base_row[36usize]) + (node_216);
+        let node_445 = ((((node_253) + (node_299)) + (node_342)) + (node_384))
+            + (current_base_row[297usize]);
+        let node_738 = ((((node_553) + (node_596)) + (node_638)) + (node_678))
+            + (current_base_row[298usize]);
+        let node_798 = (current_base_row[331usize])
+            * ((next_base_row[22usize]) + (node_224));
+        let node_713 = (next_base_row[37usize]) + (node_220);
+        let node_409 = (next_base_row[22usize]) + (node_200);
+        let node_800 = (current_base_row[277usize])
+            * ((next_base_row[22usize]) + (node_228));
+        let node_691 = (node_158)
+            + (BFieldElement::from_raw_u64(18446744047939747846u64));
+        let node_410 = (next_base_row[23usize]) + (node_204);
+        let node_802 = (current_base_row[342usize])
+            * ((next_base_row[22usize]) + (node_232));
+        let node_702 = (next_ext_row[6usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[74usize]));
+        let node_411 = (next_base_row[24usize]) + (node_208);
+        let node_804 = (current_base_row[343usize])
+            * ((next_base_row[22usize]) + (node_236));
+        let node_412 = (next_base_row[25usize]) + (node_212);
+        let node_806 = (current_base_row[344usize])
+            * ((next_base_row[22usize]) + (node_240));
+        let node_413 = (next_base_row[26usize]) + (node_216);
+        let node_1385 = ((challenges[2usize])
+            * (((challenges[2usize])
+                * (((challenges[2usize])
+                    * (((challenges[2usize]) * (current_ext_row[4usize]))
+                        + (current_base_row[22usize]))) + (current_base_row[23usize])))
+                + (current_base_row[24usize]))) + (current_base_row[25usize]);
+        let node_1439 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (next_ext_row[7usize]);
+        let node_1441 = (current_base_row[39usize]) * (current_base_row[42usize]);
+        let node_1451 = ((current_base_row[41usize]) * (current_base_row[43usize]))
+            + ((current_base_row[40usize]) * (current_base_row[44usize]));
+        let node_1380 = ((challenges[2usize])
+            * (((challenges[2usize])
+                * (((challenges[2usize]) * (current_ext_row[4usize]))
+                    + (current_base_row[22usize]))) + (current_base_row[23usize])))
+            + (current_base_row[24usize]);
+        let node_1452 = (current_base_row[41usize]) * (current_base_row[44usize]);
+        let node_1375 = ((challenges[2usize])
+            * (((challenges[2usize]) * (current_ext_row[4usize]))
+                + (current_base_row[22usize]))) + (current_base_row[23usize]);
+        let node_1370 = ((challenges[2usize]) * (current_ext_row[4usize]))
+            + (current_base_row[22usize]);
+        let node_1465 = (node_829)
+            + (BFieldElement::from_raw_u64(18446744056529682436u64));
+        let node_3899 = (next_ext_row[5usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[5usize]));
+        let node_3985 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (((((((((((challenges[32usize]) * (next_base_row[22usize]))
+                + ((challenges[33usize]) * (next_base_row[23usize])))
+                + ((challenges[34usize]) * (next_base_row[24usize])))
+                + ((challenges[35usize]) * (next_base_row[25usize])))
+                + ((challenges[36usize]) * (next_base_row[26usize])))
+                + ((challenges[37usize]) * (next_base_row[27usize])))
+                + ((challenges[38usize]) * (next_base_row[28usize])))
+                + ((challenges[39usize]) * (next_base_row[29usize])))
+                + ((challenges[40usize]) * (next_base_row[30usize])))
+                + ((challenges[41usize]) * (next_base_row[31usize])));
+        let node_3968 = (((((challenges[32usize]) * (next_base_row[22usize]))
+            + ((challenges[33usize]) * (next_base_row[23usize])))
+            + ((challenges[34usize]) * (next_base_row[24usize])))
+            + ((challenges[35usize]) * (next_base_row[25usize])))
+            + ((challenges[36usize]) * (next_base_row[26usize]));
+        let node_4065 = (challenges[10usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((challenges[55usize]) * (next_base_row[22usize])));
+        let node_4068 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * ((challenges[56usize]) * (next_base_row[23usize]));
+        let node_4079 = (node_4076)
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((challenges[56usize]) * (current_base_row[23usize])));
+        let node_4116 = ((node_4108)
+            * (((node_4076) + (node_4072))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((challenges[58usize]) * (next_base_row[22usize])))))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_4102 = (((node_4065)
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((challenges[56usize]) * (current_base_row[23usize]))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((challenges[57usize])
+                    * (BFieldElement::from_raw_u64(25769803770u64)))))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (challenges[58usize]));
+        let node_4106 = ((node_4076) + (node_4068))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * ((challenges[57usize])
+                    * (BFieldElement::from_raw_u64(17179869180u64))));
+        let node_4179 = (next_base_row[47usize])
+            * ((next_base_row[47usize])
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)));
+        let node_4248 = (challenges[12usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (next_base_row[52usize]));
+        let node_4253 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_ext_row[16usize]);
+        let node_4299 = ((next_base_row[51usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+            * (next_base_row[51usize]);
+        let node_4341 = (node_4337)
+            * ((current_base_row[58usize])
+                + (BFieldElement::from_raw_u64(18446744000695107601u64)));
+        let node_4349 = (next_base_row[57usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[57usize]));
+        let node_4340 = (current_base_row[58usize])
+            + (BFieldElement::from_raw_u64(18446744000695107601u64));
+        let node_4371 = (next_ext_row[23usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[23usize]));
+        let node_5233 = (current_base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743897615892521u64));
+        let node_5235 = (current_base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744047939747846u64));
+        let node_5466 = (next_ext_row[24usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[24usize]));
+        let node_4408 = (((((current_base_row[65usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((current_base_row[66usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((current_base_row[67usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (current_base_row[68usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_4419 = (((((current_base_row[69usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((current_base_row[70usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((current_base_row[71usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (current_base_row[72usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_4430 = (((((current_base_row[73usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((current_base_row[74usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((current_base_row[75usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (current_base_row[76usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_4441 = (((((current_base_row[77usize])
+            * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+            + ((current_base_row[78usize])
+                * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+            + ((current_base_row[79usize])
+                * (BFieldElement::from_raw_u64(281474976645120u64))))
+            + (current_base_row[80usize])) * (BFieldElement::from_raw_u64(1u64));
+        let node_5265 = (node_5235) * (node_5233);
+        let node_5279 = ((current_base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+            * ((current_base_row[62usize])
+                + (BFieldElement::from_raw_u64(18446744060824649731u64)));
+        let node_5297 = (challenges[42usize])
+            * ((next_base_row[103usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (current_base_row[103usize])));
+        let node_5298 = (challenges[43usize])
+            * ((next_base_row[104usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (current_base_row[104usize])));
+        let node_5300 = (challenges[44usize])
+            * ((next_base_row[105usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (current_base_row[105usize])));
+        let node_5302 = (challenges[45usize])
+            * ((next_base_row[106usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (current_base_row[106usize])));
+        let node_5304 = (challenges[46usize])
+            * ((next_base_row[107usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (current_base_row[107usize])));
+        let node_5306 = (challenges[47usize])
+            * ((next_base_row[108usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (current_base_row[108usize])));
+        let node_5396 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (((((((((((challenges[32usize]) * (node_5124))
+                + ((challenges[33usize]) * (node_5135)))
+                + ((challenges[34usize]) * (node_5146)))
+                + ((challenges[35usize]) * (node_5157)))
+                + ((challenges[36usize]) * (next_base_row[97usize])))
+                + ((challenges[37usize]) * (next_base_row[98usize])))
+                + ((challenges[38usize]) * (next_base_row[99usize])))
+                + ((challenges[39usize]) * (next_base_row[100usize])))
+                + ((challenges[40usize]) * (next_base_row[101usize])))
+                + ((challenges[41usize]) * (next_base_row[102usize])));
+        let node_5373 = (next_ext_row[25usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[25usize]));
+        let node_5382 = (((((challenges[32usize]) * (node_5124))
+            + ((challenges[33usize]) * (node_5135)))
+            + ((challenges[34usize]) * (node_5146)))
+            + ((challenges[35usize]) * (node_5157)))
+            + ((challenges[36usize]) * (next_base_row[97usize]));
+        let node_5407 = (next_ext_row[26usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[26usize]));
+        let node_5433 = (next_ext_row[27usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[27usize]));
+        let node_5436 = (next_base_row[63usize])
+            + (BFieldElement::from_raw_u64(18446743863256154161u64));
+        let node_5780 = (next_ext_row[44usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[44usize]));
+        let node_5796 = (next_ext_row[45usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[45usize]));
+        let node_5791 = ((challenges[52usize]) * (next_base_row[131usize]))
+            + ((challenges[53usize]) * (next_base_row[133usize]));
+        let node_5794 = ((challenges[52usize]) * (next_base_row[130usize]))
+            + ((challenges[53usize]) * (next_base_row[132usize]));
+        let node_5834 = (next_ext_row[46usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[46usize]));
+        let node_5890 = ((next_base_row[140usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[140usize])))
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_5896 = (node_5875)
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_5916 = (next_base_row[147usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_5918 = (next_base_row[147usize])
+            + (BFieldElement::from_raw_u64(18446744060824649731u64));
+        let node_5921 = (current_base_row[314usize]) * (next_base_row[147usize]);
+        let node_5923 = (current_base_row[147usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_5888 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (current_base_row[140usize]);
+        let node_5982 = (next_base_row[147usize]) * (next_base_row[147usize]);
+        let node_5932 = (BFieldElement::from_raw_u64(18446744065119617026u64))
+            * (node_5875);
+        let node_5998 = (next_ext_row[48usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_ext_row[48usize]));
+        let node_243 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[42usize]));
+        let node_245 = (BFieldElement::from_raw_u64(4294967295u64))
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (current_base_row[41usize]));
+        let node_1522 = (current_base_row[12usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_1505 = (current_base_row[13usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_133 = (current_base_row[40usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_5308 = (next_base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_5309 = (next_base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744060824649731u64));
+        let node_5311 = (next_base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744056529682436u64));
+        let node_5901 = (next_base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446744052234715141u64));
+        let node_5903 = (next_base_row[142usize])
+            + (BFieldElement::from_raw_u64(18446744009285042191u64));
+        let node_5313 = (next_base_row[64usize])
+            + (BFieldElement::from_raw_u64(18446744052234715141u64));
+        let node_5250 = (current_base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744060824649731u64));
+        let node_5272 = (current_base_row[62usize])
+            + (BFieldElement::from_raw_u64(18446744065119617026u64));
+        let node_176 = (challenges[7usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (((node_167) + ((challenges[18usize]) * (next_base_row[38usize])))
+                    + ((challenges[19usize]) * (next_base_row[37usize]))));
+        let node_547 = (challenges[7usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (((node_167) + ((challenges[18usize]) * (current_base_row[38usize])))
+                    + ((challenges[19usize]) * (current_base_row[37usize]))));
+        let node_966 = (challenges[8usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (((node_957)
+                    + (((next_base_row[22usize])
+                        + (BFieldElement::from_raw_u64(4294967295u64)))
+                        * (challenges[21usize])))
+                    + ((next_base_row[23usize]) * (challenges[22usize]))));
+        let node_1042 = (challenges[8usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (((node_955) + ((current_base_row[22usize]) * (challenges[21usize])))
+                    + ((current_base_row[23usize]) * (challenges[22usize]))));
+        let node_1050 = ((current_base_row[22usize])
+            + (BFieldElement::from_raw_u64(4294967295u64))) * (challenges[21usize]);
+        let node_1064 = ((current_base_row[22usize])
+            + (BFieldElement::from_raw_u64(8589934590u64))) * (challenges[21usize]);
+        let node_1404 = (challenges[8usize])
+            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                * (((node_957) + ((current_base_row[22usize]) * (challenges[21usize])))
+                    + ((current_base_row[39usize]) * (challenges[22usize]))));
+        let node_1406 = (current_base_row[40usize]) * (challenges[22usize]);
+        let node_1412 = (current_base_row[41usize]) * (challenges[22usize]);
+        let node_1418 = (node_957)
+            + ((current_base_row[23usize]) * (challenges[21usize]));
+        let node_1419 = (current_base_row[42usize]) * (challenges[22usize]);
+        let node_1425 = (node_957)
+            + (((current_base_row[23usize])
+                + (BFieldElement::from_raw_u64(4294967295u64))) * (challenges[21usize]));
+        let node_1432 = (node_957)
+            + (((current_base_row[23usize])
+                + (BFieldElement::from_raw_u64(8589934590u64))) * (challenges[21usize]));
+        let base_constraints = [
+            (next_base_row[0usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[0usize])
+                        + (BFieldElement::from_raw_u64(4294967295u64)))),
+            (current_base_row[6usize])
+                * ((next_base_row[6usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[6usize]))),
+            ((node_34) * (next_base_row[3usize]))
+                + ((current_base_row[4usize])
+                    * (((next_base_row[3usize]) + (node_30))
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))),
+            (current_base_row[5usize])
+                * ((next_base_row[5usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (((current_base_row[5usize])
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+                * (next_base_row[5usize]))
+                * ((next_base_row[1usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (current_base_row[5usize]) * (next_base_row[1usize]),
+            ((current_base_row[5usize]) * (node_34)) * (node_47),
+            ((next_base_row[7usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (current_base_row[7usize])))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            (current_base_row[8usize])
+                * ((next_base_row[8usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[8usize]))),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_120))
+                + ((current_base_row[213usize])
+                    * ((next_base_row[22usize]) + (node_522))))
+                + ((current_base_row[206usize]) * (node_120)))
+                + ((current_base_row[210usize])
+                    * ((current_base_row[324usize]) * (node_785))))
+                + ((current_base_row[208usize]) * (current_base_row[324usize])))
+                + ((current_base_row[216usize]) * (node_892)))
+                + ((current_base_row[221usize]) * (node_120)))
+                + ((current_base_row[214usize])
+                    * ((node_895) * (current_base_row[39usize]))))
+                + ((current_base_row[224usize])
+                    * ((node_120)
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))))
+                + ((current_base_row[228usize])
+                    * ((next_base_row[19usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((current_base_row[19usize])
+                                + (BFieldElement::from_raw_u64(
+                                    18446744065119617026u64,
+                                )))))))
+                + ((current_base_row[225usize])
+                    * ((next_base_row[9usize]) + (node_127))))
+                + ((current_base_row[226usize])
+                    * ((current_base_row[22usize])
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))))
+                + ((current_base_row[215usize]) * (node_120)))
+                + ((current_base_row[223usize]) * (node_120)))
+                + ((current_base_row[243usize]) * (node_120)))
+                + ((current_base_row[233usize]) * (node_131)))
+                + ((current_base_row[230usize])
+                    * ((current_base_row[27usize]) + (node_525))))
+                + ((current_base_row[232usize]) * (node_120)))
+                + ((current_base_row[246usize]) * (node_120)))
+                + ((current_base_row[249usize]) * (node_120)))
+                + ((current_base_row[235usize]) * ((node_785) + (node_184))))
+                + ((current_base_row[236usize]) * (node_1240)))
+                + ((current_base_row[237usize])
+                    * ((current_base_row[349usize])
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))))
+                + ((current_base_row[239usize])
+                    * ((current_base_row[39usize]) * (node_1246))))
+                + ((current_base_row[238usize])
+                    * ((current_base_row[22usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((BFieldElement::from_raw_u64(18446744069414584320u64))
+                                * (next_base_row[23usize])) + (next_base_row[22usize]))))))
+                + ((current_base_row[240usize]) * (node_120)))
+                + ((current_base_row[241usize]) * (node_120)))
+                + ((current_base_row[242usize]) * (node_120)))
+                + ((current_base_row[244usize]) * (node_120)))
+                + ((current_base_row[245usize]) * (node_120)))
+                + ((current_base_row[247usize])
+                    * (((current_base_row[22usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_1262))) + (node_1265))))
+                + ((current_base_row[250usize]) * (node_120)))
+                + ((current_base_row[275usize]) * ((node_785) + (node_192))))
+                + ((current_base_row[278usize])
+                    * ((next_base_row[22usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((node_1270)
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * (node_1271)))
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * (node_1274)))))))
+                + ((current_base_row[276usize])
+                    * ((((current_base_row[349usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_1297)))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_1300)))
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))))
+                + ((current_base_row[279usize]) * (node_1240)))
+                + ((current_base_row[254usize]) * (node_120)))
+                + ((current_base_row[259usize]) * (node_120))) + (node_1695))
+                + (node_1697)) * (node_3765)) + ((node_113) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_124)) + ((current_base_row[213usize]) * (node_526)))
+                + ((current_base_row[206usize]) * (node_124)))
+                + ((current_base_row[210usize]) * (node_253)))
+                + ((current_base_row[208usize]) * (node_553)))
+                + ((current_base_row[216usize]) * (node_120)))
+                + ((current_base_row[221usize]) * (node_124)))
+                + ((current_base_row[214usize])
+                    * ((node_895) * (current_base_row[22usize]))))
+                + ((current_base_row[224usize])
+                    * (((next_base_row[20usize]) + (node_112))
+                        + (BFieldElement::from_raw_u64(18446744060824649731u64)))))
+                + ((current_base_row[228usize])
+                    * ((next_base_row[9usize]) + (node_123))))
+                + ((current_base_row[225usize]) * (node_120)))
+                + ((current_base_row[226usize]) * (node_120)))
+                + ((current_base_row[215usize]) * (node_124)))
+                + ((current_base_row[223usize]) * (node_124)))
+                + ((current_base_row[243usize]) * (node_124)))
+                + ((current_base_row[233usize])
+                    * ((((next_base_row[32usize])
+                        * (BFieldElement::from_raw_u64(8589934590u64)))
+                        + (current_base_row[39usize])) + (node_200))))
+                + ((current_base_row[230usize])
+                    * ((current_base_row[28usize]) + (node_184))))
+                + ((current_base_row[232usize]) * (node_124)))
+                + ((current_base_row[246usize]) * (node_124)))
+                + ((current_base_row[249usize]) * (node_124)))
+                + ((current_base_row[235usize]) * (node_120)))
+                + ((current_base_row[236usize]) * (node_120)))
+                + ((current_base_row[237usize]) * (node_120)))
+                + ((current_base_row[239usize]) * ((node_1243) * (node_1246))))
+                + (current_base_row[353usize]))
+                + ((current_base_row[240usize]) * (node_124)))
+                + ((current_base_row[241usize]) * (node_124)))
+                + ((current_base_row[242usize]) * (node_124)))
+                + ((current_base_row[244usize]) * (node_124)))
+                + ((current_base_row[245usize]) * (node_124)))
+                + ((current_base_row[247usize]) * (node_120)))
+                + ((current_base_row[250usize]) * (node_124)))
+                + ((current_base_row[275usize]) * ((node_829) + (node_196))))
+                + ((current_base_row[278usize])
+                    * ((next_base_row[23usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((((((current_base_row[23usize])
+                                * (current_base_row[25usize]))
+                                + ((current_base_row[22usize])
+                                    * (current_base_row[26usize])))
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * (node_1282))) + (node_1271)) + (node_1274))))))
+                + ((current_base_row[276usize])
+                    * ((((((current_base_row[23usize]) * (next_base_row[22usize]))
+                        + ((current_base_row[22usize]) * (next_base_row[23usize])))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_1307))) + (node_1297)) + (node_1300))))
+                + ((current_base_row[279usize])
+                    * ((next_base_row[23usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((current_base_row[22usize])
+                                * (current_base_row[24usize]))))))
+                + ((current_base_row[254usize]) * (node_124)))
+                + ((current_base_row[259usize]) * (node_124))) + (node_1774))
+                + (node_1776)) * (node_3765)) + ((node_892) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_128)) + ((current_base_row[213usize]) * (node_527)))
+                + ((current_base_row[206usize]) * (node_128)))
+                + ((current_base_row[210usize]) * (node_299)))
+                + ((current_base_row[208usize]) * (node_596)))
+                + ((current_base_row[216usize]) * (node_124)))
+                + ((current_base_row[221usize]) * (node_128)))
+                + ((current_base_row[214usize])
+                    * ((((((current_base_row[11usize]) + (node_247))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((BFieldElement::from_raw_u64(8589934590u64))
+                                * (current_base_row[41usize])))) + (node_144))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((BFieldElement::from_raw_u64(137438953440u64))
+                                * (current_base_row[43usize]))))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((BFieldElement::from_raw_u64(549755813760u64))
+                                * (current_base_row[44usize]))))))
+                + ((current_base_row[224usize])
+                    * ((next_base_row[21usize]) + (node_522))))
+                + ((current_base_row[228usize]) * (node_785)))
+                + ((current_base_row[225usize]) * (node_124)))
+                + ((current_base_row[226usize]) * (node_124)))
+                + ((current_base_row[215usize]) * (node_128)))
+                + ((current_base_row[223usize]) * (node_128)))
+                + ((current_base_row[243usize]) * (node_128)))
+                + ((current_base_row[233usize])
+                    * (((node_295) * (node_785))
+                        + ((current_base_row[39usize]) * (node_703)))))
+                + ((current_base_row[230usize])
+                    * ((current_base_row[29usize]) + (node_188))))
+                + ((current_base_row[232usize]) * (node_128)))
+                + ((current_base_row[246usize]) * (node_128)))
+                + ((current_base_row[249usize]) * (node_128)))
+                + ((current_base_row[235usize]) * (node_124)))
+                + ((current_base_row[236usize]) * (node_124)))
+                + ((current_base_row[237usize]) * (node_124)))
+                + ((current_base_row[239usize])
+                    * ((next_base_row[22usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_1246)))))
+                + ((current_base_row[238usize]) * (node_527)))
+                + ((current_base_row[240usize]) * (node_128)))
+                + ((current_base_row[241usize]) * (node_128)))
+                + ((current_base_row[242usize]) * (node_128)))
+                + ((current_base_row[244usize]) * (node_128)))
+                + ((current_base_row[245usize]) * (node_128)))
+                + ((current_base_row[247usize]) * (node_124)))
+                + ((current_base_row[250usize]) * (node_128)))
+                + ((current_base_row[275usize]) * ((node_833) + (node_200))))
+                + ((current_base_row[278usize])
+                    * ((next_base_row[24usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((((current_base_row[24usize])
+                                * (current_base_row[25usize]))
+                                + ((current_base_row[23usize])
+                                    * (current_base_row[26usize])))
+                                + ((current_base_row[22usize])
+                                    * (current_base_row[27usize]))) + (node_1282))))))
+                + ((current_base_row[276usize])
+                    * (((((current_base_row[24usize]) * (next_base_row[22usize]))
+                        + (node_1262))
+                        + ((current_base_row[22usize]) * (next_base_row[24usize])))
+                        + (node_1307))))
+                + ((current_base_row[279usize])
+                    * ((next_base_row[24usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_1270)))))
+                + ((current_base_row[254usize]) * (node_128)))
+                + ((current_base_row[259usize]) * (node_128))) + (node_1853))
+                + (node_1855)) * (node_3765))
+                + (((next_base_row[11usize]) + (node_522)) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_116)) + ((current_base_row[213usize]) * (node_528)))
+                + ((current_base_row[206usize]) * (node_116)))
+                + ((current_base_row[210usize]) * (node_342)))
+                + ((current_base_row[208usize]) * (node_638)))
+                + ((current_base_row[216usize]) * (node_128)))
+                + ((current_base_row[221usize]) * (node_893)))
+                + (current_base_row[354usize]))
+                + ((current_base_row[224usize])
+                    * ((next_base_row[9usize]) + (node_522))))
+                + ((current_base_row[228usize]) * (node_829)))
+                + ((current_base_row[225usize]) * (node_128)))
+                + ((current_base_row[226usize]) * (node_128)))
+                + ((current_base_row[215usize]) * (node_116)))
+                + ((current_base_row[223usize]) * (node_116)))
+                + ((current_base_row[243usize]) * (node_893)))
+                + ((current_base_row[233usize])
+                    * (((node_295) * (node_829))
+                        + ((current_base_row[39usize]) * (node_704)))))
+                + ((current_base_row[230usize])
+                    * ((current_base_row[30usize]) + (node_192))))
+                + ((current_base_row[232usize]) * (node_893)))
+                + ((current_base_row[246usize]) * (node_893)))
+                + ((current_base_row[249usize]) * (node_893)))
+                + ((current_base_row[235usize]) * (node_128)))
+                + ((current_base_row[236usize]) * (node_128)))
+                + ((current_base_row[237usize]) * (node_128)))
+                + ((current_base_row[239usize]) * (node_120)))
+                + ((current_base_row[238usize]) * (node_528)))
+                + ((current_base_row[240usize]) * (node_893)))
+                + ((current_base_row[241usize]) * (node_893)))
+                + ((current_base_row[242usize]) * (node_893)))
+                + ((current_base_row[244usize]) * (node_893)))
+                + ((current_base_row[245usize]) * (node_893)))
+                + ((current_base_row[247usize]) * (node_128)))
+                + ((current_base_row[250usize]) * (node_893)))
+                + ((current_base_row[275usize]) * (node_329)))
+                + ((current_base_row[278usize]) * (node_329)))
+                + ((current_base_row[276usize]) * (node_837)))
+                + ((current_base_row[279usize]) * (node_197)))
+                + ((current_base_row[254usize]) * (node_116)))
+                + ((current_base_row[259usize]) * (node_116)))
+                + ((current_base_row[281usize]) * (node_893)))
+                + ((current_base_row[283usize]) * (node_893))) * (node_3765))
+                + ((node_120) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (current_base_row[263usize]))
+                + ((current_base_row[213usize]) * (node_531)))
+                + ((current_base_row[206usize]) * (current_base_row[263usize])))
+                + ((current_base_row[210usize]) * (node_788)))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[317usize])
+                        * ((next_base_row[28usize]) + (node_525)))))
+                + ((current_base_row[216usize]) * (node_829)))
+                + ((current_base_row[221usize]) * (node_833)))
+                + (current_base_row[356usize]))
+                + ((current_base_row[224usize]) * (node_833)))
+                + ((current_base_row[228usize]) * (node_841)))
+                + ((current_base_row[225usize]) * (node_833)))
+                + ((current_base_row[226usize]) * (node_189)))
+                + ((current_base_row[215usize]) * (current_base_row[263usize])))
+                + ((current_base_row[223usize]) * (current_base_row[263usize])))
+                + ((current_base_row[243usize]) * (node_416)))
+                + ((current_base_row[233usize])
+                    * (((node_295) * (node_841))
+                        + ((current_base_row[39usize]) * (node_707)))))
+                + ((current_base_row[230usize]) * (node_124)))
+                + ((current_base_row[232usize]) * (node_833)))
+                + ((current_base_row[246usize]) * (node_795)))
+                + ((current_base_row[249usize]) * (node_815)))
+                + ((current_base_row[235usize]) * (node_193)))
+                + ((current_base_row[236usize]) * (node_193)))
+                + ((current_base_row[237usize]) * (node_833)))
+                + ((current_base_row[239usize]) * (node_893)))
+                + ((current_base_row[238usize]) * (node_531)))
+                + ((current_base_row[240usize]) * (node_197)))
+                + ((current_base_row[241usize]) * (node_197)))
+                + ((current_base_row[242usize]) * (node_197)))
+                + ((current_base_row[244usize]) * (node_837)))
+                + ((current_base_row[245usize]) * (node_197)))
+                + ((current_base_row[247usize]) * (node_837)))
+                + ((current_base_row[250usize]) * (node_837)))
+                + ((current_base_row[275usize]) * (node_332)))
+                + ((current_base_row[278usize]) * (node_332)))
+                + ((current_base_row[276usize]) * (node_849)))
+                + ((current_base_row[279usize]) * (node_209)))
+                + ((current_base_row[254usize]) * (current_base_row[263usize])))
+                + ((current_base_row[259usize]) * (current_base_row[263usize])))
+                + ((current_base_row[281usize]) * (node_845)))
+                + ((current_base_row[283usize]) * (node_845))) * (node_3765))
+                + ((node_785) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (current_base_row[264usize]))
+                + ((current_base_row[213usize]) * (node_532)))
+                + ((current_base_row[206usize]) * (current_base_row[264usize])))
+                + ((current_base_row[210usize]) * (current_base_row[284usize])))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[272usize])
+                        * ((next_base_row[29usize]) + (node_525)))))
+                + ((current_base_row[216usize]) * (node_833)))
+                + ((current_base_row[221usize]) * (node_837)))
+                + ((current_base_row[214usize]) * (current_base_row[295usize])))
+                + ((current_base_row[224usize]) * (node_837)))
+                + ((current_base_row[228usize]) * (node_845)))
+                + ((current_base_row[225usize]) * (node_837)))
+                + ((current_base_row[226usize]) * (node_193)))
+                + ((current_base_row[215usize]) * (current_base_row[264usize])))
+                + ((current_base_row[223usize]) * (current_base_row[264usize])))
+                + ((current_base_row[243usize]) * (node_417)))
+                + ((current_base_row[233usize]) * (node_709)))
+                + ((current_base_row[230usize]) * (node_128)))
+                + ((current_base_row[232usize]) * (node_837)))
+                + ((current_base_row[246usize])
+                    * ((next_base_row[23usize]) + (node_224))))
+                + ((current_base_row[249usize])
+                    * ((next_base_row[33usize]) + (node_184))))
+                + ((current_base_row[235usize]) * (node_197)))
+                + ((current_base_row[236usize]) * (node_197)))
+                + ((current_base_row[237usize]) * (node_837)))
+                + ((current_base_row[239usize]) * (node_189)))
+                + ((current_base_row[238usize]) * (node_532)))
+                + ((current_base_row[240usize]) * (node_201)))
+                + ((current_base_row[241usize]) * (node_201)))
+                + ((current_base_row[242usize]) * (node_201)))
+                + ((current_base_row[244usize]) * (node_841)))
+                + ((current_base_row[245usize]) * (node_201)))
+                + ((current_base_row[247usize]) * (node_841)))
+                + ((current_base_row[250usize]) * (node_841)))
+                + ((current_base_row[275usize]) * (node_333)))
+                + ((current_base_row[278usize]) * (node_333)))
+                + ((current_base_row[276usize]) * (node_853)))
+                + ((current_base_row[279usize]) * (node_213)))
+                + ((current_base_row[254usize]) * (current_base_row[264usize])))
+                + ((current_base_row[259usize]) * (current_base_row[264usize])))
+                + ((current_base_row[281usize]) * (node_849)))
+                + ((current_base_row[283usize]) * (node_849))) * (node_3765))
+                + ((node_829) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_154)) + ((current_base_row[213usize]) * (node_533)))
+                + ((current_base_row[206usize]) * (node_154)))
+                + ((current_base_row[210usize]) * (node_792)))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[273usize])
+                        * ((next_base_row[30usize]) + (node_525)))))
+                + ((current_base_row[216usize]) * (node_837)))
+                + ((current_base_row[221usize]) * (node_841)))
+                + ((current_base_row[214usize]) * (current_base_row[296usize])))
+                + ((current_base_row[224usize]) * (node_841)))
+                + ((current_base_row[228usize]) * (node_849)))
+                + ((current_base_row[225usize]) * (node_841)))
+                + ((current_base_row[226usize]) * (node_197)))
+                + ((current_base_row[215usize]) * (node_154)))
+                + ((current_base_row[223usize]) * (node_154)))
+                + ((current_base_row[243usize]) * (node_418)))
+                + ((current_base_row[233usize]) * (node_710)))
+                + ((current_base_row[230usize]) * (node_893)))
+                + ((current_base_row[232usize]) * (node_841)))
+                + ((current_base_row[246usize])
+                    * ((next_base_row[24usize]) + (node_228))))
+                + ((current_base_row[249usize])
+                    * ((next_base_row[34usize]) + (node_188))))
+                + ((current_base_row[235usize]) * (node_201)))
+                + ((current_base_row[236usize]) * (node_201)))
+                + ((current_base_row[237usize]) * (node_841)))
+                + ((current_base_row[239usize]) * (node_193)))
+                + ((current_base_row[238usize]) * (node_533)))
+                + ((current_base_row[240usize]) * (node_205)))
+                + ((current_base_row[241usize]) * (node_205)))
+                + ((current_base_row[242usize]) * (node_205)))
+                + ((current_base_row[244usize]) * (node_845)))
+                + ((current_base_row[245usize]) * (node_205)))
+                + ((current_base_row[247usize]) * (node_845)))
+                + ((current_base_row[250usize]) * (node_845)))
+                + ((current_base_row[275usize]) * (node_334)))
+                + ((current_base_row[278usize]) * (node_334)))
+                + ((current_base_row[276usize]) * (node_857)))
+                + ((current_base_row[279usize]) * (node_217)))
+                + ((current_base_row[254usize]) * (node_154)))
+                + ((current_base_row[259usize]) * (node_154)))
+                + ((current_base_row[281usize]) * (node_853)))
+                + ((current_base_row[283usize]) * (node_853))) * (node_3765))
+                + ((node_833) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_437)) + ((current_base_row[213usize]) * (node_534)))
+                + ((current_base_row[206usize]) * (node_730)))
+                + ((current_base_row[210usize]) * (node_794)))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[319usize])
+                        * ((next_base_row[31usize]) + (node_525)))))
+                + ((current_base_row[216usize]) * (node_841)))
+                + ((current_base_row[221usize]) * (node_845)))
+                + ((current_base_row[214usize]) * (node_120)))
+                + ((current_base_row[224usize]) * (node_845)))
+                + ((current_base_row[228usize]) * (node_853)))
+                + ((current_base_row[225usize]) * (node_845)))
+                + ((current_base_row[226usize]) * (node_201)))
+                + ((current_base_row[215usize]) * (node_730)))
+                + ((current_base_row[223usize]) * (node_437)))
+                + ((current_base_row[243usize]) * (node_419)))
+                + ((current_base_row[233usize]) * (node_711)))
+                + ((current_base_row[230usize]) * (node_397)))
+                + ((current_base_row[232usize]) * (node_845)))
+                + ((current_base_row[246usize])
+                    * ((next_base_row[25usize]) + (node_232))))
+                + ((current_base_row[249usize])
+                    * ((next_base_row[35usize]) + (node_192))))
+                + ((current_base_row[235usize]) * (node_205)))
+                + ((current_base_row[236usize]) * (node_205)))
+                + ((current_base_row[237usize]) * (node_845)))
+                + ((current_base_row[239usize]) * (node_197)))
+                + ((current_base_row[238usize]) * (node_534)))
+                + ((current_base_row[240usize]) * (node_209)))
+                + ((current_base_row[241usize]) * (node_209)))
+                + ((current_base_row[242usize]) * (node_209)))
+                + ((current_base_row[244usize]) * (node_849)))
+                + ((current_base_row[245usize]) * (node_209)))
+                + ((current_base_row[247usize]) * (node_849)))
+                + ((current_base_row[250usize]) * (node_849)))
+                + ((current_base_row[275usize]) * (node_335)))
+                + ((current_base_row[278usize]) * (node_335)))
+                + ((current_base_row[276usize]) * (node_861)))
+                + ((current_base_row[279usize]) * (node_221)))
+                + ((current_base_row[254usize]) * (node_730)))
+                + ((current_base_row[259usize]) * (node_437)))
+                + ((current_base_row[281usize]) * (node_857)))
+                + ((current_base_row[283usize]) * (node_857))) * (node_3765))
+                + ((node_837) * (next_base_row[8usize])),
+            (((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_461)) + ((current_base_row[213usize]) * (node_540)))
+                + ((current_base_row[206usize]) * (node_754)))
+                + ((current_base_row[210usize]) * (node_806)))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[344usize])
+                        * ((next_base_row[37usize]) + (node_525)))))
+                + ((current_base_row[216usize]) * (node_865)))
+                + ((current_base_row[221usize]) * (node_869)))
+                + ((current_base_row[214usize]) * (node_197)))
+                + ((current_base_row[224usize]) * (node_869)))
+                + ((current_base_row[228usize]) * (node_877)))
+                + ((current_base_row[225usize]) * (node_869)))
+                + ((current_base_row[226usize]) * (node_225)))
+                + ((current_base_row[215usize]) * (node_750)))
+                + ((current_base_row[223usize]) * (node_457)))
+                + ((current_base_row[233usize]) * (node_124)))
+                + ((current_base_row[230usize]) * (node_413)))
+                + ((current_base_row[232usize]) * (node_869)))
+                + ((current_base_row[235usize]) * (node_229)))
+                + ((current_base_row[236usize]) * (node_229)))
+                + ((current_base_row[237usize]) * (node_869)))
+                + ((current_base_row[239usize]) * (node_221)))
+                + ((current_base_row[238usize]) * (node_540)))
+                + ((current_base_row[240usize]) * (node_233)))
+                + ((current_base_row[241usize]) * (node_233)))
+                + ((current_base_row[242usize]) * (node_233)))
+                + ((current_base_row[244usize]) * (node_873)))
+                + ((current_base_row[245usize]) * (node_233)))
+                + ((current_base_row[247usize]) * (node_873)))
+                + ((current_base_row[250usize]) * (node_873)))
+                + ((current_base_row[275usize]) * (node_120)))
+                + ((current_base_row[278usize]) * (node_120)))
+                + ((current_base_row[276usize]) * (node_885)))
+                + ((current_base_row[279usize]) * (node_159)))
+                + ((current_base_row[254usize]) * (node_754)))
+                + ((current_base_row[259usize]) * (node_461)))
+                + ((current_base_row[281usize]) * (node_881)))
+                + ((current_base_row[283usize]) * (node_881))) * (node_3765))
+                + ((node_861) * (next_base_row[8usize])),
+            ((((((((((((current_base_row[204usize]) * (current_base_row[317usize]))
+                + ((current_base_row[206usize]) * (current_base_row[317usize])))
+                + ((current_base_row[210usize]) * (node_528)))
+                + ((current_base_row[208usize]) * (node_800)))
+                + ((current_base_row[214usize]) * (node_159)))
+                + ((current_base_row[215usize]) * (current_base_row[324usize])))
+                + ((current_base_row[223usize]) * (current_base_row[324usize])))
+                + ((current_base_row[254usize]) * (current_base_row[324usize])))
+                + ((current_base_row[259usize]) * (current_base_row[324usize])))
+                + ((current_base_row[281usize]) * (node_1465)))
+                + ((current_base_row[283usize]) * (node_1465))) * (node_3765),
+            (((((((((current_base_row[204usize]) * (current_base_row[331usize]))
+                + ((current_base_row[206usize]) * (current_base_row[331usize])))
+                + ((current_base_row[210usize]) * (node_533)))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[186usize]))) * (node_833))))
+                + ((current_base_row[215usize]) * (current_base_row[330usize])))
+                + ((current_base_row[223usize]) * (current_base_row[330usize])))
+                + ((current_base_row[254usize]) * (current_base_row[330usize])))
+                + ((current_base_row[259usize]) * (current_base_row[330usize])))
+                * (node_3765),
+            (((((((((current_base_row[204usize]) * (current_base_row[277usize]))
+                + ((current_base_row[206usize]) * (current_base_row[277usize])))
+                + ((current_base_row[210usize]) * (node_534)))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[187usize]))) * (node_837))))
+                + ((current_base_row[215usize]) * (current_base_row[331usize])))
+                + ((current_base_row[223usize]) * (current_base_row[331usize])))
+                + ((current_base_row[254usize]) * (current_base_row[331usize])))
+                + ((current_base_row[259usize]) * (current_base_row[331usize])))
+                * (node_3765),
+            (((((((((current_base_row[204usize]) * (current_base_row[342usize]))
+                + ((current_base_row[206usize]) * (current_base_row[342usize])))
+                + ((current_base_row[210usize]) * (node_535)))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[188usize]))) * (node_841))))
+                + ((current_base_row[215usize]) * (current_base_row[277usize])))
+                + ((current_base_row[223usize]) * (current_base_row[277usize])))
+                + ((current_base_row[254usize]) * (current_base_row[277usize])))
+                + ((current_base_row[259usize]) * (current_base_row[277usize])))
+                * (node_3765),
+            (((((((((current_base_row[204usize]) * (current_base_row[343usize]))
+                + ((current_base_row[206usize]) * (current_base_row[343usize])))
+                + ((current_base_row[210usize]) * (node_536)))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[189usize]))) * (node_845))))
+                + ((current_base_row[215usize]) * (current_base_row[342usize])))
+                + ((current_base_row[223usize]) * (current_base_row[342usize])))
+                + ((current_base_row[254usize]) * (current_base_row[342usize])))
+                + ((current_base_row[259usize]) * (current_base_row[342usize])))
+                * (node_3765),
+            (((((((((current_base_row[204usize]) * (current_base_row[344usize]))
+                + ((current_base_row[206usize]) * (current_base_row[344usize])))
+                + ((current_base_row[210usize]) * (node_537)))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[317usize]))) * (node_849))))
+                + ((current_base_row[215usize]) * (current_base_row[343usize])))
+                + ((current_base_row[223usize]) * (current_base_row[343usize])))
+                + ((current_base_row[254usize]) * (current_base_row[343usize])))
+                + ((current_base_row[259usize]) * (current_base_row[343usize])))
+                * (node_3765),
+            (((current_base_row[210usize]) * (node_541))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[330usize]))) * (node_865))))
+                * (node_3765),
+            ((current_base_row[208usize])
+                * (((BFieldElement::from_raw_u64(4294967295u64))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[344usize]))) * (node_885))) * (node_3765),
+            ((current_base_row[208usize]) * (node_131)) * (node_3765),
+            ((current_base_row[208usize]) * (current_base_row[269usize])) * (node_3765),
+            ((current_base_row[208usize]) * (current_base_row[263usize])) * (node_3765),
+            ((current_base_row[208usize]) * (current_base_row[264usize])) * (node_3765),
+            ((current_base_row[208usize]) * (node_154)) * (node_3765),
+            ((current_base_row[208usize]) * (node_120)) * (node_3765),
+            ((current_base_row[208usize]) * (node_124)) * (node_3765),
+            ((current_base_row[208usize]) * (node_128)) * (node_3765),
+            ((current_base_row[208usize]) * (node_116)) * (node_3765),
+            ((current_base_row[208usize]) * (node_158)) * (node_3765),
+            (node_4156) * (node_4155),
+            ((node_4156)
+                * ((next_base_row[49usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[49usize])))) * (next_base_row[47usize]),
+            ((current_base_row[47usize])
+                * ((current_base_row[47usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                * (node_4162),
+            (((current_base_row[51usize])
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+                * (current_base_row[51usize])) * (node_4225),
+            (current_base_row[54usize]) * (node_4233),
+            (node_4230) * (node_4233),
+            ((node_4233)
+                * ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (next_base_row[51usize])))
+                * ((next_base_row[53usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[53usize]))),
+            (node_4233)
+                * ((next_base_row[55usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[55usize]))),
+            (node_4233)
+                * ((next_base_row[56usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[56usize]))),
+            (node_4337) * (node_4336),
+            (node_4341)
+                * ((next_base_row[60usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[60usize]))),
+            (node_4341)
+                * ((next_base_row[61usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[61usize]))),
+            (((node_4337)
+                * ((node_4349) + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                * ((current_base_row[58usize])
+                    + (BFieldElement::from_raw_u64(18446743927680663586u64))))
+                * (node_4340),
+            ((current_base_row[358usize])
+                * ((current_base_row[64usize])
+                    + (BFieldElement::from_raw_u64(18446744052234715141u64))))
+                * (next_base_row[64usize]),
+            (((next_base_row[62usize]) * (node_5233)) * (node_5235))
+                * (((next_base_row[64usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[64usize])))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            (current_base_row[363usize]) * (node_5263),
+            (node_5265)
+                * ((next_base_row[63usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[63usize]))),
+            (node_5265)
+                * ((next_base_row[62usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[62usize]))),
+            ((current_base_row[359usize]) * (node_5259)) * (next_base_row[62usize]),
+            (current_base_row[364usize]) * (next_base_row[62usize]),
+            ((node_5279) * (node_5251)) * (next_base_row[62usize]),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(263719581847590u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[313usize]))) + (current_base_row[113usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (node_5124))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(4758823762860u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[313usize]))) + (current_base_row[114usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (node_5135))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(123480309731250u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[313usize]))) + (current_base_row[115usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (node_5146))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(145268678818785u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[313usize]))) + (current_base_row[116usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (node_5157))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(32014686216930u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[313usize]))) + (current_base_row[117usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[97usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(185731565704980u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[313usize]))) + (current_base_row[118usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[98usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(231348413345175u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[313usize]))) + (current_base_row[119usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[99usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(51685636428030u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[313usize]))) + (current_base_row[120usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[100usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(244602682417545u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[313usize]))) + (current_base_row[121usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[101usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(118201794925695u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[313usize]))) + (current_base_row[122usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[102usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(177601192615545u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[313usize]))) + (current_base_row[123usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[103usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(175668457332795u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64))
+                        * (current_base_row[313usize]))) + (current_base_row[124usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[104usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(51629801853195u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64))
+                        * (current_base_row[313usize]))) + (current_base_row[125usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[105usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(256362302871255u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64))
+                        * (current_base_row[313usize]))) + (current_base_row[126usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[106usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(115096533571410u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(76643691379275u64))
+                        * (current_base_row[313usize]))) + (current_base_row[127usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[107usize]))),
+            (next_base_row[64usize])
+                * (((((((((((((((((((BFieldElement::from_raw_u64(76643691379275u64))
+                    * (node_4464))
+                    + ((BFieldElement::from_raw_u64(115096533571410u64)) * (node_4475)))
+                    + ((BFieldElement::from_raw_u64(256362302871255u64)) * (node_4486)))
+                    + ((BFieldElement::from_raw_u64(51629801853195u64)) * (node_4497)))
+                    + ((BFieldElement::from_raw_u64(175668457332795u64))
+                        * (current_base_row[302usize])))
+                    + ((BFieldElement::from_raw_u64(177601192615545u64))
+                        * (current_base_row[303usize])))
+                    + ((BFieldElement::from_raw_u64(118201794925695u64))
+                        * (current_base_row[304usize])))
+                    + ((BFieldElement::from_raw_u64(244602682417545u64))
+                        * (current_base_row[305usize])))
+                    + ((BFieldElement::from_raw_u64(51685636428030u64))
+                        * (current_base_row[306usize])))
+                    + ((BFieldElement::from_raw_u64(231348413345175u64))
+                        * (current_base_row[307usize])))
+                    + ((BFieldElement::from_raw_u64(185731565704980u64))
+                        * (current_base_row[308usize])))
+                    + ((BFieldElement::from_raw_u64(32014686216930u64))
+                        * (current_base_row[309usize])))
+                    + ((BFieldElement::from_raw_u64(145268678818785u64))
+                        * (current_base_row[310usize])))
+                    + ((BFieldElement::from_raw_u64(123480309731250u64))
+                        * (current_base_row[311usize])))
+                    + ((BFieldElement::from_raw_u64(4758823762860u64))
+                        * (current_base_row[312usize])))
+                    + ((BFieldElement::from_raw_u64(263719581847590u64))
+                        * (current_base_row[313usize]))) + (current_base_row[128usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[108usize]))),
+            (current_base_row[129usize]) * (node_5770),
+            (current_base_row[135usize]) * (node_5822),
+            ((next_base_row[135usize]) * (next_base_row[136usize]))
+                + ((node_5822)
+                    * (((next_base_row[136usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[136usize])))
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))),
+            ((next_base_row[139usize]) * (current_base_row[143usize])) * (node_5872),
+            (next_base_row[139usize]) * (current_base_row[145usize]),
+            (node_5882)
+                * ((next_base_row[142usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[142usize]))),
+            (((node_5882) * (current_base_row[143usize])) * (node_5872)) * (node_5890),
+            ((node_5882) * (current_base_row[145usize])) * (node_5890),
+            (((node_5882) * (node_5872)) * (node_5875)) * (node_5896),
+            ((node_5882) * (node_5878)) * (node_5899),
+            (((current_base_row[314usize]) * (node_5916)) * (node_5918))
+                * (current_base_row[147usize]),
+            ((node_5921) * (node_5918)) * (node_5923),
+            (((current_base_row[318usize]) * (node_5896)) * (node_5878)) * (node_5923),
+            (((current_base_row[318usize]) * (node_5875)) * (node_5899))
+                * (current_base_row[147usize]),
+            ((current_base_row[361usize])
+                * ((current_base_row[139usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                * ((current_base_row[147usize])
+                    + (BFieldElement::from_raw_u64(18446744060824649731u64))),
+            ((current_base_row[361usize]) * (current_base_row[139usize]))
+                * (current_base_row[147usize]),
+            ((node_5882) * (current_base_row[360usize]))
+                * (((current_base_row[147usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((BFieldElement::from_raw_u64(8589934590u64))
+                            * (next_base_row[147usize]))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((node_5875) * (node_5878)))),
+            (current_base_row[367usize]) * ((current_base_row[147usize]) + (node_5888)),
+            ((current_base_row[347usize]) * (next_base_row[143usize]))
+                * ((next_base_row[147usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[147usize]))),
+            (current_base_row[362usize])
+                * ((next_base_row[143usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[143usize]))),
+            ((current_base_row[362usize]) * (node_5899))
+                * ((current_base_row[147usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (node_5982))),
+            ((current_base_row[362usize]) * (node_5878))
+                * ((current_base_row[147usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[368usize]))),
+            ((node_5882) * ((current_base_row[345usize]) * (node_5909)))
+                * (((current_base_row[147usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[147usize]))) + (node_5932)),
+            (current_base_row[169usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_243) * (node_245))),
+            (current_base_row[170usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_1522) * (node_1505))),
+            (current_base_row[171usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_1522) * (current_base_row[13usize]))),
+            (current_base_row[172usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[12usize]) * (node_1505)) * (node_1495))),
+            (current_base_row[173usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[170usize]) * (node_1495))),
+            (current_base_row[174usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[171usize]) * (node_1495))),
+            (current_base_row[175usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[169usize]) * (current_base_row[40usize]))),
+            (current_base_row[176usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_243) * (current_base_row[41usize]))),
+            (current_base_row[177usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[176usize]) * (node_248))),
+            (current_base_row[178usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[172usize]) * (node_1497))),
+            (current_base_row[179usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[172usize]) * (current_base_row[15usize]))),
+            (current_base_row[180usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[173usize]) * (node_1497))),
+            (current_base_row[181usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[173usize]) * (current_base_row[15usize]))),
+            (current_base_row[182usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[174usize]) * (node_1497))),
+            (current_base_row[183usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[169usize]) * (node_248))),
+            (current_base_row[184usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[174usize]) * (current_base_row[15usize]))),
+            (current_base_row[185usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[183usize]) * (current_base_row[39usize]))),
+            (current_base_row[186usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[175usize]) * (node_295))),
+            (current_base_row[187usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[175usize]) * (current_base_row[39usize]))),
+            (current_base_row[188usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[177usize]) * (node_295))),
+            (current_base_row[189usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[177usize]) * (current_base_row[39usize]))),
+            (current_base_row[190usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[12usize]) * (current_base_row[13usize]))
+                        * (node_1495))),
+            (current_base_row[191usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[179usize]) * (node_1499))),
+            (current_base_row[192usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[178usize]) * (node_1499))),
+            (current_base_row[193usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[180usize]) * (node_1499))),
+            (current_base_row[194usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[181usize]) * (node_1499))),
+            (current_base_row[195usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[171usize]) * (current_base_row[14usize]))),
+            (current_base_row[196usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[182usize]) * (node_1499))),
+            (current_base_row[197usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[184usize]) * (node_1499))),
+            (current_base_row[198usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[170usize]) * (current_base_row[14usize]))),
+            (current_base_row[199usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[190usize]) * (node_1497))),
+            (current_base_row[200usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[178usize]) * (current_base_row[16usize]))),
+            (current_base_row[201usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[181usize]) * (current_base_row[16usize]))),
+            (current_base_row[202usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[180usize]) * (current_base_row[16usize]))),
+            (current_base_row[203usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[182usize]) * (current_base_row[16usize]))),
+            (current_base_row[204usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[199usize]) * (node_1499)) * (node_1501))
+                        * (node_1503))),
+            (current_base_row[205usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[184usize]) * (current_base_row[16usize]))),
+            (current_base_row[206usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[191usize]) * (node_1501)) * (node_1503))),
+            (current_base_row[207usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[196usize]) * (node_1501))),
+            (current_base_row[208usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[179usize]) * (current_base_row[16usize]))
+                        * (node_1501)) * (node_1503))),
+            (current_base_row[209usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[193usize]) * (node_1501))),
+            (current_base_row[210usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[200usize]) * (node_1501)) * (node_1503))),
+            (current_base_row[211usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[194usize]) * (node_1501))),
+            (current_base_row[212usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[197usize]) * (node_1501))),
+            (current_base_row[213usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[192usize]) * (node_1501)) * (node_1503))),
+            (current_base_row[214usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[207usize]) * (node_1503))),
+            (current_base_row[215usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[191usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[216usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[209usize]) * (node_1503))),
+            (current_base_row[217usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[195usize]) * (node_1497))),
+            (current_base_row[218usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[202usize]) * (node_1501))),
+            (current_base_row[219usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[190usize]) * (current_base_row[15usize]))
+                        * (node_1499)) * (node_1501))),
+            (current_base_row[220usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[198usize]) * (node_1497))),
+            (current_base_row[221usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[211usize]) * (node_1503))),
+            (current_base_row[222usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[195usize]) * (current_base_row[15usize]))),
+            (current_base_row[223usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[219usize]) * (node_1503))),
+            (current_base_row[224usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[192usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[225usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[201usize]) * (node_1501)) * (node_1503))),
+            (current_base_row[226usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[212usize]) * (node_1503))),
+            (current_base_row[227usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[198usize]) * (current_base_row[15usize]))),
+            (current_base_row[228usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[218usize]) * (node_1503))),
+            (current_base_row[229usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[203usize]) * (node_1501))),
+            (current_base_row[230usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[205usize]) * (node_1501)) * (node_1503))),
+            (current_base_row[231usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[42usize]) * (node_245))),
+            (current_base_row[232usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[194usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[233usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[193usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[234usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[42usize]) * (current_base_row[41usize]))),
+            (current_base_row[235usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[197usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[236usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[203usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[237usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[201usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[238usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[220usize]) * (node_1499)) * (node_1501))
+                        * (node_1503))),
+            (current_base_row[239usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[205usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[240usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[217usize]) * (node_1499)) * (node_1501))
+                        * (node_1503))),
+            (current_base_row[241usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[222usize]) * (node_1499)) * (node_1501))
+                        * (node_1503))),
+            (current_base_row[242usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[217usize]) * (current_base_row[16usize]))
+                        * (node_1501)) * (node_1503))),
+            (current_base_row[243usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[229usize]) * (node_1503))),
+            (current_base_row[244usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[227usize]) * (node_1499)) * (node_1501))
+                        * (node_1503))),
+            (current_base_row[245usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[222usize]) * (current_base_row[16usize]))
+                        * (node_1501)) * (node_1503))),
+            (current_base_row[246usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[196usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[247usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[220usize]) * (current_base_row[16usize]))
+                        * (node_1501)) * (node_1503))),
+            (current_base_row[248usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[176usize]) * (current_base_row[40usize]))),
+            (current_base_row[249usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[202usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[250usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[227usize]) * (current_base_row[16usize]))
+                        * (node_1501)) * (node_1503))),
+            (current_base_row[251usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[231usize]) * (node_248))),
+            (current_base_row[252usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[97usize]) * (current_base_row[97usize]))
+                        * (current_base_row[97usize])) * (current_base_row[97usize]))),
+            (current_base_row[253usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[98usize]) * (current_base_row[98usize]))
+                        * (current_base_row[98usize])) * (current_base_row[98usize]))),
+            (current_base_row[254usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[200usize]) * (current_base_row[17usize]))
+                        * (node_1503))),
+            (current_base_row[255usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[99usize]) * (current_base_row[99usize]))
+                        * (current_base_row[99usize])) * (current_base_row[99usize]))),
+            (current_base_row[256usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[231usize]) * (current_base_row[40usize]))),
+            (current_base_row[257usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[100usize]) * (current_base_row[100usize]))
+                        * (current_base_row[100usize])) * (current_base_row[100usize]))),
+            (current_base_row[258usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[234usize]) * (node_248))),
+            (current_base_row[259usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[199usize]) * (current_base_row[16usize]))
+                        * (node_1501)) * (node_1503))),
+            (current_base_row[260usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[101usize]) * (current_base_row[101usize]))
+                        * (current_base_row[101usize])) * (current_base_row[101usize]))),
+            (current_base_row[261usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[234usize]) * (current_base_row[40usize]))),
+            (current_base_row[262usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[102usize]) * (current_base_row[102usize]))
+                        * (current_base_row[102usize])) * (current_base_row[102usize]))),
+            (current_base_row[263usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[41usize])
+                        * ((current_base_row[41usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))),
+            (current_base_row[264usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[42usize])
+                        * ((current_base_row[42usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))),
+            (current_base_row[265usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[103usize]) * (current_base_row[103usize]))
+                        * (current_base_row[103usize])) * (current_base_row[103usize]))),
+            (current_base_row[266usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[104usize]) * (current_base_row[104usize]))
+                        * (current_base_row[104usize])) * (current_base_row[104usize]))),
+            (current_base_row[267usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[105usize]) * (current_base_row[105usize]))
+                        * (current_base_row[105usize])) * (current_base_row[105usize]))),
+            (current_base_row[268usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[106usize]) * (current_base_row[106usize]))
+                        * (current_base_row[106usize])) * (current_base_row[106usize]))),
+            (current_base_row[269usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[40usize]) * (node_133))),
+            (current_base_row[270usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[107usize]) * (current_base_row[107usize]))
+                        * (current_base_row[107usize])) * (current_base_row[107usize]))),
+            (current_base_row[271usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[108usize]) * (current_base_row[108usize]))
+                        * (current_base_row[108usize])) * (current_base_row[108usize]))),
+            (current_base_row[272usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[248usize]) * (current_base_row[39usize]))),
+            (current_base_row[273usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[251usize]) * (node_295))),
+            (current_base_row[274usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[39usize]) * (current_base_row[22usize]))),
+            (current_base_row[275usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[207usize]) * (current_base_row[18usize]))),
+            (current_base_row[276usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[209usize]) * (current_base_row[18usize]))),
+            (current_base_row[277usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[258usize]) * (node_295))),
+            (current_base_row[278usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[212usize]) * (current_base_row[18usize]))),
+            (current_base_row[279usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[229usize]) * (current_base_row[18usize]))),
+            (current_base_row[280usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((next_base_row[64usize]) * (node_5308)) * (node_5309))
+                        * (node_5311))),
+            (current_base_row[281usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[211usize]) * (current_base_row[18usize]))),
+            (current_base_row[282usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((next_base_row[62usize]) * (node_5315)) * (node_5263))),
+            (current_base_row[283usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[218usize]) * (current_base_row[18usize]))),
+            (current_base_row[284usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[272usize])
+                        * ((next_base_row[22usize]) + (node_208)))),
+            (current_base_row[285usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[185usize]) * (node_527))),
+            (current_base_row[286usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[186usize])
+                        * ((next_base_row[25usize]) + (node_184)))),
+            (current_base_row[287usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[187usize])
+                        * ((next_base_row[26usize]) + (node_184)))),
+            (current_base_row[288usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[188usize])
+                        * ((next_base_row[27usize]) + (node_184)))),
+            (current_base_row[289usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[189usize]) * (node_704))),
+            (current_base_row[290usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[185usize]) * (node_189))),
+            (current_base_row[291usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[186usize])
+                        * ((next_base_row[23usize]) + (node_192)))),
+            (current_base_row[292usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[187usize])
+                        * ((next_base_row[23usize]) + (node_196)))),
+            (current_base_row[293usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[188usize])
+                        * ((next_base_row[23usize]) + (node_200)))),
+            (current_base_row[294usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[189usize]) * (node_410))),
+            (current_base_row[295usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[43usize])
+                        * ((current_base_row[43usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                        * ((current_base_row[43usize])
+                            + (BFieldElement::from_raw_u64(18446744060824649731u64))))
+                        * ((current_base_row[43usize])
+                            + (BFieldElement::from_raw_u64(18446744056529682436u64))))),
+            (current_base_row[296usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[44usize])
+                        * ((current_base_row[44usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                        * ((current_base_row[44usize])
+                            + (BFieldElement::from_raw_u64(18446744060824649731u64))))
+                        * ((current_base_row[44usize])
+                            + (BFieldElement::from_raw_u64(18446744056529682436u64))))),
+            (current_base_row[297usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[189usize]) * (node_409))),
+            (current_base_row[298usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[189usize]) * (node_703))),
+            (current_base_row[299usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((node_5901) * (node_5903)) * (node_5907)) * (node_5909))),
+            (current_base_row[300usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_5901)
+                        * ((next_base_row[142usize])
+                            + (BFieldElement::from_raw_u64(18446744043644780551u64))))),
+            (current_base_row[301usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((node_5308) * (node_5309)) * (node_5311)) * (node_5313))),
+            (current_base_row[302usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[252usize]) * (current_base_row[97usize]))
+                        * (current_base_row[97usize])) * (current_base_row[97usize]))),
+            (current_base_row[303usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[253usize]) * (current_base_row[98usize]))
+                        * (current_base_row[98usize])) * (current_base_row[98usize]))),
+            (current_base_row[304usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[255usize]) * (current_base_row[99usize]))
+                        * (current_base_row[99usize])) * (current_base_row[99usize]))),
+            (current_base_row[305usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[257usize]) * (current_base_row[100usize]))
+                        * (current_base_row[100usize])) * (current_base_row[100usize]))),
+            (current_base_row[306usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[260usize]) * (current_base_row[101usize]))
+                        * (current_base_row[101usize])) * (current_base_row[101usize]))),
+            (current_base_row[307usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[262usize]) * (current_base_row[102usize]))
+                        * (current_base_row[102usize])) * (current_base_row[102usize]))),
+            (current_base_row[308usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[265usize]) * (current_base_row[103usize]))
+                        * (current_base_row[103usize])) * (current_base_row[103usize]))),
+            (current_base_row[309usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[266usize]) * (current_base_row[104usize]))
+                        * (current_base_row[104usize])) * (current_base_row[104usize]))),
+            (current_base_row[310usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[267usize]) * (current_base_row[105usize]))
+                        * (current_base_row[105usize])) * (current_base_row[105usize]))),
+            (current_base_row[311usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[268usize]) * (current_base_row[106usize]))
+                        * (current_base_row[106usize])) * (current_base_row[106usize]))),
+            (current_base_row[312usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[270usize]) * (current_base_row[107usize]))
+                        * (current_base_row[107usize])) * (current_base_row[107usize]))),
+            (current_base_row[313usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[271usize]) * (current_base_row[108usize]))
+                        * (current_base_row[108usize])) * (current_base_row[108usize]))),
+            (current_base_row[314usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_5882) * ((current_base_row[299usize]) * (node_5913)))),
+            (current_base_row[315usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[300usize]) * (node_5903))),
+            (current_base_row[316usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[301usize]) * (node_5315))),
+            (current_base_row[317usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[248usize]) * (node_295))),
+            (current_base_row[318usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_5921) * (node_5916))),
+            (current_base_row[319usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[251usize]) * (current_base_row[39usize]))),
+            (current_base_row[320usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[185usize]) * (node_229))),
+            (current_base_row[321usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[186usize])
+                        * ((next_base_row[33usize]) + (node_232)))),
+            (current_base_row[322usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[187usize]) * (node_337))),
+            (current_base_row[323usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[188usize])
+                        * ((next_base_row[33usize]) + (node_240)))),
+            (current_base_row[324usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[183usize]) * (node_295))),
+            (current_base_row[325usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[185usize]) * (node_537))),
+            (current_base_row[326usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[186usize])
+                        * ((next_base_row[35usize]) + (node_224)))),
+            (current_base_row[327usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[187usize])
+                        * ((next_base_row[36usize]) + (node_224)))),
+            (current_base_row[328usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[188usize])
+                        * ((next_base_row[37usize]) + (node_224)))),
+            (current_base_row[329usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((node_5254) * (node_5275)) * (next_base_row[62usize]))),
+            (current_base_row[330usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[256usize]) * (node_295))),
+            (current_base_row[331usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[256usize]) * (current_base_row[39usize]))),
+            (current_base_row[332usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[185usize]) * (node_193))),
+            (current_base_row[333usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[185usize]) * (node_197))),
+            (current_base_row[334usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[186usize])
+                        * ((next_base_row[24usize]) + (node_196)))),
+            (current_base_row[335usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[186usize])
+                        * ((next_base_row[25usize]) + (node_200)))),
+            (current_base_row[336usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[187usize])
+                        * ((next_base_row[24usize]) + (node_200)))),
+            (current_base_row[337usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[187usize]) * (node_329))),
+            (current_base_row[338usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[188usize])
+                        * ((next_base_row[24usize]) + (node_204)))),
+            (current_base_row[339usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[188usize])
+                        * ((next_base_row[25usize]) + (node_208)))),
+            (current_base_row[340usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[189usize]) * (node_411))),
+            (current_base_row[341usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[189usize]) * (node_412))),
+            (current_base_row[342usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[258usize]) * (current_base_row[39usize]))),
+            (current_base_row[343usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[261usize]) * (node_295))),
+            (current_base_row[344usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[261usize]) * (current_base_row[39usize]))),
+            (current_base_row[345usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[315usize]) * (node_5907))),
+            (current_base_row[346usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((((next_base_row[12usize])
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+                        * (next_base_row[13usize]))
+                        * ((next_base_row[14usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                        * ((next_base_row[15usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))),
+            (current_base_row[347usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_5882)
+                        * (((current_base_row[315usize]) * (node_5909)) * (node_5913)))),
+            (current_base_row[348usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[22usize]) * (current_base_row[23usize]))),
+            (current_base_row[349usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((next_base_row[22usize]) * (current_base_row[22usize]))),
+            (current_base_row[350usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[39usize]) * (node_1243))),
+            (current_base_row[351usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[316usize])
+                        * (((node_5275) * (node_5259)) * (next_base_row[62usize])))),
+            (current_base_row[352usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((node_5250) * (node_5251)) * (current_base_row[62usize]))),
+            (current_base_row[353usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[238usize])
+                        * ((next_base_row[22usize])
+                            * (((current_base_row[39usize])
+                                * ((next_base_row[23usize])
+                                    + (BFieldElement::from_raw_u64(4294967296u64))))
+                                + (BFieldElement::from_raw_u64(
+                                    18446744065119617026u64,
+                                )))))),
+            (current_base_row[354usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[214usize])
+                        * ((((node_893) * (current_base_row[22usize]))
+                            + (((node_116) * (node_895)) * (node_133)))
+                            + ((((node_113)
+                                + (BFieldElement::from_raw_u64(18446744056529682436u64)))
+                                * (node_895)) * (current_base_row[40usize]))))),
+            (current_base_row[355usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[214usize])
+                        * (((current_base_row[263usize])
+                            * ((current_base_row[41usize])
+                                + (BFieldElement::from_raw_u64(18446744060824649731u64))))
+                            * ((current_base_row[41usize])
+                                + (BFieldElement::from_raw_u64(
+                                    18446744056529682436u64,
+                                )))))),
+            (current_base_row[356usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[214usize])
+                        * (((current_base_row[264usize])
+                            * ((current_base_row[42usize])
+                                + (BFieldElement::from_raw_u64(18446744060824649731u64))))
+                            * ((current_base_row[42usize])
+                                + (BFieldElement::from_raw_u64(
+                                    18446744056529682436u64,
+                                )))))),
+            (current_base_row[357usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[346usize]) * (next_base_row[16usize]))
+                        * ((next_base_row[17usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                        * ((next_base_row[18usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))),
+            (current_base_row[358usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[64usize])
+                        * ((current_base_row[64usize])
+                            + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                        * ((current_base_row[64usize])
+                            + (BFieldElement::from_raw_u64(18446744060824649731u64))))
+                        * ((current_base_row[64usize])
+                            + (BFieldElement::from_raw_u64(18446744056529682436u64))))),
+            (current_base_row[359usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((node_5272) * (node_5251)) * (current_base_row[62usize]))
+                        * (node_5275))),
+            (current_base_row[360usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[300usize]) * (node_5907)) * (node_5909))
+                        * (node_5913))),
+            (current_base_row[361usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[318usize])
+                        * ((((BFieldElement::from_raw_u64(4294967295u64)) + (node_5932))
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (node_5878)))
+                            + (((BFieldElement::from_raw_u64(8589934590u64))
+                                * (node_5875)) * (node_5878))))),
+            (current_base_row[362usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_5882) * ((current_base_row[345usize]) * (node_5913)))),
+            (current_base_row[363usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[352usize])
+                        * (((node_5254) * (node_5259)) * (next_base_row[62usize])))),
+            (current_base_row[364usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((node_5279) * (current_base_row[62usize])) * (node_5259))),
+            (current_base_row[365usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_base_row[316usize]) * (node_5259))
+                        * (next_base_row[62usize])) * (node_5263))),
+            (current_base_row[366usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[316usize])
+                        * (((node_5366) * (node_5263)) * (node_5368)))),
+            (current_base_row[367usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (((current_base_row[347usize])
+                        * ((BFieldElement::from_raw_u64(4294967295u64))
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((next_base_row[143usize]) * (next_base_row[144usize])))))
+                        * (current_base_row[143usize]))),
+            (current_base_row[368usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_5982) * (current_base_row[143usize]))),
+        ];
+        let ext_constraints = [
+            (((BFieldElement::from_raw_u64(4294967295u64))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (current_base_row[5usize])))
+                * (((node_51)
+                    * ((challenges[3usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((((challenges[13usize]) * (current_base_row[0usize]))
+                                + ((challenges[14usize]) * (current_base_row[1usize])))
+                                + ((challenges[15usize]) * (next_base_row[1usize]))))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[2usize]))))
+                + ((current_base_row[5usize]) * (node_51)),
+            ((node_31)
+                * (((next_ext_row[1usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((challenges[29usize]) * (current_ext_row[1usize]))))
+                    + (node_74)))
+                + ((node_34)
+                    * (((next_ext_row[1usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (challenges[29usize]))) + (node_74))),
+            ((((((next_ext_row[2usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((challenges[30usize]) * (current_ext_row[2usize]))))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (next_ext_row[1usize]))) * (node_47))
+                * ((BFieldElement::from_raw_u64(4294967295u64))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((next_base_row[4usize]) * (node_90)))))
+                + ((node_88) * (next_base_row[6usize]))) + ((node_88) * (node_90)),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_131)) + ((current_base_row[213usize]) * (node_529)))
+                + ((current_base_row[206usize]) * (node_131)))
+                + ((current_base_row[210usize]) * (node_384)))
+                + ((current_base_row[208usize]) * (node_678)))
+                + ((current_base_row[216usize]) * (node_893)))
+                + ((current_base_row[221usize]) * (node_785)))
+                + ((current_base_row[214usize]) * (current_base_row[269usize])))
+                + ((current_base_row[224usize]) * (node_785)))
+                + ((current_base_row[228usize]) * (node_833)))
+                + ((current_base_row[225usize]) * (node_785)))
+                + ((current_base_row[226usize]) * (node_893)))
+                + ((current_base_row[215usize]) * (node_131)))
+                + ((current_base_row[223usize]) * (node_131)))
+                + ((current_base_row[243usize]) * (node_414)))
+                + ((current_base_row[233usize])
+                    * (((node_295) * (node_833))
+                        + ((current_base_row[39usize]) * (node_705)))))
+                + ((current_base_row[230usize])
+                    * ((current_base_row[31usize]) + (node_196))))
+                + ((current_base_row[232usize]) * (node_785)))
+                + ((current_base_row[246usize])
+                    * ((node_158) + (BFieldElement::from_raw_u64(42949672950u64)))))
+                + ((current_base_row[249usize])
+                    * ((node_158)
+                        + (BFieldElement::from_raw_u64(18446744026464911371u64)))))
+                + ((current_base_row[235usize]) * (node_893)))
+                + ((current_base_row[236usize]) * (node_893)))
+                + ((current_base_row[237usize]) * (node_893)))
+                + ((current_base_row[239usize]) * (node_124)))
+                + ((current_base_row[238usize]) * (node_529)))
+                + ((current_base_row[240usize]) * (node_189)))
+                + ((current_base_row[241usize]) * (node_189)))
+                + ((current_base_row[242usize]) * (node_189)))
+                + ((current_base_row[244usize]) * (node_829)))
+                + ((current_base_row[245usize]) * (node_189)))
+                + ((current_base_row[247usize]) * (node_893)))
+                + ((current_base_row[250usize]) * (node_829)))
+                + ((current_base_row[275usize]) * (node_330)))
+                + ((current_base_row[278usize]) * (node_330)))
+                + ((current_base_row[276usize]) * (node_841)))
+                + ((current_base_row[279usize]) * (node_201)))
+                + ((current_base_row[254usize]) * (node_131)))
+                + ((current_base_row[259usize]) * (node_131)))
+                + ((current_base_row[281usize]) * (node_517)))
+                + ((current_base_row[283usize]) * (node_517))) * (node_3765))
+                + ((node_124) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (current_base_row[269usize]))
+                + ((current_base_row[213usize]) * (node_530)))
+                + ((current_base_row[206usize]) * (current_base_row[269usize])))
+                + ((current_base_row[210usize]) * (current_base_row[297usize])))
+                + ((current_base_row[208usize]) * (current_base_row[298usize])))
+                + ((current_base_row[216usize]) * (node_785)))
+                + ((current_base_row[221usize]) * (node_829)))
+                + (current_base_row[355usize]))
+                + ((current_base_row[224usize]) * (node_829)))
+                + ((current_base_row[228usize]) * (node_837)))
+                + ((current_base_row[225usize]) * (node_829)))
+                + ((current_base_row[226usize]) * (node_185)))
+                + ((current_base_row[215usize]) * (current_base_row[269usize])))
+                + ((current_base_row[223usize]) * (current_base_row[269usize])))
+                + ((current_base_row[243usize]) * (node_415)))
+                + ((current_base_row[233usize])
+                    * (((node_295) * (node_837))
+                        + ((current_base_row[39usize]) * (node_706)))))
+                + ((current_base_row[230usize]) * (node_120)))
+                + ((current_base_row[232usize]) * (node_829)))
+                + ((current_base_row[246usize])
+                    * ((next_ext_row[6usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_ext_row[69usize])))))
+                + ((current_base_row[249usize])
+                    * ((next_ext_row[6usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_ext_row[70usize])))))
+                + ((current_base_row[235usize]) * (node_189)))
+                + ((current_base_row[236usize]) * (node_189)))
+                + ((current_base_row[237usize]) * (node_829)))
+                + ((current_base_row[239usize]) * (node_128)))
+                + ((current_base_row[238usize]) * (node_530)))
+                + ((current_base_row[240usize]) * (node_193)))
+                + ((current_base_row[241usize]) * (node_193)))
+                + ((current_base_row[242usize]) * (node_193)))
+                + ((current_base_row[244usize]) * (node_833)))
+                + ((current_base_row[245usize]) * (node_193)))
+                + ((current_base_row[247usize]) * (node_833)))
+                + ((current_base_row[250usize]) * (node_833)))
+                + ((current_base_row[275usize]) * (node_331)))
+                + ((current_base_row[278usize]) * (node_331)))
+                + ((current_base_row[276usize]) * (node_845)))
+                + ((current_base_row[279usize]) * (node_205)))
+                + ((current_base_row[254usize]) * (current_base_row[269usize])))
+                + ((current_base_row[259usize]) * (current_base_row[269usize])))
+                + ((current_base_row[281usize]) * (node_521)))
+                + ((current_base_row[283usize]) * (node_521))) * (node_3765))
+                + ((node_128) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_441)) + ((current_base_row[213usize]) * (node_535)))
+                + ((current_base_row[206usize]) * (node_734)))
+                + ((current_base_row[210usize]) * (node_796)))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[330usize]) * (node_815))))
+                + ((current_base_row[216usize]) * (node_845)))
+                + ((current_base_row[221usize]) * (node_849)))
+                + ((current_base_row[214usize]) * (node_124)))
+                + ((current_base_row[224usize]) * (node_849)))
+                + ((current_base_row[228usize]) * (node_857)))
+                + ((current_base_row[225usize]) * (node_849)))
+                + ((current_base_row[226usize]) * (node_205)))
+                + ((current_base_row[215usize])
+                    * ((((((current_base_row[185usize])
+                        * ((node_785) + (BFieldElement::from_raw_u64(4294967295u64))))
+                        + ((current_base_row[186usize])
+                            * ((node_785)
+                                + (BFieldElement::from_raw_u64(8589934590u64)))))
+                        + ((current_base_row[187usize])
+                            * ((node_785)
+                                + (BFieldElement::from_raw_u64(12884901885u64)))))
+                        + ((current_base_row[188usize])
+                            * ((node_785)
+                                + (BFieldElement::from_raw_u64(17179869180u64)))))
+                        + ((current_base_row[189usize])
+                            * ((node_785)
+                                + (BFieldElement::from_raw_u64(21474836475u64)))))))
+                + ((current_base_row[223usize])
+                    * ((((((current_base_row[185usize]) * (node_1036))
+                        + ((current_base_row[186usize])
+                            * ((node_785)
+                                + (BFieldElement::from_raw_u64(18446744060824649731u64)))))
+                        + ((current_base_row[187usize]) * (node_1062)))
+                        + ((current_base_row[188usize])
+                            * ((node_785)
+                                + (BFieldElement::from_raw_u64(18446744052234715141u64)))))
+                        + ((current_base_row[189usize])
+                            * ((node_785)
+                                + (BFieldElement::from_raw_u64(
+                                    18446744047939747846u64,
+                                ))))))) + ((current_base_row[243usize]) * (node_397)))
+                + ((current_base_row[233usize]) * (node_712)))
+                + ((current_base_row[230usize]) * (node_408)))
+                + ((current_base_row[232usize]) * (node_849)))
+                + ((current_base_row[246usize])
+                    * ((next_base_row[26usize]) + (node_236))))
+                + ((current_base_row[249usize])
+                    * ((next_base_row[36usize]) + (node_196))))
+                + ((current_base_row[235usize]) * (node_209)))
+                + ((current_base_row[236usize]) * (node_209)))
+                + ((current_base_row[237usize]) * (node_849)))
+                + ((current_base_row[239usize]) * (node_201)))
+                + ((current_base_row[238usize]) * (node_535)))
+                + ((current_base_row[240usize]) * (node_213)))
+                + ((current_base_row[241usize]) * (node_213)))
+                + ((current_base_row[242usize]) * (node_213)))
+                + ((current_base_row[244usize]) * (node_853)))
+                + ((current_base_row[245usize]) * (node_213)))
+                + ((current_base_row[247usize]) * (node_853)))
+                + ((current_base_row[250usize]) * (node_853)))
+                + ((current_base_row[275usize]) * (node_336)))
+                + ((current_base_row[278usize]) * (node_336)))
+                + ((current_base_row[276usize]) * (node_865)))
+                + ((current_base_row[279usize]) * (node_225)))
+                + ((current_base_row[254usize]) * (node_734)))
+                + ((current_base_row[259usize]) * (node_441)))
+                + ((current_base_row[281usize]) * (node_861)))
+                + ((current_base_row[283usize]) * (node_861))) * (node_3765))
+                + ((node_841) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_445)) + ((current_base_row[213usize]) * (node_536)))
+                + ((current_base_row[206usize]) * (node_738)))
+                + ((current_base_row[210usize]) * (node_798)))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[331usize])
+                        * ((next_base_row[33usize]) + (node_525)))))
+                + ((current_base_row[216usize]) * (node_849)))
+                + ((current_base_row[221usize]) * (node_853)))
+                + ((current_base_row[214usize]) * (node_128)))
+                + ((current_base_row[224usize]) * (node_853)))
+                + ((current_base_row[228usize]) * (node_861)))
+                + ((current_base_row[225usize]) * (node_853)))
+                + ((current_base_row[226usize]) * (node_209)))
+                + ((current_base_row[215usize]) * (node_734)))
+                + ((current_base_row[223usize]) * (node_441)))
+                + ((current_base_row[243usize]) * (node_408)))
+                + ((current_base_row[233usize]) * (node_713)))
+                + ((current_base_row[230usize]) * (node_409)))
+                + ((current_base_row[232usize]) * (node_853)))
+                + ((current_base_row[246usize])
+                    * ((next_base_row[27usize]) + (node_240))))
+                + ((current_base_row[249usize])
+                    * ((next_base_row[37usize]) + (node_200))))
+                + ((current_base_row[235usize]) * (node_213)))
+                + ((current_base_row[236usize]) * (node_213)))
+                + ((current_base_row[237usize]) * (node_853)))
+                + ((current_base_row[239usize]) * (node_205)))
+                + ((current_base_row[238usize]) * (node_536)))
+                + ((current_base_row[240usize]) * (node_217)))
+                + ((current_base_row[241usize]) * (node_217)))
+                + ((current_base_row[242usize]) * (node_217)))
+                + ((current_base_row[244usize]) * (node_857)))
+                + ((current_base_row[245usize]) * (node_217)))
+                + ((current_base_row[247usize]) * (node_857)))
+                + ((current_base_row[250usize]) * (node_857)))
+                + ((current_base_row[275usize]) * (node_337)))
+                + ((current_base_row[278usize]) * (node_337)))
+                + ((current_base_row[276usize]) * (node_869)))
+                + ((current_base_row[279usize]) * (node_229)))
+                + ((current_base_row[254usize]) * (node_738)))
+                + ((current_base_row[259usize]) * (node_445)))
+                + ((current_base_row[281usize]) * (node_865)))
+                + ((current_base_row[283usize]) * (node_865))) * (node_3765))
+                + ((node_845) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_449)) + ((current_base_row[213usize]) * (node_537)))
+                + ((current_base_row[206usize]) * (node_742)))
+                + ((current_base_row[210usize]) * (node_800)))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[277usize])
+                        * ((next_base_row[34usize]) + (node_525)))))
+                + ((current_base_row[216usize]) * (node_853)))
+                + ((current_base_row[221usize]) * (node_857)))
+                + ((current_base_row[214usize]) * (node_185)))
+                + ((current_base_row[224usize]) * (node_857)))
+                + ((current_base_row[228usize]) * (node_865)))
+                + ((current_base_row[225usize]) * (node_857)))
+                + ((current_base_row[226usize]) * (node_213)))
+                + (current_ext_row[84usize])) + (current_ext_row[85usize]))
+                + ((current_base_row[243usize]) * (node_513)))
+                + ((current_base_row[233usize]) * (node_691)))
+                + ((current_base_row[230usize]) * (node_410)))
+                + ((current_base_row[232usize]) * (node_857)))
+                + ((current_base_row[246usize]) * (node_513)))
+                + ((current_base_row[249usize]) * (node_513)))
+                + ((current_base_row[235usize]) * (node_217)))
+                + ((current_base_row[236usize]) * (node_217)))
+                + ((current_base_row[237usize]) * (node_857)))
+                + ((current_base_row[239usize]) * (node_209)))
+                + ((current_base_row[238usize]) * (node_537)))
+                + ((current_base_row[240usize]) * (node_221)))
+                + ((current_base_row[241usize]) * (node_221)))
+                + ((current_base_row[242usize]) * (node_221)))
+                + ((current_base_row[244usize]) * (node_861)))
+                + ((current_base_row[245usize]) * (node_221)))
+                + ((current_base_row[247usize]) * (node_861)))
+                + ((current_base_row[250usize]) * (node_861)))
+                + ((current_base_row[275usize]) * (node_338)))
+                + ((current_base_row[278usize]) * (node_338)))
+                + ((current_base_row[276usize]) * (node_873)))
+                + ((current_base_row[279usize]) * (node_233)))
+                + ((current_base_row[254usize]) * (node_742)))
+                + ((current_base_row[259usize]) * (node_449)))
+                + ((current_base_row[281usize]) * (node_869)))
+                + ((current_base_row[283usize]) * (node_869))) * (node_3765))
+                + ((node_849) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_453)) + ((current_base_row[213usize]) * (node_538)))
+                + ((current_base_row[206usize]) * (node_746)))
+                + ((current_base_row[210usize]) * (node_802)))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[342usize])
+                        * ((next_base_row[35usize]) + (node_525)))))
+                + ((current_base_row[216usize]) * (node_857)))
+                + ((current_base_row[221usize]) * (node_861)))
+                + ((current_base_row[214usize]) * (node_189)))
+                + ((current_base_row[224usize]) * (node_861)))
+                + ((current_base_row[228usize]) * (node_869)))
+                + ((current_base_row[225usize]) * (node_861)))
+                + ((current_base_row[226usize]) * (node_217)))
+                + ((current_base_row[215usize]) * (node_742)))
+                + ((current_base_row[223usize]) * (node_449)))
+                + ((current_base_row[243usize]) * (node_517)))
+                + ((current_base_row[233usize]) * (node_702)))
+                + ((current_base_row[230usize]) * (node_411)))
+                + ((current_base_row[232usize]) * (node_861)))
+                + ((current_base_row[246usize]) * (node_517)))
+                + ((current_base_row[249usize]) * (node_517)))
+                + ((current_base_row[235usize]) * (node_221)))
+                + ((current_base_row[236usize]) * (node_221)))
+                + ((current_base_row[237usize]) * (node_861)))
+                + ((current_base_row[239usize]) * (node_213)))
+                + ((current_base_row[238usize]) * (node_538)))
+                + ((current_base_row[240usize]) * (node_225)))
+                + ((current_base_row[241usize]) * (node_225)))
+                + ((current_base_row[242usize]) * (node_225)))
+                + ((current_base_row[244usize]) * (node_865)))
+                + ((current_base_row[245usize]) * (node_225)))
+                + ((current_base_row[247usize]) * (node_865)))
+                + ((current_base_row[250usize]) * (node_865)))
+                + ((current_base_row[275usize]) * (node_314)))
+                + ((current_base_row[278usize]) * (node_314)))
+                + ((current_base_row[276usize]) * (node_877)))
+                + ((current_base_row[279usize]) * (node_237)))
+                + ((current_base_row[254usize]) * (node_746)))
+                + ((current_base_row[259usize]) * (node_453)))
+                + ((current_base_row[281usize]) * (node_873)))
+                + ((current_base_row[283usize]) * (node_873))) * (node_3765))
+                + ((node_853) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_457)) + ((current_base_row[213usize]) * (node_539)))
+                + ((current_base_row[206usize]) * (node_750)))
+                + ((current_base_row[210usize]) * (node_804)))
+                + ((current_base_row[208usize])
+                    * ((current_base_row[343usize])
+                        * ((next_base_row[36usize]) + (node_525)))))
+                + ((current_base_row[216usize]) * (node_861)))
+                + ((current_base_row[221usize]) * (node_865)))
+                + ((current_base_row[214usize]) * (node_193)))
+                + ((current_base_row[224usize]) * (node_865)))
+                + ((current_base_row[228usize]) * (node_873)))
+                + ((current_base_row[225usize]) * (node_865)))
+                + ((current_base_row[226usize]) * (node_221)))
+                + ((current_base_row[215usize]) * (node_746)))
+                + ((current_base_row[223usize]) * (node_453)))
+                + ((current_base_row[243usize]) * (node_521)))
+                + ((current_base_row[233usize]) * (node_120)))
+                + ((current_base_row[230usize]) * (node_412)))
+                + ((current_base_row[232usize]) * (node_865)))
+                + ((current_base_row[246usize]) * (node_521)))
+                + ((current_base_row[249usize]) * (node_521)))
+                + ((current_base_row[235usize]) * (node_225)))
+                + ((current_base_row[236usize]) * (node_225)))
+                + ((current_base_row[237usize]) * (node_865)))
+                + ((current_base_row[239usize]) * (node_217)))
+                + ((current_base_row[238usize]) * (node_539)))
+                + ((current_base_row[240usize]) * (node_229)))
+                + ((current_base_row[241usize]) * (node_229)))
+                + ((current_base_row[242usize]) * (node_229)))
+                + ((current_base_row[244usize]) * (node_869)))
+                + ((current_base_row[245usize]) * (node_229)))
+                + ((current_base_row[247usize]) * (node_869)))
+                + ((current_base_row[250usize]) * (node_869)))
+                + ((current_base_row[275usize]) * (node_325)))
+                + ((current_base_row[278usize]) * (node_325)))
+                + ((current_base_row[276usize]) * (node_881)))
+                + ((current_base_row[279usize]) * (node_241)))
+                + ((current_base_row[254usize]) * (node_750)))
+                + ((current_base_row[259usize]) * (node_457)))
+                + ((current_base_row[281usize]) * (node_877)))
+                + ((current_base_row[283usize]) * (node_877))) * (node_3765))
+                + ((node_857) * (next_base_row[8usize])),
+            (((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_465)) + ((current_base_row[213usize]) * (node_541)))
+                + ((current_base_row[206usize]) * (node_758)))
+                + ((current_base_row[210usize]) * (node_131)))
+                + ((current_base_row[208usize]) * (node_253)))
+                + ((current_base_row[216usize]) * (node_869)))
+                + ((current_base_row[221usize]) * (node_873)))
+                + ((current_base_row[214usize]) * (node_201)))
+                + ((current_base_row[224usize]) * (node_873)))
+                + ((current_base_row[228usize]) * (node_881)))
+                + ((current_base_row[225usize]) * (node_873)))
+                + ((current_base_row[226usize]) * (node_229)))
+                + ((current_base_row[215usize]) * (node_754)))
+                + ((current_base_row[223usize]) * (node_461)))
+                + ((current_base_row[233usize]) * (node_128)))
+                + ((current_base_row[230usize]) * (node_414)))
+                + ((current_base_row[232usize]) * (node_873)))
+                + ((current_base_row[235usize]) * (node_233)))
+                + ((current_base_row[236usize]) * (node_233)))
+                + ((current_base_row[237usize]) * (node_873)))
+                + ((current_base_row[239usize]) * (node_225)))
+                + ((current_base_row[238usize]) * (node_541)))
+                + ((current_base_row[240usize]) * (node_237)))
+                + ((current_base_row[241usize]) * (node_237)))
+                + ((current_base_row[242usize]) * (node_237)))
+                + ((current_base_row[244usize]) * (node_877)))
+                + ((current_base_row[245usize]) * (node_237)))
+                + ((current_base_row[247usize]) * (node_877)))
+                + ((current_base_row[250usize]) * (node_877)))
+                + ((current_base_row[275usize]) * (node_124)))
+                + ((current_base_row[278usize]) * (node_124)))
+                + ((current_base_row[276usize]) * (node_888)))
+                + ((current_base_row[279usize]) * (node_181)))
+                + ((current_base_row[254usize]) * (node_758)))
+                + ((current_base_row[259usize]) * (node_465)))
+                + ((current_base_row[281usize]) * (node_885)))
+                + ((current_base_row[283usize]) * (node_885))) * (node_3765))
+                + ((node_865) * (next_base_row[8usize])),
+            (((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_469)) + ((current_base_row[213usize]) * (node_550)))
+                + ((current_base_row[206usize]) * (node_762)))
+                + ((current_base_row[210usize]) * (current_base_row[269usize])))
+                + ((current_base_row[208usize]) * (node_299)))
+                + ((current_base_row[216usize]) * (node_873)))
+                + ((current_base_row[221usize]) * (node_877)))
+                + ((current_base_row[214usize]) * (node_205)))
+                + ((current_base_row[224usize]) * (node_877)))
+                + ((current_base_row[228usize]) * (node_885)))
+                + ((current_base_row[225usize]) * (node_877)))
+                + ((current_base_row[226usize]) * (node_233)))
+                + ((current_base_row[215usize]) * (node_758)))
+                + ((current_base_row[223usize]) * (node_465)))
+                + ((current_base_row[233usize]) * (node_893)))
+                + ((current_base_row[230usize]) * (node_415)))
+                + ((current_base_row[232usize]) * (node_877)))
+                + ((current_base_row[235usize]) * (node_237)))
+                + ((current_base_row[236usize]) * (node_237)))
+                + ((current_base_row[237usize]) * (node_877)))
+                + ((current_base_row[239usize]) * (node_229)))
+                + ((current_base_row[238usize]) * (node_550)))
+                + ((current_base_row[240usize]) * (node_241)))
+                + ((current_base_row[241usize]) * (node_241)))
+                + ((current_base_row[242usize]) * (node_241)))
+                + ((current_base_row[244usize]) * (node_881)))
+                + ((current_base_row[245usize]) * (node_241)))
+                + ((current_base_row[247usize]) * (node_881)))
+                + ((current_base_row[250usize]) * (node_881)))
+                + ((current_base_row[275usize]) * (node_128)))
+                + ((current_base_row[278usize]) * (node_128)))
+                + ((current_base_row[276usize]) * (node_158)))
+                + ((current_base_row[279usize]) * (node_120)))
+                + ((current_base_row[254usize]) * (node_762)))
+                + ((current_base_row[259usize]) * (node_469)))
+                + ((current_base_row[281usize]) * (node_888)))
+                + ((current_base_row[283usize]) * (node_888))) * (node_3765))
+                + ((node_869) * (next_base_row[8usize])),
+            (((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_473)) + ((current_base_row[213usize]) * (node_120)))
+                + ((current_base_row[206usize]) * (node_766)))
+                + ((current_base_row[210usize]) * (current_base_row[263usize])))
+                + ((current_base_row[208usize]) * (node_342)))
+                + ((current_base_row[216usize]) * (node_877)))
+                + ((current_base_row[221usize]) * (node_881)))
+                + ((current_base_row[214usize]) * (node_209)))
+                + ((current_base_row[224usize]) * (node_881)))
+                + ((current_base_row[228usize]) * (node_888)))
+                + ((current_base_row[225usize]) * (node_881)))
+                + ((current_base_row[226usize]) * (node_237)))
+                + ((current_base_row[215usize]) * (node_762)))
+                + ((current_base_row[223usize]) * (node_469)))
+                + ((current_base_row[233usize]) * (node_513)))
+                + ((current_base_row[230usize]) * (node_416)))
+                + ((current_base_row[232usize]) * (node_881)))
+                + ((current_base_row[235usize]) * (node_241)))
+                + ((current_base_row[236usize]) * (node_241)))
+                + ((current_base_row[237usize]) * (node_881)))
+                + ((current_base_row[239usize]) * (node_233)))
+                + ((current_base_row[238usize]) * (node_120)))
+                + ((current_base_row[240usize]) * (node_159)))
+                + ((current_base_row[241usize]) * (node_159)))
+                + ((current_base_row[242usize]) * (node_159)))
+                + ((current_base_row[244usize]) * (node_885)))
+                + ((current_base_row[245usize]) * (node_159)))
+                + ((current_base_row[247usize]) * (node_885)))
+                + ((current_base_row[250usize]) * (node_885)))
+                + ((current_base_row[275usize]) * (node_893)))
+                + ((current_base_row[278usize]) * (node_893)))
+                + ((current_base_row[276usize]) * (node_120)))
+                + ((current_base_row[279usize]) * (node_124)))
+                + ((current_base_row[254usize]) * (node_766)))
+                + ((current_base_row[259usize]) * (node_473)))
+                + ((current_base_row[281usize]) * (node_158)))
+                + ((current_base_row[283usize]) * (node_158))) * (node_3765))
+                + ((node_873) * (next_base_row[8usize])),
+            (((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_477)) + ((current_base_row[213usize]) * (node_124)))
+                + ((current_base_row[206usize]) * (node_770)))
+                + ((current_base_row[210usize]) * (current_base_row[264usize])))
+                + ((current_base_row[208usize]) * (node_384)))
+                + ((current_base_row[216usize]) * (node_881)))
+                + ((current_base_row[221usize]) * (node_885)))
+                + ((current_base_row[214usize]) * (node_213)))
+                + ((current_base_row[224usize]) * (node_885)))
+                + ((current_base_row[228usize]) * (node_158)))
+                + ((current_base_row[225usize]) * (node_885)))
+                + ((current_base_row[226usize]) * (node_241)))
+                + ((current_base_row[215usize]) * (node_766)))
+                + ((current_base_row[223usize]) * (node_473)))
+                + ((current_base_row[233usize]) * (node_517)))
+                + ((current_base_row[230usize]) * (node_417)))
+                + ((current_base_row[232usize]) * (node_885)))
+                + ((current_base_row[235usize]) * (node_159)))
+                + ((current_base_row[236usize]) * (node_159)))
+                + ((current_base_row[237usize]) * (node_885)))
+                + ((current_base_row[239usize]) * (node_237)))
+                + ((current_base_row[238usize]) * (node_124)))
+                + ((current_base_row[240usize]) * (node_181)))
+                + ((current_base_row[241usize]) * (node_181)))
+                + ((current_base_row[242usize]) * (node_181)))
+                + ((current_base_row[244usize]) * (node_888)))
+                + ((current_base_row[245usize]) * (node_181)))
+                + ((current_base_row[247usize]) * (node_888)))
+                + ((current_base_row[250usize]) * (node_888)))
+                + ((current_base_row[275usize]) * (node_513)))
+                + ((current_base_row[278usize]) * (node_513)))
+                + ((current_base_row[276usize]) * (node_124)))
+                + ((current_base_row[279usize]) * (node_128)))
+                + ((current_base_row[254usize]) * (node_770)))
+                + ((current_base_row[259usize]) * (node_477))) + (node_1695))
+                + (node_1697)) * (node_3765)) + ((node_877) * (next_base_row[8usize])),
+            (((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_481)) + ((current_base_row[213usize]) * (node_128)))
+                + ((current_base_row[206usize]) * (node_774)))
+                + ((current_base_row[210usize]) * (node_154)))
+                + ((current_base_row[208usize]) * (current_base_row[297usize])))
+                + ((current_base_row[216usize]) * (node_885)))
+                + ((current_base_row[221usize]) * (node_888)))
+                + ((current_base_row[214usize]) * (node_217)))
+                + ((current_base_row[224usize]) * (node_888)))
+                + ((current_base_row[228usize]) * (node_513)))
+                + ((current_base_row[225usize]) * (node_888)))
+                + ((current_base_row[226usize]) * (node_159)))
+                + ((current_base_row[215usize]) * (node_770)))
+                + ((current_base_row[223usize]) * (node_477)))
+                + ((current_base_row[233usize]) * (node_521)))
+                + ((current_base_row[230usize]) * (node_418)))
+                + ((current_base_row[232usize]) * (node_888)))
+                + ((current_base_row[235usize]) * (node_181)))
+                + ((current_base_row[236usize]) * (node_181)))
+                + ((current_base_row[237usize]) * (node_888)))
+                + ((current_base_row[239usize]) * (node_241)))
+                + ((current_base_row[238usize]) * (node_128)))
+                + ((current_base_row[240usize]) * (node_513)))
+                + ((current_base_row[241usize]) * (node_513)))
+                + ((current_base_row[242usize]) * (node_513)))
+                + ((current_base_row[244usize]) * (node_158)))
+                + ((current_base_row[245usize]) * (node_513)))
+                + ((current_base_row[247usize]) * (node_158)))
+                + ((current_base_row[250usize]) * (node_158)))
+                + ((current_base_row[275usize]) * (node_517)))
+                + ((current_base_row[278usize]) * (node_517)))
+                + ((current_base_row[276usize]) * (node_128)))
+                + ((current_base_row[279usize]) * (node_893)))
+                + ((current_base_row[254usize]) * (node_774)))
+                + ((current_base_row[259usize]) * (node_481))) + (node_1774))
+                + (node_1776)) * (node_3765)) + ((node_881) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((((current_base_row[204usize])
+                * (node_485)) + ((current_base_row[213usize]) * (node_116)))
+                + ((current_base_row[206usize]) * (node_778)))
+                + ((current_base_row[210usize]) * (node_120)))
+                + ((current_base_row[208usize]) * (node_788)))
+                + ((current_base_row[216usize]) * (node_888)))
+                + ((current_base_row[221usize]) * (node_158)))
+                + ((current_base_row[214usize]) * (node_221)))
+                + ((current_base_row[224usize]) * (node_158)))
+                + ((current_base_row[228usize]) * (node_517)))
+                + ((current_base_row[225usize]) * (node_158)))
+                + ((current_base_row[226usize]) * (node_181)))
+                + ((current_base_row[215usize]) * (node_774)))
+                + ((current_base_row[223usize]) * (node_481)))
+                + ((current_base_row[230usize]) * (node_419)))
+                + ((current_base_row[232usize]) * (node_158)))
+                + ((current_base_row[235usize]) * (node_513)))
+                + ((current_base_row[236usize]) * (node_513)))
+                + ((current_base_row[237usize]) * (node_158)))
+                + ((current_base_row[239usize]) * (node_159)))
+                + ((current_base_row[238usize]) * (node_893)))
+                + ((current_base_row[240usize]) * (node_517)))
+                + ((current_base_row[241usize]) * (node_517)))
+                + ((current_base_row[242usize]) * (node_517)))
+                + ((current_base_row[244usize]) * (node_513)))
+                + ((current_base_row[245usize]) * (node_517)))
+                + ((current_base_row[247usize]) * (node_513)))
+                + ((current_base_row[250usize]) * (node_513)))
+                + ((current_base_row[275usize]) * (node_521)))
+                + ((current_base_row[278usize]) * (node_521)))
+                + ((current_base_row[276usize]) * (node_893)))
+                + ((current_base_row[279usize]) * (node_513)))
+                + ((current_base_row[254usize]) * (node_778)))
+                + ((current_base_row[259usize]) * (node_485))) + (node_1853))
+                + (node_1855)) * (node_3765)) + ((node_885) * (next_base_row[8usize])),
+            ((((((((((((((((((((((((((((((((((((current_base_row[204usize]) * (node_488))
+                + ((current_base_row[213usize]) * (node_513)))
+                + ((current_base_row[206usize]) * (node_781)))
+                + ((current_base_row[210usize]) * (node_124)))
+                + ((current_base_row[208usize]) * (current_base_row[284usize])))
+                + ((current_base_row[216usize]) * (node_158)))
+                + ((current_base_row[221usize]) * (node_513)))
+                + ((current_base_row[214usize]) * (node_225)))
+                + ((current_base_row[224usize]) * (node_513)))
+                + ((current_base_row[228usize]) * (node_521)))
+                + ((current_base_row[225usize]) * (node_513)))
+                + ((current_base_row[226usize]) * (node_513)))
+                + ((current_base_row[215usize]) * (node_778)))
+                + ((current_base_row[223usize]) * (node_485)))
+                + ((current_base_row[230usize]) * (node_513)))
+                + ((current_base_row[232usize]) * (node_513)))
+                + ((current_base_row[235usize]) * (node_517)))
+                + ((current_base_row[236usize]) * (node_517)))
+                + ((current_base_row[237usize]) * (node_513)))
+                + ((current_base_row[239usize]) * (node_181)))
+                + ((current_base_row[238usize]) * (node_513)))
+                + ((current_base_row[240usize]) * (node_521)))
+                + ((current_base_row[241usize]) * (node_521)))
+                + ((current_base_row[242usize]) * (node_521)))
+                + ((current_base_row[244usize]) * (node_517)))
+                + ((current_base_row[245usize]) * (node_521)))
+                + ((current_base_row[247usize]) * (node_517)))
+                + ((current_base_row[250usize]) * (node_517)))
+                + ((current_base_row[276usize]) * (node_513)))
+                + ((current_base_row[279usize]) * (node_517)))
+                + ((current_base_row[254usize])
+                    * ((node_781)
+                        + (((next_ext_row[3usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((challenges[1usize])
+                                    * (((challenges[1usize])
+                                        * (((challenges[1usize])
+                                            * (((challenges[1usize])
+                                                * ((node_1323) + (next_base_row[26usize])))
+                                                + (next_base_row[25usize]))) + (next_base_row[24usize])))
+                                        + (next_base_row[23usize]))) + (next_base_row[22usize]))))
+                            * (current_base_row[189usize])))))
+                + ((current_base_row[259usize])
+                    * ((node_488)
+                        + (((next_ext_row[4usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((challenges[2usize]) * (node_1385))
+                                    + (current_base_row[26usize]))))
+                            * (current_base_row[189usize])))))
+                + ((current_base_row[281usize])
+                    * ((current_ext_row[82usize]) + (node_1439))))
+                + ((current_base_row[283usize])
+                    * (((current_ext_row[7usize]) * (current_ext_row[75usize]))
+                        + (node_1439)))) * (node_3765))
+                + ((node_888) * (next_base_row[8usize])),
+            (((((((((((((((((((((((((((((((current_base_row[204usize]) * (node_490))
+                + ((current_base_row[213usize]) * (node_517)))
+                + ((current_base_row[206usize]) * (node_783)))
+                + ((current_base_row[210usize]) * (node_128)))
+                + ((current_base_row[208usize]) * (node_792)))
+                + ((current_base_row[216usize]) * (node_513)))
+                + ((current_base_row[221usize]) * (node_517)))
+                + ((current_base_row[214usize]) * (node_229)))
+                + ((current_base_row[224usize]) * (node_517)))
+                + ((current_base_row[225usize]) * (node_517)))
+                + ((current_base_row[226usize]) * (node_517)))
+                + ((current_base_row[215usize]) * (node_781)))
+                + ((current_base_row[223usize]) * (node_488)))
+                + ((current_base_row[230usize]) * (node_517)))
+                + ((current_base_row[232usize]) * (node_517)))
+                + ((current_base_row[235usize]) * (node_521)))
+                + ((current_base_row[236usize]) * (node_521)))
+                + ((current_base_row[237usize]) * (node_517)))
+                + ((current_base_row[239usize]) * (node_513)))
+                + ((current_base_row[238usize]) * (node_517)))
+                + ((current_base_row[244usize]) * (node_521)))
+                + ((current_base_row[247usize]) * (node_521)))
+                + ((current_base_row[250usize]) * (node_521)))
+                + ((current_base_row[276usize]) * (node_517)))
+                + ((current_base_row[279usize]) * (node_521)))
+                + ((current_base_row[254usize])
+                    * ((node_783)
+                        + (((next_ext_row[3usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((challenges[1usize])
+                                    * (((challenges[1usize])
+                                        * (((challenges[1usize])
+                                            * ((node_1323) + (next_base_row[25usize])))
+                                            + (next_base_row[24usize]))) + (next_base_row[23usize])))
+                                    + (next_base_row[22usize]))))
+                            * (current_base_row[188usize])))))
+                + ((current_base_row[259usize])
+                    * ((node_490)
+                        + (((next_ext_row[4usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (node_1385))) * (current_base_row[188usize])))))
+                + ((current_base_row[281usize])
+                    * ((node_833)
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((node_1441)
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * (node_1451)))))))
+                + ((current_base_row[283usize])
+                    * ((node_833)
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((current_base_row[40usize])
+                                * (current_base_row[39usize])))))) * (node_3765))
+                + ((node_158) * (next_base_row[8usize])),
+            (((((((((((((((((((((((((current_base_row[204usize]) * (node_491))
+                + ((current_base_row[213usize]) * (node_521)))
+                + ((current_base_row[206usize]) * (node_784)))
+                + ((current_base_row[210usize]) * (node_116)))
+                + ((current_base_row[208usize]) * (node_794)))
+                + ((current_base_row[216usize]) * (node_517)))
+                + ((current_base_row[221usize]) * (node_521)))
+                + ((current_base_row[214usize]) * (node_233)))
+                + ((current_base_row[224usize]) * (node_521)))
+                + ((current_base_row[225usize]) * (node_521)))
+                + ((current_base_row[226usize]) * (node_521)))
+                + ((current_base_row[215usize]) * (node_783)))
+                + ((current_base_row[223usize]) * (node_490)))
+                + ((current_base_row[230usize]) * (node_521)))
+                + ((current_base_row[232usize]) * (node_521)))
+                + ((current_base_row[237usize]) * (node_521)))
+                + ((current_base_row[239usize]) * (node_517)))
+                + ((current_base_row[238usize]) * (node_521)))
+                + ((current_base_row[276usize]) * (node_521)))
+                + ((current_base_row[254usize])
+                    * ((node_784)
+                        + (((next_ext_row[3usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((challenges[1usize])
+                                    * (((challenges[1usize])
+                                        * ((node_1323) + (next_base_row[24usize])))
+                                        + (next_base_row[23usize]))) + (next_base_row[22usize]))))
+                            * (current_base_row[187usize])))))
+                + ((current_base_row[259usize])
+                    * ((node_491)
+                        + (((next_ext_row[4usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (node_1380))) * (current_base_row[187usize])))))
+                + ((current_base_row[281usize])
+                    * ((node_837)
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((((current_base_row[40usize])
+                                * (current_base_row[42usize]))
+                                + ((current_base_row[39usize])
+                                    * (current_base_row[43usize])))
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * (node_1452))) + (node_1451))))))
+                + ((current_base_row[283usize])
+                    * ((node_837)
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((current_base_row[41usize])
+                                * (current_base_row[39usize])))))) * (node_3765))
+                + ((node_513) * (next_base_row[8usize])),
+            (((((((((((((((current_base_row[204usize]) * (node_267))
+                + ((current_base_row[206usize]) * (node_567)))
+                + ((current_base_row[210usize]) * (node_526)))
+                + ((current_base_row[208usize]) * (node_796)))
+                + ((current_base_row[216usize]) * (node_521)))
+                + ((current_base_row[214usize]) * (node_237)))
+                + ((current_base_row[215usize]) * (node_784)))
+                + ((current_base_row[223usize]) * (node_491)))
+                + ((current_base_row[239usize]) * (node_521)))
+                + ((current_base_row[254usize])
+                    * ((node_567)
+                        + (((next_ext_row[3usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((challenges[1usize])
+                                    * ((node_1323) + (next_base_row[23usize])))
+                                    + (next_base_row[22usize]))))
+                            * (current_base_row[186usize])))))
+                + ((current_base_row[259usize])
+                    * ((node_267)
+                        + (((next_ext_row[4usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (node_1375))) * (current_base_row[186usize])))))
+                + ((current_base_row[281usize])
+                    * ((node_841)
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((((current_base_row[234usize])
+                                + ((current_base_row[40usize])
+                                    * (current_base_row[43usize])))
+                                + ((current_base_row[39usize])
+                                    * (current_base_row[44usize]))) + (node_1452))))))
+                + ((current_base_row[283usize])
+                    * ((node_841)
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_1441))))) * (node_3765))
+                + ((node_517) * (next_base_row[8usize])),
+            (((((((((((((current_base_row[204usize]) * (current_base_row[324usize]))
+                + ((current_base_row[206usize]) * (current_base_row[324usize])))
+                + ((current_base_row[210usize]) * (node_527)))
+                + ((current_base_row[208usize]) * (node_798)))
+                + ((current_base_row[214usize]) * (node_241)))
+                + ((current_base_row[215usize]) * (node_567)))
+                + ((current_base_row[223usize]) * (node_267)))
+                + ((current_base_row[254usize])
+                    * (((next_ext_row[3usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((node_1323) + (next_base_row[22usize]))))
+                        * (current_base_row[185usize]))))
+                + ((current_base_row[259usize])
+                    * (((next_ext_row[4usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_1370))) * (current_base_row[185usize]))))
+                + ((current_base_row[281usize]) * (node_1062)))
+                + ((current_base_row[283usize]) * (node_1036))) * (node_3765))
+                + ((node_521) * (next_base_row[8usize])),
+            ((((((((((current_base_row[204usize]) * (current_base_row[272usize]))
+                + ((current_base_row[206usize]) * (current_base_row[272usize])))
+                + ((current_base_row[210usize]) * (node_529)))
+                + ((current_base_row[208usize]) * (node_802)))
+                + ((current_base_row[214usize]) * (node_181)))
+                + ((current_base_row[215usize]) * (current_base_row[317usize])))
+                + ((current_base_row[223usize]) * (current_base_row[317usize])))
+                + ((current_base_row[254usize]) * (current_base_row[317usize])))
+                + ((current_base_row[259usize]) * (current_base_row[317usize])))
+                * (node_3765),
+            ((((((((((current_base_row[204usize]) * (current_base_row[273usize]))
+                + ((current_base_row[206usize]) * (current_base_row[273usize])))
+                + ((current_base_row[210usize]) * (node_530)))
+                + ((current_base_row[208usize]) * (node_804)))
+                + ((current_base_row[214usize]) * (node_513)))
+                + ((current_base_row[215usize]) * (current_base_row[272usize])))
+                + ((current_base_row[223usize]) * (current_base_row[272usize])))
+                + ((current_base_row[254usize]) * (current_base_row[272usize])))
+                + ((current_base_row[259usize]) * (current_base_row[272usize])))
+                * (node_3765),
+            ((((((((((current_base_row[204usize]) * (current_base_row[319usize]))
+                + ((current_base_row[206usize]) * (current_base_row[319usize])))
+                + ((current_base_row[210usize]) * (node_531)))
+                + ((current_base_row[208usize]) * (node_806)))
+                + ((current_base_row[214usize]) * (node_517)))
+                + ((current_base_row[215usize]) * (current_base_row[273usize])))
+                + ((current_base_row[223usize]) * (current_base_row[273usize])))
+                + ((current_base_row[254usize]) * (current_base_row[273usize])))
+                + ((current_base_row[259usize]) * (current_base_row[273usize])))
+                * (node_3765),
+            ((((((((((current_base_row[204usize]) * (current_base_row[330usize]))
+                + ((current_base_row[206usize]) * (current_base_row[330usize])))
+                + ((current_base_row[210usize]) * (node_532)))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[185usize]))) * (node_829))))
+                + ((current_base_row[214usize]) * (node_521)))
+                + ((current_base_row[215usize]) * (current_base_row[319usize])))
+                + ((current_base_row[223usize]) * (current_base_row[319usize])))
+                + ((current_base_row[254usize]) * (current_base_row[319usize])))
+                + ((current_base_row[259usize]) * (current_base_row[319usize])))
+                * (node_3765),
+            (((((((((current_base_row[204usize]) * (node_513))
+                + ((current_base_row[206usize]) * (node_513)))
+                + ((current_base_row[210usize]) * (node_538)))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[272usize]))) * (node_853))))
+                + ((current_base_row[215usize]) * (current_base_row[344usize])))
+                + ((current_base_row[223usize]) * (current_base_row[344usize])))
+                + ((current_base_row[254usize]) * (current_base_row[344usize])))
+                + ((current_base_row[259usize]) * (current_base_row[344usize])))
+                * (node_3765),
+            (((((((((current_base_row[204usize]) * (node_517))
+                + ((current_base_row[206usize]) * (node_517)))
+                + ((current_base_row[210usize]) * (node_539)))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[273usize]))) * (node_857))))
+                + ((current_base_row[215usize]) * (node_517)))
+                + ((current_base_row[223usize]) * (node_517)))
+                + ((current_base_row[254usize]) * (node_513)))
+                + ((current_base_row[259usize]) * (node_513))) * (node_3765),
+            (((((((((current_base_row[204usize]) * (node_521))
+                + ((current_base_row[206usize]) * (node_521)))
+                + ((current_base_row[210usize]) * (node_540)))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[319usize]))) * (node_861))))
+                + ((current_base_row[215usize]) * (node_521)))
+                + ((current_base_row[223usize]) * (node_521)))
+                + ((current_base_row[254usize]) * (node_521)))
+                + ((current_base_row[259usize]) * (node_517))) * (node_3765),
+            (((current_base_row[210usize]) * (node_550))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[331usize]))) * (node_869))))
+                * (node_3765),
+            (((current_base_row[210usize]) * (node_513))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[277usize]))) * (node_873))))
+                * (node_3765),
+            (((current_base_row[210usize]) * (node_517))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[342usize]))) * (node_877))))
+                * (node_3765),
+            (((current_base_row[210usize]) * (node_521))
+                + ((current_base_row[208usize])
+                    * (((BFieldElement::from_raw_u64(4294967295u64))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_base_row[343usize]))) * (node_881))))
+                * (node_3765),
+            ((current_base_row[208usize]) * (node_513)) * (node_3765),
+            ((current_base_row[208usize]) * (node_517)) * (node_3765),
+            ((current_base_row[208usize]) * (node_521)) * (node_3765),
+            ((current_base_row[208usize]) * (node_888)) * (node_3765),
+            (((next_ext_row[13usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (current_ext_row[13usize])))
+                * ((challenges[11usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[7usize]))))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (next_base_row[45usize])),
+            ((node_3765)
+                * (((node_3899)
+                    * ((challenges[3usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((((challenges[13usize]) * (next_base_row[9usize]))
+                                + ((challenges[14usize]) * (next_base_row[10usize])))
+                                + ((challenges[15usize]) * (next_base_row[11usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((next_base_row[8usize]) * (node_3899)),
+            (next_ext_row[8usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[8usize])
+                        * ((challenges[9usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((((((challenges[24usize]) * (next_base_row[7usize]))
+                                    + ((challenges[25usize]) * (next_base_row[10usize])))
+                                    + ((challenges[26usize]) * (next_base_row[19usize])))
+                                    + ((challenges[27usize]) * (next_base_row[20usize])))
+                                    + ((challenges[28usize]) * (next_base_row[21usize]))))))),
+            (((next_base_row[10usize])
+                + (BFieldElement::from_raw_u64(18446743992105173011u64)))
+                * ((next_ext_row[9usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_ext_row[9usize]))))
+                + ((current_base_row[357usize])
+                    * (((next_ext_row[9usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((challenges[4usize]) * (current_ext_row[9usize]))))
+                        + (node_3985))),
+            (((current_base_row[10usize])
+                + (BFieldElement::from_raw_u64(18446743992105173011u64)))
+                * ((next_ext_row[10usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_ext_row[10usize]))))
+                + ((current_base_row[243usize])
+                    * (((next_ext_row[10usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * ((challenges[5usize]) * (current_ext_row[10usize]))))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_3968)))),
+            (((((((current_base_row[10usize])
+                + (BFieldElement::from_raw_u64(18446743897615892521u64)))
+                * ((current_base_row[10usize])
+                    + (BFieldElement::from_raw_u64(18446743923385696291u64))))
+                * ((current_base_row[10usize])
+                    + (BFieldElement::from_raw_u64(18446743863256154161u64))))
+                * ((next_ext_row[11usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_ext_row[11usize]))))
+                + ((current_base_row[232usize]) * (node_4045)))
+                + ((current_base_row[246usize])
+                    * ((node_4045)
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((((((((((challenges[32usize])
+                                * (current_base_row[22usize]))
+                                + ((challenges[33usize]) * (current_base_row[23usize])))
+                                + ((challenges[34usize]) * (current_base_row[24usize])))
+                                + ((challenges[35usize]) * (current_base_row[25usize])))
+                                + ((challenges[36usize]) * (current_base_row[26usize])))
+                                + ((challenges[37usize]) * (current_base_row[27usize])))
+                                + ((challenges[38usize]) * (current_base_row[28usize])))
+                                + ((challenges[39usize]) * (current_base_row[29usize])))
+                                + ((challenges[40usize]) * (current_base_row[30usize])))
+                                + ((challenges[41usize]) * (current_base_row[31usize])))))))
+                + ((current_base_row[249usize]) * ((node_4045) + (node_3985))),
+            (((((((((current_base_row[238usize])
+                * (((node_4108) * (((node_4065) + (node_4068)) + (node_4072)))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((current_base_row[240usize]) * (node_4112)))
+                + ((current_base_row[241usize]) * (node_4112)))
+                + ((current_base_row[242usize])
+                    * (((node_4108)
+                        * (((node_4079)
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((challenges[57usize])
+                                    * (BFieldElement::from_raw_u64(60129542130u64)))))
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((challenges[58usize])
+                                    * (((current_base_row[22usize])
+                                        + (current_base_row[23usize])) + (node_1265)))
+                                    * (BFieldElement::from_raw_u64(9223372036854775808u64))))))
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))))
+                + ((current_base_row[245usize]) * (node_4112)))
+                + ((current_base_row[244usize]) * (node_4116)))
+                + ((current_base_row[247usize])
+                    * (((((node_4108) * (node_4102)) * (node_4106))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_4102)))
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_4106)))))
+                + ((current_base_row[250usize]) * (node_4116)))
+                + (((BFieldElement::from_raw_u64(4294967295u64))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_base_row[14usize]))) * (node_4108)),
+            (((next_ext_row[14usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[14usize])
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((((challenges[16usize]) * (next_base_row[46usize]))
+                                    + ((challenges[17usize]) * (next_base_row[47usize])))
+                                    + ((challenges[18usize]) * (next_base_row[48usize])))
+                                    + ((challenges[19usize]) * (next_base_row[49usize]))))))))
+                * (node_4162))
+                + (((next_ext_row[14usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_ext_row[14usize]))) * (node_4179)),
+            ((((((node_4188)
+                * ((challenges[11usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((next_base_row[46usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (current_base_row[46usize]))))))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64))) * (node_4156))
+                * (node_4162)) + ((node_4188) * (node_4155)))
+                + ((node_4188) * (node_4179)),
+            ((node_4230)
+                * ((next_ext_row[16usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((current_ext_row[16usize]) * (node_4248)))))
+                + ((node_4233) * ((next_ext_row[16usize]) + (node_4253))),
+            ((node_4230)
+                * (((next_ext_row[17usize]) + (node_4253))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((node_4248) * (current_ext_row[17usize])))))
+                + ((node_4233)
+                    * ((next_ext_row[17usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_ext_row[17usize])))),
+            ((node_4230)
+                * (((next_ext_row[18usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((challenges[12usize]) * (current_ext_row[18usize]))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[55usize]))))
+                + ((node_4233)
+                    * ((next_ext_row[18usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_ext_row[18usize])))),
+            ((node_4230)
+                * (((next_ext_row[19usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((challenges[12usize]) * (current_ext_row[19usize]))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[56usize]))))
+                + ((node_4233)
+                    * ((next_ext_row[19usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_ext_row[19usize])))),
+            (((next_ext_row[20usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[20usize])
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((((next_base_row[50usize]) * (challenges[20usize]))
+                                    + ((next_base_row[52usize]) * (challenges[21usize])))
+                                    + ((next_base_row[53usize]) * (challenges[22usize])))
+                                    + ((next_base_row[51usize]) * (challenges[23usize]))))))))
+                * (node_4225))
+                + (((next_ext_row[20usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (current_ext_row[20usize]))) * (node_4299)),
+            (((current_ext_row[83usize]) * (node_4225)) + ((node_4308) * (node_4230)))
+                + ((node_4308) * (node_4299)),
+            (next_ext_row[22usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[22usize])
+                        * ((challenges[9usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((((((challenges[24usize]) * (next_base_row[57usize]))
+                                    + ((challenges[25usize]) * (next_base_row[58usize])))
+                                    + ((challenges[26usize]) * (next_base_row[59usize])))
+                                    + ((challenges[27usize]) * (next_base_row[60usize])))
+                                    + ((challenges[28usize]) * (next_base_row[61usize]))))))),
+            ((node_4337)
+                * (((node_4371)
+                    * ((challenges[11usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_4349))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_4336) * (node_4371)),
+            (((current_base_row[351usize])
+                * (((next_ext_row[24usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((challenges[30usize]) * (current_ext_row[24usize]))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((((((((((((((((((((challenges[29usize]) + (node_5124))
+                            * (challenges[29usize])) + (node_5135))
+                            * (challenges[29usize])) + (node_5146))
+                            * (challenges[29usize])) + (node_5157))
+                            * (challenges[29usize])) + (next_base_row[97usize]))
+                            * (challenges[29usize])) + (next_base_row[98usize]))
+                            * (challenges[29usize])) + (next_base_row[99usize]))
+                            * (challenges[29usize])) + (next_base_row[100usize]))
+                            * (challenges[29usize])) + (next_base_row[101usize]))
+                            * (challenges[29usize])) + (next_base_row[102usize])))))
+                + ((next_base_row[64usize]) * (node_5466)))
+                + ((node_5254) * (node_5466)),
+            ((current_base_row[352usize]) * (node_5254))
+                * (((((((((((challenges[0usize]) + (node_4408)) * (challenges[0usize]))
+                    + (node_4419)) * (challenges[0usize])) + (node_4430))
+                    * (challenges[0usize])) + (node_4441)) * (challenges[0usize]))
+                    + (current_base_row[97usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (challenges[62usize]))),
+            (current_base_row[365usize])
+                * ((((((node_5297) + (node_5298)) + (node_5300)) + (node_5302))
+                    + (node_5304)) + (node_5306)),
+            (current_base_row[366usize])
+                * (((((((((((((((((challenges[32usize])
+                    * ((node_5124)
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_4408))))
+                    + ((challenges[33usize])
+                        * ((node_5135)
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (node_4419)))))
+                    + ((challenges[34usize])
+                        * ((node_5146)
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (node_4430)))))
+                    + ((challenges[35usize])
+                        * ((node_5157)
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (node_4441)))))
+                    + ((challenges[36usize])
+                        * ((next_base_row[97usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (current_base_row[97usize])))))
+                    + ((challenges[37usize])
+                        * ((next_base_row[98usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (current_base_row[98usize])))))
+                    + ((challenges[38usize])
+                        * ((next_base_row[99usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (current_base_row[99usize])))))
+                    + ((challenges[39usize])
+                        * ((next_base_row[100usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (current_base_row[100usize])))))
+                    + ((challenges[40usize])
+                        * ((next_base_row[101usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (current_base_row[101usize])))))
+                    + ((challenges[41usize])
+                        * ((next_base_row[102usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (current_base_row[102usize]))))) + (node_5297))
+                    + (node_5298)) + (node_5300)) + (node_5302)) + (node_5304))
+                    + (node_5306)),
+            ((((current_base_row[316usize]) * (current_base_row[329usize]))
+                * (((next_ext_row[25usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((challenges[4usize]) * (current_ext_row[25usize]))))
+                    + (node_5396))) + ((next_base_row[64usize]) * (node_5373)))
+                + ((node_5259) * (node_5373)),
+            ((((node_5416) * (current_base_row[329usize]))
+                * (((next_ext_row[26usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((challenges[5usize]) * (current_ext_row[26usize]))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (node_5382)))) + ((node_5315) * (node_5407)))
+                + ((node_5259) * (node_5407)),
+            ((((current_base_row[316usize]) * (node_5366))
+                * ((((next_ext_row[27usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((challenges[6usize]) * (current_ext_row[27usize]))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((challenges[31usize]) * (next_base_row[63usize]))))
+                    + (node_5396))) + ((next_base_row[64usize]) * (node_5433)))
+                + ((((node_5263) * (node_5368)) * (node_5436)) * (node_5433)),
+            (((current_base_row[282usize])
+                * (((node_5485)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[65usize]))
+                                + ((challenges[50usize]) * (next_base_row[81usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5485))) + ((node_5493) * (node_5485)),
+            (((current_base_row[282usize])
+                * (((node_5506)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[66usize]))
+                                + ((challenges[50usize]) * (next_base_row[82usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5506))) + ((node_5493) * (node_5506)),
+            (((current_base_row[282usize])
+                * (((node_5523)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[67usize]))
+                                + ((challenges[50usize]) * (next_base_row[83usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5523))) + ((node_5493) * (node_5523)),
+            (((current_base_row[282usize])
+                * (((node_5540)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[68usize]))
+                                + ((challenges[50usize]) * (next_base_row[84usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5540))) + ((node_5493) * (node_5540)),
+            (((current_base_row[282usize])
+                * (((node_5557)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[69usize]))
+                                + ((challenges[50usize]) * (next_base_row[85usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5557))) + ((node_5493) * (node_5557)),
+            (((current_base_row[282usize])
+                * (((node_5574)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[70usize]))
+                                + ((challenges[50usize]) * (next_base_row[86usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5574))) + ((node_5493) * (node_5574)),
+            (((current_base_row[282usize])
+                * (((node_5591)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[71usize]))
+                                + ((challenges[50usize]) * (next_base_row[87usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5591))) + ((node_5493) * (node_5591)),
+            (((current_base_row[282usize])
+                * (((node_5608)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[72usize]))
+                                + ((challenges[50usize]) * (next_base_row[88usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5608))) + ((node_5493) * (node_5608)),
+            (((current_base_row[282usize])
+                * (((node_5625)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[73usize]))
+                                + ((challenges[50usize]) * (next_base_row[89usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5625))) + ((node_5493) * (node_5625)),
+            (((current_base_row[282usize])
+                * (((node_5642)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[74usize]))
+                                + ((challenges[50usize]) * (next_base_row[90usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5642))) + ((node_5493) * (node_5642)),
+            (((current_base_row[282usize])
+                * (((node_5659)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[75usize]))
+                                + ((challenges[50usize]) * (next_base_row[91usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5659))) + ((node_5493) * (node_5659)),
+            (((current_base_row[282usize])
+                * (((node_5676)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[76usize]))
+                                + ((challenges[50usize]) * (next_base_row[92usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5676))) + ((node_5493) * (node_5676)),
+            (((current_base_row[282usize])
+                * (((node_5693)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[77usize]))
+                                + ((challenges[50usize]) * (next_base_row[93usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5693))) + ((node_5493) * (node_5693)),
+            (((current_base_row[282usize])
+                * (((node_5710)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[78usize]))
+                                + ((challenges[50usize]) * (next_base_row[94usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5710))) + ((node_5493) * (node_5710)),
+            (((current_base_row[282usize])
+                * (((node_5727)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[79usize]))
+                                + ((challenges[50usize]) * (next_base_row[95usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5727))) + ((node_5493) * (node_5727)),
+            (((current_base_row[282usize])
+                * (((node_5744)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize]) * (next_base_row[80usize]))
+                                + ((challenges[50usize]) * (next_base_row[96usize]))))))
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))))
+                + ((node_5416) * (node_5744))) + ((node_5493) * (node_5744)),
+            ((node_5770)
+                * (((node_5780)
+                    * ((challenges[48usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((challenges[49usize])
+                                * (((BFieldElement::from_raw_u64(1099511627520u64))
+                                    * (next_base_row[130usize])) + (next_base_row[131usize])))
+                                + ((challenges[50usize])
+                                    * (((BFieldElement::from_raw_u64(1099511627520u64))
+                                        * (next_base_row[132usize]))
+                                        + (next_base_row[133usize])))))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[134usize]))))
+                + ((next_base_row[129usize]) * (node_5780)),
+            ((node_5770)
+                * ((((((node_5796)
+                    * ((challenges[51usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_5791))))
+                    * ((challenges[51usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (node_5794))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((BFieldElement::from_raw_u64(8589934590u64))
+                            * (challenges[51usize])))) + (node_5791)) + (node_5794)))
+                + ((next_base_row[129usize]) * (node_5796)),
+            ((node_5822)
+                * (((node_5834)
+                    * ((challenges[51usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((next_base_row[136usize]) * (challenges[52usize]))
+                                + ((next_base_row[137usize]) * (challenges[53usize]))))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[138usize]))))
+                + ((next_base_row[135usize]) * (node_5834)),
+            ((node_5822)
+                * (((next_ext_row[47usize])
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * ((current_ext_row[47usize]) * (challenges[54usize]))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[137usize]))))
+                + ((next_base_row[135usize])
+                    * ((next_ext_row[47usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (current_ext_row[47usize])))),
+            (node_5882) * (node_5998),
+            (next_base_row[139usize])
+                * (((node_5998)
+                    * ((challenges[10usize])
+                        + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                            * (((((challenges[57usize]) * (next_base_row[142usize]))
+                                + ((challenges[55usize]) * (next_base_row[143usize])))
+                                + ((challenges[56usize]) * (next_base_row[145usize])))
+                                + ((challenges[58usize]) * (next_base_row[147usize]))))))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (next_base_row[148usize]))),
+            (current_ext_row[50usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_176)
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((next_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(4294967295u64)))))
+                                    + ((challenges[19usize]) * (next_base_row[36usize]))))))),
+            (current_ext_row[51usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_547)
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((current_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(4294967295u64)))))
+                                    + ((challenges[19usize])
+                                        * (current_base_row[36usize]))))))),
+            (current_ext_row[52usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[50usize])
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((next_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(8589934590u64)))))
+                                    + ((challenges[19usize]) * (next_base_row[35usize]))))))),
+            (current_ext_row[53usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[51usize])
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((current_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(8589934590u64)))))
+                                    + ((challenges[19usize])
+                                        * (current_base_row[35usize]))))))),
+            (current_ext_row[54usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[52usize])
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((next_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(12884901885u64)))))
+                                    + ((challenges[19usize]) * (next_base_row[34usize]))))))),
+            (current_ext_row[55usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[53usize])
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((current_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(12884901885u64)))))
+                                    + ((challenges[19usize])
+                                        * (current_base_row[34usize]))))))),
+            (current_ext_row[56usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[54usize])
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((next_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(17179869180u64)))))
+                                    + ((challenges[19usize]) * (next_base_row[33usize]))))))),
+            (current_ext_row[57usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_966)
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_957)
+                                    + (((next_base_row[22usize])
+                                        + (BFieldElement::from_raw_u64(8589934590u64)))
+                                        * (challenges[21usize])))
+                                    + ((next_base_row[24usize]) * (challenges[22usize]))))))),
+            (current_ext_row[58usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((node_1042)
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_955) + (node_1050))
+                                    + ((current_base_row[24usize])
+                                        * (challenges[22usize]))))))),
+            (current_ext_row[59usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[6usize]) * (current_ext_row[56usize]))),
+            (current_ext_row[60usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[55usize])
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((current_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(17179869180u64)))))
+                                    + ((challenges[19usize])
+                                        * (current_base_row[33usize]))))))),
+            (current_ext_row[61usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[57usize])
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_957)
+                                    + (((next_base_row[22usize])
+                                        + (BFieldElement::from_raw_u64(12884901885u64)))
+                                        * (challenges[21usize])))
+                                    + ((next_base_row[25usize]) * (challenges[22usize]))))))),
+            (current_ext_row[62usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[58usize])
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_955) + (node_1064))
+                                    + ((current_base_row[25usize])
+                                        * (challenges[22usize]))))))),
+            (current_ext_row[63usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[61usize])
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_957)
+                                    + (((next_base_row[22usize])
+                                        + (BFieldElement::from_raw_u64(17179869180u64)))
+                                        * (challenges[21usize])))
+                                    + ((next_base_row[26usize]) * (challenges[22usize]))))))),
+            (current_ext_row[64usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[62usize])
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_955)
+                                    + (((current_base_row[22usize])
+                                        + (BFieldElement::from_raw_u64(12884901885u64)))
+                                        * (challenges[21usize])))
+                                    + ((current_base_row[26usize])
+                                        * (challenges[22usize]))))))),
+            (current_ext_row[65usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[189usize])
+                        * ((next_ext_row[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((current_ext_row[7usize])
+                                    * ((current_ext_row[63usize])
+                                        * ((challenges[8usize])
+                                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                                * (((node_957)
+                                                    + (((next_base_row[22usize])
+                                                        + (BFieldElement::from_raw_u64(21474836475u64)))
+                                                        * (challenges[21usize])))
+                                                    + ((next_base_row[27usize])
+                                                        * (challenges[22usize]))))))))))),
+            (current_ext_row[66usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[189usize])
+                        * ((next_ext_row[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((current_ext_row[7usize])
+                                    * ((current_ext_row[64usize])
+                                        * ((challenges[8usize])
+                                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                                * (((node_955)
+                                                    + (((current_base_row[22usize])
+                                                        + (BFieldElement::from_raw_u64(17179869180u64)))
+                                                        * (challenges[21usize])))
+                                                    + ((current_base_row[27usize])
+                                                        * (challenges[22usize]))))))))))),
+            (current_ext_row[67usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_ext_row[56usize])
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((next_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(21474836475u64)))))
+                                    + ((challenges[19usize]) * (next_base_row[32usize]))))))
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((next_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(25769803770u64)))))
+                                    + ((challenges[19usize]) * (next_base_row[31usize]))))))
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((next_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(30064771065u64)))))
+                                    + ((challenges[19usize]) * (next_base_row[30usize]))))))),
+            (current_ext_row[68usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((current_ext_row[60usize])
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((current_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(21474836475u64)))))
+                                    + ((challenges[19usize]) * (current_base_row[32usize]))))))
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((current_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(25769803770u64)))))
+                                    + ((challenges[19usize]) * (current_base_row[31usize]))))))
+                        * ((challenges[7usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_167)
+                                    + ((challenges[18usize])
+                                        * ((current_base_row[38usize])
+                                            + (BFieldElement::from_raw_u64(30064771065u64)))))
+                                    + ((challenges[19usize])
+                                        * (current_base_row[30usize]))))))),
+            (current_ext_row[69usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[6usize])
+                        * (((current_ext_row[67usize])
+                            * ((challenges[7usize])
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * (((node_167)
+                                        + ((challenges[18usize])
+                                            * ((next_base_row[38usize])
+                                                + (BFieldElement::from_raw_u64(34359738360u64)))))
+                                        + ((challenges[19usize]) * (next_base_row[29usize]))))))
+                            * ((challenges[7usize])
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * (((node_167)
+                                        + ((challenges[18usize])
+                                            * ((next_base_row[38usize])
+                                                + (BFieldElement::from_raw_u64(38654705655u64)))))
+                                        + ((challenges[19usize]) * (next_base_row[28usize])))))))),
+            (current_ext_row[70usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[6usize])
+                        * (((current_ext_row[68usize])
+                            * ((challenges[7usize])
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * (((node_167)
+                                        + ((challenges[18usize])
+                                            * ((current_base_row[38usize])
+                                                + (BFieldElement::from_raw_u64(34359738360u64)))))
+                                        + ((challenges[19usize]) * (current_base_row[29usize]))))))
+                            * ((challenges[7usize])
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * (((node_167)
+                                        + ((challenges[18usize])
+                                            * ((current_base_row[38usize])
+                                                + (BFieldElement::from_raw_u64(38654705655u64)))))
+                                        + ((challenges[19usize])
+                                            * (current_base_row[28usize])))))))),
+            (current_ext_row[71usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[6usize]) * (node_176))),
+            (current_ext_row[72usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[6usize]) * (current_ext_row[52usize]))),
+            (current_ext_row[73usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((node_1404)
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_957) + (node_1050)) + (node_1406)))))
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * (((node_957) + (node_1064)) + (node_1412)))))
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((node_1418) + (node_1419)))))),
+            (current_ext_row[74usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[6usize]) * (current_ext_row[60usize]))),
+            (current_ext_row[75usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((node_1404)
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((node_1418) + (node_1406)))))
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((node_1425) + (node_1412)))))
+                        * ((challenges[8usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((node_1432) + (node_1419)))))),
+            (current_ext_row[76usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[186usize])
+                        * ((next_ext_row[6usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((current_ext_row[6usize])
+                                    * (current_ext_row[50usize])))))),
+            (current_ext_row[77usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[188usize])
+                        * ((next_ext_row[6usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((current_ext_row[6usize])
+                                    * (current_ext_row[54usize])))))),
+            (current_ext_row[78usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[185usize]) * (node_550))),
+            (current_ext_row[79usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[186usize])
+                        * ((next_ext_row[6usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((current_ext_row[6usize])
+                                    * (current_ext_row[51usize])))))),
+            (current_ext_row[80usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[187usize])
+                        * ((next_ext_row[6usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((current_ext_row[6usize])
+                                    * (current_ext_row[53usize])))))),
+            (current_ext_row[81usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[188usize])
+                        * ((next_ext_row[6usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((current_ext_row[6usize])
+                                    * (current_ext_row[55usize])))))),
+            (current_ext_row[82usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_ext_row[7usize])
+                        * (((current_ext_row[73usize])
+                            * ((challenges[8usize])
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * ((node_1425)
+                                        + ((current_base_row[43usize]) * (challenges[22usize]))))))
+                            * ((challenges[8usize])
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * ((node_1432)
+                                        + ((current_base_row[44usize])
+                                            * (challenges[22usize])))))))),
+            (current_ext_row[83usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((((node_4308)
+                        * ((challenges[11usize])
+                            + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                * ((next_base_row[50usize])
+                                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                        * (current_base_row[50usize]))))))
+                        + (BFieldElement::from_raw_u64(18446744065119617026u64)))
+                        * (node_4233))),
+            (current_ext_row[84usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[215usize])
+                        * ((((((current_base_row[185usize])
+                            * ((next_ext_row[7usize])
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * ((current_ext_row[7usize]) * (node_966)))))
+                            + ((current_base_row[186usize])
+                                * ((next_ext_row[7usize])
+                                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                        * ((current_ext_row[7usize])
+                                            * (current_ext_row[57usize]))))))
+                            + ((current_base_row[187usize])
+                                * ((next_ext_row[7usize])
+                                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                        * ((current_ext_row[7usize])
+                                            * (current_ext_row[61usize]))))))
+                            + ((current_base_row[188usize])
+                                * ((next_ext_row[7usize])
+                                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                        * ((current_ext_row[7usize])
+                                            * (current_ext_row[63usize]))))))
+                            + (current_ext_row[65usize])))),
+            (current_ext_row[85usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * ((current_base_row[223usize])
+                        * ((((((current_base_row[185usize])
+                            * ((next_ext_row[7usize])
+                                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                    * ((current_ext_row[7usize]) * (node_1042)))))
+                            + ((current_base_row[186usize])
+                                * ((next_ext_row[7usize])
+                                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                        * ((current_ext_row[7usize])
+                                            * (current_ext_row[58usize]))))))
+                            + ((current_base_row[187usize])
+                                * ((next_ext_row[7usize])
+                                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                        * ((current_ext_row[7usize])
+                                            * (current_ext_row[62usize]))))))
+                            + ((current_base_row[188usize])
+                                * ((next_ext_row[7usize])
+                                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                                        * ((current_ext_row[7usize])
+                                            * (current_ext_row[64usize]))))))
+                            + (current_ext_row[66usize])))),
+        ];
+        base_constraints.into_iter().chain(ext_constraints).collect()
     }
-
+    #[allow(unused_variables)]
     fn evaluate_terminal_constraints(
-        _: ArrayView1<XFieldElement>,
-        _: ArrayView1<XFieldElement>,
-        _: &Challenges,
+        base_row: ArrayView1<XFieldElement>,
+        ext_row: ArrayView1<XFieldElement>,
+        challenges: &Challenges,
     ) -> Vec<XFieldElement> {
-        panic!("{ERROR_MESSAGE_GENERATE_CONSTRAINTS}")
+        let base_constraints = [
+            (base_row[5usize]) + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((base_row[3usize]) + (BFieldElement::from_raw_u64(18446744030759878666u64)))
+                * ((base_row[6usize])
+                    + (BFieldElement::from_raw_u64(18446744065119617026u64))),
+            base_row[10usize],
+            ((base_row[62usize])
+                * ((base_row[63usize])
+                    + (BFieldElement::from_raw_u64(18446743897615892521u64))))
+                * ((base_row[64usize])
+                    + (BFieldElement::from_raw_u64(18446744047939747846u64))),
+            (base_row[143usize])
+                * ((base_row[142usize])
+                    + (BFieldElement::from_raw_u64(18446743940565565471u64))),
+            base_row[145usize],
+        ];
+        let ext_constraints = [
+            (((ext_row[18usize]) * (ext_row[16usize]))
+                + ((ext_row[19usize]) * (ext_row[17usize])))
+                + (BFieldElement::from_raw_u64(18446744065119617026u64)),
+            ((((base_row[62usize])
+                + (BFieldElement::from_raw_u64(18446744060824649731u64)))
+                * ((base_row[62usize])
+                    + (BFieldElement::from_raw_u64(18446744056529682436u64))))
+                * (base_row[62usize]))
+                * (((((((((((challenges[0usize])
+                    + ((((((base_row[65usize])
+                        * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+                        + ((base_row[66usize])
+                            * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+                        + ((base_row[67usize])
+                            * (BFieldElement::from_raw_u64(281474976645120u64))))
+                        + (base_row[68usize])) * (BFieldElement::from_raw_u64(1u64))))
+                    * (challenges[0usize]))
+                    + ((((((base_row[69usize])
+                        * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+                        + ((base_row[70usize])
+                            * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+                        + ((base_row[71usize])
+                            * (BFieldElement::from_raw_u64(281474976645120u64))))
+                        + (base_row[72usize])) * (BFieldElement::from_raw_u64(1u64))))
+                    * (challenges[0usize]))
+                    + ((((((base_row[73usize])
+                        * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+                        + ((base_row[74usize])
+                            * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+                        + ((base_row[75usize])
+                            * (BFieldElement::from_raw_u64(281474976645120u64))))
+                        + (base_row[76usize])) * (BFieldElement::from_raw_u64(1u64))))
+                    * (challenges[0usize]))
+                    + ((((((base_row[77usize])
+                        * (BFieldElement::from_raw_u64(18446744069414518785u64)))
+                        + ((base_row[78usize])
+                            * (BFieldElement::from_raw_u64(18446744069414584320u64))))
+                        + ((base_row[79usize])
+                            * (BFieldElement::from_raw_u64(281474976645120u64))))
+                        + (base_row[80usize])) * (BFieldElement::from_raw_u64(1u64))))
+                    * (challenges[0usize])) + (base_row[97usize]))
+                    + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                        * (challenges[62usize]))),
+            (ext_row[47usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (challenges[61usize])),
+            (ext_row[2usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[24usize])),
+            (challenges[59usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[3usize])),
+            (ext_row[4usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (challenges[60usize])),
+            (ext_row[5usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[0usize])),
+            (ext_row[6usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[14usize])),
+            (ext_row[7usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[20usize])),
+            (ext_row[8usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[22usize])),
+            (ext_row[9usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[25usize])),
+            (ext_row[26usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[10usize])),
+            (ext_row[11usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[27usize])),
+            ((((((((((((((((ext_row[44usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[28usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[29usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[30usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[31usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[32usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[33usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[34usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[35usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[36usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[37usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[38usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[39usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[40usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[41usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[42usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[43usize])),
+            (ext_row[45usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[46usize])),
+            (ext_row[12usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[48usize])),
+            (((ext_row[13usize])
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[15usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[21usize])))
+                + ((BFieldElement::from_raw_u64(18446744065119617026u64))
+                    * (ext_row[23usize])),
+        ];
+        base_constraints.into_iter().chain(ext_constraints).collect()
     }
 }
-
 impl Quotientable for MasterExtTable {
-    const NUM_INITIAL_CONSTRAINTS: usize = 0;
-    const NUM_CONSISTENCY_CONSTRAINTS: usize = 0;
-    const NUM_TRANSITION_CONSTRAINTS: usize = 0;
-    const NUM_TERMINAL_CONSTRAINTS: usize = 0;
-
-    fn initial_quotient_degree_bounds(_: isize) -> Vec<isize> {
-        panic!("{ERROR_MESSAGE_GENERATE_DEGREE_BOUNDS}")
+    const NUM_INITIAL_CONSTRAINTS: usize = 81usize;
+    const NUM_CONSISTENCY_CONSTRAINTS: usize = 94usize;
+    const NUM_TRANSITION_CONSTRAINTS: usize = 414usize;
+    const NUM_TERMINAL_CONSTRAINTS: usize = 23usize;
+    #[allow(unused_variables)]
+    fn initial_quotient_degree_bounds(interpolant_degree: isize) -> Vec<isize> {
+        let zerofier_degree = 1;
+        [
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+        ]
+            .to_vec()
     }
-
-    fn consistency_quotient_degree_bounds(_: isize, _: usize) -> Vec<isize> {
-        panic!("{ERROR_MESSAGE_GENERATE_DEGREE_BOUNDS}")
+    #[allow(unused_variables)]
+    fn consistency_quotient_degree_bounds(
+        interpolant_degree: isize,
+        padded_height: usize,
+    ) -> Vec<isize> {
+        let zerofier_degree = padded_height as isize;
+        [
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+        ]
+            .to_vec()
     }
-
-    fn transition_quotient_degree_bounds(_: isize, _: usize) -> Vec<isize> {
-        panic!("{ERROR_MESSAGE_GENERATE_DEGREE_BOUNDS}")
+    #[allow(unused_variables)]
+    fn transition_quotient_degree_bounds(
+        interpolant_degree: isize,
+        padded_height: usize,
+    ) -> Vec<isize> {
+        let zerofier_degree = padded_height as isize - 1;
+        [
+            interpolant_degree - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+        ]
+            .to_vec()
     }
-
-    fn terminal_quotient_degree_bounds(_: isize) -> Vec<isize> {
-        panic!("{ERROR_MESSAGE_GENERATE_DEGREE_BOUNDS}")
+    #[allow(unused_variables)]
+    fn terminal_quotient_degree_bounds(interpolant_degree: isize) -> Vec<isize> {
+        let zerofier_degree = 1;
+        [
+            interpolant_degree - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree * 3isize - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree * 2isize - zerofier_degree,
+            interpolant_degree * 4isize - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+            interpolant_degree - zerofier_degree,
+        ]
+            .to_vec()
     }
 }
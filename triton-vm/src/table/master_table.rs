@@ -1,6 +1,7 @@
 use std::ops::Mul;
 use std::ops::MulAssign;
 use std::ops::Range;
+use std::time::Instant;
 
 use gpu_accelerator::Array_u64_2d;
 use gpu_accelerator::FutharkContext;
@@ -503,9 +504,21 @@ impl MasterTable<BFieldElement> for MasterBaseTable {
             prof_stop!(maybe_profiler, "LDE-GPU-2");
 
             prof_start!(maybe_profiler, "LDE-GPU-3", "LDE");
+            // The context does not report kernel timings, but its entry points block until the
+            // device has finished.
+            let kernel_start = Instant::now();
             let res: (Array_u64_2d, Array_u64_2d) = ctx
                 .lde_multiple_columns(expansion_factor as i64, trace_columns)
                 .unwrap();
+            if let Some(profiler) = maybe_profiler.as_ref() {
+                profiler.handle().record_device_span(
+                    "GPU",
+                    "lde_multiple_columns",
+                    Some("LDE".to_string()),
+                    kernel_start,
+                    kernel_start.elapsed(),
+                );
+            }
             prof_stop!(maybe_profiler, "LDE-GPU-3");
 
             prof_start!(maybe_profiler, "LDE-GPU-4", "LDE");
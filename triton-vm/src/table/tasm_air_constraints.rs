@@ -1,13 +1,7548 @@
-//! This file is a placeholder for auto-generated code
-//! Run `cargo run --bin constraint-evaluation-generator`
-//! to fill in this file with optimized constraints.
-
+use twenty_first::prelude::BFieldCodec;
+use twenty_first::prelude::BFieldElement;
 use crate::instruction::LabelledInstruction;
-use crate::table::constraints::ERROR_MESSAGE_GENERATE_CONSTRAINTS;
+use crate::Program;
 use crate::table::TasmConstraintEvaluationMemoryLayout;
+#[allow(unused_imports)]
+use crate::table::extension_table::Quotientable;
+/**
+         The emitted Triton assembly has the following signature:
+
+         # Signature
+
+         ```text
+         BEFORE: _
+         AFTER:  _ *evaluated_constraints
+         ```
+         # Requirements
+
+         In order for this method to emit Triton assembly, various memory regions need to be
+         declared. This is done through [`TasmConstraintEvaluationMemoryLayout`]. The memory
+         layout must be [integral].
+
+         # Guarantees
+
+         - The emitted code does not declare any labels.
+         - The emitted code is “straight-line”, _i.e._, does not contain any of the instructions
+         `call`, `return`, `recurse`, or `skiz`.
+         - The emitted code does not contain instruction `halt`.
+         - All memory write access of the emitted code is within the bounds of the memory region
+         pointed to by `*free_memory_page`.
+         - `*evaluated_constraints` points to an array of [`XFieldElement`][xfe]s of length
+         [`NUM_CONSTRAINTS`][total]. Each element is the evaluation of one constraint. In
+         particular, the disjoint sequence of slices containing
+         [`NUM_INITIAL_CONSTRAINTS`][init], [`NUM_CONSISTENCY_CONSTRAINTS`][cons],
+         [`NUM_TRANSITION_CONSTRAINTS`][tran], and [`NUM_TERMINAL_CONSTRAINTS`][term]
+         (respectively and in this order) correspond to the evaluations of the initial, consistency,
+         transition, and terminal constraints.
 
+         [integral]: TasmConstraintEvaluationMemoryLayout::is_integral
+         [xfe]: twenty_first::prelude::XFieldElement
+         [total]: crate::table::master_table::MasterExtTable::NUM_CONSTRAINTS
+         [init]: crate::table::master_table::MasterExtTable::NUM_INITIAL_CONSTRAINTS
+         [cons]: crate::table::master_table::MasterExtTable::NUM_CONSISTENCY_CONSTRAINTS
+         [tran]: crate::table::master_table::MasterExtTable::NUM_TRANSITION_CONSTRAINTS
+         [term]: crate::table::master_table::MasterExtTable::NUM_TERMINAL_CONSTRAINTS
+        */
 pub fn air_constraint_evaluation_tasm(
-    _: TasmConstraintEvaluationMemoryLayout,
+    mem_layout: TasmConstraintEvaluationMemoryLayout,
 ) -> Vec<LabelledInstruction> {
-    panic!("{ERROR_MESSAGE_GENERATE_CONSTRAINTS}");
+    let free_mem_page_ptr = mem_layout.free_mem_page_ptr.value();
+    let curr_base_row_ptr = mem_layout.curr_base_row_ptr.value();
+    let curr_ext_row_ptr = mem_layout.curr_ext_row_ptr.value();
+    let next_base_row_ptr = mem_layout.next_base_row_ptr.value();
+    let next_ext_row_ptr = mem_layout.next_ext_row_ptr.value();
+    let challenges_ptr = mem_layout.challenges_ptr.value();
+    let raw_instructions = vec![
+        61908u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 389u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 1404u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, challenges_ptr + 158u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        395u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 161u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 401u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 1422u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 158u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        392u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 161u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 398u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 1431u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 2u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        4294901760u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 11u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4294901763u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 17u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 4294901766u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        4294901769u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 29u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4294901772u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 59u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 4294901775u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 62u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        4294901778u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 65u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4294901781u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 4294901784u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        4294901787u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 74u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4294901790u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 77u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 4294901793u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 80u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        4294901796u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 83u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4294901799u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 86u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 4294901802u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        4294901805u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 92u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4294901808u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 95u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 4294901811u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 98u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        4294901814u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 116u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584305u64, 66u64,
+        1u64, free_mem_page_ptr + 4294901817u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 146u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584305u64, 66u64, 1u64, free_mem_page_ptr + 4294901820u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 167u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 4294901823u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 173u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        4294901826u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 179u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4294901829u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 182u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 4294901832u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 185u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        4294901835u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 188u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64,
+        1u64, free_mem_page_ptr + 4294901838u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        4294901841u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 410u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4294901844u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 449u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 38u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        66u64, 1u64, curr_base_row_ptr + 41u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 44u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, curr_base_row_ptr + 47u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294901847u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 452u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 449u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 50u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, curr_base_row_ptr + 53u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 1u64,
+        curr_base_row_ptr + 56u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294901850u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 2u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4294901853u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_ext_row_ptr + 5u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 89u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, curr_base_row_ptr + 5u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294901856u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 8u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 4294901859u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 2u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 101u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, challenges_ptr
+        + 2u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 104u64, 41u64,
+        3u64, 3u64, 1u64, 66u64, 1u64, challenges_ptr + 2u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 107u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        challenges_ptr + 2u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr +
+        110u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, challenges_ptr + 2u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 113u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294901862u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 11u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 4294901865u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 17u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 11u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 44u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 32u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        challenges_ptr + 47u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 35u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        4294901868u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 14u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64,
+        1u64, free_mem_page_ptr + 4294901871u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 4294901874u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        4294901877u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 26u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 29u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 77u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 32u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294901880u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_ext_row_ptr + 41u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        35u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 137u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294901883u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 32u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584303u64, 66u64, 1u64, curr_ext_row_ptr + 29u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        66u64, 74u64, 1u64, curr_base_row_ptr + 452u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 29u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 14u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294901886u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_ext_row_ptr + 32u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        4294901889u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 35u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64,
+        1u64, free_mem_page_ptr + 4294901892u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 38u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        4294901895u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 44u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 50u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 140u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        challenges_ptr + 53u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        143u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 56u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 16u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 59u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        149u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 143u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584319u64, 66u64, 74u64, 1u64, curr_ext_row_ptr + 44u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        66u64, 1u64, curr_base_row_ptr + 143u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 143u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 66u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294901898u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 47u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        4294901901u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 56u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4294901904u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_ext_row_ptr + 59u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 170u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294901907u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 50u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 38u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 158u64, 41u64,
+        3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 4294901910u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_ext_row_ptr + 53u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        4294901913u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 62u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        62u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 155u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 158u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 65u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 161u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 66u64, 1u64, curr_base_row_ptr +
+        155u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584319u64, 66u64, 74u64, 1u64, curr_ext_row_ptr + 62u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64,
+        1u64, curr_base_row_ptr + 155u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, curr_base_row_ptr + 155u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294901916u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 65u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4294901919u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_ext_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 29u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 77u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        176u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294901922u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        4294901925u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 77u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64,
+        1u64, free_mem_page_ptr + 4294901928u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 80u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 4294901931u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 83u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        4294901934u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 74u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 92u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 89u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 197u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 281474976710656u64, 74u64, 1u64, curr_base_row_ptr +
+        200u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967296u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 203u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 65536u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 206u64,
+        41u64, 3u64, 3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744065119617025u64, 74u64, 66u64, 1u64, challenges_ptr + 89u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 209u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 281474976710656u64, 74u64, 1u64, curr_base_row_ptr
+        + 212u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967296u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 215u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 65536u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 218u64,
+        41u64, 3u64, 3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744065119617025u64, 74u64, 66u64, 1u64, challenges_ptr + 89u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 221u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 281474976710656u64, 74u64, 1u64, curr_base_row_ptr
+        + 224u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967296u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 227u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 65536u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 230u64,
+        41u64, 3u64, 3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744065119617025u64, 74u64, 66u64, 1u64, challenges_ptr + 89u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 233u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 281474976710656u64, 74u64, 1u64, curr_base_row_ptr
+        + 236u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967296u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 239u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 65536u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 242u64,
+        41u64, 3u64, 3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744065119617025u64, 74u64, 66u64, 1u64, challenges_ptr + 89u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 293u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, challenges_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 296u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, challenges_ptr
+        + 89u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 299u64, 41u64,
+        3u64, 3u64, 1u64, 66u64, 1u64, challenges_ptr + 89u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 302u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        challenges_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr +
+        305u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, challenges_ptr + 89u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 308u64, 41u64, 3u64, 3u64,
+        1u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294901937u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_ext_row_ptr + 86u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 146u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 197u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        challenges_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        245u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        4294901940u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 89u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 149u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 200u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 248u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294901943u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 92u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        203u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 251u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 4294901946u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 95u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 146u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 206u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        challenges_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        254u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        4294901949u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 98u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 149u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 209u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 257u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294901952u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 101u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        146u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 212u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        challenges_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        260u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        4294901955u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 104u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 149u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 215u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 263u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294901958u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 107u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        146u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 218u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        challenges_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        266u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        4294901961u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 110u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 149u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 221u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 269u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294901964u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 113u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        146u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 224u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        challenges_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        272u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        4294901967u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 116u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 149u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 227u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 275u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294901970u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 119u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        146u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 230u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        challenges_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        278u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        4294901973u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 122u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 149u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 233u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 281u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294901976u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 125u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        146u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 236u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        challenges_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        284u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        4294901979u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 128u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 149u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 239u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 287u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294901982u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 131u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        146u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 242u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        challenges_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        290u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        4294901985u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1406u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 134u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 146u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 256u64, 1u64, curr_base_row_ptr + 392u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 395u64, 41u64, 3u64, 3u64,
+        1u64, 66u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 256u64, 1u64, curr_base_row_ptr + 398u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 401u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 404u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 1u64, curr_base_row_ptr + 389u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_ext_row_ptr + 134u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294901988u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1406u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr +
+        137u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 155u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 1424u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        challenges_ptr + 155u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 1433u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 2u64, 1u64, challenges_ptr + 155u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 1424u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1433u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 74u64, 1u64, curr_base_row_ptr + 389u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 137u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294901991u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 140u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        155u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 413u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 161u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 416u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294901994u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 143u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 164u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 413u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294901997u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 419u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 66u64, 1u64, curr_ext_row_ptr + 146u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 419u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_ext_row_ptr + 146u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 32u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 167u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 431u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        challenges_ptr + 170u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        437u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 173u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 428u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, challenges_ptr + 176u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 443u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 446u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294902000u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 458u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584316u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 306u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 461u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584316u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        663u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 464u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584317u64, 66u64, 74u64, 1u64,
+        curr_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584316u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 714u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 464u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584318u64, 66u64, 74u64, 1u64, curr_base_row_ptr
+        + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584316u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 735u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 473u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 1965u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 191u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584303u64, 66u64, 1u64, curr_base_row_ptr +
+        191u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584287u64, 66u64, 74u64, 1u64, curr_base_row_ptr + 191u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584273u64, 66u64,
+        74u64, 1u64, free_mem_page_ptr + 342u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 308u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        485u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 348u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 482u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 1980u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584316u64, 66u64, 1u64, free_mem_page_ptr +
+        303u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 428u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584293u64, 66u64, 1u64,
+        free_mem_page_ptr + 2034u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        428u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584291u64, 66u64, 1u64, free_mem_page_ptr + 2022u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584318u64, 66u64, 1u64, free_mem_page_ptr +
+        282u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 194u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584317u64, 66u64, 1u64,
+        free_mem_page_ptr + 291u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 4294967295u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 197u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 65536u64, 74u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 200u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 329u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        free_mem_page_ptr + 459u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 4294967295u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 209u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 65536u64, 74u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 212u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 332u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        free_mem_page_ptr + 465u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 4294967295u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 221u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 65536u64, 74u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 224u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 335u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        free_mem_page_ptr + 471u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 4294967295u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 233u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 65536u64, 74u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 236u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 338u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        free_mem_page_ptr + 477u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        419u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 2040u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584319u64, 66u64, 1u64, free_mem_page_ptr +
+        270u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 428u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584309u64, 66u64, 1u64,
+        free_mem_page_ptr + 2010u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 9u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, curr_base_row_ptr + 11u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 14u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 33u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        9u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 11u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 24u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        188u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, curr_base_row_ptr + 188u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584319u64, 66u64, 74u64,
+        1u64, curr_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584318u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        312u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 188u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584319u64, 66u64, 1u64,
+        free_mem_page_ptr + 255u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        191u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584303u64, 66u64, 1u64, free_mem_page_ptr + 219u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 191u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584287u64, 66u64, 1u64, free_mem_page_ptr +
+        237u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 191u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584273u64, 66u64, 1u64,
+        free_mem_page_ptr + 246u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 4294967295u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 197u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 65536u64, 74u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 200u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 378u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 4294967295u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 209u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 65536u64, 74u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 212u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 399u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967295u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 221u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 65536u64, 74u64, 74u64, 66u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        224u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 420u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967295u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 233u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 65536u64, 74u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 236u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 441u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 267u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 428u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584317u64, 66u64, 1u64, free_mem_page_ptr +
+        1989u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 428u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584307u64, 66u64, 1u64,
+        free_mem_page_ptr + 1998u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        188u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 257u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 258u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 249u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 188u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584318u64, 66u64, 1u64,
+        free_mem_page_ptr + 309u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        35u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 14u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902003u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 35u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902006u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 17u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        17u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 4294902009u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 20u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        4294902012u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 38u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 38u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902015u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 41u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        41u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 4294902018u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 44u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 44u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        4294902021u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 47u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 47u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902024u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 50u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        50u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 4294902027u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 53u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 53u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        4294902030u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 56u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 56u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902033u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        26u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 4294902036u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 32u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 38u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        2u64, 1u64, curr_base_row_ptr + 41u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 4u64, 1u64, curr_base_row_ptr + 44u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 8u64, 1u64,
+        curr_base_row_ptr + 47u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 16u64, 1u64, curr_base_row_ptr + 50u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 32u64, 1u64, curr_base_row_ptr
+        + 53u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        64u64, 1u64, curr_base_row_ptr + 56u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902039u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr
+        + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, curr_base_row_ptr + 137u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902042u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 314u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902045u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 257u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        221u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902048u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 497u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 239u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 248u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902051u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 314u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        194u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902054u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 344u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902057u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 461u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        329u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902060u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 467u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 332u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902063u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 473u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        335u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902066u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 479u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 338u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902069u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 461u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        380u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902072u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 467u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 401u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902075u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 473u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        422u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902078u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 479u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 443u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902081u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 461u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        203u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 65536u64, 74u64,
+        1u64, curr_base_row_ptr + 206u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902084u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 467u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        215u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 65536u64, 74u64,
+        1u64, curr_base_row_ptr + 218u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902087u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 473u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        227u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 65536u64, 74u64,
+        1u64, curr_base_row_ptr + 230u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902090u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 479u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        239u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 65536u64, 74u64,
+        1u64, curr_base_row_ptr + 242u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902093u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 344u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        311u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902096u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 344u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 314u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902099u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 344u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        317u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902102u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 344u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 320u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902105u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 344u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        323u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902108u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 344u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 326u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902111u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 350u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        311u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 4294902114u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 350u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 314u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr
+        + 4294902117u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 350u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 317u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902120u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 350u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        320u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 4294902123u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 350u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 323u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr
+        + 4294902126u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 350u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 326u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902129u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 308u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        341u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        4815968766059126563u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 665u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 341u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 914215420835200215u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 467u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        341u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        4610987870046315072u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 716u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 341u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 17896753344480921024u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 341u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 15096636905099313914u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294902132u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 308u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 344u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 1549816495321350447u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        344u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        13229958218991904766u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 467u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 344u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 16797990613470240149u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 716u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 344u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 13544759223296506920u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        737u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 344u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 730801235115235144u64, 66u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294902135u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 308u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        347u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        8067294415764453826u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 665u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 347u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 3028672737319552474u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 467u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        347u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        8610619595845325838u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 716u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 347u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 6988101035717808552u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 347u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 8846134920194710325u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294902138u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 308u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 350u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 16481335705001490826u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        350u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        6524814306459438063u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 467u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 350u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 5579102472306652092u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 716u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 350u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 9739958805295371611u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        737u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 350u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 5552386433594580372u64, 66u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294902141u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 308u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        353u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        3214205122324399210u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 665u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 353u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 8708025075737564447u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 467u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        353u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        7192591432721623726u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 716u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 353u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 5924985931398860249u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 353u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 13849094411374069690u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294902144u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 308u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 356u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 2554109671322837247u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        356u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        14982163669981587174u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 467u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 356u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1895911332274723213u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 716u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 356u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 6568830006997606125u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        737u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 356u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 10711180118494092474u64, 66u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294902147u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 308u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        359u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        14457609929389712553u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 665u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 359u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 5038309300297420271u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 467u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        359u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        6585170098933851059u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 716u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 359u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 7113425818280060569u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 359u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 16783364613543697140u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294902150u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 308u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 362u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 15595332157286853456u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        362u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18182315850764967890u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 467u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 362u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 17190083595825910826u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 716u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 362u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 14512844438135975698u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        737u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 362u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4557445965775754615u64, 66u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294902153u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 308u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        365u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        9737607630120825545u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 665u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 365u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 14010496200406502940u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 467u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        365u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        4567238068738129185u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 716u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 365u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1811615097393426397u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 365u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 11071213718193699887u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294902156u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 308u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 368u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 14751885399751644587u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        368u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        14383614633563780100u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 467u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 368u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 7882640226732225600u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 716u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 368u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 8155406896305633871u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        737u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 368u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 14944721636129315170u64, 66u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294902159u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 308u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        371u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        5754303825099257180u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 665u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 371u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 15581670913673464204u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 467u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        371u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        2303901544618186800u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 716u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 371u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 14304636914390384971u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 371u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 9214938738983527369u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294902162u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 308u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 374u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 7724427903056507572u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        374u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        12696909631804818327u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 467u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 374u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 15159645477465953737u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 716u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 374u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 1472809535626840784u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        737u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 374u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 9194471314126060596u64, 66u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294902165u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 308u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        377u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        5701314748972944873u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 665u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 377u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 11642547305225175886u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 467u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        377u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        17760832598353299516u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 716u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 377u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 7378632530289409100u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 377u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 8432475407087838102u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294902168u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 308u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 380u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 514319845690593900u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        380u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        1386274868121595813u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 467u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 380u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 13161445292495706298u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 716u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 380u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 899974374584380715u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        737u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 380u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 2881712436463741087u64, 66u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294902171u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 308u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        383u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        10888641534546646858u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 665u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 383u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 8971360512677377613u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 467u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        383u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        135790497646536967u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 716u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 383u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 13131526324589515328u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 383u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 17237018795892764998u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294902174u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 308u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 386u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 2895696633559052917u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        386u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        5570399983803119301u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 467u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 386u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 15304477718784582286u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 716u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 386u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 13837149816504971240u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        737u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 386u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 12422101204816739213u64, 66u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294902177u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 389u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 389u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902180u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 407u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 407u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902183u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 419u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 419u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902186u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 419u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        422u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902189u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 425u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 422u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584288u64, 66u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294902192u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 434u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1967u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902195u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 431u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 1967u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902198u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 440u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1982u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902201u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 437u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 1982u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902204u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 503u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        443u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584319u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 4294902207u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 506u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 443u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902210u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 491u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1967u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 1982u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 443u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902213u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 500u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2036u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 1982u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 443u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        4294902216u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 494u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2042u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 1967u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 443u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 1u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 4294902219u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 500u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2024u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 1967u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 443u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902222u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 494u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        419u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 1967u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902225u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 2042u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 446u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902228u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 455u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 194u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 269u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902231u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 458u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 269u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 272u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 284u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 293u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902234u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 461u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 194u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 272u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, free_mem_page_ptr + 284u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 293u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902237u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 464u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 455u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 272u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902240u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 467u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 455u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 284u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 293u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 305u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902243u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 470u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 1991u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 428u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584315u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294902246u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 473u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 431u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 434u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902249u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 476u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 1991u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2000u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 2012u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 2024u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902252u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 479u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 470u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 2000u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902255u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 482u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 437u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 440u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902258u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 485u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 260u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 188u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902261u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 488u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        476u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2036u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902264u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 491u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        470u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2012u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 2024u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 2036u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294902267u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 494u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 479u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 2024u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, free_mem_page_ptr + 2036u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294902270u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 497u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 251u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 311u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 191u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584281u64, 66u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902273u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 500u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 479u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 2012u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902276u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 503u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 488u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2042u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 1967u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 1982u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902279u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 506u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 488u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 419u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, free_mem_page_ptr + 1967u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 1982u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902282u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 11295u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        59u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 59u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 360u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_ext_row_ptr + 11u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 11u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 1551u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_ext_row_ptr + 14u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 14u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 1563u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 62u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 62u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 372u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 65u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 65u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 384u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, next_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 23u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 1539u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 56u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        4509u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 29u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 29u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        2679u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 116u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 116u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 474u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        53u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 4503u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 68u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2355u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 50u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr +
+        53u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 41u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 501u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 1575u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        842u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 194u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584317u64, 66u64, 74u64,
+        1u64, free_mem_page_ptr + 16248u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 74u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2499u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 77u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 77u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2511u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 80u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 80u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2523u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 191u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584303u64, 66u64, 1u64, next_base_row_ptr +
+        191u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584287u64, 66u64, 74u64, 1u64, next_base_row_ptr + 191u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584273u64, 66u64,
+        74u64, 1u64, free_mem_page_ptr + 16479u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 245u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 281474976710656u64, 74u64, 1u64, curr_base_row_ptr + 248u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967296u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 251u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 65536u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 254u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744065119617025u64, 74u64,
+        1u64, free_mem_page_ptr + 13392u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 257u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 281474976710656u64, 74u64, 1u64, curr_base_row_ptr + 260u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967296u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 263u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 65536u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 266u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744065119617025u64, 74u64,
+        1u64, free_mem_page_ptr + 13425u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 269u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 281474976710656u64, 74u64, 1u64, curr_base_row_ptr + 272u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967296u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 275u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 65536u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 278u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744065119617025u64, 74u64,
+        1u64, free_mem_page_ptr + 13458u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 281u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 281474976710656u64, 74u64, 1u64, curr_base_row_ptr + 284u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967296u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 287u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 65536u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 290u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744065119617025u64, 74u64,
+        1u64, free_mem_page_ptr + 13491u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 83u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 83u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2535u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 86u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 86u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2547u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 89u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2559u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 92u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 92u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2571u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 95u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 95u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2583u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 98u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 98u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2595u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 101u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        101u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2607u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 104u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 104u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 2619u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        107u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 107u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2631u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 110u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 110u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2643u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 113u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        113u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2655u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr +
+        20u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2664u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, curr_base_row_ptr + 83u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 600u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        71u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 71u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2487u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        80u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 588u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 119u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 885u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 77u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 576u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 86u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 612u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        98u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 660u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 624u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 101u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 672u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        92u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 636u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 95u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 648u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        50u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 4497u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 77u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 590u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 591u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        80u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 602u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 603u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 83u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr
+        + 614u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 615u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, curr_base_row_ptr + 104u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 684u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        86u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 626u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 627u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr
+        + 638u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 639u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 92u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 651u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        476u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 477u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        95u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 662u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 663u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 215u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 543u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 98u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 674u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 675u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        101u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 686u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 687u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 104u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 107u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 699u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 107u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        110u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 711u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 110u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 113u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 723u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        419u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 17646u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 62u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr +
+        71u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 2871u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 552u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 74u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 564u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 29u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 29u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584319u64,
+        66u64, 1u64, free_mem_page_ptr + 348u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        566u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 567u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 578u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 579u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 107u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 696u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, next_base_row_ptr + 158u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 158u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 164u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 12699u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        119u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 119u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64,
+        1u64, free_mem_page_ptr + 393u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 110u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 708u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 35u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, 0u64, 1u64, 0u64, 1u64, 8u64, 1u64,
+        curr_base_row_ptr + 128u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        4u64, 1u64, curr_base_row_ptr + 125u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 2u64, 1u64, curr_base_row_ptr + 122u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 119u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 462u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 113u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 720u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 158u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 158u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 12690u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584318u64, 66u64, 1u64, free_mem_page_ptr +
+        15777u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 437u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 2u64, 1u64, next_base_row_ptr + 437u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 17634u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, next_ext_row_ptr + 38u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 38u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 12324u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 431u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, 0u64, 1u64, 0u64, 1u64, 2u64,
+        1u64, next_base_row_ptr + 431u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 17625u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 47u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 4491u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 62u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr +
+        2865u64, 11u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 5u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_ext_row_ptr + 11u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 3969u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        191u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584281u64, 66u64, 1u64, free_mem_page_ptr + 15789u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 35u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 1566u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        824u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 2685u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 29u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 29u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 339u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 554u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 1581u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        77u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 566u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1584u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 80u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr
+        + 578u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1587u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 83u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 590u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 1590u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        86u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 602u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1593u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr
+        + 614u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1596u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 92u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 1599u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        95u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 638u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1602u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 1623u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 98u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 1605u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        101u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 662u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1608u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 104u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 674u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 1611u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        107u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 686u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1614u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 110u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 698u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 1617u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        113u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 710u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1620u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 20u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 503u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 56u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 116u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 59u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        113u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 1650u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 179u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 179u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        66u64, 1u64, free_mem_page_ptr + 13011u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 197u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 281474976710656u64, 74u64, 1u64, next_base_row_ptr + 200u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967296u64, 74u64, 66u64, 1u64,
+        next_base_row_ptr + 203u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 65536u64, 74u64, 66u64, 1u64, next_base_row_ptr + 206u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744065119617025u64, 74u64,
+        1u64, free_mem_page_ptr + 15372u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 209u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 281474976710656u64, 74u64, 1u64, next_base_row_ptr + 212u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967296u64, 74u64, 66u64, 1u64,
+        next_base_row_ptr + 215u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 65536u64, 74u64, 66u64, 1u64, next_base_row_ptr + 218u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744065119617025u64, 74u64,
+        1u64, free_mem_page_ptr + 15405u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 221u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 281474976710656u64, 74u64, 1u64, next_base_row_ptr + 224u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967296u64, 74u64, 66u64, 1u64,
+        next_base_row_ptr + 227u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 65536u64, 74u64, 66u64, 1u64, next_base_row_ptr + 230u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744065119617025u64, 74u64,
+        1u64, free_mem_page_ptr + 15438u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 233u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 281474976710656u64, 74u64, 1u64, next_base_row_ptr + 236u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967296u64, 74u64, 66u64, 1u64,
+        next_base_row_ptr + 239u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 65536u64, 74u64, 66u64, 1u64, next_base_row_ptr + 242u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744065119617025u64, 74u64,
+        1u64, free_mem_page_ptr + 15471u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 15762u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, next_base_row_ptr + 407u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 17466u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 428u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584291u64, 66u64, 1u64, free_mem_page_ptr +
+        17727u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 44u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        free_mem_page_ptr + 4485u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 122u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 744u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        428u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584293u64, 66u64, 1u64, free_mem_page_ptr + 17739u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 14u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 9u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 11u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 102u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 1052u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 3738u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1577u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 1578u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        557u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 554u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 1u64, free_mem_page_ptr + 759u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        68u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 566u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 897u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        578u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 1026u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 77u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 987u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        566u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 590u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 1u64, free_mem_page_ptr + 1152u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 83u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        662u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1242u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 80u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 990u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        68u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 554u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 555u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 86u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr
+        + 674u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1245u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 83u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 638u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 993u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        89u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 686u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1248u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 86u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr
+        + 650u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 996u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 92u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 698u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 1251u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        89u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 662u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 999u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 95u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr
+        + 710u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1254u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 92u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 674u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 1002u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 479u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 2u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 563u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 3u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr
+        + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 476u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        476u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 5u64, 66u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 1311u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1625u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 560u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584319u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        476u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584318u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 566u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584317u64, 66u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584316u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        2190u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 98u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 722u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        1u64, free_mem_page_ptr + 1257u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 5u64, 66u64, 1u64, free_mem_page_ptr + 1191u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 95u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr
+        + 686u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1005u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 545u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_ext_row_ptr + 230u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr +
+        20u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 218u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 66u64, 1u64, curr_ext_row_ptr + 233u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 179u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 1323u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 236u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 239u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, curr_ext_row_ptr
+        + 242u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, curr_ext_row_ptr + 245u64, 41u64,
+        3u64, 3u64, 1u64, 66u64, 1u64, curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 224u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2202u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr +
+        179u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 1224u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 98u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 698u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 1008u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        101u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 710u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1011u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 872u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 875u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        curr_base_row_ptr + 878u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        curr_base_row_ptr + 881u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        curr_base_row_ptr + 884u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 1347u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        857u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 860u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, curr_base_row_ptr + 863u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, curr_base_row_ptr + 866u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        curr_base_row_ptr + 869u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 2226u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        104u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 722u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1014u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 998u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1004u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        curr_base_row_ptr + 1010u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        curr_base_row_ptr + 1016u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        curr_base_row_ptr + 1022u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 1359u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1586u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 80u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr
+        + 566u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        563u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 83u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 86u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        566u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        569u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 89u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 2238u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 3u64, 66u64, 1u64, free_mem_page_ptr + 942u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 1001u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1007u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        curr_base_row_ptr + 1013u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        curr_base_row_ptr + 1019u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        curr_base_row_ptr + 1025u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 1371u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1589u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 83u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr
+        + 578u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        563u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 86u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 578u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        578u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        569u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 92u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 578u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 2250u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 218u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 975u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 605u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        80u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 614u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 563u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 992u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 80u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        638u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        569u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 80u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 1383u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1592u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 560u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 86u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 590u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        89u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 590u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 566u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 92u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 590u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        95u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 590u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2262u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 617u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        83u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 626u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 563u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 995u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 83u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        650u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        569u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1244u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 1395u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1595u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        89u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 602u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 563u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 92u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 602u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        95u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 602u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 569u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 98u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 602u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 2274u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 629u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 86u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr
+        + 638u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        563u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 998u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 86u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 662u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1247u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        1407u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 557u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 1598u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 92u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        614u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        563u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 95u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 98u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        614u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        569u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 101u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 2286u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        641u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 560u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1001u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 566u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 89u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 674u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1250u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 1419u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1601u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 95u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr
+        + 626u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        563u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 98u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 101u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        626u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        569u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 104u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 2298u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        653u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 560u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 92u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 662u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1004u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 566u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 92u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1253u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 1431u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1604u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 98u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr
+        + 638u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        563u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 101u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 638u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 104u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        638u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        569u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 107u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 638u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 2310u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        665u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 560u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 95u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 674u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1007u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 566u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 95u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 698u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1256u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 1443u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1607u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 101u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        104u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 650u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 566u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 107u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        110u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 650u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2322u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 677u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        98u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 686u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 563u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 1010u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 98u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        710u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        569u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1259u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 1455u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1610u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        104u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 662u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 563u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 107u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 662u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        110u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 662u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 569u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 113u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 662u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 2334u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        962u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 965u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, curr_base_row_ptr + 968u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, curr_base_row_ptr + 971u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 1464u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        977u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 980u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, curr_base_row_ptr + 983u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, curr_base_row_ptr + 986u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 2343u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 701u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 104u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 710u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1016u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        1470u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 557u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 1616u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 110u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        686u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        563u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 113u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 2349u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        713u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 560u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 107u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 722u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 1473u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1619u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 560u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 113u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 698u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 2352u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        725u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 801u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1622u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 1701u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr +
+        35u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 20u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_ext_row_ptr + 35u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr +
+        95u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 32u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 12135u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 173u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        32u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 1u64, free_mem_page_ptr + 12216u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 12326u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 32u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 167u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 170u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        71u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        12218u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 176u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        free_mem_page_ptr + 12336u64, 11u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        32u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 167u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 12228u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 146u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        66u64, 1u64, free_mem_page_ptr + 12468u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 146u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 12465u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 143u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584319u64, 66u64, 1u64, free_mem_page_ptr +
+        12486u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 47u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 47u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 12564u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        155u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584319u64, 66u64, 1u64, free_mem_page_ptr + 12675u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, next_ext_row_ptr + 65u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 65u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 12924u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 179u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        179u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        13008u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 188u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584318u64, 66u64, 1u64,
+        free_mem_page_ptr + 15753u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584316u64, 66u64, 1u64, free_mem_page_ptr + 15945u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 191u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584303u64, 66u64, 1u64, free_mem_page_ptr +
+        16098u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 191u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584287u64, 66u64, 1u64,
+        free_mem_page_ptr + 16104u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr +
+        86u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 86u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16455u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 89u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16518u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 92u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 92u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16569u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 95u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 95u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16620u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 98u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 98u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16671u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 101u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 101u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 16722u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_ext_row_ptr + 104u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 104u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 16773u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, next_ext_row_ptr + 107u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 107u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 16824u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, next_ext_row_ptr + 110u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 110u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 16875u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 113u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 113u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 16926u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 116u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 116u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 16977u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 119u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 119u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 17028u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 122u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 122u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 17079u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 125u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 125u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 17130u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 128u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 128u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 17181u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 131u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 131u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 17232u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, next_base_row_ptr + 389u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 17310u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 428u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584291u64, 66u64, 1u64, free_mem_page_ptr +
+        17616u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17636u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64,
+        1u64, free_mem_page_ptr + 17697u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 428u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584309u64, 66u64, 1u64, free_mem_page_ptr + 17721u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584319u64, 66u64, 1u64,
+        free_mem_page_ptr + 15825u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 11u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 90u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 141u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 2u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 2u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 153u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 9u64, 1u64, free_mem_page_ptr +
+        92u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 93u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 5u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr
+        + 222u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 9u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, next_base_row_ptr + 11u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 270u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 8u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 8u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 264u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 32u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 32u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2676u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 65u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 381u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        68u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 1046u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 3720u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr
+        + 71u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 3786u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 3795u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        68u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 77u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 3810u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr
+        + 80u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 3813u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 71u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 83u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 3822u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        74u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 71u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 3891u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr
+        + 74u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 3900u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 5085u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        851u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 362u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 5091u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1580u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 1659u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 29u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 336u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        62u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 369u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1577u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 3729u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        74u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 83u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 3846u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr
+        + 74u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 3921u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 5322u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        851u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 374u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 5328u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1577u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        1788u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 122u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, free_mem_page_ptr + 741u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        8u64, 1u64, curr_base_row_ptr + 128u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        1u64, free_mem_page_ptr + 432u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 83u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1577u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 2109u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 5559u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        851u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 386u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 5565u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 77u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1577u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        1914u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 86u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 554u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        1u64, free_mem_page_ptr + 2112u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        80u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1577u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 2034u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 2115u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        92u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 578u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 2118u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 953u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        614u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 2364u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 95u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 590u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 2121u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        68u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 662u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 2385u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 98u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr
+        + 1577u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 2445u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 101u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 2127u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        821u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 638u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 1u64, free_mem_page_ptr + 2376u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 104u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        626u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 2130u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 959u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr
+        + 650u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        2382u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 107u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 638u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        1u64, free_mem_page_ptr + 2133u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 992u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2387u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 2388u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2357u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        free_mem_page_ptr + 3108u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2357u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584318u64, 66u64, 1u64, free_mem_page_ptr + 3186u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 110u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 2136u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        761u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 899u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1028u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, free_mem_page_ptr + 1154u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        curr_base_row_ptr + 893u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 1335u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1661u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1790u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1916u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, free_mem_page_ptr + 2036u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        curr_base_row_ptr + 896u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 2214u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        995u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 674u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 1u64, free_mem_page_ptr + 2394u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 113u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        662u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 2139u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 602u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 1227u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        833u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 1u64, free_mem_page_ptr + 2400u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584316u64, 66u64, 1u64, free_mem_page_ptr + 2073u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 1230u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        1028u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 698u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 1u64, free_mem_page_ptr + 2406u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 224u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2106u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr
+        + 626u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1233u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1031u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 710u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 2412u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        77u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 638u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 1236u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 1034u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        722u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 2418u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 80u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 1239u64, 11u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        8u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 8u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 8u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr
+        + 8u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 14u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 74u64, 1u64, curr_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 1u64, curr_base_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64,
+        1u64, curr_base_row_ptr + 77u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 4155u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, next_ext_row_ptr + 23u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, free_mem_page_ptr + 4317u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 119u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        128u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4323u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 125u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 131u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 122u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        134u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4353u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 8u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, challenges_ptr + 8u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        8u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 14u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 1u64, curr_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64,
+        1u64, curr_base_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 4140u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        125u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 134u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4356u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, challenges_ptr + 8u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        8u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 14u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 1u64, curr_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 4125u64, 11u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        8u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 14u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        1u64, free_mem_page_ptr + 4110u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2489u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584318u64, 66u64, 1u64, free_mem_page_ptr + 4395u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 17u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 17u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11697u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 98u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 68u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 101u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        1u64, challenges_ptr + 104u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        74u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 107u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 77u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, challenges_ptr + 110u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 80u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 113u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        83u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 116u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 86u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, challenges_ptr + 119u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 122u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        92u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 125u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 95u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 11955u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 98u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr +
+        101u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 71u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 104u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 107u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        77u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 110u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 80u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11904u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, challenges_ptr + 32u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, challenges_ptr + 167u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 12195u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 170u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 1u64, free_mem_page_ptr + 12204u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 12230u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, challenges_ptr + 170u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 12237u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 12326u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        12230u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 12218u64, 41u64,
+        3u64, 3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, challenges_ptr + 176u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 12348u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 12197u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr
+        + 170u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 71u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 173u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 6u64, 74u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, challenges_ptr + 176u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 12306u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 12230u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 12206u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 173u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 12318u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        143u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 143u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64,
+        1u64, free_mem_page_ptr + 12537u64, 11u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr
+        + 38u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, next_base_row_ptr + 158u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 12744u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr +
+        50u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 12759u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 155u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        next_base_row_ptr + 155u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 12897u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        13013u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 176u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584305u64, 66u64, 74u64,
+        1u64, free_mem_page_ptr + 13023u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 173u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 173u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 13047u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 176u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584305u64, 66u64, 1u64, free_mem_page_ptr +
+        13020u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 71u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 13113u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        191u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584281u64, 66u64, 1u64, free_mem_page_ptr + 15699u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584316u64, 66u64, 1u64, free_mem_page_ptr +
+        15705u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 74u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 16398u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        197u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        281474976710656u64, 74u64, 1u64, curr_base_row_ptr + 200u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967296u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 203u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 65536u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 206u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744065119617025u64, 74u64,
+        1u64, free_mem_page_ptr + 13224u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 209u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 281474976710656u64, 74u64, 1u64, curr_base_row_ptr + 212u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967296u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 215u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 65536u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 218u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744065119617025u64, 74u64,
+        1u64, free_mem_page_ptr + 13257u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 221u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 281474976710656u64, 74u64, 1u64, curr_base_row_ptr + 224u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967296u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 227u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 65536u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 230u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744065119617025u64, 74u64,
+        1u64, free_mem_page_ptr + 13290u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 233u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 281474976710656u64, 74u64, 1u64, curr_base_row_ptr + 236u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967296u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 239u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 65536u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 242u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744065119617025u64, 74u64,
+        1u64, free_mem_page_ptr + 13323u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 15707u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        15701u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 15795u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 188u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        curr_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584319u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 15837u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 128u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 311u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 311u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 15891u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 131u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 314u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 314u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 15894u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, challenges_ptr + 134u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 317u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 317u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 15900u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, challenges_ptr + 137u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 320u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 320u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 15906u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, challenges_ptr + 140u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 323u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 323u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 15912u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, challenges_ptr + 143u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 326u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 326u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 15918u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 98u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        15374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 101u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 15407u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, challenges_ptr + 104u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 15440u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 107u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        15473u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 110u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 293u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, challenges_ptr + 113u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 296u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 116u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        299u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 119u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 302u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, challenges_ptr + 122u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 305u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 125u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        308u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        16188u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 77u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 77u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 16119u64, 11u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        98u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 15374u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, challenges_ptr + 101u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 15407u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 104u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        15440u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 107u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 15473u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, challenges_ptr + 110u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 293u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 16146u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr +
+        80u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 80u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16221u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 83u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 83u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16299u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 191u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584273u64, 66u64, 1u64, free_mem_page_ptr + 16308u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 134u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 134u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 17340u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 137u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 137u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 17388u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 158u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 395u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr
+        + 161u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 401u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 17373u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 158u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 392u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr
+        + 161u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 398u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 17382u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, next_ext_row_ptr + 140u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 140u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 17502u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 422u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        422u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 17670u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 17627u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        17688u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 443u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        free_mem_page_ptr + 17748u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        443u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584319u64, 66u64, 1u64, free_mem_page_ptr + 17754u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 944u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 443u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 17763u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        443u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 17769u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 422u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 17664u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        443u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 443u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 17946u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr +
+        17627u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 17796u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 146u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 146u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 17994u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 128u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 729u64, 11u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 125u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 735u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        38u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 4566u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 41u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        4515u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 122u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        free_mem_page_ptr + 399u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr + 15924u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584319u64, 66u64, 1u64, free_mem_page_ptr +
+        15927u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 194u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584318u64, 66u64, 1u64,
+        free_mem_page_ptr + 15933u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        428u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584317u64, 66u64, 1u64, free_mem_page_ptr + 17703u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 428u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584307u64, 66u64, 1u64, free_mem_page_ptr +
+        17709u64, 11u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 194u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584317u64, 66u64, 1u64,
+        free_mem_page_ptr + 15939u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        188u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584319u64, 66u64, 1u64, free_mem_page_ptr + 15750u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        15816u64, 11u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 23u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 503u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        56u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 116u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 59u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 113u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 528u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 503u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 56u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 116u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 59u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        113u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 1641u64, 11u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        26u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 2873u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 1u64, 66u64, 1u64, challenges_ptr + 65u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, next_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 2898u64, 11u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr
+        + 26u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 2867u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 65u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 68u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        3126u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 66u64, 1u64, challenges_ptr +
+        65u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 3150u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 2u64, 66u64, 1u64, challenges_ptr + 65u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 3192u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, challenges_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 2873u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 65u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 119u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4212u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        122u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 68u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, free_mem_page_ptr + 4218u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 125u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4236u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2873u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        65u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4254u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 128u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4257u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2873u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 71u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 66u64, 1u64, challenges_ptr +
+        65u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4275u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2873u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 2u64, 66u64, 1u64, challenges_ptr + 65u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4296u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 2u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 2u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr
+        + 4294902285u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 20u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 20u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        4294902288u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 104u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 11u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 14u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 11u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        92u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294902291u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 17u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 17u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902294u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 17u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, next_base_row_ptr + 17u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, next_base_row_ptr + 5u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902297u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 17u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        5u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902300u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 17u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 104u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 143u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902303u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 23u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64,
+        1u64, free_mem_page_ptr + 4294902306u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        26u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 26u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 4294902309u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 641u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        68u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1568u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 974u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2357u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        626u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 974u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 2678u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 644u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2687u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 119u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 674u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        59u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 59u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 66u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 677u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 29u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        383u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        680u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 731u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 701u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 395u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        83u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1577u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 698u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 740u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 749u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 707u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2357u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 554u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 710u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 3722u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        1049u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 719u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 119u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 3740u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 716u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4294967296u64, 1u64,
+        next_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 722u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 728u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 3788u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 3797u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 827u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2357u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 578u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 836u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 3812u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 3815u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 3824u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        1049u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 3893u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 3902u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        3722u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 5087u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 5093u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 341u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294902312u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 614u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 641u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1580u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 632u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 761u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1661u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 665u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2687u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        62u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 338u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584319u64, 66u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 29u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        371u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        677u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 362u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 731u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 701u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 98u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 2u64, 74u64, 1u64, curr_base_row_ptr + 119u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, free_mem_page_ptr + 602u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 86u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        554u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        698u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 374u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 740u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 749u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 707u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 710u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        3731u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 3740u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 1061u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, curr_base_row_ptr + 722u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 728u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 827u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2489u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 590u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 836u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 71u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 77u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 80u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 3848u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 3815u64, 41u64,
+        3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 3824u64, 41u64, 3u64, 3u64,
+        1u64, 66u64, 74u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 830u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        71u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 3923u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 3893u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, free_mem_page_ptr + 3902u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 5324u64,
+        41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 5330u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 2678u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902315u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 641u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1583u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        899u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1790u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 35u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 743u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        2u64, 1u64, curr_base_row_ptr + 125u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 434u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        32u64, 1u64, curr_base_row_ptr + 131u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 128u64, 1u64, curr_base_row_ptr + 134u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 674u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 65u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1568u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2357u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 677u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 731u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 701u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        887u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2357u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 119u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2111u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        566u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        698u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 386u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 740u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 749u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 707u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 710u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        68u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 3740u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 716u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 1583u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 722u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 728u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 827u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2501u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 602u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 836u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 74u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 77u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, curr_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 80u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        83u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 3848u64,
+        41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        74u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 3788u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 3923u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        74u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 3812u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 5561u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 5567u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        next_base_row_ptr + 35u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1568u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, next_base_row_ptr + 26u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902318u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 350u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 641u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1586u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 620u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 350u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1028u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1916u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 650u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2681u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 1064u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        29u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1568u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 686u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 2489u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 677u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 647u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 350u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 350u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 731u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2681u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 701u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 887u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 2489u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 119u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2114u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        92u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 578u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 698u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 2681u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 740u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2681u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 749u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2681u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 707u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 710u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 719u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 716u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1586u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 722u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2681u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 725u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2681u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 728u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2681u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2681u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 737u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2681u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2681u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 827u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 989u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 836u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 989u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2513u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 839u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 593u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 350u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        350u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 845u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2681u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 851u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2681u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294902321u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 614u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 791u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 641u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1595u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        791u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 632u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2366u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 953u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 86u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1577u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2489u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 665u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2501u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 1070u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2501u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2525u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 677u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2501u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        791u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 791u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 731u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1250u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 701u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        887u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2525u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 119u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2123u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 698u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2501u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 740u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2387u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 749u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2447u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 707u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        581u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 710u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 581u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2501u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2681u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 716u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1595u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 722u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 593u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        593u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 728u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 593u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2513u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        593u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2513u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2513u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 827u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        998u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 836u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 998u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2549u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        629u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 791u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 791u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2537u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 851u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2537u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 2357u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902324u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        794u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 641u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1598u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 794u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        854u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 818u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1577u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2501u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2513u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 887u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2513u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2537u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 677u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2513u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 581u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        794u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 794u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 731u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1253u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 701u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2129u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 692u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 698u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2513u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 740u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        71u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 674u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 749u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 101u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 554u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 707u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        593u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 710u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 593u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2513u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        569u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 716u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1598u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 722u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 605u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        605u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 728u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 605u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2525u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        605u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2525u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2525u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 827u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1001u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 836u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1001u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2561u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        641u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 794u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 794u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2549u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 851u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2549u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 2489u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902327u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        464u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 641u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1601u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 464u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2378u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 821u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 92u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1577u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2513u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2525u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 890u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2525u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2549u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 677u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2525u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 593u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        464u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 464u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 731u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1256u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 701u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2132u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 692u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2681u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 698u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2525u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 740u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        74u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 686u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 749u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 104u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 707u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        605u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 710u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 605u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2525u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        581u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 716u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1601u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 722u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 617u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        617u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 728u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 617u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2537u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        617u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2537u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2537u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 827u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1004u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 836u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1004u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2573u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        653u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 464u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 464u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2561u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 851u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2561u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 2501u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902330u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1313u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 641u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1604u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2192u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2384u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 959u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 95u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1577u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2525u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2537u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2537u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2561u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 677u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2537u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 605u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2192u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1313u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 731u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1259u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 701u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2135u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 692u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1193u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 698u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2537u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 740u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        77u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 698u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 749u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 107u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 578u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 707u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        617u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 710u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 617u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2537u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        593u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 716u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1604u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 722u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 629u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        629u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 728u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 629u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2549u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        629u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2549u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2549u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 827u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1007u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 836u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1007u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2585u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        665u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2192u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1313u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2573u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 851u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2573u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 2513u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902333u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1385u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 641u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1622u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2264u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2420u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1034u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 113u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1577u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2597u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2609u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 593u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2609u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2633u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 677u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2609u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 677u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2252u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1373u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 701u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1241u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 698u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2609u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 707u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 689u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 710u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        689u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 713u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2609u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 716u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1622u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 722u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 701u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 701u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 728u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        701u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2621u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 701u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 743u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2621u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2621u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 827u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 836u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 830u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2657u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 479u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2264u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1385u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2645u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 851u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2645u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 2585u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 26u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902336u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 953u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        953u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 632u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1586u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2402u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 644u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        479u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 647u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 974u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 974u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        974u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 974u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 4397u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 851u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        4397u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr +
+        4294902339u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 614u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 995u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 995u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1601u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 560u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2501u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 647u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 992u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 992u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        992u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 992u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 4294902342u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        833u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 620u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 833u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1604u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 2513u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        995u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 995u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 995u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        995u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr +
+        4294902345u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 614u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1028u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1028u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1607u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 566u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2525u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 647u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 833u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 833u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        833u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 833u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 4294902348u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        1031u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 620u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1031u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1610u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 2537u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        1028u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1028u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 1028u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        1028u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr +
+        4294902351u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 614u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1034u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1034u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1613u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 953u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2549u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 647u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 1031u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1031u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        1031u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1031u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 4294902354u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1625u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 626u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 992u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2597u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, free_mem_page_ptr + 4294902357u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 1034u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 2657u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902360u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        395u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 11297u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902363u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 809u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902366u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        791u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 11297u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902369u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 794u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902372u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        464u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 11297u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902375u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902378u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 11297u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902381u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902384u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        350u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 11297u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902387u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902390u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 12470u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        12467u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr +
+        4294902393u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 12470u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 149u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        next_base_row_ptr + 143u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902396u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 143u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        143u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 12488u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902399u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 155u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, curr_base_row_ptr +
+        155u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 12677u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902402u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 164u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 12701u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902405u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 12692u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        12701u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr +
+        4294902408u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 12701u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, next_base_row_ptr + 155u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 1u64,
+        next_base_row_ptr + 161u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 161u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 4294902411u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 12701u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 167u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 167u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 4294902414u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 12701u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 170u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 170u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        4294902417u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 13013u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 13010u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, free_mem_page_ptr + 4294902420u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 13025u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 182u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 182u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 4294902423u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 13025u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 185u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 185u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 4294902426u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 13013u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 13049u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 1u64, curr_base_row_ptr
+        + 176u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584288u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 13022u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902429u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 1076u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584317u64, 66u64, 74u64, 1u64, next_base_row_ptr + 194u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902432u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 15701u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 15707u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 194u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        66u64, 74u64, 1u64, free_mem_page_ptr + 4294902435u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 1091u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 15791u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902438u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 15797u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        191u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 191u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 4294902441u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 15797u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 188u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 4294902444u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1079u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 15779u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        next_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902447u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1094u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        188u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902450u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 15839u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 15755u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        next_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902453u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 61402u64, 1u64, free_mem_page_ptr + 13394u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 17845u64, 1u64, free_mem_page_ptr +
+        13427u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        26798u64, 1u64, free_mem_page_ptr + 13460u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 59689u64, 1u64, free_mem_page_ptr +
+        13493u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        12021u64, 1u64, curr_base_row_ptr + 908u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 40901u64, 1u64, curr_base_row_ptr + 911u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 41351u64,
+        1u64, curr_base_row_ptr + 914u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 27521u64, 1u64, curr_base_row_ptr + 917u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 56951u64, 1u64,
+        curr_base_row_ptr + 920u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 12034u64, 1u64, curr_base_row_ptr + 923u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 53865u64, 1u64,
+        curr_base_row_ptr + 926u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 43244u64, 1u64, curr_base_row_ptr + 929u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 7454u64, 1u64,
+        curr_base_row_ptr + 932u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 33823u64, 1u64, curr_base_row_ptr + 935u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 28750u64, 1u64,
+        curr_base_row_ptr + 938u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 1108u64, 1u64, curr_base_row_ptr + 941u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 341u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 15374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902456u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 1108u64, 1u64, free_mem_page_ptr + 13394u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 61402u64, 1u64, free_mem_page_ptr +
+        13427u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        17845u64, 1u64, free_mem_page_ptr + 13460u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 26798u64, 1u64, free_mem_page_ptr +
+        13493u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        59689u64, 1u64, curr_base_row_ptr + 908u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 12021u64, 1u64, curr_base_row_ptr + 911u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 40901u64,
+        1u64, curr_base_row_ptr + 914u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 41351u64, 1u64, curr_base_row_ptr + 917u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 27521u64, 1u64,
+        curr_base_row_ptr + 920u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 56951u64, 1u64, curr_base_row_ptr + 923u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 12034u64, 1u64,
+        curr_base_row_ptr + 926u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 53865u64, 1u64, curr_base_row_ptr + 929u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 43244u64, 1u64,
+        curr_base_row_ptr + 932u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 7454u64, 1u64, curr_base_row_ptr + 935u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 33823u64, 1u64,
+        curr_base_row_ptr + 938u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 28750u64, 1u64, curr_base_row_ptr + 941u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 344u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 15407u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902459u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 28750u64, 1u64, free_mem_page_ptr + 13394u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1108u64, 1u64, free_mem_page_ptr + 13427u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 61402u64,
+        1u64, free_mem_page_ptr + 13460u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 17845u64, 1u64, free_mem_page_ptr + 13493u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 26798u64, 1u64,
+        curr_base_row_ptr + 908u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 59689u64, 1u64, curr_base_row_ptr + 911u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 12021u64, 1u64,
+        curr_base_row_ptr + 914u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 40901u64, 1u64, curr_base_row_ptr + 917u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 41351u64, 1u64,
+        curr_base_row_ptr + 920u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 27521u64, 1u64, curr_base_row_ptr + 923u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 56951u64, 1u64,
+        curr_base_row_ptr + 926u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 12034u64, 1u64, curr_base_row_ptr + 929u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 53865u64, 1u64,
+        curr_base_row_ptr + 932u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 43244u64, 1u64, curr_base_row_ptr + 935u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 7454u64, 1u64,
+        curr_base_row_ptr + 938u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 33823u64, 1u64, curr_base_row_ptr + 941u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 347u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 15440u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902462u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 33823u64, 1u64, free_mem_page_ptr + 13394u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 28750u64, 1u64, free_mem_page_ptr +
+        13427u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        1108u64, 1u64, free_mem_page_ptr + 13460u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 61402u64, 1u64, free_mem_page_ptr +
+        13493u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        17845u64, 1u64, curr_base_row_ptr + 908u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 26798u64, 1u64, curr_base_row_ptr + 911u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 59689u64,
+        1u64, curr_base_row_ptr + 914u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 12021u64, 1u64, curr_base_row_ptr + 917u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 40901u64, 1u64,
+        curr_base_row_ptr + 920u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 41351u64, 1u64, curr_base_row_ptr + 923u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 27521u64, 1u64,
+        curr_base_row_ptr + 926u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 56951u64, 1u64, curr_base_row_ptr + 929u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 12034u64, 1u64,
+        curr_base_row_ptr + 932u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 53865u64, 1u64, curr_base_row_ptr + 935u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 43244u64, 1u64,
+        curr_base_row_ptr + 938u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 7454u64, 1u64, curr_base_row_ptr + 941u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 350u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 15473u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902465u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 7454u64, 1u64, free_mem_page_ptr + 13394u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 33823u64, 1u64, free_mem_page_ptr +
+        13427u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        28750u64, 1u64, free_mem_page_ptr + 13460u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1108u64, 1u64, free_mem_page_ptr + 13493u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 61402u64,
+        1u64, curr_base_row_ptr + 908u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 17845u64, 1u64, curr_base_row_ptr + 911u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 26798u64, 1u64,
+        curr_base_row_ptr + 914u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 59689u64, 1u64, curr_base_row_ptr + 917u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 12021u64, 1u64,
+        curr_base_row_ptr + 920u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 40901u64, 1u64, curr_base_row_ptr + 923u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 41351u64, 1u64,
+        curr_base_row_ptr + 926u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 27521u64, 1u64, curr_base_row_ptr + 929u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 56951u64, 1u64,
+        curr_base_row_ptr + 932u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 12034u64, 1u64, curr_base_row_ptr + 935u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 53865u64, 1u64,
+        curr_base_row_ptr + 938u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 43244u64, 1u64, curr_base_row_ptr + 941u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 353u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 293u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902468u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 43244u64, 1u64, free_mem_page_ptr + 13394u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 7454u64, 1u64, free_mem_page_ptr + 13427u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 33823u64,
+        1u64, free_mem_page_ptr + 13460u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 28750u64, 1u64, free_mem_page_ptr + 13493u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1108u64, 1u64,
+        curr_base_row_ptr + 908u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 61402u64, 1u64, curr_base_row_ptr + 911u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 17845u64, 1u64,
+        curr_base_row_ptr + 914u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 26798u64, 1u64, curr_base_row_ptr + 917u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 59689u64, 1u64,
+        curr_base_row_ptr + 920u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 12021u64, 1u64, curr_base_row_ptr + 923u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 40901u64, 1u64,
+        curr_base_row_ptr + 926u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 41351u64, 1u64, curr_base_row_ptr + 929u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 27521u64, 1u64,
+        curr_base_row_ptr + 932u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 56951u64, 1u64, curr_base_row_ptr + 935u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 12034u64, 1u64,
+        curr_base_row_ptr + 938u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 53865u64, 1u64, curr_base_row_ptr + 941u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 356u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 296u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902471u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 53865u64, 1u64, free_mem_page_ptr + 13394u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 43244u64, 1u64, free_mem_page_ptr +
+        13427u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        7454u64, 1u64, free_mem_page_ptr + 13460u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 33823u64, 1u64, free_mem_page_ptr +
+        13493u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        28750u64, 1u64, curr_base_row_ptr + 908u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1108u64, 1u64, curr_base_row_ptr + 911u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 61402u64,
+        1u64, curr_base_row_ptr + 914u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 17845u64, 1u64, curr_base_row_ptr + 917u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 26798u64, 1u64,
+        curr_base_row_ptr + 920u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 59689u64, 1u64, curr_base_row_ptr + 923u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 12021u64, 1u64,
+        curr_base_row_ptr + 926u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 40901u64, 1u64, curr_base_row_ptr + 929u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 41351u64, 1u64,
+        curr_base_row_ptr + 932u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 27521u64, 1u64, curr_base_row_ptr + 935u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 56951u64, 1u64,
+        curr_base_row_ptr + 938u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 12034u64, 1u64, curr_base_row_ptr + 941u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 359u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 299u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902474u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 12034u64, 1u64, free_mem_page_ptr + 13394u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 53865u64, 1u64, free_mem_page_ptr +
+        13427u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        43244u64, 1u64, free_mem_page_ptr + 13460u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 7454u64, 1u64, free_mem_page_ptr + 13493u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 33823u64,
+        1u64, curr_base_row_ptr + 908u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 28750u64, 1u64, curr_base_row_ptr + 911u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1108u64, 1u64,
+        curr_base_row_ptr + 914u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 61402u64, 1u64, curr_base_row_ptr + 917u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 17845u64, 1u64,
+        curr_base_row_ptr + 920u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 26798u64, 1u64, curr_base_row_ptr + 923u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 59689u64, 1u64,
+        curr_base_row_ptr + 926u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 12021u64, 1u64, curr_base_row_ptr + 929u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 40901u64, 1u64,
+        curr_base_row_ptr + 932u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 41351u64, 1u64, curr_base_row_ptr + 935u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 27521u64, 1u64,
+        curr_base_row_ptr + 938u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 56951u64, 1u64, curr_base_row_ptr + 941u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 362u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 302u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902477u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 56951u64, 1u64, free_mem_page_ptr + 13394u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 12034u64, 1u64, free_mem_page_ptr +
+        13427u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        53865u64, 1u64, free_mem_page_ptr + 13460u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 43244u64, 1u64, free_mem_page_ptr +
+        13493u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        7454u64, 1u64, curr_base_row_ptr + 908u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 33823u64, 1u64, curr_base_row_ptr + 911u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 28750u64, 1u64,
+        curr_base_row_ptr + 914u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 1108u64, 1u64, curr_base_row_ptr + 917u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 61402u64, 1u64,
+        curr_base_row_ptr + 920u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 17845u64, 1u64, curr_base_row_ptr + 923u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 26798u64, 1u64,
+        curr_base_row_ptr + 926u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 59689u64, 1u64, curr_base_row_ptr + 929u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 12021u64, 1u64,
+        curr_base_row_ptr + 932u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 40901u64, 1u64, curr_base_row_ptr + 935u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 41351u64, 1u64,
+        curr_base_row_ptr + 938u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 27521u64, 1u64, curr_base_row_ptr + 941u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 365u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 305u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902480u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 27521u64, 1u64, free_mem_page_ptr + 13394u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 56951u64, 1u64, free_mem_page_ptr +
+        13427u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        12034u64, 1u64, free_mem_page_ptr + 13460u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 53865u64, 1u64, free_mem_page_ptr +
+        13493u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        43244u64, 1u64, curr_base_row_ptr + 908u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 7454u64, 1u64, curr_base_row_ptr + 911u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 33823u64,
+        1u64, curr_base_row_ptr + 914u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 28750u64, 1u64, curr_base_row_ptr + 917u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1108u64, 1u64,
+        curr_base_row_ptr + 920u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 61402u64, 1u64, curr_base_row_ptr + 923u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 17845u64, 1u64,
+        curr_base_row_ptr + 926u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 26798u64, 1u64, curr_base_row_ptr + 929u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 59689u64, 1u64,
+        curr_base_row_ptr + 932u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 12021u64, 1u64, curr_base_row_ptr + 935u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 40901u64, 1u64,
+        curr_base_row_ptr + 938u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 41351u64, 1u64, curr_base_row_ptr + 941u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 368u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 308u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902483u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 41351u64, 1u64, free_mem_page_ptr + 13394u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 27521u64, 1u64, free_mem_page_ptr +
+        13427u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        56951u64, 1u64, free_mem_page_ptr + 13460u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 12034u64, 1u64, free_mem_page_ptr +
+        13493u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        53865u64, 1u64, curr_base_row_ptr + 908u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 43244u64, 1u64, curr_base_row_ptr + 911u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 7454u64,
+        1u64, curr_base_row_ptr + 914u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 33823u64, 1u64, curr_base_row_ptr + 917u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 28750u64, 1u64,
+        curr_base_row_ptr + 920u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 1108u64, 1u64, curr_base_row_ptr + 923u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 61402u64, 1u64,
+        curr_base_row_ptr + 926u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 17845u64, 1u64, curr_base_row_ptr + 929u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 26798u64, 1u64,
+        curr_base_row_ptr + 932u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 59689u64, 1u64, curr_base_row_ptr + 935u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 12021u64, 1u64,
+        curr_base_row_ptr + 938u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 40901u64, 1u64, curr_base_row_ptr + 941u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 371u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 311u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902486u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 40901u64, 1u64, free_mem_page_ptr + 13394u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 41351u64, 1u64, free_mem_page_ptr +
+        13427u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        27521u64, 1u64, free_mem_page_ptr + 13460u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 56951u64, 1u64, free_mem_page_ptr +
+        13493u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        12034u64, 1u64, curr_base_row_ptr + 908u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 53865u64, 1u64, curr_base_row_ptr + 911u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 43244u64,
+        1u64, curr_base_row_ptr + 914u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 7454u64, 1u64, curr_base_row_ptr + 917u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 33823u64, 1u64,
+        curr_base_row_ptr + 920u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 28750u64, 1u64, curr_base_row_ptr + 923u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1108u64, 1u64,
+        curr_base_row_ptr + 926u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 61402u64, 1u64, curr_base_row_ptr + 929u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 17845u64, 1u64,
+        curr_base_row_ptr + 932u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 26798u64, 1u64, curr_base_row_ptr + 935u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 59689u64, 1u64,
+        curr_base_row_ptr + 938u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 12021u64, 1u64, curr_base_row_ptr + 941u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 374u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 314u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902489u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 12021u64, 1u64, free_mem_page_ptr + 13394u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 40901u64, 1u64, free_mem_page_ptr +
+        13427u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        41351u64, 1u64, free_mem_page_ptr + 13460u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 27521u64, 1u64, free_mem_page_ptr +
+        13493u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        56951u64, 1u64, curr_base_row_ptr + 908u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 12034u64, 1u64, curr_base_row_ptr + 911u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 53865u64,
+        1u64, curr_base_row_ptr + 914u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 43244u64, 1u64, curr_base_row_ptr + 917u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 7454u64, 1u64,
+        curr_base_row_ptr + 920u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 33823u64, 1u64, curr_base_row_ptr + 923u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 28750u64, 1u64,
+        curr_base_row_ptr + 926u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 1108u64, 1u64, curr_base_row_ptr + 929u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 61402u64, 1u64,
+        curr_base_row_ptr + 932u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 17845u64, 1u64, curr_base_row_ptr + 935u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 26798u64, 1u64,
+        curr_base_row_ptr + 938u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 59689u64, 1u64, curr_base_row_ptr + 941u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 377u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 317u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902492u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 59689u64, 1u64, free_mem_page_ptr + 13394u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 12021u64, 1u64, free_mem_page_ptr +
+        13427u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        40901u64, 1u64, free_mem_page_ptr + 13460u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 41351u64, 1u64, free_mem_page_ptr +
+        13493u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        27521u64, 1u64, curr_base_row_ptr + 908u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 56951u64, 1u64, curr_base_row_ptr + 911u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 12034u64,
+        1u64, curr_base_row_ptr + 914u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 53865u64, 1u64, curr_base_row_ptr + 917u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 43244u64, 1u64,
+        curr_base_row_ptr + 920u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 7454u64, 1u64, curr_base_row_ptr + 923u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 33823u64, 1u64,
+        curr_base_row_ptr + 926u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 28750u64, 1u64, curr_base_row_ptr + 929u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1108u64, 1u64,
+        curr_base_row_ptr + 932u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 61402u64, 1u64, curr_base_row_ptr + 935u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 17845u64, 1u64,
+        curr_base_row_ptr + 938u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 26798u64, 1u64, curr_base_row_ptr + 941u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 380u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 320u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902495u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 26798u64, 1u64, free_mem_page_ptr + 13394u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 59689u64, 1u64, free_mem_page_ptr +
+        13427u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        12021u64, 1u64, free_mem_page_ptr + 13460u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 40901u64, 1u64, free_mem_page_ptr +
+        13493u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        41351u64, 1u64, curr_base_row_ptr + 908u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 27521u64, 1u64, curr_base_row_ptr + 911u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 56951u64,
+        1u64, curr_base_row_ptr + 914u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 12034u64, 1u64, curr_base_row_ptr + 917u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 53865u64, 1u64,
+        curr_base_row_ptr + 920u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 43244u64, 1u64, curr_base_row_ptr + 923u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 7454u64, 1u64,
+        curr_base_row_ptr + 926u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 33823u64, 1u64, curr_base_row_ptr + 929u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 28750u64, 1u64,
+        curr_base_row_ptr + 932u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 1108u64, 1u64, curr_base_row_ptr + 935u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 61402u64, 1u64,
+        curr_base_row_ptr + 938u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 17845u64, 1u64, curr_base_row_ptr + 941u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 383u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 323u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902498u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 17845u64, 1u64, free_mem_page_ptr + 13394u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 26798u64, 1u64, free_mem_page_ptr +
+        13427u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        59689u64, 1u64, free_mem_page_ptr + 13460u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 12021u64, 1u64, free_mem_page_ptr +
+        13493u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        40901u64, 1u64, curr_base_row_ptr + 908u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 41351u64, 1u64, curr_base_row_ptr + 911u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 27521u64,
+        1u64, curr_base_row_ptr + 914u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 56951u64, 1u64, curr_base_row_ptr + 917u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 12034u64, 1u64,
+        curr_base_row_ptr + 920u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 53865u64, 1u64, curr_base_row_ptr + 923u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 43244u64, 1u64,
+        curr_base_row_ptr + 926u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 7454u64, 1u64, curr_base_row_ptr + 929u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 33823u64, 1u64,
+        curr_base_row_ptr + 932u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 28750u64, 1u64, curr_base_row_ptr + 935u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1108u64, 1u64,
+        curr_base_row_ptr + 938u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 61402u64, 1u64, curr_base_row_ptr + 941u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 386u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 326u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902501u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 389u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        17312u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr +
+        4294902504u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 407u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17468u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 4294902507u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 407u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        410u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 17468u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 410u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 410u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902510u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 419u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        431u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 17618u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902513u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 419u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 437u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902516u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 17648u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        428u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 428u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 4294902519u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 17648u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 431u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 17618u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 17672u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902522u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 17648u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        437u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 17672u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902525u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17648u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 17618u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 17627u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 17690u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902528u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 17648u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        17636u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 17699u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902531u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 944u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 17750u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 17756u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 443u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902534u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 17765u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        17756u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 17771u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902537u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 956u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 17690u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 17636u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 17771u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902540u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 956u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        17627u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 17699u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 443u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294902543u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 1085u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 419u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 66u64, 74u64, 1u64, curr_base_row_ptr + 443u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584319u64,
+        66u64, 74u64, 1u64, free_mem_page_ptr + 4294902546u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 1085u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 419u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 443u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294902549u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 17648u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        1082u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 443u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 2u64, 1u64, next_base_row_ptr + 443u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 17627u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 17636u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 74u64, 1u64, free_mem_page_ptr + 4294902552u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 1103u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 443u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        17666u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        4294902555u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1043u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 431u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, next_base_row_ptr + 443u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 443u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        4294902558u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1088u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 431u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        431u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        4294902561u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1088u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17699u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 443u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 17948u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        4294902564u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1088u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17636u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 443u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 1106u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        4294902567u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17648u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1037u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 17729u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        1u64, curr_base_row_ptr + 443u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, next_base_row_ptr + 443u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 17798u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 4294902570u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 509u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr +
+        731u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 737u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902573u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 512u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr +
+        4568u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4517u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902576u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 515u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr +
+        4568u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 41u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902579u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 518u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        38u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4517u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4487u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902582u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 521u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 512u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4487u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902585u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 524u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        515u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4487u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902588u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 527u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        509u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 122u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902591u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 530u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr +
+        731u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 125u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902594u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 533u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        530u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 746u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902597u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 536u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        518u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4493u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902600u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 539u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        518u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 47u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902603u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 542u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        521u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4493u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902606u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 545u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        521u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 47u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902609u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 548u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        524u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4493u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902612u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 551u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        509u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 746u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902615u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 554u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        524u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 47u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902618u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        551u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 119u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902621u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        527u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 887u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902624u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        527u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 119u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902627u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        533u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 887u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902630u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        533u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 119u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902633u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 572u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        38u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 41u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4487u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902636u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 575u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 539u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4499u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902639u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 578u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        536u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4499u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902642u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 581u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        542u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4499u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902645u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 584u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        545u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4499u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902648u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 587u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        515u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 44u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902651u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 590u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        548u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4499u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902654u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 593u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        554u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4499u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902657u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 596u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        512u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 44u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902660u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 599u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        572u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4493u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902663u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 602u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        536u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 50u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902666u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 605u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        545u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 50u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902669u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 608u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        542u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 50u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902672u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 611u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        548u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 50u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902675u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        599u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4499u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294902678u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 617u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 554u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 50u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902681u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 575u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294902684u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 623u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 590u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902687u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 539u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 50u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902690u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 629u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 581u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902693u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 602u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294902696u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 635u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 584u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902699u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 638u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 593u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902702u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 641u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 578u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902705u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 644u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 623u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902708u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 575u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 53u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902711u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 629u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902714u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 653u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 587u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4493u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902717u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 656u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        608u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902720u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 659u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        572u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 47u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4499u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294902723u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 662u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 596u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 4493u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902726u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 635u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902729u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 668u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 587u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 47u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902732u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        659u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902735u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        578u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 53u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902738u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 677u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 605u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902741u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 638u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902744u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 683u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 596u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 47u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902747u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        656u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902750u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 689u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        611u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902753u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        617u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902756u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 695u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 128u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 737u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902759u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 698u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        584u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 53u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902762u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 701u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 581u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 53u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902765u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 704u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 128u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 125u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902768u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 707u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 593u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 53u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902771u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 710u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 611u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 53u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294902774u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 605u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 53u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294902777u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 716u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 662u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 4499u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902780u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 617u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 53u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294902783u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 722u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 653u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 4499u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902786u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 668u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 4499u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902789u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 728u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 653u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 50u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902792u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 731u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 689u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902795u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 683u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4499u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902798u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 668u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 50u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902801u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 740u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 590u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 53u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294902804u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 743u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 662u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 50u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902807u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 746u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 530u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 122u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902810u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 749u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 608u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 53u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294902813u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 683u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 50u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902816u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 755u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 695u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 746u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902819u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 758u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 293u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 293u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 293u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 293u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902822u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 761u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 296u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 296u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 296u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 296u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902825u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 602u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 53u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294902828u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 767u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 299u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 299u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 299u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 299u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902831u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 770u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 695u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 122u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902834u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 773u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 302u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 302u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 302u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 302u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902837u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 776u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 704u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 746u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902840u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 599u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 50u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 4505u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4511u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902843u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 782u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 305u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 305u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 305u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 305u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902846u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 785u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 704u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 122u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902849u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 788u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 308u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 308u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 308u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 308u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902852u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 791u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 125u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 125u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294902855u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 794u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 128u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 128u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294902858u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 797u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 311u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 311u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 311u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 311u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902861u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 800u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 314u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 314u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 314u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 314u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902864u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 803u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 317u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 317u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 317u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 317u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902867u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 806u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 320u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 320u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 320u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 320u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902870u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 809u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 122u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 401u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902873u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 812u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 323u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 323u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 323u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 323u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902876u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 815u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 326u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 326u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 326u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 326u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902879u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 818u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 746u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 119u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902882u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 821u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 755u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 887u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902885u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 824u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 119u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902888u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 827u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        623u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 56u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902891u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        629u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 56u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902894u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 833u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        776u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 887u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902897u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 836u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        638u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 56u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902900u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        689u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 56u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902903u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 842u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, next_base_row_ptr +
+        194u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 15926u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 15929u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 15935u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294902906u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 635u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 56u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902909u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 848u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, next_base_row_ptr + 188u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 15947u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 15791u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294902912u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 851u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 656u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 56u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902915u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 854u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 818u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294902918u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 857u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 557u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 1583u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902921u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 860u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 560u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 77u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 554u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294902924u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 863u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 563u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 80u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 554u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294902927u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 866u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 566u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 83u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 554u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294902930u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 869u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 569u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 2114u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902933u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 872u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 557u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 569u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902936u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 875u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 560u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 71u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 578u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902939u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 878u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 563u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 590u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294902942u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 881u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 566u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 602u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294902945u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 884u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 569u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 1232u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902948u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 887u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 131u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 131u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 1u64,
+        curr_base_row_ptr + 131u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584319u64, 66u64, 74u64, 1u64, curr_base_row_ptr + 131u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584318u64,
+        66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902951u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 890u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        134u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 134u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64,
+        1u64, curr_base_row_ptr + 134u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584319u64, 66u64, 74u64, 1u64, curr_base_row_ptr +
+        134u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584318u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294902954u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 893u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1229u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294902957u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 896u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2111u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294902960u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 899u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 17705u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        17711u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 17723u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 17729u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294902963u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 902u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr +
+        17705u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 428u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584315u64, 66u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294902966u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 905u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 15926u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 15929u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 15935u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 15941u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902969u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 908u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 758u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 293u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 293u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 293u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902972u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 911u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 761u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 296u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 296u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 296u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902975u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 914u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 767u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 299u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 299u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 299u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902978u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 917u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 773u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 302u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 302u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 302u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902981u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 920u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 782u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 305u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 305u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 305u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902984u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 923u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 788u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 308u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 308u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 308u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902987u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 926u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 797u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 311u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 311u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 311u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902990u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 929u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 800u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 314u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 314u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 314u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902993u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 932u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 803u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 317u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 317u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 317u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902996u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 935u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 806u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 320u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 320u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 320u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294902999u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 938u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 812u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 323u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 323u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 323u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903002u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 941u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 815u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 326u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 326u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 326u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903005u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 944u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 17648u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 899u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 17741u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294903008u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 947u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 902u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 17711u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294903011u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 950u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 905u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 15947u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903014u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 953u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 746u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 887u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903017u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 956u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr +
+        17765u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17750u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903020u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 959u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 755u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        119u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903023u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 962u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        689u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903026u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 965u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        101u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 698u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903029u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 968u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1013u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903032u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 971u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        101u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 722u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903035u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 974u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 551u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        887u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903038u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 977u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1613u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903041u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 980u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        107u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 674u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903044u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 983u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        110u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 674u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903047u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 986u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        113u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 674u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903050u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 989u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 15764u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        15827u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, next_base_row_ptr + 188u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903053u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 992u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 770u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        887u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903056u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 995u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 770u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        119u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903059u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 998u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        581u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903062u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1001u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        593u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903065u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1004u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        74u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 590u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903068u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1007u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        77u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 602u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903071u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1010u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        74u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 602u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903074u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1013u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        989u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903077u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1016u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        74u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 614u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903080u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1019u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        77u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 626u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903083u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1022u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1235u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903086u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1025u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1238u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903089u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1028u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 776u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        119u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903092u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1031u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 785u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        887u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903095u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1034u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 785u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        119u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903098u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1037u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 947u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        17723u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903101u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1040u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 38u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 1u64, next_base_row_ptr + 41u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, next_base_row_ptr + 44u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 1u64,
+        next_base_row_ptr + 47u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903104u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1043u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 17648u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        947u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17729u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 17741u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903107u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 1046u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 68u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 71u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903110u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 1049u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, next_base_row_ptr + 68u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903113u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 1052u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        119u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 3731u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903116u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1055u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        950u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 15827u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 15779u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, next_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294903119u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1058u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 15752u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 15755u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, curr_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294903122u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1061u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 716u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 119u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        71u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744065119617026u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903125u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1064u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 644u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 2681u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 350u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2687u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 401u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 341u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584318u64, 66u64, 1u64,
+        free_mem_page_ptr + 2687u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 122u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294903128u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1067u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 644u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 791u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 125u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584319u64, 66u64, 74u64, 1u64, curr_base_row_ptr + 125u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584318u64,
+        66u64, 74u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903131u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1070u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 644u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        794u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 128u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584319u64, 66u64, 74u64,
+        1u64, curr_base_row_ptr + 128u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584318u64, 66u64, 74u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903134u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1073u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 1040u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 50u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, next_base_row_ptr + 53u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 66u64, 74u64, 1u64, next_base_row_ptr + 56u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903137u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 1076u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        194u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 194u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64,
+        1u64, curr_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584319u64, 66u64, 74u64, 1u64, curr_base_row_ptr +
+        194u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584318u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903140u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1079u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 15818u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        15755u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 188u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 15827u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903143u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1082u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        902u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17723u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 17729u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 17741u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294903146u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1085u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 956u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, free_mem_page_ptr +
+        17798u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 17636u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 2u64, 1u64, free_mem_page_ptr +
+        17627u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 17636u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903149u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1088u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 17648u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 1037u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 17741u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294903152u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1091u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 1058u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 15764u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 15779u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        next_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294903155u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1094u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 15839u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, free_mem_page_ptr + 15779u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294903158u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1097u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 950u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 15779u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, next_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 15791u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903161u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1100u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 950u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 16100u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 15791u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 16106u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294903164u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1103u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 1043u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, next_base_row_ptr + 431u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 434u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 74u64, 1u64, curr_base_row_ptr + 431u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294903167u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 1106u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 17948u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 431u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903170u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 17u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 155u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, challenges_ptr + 11u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, challenges_ptr + 41u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 2u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        challenges_ptr + 44u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 5u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 47u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 5u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 8u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 1u64, curr_base_row_ptr + 17u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 155u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903173u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 95u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr +
+        5u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 89u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_ext_row_ptr + 5u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 224u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 104u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr +
+        5u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 89u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 224u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294903176u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_ext_row_ptr + 8u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, challenges_ptr + 92u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_ext_row_ptr + 8u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_ext_row_ptr + 5u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 143u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 14u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        272u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 266u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        20u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 266u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 272u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903179u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 395u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 641u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1589u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 620u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 395u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1154u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2036u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 650u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2681u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2357u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 644u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        809u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 674u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2357u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2501u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 677u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2357u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2681u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 395u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        395u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 731u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1244u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 701u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 887u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2501u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 119u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2117u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        95u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 590u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 698u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 2357u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 740u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 10u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 749u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584311u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 707u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2681u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 710u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2681u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2681u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 716u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1589u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 722u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        569u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 728u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 569u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2489u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        569u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2681u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2489u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 827u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        992u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 836u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 992u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2525u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        605u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 395u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 395u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 851u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903182u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        809u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 641u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1592u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 809u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        893u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 896u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2357u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2489u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        1067u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, curr_base_row_ptr + 674u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2489u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2513u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 677u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2489u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 557u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 809u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        809u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 731u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1247u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 701u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 887u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2513u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 119u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2120u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 698u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2489u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 740u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 209u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 749u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 212u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        707u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 569u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 710u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2489u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 716u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1592u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 722u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 581u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        581u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 728u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 581u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2501u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        581u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2501u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2501u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 827u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        995u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 836u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 995u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2537u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        617u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 809u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 809u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 851u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903185u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1325u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 641u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1607u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2204u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2390u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 992u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 2447u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2537u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2549u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2549u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2573u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 677u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2549u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 617u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2357u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 66u64, 74u64, 1u64,
+        curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2357u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 2u64, 66u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2357u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 3u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 566u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 2357u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 4u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        569u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2357u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 5u64, 66u64, 74u64, 66u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        3110u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 560u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2357u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584319u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        3188u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 566u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2357u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584317u64, 66u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2357u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584316u64, 66u64, 74u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 731u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1193u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 701u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2138u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1226u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 698u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2549u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 740u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 80u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 710u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 749u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 110u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        590u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        707u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 629u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 710u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 629u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2549u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        605u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 716u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1607u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 722u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 641u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        641u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 728u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 641u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2561u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        641u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2561u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2561u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 827u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1010u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 836u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1010u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2597u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        677u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2204u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1325u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2585u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 851u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2585u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 2525u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903188u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1337u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 641u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1610u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2216u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2396u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 995u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 101u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1577u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2549u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2561u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2561u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2585u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 677u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2561u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 629u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2204u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1325u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 731u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1226u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 701u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2141u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 692u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1229u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 698u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2561u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 740u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        83u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 722u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 749u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 113u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 602u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 707u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        641u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 710u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 641u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2561u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        617u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 716u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1610u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 722u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 653u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        653u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 728u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 653u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2573u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        653u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2573u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2573u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 827u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1013u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 836u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1013u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2609u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        689u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2216u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1337u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2597u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 851u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2597u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 2537u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903191u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1349u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 641u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1613u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2228u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2402u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 833u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 104u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1577u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2561u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2573u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 557u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2573u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2597u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 677u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2573u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 641u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_ext_row_ptr + 254u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, curr_ext_row_ptr
+        + 257u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, curr_base_row_ptr + 731u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 701u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2075u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1232u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 698u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2573u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 740u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 749u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 707u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 653u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 710u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 653u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2573u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 719u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 629u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 716u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1613u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 722u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        665u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 725u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 665u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 728u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2585u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 737u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 665u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2585u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2585u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 827u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1016u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 836u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1016u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2621u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 839u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 701u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2228u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1349u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 845u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2609u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 851u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2609u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 2549u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903194u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 614u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1361u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 641u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1616u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2240u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 632u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2408u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 1028u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 107u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1577u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2573u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 665u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2585u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2585u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 686u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2609u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 677u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2585u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        653u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 647u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2228u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1349u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 731u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 701u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2108u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1235u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 698u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2585u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 740u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 749u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 707u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        665u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 710u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 665u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2585u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        641u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 716u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1616u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 722u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 677u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        677u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 728u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 677u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2597u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        677u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2597u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2597u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 827u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        944u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 836u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 944u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2633u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        713u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2240u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1361u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2621u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 851u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2621u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 2561u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903197u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1373u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 641u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1619u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2252u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2414u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1031u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 110u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1577u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2585u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2597u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 581u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2597u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2621u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 677u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2597u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2240u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1361u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 731u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 701u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 692u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1238u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 698u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2597u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 740u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 749u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 707u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 677u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 710u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        677u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 713u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2597u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 653u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 716u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1619u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 722u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 689u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 689u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 728u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        689u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2609u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 689u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 743u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2609u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2609u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 827u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 977u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 836u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        977u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 830u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2645u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 725u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2252u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1373u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2633u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 851u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2633u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 2573u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 26u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903200u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1397u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 641u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1625u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 620u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2276u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 395u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        761u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 650u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2609u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2621u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 644u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        605u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 674u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2621u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2645u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 677u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2621u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 689u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2264u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1385u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 701u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1244u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 698u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2621u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 707u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 701u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 710u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 701u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2621u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 719u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 677u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 716u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1625u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 722u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        713u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 725u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 713u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 728u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 713u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2633u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 737u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 713u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2633u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2633u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 827u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 836u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2666u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 839u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 545u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2276u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1397u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 845u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2657u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 851u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2657u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 2597u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903203u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 614u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1409u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 641u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1652u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2288u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 632u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 809u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 899u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2621u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 665u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2633u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 617u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2633u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 686u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2657u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 677u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2633u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        701u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 647u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2276u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1397u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 701u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2681u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 692u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1247u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 698u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2633u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 707u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        713u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 710u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 713u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2633u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        689u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 716u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1652u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 722u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 725u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        725u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 728u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 725u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2645u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        725u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2645u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2645u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 827u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 836u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2288u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1409u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2666u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 851u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2666u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 2609u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903206u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1421u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 641u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2300u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        791u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1028u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2633u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2645u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 629u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2645u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2666u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 677u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2645u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 713u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2288u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1409u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 701u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1250u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 698u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2645u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 707u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 725u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 710u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        725u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 713u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2645u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 701u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 716u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        362u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 722u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 479u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 479u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 728u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        479u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2657u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 479u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 743u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2657u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2657u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 827u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2681u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 836u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2681u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 830u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2300u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1421u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 851u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        476u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 2621u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 26u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903209u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1433u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 641u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 620u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2312u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 794u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1154u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 650u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2645u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2657u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 644u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        641u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 674u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2657u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 677u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2657u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 725u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2300u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1421u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 701u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1253u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 698u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2657u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 707u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 479u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 710u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 479u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2657u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 719u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 713u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 716u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 722u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        545u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 725u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 545u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 728u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 545u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2666u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 737u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 545u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2666u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2666u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 827u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 836u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        374u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 839u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2312u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1433u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        5087u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 5093u64,
+        41u64, 3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 2633u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903212u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1445u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 641u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2324u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        464u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 893u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2657u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2666u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 653u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2666u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 686u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 677u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2666u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 479u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2312u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1433u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 701u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1256u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 698u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2666u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 707u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 545u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 710u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        545u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 713u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2666u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 725u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 716u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        386u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 722u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 728u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 743u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        476u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 827u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 836u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 830u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2681u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2324u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1445u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 5324u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, free_mem_page_ptr + 5330u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 2645u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903215u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 614u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1457u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 641u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 350u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2336u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 632u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 362u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2366u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2666u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 665u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        476u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 686u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 677u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        545u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 647u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2324u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1445u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1259u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 698u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 707u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 710u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 713u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 479u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 716u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2681u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 722u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 728u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 743u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 827u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 836u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 830u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2681u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2336u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1457u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 5561u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, free_mem_page_ptr + 5567u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 2657u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903218u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 614u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1466u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 641u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2345u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 632u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 374u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 854u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        476u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 665u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 677u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 686u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 677u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 647u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2336u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1457u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 698u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 707u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 710u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 713u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 545u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 716u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 722u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 725u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 728u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 734u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 743u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 839u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2345u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_ext_row_ptr + 11u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 5u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, challenges_ptr + 5u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 5u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 5u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 3971u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 80u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64,
+        1u64, next_base_row_ptr + 77u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64,
+        next_base_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64,
+        next_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64,
+        next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1466u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr +
+        14u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 8u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 4157u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 80u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr
+        + 248u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4319u64, 41u64,
+        3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 851u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 227u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4319u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 2666u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903221u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 614u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1472u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 641u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2351u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 632u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 386u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2378u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 665u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 689u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 677u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2345u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1466u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 692u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 698u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 707u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 710u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 719u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 716u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 734u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 839u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2351u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 11u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 5u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, challenges_ptr + 5u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        5u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 3971u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 77u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 1u64, next_base_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64,
+        1u64, next_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64,
+        next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1472u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr +
+        14u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 4157u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 2501u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 4325u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, free_mem_page_ptr + 4355u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64,
+        66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 851u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2501u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 122u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 119u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 476u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294903224u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1475u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 641u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2354u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        350u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2384u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 650u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 665u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 701u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 674u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 677u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 680u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2351u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1472u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 692u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 698u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 713u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 719u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 716u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 830u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2354u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_ext_row_ptr + 11u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 5u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, challenges_ptr + 5u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 3971u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        74u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64, next_base_row_ptr + 71u64,
+        41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64, next_base_row_ptr + 68u64, 41u64,
+        3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 563u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1475u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_ext_row_ptr + 14u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 4142u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 563u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 845u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2513u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        122u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 128u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 119u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 131u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr +
+        4358u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4355u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 851u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2513u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 125u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 119u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903227u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        803u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 620u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1703u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1580u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2390u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 650u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 713u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2354u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1475u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 719u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1703u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 11u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 5u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        3971u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 71u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64, 3u64,
+        1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 803u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 14u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 4127u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 845u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 2525u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 704u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 122u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 131u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 119u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 134u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4358u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 851u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 2525u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 4325u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294903230u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        974u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 620u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 974u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1583u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2396u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 725u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1703u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        803u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 11u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr +
+        3971u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 557u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_ext_row_ptr + 14u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 4112u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 557u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 845u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 3188u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 851u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 3110u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        26u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903233u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 614u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 818u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 818u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1589u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2408u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 545u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        953u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 953u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 953u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        953u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr +
+        4294903236u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 614u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 821u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 821u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1592u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2414u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        818u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 818u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 818u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        818u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr +
+        4294903239u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 614u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 959u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 959u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1595u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 2420u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        821u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 821u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 821u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        821u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr +
+        4294903242u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 614u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 992u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 992u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1598u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 557u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2489u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 644u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 959u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        959u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 959u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 959u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294903245u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 620u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1616u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 818u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 2561u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        1034u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1034u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 1034u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        1034u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr +
+        4294903248u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 614u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 620u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1619u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 626u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 821u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2573u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 647u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 671u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 779u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, free_mem_page_ptr + 4294903251u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 614u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 620u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1622u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 959u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 2585u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 671u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 764u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 779u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr +
+        4294903254u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 632u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1652u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, curr_base_row_ptr + 995u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 2609u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294903257u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 626u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 833u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2621u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, free_mem_page_ptr + 4294903260u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 1028u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 2633u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294903263u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 632u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 626u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 1031u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 2645u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, free_mem_page_ptr + 4294903266u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 1541u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294903269u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        1553u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 11297u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294903272u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 1565u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 11297u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294903275u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 626u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        2666u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 11297u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 4294903278u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 41u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 41u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 35u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, next_base_row_ptr +
+        137u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903281u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 11297u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 11699u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 11u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 41u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 29u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, challenges_ptr + 44u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        32u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 47u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 35u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, next_base_row_ptr + 26u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 11699u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294903284u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_ext_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 26u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 29u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 74u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, challenges_ptr + 77u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        32u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 80u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 59u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, challenges_ptr + 83u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 62u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 86u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 65u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903287u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 32u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584303u64, 66u64, 1u64, next_ext_row_ptr + 29u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 29u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        curr_base_row_ptr + 1073u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr +
+        29u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 14u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_ext_row_ptr + 29u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 11957u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294903290u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 32u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584303u64, 66u64, 1u64, next_ext_row_ptr + 32u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 32u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        curr_base_row_ptr + 731u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr +
+        32u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 17u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_ext_row_ptr + 32u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr +
+        11906u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903293u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 32u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584281u64, 66u64, 1u64, curr_base_row_ptr + 32u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584287u64, 66u64, 74u64,
+        1u64, curr_base_row_ptr + 32u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584273u64, 66u64, 74u64, 1u64, next_ext_row_ptr + 35u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, curr_ext_row_ptr + 35u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64,
+        1u64, curr_base_row_ptr + 698u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 12137u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 740u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        12137u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 98u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        challenges_ptr + 101u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        71u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 104u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 74u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, challenges_ptr + 107u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 77u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 110u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        80u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 113u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 83u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, challenges_ptr + 116u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 86u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 119u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        89u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 122u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 92u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, challenges_ptr + 125u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 95u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 749u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 12137u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        11957u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903296u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 716u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 12326u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 12197u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 12206u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 12218u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 1u64, curr_base_row_ptr
+        + 722u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 12338u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 725u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 12338u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, curr_base_row_ptr + 728u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 12326u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        12239u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 173u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 14u64, 74u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, challenges_ptr + 176u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 71u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 3797u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 9223372034707292161u64, 74u64, 74u64, 66u64, 74u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 737u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        12338u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr +
+        734u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 12350u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 743u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 12326u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 12308u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 12320u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 12308u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 12320u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 752u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 12350u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 44u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 12326u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294903299u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, next_ext_row_ptr + 44u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 44u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 50u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 140u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, challenges_ptr + 53u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        143u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 56u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 146u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, challenges_ptr + 59u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 12488u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, next_ext_row_ptr + 44u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 44u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 12539u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903302u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 12566u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 35u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, next_base_row_ptr + 140u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 140u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        free_mem_page_ptr + 12470u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 12488u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 12566u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        12467u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        12566u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 12539u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903305u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 12692u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_ext_row_ptr + 50u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 50u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 12746u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 74u64, 1u64, free_mem_page_ptr + 12701u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 50u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        12761u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903308u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 12692u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 53u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 12761u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 12746u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 53u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 12701u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_ext_row_ptr + 53u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 53u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903311u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 12692u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 56u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr +
+        38u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 56u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, next_base_row_ptr + 167u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 12701u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_ext_row_ptr + 56u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 56u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903314u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 12692u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_ext_row_ptr + 59u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 38u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_ext_row_ptr + 59u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 170u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 12701u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr +
+        59u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 59u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903317u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, next_ext_row_ptr + 62u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 62u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, next_base_row_ptr +
+        152u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 62u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, next_base_row_ptr + 158u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 65u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        next_base_row_ptr + 161u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, next_base_row_ptr + 155u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 71u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        12677u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, next_ext_row_ptr + 62u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 62u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 12899u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903320u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 251u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        12677u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 12926u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 12692u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 12926u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 12899u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903323u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, challenges_ptr + 29u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, challenges_ptr + 74u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 173u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        challenges_ptr + 77u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        176u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, challenges_ptr + 80u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 179u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, challenges_ptr + 83u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 182u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 86u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        185u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294903326u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 13013u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        13115u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 35u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 13049u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 13010u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        13115u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903329u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1055u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 92u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        15374u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, challenges_ptr + 89u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 15407u64, 41u64, 3u64, 3u64,
+        1u64, 66u64, 1u64, challenges_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 15440u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        challenges_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr +
+        15473u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, challenges_ptr + 89u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 1u64, next_base_row_ptr + 293u64, 41u64, 3u64, 3u64,
+        1u64, 66u64, 1u64, challenges_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        next_base_row_ptr + 296u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, challenges_ptr
+        + 89u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, next_base_row_ptr + 299u64, 41u64,
+        3u64, 3u64, 1u64, 66u64, 1u64, challenges_ptr + 89u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 1u64, next_base_row_ptr + 302u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        challenges_ptr + 89u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, next_base_row_ptr +
+        305u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, challenges_ptr + 89u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 1u64, next_base_row_ptr + 308u64, 41u64, 3u64, 3u64,
+        1u64, 66u64, 74u64, 66u64, 74u64, 1u64, next_base_row_ptr + 194u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 16400u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 15764u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 16400u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903332u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1058u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        15764u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 2u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 13226u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, challenges_ptr + 2u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 13259u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        challenges_ptr + 2u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr +
+        13292u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, challenges_ptr + 2u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 13325u64, 41u64, 3u64, 3u64,
+        1u64, 66u64, 1u64, challenges_ptr + 2u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        curr_base_row_ptr + 293u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 188u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 4294903335u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 1097u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 15893u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 15896u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 15902u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 15908u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 15914u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 15920u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294903338u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 1100u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        98u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 15374u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 13226u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        challenges_ptr + 101u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        15407u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 13259u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 66u64, 1u64, challenges_ptr + 104u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 15440u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr +
+        13292u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 107u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        15473u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 13325u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 66u64, 1u64, challenges_ptr + 110u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 293u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        293u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64, challenges_ptr
+        + 113u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 296u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 296u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        1u64, challenges_ptr + 116u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        299u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 299u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 66u64, 1u64, challenges_ptr + 119u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, next_base_row_ptr + 302u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr +
+        302u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 1u64, challenges_ptr
+        + 122u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 305u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 305u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        1u64, challenges_ptr + 125u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        308u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 308u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 15893u64, 41u64,
+        3u64, 3u64, 1u64, 66u64, 1u64, free_mem_page_ptr + 15896u64, 41u64, 3u64, 3u64,
+        1u64, 66u64, 1u64, free_mem_page_ptr + 15902u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        1u64, free_mem_page_ptr + 15908u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 15914u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 15920u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294903341u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 950u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        989u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, next_ext_row_ptr + 77u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 14u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 77u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 16190u64,
+        41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64, next_base_row_ptr + 194u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 16121u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 15779u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 16121u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903344u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 16250u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        989u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, next_ext_row_ptr + 80u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 17u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 80u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 16148u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 15947u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 16223u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 15779u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 16223u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903347u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 950u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        16100u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, next_ext_row_ptr + 83u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 83u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 95u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_base_row_ptr + 191u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 16190u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64,
+        next_base_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        16301u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        15791u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 16106u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 1u64, free_mem_page_ptr + 16310u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 1u64, free_mem_page_ptr + 16301u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 4294903350u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 848u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        16457u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        197u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 245u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 16250u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 16457u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16481u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 16457u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903353u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 848u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        16520u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        200u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 248u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 16250u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 16520u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16481u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 16520u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903356u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 848u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        16571u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        203u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 251u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 16250u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 16571u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16481u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 16571u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903359u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 848u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        16622u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        206u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 254u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 16250u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 16622u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16481u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 16622u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903362u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 848u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        16673u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        209u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 257u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 16250u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 16673u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16481u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 16673u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903365u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 848u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        16724u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        212u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 260u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 16250u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 16724u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16481u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 16724u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903368u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 848u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        16775u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        215u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 263u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 16250u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 16775u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16481u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 16775u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903371u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 848u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        16826u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        218u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 266u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 16250u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 16826u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16481u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 16826u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903374u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 848u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        16877u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        221u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 269u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 16250u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 16877u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16481u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 16877u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903377u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 848u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        16928u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        224u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 272u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 16250u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 16928u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16481u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 16928u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903380u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 848u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        16979u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        227u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 275u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 16250u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 16979u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16481u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 16979u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903383u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 848u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        17030u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        230u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 278u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 16250u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17030u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16481u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 17030u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903386u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 848u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        17081u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        233u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 281u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 16250u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17081u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16481u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 17081u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903389u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 848u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        17132u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        236u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 284u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 16250u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17132u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16481u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 17132u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903392u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 848u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        17183u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        239u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 287u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 16250u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17183u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16481u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 17183u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903395u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 848u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        17234u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        242u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 290u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr + 16250u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17234u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 16481u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 17234u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903398u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        free_mem_page_ptr + 17312u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        17342u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 146u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 149u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        256u64, 1u64, next_base_row_ptr + 392u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        next_base_row_ptr + 395u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64,
+        challenges_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        256u64, 1u64, next_base_row_ptr + 398u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        next_base_row_ptr + 401u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 74u64,
+        66u64, 74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 404u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        next_base_row_ptr + 389u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        17342u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903401u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17312u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17390u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 155u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 17375u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64, challenges_ptr + 155u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 17384u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        2u64, 1u64, challenges_ptr + 155u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, free_mem_page_ptr + 17375u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 17384u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 1u64,
+        next_base_row_ptr + 389u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        17390u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903404u64, 11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17468u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17504u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 155u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, next_base_row_ptr + 410u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 158u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        1u64, next_base_row_ptr + 413u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        161u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, next_base_row_ptr + 416u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64, next_base_row_ptr + 407u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17504u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903407u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 17468u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 143u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 143u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 164u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        next_base_row_ptr + 413u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        next_base_row_ptr + 407u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr +
+        143u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 143u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903410u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 17648u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 17996u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294903413u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 419u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        17996u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 32u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 173u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        428u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, challenges_ptr + 167u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 431u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, challenges_ptr + 170u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 437u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 176u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        443u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, next_base_row_ptr + 446u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        4294903416u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 530u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 503u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 56u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 116u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 1u64, 66u64, 74u64, 66u64, 1u64, challenges_ptr + 59u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 110u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        74u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903419u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 155u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 1643u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 503u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 56u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 116u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 1u64, 66u64, 74u64, 66u64, 1u64, challenges_ptr + 59u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 110u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        74u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903422u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 158u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 23u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, free_mem_page_ptr + 503u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        56u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 116u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 2u64, 66u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 59u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        107u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294903425u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 161u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 155u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 503u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 56u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 116u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 2u64, 66u64, 74u64, 66u64, 1u64, challenges_ptr + 59u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 107u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        74u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903428u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 164u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 158u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 23u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, free_mem_page_ptr + 503u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        56u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 116u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 3u64, 66u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 59u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        104u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294903431u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 167u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 161u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 503u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 56u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 116u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 3u64, 66u64, 74u64, 66u64, 1u64, challenges_ptr + 59u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 104u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        74u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903434u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 170u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 164u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 23u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, free_mem_page_ptr + 503u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        56u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 116u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4u64, 66u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 59u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr +
+        101u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294903437u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 173u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 2900u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 2873u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 2u64, 66u64, 1u64, challenges_ptr + 65u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, next_base_row_ptr + 74u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64,
+        66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903440u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_ext_row_ptr + 176u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 3128u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 26u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr +
+        2867u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 3152u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, curr_base_row_ptr + 74u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, challenges_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64,
+        66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903443u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_ext_row_ptr + 179u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 20u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 170u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903446u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_ext_row_ptr + 182u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 167u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr +
+        503u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 56u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 116u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 4u64, 66u64, 74u64, 66u64, 1u64, challenges_ptr + 59u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 101u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903449u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 185u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 173u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 26u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, free_mem_page_ptr + 2873u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        3u64, 66u64, 1u64, challenges_ptr + 65u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        1u64, next_base_row_ptr + 77u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294903452u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 176u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 2867u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 3194u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 1u64, curr_base_row_ptr + 77u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903455u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_ext_row_ptr + 191u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 185u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, challenges_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr +
+        2873u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 4u64, 66u64, 1u64, challenges_ptr +
+        65u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, next_base_row_ptr + 80u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 68u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903458u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 194u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 26u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, free_mem_page_ptr + 2867u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        3u64, 66u64, 1u64, challenges_ptr + 65u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        1u64, curr_base_row_ptr + 80u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 74u64, 66u64,
+        1u64, free_mem_page_ptr + 4294903461u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 197u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 23u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_ext_row_ptr + 191u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 2873u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 5u64, 66u64, 1u64, challenges_ptr + 65u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, next_base_row_ptr + 83u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        74u64, 74u64, 74u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903464u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 200u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 569u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr +
+        23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_ext_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        26u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 2867u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 4u64, 66u64, 1u64, challenges_ptr + 65u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 83u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        challenges_ptr + 68u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        74u64, 74u64, 74u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903467u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 203u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 170u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 23u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, free_mem_page_ptr + 503u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        56u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 116u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 5u64, 66u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 59u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 98u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 1u64, challenges_ptr
+        + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 503u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 56u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 116u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 6u64, 66u64, 74u64, 66u64, 1u64, challenges_ptr + 59u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 95u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        74u64, 66u64, 74u64, 1u64, challenges_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr +
+        503u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 56u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 116u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 7u64, 66u64, 74u64, 66u64, 1u64, challenges_ptr + 59u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 92u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903470u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 206u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 182u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 23u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, free_mem_page_ptr + 503u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        56u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 116u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 5u64, 66u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 59u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 98u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 1u64, challenges_ptr
+        + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 503u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 56u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 116u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 6u64, 66u64, 74u64, 66u64, 1u64, challenges_ptr + 59u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 95u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        74u64, 66u64, 74u64, 1u64, challenges_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr +
+        503u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 56u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 116u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 7u64, 66u64, 74u64, 66u64, 1u64, challenges_ptr + 59u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 92u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 74u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903473u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 209u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr +
+        203u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 23u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 503u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        56u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 116u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 8u64, 66u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 59u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_base_row_ptr + 89u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 1u64, challenges_ptr
+        + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 503u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 56u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_base_row_ptr + 116u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 9u64, 66u64, 74u64, 66u64, 1u64, challenges_ptr + 59u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_base_row_ptr + 86u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        74u64, 66u64, 74u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903476u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 212u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr +
+        206u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 23u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 503u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        56u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 116u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 8u64, 66u64, 74u64, 66u64, 1u64,
+        challenges_ptr + 59u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 89u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 1u64, challenges_ptr
+        + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 503u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 56u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 116u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 9u64, 66u64, 74u64, 66u64, 1u64, challenges_ptr + 59u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 86u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64,
+        74u64, 66u64, 74u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903479u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 215u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        530u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903482u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 218u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr +
+        158u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903485u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 221u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 4214u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        26u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 2873u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 3152u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64,
+        free_mem_page_ptr + 4220u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 74u64,
+        1u64, challenges_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 2873u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, free_mem_page_ptr + 3194u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        1u64, free_mem_page_ptr + 4238u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64,
+        74u64, 1u64, challenges_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 4256u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4259u64, 41u64, 3u64, 3u64, 1u64,
+        66u64, 74u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903488u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 224u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr +
+        182u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903491u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 227u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 4214u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr +
+        26u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 4256u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 4220u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64,
+        66u64, 74u64, 1u64, challenges_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, free_mem_page_ptr + 4277u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4238u64, 41u64, 3u64, 3u64,
+        1u64, 66u64, 74u64, 66u64, 74u64, 1u64, challenges_ptr + 26u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 4298u64, 41u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr +
+        4259u64, 41u64, 3u64, 3u64, 1u64, 66u64, 74u64, 66u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903494u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 230u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, next_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 20u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, curr_ext_row_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 74u64,
+        74u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903497u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 233u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr +
+        20u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_ext_row_ptr + 164u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903500u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_ext_row_ptr + 236u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 557u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 1652u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903503u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_ext_row_ptr + 239u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 560u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 20u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 155u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 74u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903506u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 242u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64, next_ext_row_ptr +
+        20u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_ext_row_ptr + 161u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903509u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_ext_row_ptr + 245u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 566u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, next_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 20u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 167u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 74u64, 66u64, 74u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903512u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 248u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr +
+        221u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 26u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 4277u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        131u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 68u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 1u64, challenges_ptr + 26u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        free_mem_page_ptr + 4298u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr +
+        134u64, 41u64, 3u64, 3u64, 1u64, 1u64, challenges_ptr + 68u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 74u64, 66u64, 74u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903515u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 251u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, free_mem_page_ptr + 12926u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 35u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, next_base_row_ptr + 152u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_base_row_ptr + 152u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 66u64,
+        74u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 66u64, 1u64,
+        free_mem_page_ptr + 12701u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903518u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 254u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_base_row_ptr + 647u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, free_mem_page_ptr + 2900u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        74u64, 1u64, curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_ext_row_ptr + 173u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_ext_row_ptr + 185u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_ext_row_ptr + 191u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        74u64, 66u64, 1u64, curr_ext_row_ptr + 197u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903521u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_ext_row_ptr + 257u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_base_row_ptr + 671u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 557u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, next_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 23u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, free_mem_page_ptr + 3128u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64,
+        66u64, 74u64, 1u64, curr_base_row_ptr + 560u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_ext_row_ptr + 176u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 563u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_ext_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        74u64, 66u64, 1u64, curr_base_row_ptr + 566u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        next_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 23u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, curr_ext_row_ptr + 194u64, 41u64, 3u64, 3u64, 1u64, 74u64, 74u64, 66u64,
+        74u64, 66u64, 1u64, curr_ext_row_ptr + 200u64, 41u64, 3u64, 3u64, 1u64, 66u64,
+        74u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903524u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 17u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903527u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 11u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584312u64, 66u64,
+        1u64, curr_base_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        4294903530u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 32u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4294903533u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 191u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584281u64, 66u64, 74u64, 1u64, curr_base_row_ptr + 194u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584316u64,
+        66u64, 74u64, 1u64, free_mem_page_ptr + 4294903536u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_base_row_ptr + 431u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 428u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584291u64, 66u64, 74u64, 1u64, free_mem_page_ptr +
+        4294903539u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 437u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4294903542u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_ext_row_ptr + 56u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 50u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_ext_row_ptr
+        + 59u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 53u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        66u64, 1u64, free_mem_page_ptr + 4294903545u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584319u64, 66u64, 1u64, curr_base_row_ptr + 188u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584318u64, 66u64,
+        74u64, 1u64, curr_base_row_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 74u64, 1u64,
+        challenges_ptr + 2u64, 41u64, 3u64, 3u64, 1u64, 1u64, curr_base_row_ptr + 197u64,
+        41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 281474976710656u64, 74u64,
+        1u64, curr_base_row_ptr + 200u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 4294967296u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 203u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 65536u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 206u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744065119617025u64, 74u64, 66u64, 1u64, challenges_ptr + 2u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 209u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 281474976710656u64, 74u64, 1u64,
+        curr_base_row_ptr + 212u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 4294967296u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 215u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 65536u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 218u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744065119617025u64, 74u64, 66u64, 1u64, challenges_ptr + 2u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 221u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 281474976710656u64, 74u64, 1u64,
+        curr_base_row_ptr + 224u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 4294967296u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 227u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 65536u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 230u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744065119617025u64, 74u64, 66u64, 1u64, challenges_ptr + 2u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 233u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 281474976710656u64, 74u64, 1u64,
+        curr_base_row_ptr + 236u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 4294967296u64, 74u64, 66u64, 1u64, curr_base_row_ptr + 239u64, 41u64, 3u64,
+        3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 65536u64, 74u64, 66u64, 1u64,
+        curr_base_row_ptr + 242u64, 41u64, 3u64, 3u64, 1u64, 66u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744065119617025u64, 74u64, 66u64, 1u64, challenges_ptr + 2u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 1u64, curr_base_row_ptr + 293u64, 41u64, 3u64,
+        3u64, 1u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        challenges_ptr + 188u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 74u64, 1u64,
+        free_mem_page_ptr + 4294903548u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 143u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, challenges_ptr + 185u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294903551u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_ext_row_ptr + 8u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 74u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903554u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, challenges_ptr + 179u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 11u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903557u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_ext_row_ptr + 14u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, challenges_ptr + 182u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903560u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 17u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 2u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903563u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 20u64, 41u64, 3u64, 3u64, 1u64,
+        1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr +
+        44u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr +
+        4294903566u64, 11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 23u64, 41u64,
+        3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 62u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903569u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 26u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 68u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, free_mem_page_ptr + 4294903572u64, 11u64, 3u64, 3u64, 1u64,
+        1u64, curr_ext_row_ptr + 29u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64,
+        1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 77u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903575u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_ext_row_ptr + 80u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 32u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903578u64, 11u64, 3u64,
+        3u64, 1u64, 1u64, curr_ext_row_ptr + 35u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 83u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903581u64, 11u64,
+        3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 134u64, 41u64, 3u64, 3u64, 1u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 86u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 89u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 92u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 95u64, 41u64,
+        3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 98u64, 41u64, 3u64, 3u64, 1u64,
+        74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 101u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 104u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 107u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 110u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 113u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 116u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 119u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 122u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 125u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 128u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, 0u64,
+        1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 131u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903584u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, curr_ext_row_ptr + 137u64, 41u64, 3u64, 3u64,
+        1u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64,
+        curr_ext_row_ptr + 140u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        free_mem_page_ptr + 4294903587u64, 11u64, 3u64, 3u64, 1u64, 1u64,
+        curr_ext_row_ptr + 38u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64, 0u64, 1u64,
+        18446744069414584320u64, 1u64, curr_ext_row_ptr + 146u64, 41u64, 3u64, 3u64,
+        1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903590u64, 11u64, 3u64, 3u64,
+        1u64, 1u64, curr_ext_row_ptr + 41u64, 41u64, 3u64, 3u64, 1u64, 1u64, 0u64, 1u64,
+        0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 47u64, 41u64, 3u64,
+        3u64, 1u64, 74u64, 66u64, 1u64, 0u64, 1u64, 0u64, 1u64, 18446744069414584320u64,
+        1u64, curr_ext_row_ptr + 65u64, 41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64,
+        0u64, 1u64, 0u64, 1u64, 18446744069414584320u64, 1u64, curr_ext_row_ptr + 71u64,
+        41u64, 3u64, 3u64, 1u64, 74u64, 66u64, 1u64, free_mem_page_ptr + 4294903593u64,
+        11u64, 3u64, 3u64, 1u64, 1u64, free_mem_page_ptr + 4294901760u64,
+    ];
+    let raw_instructions = raw_instructions
+        .into_iter()
+        .map(BFieldElement::new)
+        .collect::<Vec<_>>();
+    let program = Program::decode(&raw_instructions).unwrap();
+    let irrelevant_label = |_: &_| String::new();
+    program
+        .into_iter()
+        .map(|instruction| instruction.map_call_address(irrelevant_label))
+        .map(LabelledInstruction::Instruction)
+        .collect()
 }
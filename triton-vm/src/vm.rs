@@ -17,8 +17,10 @@ use twenty_first::util_types::algebraic_hasher::Domain;
 
 use crate::error::InstructionError;
 use crate::error::InstructionError::*;
+use crate::error::SnapshotError;
 use crate::instruction::AnInstruction::*;
 use crate::instruction::Instruction;
+use crate::observer::VMObserver;
 use crate::op_stack::OpStackElement::*;
 use crate::op_stack::*;
 use crate::program::*;
@@ -100,6 +102,56 @@ pub enum CoProcessorCall {
     RamCall(RamTableCall),
 }
 
+/// A self-contained, serializable copy of a [`VMState`] in between two instructions. Execution can
+/// be resumed from the [restored](VMState::restore) state, for example using
+/// [`Program::trace_execution_of_state`], which then produces the same suffix of the
+/// [`AlgebraicExecutionTrace`](crate::aet::AlgebraicExecutionTrace) as an uninterrupted execution.
+///
+/// # Format
+///
+/// A snapshot consists of
+/// 1. the [format version](Self::FORMAT_VERSION), and
+/// 1. the complete [`VMState`], _i.e._, the program, the remaining public and secret input, the
+///    public output produced so far, the RAM, the op stack, the jump stack, the cycle count, the
+///    instruction pointer, the Sponge state, and the halting flag.
+///
+/// While executing a single instruction, the VM records all accesses to RAM and to the op-stack
+/// underflow memory. These recordings are part of the [`VMState`] but always empty in a snapshot.
+///
+/// Snapshots are independent of any particular data format: they can be stored using any format
+/// supported by [`serde`](https://serde.rs). Restoring a snapshot with a different format version
+/// fails.
+///
+/// # Examples
+///
+/// ```
+/// # use triton_vm::prelude::*;
+/// let program = triton_program!(push 3 push 4 mul write_io 1 halt);
+/// let mut state = VMState::new(&program, [].into(), [].into());
+/// state.step().unwrap();
+///
+/// let snapshot = state.snapshot();
+/// let mut forked_state = VMState::restore(snapshot).unwrap();
+/// state.run().unwrap();
+/// forked_state.run().unwrap();
+/// assert_eq!(state.public_output, forked_state.public_output);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct VMSnapshot {
+    format_version: u32,
+    state: VMState,
+}
+
+impl VMSnapshot {
+    /// The version of the snapshot format. Changes whenever the format changes.
+    pub const FORMAT_VERSION: u32 = 1;
+
+    /// The format version this snapshot was created with.
+    pub fn format_version(&self) -> u32 {
+        self.format_version
+    }
+}
+
 impl VMState {
     /// Create initial `VMState` for a given `program`
     ///
@@ -956,10 +1008,108 @@ impl VMState {
         }
     }
 
+    /// Perform the state transition like [`step`](Self::step) does, notifying the given
+    /// [`VMObserver`] along the way.
+    pub fn step_with_observer<O: VMObserver>(
+        &mut self,
+        observer: &mut O,
+    ) -> Result<Vec<CoProcessorCall>> {
+        if self.halting {
+            observer.error(self, MachineHalted);
+            return Err(MachineHalted);
+        }
+        let instruction = match self.current_instruction() {
+            Ok(instruction) => instruction,
+            Err(err) => {
+                observer.error(self, err);
+                return Err(err);
+            }
+        };
+        observer.before_instruction(self, instruction);
+
+        let public_input_to_be_read: Vec<_> = match instruction {
+            ReadIo(n) => self
+                .public_input
+                .iter()
+                .take(n.num_words())
+                .copied()
+                .collect(),
+            _ => vec![],
+        };
+        let public_output_len = self.public_output.len();
+
+        let co_processor_calls = match self.step() {
+            Ok(calls) => calls,
+            Err(err) => {
+                observer.error(self, err);
+                return Err(err);
+            }
+        };
+
+        if !public_input_to_be_read.is_empty() {
+            observer.public_input_read(&public_input_to_be_read);
+        }
+        for call in &co_processor_calls {
+            observer.co_processor_call(call);
+            if let RamCall(ram_call) = call {
+                observer.ram_access(ram_call);
+            }
+        }
+        if self.public_output.len() > public_output_len {
+            observer.public_output_written(&self.public_output[public_output_len..]);
+        }
+        observer.after_instruction(self, instruction);
+
+        Ok(co_processor_calls)
+    }
+
+    /// A [snapshot](VMSnapshot) of this state. If the most recent instruction crashed the VM, any
+    /// co-processor calls it recorded before crashing are not part of the snapshot.
+    pub fn snapshot(&self) -> VMSnapshot {
+        let mut state = self.clone();
+        state.ram_calls.clear();
+        let _ = state.op_stack.stop_recording_underflow_io_sequence();
+
+        VMSnapshot {
+            format_version: VMSnapshot::FORMAT_VERSION,
+            state,
+        }
+    }
+
+    /// Restore the state captured in the given [snapshot](VMSnapshot).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the snapshot's format version is not supported or if the captured
+    /// state is inconsistent, which can happen if a snapshot was manipulated after serialization.
+    pub fn restore(snapshot: VMSnapshot) -> std::result::Result<Self, SnapshotError> {
+        if snapshot.format_version != VMSnapshot::FORMAT_VERSION {
+            return Err(SnapshotError::UnsupportedFormatVersion(
+                snapshot.format_version,
+            ));
+        }
+
+        let state = snapshot.state;
+        if !state.ram_calls.is_empty() || state.op_stack.has_recorded_underflow_io() {
+            return Err(SnapshotError::PendingCoProcessorCalls);
+        }
+        if state.op_stack.would_be_too_shallow(0) {
+            return Err(SnapshotError::OpStackTooShallow);
+        }
+
+        Ok(state)
+    }
+
     /// Run Triton VM on this state to completion, or until an error occurs.
     pub fn run(&mut self) -> Result<()> {
+        self.run_with_observer(&mut ())
+    }
+
+    /// Run Triton VM on this state to completion, or until an error occurs, notifying the given
+    /// [`VMObserver`] about every step.
+    pub fn run_with_observer<O: VMObserver>(&mut self, observer: &mut O) -> Result<()> {
         while !self.halting {
-            self.step()?;
+            self.step_with_observer(observer)?;
         }
         Ok(())
     }
@@ -1089,6 +1239,8 @@ pub(crate) mod tests {
     use assert2::assert;
     use assert2::let_assert;
     use itertools::izip;
+    use ndarray::s;
+    use ndarray::Array2;
     use proptest::collection::vec;
     use proptest::prelude::*;
     use proptest_arbitrary_interop::arb;
@@ -1105,6 +1257,7 @@ pub(crate) mod tests {
     use test_strategy::proptest;
     use twenty_first::math::other::random_elements;
 
+    use crate::aet::AlgebraicExecutionTrace;
     use crate::example_programs::*;
     use crate::op_stack::NumberOfWords::*;
     use crate::shared_tests::prove_with_low_security_level;
@@ -2494,6 +2647,111 @@ pub(crate) mod tests {
         prop_assert_eq!(vm_state, deserialized);
     }
 
+    #[proptest]
+    fn restoring_snapshot_of_vm_state_is_identity(#[strategy(arb())] mut vm_state: VMState) {
+        vm_state.ram_calls.clear();
+        let _ = vm_state.op_stack.stop_recording_underflow_io_sequence();
+        prop_assume!(!vm_state.op_stack.would_be_too_shallow(0));
+
+        let snapshot = vm_state.snapshot();
+        let serialized = serde_json::to_string(&snapshot).unwrap();
+        let deserialized = serde_json::from_str(&serialized).unwrap();
+        let restored_state = VMState::restore(deserialized).unwrap();
+        prop_assert_eq!(vm_state, restored_state);
+    }
+
+    #[proptest]
+    fn snapshot_of_vm_state_never_contains_pending_co_processor_calls(
+        #[strategy(arb())] vm_state: VMState,
+    ) {
+        let snapshot = vm_state.snapshot();
+        prop_assert!(snapshot.state.ram_calls.is_empty());
+        prop_assert!(!snapshot.state.op_stack.has_recorded_underflow_io());
+    }
+
+    #[test]
+    fn restoring_snapshot_with_unknown_format_version_fails() {
+        let state = VMState::new(&triton_program!(halt), [].into(), [].into());
+        let mut snapshot = state.snapshot();
+        snapshot.format_version += 1;
+
+        let_assert!(Err(err) = VMState::restore(snapshot));
+        assert!(SnapshotError::UnsupportedFormatVersion(VMSnapshot::FORMAT_VERSION + 1) == err);
+    }
+
+    #[test]
+    fn restoring_snapshot_with_pending_co_processor_calls_fails() {
+        let state = VMState::new(&triton_program!(halt), [].into(), [].into());
+        let mut snapshot = state.snapshot();
+        snapshot.state.op_stack.push(bfe!(42));
+
+        let_assert!(Err(err) = VMState::restore(snapshot));
+        assert!(SnapshotError::PendingCoProcessorCalls == err);
+    }
+
+    fn assert_trace_of_restored_snapshot_is_suffix_of_full_trace(
+        program_and_input: &ProgramAndInput,
+        full_aet: &AlgebraicExecutionTrace,
+        num_steps_before_snapshot: usize,
+    ) {
+        let program = &program_and_input.program;
+        let public_input = program_and_input.public_input();
+        let non_determinism = program_and_input.non_determinism();
+
+        let mut state = VMState::new(program, public_input, non_determinism);
+        for _ in 0..num_steps_before_snapshot {
+            state.step().unwrap();
+        }
+        let serialized_snapshot = serde_json::to_string(&state.snapshot()).unwrap();
+        let snapshot = serde_json::from_str(&serialized_snapshot).unwrap();
+        let restored_state = VMState::restore(snapshot).unwrap();
+        let (resumed_aet, _) = program.trace_execution_of_state(restored_state).unwrap();
+
+        let assert_is_suffix = |full: &Array2<BFieldElement>, suffix: &Array2<BFieldElement>| {
+            assert!(full.nrows() >= suffix.nrows());
+            let suffix_start = full.nrows() - suffix.nrows();
+            assert!(full.slice(s![suffix_start.., ..]) == suffix);
+        };
+        assert_is_suffix(&full_aet.processor_trace, &resumed_aet.processor_trace);
+        assert_is_suffix(
+            &full_aet.op_stack_underflow_trace,
+            &resumed_aet.op_stack_underflow_trace,
+        );
+        assert_is_suffix(&full_aet.ram_trace, &resumed_aet.ram_trace);
+        assert_is_suffix(&full_aet.hash_trace, &resumed_aet.hash_trace);
+        assert_is_suffix(&full_aet.sponge_trace, &resumed_aet.sponge_trace);
+
+        let num_resumed_cycles = full_aet.processor_trace.nrows() - num_steps_before_snapshot;
+        assert!(num_resumed_cycles == resumed_aet.processor_trace.nrows());
+    }
+
+    #[test]
+    fn trace_of_restored_snapshot_is_suffix_of_full_trace() {
+        let programs_and_inputs = [
+            property_based_test_program_for_random_ram_access(),
+            test_program_for_many_sponge_instructions(),
+            test_program_for_call_recurse_return(),
+            test_program_for_div_mod(),
+        ];
+        for program_and_input in programs_and_inputs {
+            let (full_aet, _) = program_and_input
+                .program
+                .trace_execution(
+                    program_and_input.public_input(),
+                    program_and_input.non_determinism(),
+                )
+                .unwrap();
+            let num_cycles = full_aet.processor_trace.nrows();
+            for num_steps in [0, 1, num_cycles / 2, num_cycles - 1] {
+                assert_trace_of_restored_snapshot_is_suffix_of_full_trace(
+                    &program_and_input,
+                    &full_aet,
+                    num_steps,
+                );
+            }
+        }
+    }
+
     #[proptest]
     fn xxdotstep(
         #[strategy(0_usize..=25)] n: usize,